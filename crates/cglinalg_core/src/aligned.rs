@@ -0,0 +1,388 @@
+use crate::matrix::Matrix3x3;
+use crate::vector::Vector3;
+use cglinalg_numeric::SimdScalar;
+
+use core::fmt;
+
+
+/// A three-dimensional vector padded out to the size and alignment of a
+/// four-dimensional vector.
+///
+/// The `std140` and `std430` buffer layouts in GLSL, and the uniform and storage
+/// buffer layouts in WGSL, align a three-dimensional vector of four-byte scalars
+/// to sixteen bytes. A [`Vector3`] is only aligned to the alignment of its
+/// scalars, so an array of them, or a structure containing one followed by
+/// another field, does not match the layout a shader expects. This type stores
+/// the three components followed by an explicit padding component so that its
+/// memory layout matches the shader-side layout exactly.
+///
+/// The padding component is always zero when constructed from a vector.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     AlignedVector3,
+/// #     Vector3,
+/// # };
+/// #
+/// let vector = Vector3::new(1_f32, 2_f32, 3_f32);
+/// let aligned = AlignedVector3::from_vector(&vector);
+///
+/// assert_eq!(core::mem::size_of::<AlignedVector3<f32>>(), 16);
+/// assert_eq!(core::mem::align_of::<AlignedVector3<f32>>(), 16);
+/// assert_eq!(aligned.to_vector(), vector);
+/// ```
+#[repr(C, align(16))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AlignedVector3<S> {
+    data: [S; 3],
+    padding: S,
+}
+
+impl<S> AlignedVector3<S>
+where
+    S: SimdScalar,
+{
+    /// Construct a new padded vector from its components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     AlignedVector3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let aligned = AlignedVector3::new(1_f32, 2_f32, 3_f32);
+    ///
+    /// assert_eq!(aligned.to_vector(), Vector3::new(1_f32, 2_f32, 3_f32));
+    /// ```
+    #[inline]
+    pub fn new(x: S, y: S, z: S) -> Self {
+        Self {
+            data: [x, y, z],
+            padding: S::zero(),
+        }
+    }
+
+    /// Construct a padded vector from a vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     AlignedVector3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let vector = Vector3::new(1_i32, 2_i32, 3_i32);
+    /// let aligned = AlignedVector3::from_vector(&vector);
+    ///
+    /// assert_eq!(aligned.to_vector(), vector);
+    /// ```
+    #[inline]
+    pub fn from_vector(vector: &Vector3<S>) -> Self {
+        Self::new(vector[0], vector[1], vector[2])
+    }
+
+    /// Convert a padded vector back into a vector, dropping the padding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     AlignedVector3,
+    /// #     Vector3,
+    /// # };
+    /// #
+    /// let aligned = AlignedVector3::new(1_u32, 2_u32, 3_u32);
+    ///
+    /// assert_eq!(aligned.to_vector(), Vector3::new(1_u32, 2_u32, 3_u32));
+    /// ```
+    #[inline]
+    pub fn to_vector(&self) -> Vector3<S> {
+        Vector3::new(self.data[0], self.data[1], self.data[2])
+    }
+}
+
+impl<S> Default for AlignedVector3<S>
+where
+    S: SimdScalar,
+{
+    fn default() -> Self {
+        Self::from_vector(&Vector3::zero())
+    }
+}
+
+impl<S> fmt::Display for AlignedVector3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "AlignedVector3 [{}, {}, {}]", self.data[0], self.data[1], self.data[2])
+    }
+}
+
+impl<S> From<Vector3<S>> for AlignedVector3<S>
+where
+    S: SimdScalar,
+{
+    #[inline]
+    fn from(vector: Vector3<S>) -> Self {
+        Self::from_vector(&vector)
+    }
+}
+
+impl<S> From<&Vector3<S>> for AlignedVector3<S>
+where
+    S: SimdScalar,
+{
+    #[inline]
+    fn from(vector: &Vector3<S>) -> Self {
+        Self::from_vector(vector)
+    }
+}
+
+impl<S> From<AlignedVector3<S>> for Vector3<S>
+where
+    S: SimdScalar,
+{
+    #[inline]
+    fn from(aligned: AlignedVector3<S>) -> Self {
+        aligned.to_vector()
+    }
+}
+
+impl<S> From<&AlignedVector3<S>> for Vector3<S>
+where
+    S: SimdScalar,
+{
+    #[inline]
+    fn from(aligned: &AlignedVector3<S>) -> Self {
+        aligned.to_vector()
+    }
+}
+
+
+/// A three-by-three matrix whose columns are padded out to the size and alignment
+/// of a four-dimensional vector.
+///
+/// The `std140` and `std430` buffer layouts in GLSL, and the uniform and storage
+/// buffer layouts in WGSL, store a `mat3` of four-byte scalars as three columns
+/// with a stride of sixteen bytes each, for a total of forty-eight bytes. A
+/// [`Matrix3x3`] stores its columns tightly packed in thirty-six bytes. This type
+/// stores each column as an [`AlignedVector3`] so that its memory layout matches
+/// the shader-side layout exactly.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     AlignedMatrix3x3,
+/// #     Matrix3x3,
+/// # };
+/// #
+/// let matrix = Matrix3x3::new(
+///     1_f32, 2_f32, 3_f32,
+///     4_f32, 5_f32, 6_f32,
+///     7_f32, 8_f32, 9_f32,
+/// );
+/// let aligned = AlignedMatrix3x3::from_matrix(&matrix);
+///
+/// assert_eq!(core::mem::size_of::<AlignedMatrix3x3<f32>>(), 48);
+/// assert_eq!(core::mem::align_of::<AlignedMatrix3x3<f32>>(), 16);
+/// assert_eq!(aligned.to_matrix(), matrix);
+/// ```
+#[repr(C, align(16))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AlignedMatrix3x3<S> {
+    columns: [AlignedVector3<S>; 3],
+}
+
+impl<S> AlignedMatrix3x3<S>
+where
+    S: SimdScalar,
+{
+    /// Construct a padded matrix from a matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     AlignedMatrix3x3,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix = Matrix3x3::from_diagonal_value(2_f32);
+    /// let aligned = AlignedMatrix3x3::from_matrix(&matrix);
+    ///
+    /// assert_eq!(aligned.to_matrix(), matrix);
+    /// ```
+    #[inline]
+    pub fn from_matrix(matrix: &Matrix3x3<S>) -> Self {
+        Self {
+            columns: [
+                AlignedVector3::from_vector(&matrix[0]),
+                AlignedVector3::from_vector(&matrix[1]),
+                AlignedVector3::from_vector(&matrix[2]),
+            ],
+        }
+    }
+
+    /// Convert a padded matrix back into a matrix, dropping the padding.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     AlignedMatrix3x3,
+    /// #     Matrix3x3,
+    /// # };
+    /// #
+    /// let matrix: Matrix3x3<f32> = Matrix3x3::identity();
+    /// let aligned = AlignedMatrix3x3::from_matrix(&matrix);
+    ///
+    /// assert_eq!(aligned.to_matrix(), matrix);
+    /// ```
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix3x3<S> {
+        Matrix3x3::from_columns(&[
+            self.columns[0].to_vector(),
+            self.columns[1].to_vector(),
+            self.columns[2].to_vector(),
+        ])
+    }
+}
+
+impl<S> Default for AlignedMatrix3x3<S>
+where
+    S: SimdScalar,
+{
+    fn default() -> Self {
+        Self::from_matrix(&Matrix3x3::zero())
+    }
+}
+
+impl<S> fmt::Display for AlignedMatrix3x3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "AlignedMatrix3x3 [[{}, {}, {}], [{}, {}, {}], [{}, {}, {}]]",
+            self.columns[0].data[0],
+            self.columns[0].data[1],
+            self.columns[0].data[2],
+            self.columns[1].data[0],
+            self.columns[1].data[1],
+            self.columns[1].data[2],
+            self.columns[2].data[0],
+            self.columns[2].data[1],
+            self.columns[2].data[2],
+        )
+    }
+}
+
+impl<S> From<Matrix3x3<S>> for AlignedMatrix3x3<S>
+where
+    S: SimdScalar,
+{
+    #[inline]
+    fn from(matrix: Matrix3x3<S>) -> Self {
+        Self::from_matrix(&matrix)
+    }
+}
+
+impl<S> From<&Matrix3x3<S>> for AlignedMatrix3x3<S>
+where
+    S: SimdScalar,
+{
+    #[inline]
+    fn from(matrix: &Matrix3x3<S>) -> Self {
+        Self::from_matrix(matrix)
+    }
+}
+
+impl<S> From<AlignedMatrix3x3<S>> for Matrix3x3<S>
+where
+    S: SimdScalar,
+{
+    #[inline]
+    fn from(aligned: AlignedMatrix3x3<S>) -> Self {
+        aligned.to_matrix()
+    }
+}
+
+impl<S> From<&AlignedMatrix3x3<S>> for Matrix3x3<S>
+where
+    S: SimdScalar,
+{
+    #[inline]
+    fn from(aligned: &AlignedMatrix3x3<S>) -> Self {
+        aligned.to_matrix()
+    }
+}
+
+/// Generate a padded vector from an arbitrary vector, keeping the padding
+/// component zero.
+#[cfg(feature = "arbitrary")]
+impl<'a, S> arbitrary::Arbitrary<'a> for AlignedVector3<S>
+where
+    S: SimdScalar + arbitrary::Arbitrary<'a>,
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let vector: Vector3<S> = u.arbitrary()?;
+
+        Ok(Self::from_vector(&vector))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Vector3<S> as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+/// Generate a padded matrix from an arbitrary matrix, keeping the padding
+/// components zero.
+#[cfg(feature = "arbitrary")]
+impl<'a, S> arbitrary::Arbitrary<'a> for AlignedMatrix3x3<S>
+where
+    S: SimdScalar + arbitrary::Arbitrary<'a>,
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let matrix: Matrix3x3<S> = u.arbitrary()?;
+
+        Ok(Self::from_matrix(&matrix))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Matrix3x3<S> as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+macro_rules! impl_aligned_pod {
+    ($($ScalarType:ty),* $(,)*) => {$(
+        // SAFETY: Three components and one padding component of a four-byte scalar
+        // fill the sixteen-byte alignment exactly, so there are no padding bytes.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Zeroable for AlignedVector3<$ScalarType> {}
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Pod for AlignedVector3<$ScalarType> {}
+
+        // SAFETY: Three sixteen-byte columns fill the sixteen-byte alignment exactly,
+        // so there are no padding bytes.
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Zeroable for AlignedMatrix3x3<$ScalarType> {}
+
+        #[cfg(feature = "bytemuck")]
+        unsafe impl bytemuck::Pod for AlignedMatrix3x3<$ScalarType> {}
+    )*}
+}
+
+impl_aligned_pod!(f32, i32, u32);
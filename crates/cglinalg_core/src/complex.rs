@@ -1,4 +1,8 @@
 use crate::normed::Normed;
+use crate::parse::{
+    parse_components,
+    ParseError,
+};
 use crate::unit::Unit;
 use cglinalg_numeric::{
     SimdCast,
@@ -13,11 +17,14 @@ use cglinalg_trigonometry::{
 
 use core::fmt;
 use core::ops;
+use core::str::FromStr;
 
 
 /// A complex number in Cartesian form.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Complex<S> {
     /// The real part of a complex number.
     pub re: S,
@@ -2158,6 +2165,83 @@ where
     }
 }
 
+/// Parse a complex number from a string.
+///
+/// This accepts the output of the [`Display`](fmt::Display) implementation, such
+/// as `1 + i2`, and the same form with the imaginary unit written after the
+/// imaginary part, such as `1 + 2i`. The parts can also be separated by a minus
+/// sign, such as `1 - i2` or `1 - 2i`, which negates the imaginary part. It also
+/// accepts a list of the real part followed by the imaginary part separated by a
+/// comma or whitespace that is optionally enclosed in brackets or parentheses, such
+/// as `[1, 2]` or `(1 2)`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     Complex,
+/// #     ParseError,
+/// # };
+/// #
+/// let expected = Complex::new(1_f64, -2_f64);
+///
+/// assert_eq!("1 + i-2".parse(), Ok(expected));
+/// assert_eq!("1 + -2i".parse(), Ok(expected));
+/// assert_eq!("1 - 2i".parse(), Ok(expected));
+/// assert_eq!("(1 -2)".parse(), Ok(expected));
+/// assert_eq!("1 + 2".parse::<Complex<f64>>(), Err(ParseError::InvalidSyntax));
+/// ```
+impl<S> FromStr for Complex<S>
+where
+    S: SimdScalar + FromStr,
+{
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+        // The sign separating the parts is the first one that is neither the sign of
+        // the real part nor the sign of an exponent.
+        let separator = string
+            .char_indices()
+            .skip(1)
+            .find(|&(i, c)| (c == '+' || c == '-') && !string[..i].ends_with(['e', 'E']));
+        if let Some((i, sign)) = separator {
+            let real_part = string[..i].trim();
+            let imaginary_part = string[(i + 1)..].trim();
+            let imaginary_part = imaginary_part
+                .strip_prefix('i')
+                .or_else(|| imaginary_part.strip_suffix('i'));
+            match (sign, imaginary_part) {
+                (_, Some(imaginary_part)) => {
+                    let re = real_part.parse().map_err(|_| ParseError::InvalidComponent { index: 0 })?;
+                    let im: S = imaginary_part
+                        .trim()
+                        .parse()
+                        .map_err(|_| ParseError::InvalidComponent { index: 1 })?;
+                    let im = if sign == '-' {
+                        // Scalars without negative values have no negative imaginary parts.
+                        let minus_one: S = "-1".parse().map_err(|_| ParseError::InvalidComponent { index: 1 })?;
+                        minus_one * im
+                    } else {
+                        im
+                    };
+
+                    return Ok(Self::new(re, im));
+                }
+                // A minus sign without an imaginary unit is the sign of the second
+                // component of a list, such as `(1 -2)`.
+                ('-', None) => {}
+                _ => return Err(ParseError::InvalidSyntax),
+            }
+        }
+
+        let mut parts = [S::zero(); 2];
+        parse_components(string, 0, &mut parts)?;
+
+        Ok(Self::new(parts[0], parts[1]))
+    }
+}
+
 impl<S> ops::Neg for Complex<S>
 where
     S: SimdScalarSigned,
@@ -3032,6 +3116,50 @@ macro_rules! impl_scalar_complex_div_ops {
 impl_scalar_complex_div_ops!(i8, i16, i32, i64, i128, isize, f32, f64);
 
 
+// SAFETY: A complex number is a `#[repr(C)]` pair of scalars of the same type. It has no
+// padding, and every bit pattern is valid when every bit pattern of `S` is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Complex<S> where S: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Complex<S> where S: bytemuck::Pod {}
+
+/// Sample a complex number whose real and imaginary parts are sampled independently
+/// from the standard distribution of the scalar type.
+#[cfg(feature = "rand")]
+impl<S> rand::distributions::Distribution<Complex<S>> for rand::distributions::Standard
+where
+    rand::distributions::Standard: rand::distributions::Distribution<S>,
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Complex<S> {
+        Complex::new(self.sample(rng), self.sample(rng))
+    }
+}
+
+/// Generate a unit complex number from a unit vector in two dimensions.
+///
+/// When the generated components are too small to normalize reliably, the
+/// complex number `1` is generated instead.
+#[cfg(feature = "arbitrary")]
+impl<'a, S> arbitrary::Arbitrary<'a> for Unit<Complex<S>>
+where
+    S: SimdScalarFloat,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let unit_vector: Unit<crate::vector::Vector2<S>> = u.arbitrary()?;
+        let vector = unit_vector.into_inner();
+        let complex = Complex::new(vector[0], vector[1]);
+
+        Ok(Unit::from_value_unchecked(complex))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Unit<crate::vector::Vector2<S>> as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
 impl<S> approx_cmp::AbsDiffEq for Complex<S>
 where
     S: SimdScalarFloat,
@@ -0,0 +1,220 @@
+use crate::unit::Unit;
+use crate::vector::{
+    Vector2,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use rand::distributions::{
+    Distribution,
+    Standard,
+};
+use rand::Rng;
+
+
+/// The uniform distribution of unit vectors on the unit sphere in three
+/// dimensions.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     Normed,
+/// #     Unit,
+/// #     UnitSphere,
+/// #     Vector3,
+/// # };
+/// # use rand::{
+/// #     Rng,
+/// #     SeedableRng,
+/// # };
+/// # use rand_isaac::IsaacRng;
+/// #
+/// let mut rng = IsaacRng::seed_from_u64(0);
+/// let direction: Unit<Vector3<f64>> = rng.sample(UnitSphere::new());
+///
+/// assert!((direction.norm() - 1_f64).abs() <= 1e-12);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct UnitSphere {}
+
+impl UnitSphere {
+    /// Construct the uniform distribution on the unit sphere.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<S> Distribution<Unit<Vector3<S>>> for UnitSphere
+where
+    S: SimdScalarFloat,
+    Standard: Distribution<S>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Unit<Vector3<S>> {
+        let two = S::one() + S::one();
+        let u1: S = rng.sample(Standard);
+        let u2: S = rng.sample(Standard);
+        let z = S::one() - two * u1;
+        let radius = (S::one() - z * z).max(S::zero()).sqrt();
+        let (sin_phi, cos_phi) = (S::two_pi() * u2).sin_cos();
+        let vector = Vector3::new(radius * cos_phi, radius * sin_phi, z);
+
+        Unit::from_value(vector)
+    }
+}
+
+
+/// The uniform distribution of vectors inside the closed unit ball in three
+/// dimensions.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     Normed,
+/// #     UnitBall,
+/// #     Vector3,
+/// # };
+/// # use rand::{
+/// #     Rng,
+/// #     SeedableRng,
+/// # };
+/// # use rand_isaac::IsaacRng;
+/// #
+/// let mut rng = IsaacRng::seed_from_u64(0);
+/// let vector: Vector3<f64> = rng.sample(UnitBall::new());
+///
+/// assert!(vector.norm() <= 1_f64);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct UnitBall {}
+
+impl UnitBall {
+    /// Construct the uniform distribution in the unit ball.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<S> Distribution<Vector3<S>> for UnitBall
+where
+    S: SimdScalarFloat,
+    Standard: Distribution<S>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector3<S> {
+        let direction: Unit<Vector3<S>> = rng.sample(UnitSphere::new());
+        let u: S = rng.sample(Standard);
+        let radius = u.cbrt();
+
+        direction.into_inner() * radius
+    }
+}
+
+
+/// The uniform distribution of vectors inside the closed unit disk in two
+/// dimensions.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     Normed,
+/// #     UnitDisk,
+/// #     Vector2,
+/// # };
+/// # use rand::{
+/// #     Rng,
+/// #     SeedableRng,
+/// # };
+/// # use rand_isaac::IsaacRng;
+/// #
+/// let mut rng = IsaacRng::seed_from_u64(0);
+/// let vector: Vector2<f64> = rng.sample(UnitDisk::new());
+///
+/// assert!(vector.norm() <= 1_f64);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct UnitDisk {}
+
+impl UnitDisk {
+    /// Construct the uniform distribution in the unit disk.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<S> Distribution<Vector2<S>> for UnitDisk
+where
+    S: SimdScalarFloat,
+    Standard: Distribution<S>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vector2<S> {
+        let u1: S = rng.sample(Standard);
+        let u2: S = rng.sample(Standard);
+        let radius = u1.sqrt();
+        let (sin_theta, cos_theta) = (S::two_pi() * u2).sin_cos();
+
+        Vector2::new(radius * cos_theta, radius * sin_theta)
+    }
+}
+
+
+/// The cosine-weighted distribution of unit vectors on the hemisphere about the
+/// positive **z-axis**.
+///
+/// The probability density of a direction `omega` is `cos(theta) / pi`, where
+/// `theta` is the angle between `omega` and the **z-axis**. This is the importance
+/// sampling distribution for diffuse (Lambertian) reflection in Monte Carlo
+/// rendering. To sample about an arbitrary surface normal, rotate the samples
+/// into a frame whose **z-axis** is the normal.
+///
+/// The samples are generated with Malley's method, which projects a uniform
+/// sample of the unit disk up onto the hemisphere.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     CosineHemisphere,
+/// #     Normed,
+/// #     Unit,
+/// #     Vector3,
+/// # };
+/// # use rand::{
+/// #     Rng,
+/// #     SeedableRng,
+/// # };
+/// # use rand_isaac::IsaacRng;
+/// #
+/// let mut rng = IsaacRng::seed_from_u64(0);
+/// let direction: Unit<Vector3<f64>> = rng.sample(CosineHemisphere::new());
+///
+/// assert!(direction.z >= 0_f64);
+/// assert!((direction.norm() - 1_f64).abs() <= 1e-12);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct CosineHemisphere {}
+
+impl CosineHemisphere {
+    /// Construct the cosine-weighted distribution on the upper hemisphere.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<S> Distribution<Unit<Vector3<S>>> for CosineHemisphere
+where
+    S: SimdScalarFloat,
+    Standard: Distribution<S>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Unit<Vector3<S>> {
+        let disk: Vector2<S> = rng.sample(UnitDisk::new());
+        let z = (S::one() - disk.x * disk.x - disk.y * disk.y).max(S::zero()).sqrt();
+        let vector = Vector3::new(disk.x, disk.y, z);
+
+        Unit::from_value(vector)
+    }
+}
@@ -152,6 +152,8 @@ use core::fmt;
 /// ```
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Euler<A> {
     /// The rotation angle about the **x-axis** in the **yz-plane**. This is also
     /// known as the **roll** angle.
@@ -586,6 +588,29 @@ where
 }
 
 
+/// Euler angles in **ZYX** rotation order correspond to extrinsic rotations about
+/// the **z-axis**, then the **y-axis**, then the **x-axis** in `mint`.
+#[cfg(feature = "mint")]
+impl<S> From<Euler<Radians<S>>> for mint::EulerAngles<S, mint::ExtraZYX> {
+    #[inline]
+    fn from(euler: Euler<Radians<S>>) -> Self {
+        Self::from([euler.z.0, euler.y.0, euler.x.0])
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::EulerAngles<S, mint::ExtraZYX>> for Euler<Radians<S>> {
+    #[inline]
+    fn from(euler: mint::EulerAngles<S, mint::ExtraZYX>) -> Self {
+        Self::new(Radians(euler.c), Radians(euler.b), Radians(euler.a))
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> mint::IntoMint for Euler<Radians<S>> {
+    type MintType = mint::EulerAngles<S, mint::ExtraZYX>;
+}
+
 impl<A> approx_cmp::AbsDiffEq for Euler<A>
 where
     A: Angle,
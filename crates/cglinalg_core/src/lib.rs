@@ -1,10 +1,16 @@
+mod aligned;
 mod complex;
+#[cfg(feature = "rand")]
+mod distributions;
 mod euler;
 mod matrix;
 mod matrix_euler;
 mod normed;
+mod parse;
 mod point;
 mod quaternion;
+#[cfg(feature = "proptest")]
+pub mod strategies;
 mod unit;
 mod vector;
 
@@ -12,12 +18,16 @@ mod constraint;
 mod coordinates;
 
 
+pub use aligned::*;
 pub use complex::*;
 pub use constraint::*;
+#[cfg(feature = "rand")]
+pub use distributions::*;
 pub use euler::*;
 pub use matrix::*;
 // pub use matrix_euler::*;
 pub use normed::*;
+pub use parse::*;
 pub use point::*;
 pub use quaternion::*;
 pub use unit::*;
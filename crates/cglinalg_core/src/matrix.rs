@@ -13,6 +13,11 @@ use crate::normed::{
     Norm,
     Normed,
 };
+use crate::parse::{
+    parse_columns,
+    strip_type_name,
+    ParseError,
+};
 use crate::point::{
     Point2,
     Point3,
@@ -46,6 +51,7 @@ use cglinalg_trigonometry::{
 
 use core::fmt;
 use core::ops;
+use core::str::FromStr;
 
 
 /// A stack-allocated **(1 row, 1 column)** matrix in column-major order.
@@ -133,6 +139,7 @@ where
 /// A stack-allocated **(R row, C column)** matrix in column-major order.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Matrix<S, const R: usize, const C: usize> {
     data: [[S; R]; C],
 }
@@ -293,6 +300,42 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<S, const R: usize, const C: usize> serde::Serialize for Matrix<S, R, C>
+where
+    S: serde::Serialize,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+
+        let columns: &[Vector<S, R>; C] = self.as_ref();
+        let mut tuple = serializer.serialize_tuple(C)?;
+        for column in columns.iter() {
+            tuple.serialize_element(column)?;
+        }
+
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S, const R: usize, const C: usize> serde::Deserialize<'de> for Matrix<S, R, C>
+where
+    S: serde::Deserialize<'de> + Copy,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        let columns: Vector<Vector<S, R>, C> = serde::Deserialize::deserialize(deserializer)?;
+
+        Ok(Self::from_columns(columns.as_ref()))
+    }
+}
+
 impl<'a, S, const R: usize, const C: usize> From<&'a [Vector<S, R>; C]> for &'a Matrix<S, R, C>
 where
     S: Copy,
@@ -1556,6 +1599,44 @@ where
     }
 }
 
+/// Parse a matrix from a string.
+///
+/// This accepts the output of the [`Display`](fmt::Display) implementation, such
+/// as `Matrix2x2 [[1, 2], [3, 4]]`, as well as a list of columns, or a single list
+/// of all the components in column-major order. The lists separate their elements
+/// with commas or whitespace, and are optionally enclosed in brackets or
+/// parentheses, such as `[[1, 2], [3, 4]]`, `((1 2) (3 4))`, or `[1, 2, 3, 4]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     Matrix2x2,
+/// #     ParseError,
+/// # };
+/// #
+/// let expected = Matrix2x2::new(1_f64, 2_f64, 3_f64, 4_f64);
+///
+/// assert_eq!("Matrix2x2 [[1, 2], [3, 4]]".parse(), Ok(expected));
+/// assert_eq!("((1 2) (3 4))".parse(), Ok(expected));
+/// assert_eq!("[1, 2, 3, 4]".parse(), Ok(expected));
+/// assert_eq!("[[1, 2]]".parse::<Matrix2x2<f64>>(), Err(ParseError::ColumnCount { expected: 2, found: 1 }));
+/// ```
+impl<S, const R: usize, const C: usize> FromStr for Matrix<S, R, C>
+where
+    S: SimdScalar + FromStr,
+{
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let list = strip_type_name(string, "Matrix", &[R, C])?;
+        let mut data = [[S::zero(); R]; C];
+        parse_columns(list, &mut data)?;
+
+        Ok(Self { data })
+    }
+}
+
 
 impl<S, const R: usize, const C: usize> Matrix<S, R, C>
 where
@@ -8925,6 +9006,71 @@ where
 }
 
 
+// SAFETY: A matrix is a `#[repr(C)]` wrapper around an array of `C` columns of `R` components. It has no
+// padding, and every bit pattern is valid when every bit pattern of `S` is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl<S, const R: usize, const C: usize> bytemuck::Zeroable for Matrix<S, R, C> where S: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S, const R: usize, const C: usize> bytemuck::Pod for Matrix<S, R, C> where S: bytemuck::Pod {}
+
+#[cfg(feature = "mint")]
+macro_rules! impl_mint_matrix_conversions {
+    ($MatrixRxC:ident, $MintColumnMatrixRxC:ident) => {
+        impl<S> From<$MatrixRxC<S>> for mint::$MintColumnMatrixRxC<S> {
+            #[inline]
+            fn from(matrix: $MatrixRxC<S>) -> Self {
+                Self::from(matrix.data)
+            }
+        }
+
+        impl<S> From<mint::$MintColumnMatrixRxC<S>> for $MatrixRxC<S> {
+            #[inline]
+            fn from(matrix: mint::$MintColumnMatrixRxC<S>) -> Self {
+                Self { data: matrix.into() }
+            }
+        }
+
+        impl<S> mint::IntoMint for $MatrixRxC<S> {
+            type MintType = mint::$MintColumnMatrixRxC<S>;
+        }
+    };
+}
+
+#[cfg(feature = "mint")]
+impl_mint_matrix_conversions!(Matrix2x2, ColumnMatrix2);
+#[cfg(feature = "mint")]
+impl_mint_matrix_conversions!(Matrix3x3, ColumnMatrix3);
+#[cfg(feature = "mint")]
+impl_mint_matrix_conversions!(Matrix4x4, ColumnMatrix4);
+#[cfg(feature = "mint")]
+impl_mint_matrix_conversions!(Matrix2x3, ColumnMatrix2x3);
+#[cfg(feature = "mint")]
+impl_mint_matrix_conversions!(Matrix3x2, ColumnMatrix3x2);
+#[cfg(feature = "mint")]
+impl_mint_matrix_conversions!(Matrix2x4, ColumnMatrix2x4);
+#[cfg(feature = "mint")]
+impl_mint_matrix_conversions!(Matrix4x2, ColumnMatrix4x2);
+#[cfg(feature = "mint")]
+impl_mint_matrix_conversions!(Matrix3x4, ColumnMatrix3x4);
+#[cfg(feature = "mint")]
+impl_mint_matrix_conversions!(Matrix4x3, ColumnMatrix4x3);
+
+/// Sample a matrix whose entries are sampled independently from the standard
+/// distribution of the scalar type.
+#[cfg(feature = "rand")]
+impl<S, const R: usize, const C: usize> rand::distributions::Distribution<Matrix<S, R, C>> for rand::distributions::Standard
+where
+    rand::distributions::Standard: rand::distributions::Distribution<S>,
+{
+    #[inline]
+    fn sample<Rng: rand::Rng + ?Sized>(&self, rng: &mut Rng) -> Matrix<S, R, C> {
+        Matrix {
+            data: core::array::from_fn(|_| core::array::from_fn(|_| self.sample(rng))),
+        }
+    }
+}
+
 impl<S, const R: usize, const C: usize> approx_cmp::AbsDiffEq for Matrix<S, R, C>
 where
     S: SimdScalarFloat,
@@ -0,0 +1,249 @@
+use core::fmt;
+use core::str::FromStr;
+
+
+/// An error returned when parsing a vector, point, matrix, quaternion, or complex
+/// number from a string fails.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     ParseError,
+/// #     Vector3,
+/// # };
+/// #
+/// let result = "[1, 2]".parse::<Vector3<f64>>();
+///
+/// assert_eq!(result, Err(ParseError::ComponentCount { expected: 3, found: 2 }));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// The string is empty or contains only whitespace.
+    Empty,
+    /// The string starts with the type name of a different type or shape, such as
+    /// `Vector4` when parsing a three-dimensional vector.
+    InvalidPrefix,
+    /// An opening bracket or parenthesis has no matching closing one, or the
+    /// closing one is of the wrong kind.
+    UnbalancedDelimiters,
+    /// The string is not in any of the accepted forms.
+    InvalidSyntax,
+    /// A matrix has the wrong number of columns.
+    ColumnCount {
+        /// The number of columns of the matrix type.
+        expected: usize,
+        /// The number of columns in the string.
+        found: usize,
+    },
+    /// A list of scalars has the wrong number of components.
+    ComponentCount {
+        /// The number of components the list should have.
+        expected: usize,
+        /// The number of components in the string.
+        found: usize,
+    },
+    /// A component is not a valid scalar.
+    InvalidComponent {
+        /// The position of the component, counting every component of the value
+        /// in the order they appear in the string.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(formatter, "cannot parse a value from an empty string"),
+            ParseError::InvalidPrefix => write!(formatter, "type name does not match the type being parsed"),
+            ParseError::UnbalancedDelimiters => write!(formatter, "unbalanced brackets or parentheses"),
+            ParseError::InvalidSyntax => write!(formatter, "invalid syntax"),
+            ParseError::ColumnCount { expected, found } => {
+                write!(formatter, "expected {} columns, found {}", expected, found)
+            }
+            ParseError::ComponentCount { expected, found } => {
+                write!(formatter, "expected {} components, found {}", expected, found)
+            }
+            ParseError::InvalidComponent { index } => {
+                write!(formatter, "component {} is not a valid scalar", index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Remove a leading type name such as `Vector3` or `Matrix2x3` from a string.
+///
+/// The type name is `base` followed by the dimensions in `shape` separated by
+/// `x`. A string that does not start with `base` is returned unchanged.
+pub(crate) fn strip_type_name<'a>(string: &'a str, base: &str, shape: &[usize]) -> Result<&'a str, ParseError> {
+    let Some(mut rest) = string.trim_start().strip_prefix(base) else {
+        return Ok(string);
+    };
+    for (i, &dimension) in shape.iter().enumerate() {
+        if i > 0 {
+            rest = rest.strip_prefix('x').ok_or(ParseError::InvalidPrefix)?;
+        }
+
+        let end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        match rest[..end].parse::<usize>() {
+            Ok(found) if found == dimension => rest = &rest[end..],
+            _ => return Err(ParseError::InvalidPrefix),
+        }
+    }
+
+    if rest.starts_with(char::is_alphanumeric) {
+        return Err(ParseError::InvalidPrefix);
+    }
+
+    Ok(rest)
+}
+
+/// Find the end of the bracketed or parenthesized group at the start of a string.
+///
+/// The returned index is one past the closing delimiter matching the opening
+/// delimiter at the start of the string.
+fn group_end(string: &str) -> Result<usize, ParseError> {
+    let closing = match string.chars().next() {
+        Some('[') => ']',
+        Some('(') => ')',
+        _ => return Err(ParseError::InvalidSyntax),
+    };
+    let mut depth = 0_usize;
+    for (i, c) in string.char_indices() {
+        match c {
+            '[' | '(' => depth += 1,
+            ']' | ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    if c != closing {
+                        return Err(ParseError::UnbalancedDelimiters);
+                    }
+
+                    return Ok(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Err(ParseError::UnbalancedDelimiters)
+}
+
+/// Remove a matching pair of brackets or parentheses enclosing a whole string.
+///
+/// A string that is not enclosed in a single group is returned unchanged, apart
+/// from surrounding whitespace.
+fn strip_delimiters(string: &str) -> Result<&str, ParseError> {
+    let string = string.trim();
+    if !string.starts_with(['[', '(']) {
+        return Ok(string);
+    }
+
+    let end = group_end(string)?;
+    if end == string.len() {
+        Ok(string[1..(end - 1)].trim())
+    } else {
+        Ok(string)
+    }
+}
+
+/// Parse a list of scalars into `components`.
+///
+/// The scalars are separated either by commas or by whitespace, and the list
+/// is optionally enclosed in brackets or parentheses. The position of the first
+/// component among all components of the value being parsed is `first_index`,
+/// which is used to report which component is invalid.
+pub(crate) fn parse_components<S>(string: &str, first_index: usize, components: &mut [S]) -> Result<(), ParseError>
+where
+    S: FromStr,
+{
+    if string.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let list = strip_delimiters(string)?;
+    if list.contains(['[', '(', ']', ')']) {
+        let openings = list.matches(['[', '(']).count();
+        let closings = list.matches([']', ')']).count();
+        if openings != closings {
+            return Err(ParseError::UnbalancedDelimiters);
+        }
+
+        return Err(ParseError::InvalidSyntax);
+    }
+
+    let mut comma_separated;
+    let mut whitespace_separated;
+    let tokens: &mut dyn Iterator<Item = &str> = if list.contains(',') {
+        comma_separated = list.split(',').map(str::trim);
+        &mut comma_separated
+    } else {
+        whitespace_separated = list.split_whitespace();
+        &mut whitespace_separated
+    };
+
+    let mut found = 0;
+    for (i, token) in tokens.enumerate() {
+        if i < components.len() {
+            components[i] = token.parse().map_err(|_| ParseError::InvalidComponent { index: first_index + i })?;
+        }
+
+        found = i + 1;
+    }
+
+    if found != components.len() {
+        return Err(ParseError::ComponentCount {
+            expected: components.len(),
+            found,
+        });
+    }
+
+    Ok(())
+}
+
+/// Parse the columns of a matrix into `columns`.
+///
+/// The columns are either a list of bracketed or parenthesized lists of scalars,
+/// or a single list of all the scalars in column-major order. In both cases the
+/// whole matrix is optionally enclosed in brackets or parentheses.
+pub(crate) fn parse_columns<S, const R: usize>(string: &str, columns: &mut [[S; R]]) -> Result<(), ParseError>
+where
+    S: FromStr,
+{
+    if string.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let list = strip_delimiters(string)?;
+    if !list.starts_with(['[', '(']) {
+        return parse_components(list, 0, columns.as_flattened_mut());
+    }
+
+    let mut rest = list;
+    let mut found = 0;
+    while !rest.is_empty() {
+        let end = group_end(rest)?;
+        if found < columns.len() {
+            parse_components(&rest[..end], found * R, &mut columns[found])?;
+        }
+
+        found += 1;
+        rest = rest[end..].trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+        if !rest.is_empty() && !rest.starts_with(['[', '(']) {
+            return Err(ParseError::InvalidSyntax);
+        }
+    }
+
+    if found != columns.len() {
+        return Err(ParseError::ColumnCount {
+            expected: columns.len(),
+            found,
+        });
+    }
+
+    Ok(())
+}
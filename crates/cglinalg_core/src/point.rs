@@ -7,6 +7,11 @@ use crate::constraint::{
     ShapeConstraint,
 };
 use crate::normed::Normed;
+use crate::parse::{
+    parse_components,
+    strip_type_name,
+    ParseError,
+};
 use crate::unit::Unit;
 use crate::vector::{
     Vector,
@@ -27,6 +32,7 @@ use cglinalg_numeric::{
 
 use core::fmt;
 use core::ops;
+use core::str::FromStr;
 
 
 /// A point is a location in a one-dimensional Euclidean space.
@@ -42,6 +48,8 @@ pub type Point3<S> = Point<S, 3>;
 /// A point is a location in a one-dimensional Euclidean space.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Point<S, const N: usize> {
     coords: Vector<S, N>,
 }
@@ -555,6 +563,42 @@ where
     }
 }
 
+/// Parse a point from a string.
+///
+/// This accepts the output of the [`Display`](fmt::Display) implementation, such
+/// as `Point3 [1, 2, 3]`, as well as a list of coordinates separated by commas or
+/// whitespace that is optionally enclosed in brackets or parentheses, such as
+/// `[1, 2, 3]`, `(1 2 3)`, or `1, 2, 3`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     ParseError,
+/// #     Point3,
+/// # };
+/// #
+/// let expected = Point3::new(1_f64, 2_f64, 3_f64);
+///
+/// assert_eq!("Point3 [1, 2, 3]".parse(), Ok(expected));
+/// assert_eq!("(1 2 3)".parse(), Ok(expected));
+/// assert_eq!("Point2 [1, 2]".parse::<Point3<f64>>(), Err(ParseError::InvalidPrefix));
+/// ```
+impl<S, const N: usize> FromStr for Point<S, N>
+where
+    S: SimdScalar + FromStr,
+{
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let list = strip_type_name(string, "Point", &[N])?;
+        let mut coords = [S::zero(); N];
+        parse_components(list, 0, &mut coords)?;
+
+        Ok(Self::from(coords))
+    }
+}
+
 impl<S, const N: usize> Default for Point<S, N>
 where
     S: SimdScalar,
@@ -1005,6 +1049,59 @@ where
 }
 
 
+// SAFETY: A point is a `#[repr(C)]` wrapper around a vector. It has no
+// padding, and every bit pattern is valid when every bit pattern of `S` is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl<S, const N: usize> bytemuck::Zeroable for Point<S, N> where S: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S, const N: usize> bytemuck::Pod for Point<S, N> where S: bytemuck::Pod {}
+
+#[cfg(feature = "mint")]
+macro_rules! impl_mint_point_conversions {
+    ($PointN:ident, $MintPointN:ident, $MintVectorN:ident) => {
+        impl<S> From<$PointN<S>> for mint::$MintPointN<S> {
+            #[inline]
+            fn from(point: $PointN<S>) -> Self {
+                Self::from(mint::$MintVectorN::from(point.coords))
+            }
+        }
+
+        impl<S> From<mint::$MintPointN<S>> for $PointN<S> {
+            #[inline]
+            fn from(point: mint::$MintPointN<S>) -> Self {
+                Self {
+                    coords: mint::$MintVectorN::from(point).into(),
+                }
+            }
+        }
+
+        impl<S> mint::IntoMint for $PointN<S> {
+            type MintType = mint::$MintPointN<S>;
+        }
+    };
+}
+
+#[cfg(feature = "mint")]
+impl_mint_point_conversions!(Point2, Point2, Vector2);
+#[cfg(feature = "mint")]
+impl_mint_point_conversions!(Point3, Point3, Vector3);
+
+/// Sample a point whose coordinates are sampled independently from the standard
+/// distribution of the scalar type.
+#[cfg(feature = "rand")]
+impl<S, const N: usize> rand::distributions::Distribution<Point<S, N>> for rand::distributions::Standard
+where
+    rand::distributions::Standard: rand::distributions::Distribution<S>,
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Point<S, N> {
+        Point {
+            coords: rand::distributions::Distribution::<Vector<S, N>>::sample(self, rng),
+        }
+    }
+}
+
 impl<S, const N: usize> approx_cmp::AbsDiffEq for Point<S, N>
 where
     S: SimdScalarFloat,
@@ -3,6 +3,10 @@ use crate::matrix::{
     Matrix4x4,
 };
 use crate::normed::Normed;
+use crate::parse::{
+    parse_components,
+    ParseError,
+};
 use crate::point::Point3;
 use crate::unit::Unit;
 use crate::vector::{
@@ -22,6 +26,7 @@ use cglinalg_trigonometry::{
 
 use core::fmt;
 use core::ops;
+use core::str::FromStr;
 
 
 /// A stack-allocated quaternion.
@@ -45,6 +50,8 @@ use core::ops;
 /// part and `(x, y, z)` are the vector components.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Quaternion<S> {
     coords: Vector4<S>,
 }
@@ -3960,6 +3967,53 @@ where
     }
 }
 
+/// Parse a quaternion from a string.
+///
+/// This accepts the output of the [`Display`](fmt::Display) implementation, such
+/// as `1 + [2, 3, 4]`, where the scalar part comes before the vector part, as well
+/// as a list of the scalar part followed by the vector components separated by
+/// commas or whitespace that is optionally enclosed in brackets or parentheses,
+/// such as `[1, 2, 3, 4]` or `(1 2 3 4)`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     ParseError,
+/// #     Quaternion,
+/// # };
+/// #
+/// let expected = Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64);
+///
+/// assert_eq!("1 + [2, 3, 4]".parse(), Ok(expected));
+/// assert_eq!("[1, 2, 3, 4]".parse(), Ok(expected));
+/// assert_eq!("(1 2 3 4)".parse(), Ok(expected));
+/// assert_eq!("x + [2, 3, 4]".parse::<Quaternion<f64>>(), Err(ParseError::InvalidComponent { index: 0 }));
+/// ```
+impl<S> FromStr for Quaternion<S>
+where
+    S: SimdScalar + FromStr,
+{
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+        let mut coords = [S::zero(); 4];
+        if let Some(vector_start) = string.rfind(['[', '(']) {
+            if let Some(scalar) = string[..vector_start].trim_end().strip_suffix('+') {
+                coords[0] = scalar.trim().parse().map_err(|_| ParseError::InvalidComponent { index: 0 })?;
+                parse_components(&string[vector_start..], 1, &mut coords[1..])?;
+
+                return Ok(Self::new(coords[0], coords[1], coords[2], coords[3]));
+            }
+        }
+
+        parse_components(string, 0, &mut coords)?;
+
+        Ok(Self::new(coords[0], coords[1], coords[2], coords[3]))
+    }
+}
+
 impl<S> ops::Neg for Quaternion<S>
 where
     S: SimdScalarSigned,
@@ -4402,6 +4456,112 @@ where
 }
 
 
+// SAFETY: A quaternion is a `#[repr(C)]` wrapper around a four-dimensional vector. It has no
+// padding, and every bit pattern is valid when every bit pattern of `S` is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Zeroable for Quaternion<S> where S: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S> bytemuck::Pod for Quaternion<S> where S: bytemuck::Pod {}
+
+#[cfg(feature = "mint")]
+impl<S> From<Quaternion<S>> for mint::Quaternion<S> {
+    #[inline]
+    fn from(quaternion: Quaternion<S>) -> Self {
+        let [s, x, y, z]: [S; 4] = mint::Vector4::from(quaternion.coords).into();
+
+        Self::from([x, y, z, s])
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> From<mint::Quaternion<S>> for Quaternion<S> {
+    #[inline]
+    fn from(quaternion: mint::Quaternion<S>) -> Self {
+        let [x, y, z, s]: [S; 4] = quaternion.into();
+
+        Self {
+            coords: mint::Vector4::from([s, x, y, z]).into(),
+        }
+    }
+}
+
+#[cfg(feature = "mint")]
+impl<S> mint::IntoMint for Quaternion<S> {
+    type MintType = mint::Quaternion<S>;
+}
+
+/// Sample a quaternion whose components are sampled independently from the standard
+/// distribution of the scalar type.
+#[cfg(feature = "rand")]
+impl<S> rand::distributions::Distribution<Quaternion<S>> for rand::distributions::Standard
+where
+    rand::distributions::Standard: rand::distributions::Distribution<S>,
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Quaternion<S> {
+        Quaternion {
+            coords: rand::distributions::Distribution::<Vector4<S>>::sample(self, rng),
+        }
+    }
+}
+
+/// Sample a unit quaternion uniformly distributed over the group of rotations in
+/// three dimensions.
+///
+/// This uses Shoemake's subgroup algorithm, which maps three uniform samples in
+/// the unit interval onto a uniformly distributed point on the unit three-sphere.
+/// Since a unit quaternion and its negation represent the same rotation, the
+/// resulting rotations are uniformly distributed with respect to the Haar measure
+/// on the rotation group.
+#[cfg(feature = "rand")]
+impl<S> rand::distributions::Distribution<Unit<Quaternion<S>>> for rand::distributions::Standard
+where
+    S: SimdScalarFloat,
+    rand::distributions::Standard: rand::distributions::Distribution<S>,
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Unit<Quaternion<S>> {
+        let u1: S = self.sample(rng);
+        let u2: S = self.sample(rng);
+        let u3: S = self.sample(rng);
+        let radius1 = (S::one() - u1).sqrt();
+        let radius2 = u1.sqrt();
+        let (sin_theta1, cos_theta1) = (S::two_pi() * u2).sin_cos();
+        let (sin_theta2, cos_theta2) = (S::two_pi() * u3).sin_cos();
+        let quaternion = Quaternion::new(
+            radius2 * cos_theta2,
+            radius1 * sin_theta1,
+            radius1 * cos_theta1,
+            radius2 * sin_theta2,
+        );
+
+        Unit::from_value(quaternion)
+    }
+}
+
+/// Generate a unit quaternion from a unit vector in four dimensions.
+///
+/// When the generated components are too small to normalize reliably, the
+/// identity quaternion is generated instead.
+#[cfg(feature = "arbitrary")]
+impl<'a, S> arbitrary::Arbitrary<'a> for Unit<Quaternion<S>>
+where
+    S: SimdScalarFloat,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let unit_vector: Unit<Vector4<S>> = u.arbitrary()?;
+        let vector = unit_vector.into_inner();
+        let quaternion = Quaternion::new(vector[0], vector[1], vector[2], vector[3]);
+
+        Ok(Unit::from_value_unchecked(quaternion))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Unit<Vector4<S>> as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
 impl<S> approx_cmp::AbsDiffEq for Quaternion<S>
 where
     S: SimdScalarFloat,
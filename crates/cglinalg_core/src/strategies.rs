@@ -0,0 +1,511 @@
+use crate::complex::Complex;
+use crate::euler::Euler;
+use crate::matrix::{
+    Matrix,
+    Matrix2x2,
+    Matrix3x3,
+    Matrix4x4,
+};
+use crate::point::Point;
+use crate::quaternion::Quaternion;
+use crate::unit::Unit;
+use crate::vector::Vector;
+use cglinalg_numeric::{
+    SimdScalar,
+    SimdScalarFloat,
+    SimdScalarSigned,
+};
+use cglinalg_trigonometry::Radians;
+
+use proptest::prelude::*;
+
+
+fn rescale<S>(value: S, min_value: S, max_value: S) -> S
+where
+    S: SimdScalar,
+{
+    let width = max_value - min_value;
+    let remainder = value % width;
+    if remainder < S::zero() {
+        min_value + (remainder + width)
+    } else {
+        min_value + remainder
+    }
+}
+
+fn rescale_signed<S>(value: S, min_value: S, max_value: S) -> S
+where
+    S: SimdScalarSigned,
+{
+    let abs_value = rescale(value, min_value, max_value);
+    if value < S::zero() {
+        -abs_value
+    } else {
+        abs_value
+    }
+}
+
+/// Generate scalars in the range `[min_value, max_value]`.
+///
+/// The bounds must satisfy `min_value < max_value`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(value in strategies::scalar_from_range(-5_i32, 5_i32))| {
+///     prop_assert!(value >= -5_i32 && value <= 5_i32);
+/// });
+/// ```
+pub fn scalar_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = S>
+where
+    S: SimdScalar + Arbitrary,
+{
+    any::<S>().prop_map(move |value| rescale(value, min_value, max_value))
+}
+
+/// Generate scalars of either sign whose absolute values lie in the range
+/// `[min_value, max_value]`.
+///
+/// The bounds must satisfy `0 <= min_value < max_value`. A positive lower bound
+/// guarantees that every generated scalar is nonzero.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(value in strategies::scalar_signed_from_abs_range(1_f64, 10_f64))| {
+///     prop_assert!(value.abs() >= 1_f64 && value.abs() <= 10_f64);
+/// });
+/// ```
+pub fn scalar_signed_from_abs_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = S>
+where
+    S: SimdScalarSigned + Arbitrary,
+{
+    any::<S>().prop_map(move |value| rescale_signed(value, min_value, max_value))
+}
+
+/// Generate vectors whose components lie in the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(vector in strategies::vector_from_range::<f64, 3>(-1_f64, 1_f64))| {
+///     prop_assert!(vector.as_slice().iter().all(|&component| component.abs() <= 1_f64));
+/// });
+/// ```
+pub fn vector_from_range<S, const N: usize>(min_value: S, max_value: S) -> impl Strategy<Value = Vector<S, N>>
+where
+    S: SimdScalar + Arbitrary,
+{
+    any::<[S; N]>().prop_map(move |array| Vector::from(array.map(|component| rescale(component, min_value, max_value))))
+}
+
+/// Generate vectors whose components have either sign and absolute values in
+/// the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(vector in strategies::vector_signed_from_abs_range::<i32, 2>(1_i32, 100_i32))| {
+///     prop_assert!(vector.x != 0_i32 && vector.y != 0_i32);
+/// });
+/// ```
+pub fn vector_signed_from_abs_range<S, const N: usize>(min_value: S, max_value: S) -> impl Strategy<Value = Vector<S, N>>
+where
+    S: SimdScalarSigned + Arbitrary,
+{
+    any::<[S; N]>().prop_map(move |array| Vector::from(array.map(|component| rescale_signed(component, min_value, max_value))))
+}
+
+/// Generate points whose coordinates lie in the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(point in strategies::point_from_range::<f64, 3>(0_f64, 10_f64))| {
+///     prop_assert!(point.z >= 0_f64 && point.z <= 10_f64);
+/// });
+/// ```
+pub fn point_from_range<S, const N: usize>(min_value: S, max_value: S) -> impl Strategy<Value = Point<S, N>>
+where
+    S: SimdScalar + Arbitrary,
+{
+    any::<[S; N]>().prop_map(move |array| Point::from(array.map(|component| rescale(component, min_value, max_value))))
+}
+
+/// Generate points whose coordinates have either sign and absolute values in
+/// the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(point in strategies::point_signed_from_abs_range::<f64, 2>(1_f64, 2_f64))| {
+///     prop_assert!(point.x.abs() >= 1_f64 && point.x.abs() <= 2_f64);
+/// });
+/// ```
+pub fn point_signed_from_abs_range<S, const N: usize>(min_value: S, max_value: S) -> impl Strategy<Value = Point<S, N>>
+where
+    S: SimdScalarSigned + Arbitrary,
+{
+    any::<[S; N]>().prop_map(move |array| Point::from(array.map(|component| rescale_signed(component, min_value, max_value))))
+}
+
+/// Generate matrices whose elements lie in the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(matrix in strategies::matrix_from_range::<f64, 2, 3>(-1_f64, 1_f64))| {
+///     prop_assert!(matrix[2][1].abs() <= 1_f64);
+/// });
+/// ```
+pub fn matrix_from_range<S, const R: usize, const C: usize>(min_value: S, max_value: S) -> impl Strategy<Value = Matrix<S, R, C>>
+where
+    S: SimdScalar + Arbitrary,
+{
+    any::<[[S; R]; C]>()
+        .prop_map(move |array| Matrix::from(array.map(|column| column.map(|element| rescale(element, min_value, max_value)))))
+}
+
+/// Generate matrices whose elements have either sign and absolute values in
+/// the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(matrix in strategies::matrix_signed_from_abs_range::<i32, 3, 3>(1_i32, 10_i32))| {
+///     prop_assert!(matrix[1][1] != 0_i32);
+/// });
+/// ```
+pub fn matrix_signed_from_abs_range<S, const R: usize, const C: usize>(min_value: S, max_value: S) -> impl Strategy<Value = Matrix<S, R, C>>
+where
+    S: SimdScalarSigned + Arbitrary,
+{
+    any::<[[S; R]; C]>()
+        .prop_map(move |array| Matrix::from(array.map(|column| column.map(|element| rescale_signed(element, min_value, max_value)))))
+}
+
+/// Generate quaternions whose components lie in the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(quaternion in strategies::quaternion_from_range(-1_f64, 1_f64))| {
+///     prop_assert!(quaternion.s.abs() <= 1_f64);
+/// });
+/// ```
+pub fn quaternion_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Quaternion<S>>
+where
+    S: SimdScalar + Arbitrary,
+{
+    any::<[S; 4]>().prop_map(move |array| Quaternion::from(array.map(|component| rescale(component, min_value, max_value))))
+}
+
+/// Generate quaternions whose components have either sign and absolute values in
+/// the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(quaternion in strategies::quaternion_signed_from_abs_range(1_f64, 2_f64))| {
+///     prop_assert!(quaternion.s.abs() >= 1_f64);
+/// });
+/// ```
+pub fn quaternion_signed_from_abs_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Quaternion<S>>
+where
+    S: SimdScalarSigned + Arbitrary,
+{
+    any::<[S; 4]>().prop_map(move |array| Quaternion::from(array.map(|component| rescale_signed(component, min_value, max_value))))
+}
+
+/// Generate complex numbers whose real and imaginary parts lie in the range
+/// `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(z in strategies::complex_from_range(0_f64, 1_f64))| {
+///     prop_assert!(z.re >= 0_f64 && z.im >= 0_f64);
+/// });
+/// ```
+pub fn complex_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Complex<S>>
+where
+    S: SimdScalar + Arbitrary,
+{
+    any::<(S, S)>().prop_map(move |(re, im)| Complex::new(rescale(re, min_value, max_value), rescale(im, min_value, max_value)))
+}
+
+/// Generate complex numbers whose real and imaginary parts have either sign and
+/// absolute values in the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(z in strategies::complex_signed_from_abs_range(1_i32, 10_i32))| {
+///     prop_assert!(z.re != 0_i32 && z.im != 0_i32);
+/// });
+/// ```
+pub fn complex_signed_from_abs_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Complex<S>>
+where
+    S: SimdScalarSigned + Arbitrary,
+{
+    any::<(S, S)>()
+        .prop_map(move |(re, im)| Complex::new(rescale_signed(re, min_value, max_value), rescale_signed(im, min_value, max_value)))
+}
+
+/// Generate Euler angles whose three angles lie in the range
+/// `[min_angle, max_angle]` radians.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use core::f64;
+/// # use proptest::prelude::*;
+/// #
+/// let max_angle = f64::consts::FRAC_PI_2;
+/// proptest!(|(euler in strategies::euler_from_range(-max_angle, max_angle))| {
+///     prop_assert!(euler.y.0.abs() <= max_angle);
+/// });
+/// ```
+pub fn euler_from_range<S>(min_angle: S, max_angle: S) -> impl Strategy<Value = Euler<Radians<S>>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    let angle = move || cglinalg_trigonometry::strategies::radians_from_range(min_angle, max_angle);
+
+    (angle(), angle(), angle()).prop_map(|(x, y, z)| Euler::new(x, y, z))
+}
+
+/// Generate unit vectors.
+///
+/// The vectors are normalized from vectors with components in the range
+/// `[-1, 1]`, rejecting any whose norm is too small to normalize reliably.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(direction in strategies::unit_vector::<f64, 3>())| {
+///     prop_assert!((direction.norm() - 1_f64).abs() <= 1e-12);
+/// });
+/// ```
+pub fn unit_vector<S, const N: usize>() -> impl Strategy<Value = Unit<Vector<S, N>>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    let threshold = S::default_epsilon().sqrt();
+
+    vector_from_range(-S::one(), S::one()).prop_filter_map("vector too close to zero to normalize", move |vector| {
+        Unit::try_from_value(vector, threshold)
+    })
+}
+
+/// Generate unit quaternions.
+///
+/// Every unit quaternion represents a rotation, so this strategy generates
+/// arbitrary rotations in three dimensions.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(quaternion in strategies::unit_quaternion::<f64>())| {
+///     prop_assert!((quaternion.norm() - 1_f64).abs() <= 1e-12);
+/// });
+/// ```
+pub fn unit_quaternion<S>() -> impl Strategy<Value = Unit<Quaternion<S>>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    let threshold = S::default_epsilon().sqrt();
+
+    quaternion_from_range(-S::one(), S::one()).prop_filter_map("quaternion too close to zero to normalize", move |quaternion| {
+        Unit::try_from_value(quaternion, threshold)
+    })
+}
+
+/// Generate unit complex numbers.
+///
+/// Every unit complex number represents a rotation, so this strategy generates
+/// arbitrary rotations in two dimensions.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(z in strategies::unit_complex::<f64>())| {
+///     prop_assert!((z.modulus() - 1_f64).abs() <= 1e-12);
+/// });
+/// ```
+pub fn unit_complex<S>() -> impl Strategy<Value = Unit<Complex<S>>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    let threshold = S::default_epsilon().sqrt();
+
+    complex_from_range(-S::one(), S::one()).prop_filter_map("complex number too close to zero to normalize", move |z| {
+        Unit::try_from_value(z, threshold)
+    })
+}
+
+/// Generate invertible two-by-two matrices whose elements lie in the range
+/// `[min_value, max_value]`.
+///
+/// Matrices that fail [`Matrix2x2::is_invertible`] are rejected.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(matrix in strategies::invertible_matrix2x2_from_range(-10_f64, 10_f64))| {
+///     prop_assert!(matrix.try_inverse().is_some());
+/// });
+/// ```
+pub fn invertible_matrix2x2_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Matrix2x2<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    matrix_from_range(min_value, max_value).prop_filter("matrix is not invertible", Matrix2x2::is_invertible)
+}
+
+/// Generate invertible three-by-three matrices whose elements lie in the range
+/// `[min_value, max_value]`.
+///
+/// Matrices that fail [`Matrix3x3::is_invertible`] are rejected.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(matrix in strategies::invertible_matrix3x3_from_range(-10_f64, 10_f64))| {
+///     prop_assert!(matrix.try_inverse().is_some());
+/// });
+/// ```
+pub fn invertible_matrix3x3_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Matrix3x3<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    matrix_from_range(min_value, max_value).prop_filter("matrix is not invertible", Matrix3x3::is_invertible)
+}
+
+/// Generate invertible four-by-four matrices whose elements lie in the range
+/// `[min_value, max_value]`.
+///
+/// Matrices that fail [`Matrix4x4::is_invertible`] are rejected.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(matrix in strategies::invertible_matrix4x4_from_range(-10_f64, 10_f64))| {
+///     prop_assert!(matrix.try_inverse().is_some());
+/// });
+/// ```
+pub fn invertible_matrix4x4_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Matrix4x4<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    matrix_from_range(min_value, max_value).prop_filter("matrix is not invertible", Matrix4x4::is_invertible)
+}
+
+/// Generate two-by-two orthonormal matrices with determinant one.
+///
+/// These are exactly the rotation matrices in two dimensions.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::relative_eq;
+/// # use cglinalg_core::{
+/// #     strategies,
+/// #     Matrix2x2,
+/// # };
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(matrix in strategies::orthonormal_matrix2x2::<f64>())| {
+///     let identity = Matrix2x2::identity();
+///
+///     prop_assert!(relative_eq!(matrix.transpose() * matrix, identity, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON));
+/// });
+/// ```
+pub fn orthonormal_matrix2x2<S>() -> impl Strategy<Value = Matrix2x2<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    cglinalg_trigonometry::strategies::radians_from_range(S::zero(), S::two_pi()).prop_map(Matrix2x2::from_angle)
+}
+
+/// Generate three-by-three orthonormal matrices with determinant one.
+///
+/// These are exactly the rotation matrices in three dimensions.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::relative_eq;
+/// # use cglinalg_core::{
+/// #     strategies,
+/// #     Matrix3x3,
+/// # };
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(matrix in strategies::orthonormal_matrix3x3::<f64>())| {
+///     let identity = Matrix3x3::identity();
+///
+///     prop_assert!(relative_eq!(matrix.transpose() * matrix, identity, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON));
+/// });
+/// ```
+pub fn orthonormal_matrix3x3<S>() -> impl Strategy<Value = Matrix3x3<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    unit_quaternion().prop_map(|quaternion| Matrix3x3::from(quaternion.into_inner()))
+}
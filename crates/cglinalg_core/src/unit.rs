@@ -15,6 +15,7 @@ use core::ops;
 /// a calculation involving unit vectors.
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct Unit<T> {
     value: T,
 }
@@ -224,6 +225,39 @@ where
 }
 
 
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Unit<T>
+where
+    T: Normed + serde::Deserialize<'de>,
+    T::Output: SimdScalarFloat,
+{
+    /// Deserialize a unit value.
+    ///
+    /// The deserialized value is checked for unit norm up to the square root of the
+    /// default epsilon of the underlying scalar type. Values that are not normalized
+    /// are rejected instead of being silently renormalized.
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        use cglinalg_numeric::{
+            SimdScalarCmp,
+            SimdScalarSigned,
+        };
+
+        let value = T::deserialize(deserializer)?;
+        let one: T::Output = cglinalg_numeric::cast(1);
+        let tolerance = T::Output::default_epsilon().sqrt();
+        let norm = value.norm();
+
+        if (norm - one).abs() <= tolerance {
+            Ok(Self::from_value_unchecked(value))
+        } else {
+            Err(serde::de::Error::custom("value does not have unit norm"))
+        }
+    }
+}
+
 impl<T> approx_cmp::AbsDiffEq for Unit<T>
 where
     T: approx_cmp::AbsDiffEq,
@@ -10,6 +10,11 @@ use crate::normed::{
     Norm,
     Normed,
 };
+use crate::parse::{
+    parse_components,
+    strip_type_name,
+    ParseError,
+};
 use crate::unit::Unit;
 use crate::{
     impl_coords,
@@ -25,6 +30,7 @@ use cglinalg_numeric::{
 
 use core::fmt;
 use core::ops;
+use core::str::FromStr;
 
 
 /// A stack-allocated one-dimensional vector.
@@ -42,6 +48,7 @@ pub type Vector4<S> = Vector<S, 4>;
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 /// A stack-allocated vector.
 pub struct Vector<S, const N: usize> {
     data: [S; N],
@@ -870,6 +877,41 @@ where
         other * (self.dot(other) / other.norm_squared())
     }
 
+    /// Compute the rejection of the vector `self` from the vector `other`.
+    ///
+    /// Given vectors `v` and `w`, the projection of `v` onto `w` is the
+    /// component of the vector `v` that is parallel to the vector `w`.
+    /// We can decompose the vector `v` as follows
+    /// ```text
+    /// v := v_parallel + v_perpendicular
+    /// ```
+    /// The component `v_parallel` is the component of `v` parallel to `w`, or
+    /// projected onto `w`. The component `v_perpendicular` is the component
+    /// perpendicular to `w`, or rejected by `w`. This leads to the decomposition
+    /// ```text
+    /// v == v.project(w) + v.reject(w)
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// #
+    /// let v = Vector3::new(5_f64, 2_f64, 8_f64);
+    /// let w = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let v_proj = v.project(&w);
+    /// let v_rej = v.reject(&w);
+    ///
+    /// assert_relative_eq!(v_proj + v_rej, v, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(v_rej.dot(&w), 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_eq!(Vector3::new(1_f64, 2_f64, 3_f64).reject(&Vector3::unit_z()), Vector3::new(1_f64, 2_f64, 0_f64));
+    /// ```
+    #[inline]
+    pub fn reject(&self, other: &Self) -> Self {
+        self - self.project(other)
+    }
+
     /// Reflect a vector about a normal vector.
     ///
     /// # Example
@@ -1158,6 +1200,90 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<S, const N: usize> serde::Serialize for Vector<S, N>
+where
+    S: serde::Serialize,
+{
+    fn serialize<Ser>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error>
+    where
+        Ser: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for component in self.data.iter() {
+            tuple.serialize_element(component)?;
+        }
+
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S, const N: usize> serde::Deserialize<'de> for Vector<S, N>
+where
+    S: serde::Deserialize<'de>,
+{
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        struct VectorVisitor<S, const N: usize> {
+            _marker: core::marker::PhantomData<S>,
+        }
+
+        impl<'de, S, const N: usize> serde::de::Visitor<'de> for VectorVisitor<S, N>
+        where
+            S: serde::Deserialize<'de>,
+        {
+            type Value = Vector<S, N>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "an array of {} vector components", N)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut error = None;
+                let data: [Option<S>; N] = core::array::from_fn(|i| {
+                    if error.is_some() {
+                        return None;
+                    }
+
+                    match seq.next_element() {
+                        Ok(Some(component)) => Some(component),
+                        Ok(None) => {
+                            error = Some(serde::de::Error::invalid_length(i, &self));
+                            None
+                        }
+                        Err(err) => {
+                            error = Some(err);
+                            None
+                        }
+                    }
+                });
+
+                match error {
+                    Some(err) => Err(err),
+                    None => Ok(Vector {
+                        data: data.map(|component| component.unwrap()),
+                    }),
+                }
+            }
+        }
+
+        deserializer.deserialize_tuple(
+            N,
+            VectorVisitor::<S, N> {
+                _marker: core::marker::PhantomData,
+            },
+        )
+    }
+}
+
 macro_rules! impl_vector_index_ops {
     ($IndexType:ty, $Output:ty) => {
         impl<S, const N: usize> ops::Index<$IndexType> for Vector<S, N> {
@@ -1200,6 +1326,136 @@ where
     }
 }
 
+/// Parse a vector from a string.
+///
+/// This accepts the output of the [`Display`](fmt::Display) implementation, such
+/// as `Vector3 [1, 2, 3]`, as well as a list of components separated by commas or
+/// whitespace that is optionally enclosed in brackets or parentheses, such as
+/// `[1, 2, 3]`, `(1 2 3)`, or `1, 2, 3`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     ParseError,
+/// #     Vector3,
+/// # };
+/// #
+/// let expected = Vector3::new(1_f64, 2_f64, 3_f64);
+///
+/// assert_eq!("Vector3 [1, 2, 3]".parse(), Ok(expected));
+/// assert_eq!("[1, 2, 3]".parse(), Ok(expected));
+/// assert_eq!("(1 2 3)".parse(), Ok(expected));
+/// assert_eq!("[1, 2, x]".parse::<Vector3<f64>>(), Err(ParseError::InvalidComponent { index: 2 }));
+/// ```
+impl<S, const N: usize> FromStr for Vector<S, N>
+where
+    S: SimdScalar + FromStr,
+{
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let list = strip_type_name(string, "Vector", &[N])?;
+        let mut data = [S::zero(); N];
+        parse_components(list, 0, &mut data)?;
+
+        Ok(Self { data })
+    }
+}
+
+
+// SAFETY: A vector is a `#[repr(C)]` wrapper around an array of `N` components. It has no
+// padding, and every bit pattern is valid when every bit pattern of `S` is valid.
+#[cfg(feature = "bytemuck")]
+unsafe impl<S, const N: usize> bytemuck::Zeroable for Vector<S, N> where S: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S, const N: usize> bytemuck::Pod for Vector<S, N> where S: bytemuck::Pod {}
+
+#[cfg(feature = "mint")]
+macro_rules! impl_mint_vector_conversions {
+    ($VectorN:ident, $MintVectorN:ident) => {
+        impl<S> From<$VectorN<S>> for mint::$MintVectorN<S> {
+            #[inline]
+            fn from(vector: $VectorN<S>) -> Self {
+                Self::from(vector.data)
+            }
+        }
+
+        impl<S> From<mint::$MintVectorN<S>> for $VectorN<S> {
+            #[inline]
+            fn from(vector: mint::$MintVectorN<S>) -> Self {
+                Self { data: vector.into() }
+            }
+        }
+
+        impl<S> mint::IntoMint for $VectorN<S> {
+            type MintType = mint::$MintVectorN<S>;
+        }
+    };
+}
+
+#[cfg(feature = "mint")]
+impl_mint_vector_conversions!(Vector2, Vector2);
+#[cfg(feature = "mint")]
+impl_mint_vector_conversions!(Vector3, Vector3);
+#[cfg(feature = "mint")]
+impl_mint_vector_conversions!(Vector4, Vector4);
+
+/// Sample a vector whose components are sampled independently from the standard
+/// distribution of the scalar type.
+#[cfg(feature = "rand")]
+impl<S, const N: usize> rand::distributions::Distribution<Vector<S, N>> for rand::distributions::Standard
+where
+    rand::distributions::Standard: rand::distributions::Distribution<S>,
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vector<S, N> {
+        Vector {
+            data: core::array::from_fn(|_| self.sample(rng)),
+        }
+    }
+}
+
+/// Generate a unit vector by normalizing a vector whose components lie in the
+/// interval `[-1, 1]`.
+///
+/// When the generated vector is too short to normalize reliably, the unit vector
+/// along the **x-axis** is generated instead. There are no unit vectors in zero
+/// dimensions, so generating a zero-dimensional unit vector always fails.
+#[cfg(feature = "arbitrary")]
+impl<'a, S, const N: usize> arbitrary::Arbitrary<'a> for Unit<Vector<S, N>>
+where
+    S: SimdScalarFloat,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if N == 0 {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+
+        let max_component: S = cglinalg_numeric::cast(i32::MAX);
+        let mut vector = Vector::zero();
+        for i in 0..N {
+            let component: i32 = u.arbitrary()?;
+            vector[i] = cglinalg_numeric::cast::<i32, S>(component) / max_component;
+        }
+
+        let threshold = S::default_epsilon().sqrt();
+        let unit_vector = Unit::try_from_value(vector, threshold).unwrap_or_else(|| {
+            let mut unit_x = Vector::zero();
+            unit_x[0] = S::one();
+
+            Unit::from_value_unchecked(unit_x)
+        });
+
+        Ok(unit_vector)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[i32; N] as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
 
 impl<S, const N: usize> approx_cmp::AbsDiffEq for Vector<S, N>
 where
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod aligned_vector3_tests {
+    use cglinalg_core::{
+        AlignedVector3,
+        Vector3,
+    };
+
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(core::mem::size_of::<AlignedVector3<f32>>(), 16);
+        assert_eq!(core::mem::align_of::<AlignedVector3<f32>>(), 16);
+        assert_eq!(core::mem::size_of::<[AlignedVector3<f32>; 4]>(), 64);
+    }
+
+    #[test]
+    fn test_vector_round_trip() {
+        let vector = Vector3::new(1_f32, -2_f32, 3_f32);
+        let aligned = AlignedVector3::from(vector);
+        let result: Vector3<f32> = aligned.into();
+
+        assert_eq!(result, vector);
+    }
+
+    #[test]
+    fn test_default_is_zero() {
+        let aligned: AlignedVector3<f32> = AlignedVector3::default();
+
+        assert_eq!(aligned.to_vector(), Vector3::zero());
+    }
+}
+
+
+#[cfg(test)]
+mod aligned_matrix3x3_tests {
+    use cglinalg_core::{
+        AlignedMatrix3x3,
+        Matrix3x3,
+    };
+
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(core::mem::size_of::<AlignedMatrix3x3<f32>>(), 48);
+        assert_eq!(core::mem::align_of::<AlignedMatrix3x3<f32>>(), 16);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_matrix_round_trip() {
+        let matrix = Matrix3x3::new(
+            1_f32, 2_f32, 3_f32,
+            4_f32, 5_f32, 6_f32,
+            7_f32, 8_f32, 9_f32,
+        );
+        let aligned = AlignedMatrix3x3::from(&matrix);
+        let result: Matrix3x3<f32> = aligned.into();
+
+        assert_eq!(result, matrix);
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(all(test, feature = "arbitrary"))]
+mod arbitrary_tests {
+    use approx_cmp::assert_relative_eq;
+    use arbitrary::{
+        Arbitrary,
+        Unstructured,
+    };
+    use cglinalg_core::{
+        AlignedMatrix3x3,
+        AlignedVector3,
+        Complex,
+        Quaternion,
+        Unit,
+        Vector,
+        Vector3,
+    };
+    use rand::{
+        Rng,
+        SeedableRng,
+    };
+    use rand_isaac::IsaacRng;
+
+
+    fn inputs() -> Vec<Vec<u8>> {
+        let mut rng = IsaacRng::seed_from_u64(0);
+        let mut inputs = vec![vec![], vec![0x00; 256], vec![0xFF; 256]];
+        for _ in 0..1000 {
+            let length = rng.gen_range(0..256);
+            inputs.push((0..length).map(|_| rng.gen()).collect());
+        }
+
+        inputs
+    }
+
+    #[test]
+    fn test_unit_vector_has_unit_norm() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let unit_vector: Unit<Vector3<f64>> = Unit::arbitrary(&mut u).unwrap();
+
+            assert_relative_eq!(unit_vector.norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_unit_vector_empty_input() {
+        let mut u = Unstructured::new(&[]);
+        let unit_vector: Unit<Vector3<f32>> = Unit::arbitrary(&mut u).unwrap();
+
+        assert_eq!(unit_vector.into_inner(), Vector3::unit_x());
+    }
+
+    #[test]
+    fn test_unit_vector_zero_dimensions() {
+        let mut u = Unstructured::new(&[1, 2, 3, 4]);
+
+        assert!(Unit::<Vector<f64, 0>>::arbitrary(&mut u).is_err());
+    }
+
+    #[test]
+    fn test_unit_quaternion_has_unit_norm() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let quaternion: Unit<Quaternion<f64>> = Unit::arbitrary(&mut u).unwrap();
+
+            assert_relative_eq!(quaternion.norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_unit_complex_has_unit_norm() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let complex: Unit<Complex<f32>> = Unit::arbitrary(&mut u).unwrap();
+
+            assert_relative_eq!(complex.norm(), 1_f32, abs_diff <= 1e-6, relative <= f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_aligned_padding_is_zero() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let vector: AlignedVector3<i32> = AlignedVector3::arbitrary(&mut u).unwrap();
+            let matrix: AlignedMatrix3x3<i32> = AlignedMatrix3x3::arbitrary(&mut u).unwrap();
+
+            assert_eq!(AlignedVector3::from_vector(&vector.to_vector()), vector);
+            assert_eq!(AlignedMatrix3x3::from_matrix(&matrix.to_matrix()), matrix);
+        }
+    }
+}
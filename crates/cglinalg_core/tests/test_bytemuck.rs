@@ -0,0 +1,95 @@
+#[cfg(all(test, feature = "bytemuck"))]
+mod bytemuck_tests {
+    use cglinalg_core::{
+        AlignedMatrix3x3,
+        AlignedVector3,
+        Complex,
+        Matrix2x2,
+        Matrix3x3,
+        Point3,
+        Quaternion,
+        Vector3,
+        Vector4,
+    };
+
+
+    #[test]
+    fn test_cast_vector_slice() {
+        let vectors = [Vector3::new(1_f32, 2_f32, 3_f32), Vector3::new(4_f32, 5_f32, 6_f32)];
+        let expected = [1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32];
+        let result: &[f32] = bytemuck::cast_slice(&vectors);
+
+        assert_eq!(result, &expected);
+        assert_eq!(bytemuck::cast_slice::<Vector3<f32>, u8>(&vectors).len(), 24);
+    }
+
+    #[test]
+    fn test_cast_bytes_to_vectors() {
+        let data = [1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32, 7_f32, 8_f32];
+        let bytes: &[u8] = bytemuck::cast_slice(&data);
+        let result: &[Vector4<f32>] = bytemuck::try_cast_slice(bytes).unwrap();
+
+        assert_eq!(result, &[Vector4::new(1_f32, 2_f32, 3_f32, 4_f32), Vector4::new(5_f32, 6_f32, 7_f32, 8_f32)]);
+    }
+
+    #[test]
+    fn test_cast_point() {
+        let point = Point3::new(1_i32, 2_i32, 3_i32);
+        let result: [i32; 3] = bytemuck::cast(point);
+
+        assert_eq!(result, [1_i32, 2_i32, 3_i32]);
+    }
+
+    #[test]
+    fn test_cast_matrix_column_major() {
+        let matrix = Matrix2x2::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let result: [f64; 4] = bytemuck::cast(matrix);
+
+        assert_eq!(result, [1_f64, 2_f64, 3_f64, 4_f64]);
+    }
+
+    #[test]
+    fn test_cast_quaternion_and_complex() {
+        let quaternion = Quaternion::new(1_f32, 2_f32, 3_f32, 4_f32);
+        let z = Complex::new(5_f32, 6_f32);
+
+        assert_eq!(bytemuck::cast::<Quaternion<f32>, [f32; 4]>(quaternion), [1_f32, 2_f32, 3_f32, 4_f32]);
+        assert_eq!(bytemuck::cast::<Complex<f32>, [f32; 2]>(z), [5_f32, 6_f32]);
+    }
+
+    #[test]
+    fn test_zeroed() {
+        let vector: Vector3<f32> = bytemuck::Zeroable::zeroed();
+        let matrix: Matrix3x3<f32> = bytemuck::Zeroable::zeroed();
+
+        assert_eq!(vector, Vector3::zero());
+        assert_eq!(matrix, Matrix3x3::zero());
+    }
+
+    #[test]
+    fn test_cast_aligned_vector3() {
+        let aligned = AlignedVector3::new(1_f32, 2_f32, 3_f32);
+        let result: [f32; 4] = bytemuck::cast(aligned);
+
+        assert_eq!(result, [1_f32, 2_f32, 3_f32, 0_f32]);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_cast_aligned_matrix3x3() {
+        let matrix = Matrix3x3::new(
+            1_f32, 2_f32, 3_f32,
+            4_f32, 5_f32, 6_f32,
+            7_f32, 8_f32, 9_f32,
+        );
+        let aligned = AlignedMatrix3x3::from_matrix(&matrix);
+        let expected = [
+            1_f32, 2_f32, 3_f32, 0_f32,
+            4_f32, 5_f32, 6_f32, 0_f32,
+            7_f32, 8_f32, 9_f32, 0_f32,
+        ];
+        let result: [f32; 12] = bytemuck::cast(aligned);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,166 @@
+#[cfg(all(test, feature = "rand"))]
+mod standard_tests {
+    use cglinalg_core::{
+        Complex,
+        Matrix3x3,
+        Point3,
+        Quaternion,
+        Vector4,
+    };
+    use rand::{
+        Rng,
+        SeedableRng,
+    };
+    use rand_isaac::IsaacRng;
+
+
+    #[test]
+    fn test_vector_components_in_unit_interval() {
+        let mut rng = IsaacRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let vector: Vector4<f64> = rng.gen();
+
+            assert!(vector.as_slice().iter().all(|&component| (0_f64..1_f64).contains(&component)));
+        }
+    }
+
+    #[test]
+    fn test_sample_same_values_as_components() {
+        let mut rng1 = IsaacRng::seed_from_u64(1);
+        let mut rng2 = IsaacRng::seed_from_u64(1);
+        let point: Point3<f64> = rng1.gen();
+        let quaternion: Quaternion<f64> = rng1.gen();
+        let z: Complex<f64> = rng1.gen();
+        let components: [f64; 9] = rng2.gen();
+
+        assert_eq!(point, Point3::new(components[0], components[1], components[2]));
+        assert_eq!(quaternion, Quaternion::new(components[3], components[4], components[5], components[6]));
+        assert_eq!(z, Complex::new(components[7], components[8]));
+    }
+
+    #[test]
+    fn test_matrix_column_major() {
+        let mut rng1 = IsaacRng::seed_from_u64(2);
+        let mut rng2 = IsaacRng::seed_from_u64(2);
+        let matrix: Matrix3x3<f64> = rng1.gen();
+        let columns: [[f64; 3]; 3] = rng2.gen();
+
+        assert_eq!(matrix, Matrix3x3::from(columns));
+    }
+}
+
+
+#[cfg(all(test, feature = "rand"))]
+mod distributions_tests {
+    use cglinalg_core::{
+        CosineHemisphere,
+        Matrix3x3,
+        Quaternion,
+        Unit,
+        UnitBall,
+        UnitDisk,
+        UnitSphere,
+        Vector2,
+        Vector3,
+    };
+    use rand::{
+        Rng,
+        SeedableRng,
+    };
+    use rand_isaac::IsaacRng;
+
+
+    const SAMPLE_COUNT: usize = 100_000;
+
+    #[test]
+    fn test_unit_sphere() {
+        let mut rng = IsaacRng::seed_from_u64(0);
+        let mut mean = Vector3::zero();
+        let mut z_squared_mean = 0_f64;
+        for _ in 0..SAMPLE_COUNT {
+            let direction: Unit<Vector3<f64>> = rng.sample(UnitSphere::new());
+
+            assert!((direction.norm() - 1_f64).abs() <= 1e-12);
+            mean += direction.into_inner() / SAMPLE_COUNT as f64;
+            z_squared_mean += direction.z * direction.z / SAMPLE_COUNT as f64;
+        }
+
+        assert!(mean.norm() <= 0.01);
+        assert!((z_squared_mean - 1_f64 / 3_f64).abs() <= 0.01);
+    }
+
+    #[test]
+    fn test_unit_ball() {
+        let mut rng = IsaacRng::seed_from_u64(1);
+        let mut inner_count = 0;
+        for _ in 0..SAMPLE_COUNT {
+            let vector: Vector3<f64> = rng.sample(UnitBall::new());
+
+            assert!(vector.norm() <= 1_f64);
+            if vector.norm() <= 0.5_f64 {
+                inner_count += 1;
+            }
+        }
+        let inner_fraction = inner_count as f64 / SAMPLE_COUNT as f64;
+
+        assert!((inner_fraction - 1_f64 / 8_f64).abs() <= 0.01);
+    }
+
+    #[test]
+    fn test_unit_disk() {
+        let mut rng = IsaacRng::seed_from_u64(2);
+        let mut inner_count = 0;
+        let mut mean = Vector2::zero();
+        for _ in 0..SAMPLE_COUNT {
+            let vector: Vector2<f64> = rng.sample(UnitDisk::new());
+
+            assert!(vector.norm() <= 1_f64);
+            if vector.norm() <= 0.5_f64 {
+                inner_count += 1;
+            }
+            mean += vector / SAMPLE_COUNT as f64;
+        }
+        let inner_fraction = inner_count as f64 / SAMPLE_COUNT as f64;
+
+        assert!((inner_fraction - 1_f64 / 4_f64).abs() <= 0.01);
+        assert!(mean.norm() <= 0.01);
+    }
+
+    #[test]
+    fn test_cosine_hemisphere() {
+        let mut rng = IsaacRng::seed_from_u64(3);
+        let mut cos_theta_mean = 0_f64;
+        for _ in 0..SAMPLE_COUNT {
+            let direction: Unit<Vector3<f64>> = rng.sample(CosineHemisphere::new());
+
+            assert!(direction.z >= 0_f64);
+            assert!((direction.norm() - 1_f64).abs() <= 1e-12);
+            cos_theta_mean += direction.z / SAMPLE_COUNT as f64;
+        }
+
+        // The mean of `cos(theta)` under the density `cos(theta) / pi` is `2 / 3`.
+        assert!((cos_theta_mean - 2_f64 / 3_f64).abs() <= 0.01);
+    }
+
+    #[test]
+    fn test_unit_quaternion() {
+        let mut rng = IsaacRng::seed_from_u64(4);
+        let mut component_squared_mean = [0_f64; 4];
+        let mut rotated_mean = Vector3::zero();
+        for _ in 0..SAMPLE_COUNT {
+            let quaternion: Unit<Quaternion<f64>> = rng.gen();
+
+            assert!((quaternion.norm() - 1_f64).abs() <= 1e-12);
+            for i in 0..4 {
+                component_squared_mean[i] += quaternion[i] * quaternion[i] / SAMPLE_COUNT as f64;
+            }
+            let rotation = Matrix3x3::from(quaternion.into_inner());
+            rotated_mean += rotation * Vector3::unit_x() / SAMPLE_COUNT as f64;
+        }
+
+        for component in component_squared_mean.iter() {
+            assert!((component - 0.25_f64).abs() <= 0.01);
+        }
+        assert!(rotated_mean.norm() <= 0.01);
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(all(test, feature = "mint"))]
+mod mint_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Euler,
+        Matrix2x3,
+        Matrix3x3,
+        Matrix4x4,
+        Point2,
+        Point3,
+        Quaternion,
+        Vector2,
+        Vector3,
+        Vector4,
+    };
+    use cglinalg_trigonometry::Radians;
+    use mint::IntoMint;
+
+
+    #[test]
+    fn test_vector_conversions() {
+        let vector2 = Vector2::new(1_f32, 2_f32);
+        let vector3 = Vector3::new(1_f32, 2_f32, 3_f32);
+        let vector4 = Vector4::new(1_f32, 2_f32, 3_f32, 4_f32);
+        let mint_vector3: mint::Vector3<f32> = vector3.into();
+        let mint_vector4: <Vector4<f32> as IntoMint>::MintType = vector4.into();
+
+        assert_eq!(mint_vector3, mint::Vector3 { x: 1_f32, y: 2_f32, z: 3_f32 });
+        assert_eq!(Vector2::from(mint::Vector2::from(vector2)), vector2);
+        assert_eq!(Vector3::from(mint_vector3), vector3);
+        assert_eq!(Vector4::from(mint_vector4), vector4);
+    }
+
+    #[test]
+    fn test_point_conversions() {
+        let point2 = Point2::new(1_f64, 2_f64);
+        let point3 = Point3::new(1_f64, 2_f64, 3_f64);
+        let mint_point3: mint::Point3<f64> = point3.into();
+
+        assert_eq!(mint_point3, mint::Point3 { x: 1_f64, y: 2_f64, z: 3_f64 });
+        assert_eq!(Point2::from(mint::Point2::from(point2)), point2);
+        assert_eq!(Point3::from(mint_point3), point3);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_matrix_conversions_column_major() {
+        let matrix = Matrix3x3::new(
+            1_i32, 2_i32, 3_i32,
+            4_i32, 5_i32, 6_i32,
+            7_i32, 8_i32, 9_i32,
+        );
+        let expected = mint::ColumnMatrix3 {
+            x: mint::Vector3 { x: 1_i32, y: 2_i32, z: 3_i32 },
+            y: mint::Vector3 { x: 4_i32, y: 5_i32, z: 6_i32 },
+            z: mint::Vector3 { x: 7_i32, y: 8_i32, z: 9_i32 },
+        };
+        let result: mint::ColumnMatrix3<i32> = matrix.into();
+
+        assert_eq!(result, expected);
+        assert_eq!(Matrix3x3::from(result), matrix);
+    }
+
+    #[test]
+    fn test_rectangular_matrix_conversions() {
+        let matrix = Matrix2x3::new(1_f32, 2_f32, 3_f32, 4_f32, 5_f32, 6_f32);
+        let result: mint::ColumnMatrix2x3<f32> = matrix.into();
+
+        assert_eq!(result.z, mint::Vector2 { x: 5_f32, y: 6_f32 });
+        assert_eq!(Matrix2x3::from(result), matrix);
+    }
+
+    #[test]
+    fn test_matrix4x4_conversions() {
+        let matrix = Matrix4x4::from_affine_translation(&Vector3::new(1_f32, 2_f32, 3_f32));
+        let result: <Matrix4x4<f32> as IntoMint>::MintType = matrix.into();
+
+        assert_eq!(result.w, mint::Vector4 { x: 1_f32, y: 2_f32, z: 3_f32, w: 1_f32 });
+        assert_eq!(Matrix4x4::from(result), matrix);
+    }
+
+    #[test]
+    fn test_quaternion_conversions() {
+        let quaternion = Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let result: mint::Quaternion<f64> = quaternion.into();
+
+        assert_eq!(result.s, 1_f64);
+        assert_eq!(result.v, mint::Vector3 { x: 2_f64, y: 3_f64, z: 4_f64 });
+        assert_eq!(Quaternion::from(result), quaternion);
+    }
+
+    #[test]
+    fn test_euler_conversions() {
+        let euler = Euler::new(Radians(0.1_f64), Radians(0.2_f64), Radians(0.3_f64));
+        let result: mint::EulerAngles<f64, mint::ExtraZYX> = euler.into();
+
+        assert_eq!((result.a, result.b, result.c), (0.3_f64, 0.2_f64, 0.1_f64));
+        assert_eq!(Euler::from(result), euler);
+    }
+
+    #[test]
+    fn test_euler_conversion_matches_intrinsic_xyz_rotation() {
+        let euler = Euler::new(Radians(0.4_f64), Radians(-0.7_f64), Radians(1.1_f64));
+        let intrinsic: mint::EulerAngles<f64, mint::IntraXYZ> = mint::EulerAngles::<f64, mint::ExtraZYX>::from(euler).into();
+        let rotation_x = Matrix3x3::from_angle_x(Radians(intrinsic.a));
+        let rotation_y = Matrix3x3::from_angle_y(Radians(intrinsic.b));
+        let rotation_z = Matrix3x3::from_angle_z(Radians(intrinsic.c));
+        let expected = rotation_x * rotation_y * rotation_z;
+        let result = euler.to_matrix();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+    }
+}
@@ -0,0 +1,285 @@
+#[cfg(test)]
+mod vector_parse_tests {
+    use cglinalg_core::{
+        ParseError,
+        Vector1,
+        Vector3,
+        Vector4,
+    };
+
+
+    #[test]
+    fn test_display_round_trip() {
+        let vector = Vector4::new(1.5_f64, -2_f64, 3e-7_f64, 4e20_f64);
+        let result = vector.to_string().parse();
+
+        assert_eq!(result, Ok(vector));
+    }
+
+    #[test]
+    fn test_common_forms() {
+        let expected = Vector3::new(1_i32, -2_i32, 3_i32);
+
+        assert_eq!("[1, -2, 3]".parse(), Ok(expected));
+        assert_eq!("(1 -2 3)".parse(), Ok(expected));
+        assert_eq!("1,-2,3".parse(), Ok(expected));
+        assert_eq!("  [ 1 -2  3 ]  ".parse(), Ok(expected));
+        assert_eq!("Vector3 (1 -2 3)".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_one_dimensional() {
+        assert_eq!("Vector1 [5]".parse(), Ok(Vector1::new(5_f32)));
+        assert_eq!("5".parse(), Ok(Vector1::new(5_f32)));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!("".parse::<Vector3<f64>>(), Err(ParseError::Empty));
+        assert_eq!("Vector4 [1, 2, 3]".parse::<Vector3<f64>>(), Err(ParseError::InvalidPrefix));
+        assert_eq!("Vector3x [1, 2, 3]".parse::<Vector3<f64>>(), Err(ParseError::InvalidPrefix));
+        assert_eq!("[1, 2, 3".parse::<Vector3<f64>>(), Err(ParseError::UnbalancedDelimiters));
+        assert_eq!("1, 2, 3]".parse::<Vector3<f64>>(), Err(ParseError::UnbalancedDelimiters));
+        assert_eq!("[1, 2, 3)".parse::<Vector3<f64>>(), Err(ParseError::UnbalancedDelimiters));
+        assert_eq!("[1, 2] [3]".parse::<Vector3<f64>>(), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            "[1, 2]".parse::<Vector3<f64>>(),
+            Err(ParseError::ComponentCount { expected: 3, found: 2 })
+        );
+        assert_eq!(
+            "1 2 3 4".parse::<Vector3<f64>>(),
+            Err(ParseError::ComponentCount { expected: 3, found: 4 })
+        );
+        assert_eq!("[1, , 3]".parse::<Vector3<f64>>(), Err(ParseError::InvalidComponent { index: 1 }));
+        assert_eq!(
+            "[1, 2, 3.5]".parse::<Vector3<i32>>(),
+            Err(ParseError::InvalidComponent { index: 2 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod point_parse_tests {
+    use cglinalg_core::{
+        ParseError,
+        Point2,
+        Point3,
+    };
+
+
+    #[test]
+    fn test_display_round_trip() {
+        let point = Point3::new(-1_f32, 0.25_f32, 8_f32);
+        let result = point.to_string().parse();
+
+        assert_eq!(result, Ok(point));
+    }
+
+    #[test]
+    fn test_common_forms() {
+        let expected = Point2::new(3_f64, 4_f64);
+
+        assert_eq!("[3, 4]".parse(), Ok(expected));
+        assert_eq!("(3 4)".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!("Vector2 [3, 4]".parse::<Point2<f64>>(), Err(ParseError::InvalidSyntax));
+        assert_eq!("Point3 [3, 4]".parse::<Point2<f64>>(), Err(ParseError::InvalidPrefix));
+        assert_eq!(
+            "(3)".parse::<Point2<f64>>(),
+            Err(ParseError::ComponentCount { expected: 2, found: 1 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod matrix_parse_tests {
+    use cglinalg_core::{
+        Matrix2x2,
+        Matrix2x3,
+        Matrix4x4,
+        ParseError,
+    };
+
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_display_round_trip() {
+        let matrix = Matrix4x4::new(
+            1_f64,  2_f64,  3_f64,  4_f64,
+            5_f64,  6_f64,  7_f64,  8_f64,
+            9_f64,  10_f64, 11_f64, 12_f64,
+            13_f64, 14_f64, 15_f64, -16.5_f64,
+        );
+        let result = matrix.to_string().parse();
+
+        assert_eq!(result, Ok(matrix));
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_rectangular_display_round_trip() {
+        let matrix = Matrix2x3::new(
+            1_i32, 2_i32,
+            3_i32, 4_i32,
+            5_i32, 6_i32,
+        );
+        let result = matrix.to_string().parse();
+
+        assert_eq!(matrix.to_string(), "Matrix2x3 [[1, 2], [3, 4], [5, 6]]");
+        assert_eq!(result, Ok(matrix));
+    }
+
+    #[test]
+    fn test_common_forms() {
+        let expected = Matrix2x2::new(1_f64, 2_f64, 3_f64, 4_f64);
+
+        assert_eq!("[[1, 2], [3, 4]]".parse(), Ok(expected));
+        assert_eq!("((1 2) (3 4))".parse(), Ok(expected));
+        assert_eq!("[1, 2], [3, 4]".parse(), Ok(expected));
+        assert_eq!("[1, 2, 3, 4]".parse(), Ok(expected));
+        assert_eq!("1 2 3 4".parse(), Ok(expected));
+        assert_eq!("Matrix2x2 [1 2 3 4]".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!("".parse::<Matrix2x2<f64>>(), Err(ParseError::Empty));
+        assert_eq!(
+            "Matrix3x3 [[1, 2], [3, 4]]".parse::<Matrix2x2<f64>>(),
+            Err(ParseError::InvalidPrefix)
+        );
+        assert_eq!("Matrix2 [[1, 2], [3, 4]]".parse::<Matrix2x2<f64>>(), Err(ParseError::InvalidPrefix));
+        assert_eq!("[[1, 2], [3, 4]".parse::<Matrix2x2<f64>>(), Err(ParseError::UnbalancedDelimiters));
+        assert_eq!("[[1, 2], 3, 4]".parse::<Matrix2x2<f64>>(), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            "[[1, 2]]".parse::<Matrix2x2<f64>>(),
+            Err(ParseError::ColumnCount { expected: 2, found: 1 })
+        );
+        assert_eq!(
+            "[[1, 2], [3, 4], [5, 6]]".parse::<Matrix2x2<f64>>(),
+            Err(ParseError::ColumnCount { expected: 2, found: 3 })
+        );
+        assert_eq!(
+            "[[1, 2], [3]]".parse::<Matrix2x2<f64>>(),
+            Err(ParseError::ComponentCount { expected: 2, found: 1 })
+        );
+        assert_eq!(
+            "[1, 2, 3]".parse::<Matrix2x2<f64>>(),
+            Err(ParseError::ComponentCount { expected: 4, found: 3 })
+        );
+        assert_eq!(
+            "[[1, 2], [3, x]]".parse::<Matrix2x2<f64>>(),
+            Err(ParseError::InvalidComponent { index: 3 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod quaternion_parse_tests {
+    use cglinalg_core::{
+        ParseError,
+        Quaternion,
+    };
+
+
+    #[test]
+    fn test_display_round_trip() {
+        let quaternion = Quaternion::new(1_f64, -2.5_f64, 3_f64, 1e-12_f64);
+        let result = quaternion.to_string().parse();
+
+        assert_eq!(result, Ok(quaternion));
+    }
+
+    #[test]
+    fn test_common_forms() {
+        let expected = Quaternion::new(-1_f64, 2_f64, 3_f64, 4_f64);
+
+        assert_eq!("-1 + [2, 3, 4]".parse(), Ok(expected));
+        assert_eq!("-1+(2 3 4)".parse(), Ok(expected));
+        assert_eq!("[-1, 2, 3, 4]".parse(), Ok(expected));
+        assert_eq!("(-1 2 3 4)".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!("".parse::<Quaternion<f64>>(), Err(ParseError::Empty));
+        assert_eq!(
+            " + [2, 3, 4]".parse::<Quaternion<f64>>(),
+            Err(ParseError::InvalidComponent { index: 0 })
+        );
+        assert_eq!(
+            "1 + [2, 3]".parse::<Quaternion<f64>>(),
+            Err(ParseError::ComponentCount { expected: 3, found: 2 })
+        );
+        assert_eq!(
+            "1 + [2, 3, y]".parse::<Quaternion<f64>>(),
+            Err(ParseError::InvalidComponent { index: 3 })
+        );
+        assert_eq!(
+            "[1, 2, 3]".parse::<Quaternion<f64>>(),
+            Err(ParseError::ComponentCount { expected: 4, found: 3 })
+        );
+    }
+}
+
+#[cfg(test)]
+mod complex_parse_tests {
+    use cglinalg_core::{
+        Complex,
+        ParseError,
+    };
+
+
+    #[test]
+    fn test_display_round_trip() {
+        let complex = Complex::new(-1.5_f64, -2e-10_f64);
+        let result = complex.to_string().parse();
+
+        assert_eq!(result, Ok(complex));
+    }
+
+    #[test]
+    fn test_common_forms() {
+        let expected = Complex::new(1e5_f64, 2_f64);
+
+        assert_eq!("1e5 + i2".parse(), Ok(expected));
+        assert_eq!("1e+5 + 2i".parse(), Ok(expected));
+        assert_eq!("1e5+i 2".parse(), Ok(expected));
+        assert_eq!("[1e5, 2]".parse(), Ok(expected));
+        assert_eq!("(1e5 2)".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_minus_separator() {
+        let expected = Complex::new(-1_f64, -2_f64);
+
+        assert_eq!("-1 - 2i".parse(), Ok(expected));
+        assert_eq!("-1 - i2".parse(), Ok(expected));
+        assert_eq!("-1-2i".parse(), Ok(expected));
+        assert_eq!("-1 - -2i".parse(), Ok(Complex::new(-1_f64, 2_f64)));
+        assert_eq!("1e-5 - 2e-3i".parse(), Ok(Complex::new(1e-5_f64, -2e-3_f64)));
+        assert_eq!("(-1 -2)".parse(), Ok(expected));
+        assert_eq!("-1 -2".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_minus_separator_unsigned() {
+        assert_eq!("1 - 2i".parse::<Complex<u32>>(), Err(ParseError::InvalidComponent { index: 1 }));
+        assert_eq!("1 + 2i".parse::<Complex<u32>>(), Ok(Complex::new(1_u32, 2_u32)));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!("".parse::<Complex<f64>>(), Err(ParseError::Empty));
+        assert_eq!("1 + 2".parse::<Complex<f64>>(), Err(ParseError::InvalidSyntax));
+        assert_eq!("x + i2".parse::<Complex<f64>>(), Err(ParseError::InvalidComponent { index: 0 }));
+        assert_eq!("1 + iy".parse::<Complex<f64>>(), Err(ParseError::InvalidComponent { index: 1 }));
+        assert_eq!(
+            "[1, 2, 3]".parse::<Complex<f64>>(),
+            Err(ParseError::ComponentCount { expected: 2, found: 3 })
+        );
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use cglinalg_core::{
+        Complex,
+        Euler,
+        Matrix2x3,
+        Point3,
+        Quaternion,
+        Unit,
+        Vector,
+        Vector3,
+    };
+    use cglinalg_trigonometry::Radians;
+
+
+    #[test]
+    fn test_vector_serialize_array() {
+        let vector = Vector3::new(1_f64, 2_f64, 3_f64);
+        let expected = "[1.0,2.0,3.0]";
+        let result = serde_json::to_string(&vector).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_vector_round_trip() {
+        let vector = Vector::from([1_i32, -2_i32, 3_i32, -4_i32, 5_i32]);
+        let json = serde_json::to_string(&vector).unwrap();
+        let result: Vector<i32, 5> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(result, vector);
+    }
+
+    #[test]
+    fn test_vector_wrong_length() {
+        assert!(serde_json::from_str::<Vector3<f64>>("[1.0,2.0]").is_err());
+        assert!(serde_json::from_str::<Vector3<f64>>("[1.0,2.0,3.0,4.0]").is_err());
+    }
+
+    #[test]
+    fn test_point_serialize_array() {
+        let point = Point3::new(1_f64, 2_f64, 3_f64);
+        let json = serde_json::to_string(&point).unwrap();
+        let result: Point3<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, "[1.0,2.0,3.0]");
+        assert_eq!(result, point);
+    }
+
+    #[test]
+    fn test_matrix_serialize_columns() {
+        let matrix = Matrix2x3::new(1_f64, 2_f64, 3_f64, 4_f64, 5_f64, 6_f64);
+        let json = serde_json::to_string(&matrix).unwrap();
+        let result: Matrix2x3<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, "[[1.0,2.0],[3.0,4.0],[5.0,6.0]]");
+        assert_eq!(result, matrix);
+    }
+
+    #[test]
+    fn test_quaternion_serialize_array() {
+        let quaternion = Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let json = serde_json::to_string(&quaternion).unwrap();
+        let result: Quaternion<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, "[1.0,2.0,3.0,4.0]");
+        assert_eq!(result, quaternion);
+    }
+
+    #[test]
+    fn test_complex_round_trip() {
+        let z = Complex::new(1_f64, -2_f64);
+        let json = serde_json::to_string(&z).unwrap();
+        let result: Complex<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(result, z);
+    }
+
+    #[test]
+    fn test_euler_round_trip() {
+        let euler = Euler::new(Radians(1_f64), Radians(2_f64), Radians(3_f64));
+        let json = serde_json::to_string(&euler).unwrap();
+        let result: Euler<Radians<f64>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(result, euler);
+    }
+
+    #[test]
+    fn test_unit_round_trip() {
+        let unit = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let json = serde_json::to_string(&unit).unwrap();
+        let result: Unit<Vector3<f64>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(result, unit);
+    }
+
+    #[test]
+    fn test_unit_rejects_unnormalized_value() {
+        assert!(serde_json::from_str::<Unit<Vector3<f64>>>("[1.0,2.0,3.0]").is_err());
+        assert!(serde_json::from_str::<Unit<Vector3<f64>>>("[0.0,0.0,0.0]").is_err());
+        assert!(serde_json::from_str::<Unit<Quaternion<f64>>>("[0.0,0.0,0.0,1.0]").is_ok());
+    }
+}
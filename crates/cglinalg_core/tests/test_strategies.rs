@@ -0,0 +1,85 @@
+#[cfg(all(test, feature = "proptest"))]
+mod strategies_tests {
+    use approx_cmp::relative_eq;
+    use cglinalg_core::{
+        strategies,
+        Matrix3x3,
+    };
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_scalar_from_range_integer(value in strategies::scalar_from_range(i32::MIN / 4, i32::MAX / 4)) {
+            prop_assert!(value >= i32::MIN / 4 && value <= i32::MAX / 4);
+        }
+
+        #[test]
+        fn test_scalar_signed_from_abs_range_nonzero(value in strategies::scalar_signed_from_abs_range(1_i32, 5_i32)) {
+            prop_assert!(value != 0_i32 && value.abs() <= 5_i32);
+        }
+
+        #[test]
+        fn test_vector_from_range(vector in strategies::vector_from_range::<f64, 4>(-3_f64, 7_f64)) {
+            for component in vector.as_slice() {
+                prop_assert!(*component >= -3_f64 && *component <= 7_f64);
+            }
+        }
+
+        #[test]
+        fn test_matrix_signed_from_abs_range(matrix in strategies::matrix_signed_from_abs_range::<f64, 3, 2>(2_f64, 4_f64)) {
+            for c in 0..2 {
+                for r in 0..3 {
+                    prop_assert!(matrix[c][r].abs() >= 2_f64 && matrix[c][r].abs() <= 4_f64);
+                }
+            }
+        }
+
+        #[test]
+        fn test_unit_vector_unit_norm(vector in strategies::unit_vector::<f64, 3>()) {
+            prop_assert!(relative_eq!(vector.norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_unit_quaternion_unit_norm(quaternion in strategies::unit_quaternion::<f32>()) {
+            prop_assert!(relative_eq!(quaternion.norm(), 1_f32, abs_diff <= 1e-6, relative <= f32::EPSILON));
+        }
+
+        #[test]
+        fn test_unit_complex_unit_modulus(z in strategies::unit_complex::<f64>()) {
+            prop_assert!(relative_eq!(z.modulus(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_invertible_matrix3x3(matrix in strategies::invertible_matrix3x3_from_range(-100_f64, 100_f64)) {
+            prop_assert!(matrix.try_inverse().is_some());
+        }
+
+        #[test]
+        fn test_invertible_matrix4x4(matrix in strategies::invertible_matrix4x4_from_range(-1_f64, 1_f64)) {
+            prop_assert!(matrix.try_inverse().is_some());
+        }
+
+        #[test]
+        fn test_orthonormal_matrix3x3(matrix in strategies::orthonormal_matrix3x3::<f64>()) {
+            let identity = Matrix3x3::identity();
+
+            prop_assert!(relative_eq!(matrix.transpose() * matrix, identity, abs_diff_all <= 1e-12, relative_all <= f64::EPSILON));
+            prop_assert!(relative_eq!(matrix.determinant(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_orthonormal_matrix2x2_preserves_norm(
+            matrix in strategies::orthonormal_matrix2x2::<f64>(),
+            vector in strategies::vector_from_range::<f64, 2>(-10_f64, 10_f64)
+        ) {
+            let result = (matrix * vector).norm();
+            let expected = vector.norm();
+
+            prop_assert!(relative_eq!(result, expected, abs_diff <= 1e-10, relative <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_euler_from_range(euler in strategies::euler_from_range(-1_f64, 1_f64)) {
+            prop_assert!(euler.x.0.abs() <= 1_f64 && euler.y.0.abs() <= 1_f64 && euler.z.0.abs() <= 1_f64);
+        }
+    }}
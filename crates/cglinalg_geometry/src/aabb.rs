@@ -0,0 +1,1075 @@
+use crate::bounded::Bounded;
+use crate::support_map::SupportMap;
+use cglinalg_core::{
+    Const,
+    DimAdd,
+    Matrix,
+    Point,
+    Point3,
+    ShapeConstraint,
+    Vector,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::{
+    Isometry,
+    Similarity,
+    Transform,
+};
+
+use core::fmt;
+
+
+/// An axis-aligned bounding box in two dimensions.
+pub type Aabb2<S> = Aabb<S, 2>;
+
+/// An axis-aligned bounding box in three dimensions.
+pub type Aabb3<S> = Aabb<S, 3>;
+
+
+/// An axis-aligned bounding box.
+///
+/// An axis-aligned bounding box is the set of points
+///
+/// ```text
+/// { p in R^N | forall i in [0, N). min[i] <= p[i] <= max[i] }
+/// ```
+///
+/// where `min` is the **minimum corner** and `max` is the **maximum corner** of the box.
+/// The faces of the box are parallel to the coordinate planes. A box whose corners
+/// coincide along some axis is degenerate, but remains a valid box.
+///
+/// This is the most general axis-aligned bounding box type. The vast majority of
+/// applications should use [`Aabb2`] or [`Aabb3`] instead of this type directly.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Aabb<S, const N: usize> {
+    min: Point<S, N>,
+    max: Point<S, N>,
+}
+
+impl<S, const N: usize> Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new axis-aligned bounding box from its minimum and maximum corners.
+    ///
+    /// The function does not check that `min` is less than or equal to `max`
+    /// componentwise. Use [`Aabb::from_corners`] to construct a box from any two
+    /// opposite corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let min = Point3::new(-1_f64, -2_f64, -3_f64);
+    /// let max = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let aabb = Aabb3::new(min, max);
+    ///
+    /// assert_eq!(aabb.min(), min);
+    /// assert_eq!(aabb.max(), max);
+    /// ```
+    #[inline]
+    pub const fn new(min: Point<S, N>, max: Point<S, N>) -> Self {
+        Self { min, max }
+    }
+
+    /// Construct the smallest axis-aligned bounding box containing two opposite
+    /// corners `a` and `b` of a box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let a = Point3::new(1_f64, -2_f64, 3_f64);
+    /// let b = Point3::new(-1_f64, 2_f64, -3_f64);
+    /// let aabb = Aabb3::from_corners(&a, &b);
+    ///
+    /// assert_eq!(aabb.min(), Point3::new(-1_f64, -2_f64, -3_f64));
+    /// assert_eq!(aabb.max(), Point3::new(1_f64, 2_f64, 3_f64));
+    /// ```
+    #[inline]
+    pub fn from_corners(a: &Point<S, N>, b: &Point<S, N>) -> Self {
+        let min = Point::from_vector(&a.to_vector().component_min(&b.to_vector()));
+        let max = Point::from_vector(&a.to_vector().component_max(&b.to_vector()));
+
+        Self::new(min, max)
+    }
+
+    /// Construct an axis-aligned bounding box from its center and its half extents.
+    ///
+    /// The half extents are the distances from the center of the box to its faces
+    /// along each coordinate axis. The function does not check that the half extents
+    /// are nonnegative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let center = Point3::new(1_f64, 1_f64, 1_f64);
+    /// let half_extents = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let aabb = Aabb3::from_center_half_extents(&center, &half_extents);
+    ///
+    /// assert_eq!(aabb.min(), Point3::new(0_f64, -1_f64, -2_f64));
+    /// assert_eq!(aabb.max(), Point3::new(2_f64, 3_f64, 4_f64));
+    /// ```
+    #[inline]
+    pub fn from_center_half_extents(center: &Point<S, N>, half_extents: &Vector<S, N>) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Construct the smallest axis-aligned bounding box containing every point
+    /// in a slice of points.
+    ///
+    /// The function returns `None` if the slice is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let points = [
+    ///     Point3::new(1_f64, 0_f64, 0_f64),
+    ///     Point3::new(0_f64, -2_f64, 5_f64),
+    ///     Point3::new(-3_f64, 4_f64, 1_f64),
+    /// ];
+    /// let aabb = Aabb3::from_points(&points).unwrap();
+    ///
+    /// assert_eq!(aabb.min(), Point3::new(-3_f64, -2_f64, 0_f64));
+    /// assert_eq!(aabb.max(), Point3::new(1_f64, 4_f64, 5_f64));
+    /// assert!(Aabb3::<f64>::from_points(&[]).is_none());
+    /// ```
+    #[inline]
+    pub fn from_points(points: &[Point<S, N>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let mut result = Self::new(*first, *first);
+        for point in rest.iter() {
+            result = result.union_point(point);
+        }
+
+        Some(result)
+    }
+
+    /// Get the minimum corner of the bounding box.
+    #[inline]
+    pub const fn min(&self) -> Point<S, N> {
+        self.min
+    }
+
+    /// Get the maximum corner of the bounding box.
+    #[inline]
+    pub const fn max(&self) -> Point<S, N> {
+        self.max
+    }
+
+    /// Compute the center of the bounding box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(0_f64, -2_f64, 1_f64), Point3::new(2_f64, 4_f64, 5_f64));
+    ///
+    /// assert_eq!(aabb.center(), Point3::new(1_f64, 1_f64, 3_f64));
+    /// ```
+    #[inline]
+    pub fn center(&self) -> Point<S, N> {
+        let one = S::one();
+        let one_half = one / (one + one);
+
+        self.min + (self.max - self.min) * one_half
+    }
+
+    /// Compute the extents of the bounding box.
+    ///
+    /// The extents of a box are the lengths of its edges along each coordinate axis,
+    /// i.e. the vector from its minimum corner to its maximum corner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(0_f64, -2_f64, 1_f64), Point3::new(2_f64, 4_f64, 5_f64));
+    ///
+    /// assert_eq!(aabb.extents(), Vector3::new(2_f64, 6_f64, 4_f64));
+    /// ```
+    #[inline]
+    pub fn extents(&self) -> Vector<S, N> {
+        self.max - self.min
+    }
+
+    /// Compute the half extents of the bounding box.
+    ///
+    /// The half extents of a box are the distances from its center to its faces
+    /// along each coordinate axis.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(0_f64, -2_f64, 1_f64), Point3::new(2_f64, 4_f64, 5_f64));
+    ///
+    /// assert_eq!(aabb.half_extents(), Vector3::new(1_f64, 3_f64, 2_f64));
+    /// ```
+    #[inline]
+    pub fn half_extents(&self) -> Vector<S, N> {
+        let one = S::one();
+        let one_half = one / (one + one);
+
+        self.extents() * one_half
+    }
+
+    /// Compute the surface area of the bounding box.
+    ///
+    /// The surface area is the total measure of the faces of the box. For a box with
+    /// extents `e`, this is
+    ///
+    /// ```text
+    /// surface_area == sum(i in [0, N)) 2 * product(j in [0, N), j != i) e[j]
+    /// ```
+    ///
+    /// In two dimensions this is the perimeter of the rectangle, and in three dimensions
+    /// this is the usual surface area of the box. The surface area is the cost metric
+    /// of the surface area heuristic for building bounding volume hierarchies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Point3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Aabb2,
+    /// #     Aabb3,
+    /// # };
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 2_f64, 3_f64));
+    ///
+    /// assert_eq!(aabb.surface_area(), 22_f64);
+    ///
+    /// let aabb = Aabb2::new(Point2::new(0_f64, 0_f64), Point2::new(1_f64, 2_f64));
+    ///
+    /// assert_eq!(aabb.surface_area(), 6_f64);
+    /// ```
+    #[inline]
+    pub fn surface_area(&self) -> S {
+        let two = S::one() + S::one();
+        let extents = self.extents();
+        let mut result = S::zero();
+        for i in 0..N {
+            let mut face_area = S::one();
+            for j in 0..N {
+                if j != i {
+                    face_area *= extents[j];
+                }
+            }
+
+            result += two * face_area;
+        }
+
+        result
+    }
+
+    /// Compute the volume of the bounding box.
+    ///
+    /// The volume is the product of the extents of the box. In two dimensions this
+    /// is the area of the rectangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 2_f64, 3_f64));
+    ///
+    /// assert_eq!(aabb.volume(), 6_f64);
+    /// ```
+    #[inline]
+    pub fn volume(&self) -> S {
+        let extents = self.extents();
+        let mut result = S::one();
+        for i in 0..N {
+            result *= extents[i];
+        }
+
+        result
+    }
+
+    /// Compute the smallest axis-aligned bounding box containing two bounding boxes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb1 = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 1_f64));
+    /// let aabb2 = Aabb3::new(Point3::new(2_f64, -1_f64, 0_f64), Point3::new(3_f64, 0_f64, 1_f64));
+    /// let expected = Aabb3::new(Point3::new(0_f64, -1_f64, 0_f64), Point3::new(3_f64, 1_f64, 1_f64));
+    /// let result = aabb1.union(&aabb2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        let min = Point::from_vector(&self.min.to_vector().component_min(&other.min.to_vector()));
+        let max = Point::from_vector(&self.max.to_vector().component_max(&other.max.to_vector()));
+
+        Self::new(min, max)
+    }
+
+    /// Compute the smallest axis-aligned bounding box containing a bounding box
+    /// and a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 1_f64));
+    /// let point = Point3::new(2_f64, -1_f64, 1_f64 / 2_f64);
+    /// let expected = Aabb3::new(Point3::new(0_f64, -1_f64, 0_f64), Point3::new(2_f64, 1_f64, 1_f64));
+    /// let result = aabb.union_point(&point);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn union_point(&self, point: &Point<S, N>) -> Self {
+        let min = Point::from_vector(&self.min.to_vector().component_min(&point.to_vector()));
+        let max = Point::from_vector(&self.max.to_vector().component_max(&point.to_vector()));
+
+        Self::new(min, max)
+    }
+
+    /// Compute the intersection of two bounding boxes.
+    ///
+    /// The function returns `None` if the boxes do not intersect. Boxes that touch
+    /// along a face, an edge, or a corner intersect in a degenerate box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb1 = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(2_f64, 2_f64, 2_f64));
+    /// let aabb2 = Aabb3::new(Point3::new(1_f64, -1_f64, 1_f64), Point3::new(3_f64, 1_f64, 3_f64));
+    /// let expected = Some(Aabb3::new(Point3::new(1_f64, 0_f64, 1_f64), Point3::new(2_f64, 1_f64, 2_f64)));
+    /// let result = aabb1.intersection(&aabb2);
+    ///
+    /// assert_eq!(result, expected);
+    ///
+    /// let aabb3 = Aabb3::new(Point3::new(3_f64, 3_f64, 3_f64), Point3::new(4_f64, 4_f64, 4_f64));
+    ///
+    /// assert_eq!(aabb1.intersection(&aabb3), None);
+    /// ```
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = self.min.to_vector().component_max(&other.min.to_vector());
+        let max = self.max.to_vector().component_min(&other.max.to_vector());
+        for i in 0..N {
+            if min[i] > max[i] {
+                return None;
+            }
+        }
+
+        Some(Self::new(Point::from_vector(&min), Point::from_vector(&max)))
+    }
+
+    /// Determine whether two bounding boxes intersect.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb1 = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(2_f64, 2_f64, 2_f64));
+    /// let aabb2 = Aabb3::new(Point3::new(2_f64, 2_f64, 2_f64), Point3::new(3_f64, 3_f64, 3_f64));
+    /// let aabb3 = Aabb3::new(Point3::new(3_f64, 3_f64, 3_f64), Point3::new(4_f64, 4_f64, 4_f64));
+    ///
+    /// assert!(aabb1.intersects(&aabb2));
+    /// assert!(!aabb1.intersects(&aabb3));
+    /// ```
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        for i in 0..N {
+            if self.min[i] > other.max[i] || other.min[i] > self.max[i] {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Determine whether a bounding box contains a point.
+    ///
+    /// The bounding box is closed, so points on its boundary lie inside of it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 1_f64));
+    ///
+    /// assert!(aabb.contains(&Point3::new(1_f64 / 2_f64, 1_f64 / 2_f64, 1_f64 / 2_f64)));
+    /// assert!(aabb.contains(&Point3::new(1_f64, 0_f64, 1_f64)));
+    /// assert!(!aabb.contains(&Point3::new(2_f64, 1_f64 / 2_f64, 1_f64 / 2_f64)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: &Point<S, N>) -> bool {
+        for i in 0..N {
+            if point[i] < self.min[i] || point[i] > self.max[i] {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Determine whether a bounding box contains another bounding box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb1 = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(4_f64, 4_f64, 4_f64));
+    /// let aabb2 = Aabb3::new(Point3::new(1_f64, 1_f64, 1_f64), Point3::new(2_f64, 2_f64, 2_f64));
+    ///
+    /// assert!(aabb1.contains_aabb(&aabb2));
+    /// assert!(!aabb2.contains_aabb(&aabb1));
+    /// ```
+    #[inline]
+    pub fn contains_aabb(&self, other: &Self) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+
+    /// Compute the point of the bounding box closest to a point.
+    ///
+    /// Points inside the box are their own closest points. Points outside the box
+    /// are clamped to the boundary of the box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 1_f64));
+    /// let point = Point3::new(2_f64, 1_f64 / 2_f64, -3_f64);
+    /// let expected = Point3::new(1_f64, 1_f64 / 2_f64, 0_f64);
+    /// let result = aabb.closest_point(&point);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn closest_point(&self, point: &Point<S, N>) -> Point<S, N> {
+        let mut result = *point;
+        for i in 0..N {
+            result[i] = result[i].max(self.min[i]).min(self.max[i]);
+        }
+
+        result
+    }
+
+    /// Compute the squared distance from a bounding box to a point.
+    ///
+    /// The distance is zero for points inside the box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 1_f64));
+    ///
+    /// assert_eq!(aabb.distance_squared(&Point3::new(3_f64, 1_f64 / 2_f64, -1_f64)), 5_f64);
+    /// assert_eq!(aabb.distance_squared(&Point3::new(1_f64 / 2_f64, 1_f64 / 2_f64, 1_f64 / 2_f64)), 0_f64);
+    /// ```
+    #[inline]
+    pub fn distance_squared(&self, point: &Point<S, N>) -> S {
+        (point - self.closest_point(point)).norm_squared()
+    }
+
+    /// Transform a bounding box by an isometry.
+    ///
+    /// The function returns the smallest axis-aligned bounding box containing
+    /// the image of the bounding box under the isometry. The computation uses
+    /// Arvo's method, which computes the bounds of the image directly from the
+    /// corners of the box without transforming each of its `2^N` corners.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Vector2,
+    /// # };
+    /// # use cglinalg_geometry::Aabb2;
+    /// # use cglinalg_transform::Isometry2;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let aabb = Aabb2::new(Point2::new(-1_f64, -1_f64), Point2::new(1_f64, 1_f64));
+    /// let isometry = Isometry2::from_angle_translation(Degrees(45_f64), &Vector2::new(2_f64, 0_f64));
+    /// let sqrt_2 = f64::sqrt(2_f64);
+    /// let expected = Aabb2::new(Point2::new(2_f64 - sqrt_2, -sqrt_2), Point2::new(2_f64 + sqrt_2, sqrt_2));
+    /// let result = aabb.transform_isometry(&isometry);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn transform_isometry(&self, isometry: &Isometry<S, N>) -> Self {
+        let rotation = isometry.rotation().matrix();
+        let translation = isometry.translation().vector();
+
+        self.transform_affine_parts(|row, column| rotation[column][row], translation)
+    }
+
+    /// Transform a bounding box by a similarity transformation.
+    ///
+    /// The function returns the smallest axis-aligned bounding box containing
+    /// the image of the bounding box under the similarity transformation using
+    /// Arvo's method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Vector2,
+    /// # };
+    /// # use cglinalg_geometry::Aabb2;
+    /// # use cglinalg_transform::{
+    /// #     Rotation2,
+    /// #     Similarity2,
+    /// #     Translation2,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let aabb = Aabb2::new(Point2::new(-1_f64, -1_f64), Point2::new(1_f64, 1_f64));
+    /// let similarity = Similarity2::from_parts(
+    ///     &Translation2::new(2_f64, 0_f64),
+    ///     &Rotation2::from_angle(Degrees(45_f64)),
+    ///     3_f64,
+    /// );
+    /// let sqrt_2 = f64::sqrt(2_f64);
+    /// let expected = Aabb2::new(Point2::new(2_f64 - 3_f64 * sqrt_2, -3_f64 * sqrt_2), Point2::new(2_f64 + 3_f64 * sqrt_2, 3_f64 * sqrt_2));
+    /// let result = aabb.transform_similarity(&similarity);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn transform_similarity(&self, similarity: &Similarity<S, N>) -> Self {
+        let scale = similarity.scale();
+        let rotation = similarity.rotation().matrix();
+        let translation = similarity.translation().vector();
+
+        self.transform_affine_parts(|row, column| scale * rotation[column][row], translation)
+    }
+
+    /// Transform a bounding box by a generic transformation.
+    ///
+    /// The function returns the smallest axis-aligned bounding box containing
+    /// the image of the bounding box under the transformation. Affine
+    /// transformations use Arvo's method. For a projective transformation, i.e.
+    /// one whose matrix has a bottom row other than `[0, ..., 0, 1]`, the image
+    /// of the box is the convex hull of the images of its corners, so the function
+    /// bounds the projected corners instead.
+    ///
+    /// The function returns `None` if the box touches or crosses the plane that a
+    /// projective transformation sends to infinity, since the image of the box is
+    /// unbounded in that case.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     Point2,
+    /// # };
+    /// # use cglinalg_geometry::Aabb2;
+    /// # use cglinalg_transform::Transform2;
+    /// #
+    /// let aabb = Aabb2::new(Point2::new(0_f64, 0_f64), Point2::new(1_f64, 1_f64));
+    /// #[rustfmt::skip]
+    /// let transform = Transform2::from_matrix_unchecked(Matrix3x3::new(
+    ///     1_f64, 0_f64, 0_f64,
+    ///     2_f64, 1_f64, 0_f64,
+    ///     5_f64, 1_f64, 1_f64,
+    /// ));
+    /// let expected = Aabb2::new(Point2::new(5_f64, 1_f64), Point2::new(8_f64, 2_f64));
+    /// let result = aabb.transform(&transform).unwrap();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transform<const NPLUS1: usize>(&self, transform: &Transform<S, N, NPLUS1>) -> Option<Self>
+    where
+        ShapeConstraint: DimAdd<Const<N>, Const<1>, Output = Const<NPLUS1>>,
+        ShapeConstraint: DimAdd<Const<1>, Const<N>, Output = Const<NPLUS1>>,
+    {
+        let matrix = transform.matrix();
+        let is_affine = (0..N).all(|column| matrix[column][N].is_zero()) && matrix[N][N] == S::one();
+        if is_affine {
+            Some(self.transform_affine_matrix(matrix))
+        } else {
+            self.transform_projective_matrix(matrix)
+        }
+    }
+
+    /// Transform a bounding box by an affine matrix in homogeneous coordinates,
+    /// e.g. a [`Matrix4x4`] in three dimensions.
+    ///
+    /// The function returns the smallest axis-aligned bounding box containing
+    /// the image of the bounding box under the affine matrix using Arvo's
+    /// method. The function does not check that the matrix is affine.
+    ///
+    /// # Discussion
+    ///
+    /// Let `M` be the linear part of the affine matrix, and let `t` be its
+    /// translation part. Each coordinate of the image of a point `p` inside the box
+    /// is a sum
+    ///
+    /// ```text
+    /// (M * p + t)[i] == t[i] + sum(j in [0, N)) M[i, j] * p[j]
+    /// ```
+    ///
+    /// whose terms are independent of each other. Each term is extremal at one of
+    /// the endpoints `min[j]` or `max[j]`, so the bounds of the image are
+    ///
+    /// ```text
+    /// new_min[i] == t[i] + sum(j in [0, N)) min(M[i, j] * min[j], M[i, j] * max[j])
+    /// new_max[i] == t[i] + sum(j in [0, N)) max(M[i, j] * min[j], M[i, j] * max[j])
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Matrix4x4,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Aabb3;
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(1_f64, 1_f64, 1_f64));
+    /// let matrix = Matrix4x4::from_affine_translation(&Vector3::new(1_f64, 2_f64, 3_f64))
+    ///     * Matrix4x4::from_affine_nonuniform_scale(&Vector3::new(2_f64, 3_f64, 4_f64));
+    /// let expected = Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(3_f64, 5_f64, 7_f64));
+    /// let result = aabb.transform_affine_matrix(&matrix);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    ///
+    /// [`Matrix4x4`]: cglinalg_core::Matrix4x4
+    #[inline]
+    pub fn transform_affine_matrix<const NPLUS1: usize>(&self, matrix: &Matrix<S, NPLUS1, NPLUS1>) -> Self
+    where
+        ShapeConstraint: DimAdd<Const<N>, Const<1>, Output = Const<NPLUS1>>,
+        ShapeConstraint: DimAdd<Const<1>, Const<N>, Output = Const<NPLUS1>>,
+    {
+        let mut translation = Vector::zero();
+        for i in 0..N {
+            translation[i] = matrix[N][i];
+        }
+
+        self.transform_affine_parts(|row, column| matrix[column][row], &translation)
+    }
+
+    /// Transform a bounding box by a projective matrix in homogeneous coordinates
+    /// by bounding the images of its corners.
+    ///
+    /// The function returns `None` if the `w` components of the images of the
+    /// corners are not all nonzero with the same sign.
+    fn transform_projective_matrix<const NPLUS1: usize>(&self, matrix: &Matrix<S, NPLUS1, NPLUS1>) -> Option<Self> {
+        let mut result: Option<Self> = None;
+        let mut previous_w = S::zero();
+        for corner_index in 0..(1_usize << N) {
+            let mut corner = self.min;
+            for j in 0..N {
+                if (corner_index >> j) & 1 == 1 {
+                    corner[j] = self.max[j];
+                }
+            }
+
+            let mut homogeneous = matrix[N];
+            for j in 0..N {
+                homogeneous += matrix[j] * corner[j];
+            }
+
+            let w = homogeneous[N];
+            if w.is_zero() || (w * previous_w) < S::zero() {
+                return None;
+            }
+            previous_w = w;
+
+            let mut image = Point::origin();
+            for i in 0..N {
+                image[i] = homogeneous[i] / w;
+            }
+
+            result = Some(match result {
+                Some(aabb) => aabb.union_point(&image),
+                None => Self::new(image, image),
+            });
+        }
+
+        result
+    }
+
+    /// Transform a bounding box by the affine transformation with linear part
+    /// whose element at `(row, column)` is `linear(row, column)`, and translation
+    /// part `translation`, using Arvo's method.
+    #[inline]
+    fn transform_affine_parts<F>(&self, linear: F, translation: &Vector<S, N>) -> Self
+    where
+        F: Fn(usize, usize) -> S,
+    {
+        let mut min = Point::from_vector(translation);
+        let mut max = min;
+        for i in 0..N {
+            for j in 0..N {
+                let element = linear(i, j);
+                let a = element * self.min[j];
+                let b = element * self.max[j];
+                min[i] += a.min(b);
+                max[i] += a.max(b);
+            }
+        }
+
+        Self::new(min, max)
+    }
+}
+
+impl<S, const N: usize> fmt::Display for Aabb<S, N>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Aabb{} [min={}, max={}]", N, self.min, self.max)
+    }
+}
+
+
+impl<S> SupportMap<S> for Aabb<S, 3>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S> {
+        let select = |i: usize| if direction[i] >= S::zero() { self.max[i] } else { self.min[i] };
+
+        Point3::new(select(0), select(1), select(2))
+    }
+}
+
+impl<S> Bounded<S> for Aabb<S, 3>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn aabb(&self) -> Aabb3<S> {
+        *self
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AabbTol<S, const N: usize> {
+    min: Vector<S, N>,
+    max: Vector<S, N>,
+}
+
+impl<S, const N: usize> AabbTol<S, N> {
+    #[inline]
+    pub const fn from_parts(min: Vector<S, N>, max: Vector<S, N>) -> Self {
+        Self { min, max }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AabbDiff<S, const N: usize> {
+    min: Vector<S, N>,
+    max: Vector<S, N>,
+}
+
+impl<S, const N: usize> AabbDiff<S, N> {
+    #[inline]
+    const fn from_parts(min: Vector<S, N>, max: Vector<S, N>) -> Self {
+        Self { min, max }
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = AabbTol<<S as approx_cmp::AbsDiffEq>::Tolerance, N>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.min, &other.min, &max_abs_diff.min)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.max, &other.max, &max_abs_diff.max)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffAllEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.min, &other.min, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.max, &other.max, max_abs_diff)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = AabbDiff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff, N>;
+    type DebugTolerance = AabbTol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let min = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.min, &other.min);
+        let max = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.max, &other.max);
+
+        AabbDiff::from_parts(min, max)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let min = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.min, &other.min, &max_abs_diff.min);
+        let max = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.max, &other.max, &max_abs_diff.max);
+
+        AabbTol::from_parts(min, max)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffAllEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = AabbTol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let min = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.min, &other.min, max_abs_diff);
+        let max = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.max, &other.max, max_abs_diff);
+
+        AabbTol::from_parts(min, max)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = AabbTol<<S as approx_cmp::RelativeEq>::Tolerance, N>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.min, &other.min, &max_abs_diff.min, &max_relative.min)
+            && approx_cmp::RelativeEq::relative_eq(&self.max, &other.max, &max_abs_diff.max, &max_relative.max)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeAllEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.min, &other.min, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.max, &other.max, max_abs_diff, max_relative)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = AabbDiff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff, N>;
+    type DebugTolerance = AabbTol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let min = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.min, &other.min);
+        let max = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.max, &other.max);
+
+        AabbDiff::from_parts(min, max)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let min = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.min, &other.min, &max_abs_diff.min);
+        let max = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.max, &other.max, &max_abs_diff.max);
+
+        AabbTol::from_parts(min, max)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let min = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.min, &other.min, &max_relative.min);
+        let max = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.max, &other.max, &max_relative.max);
+
+        AabbTol::from_parts(min, max)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeAllEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = AabbTol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let min = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.min, &other.min, max_abs_diff);
+        let max = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.max, &other.max, max_abs_diff);
+
+        AabbTol::from_parts(min, max)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let min = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.min, &other.min, max_relative);
+        let max = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.max, &other.max, max_relative);
+
+        AabbTol::from_parts(min, max)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = AabbTol<<S as approx_cmp::UlpsEq>::Tolerance, N>;
+    type UlpsTolerance = AabbTol<<S as approx_cmp::UlpsEq>::UlpsTolerance, N>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.min, &other.min, &max_abs_diff.min, &max_ulps.min)
+            && approx_cmp::UlpsEq::ulps_eq(&self.max, &other.max, &max_abs_diff.max, &max_ulps.max)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsAllEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.min, &other.min, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.max, &other.max, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = AabbDiff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff, N>;
+    type DebugUlpsDiff = AabbDiff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff, N>;
+    type DebugTolerance = AabbTol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance, N>;
+    type DebugUlpsTolerance = AabbTol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let min = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.min, &other.min);
+        let max = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.max, &other.max);
+
+        AabbDiff::from_parts(min, max)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let min = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.min, &other.min);
+        let max = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.max, &other.max);
+
+        AabbDiff::from_parts(min, max)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let min = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.min, &other.min, &max_abs_diff.min);
+        let max = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.max, &other.max, &max_abs_diff.max);
+
+        AabbTol::from_parts(min, max)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let min = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.min, &other.min, &max_ulps.min);
+        let max = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.max, &other.max, &max_ulps.max);
+
+        AabbTol::from_parts(min, max)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsAllEq for Aabb<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = AabbTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance, N>;
+    type AllDebugUlpsTolerance = AabbTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let min = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.min, &other.min, max_abs_diff);
+        let max = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.max, &other.max, max_abs_diff);
+
+        AabbTol::from_parts(min, max)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let min = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.min, &other.min, max_ulps);
+        let max = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.max, &other.max, max_ulps);
+
+        AabbTol::from_parts(min, max)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::aabb::Aabb3;
+use cglinalg_numeric::SimdScalarFloat;
+
+
+/// A shape that can report an axis-aligned bounding box enclosing it.
+///
+/// Acceleration structures such as [`Bvh`](crate::Bvh) organize arbitrary objects by
+/// their bounding boxes, so any object implementing this trait can be stored in them.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::Point3;
+/// # use cglinalg_geometry::{
+/// #     Aabb3,
+/// #     Bounded,
+/// #     Sphere3,
+/// # };
+/// #
+/// let sphere = Sphere3::new(Point3::new(1_f64, 2_f64, 3_f64), 1_f64);
+/// let expected = Aabb3::new(Point3::new(0_f64, 1_f64, 2_f64), Point3::new(2_f64, 3_f64, 4_f64));
+///
+/// assert_eq!(sphere.aabb(), expected);
+/// ```
+pub trait Bounded<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute an axis-aligned bounding box enclosing the shape.
+    ///
+    /// The bounding box need not be the smallest one enclosing the shape, but tighter
+    /// bounding boxes make for faster queries.
+    fn aabb(&self) -> Aabb3<S>;
+}
+
+impl<S, T> Bounded<S> for &T
+where
+    S: SimdScalarFloat,
+    T: Bounded<S> + ?Sized,
+{
+    #[inline]
+    fn aabb(&self) -> Aabb3<S> {
+        (**self).aabb()
+    }
+}
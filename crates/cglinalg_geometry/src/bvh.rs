@@ -0,0 +1,544 @@
+use crate::aabb::Aabb3;
+use crate::bounded::Bounded;
+use crate::ray::{
+    Ray3,
+    RayHit,
+};
+use cglinalg_core::Point3;
+use cglinalg_numeric::SimdScalarFloat;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+
+/// The number of bins along each axis used to evaluate the surface area heuristic.
+const BVH_BIN_COUNT: usize = 16;
+
+/// The largest number of objects stored in a leaf node when splitting the node is
+/// not worth it according to the surface area heuristic.
+const BVH_MAX_LEAF_SIZE: usize = 8;
+
+
+/// A node of a bounding volume hierarchy.
+///
+/// An interior node has `count == 0`, and its children are the nodes `first` and
+/// `first + 1`. A leaf node refers to the objects `indices[first..(first + count)]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct BvhNode<S> {
+    aabb: Aabb3<S>,
+    first: usize,
+    count: usize,
+}
+
+impl<S> BvhNode<S> {
+    #[inline]
+    const fn is_leaf(&self) -> bool {
+        self.count > 0
+    }
+}
+
+/// The bins of the surface area heuristic along one axis.
+#[derive(Copy, Clone, Debug)]
+struct BvhBin<S> {
+    aabb: Option<Aabb3<S>>,
+    count: usize,
+}
+
+
+/// A bounding volume hierarchy over a set of objects in three dimensions.
+///
+/// A bounding volume hierarchy is a binary tree whose leaves hold the objects, and
+/// whose nodes each hold an axis-aligned bounding box enclosing every object below the
+/// node. Queries skip every subtree whose bounding box misses the query, so a query
+/// usually touches a small fraction of the objects.
+///
+/// The hierarchy only stores the indices of the objects in the slice it was built
+/// from, so queries that need the objects themselves take a closure that receives the
+/// index of each candidate object. The tree is built top down, splitting each node
+/// where the binned **surface area heuristic** estimates the cheapest ray traversal.
+///
+/// This type requires the `alloc` feature.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     Point3,
+/// #     Vector3,
+/// # };
+/// # use cglinalg_geometry::{
+/// #     Bvh,
+/// #     Ray3,
+/// #     Sphere3,
+/// # };
+/// #
+/// let spheres = [
+///     Sphere3::new(Point3::new(0_f64, 0_f64, 5_f64), 1_f64),
+///     Sphere3::new(Point3::new(0_f64, 0_f64, 10_f64), 1_f64),
+///     Sphere3::new(Point3::new(4_f64, 0_f64, 5_f64), 1_f64),
+/// ];
+/// let bvh = Bvh::build(&spheres);
+/// let ray = Ray3::new(Point3::origin(), Vector3::unit_z());
+/// let (index, hit) = bvh
+///     .intersect_ray_nearest(&ray, |i| ray.intersect_sphere(&spheres[i]))
+///     .unwrap();
+///
+/// assert_eq!(index, 0);
+/// assert_eq!(hit.distance(), 4_f64);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bvh<S> {
+    nodes: Vec<BvhNode<S>>,
+    indices: Vec<usize>,
+}
+
+impl<S> Bvh<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Build a bounding volume hierarchy over a slice of objects.
+    ///
+    /// The hierarchy refers to each object by its index in `objects`.
+    pub fn build<T>(objects: &[T]) -> Self
+    where
+        T: Bounded<S>,
+    {
+        let aabbs: Vec<Aabb3<S>> = objects.iter().map(|object| object.aabb()).collect();
+        let centroids: Vec<Point3<S>> = aabbs.iter().map(|aabb| aabb.center()).collect();
+        let mut bvh = Self {
+            nodes: Vec::with_capacity(2 * objects.len()),
+            indices: (0..objects.len()).collect(),
+        };
+        if objects.is_empty() {
+            return bvh;
+        }
+
+        bvh.nodes.push(BvhNode {
+            aabb: aabbs[0],
+            first: 0,
+            count: objects.len(),
+        });
+
+        // The surface area heuristic can split off a single object at every level on
+        // skewed inputs, so the tree can be as deep as the number of objects. Building
+        // it with an explicit stack keeps the call stack depth constant.
+        let mut stack = vec![0];
+        while let Some(node_index) = stack.pop() {
+            if let Some(left_index) = bvh.subdivide(node_index, &aabbs, &centroids) {
+                stack.push(left_index + 1);
+                stack.push(left_index);
+            }
+        }
+
+        bvh
+    }
+
+    /// Compute the bounds of a node, and split it if the surface area heuristic says
+    /// that is worthwhile.
+    ///
+    /// The function returns the index of the left child of the node if it splits the
+    /// node. The right child follows the left child.
+    fn subdivide(&mut self, node_index: usize, aabbs: &[Aabb3<S>], centroids: &[Point3<S>]) -> Option<usize> {
+        let first = self.nodes[node_index].first;
+        let count = self.nodes[node_index].count;
+        let indices = &mut self.indices[first..(first + count)];
+        let aabb = indices[1..]
+            .iter()
+            .fold(aabbs[indices[0]], |acc, index| acc.union(&aabbs[*index]));
+        self.nodes[node_index].aabb = aabb;
+        if count == 1 {
+            return None;
+        }
+
+        let centroid_bounds = indices[1..].iter().fold(Aabb3::new(centroids[indices[0]], centroids[indices[0]]), |acc, index| {
+            acc.union_point(&centroids[*index])
+        });
+        let Some((axis, split, split_cost)) = Self::find_split(indices, aabbs, centroids, &centroid_bounds) else {
+            // Every centroid coincides, so no split separates the objects.
+            return None;
+        };
+        let leaf_cost = aabb.surface_area() * cglinalg_numeric::cast(count as f64);
+        if split_cost >= leaf_cost && count <= BVH_MAX_LEAF_SIZE {
+            return None;
+        }
+
+        // Partition the objects of the node into the bins on either side of the split.
+        let mut left_count = 0;
+        for i in 0..count {
+            if Self::bin_index(&centroids[indices[i]], &centroid_bounds, axis) < split {
+                indices.swap(i, left_count);
+                left_count += 1;
+            }
+        }
+        if left_count == 0 || left_count == count {
+            return None;
+        }
+
+        let left_index = self.nodes.len();
+        self.nodes.push(BvhNode {
+            aabb,
+            first,
+            count: left_count,
+        });
+        self.nodes.push(BvhNode {
+            aabb,
+            first: first + left_count,
+            count: count - left_count,
+        });
+        self.nodes[node_index].first = left_index;
+        self.nodes[node_index].count = 0;
+
+        Some(left_index)
+    }
+
+    /// Find the bin boundary along the axes of the centroid bounds with the lowest
+    /// surface area heuristic cost.
+    ///
+    /// The function returns the axis, the index of the first bin on the right side of
+    /// the split, and the cost of the split.
+    fn find_split(indices: &[usize], aabbs: &[Aabb3<S>], centroids: &[Point3<S>], centroid_bounds: &Aabb3<S>) -> Option<(usize, usize, S)> {
+        let extents = centroid_bounds.extents();
+        let mut result = None;
+        let mut result_cost = S::infinity();
+        for axis in 0..3 {
+            if extents[axis].is_zero() {
+                continue;
+            }
+
+            let mut bins = [BvhBin { aabb: None, count: 0 }; BVH_BIN_COUNT];
+            for index in indices.iter() {
+                let bin = &mut bins[Self::bin_index(&centroids[*index], centroid_bounds, axis)];
+                bin.aabb = Some(bin.aabb.map_or(aabbs[*index], |aabb| aabb.union(&aabbs[*index])));
+                bin.count += 1;
+            }
+
+            // Sweep from the right to accumulate the cost of the right side of each split,
+            // then sweep from the left to find the cheapest split.
+            let mut right_costs = [S::zero(); BVH_BIN_COUNT];
+            let mut right_aabb: Option<Aabb3<S>> = None;
+            let mut right_count = 0;
+            for split in (1..BVH_BIN_COUNT).rev() {
+                right_aabb = Self::union_bin(right_aabb, bins[split].aabb);
+                right_count += bins[split].count;
+                right_costs[split] = right_aabb.map_or(S::zero(), |aabb| aabb.surface_area() * cglinalg_numeric::cast(right_count as f64));
+            }
+
+            let mut left_aabb: Option<Aabb3<S>> = None;
+            let mut left_count = 0;
+            for split in 1..BVH_BIN_COUNT {
+                left_aabb = Self::union_bin(left_aabb, bins[split - 1].aabb);
+                left_count += bins[split - 1].count;
+                if left_count == 0 || left_count == indices.len() {
+                    continue;
+                }
+
+                let left_cost = left_aabb.map_or(S::zero(), |aabb| aabb.surface_area() * cglinalg_numeric::cast(left_count as f64));
+                let cost = left_cost + right_costs[split];
+                if cost < result_cost {
+                    result = Some((axis, split, cost));
+                    result_cost = cost;
+                }
+            }
+        }
+
+        result
+    }
+
+    #[inline]
+    fn union_bin(aabb: Option<Aabb3<S>>, other: Option<Aabb3<S>>) -> Option<Aabb3<S>> {
+        match (aabb, other) {
+            (Some(aabb), Some(other)) => Some(aabb.union(&other)),
+            (Some(aabb), None) => Some(aabb),
+            (None, other) => other,
+        }
+    }
+
+    /// Compute the bin containing a centroid along an axis of the centroid bounds.
+    #[inline]
+    fn bin_index(centroid: &Point3<S>, centroid_bounds: &Aabb3<S>, axis: usize) -> usize {
+        let min = centroid_bounds.min()[axis];
+        let extent = centroid_bounds.max()[axis] - min;
+        let bin_count: S = cglinalg_numeric::cast(BVH_BIN_COUNT as f64);
+        let bin = ((centroid[axis] - min) / extent * bin_count).to_usize().unwrap_or(0);
+
+        bin.min(BVH_BIN_COUNT - 1)
+    }
+
+    /// Get the number of objects in the hierarchy.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Determine whether the hierarchy contains no objects.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.indices.is_empty()
+    }
+
+    /// Get the bounding box enclosing every object in the hierarchy.
+    ///
+    /// The function returns `None` if the hierarchy is empty.
+    #[inline]
+    pub fn aabb(&self) -> Option<Aabb3<S>> {
+        self.nodes.first().map(|node| node.aabb)
+    }
+
+    /// Update the bounding boxes of the hierarchy after the objects move.
+    ///
+    /// Refitting keeps the structure of the tree, so it is much faster than
+    /// rebuilding the hierarchy, but the quality of the tree degrades as the objects
+    /// move further from where they were when the hierarchy was built. The slice
+    /// `objects` must hold the same objects, in the same order, as the slice the
+    /// hierarchy was built from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Bvh,
+    /// #     Sphere3,
+    /// # };
+    /// #
+    /// let mut spheres = [
+    ///     Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 1_f64),
+    ///     Sphere3::new(Point3::new(5_f64, 0_f64, 0_f64), 1_f64),
+    /// ];
+    /// let mut bvh = Bvh::build(&spheres);
+    /// spheres[1] = Sphere3::new(Point3::new(5_f64, 5_f64, 0_f64), 1_f64);
+    /// bvh.refit(&spheres);
+    ///
+    /// let expected = Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(6_f64, 6_f64, 1_f64));
+    ///
+    /// assert_eq!(bvh.aabb(), Some(expected));
+    /// ```
+    pub fn refit<T>(&mut self, objects: &[T])
+    where
+        T: Bounded<S>,
+    {
+        // Children always come after their parents, so visiting the nodes in reverse
+        // order updates both children of a node before the node itself.
+        for node_index in (0..self.nodes.len()).rev() {
+            let node = self.nodes[node_index];
+            let aabb = if node.is_leaf() {
+                let indices = &self.indices[node.first..(node.first + node.count)];
+                indices[1..]
+                    .iter()
+                    .fold(objects[indices[0]].aabb(), |acc, index| acc.union(&objects[*index].aabb()))
+            } else {
+                self.nodes[node.first].aabb.union(&self.nodes[node.first + 1].aabb)
+            };
+            self.nodes[node_index].aabb = aabb;
+        }
+    }
+
+    /// Compute the ray parameter at which a ray enters a bounding box, if the ray
+    /// meets the box at all.
+    #[inline]
+    fn ray_entry(ray: &Ray3<S>, aabb: &Aabb3<S>) -> Option<S> {
+        if aabb.contains(&ray.origin()) {
+            return Some(S::zero());
+        }
+
+        ray.intersect_aabb(aabb).map(|hit| hit.distance())
+    }
+
+    /// Find the nearest object hit by a ray.
+    ///
+    /// The closure `intersect` computes the intersection of the ray with the object
+    /// with the given index, and the function returns the index and the hit of the
+    /// object with the smallest hit distance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Bvh,
+    /// #     Ray3,
+    /// #     Triangle3,
+    /// # };
+    /// #
+    /// let triangles: [Triangle3<f64>; 4] = core::array::from_fn(|i| {
+    ///     let z = 2_f64 * (4 - i) as f64;
+    ///     Triangle3::new(Point3::new(-1_f64, -1_f64, z), Point3::new(1_f64, -1_f64, z), Point3::new(0_f64, 1_f64, z))
+    /// });
+    /// let bvh = Bvh::build(&triangles);
+    /// let ray = Ray3::new(Point3::origin(), Vector3::unit_z());
+    /// let (index, hit) = bvh
+    ///     .intersect_ray_nearest(&ray, |i| {
+    ///         let triangle = &triangles[i];
+    ///         ray.intersect_triangle(&triangle.a(), &triangle.b(), &triangle.c()).map(|hit| hit.to_ray_hit())
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(index, 3);
+    /// assert_eq!(hit.distance(), 2_f64);
+    /// ```
+    pub fn intersect_ray_nearest<F>(&self, ray: &Ray3<S>, mut intersect: F) -> Option<(usize, RayHit<S, 3>)>
+    where
+        F: FnMut(usize) -> Option<RayHit<S, 3>>,
+    {
+        let root = self.nodes.first()?;
+        let root_entry = Self::ray_entry(ray, &root.aabb)?;
+        let mut result: Option<(usize, RayHit<S, 3>)> = None;
+        let mut stack = vec![(0, root_entry)];
+        while let Some((node_index, entry)) = stack.pop() {
+            let nearest = result.map_or(S::infinity(), |(_, hit)| hit.distance());
+            if entry > nearest {
+                continue;
+            }
+
+            let node = &self.nodes[node_index];
+            if node.is_leaf() {
+                for index in self.indices[node.first..(node.first + node.count)].iter() {
+                    if let Some(hit) = intersect(*index) {
+                        let nearest = result.map_or(S::infinity(), |(_, hit)| hit.distance());
+                        if hit.distance() < nearest {
+                            result = Some((*index, hit));
+                        }
+                    }
+                }
+
+                continue;
+            }
+
+            // Visit the nearer child first, so that its hits prune the farther child.
+            let left_entry = Self::ray_entry(ray, &self.nodes[node.first].aabb);
+            let right_entry = Self::ray_entry(ray, &self.nodes[node.first + 1].aabb);
+            match (left_entry, right_entry) {
+                (Some(left), Some(right)) if left <= right => {
+                    stack.push((node.first + 1, right));
+                    stack.push((node.first, left));
+                }
+                (Some(left), Some(right)) => {
+                    stack.push((node.first, left));
+                    stack.push((node.first + 1, right));
+                }
+                (Some(left), None) => stack.push((node.first, left)),
+                (None, Some(right)) => stack.push((node.first + 1, right)),
+                (None, None) => {}
+            }
+        }
+
+        result
+    }
+
+    /// Find every object hit by a ray.
+    ///
+    /// The closure `intersect` computes the intersection of the ray with the object
+    /// with the given index. The function returns the index and the hit of each object
+    /// hit by the ray, sorted by increasing hit distance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Bvh,
+    /// #     Ray3,
+    /// #     Sphere3,
+    /// # };
+    /// #
+    /// let spheres = [
+    ///     Sphere3::new(Point3::new(0_f64, 0_f64, 10_f64), 1_f64),
+    ///     Sphere3::new(Point3::new(3_f64, 0_f64, 5_f64), 1_f64),
+    ///     Sphere3::new(Point3::new(0_f64, 0_f64, 5_f64), 1_f64),
+    /// ];
+    /// let bvh = Bvh::build(&spheres);
+    /// let ray = Ray3::new(Point3::origin(), Vector3::unit_z());
+    /// let hits = bvh.intersect_ray_all(&ray, |i| ray.intersect_sphere(&spheres[i]));
+    /// let indices: Vec<usize> = hits.iter().map(|(index, _)| *index).collect();
+    ///
+    /// assert_eq!(indices, vec![2, 0]);
+    /// ```
+    pub fn intersect_ray_all<F>(&self, ray: &Ray3<S>, mut intersect: F) -> Vec<(usize, RayHit<S, 3>)>
+    where
+        F: FnMut(usize) -> Option<RayHit<S, 3>>,
+    {
+        let mut result = Vec::new();
+        self.visit(|aabb| Self::ray_entry(ray, aabb).is_some(), |index| {
+            if let Some(hit) = intersect(index) {
+                result.push((index, hit));
+            }
+        });
+        result.sort_by(|(_, hit1), (_, hit2)| hit1.distance().partial_cmp(&hit2.distance()).unwrap_or(core::cmp::Ordering::Equal));
+
+        result
+    }
+
+    /// Find every object whose bounding box overlaps a bounding box.
+    ///
+    /// The function returns the indices of the objects in increasing order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Bvh,
+    /// #     Sphere3,
+    /// # };
+    /// #
+    /// let spheres: [Sphere3<f64>; 10] = core::array::from_fn(|i| {
+    ///     Sphere3::new(Point3::new(3_f64 * i as f64, 0_f64, 0_f64), 1_f64)
+    /// });
+    /// let bvh = Bvh::build(&spheres);
+    /// let aabb = Aabb3::new(Point3::new(5_f64, -1_f64, -1_f64), Point3::new(10_f64, 1_f64, 1_f64));
+    ///
+    /// assert_eq!(bvh.query_aabb(&aabb), vec![2, 3]);
+    /// ```
+    pub fn query_aabb(&self, aabb: &Aabb3<S>) -> Vec<usize> {
+        let mut result = Vec::new();
+        self.visit(|node_aabb| node_aabb.intersects(aabb), |index| result.push(index));
+        result.sort_unstable();
+
+        result
+    }
+
+    /// Visit the objects in every leaf whose path from the root only passes through
+    /// nodes whose bounding boxes satisfy a predicate.
+    fn visit<P, F>(&self, mut predicate: P, mut visitor: F)
+    where
+        P: FnMut(&Aabb3<S>) -> bool,
+        F: FnMut(usize),
+    {
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index];
+            if !predicate(&node.aabb) {
+                continue;
+            }
+
+            if node.is_leaf() {
+                for index in self.indices[node.first..(node.first + node.count)].iter() {
+                    visitor(*index);
+                }
+            } else {
+                stack.push(node.first + 1);
+                stack.push(node.first);
+            }
+        }
+    }
+}
+
+impl<S> Default for Bvh<S> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            indices: Vec::new(),
+        }
+    }
+}
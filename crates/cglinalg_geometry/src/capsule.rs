@@ -0,0 +1,512 @@
+use crate::aabb::Aabb3;
+use crate::bounded::Bounded;
+use crate::segment::Segment3;
+use crate::support_map::SupportMap;
+use cglinalg_core::{
+    Point3,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::Isometry3;
+
+use core::fmt;
+
+
+/// A capsule in three dimensions.
+///
+/// A capsule is the set of points
+///
+/// ```text
+/// { p | distance(p, segment) <= radius }
+/// ```
+///
+/// where `segment` is the line segment between the endpoints `start` and `end` of the
+/// capsule. Equivalently, a capsule is a cylinder capped by two hemispheres, or the
+/// sphere of radius `radius` swept along the segment.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capsule3<S> {
+    start: Point3<S>,
+    end: Point3<S>,
+    radius: S,
+}
+
+impl<S> Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new capsule from the endpoints of its core segment, and its radius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Capsule3;
+    /// #
+    /// let start = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let end = Point3::new(0_f64, 2_f64, 0_f64);
+    /// let capsule = Capsule3::new(start, end, 1_f64);
+    ///
+    /// assert_eq!(capsule.start(), start);
+    /// assert_eq!(capsule.end(), end);
+    /// assert_eq!(capsule.radius(), 1_f64);
+    /// ```
+    #[inline]
+    pub const fn new(start: Point3<S>, end: Point3<S>, radius: S) -> Self {
+        Self { start, end, radius }
+    }
+
+    /// Get the starting point of the core segment of the capsule.
+    #[inline]
+    pub const fn start(&self) -> Point3<S> {
+        self.start
+    }
+
+    /// Get the ending point of the core segment of the capsule.
+    #[inline]
+    pub const fn end(&self) -> Point3<S> {
+        self.end
+    }
+
+    /// Get the radius of the capsule.
+    #[inline]
+    pub const fn radius(&self) -> S {
+        self.radius
+    }
+
+    /// Get the core segment of the capsule.
+    #[inline]
+    pub const fn segment(&self) -> Segment3<S> {
+        Segment3::new(self.start, self.end)
+    }
+
+    /// Determine whether a capsule contains a point.
+    ///
+    /// The capsule is closed, so points on the boundary of the capsule are contained
+    /// in the capsule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Capsule3;
+    /// #
+    /// let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 2_f64, 0_f64), 1_f64);
+    ///
+    /// assert!(capsule.contains(&Point3::new(1_f64, 1_f64, 0_f64)));
+    /// assert!(capsule.contains(&Point3::new(0_f64, 3_f64, 0_f64)));
+    /// assert!(!capsule.contains(&Point3::new(1_f64, 3_f64, 0_f64)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: &Point3<S>) -> bool {
+        self.segment().distance_squared(point) <= self.radius * self.radius
+    }
+
+    /// Compute the closest point of a capsule to a point.
+    ///
+    /// The function returns the point itself when the capsule contains the point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Capsule3;
+    /// #
+    /// let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 2_f64, 0_f64), 1_f64);
+    ///
+    /// assert_eq!(capsule.closest_point(&Point3::new(3_f64, 1_f64, 0_f64)), Point3::new(1_f64, 1_f64, 0_f64));
+    /// assert_eq!(capsule.closest_point(&Point3::new(0_f64, 5_f64, 0_f64)), Point3::new(0_f64, 3_f64, 0_f64));
+    /// assert_eq!(capsule.closest_point(&Point3::new(0.5_f64, 1_f64, 0_f64)), Point3::new(0.5_f64, 1_f64, 0_f64));
+    /// ```
+    #[inline]
+    pub fn closest_point(&self, point: &Point3<S>) -> Point3<S> {
+        let closest_on_segment = self.segment().closest_point(point);
+        let offset = point - closest_on_segment;
+        let distance = offset.norm();
+        if distance <= self.radius {
+            return *point;
+        }
+
+        closest_on_segment + offset * (self.radius / distance)
+    }
+
+    /// Compute the smallest axis-aligned bounding box containing the capsule.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Capsule3,
+    /// # };
+    /// #
+    /// let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 2_f64, 0_f64), 1_f64);
+    /// let expected = Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(1_f64, 3_f64, 1_f64));
+    ///
+    /// assert_eq!(capsule.to_aabb(), expected);
+    /// ```
+    #[inline]
+    pub fn to_aabb(&self) -> Aabb3<S> {
+        let aabb = Aabb3::from_corners(&self.start, &self.end);
+        let radius = Vector3::from_fill(self.radius);
+
+        Aabb3::new(aabb.min() - radius, aabb.max() + radius)
+    }
+
+    /// Transform a capsule by an isometry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Capsule3;
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(2_f64, 0_f64, 0_f64), 1_f64);
+    /// let isometry = Isometry3::from_parts(
+    ///     &Translation3::new(0_f64, 0_f64, 1_f64),
+    ///     &Rotation3::from_angle_z(Degrees(90_f64)),
+    /// );
+    /// let expected = Capsule3::new(Point3::new(0_f64, 0_f64, 1_f64), Point3::new(0_f64, 2_f64, 1_f64), 1_f64);
+    /// let result = capsule.transform_isometry(&isometry);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn transform_isometry(&self, isometry: &Isometry3<S>) -> Self {
+        Self::new(isometry.apply_point(&self.start), isometry.apply_point(&self.end), self.radius)
+    }
+}
+
+impl<S> fmt::Display for Capsule3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Capsule3 [start={}, end={}, radius={}]", self.start, self.end, self.radius)
+    }
+}
+
+impl<S> Bounded<S> for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn aabb(&self) -> Aabb3<S> {
+        self.to_aabb()
+    }
+}
+
+impl<S> SupportMap<S> for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S> {
+        let endpoint = self.segment().local_support_point(direction);
+        let norm = direction.norm();
+        if norm.is_zero() {
+            return endpoint;
+        }
+
+        endpoint + direction * (self.radius / norm)
+    }
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capsule3Tol<S> {
+    start: Vector3<S>,
+    end: Vector3<S>,
+    radius: S,
+}
+
+impl<S> Capsule3Tol<S> {
+    #[inline]
+    pub const fn from_parts(start: Vector3<S>, end: Vector3<S>, radius: S) -> Self {
+        Self { start, end, radius }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capsule3Diff<S> {
+    start: Vector3<S>,
+    end: Vector3<S>,
+    radius: S,
+}
+
+impl<S> Capsule3Diff<S> {
+    #[inline]
+    const fn from_parts(start: Vector3<S>, end: Vector3<S>, radius: S) -> Self {
+        Self { start, end, radius }
+    }
+}
+
+impl<S> approx_cmp::AbsDiffEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Capsule3Tol<<S as approx_cmp::AbsDiffEq>::Tolerance>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.start, &other.start, &max_abs_diff.start)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.end, &other.end, &max_abs_diff.end)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.radius, &other.radius, &max_abs_diff.radius)
+    }
+}
+
+impl<S> approx_cmp::AbsDiffAllEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.start, &other.start, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.end, &other.end, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.radius, &other.radius, max_abs_diff)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Capsule3Diff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff>;
+    type DebugTolerance = Capsule3Tol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.end, &other.end);
+        let radius = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.radius, &other.radius);
+
+        Capsule3Diff::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+        let radius = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.radius, &other.radius, &max_abs_diff.radius);
+
+        Capsule3Tol::from_parts(start, end, radius)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffAllEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Capsule3Tol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+        let radius = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.radius, &other.radius, max_abs_diff);
+
+        Capsule3Tol::from_parts(start, end, radius)
+    }
+}
+
+impl<S> approx_cmp::RelativeEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Capsule3Tol<<S as approx_cmp::RelativeEq>::Tolerance>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.start, &other.start, &max_abs_diff.start, &max_relative.start)
+            && approx_cmp::RelativeEq::relative_eq(&self.end, &other.end, &max_abs_diff.end, &max_relative.end)
+            && approx_cmp::RelativeEq::relative_eq(&self.radius, &other.radius, &max_abs_diff.radius, &max_relative.radius)
+    }
+}
+
+impl<S> approx_cmp::RelativeAllEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.start, &other.start, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.end, &other.end, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.radius, &other.radius, max_abs_diff, max_relative)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Capsule3Diff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff>;
+    type DebugTolerance = Capsule3Tol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.end, &other.end);
+        let radius = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.radius, &other.radius);
+
+        Capsule3Diff::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+        let radius = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.radius, &other.radius, &max_abs_diff.radius);
+
+        Capsule3Tol::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.start, &other.start, &max_relative.start);
+        let end = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.end, &other.end, &max_relative.end);
+        let radius = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.radius, &other.radius, &max_relative.radius);
+
+        Capsule3Tol::from_parts(start, end, radius)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeAllEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Capsule3Tol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+        let radius = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.radius, &other.radius, max_abs_diff);
+
+        Capsule3Tol::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.start, &other.start, max_relative);
+        let end = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.end, &other.end, max_relative);
+        let radius = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.radius, &other.radius, max_relative);
+
+        Capsule3Tol::from_parts(start, end, radius)
+    }
+}
+
+impl<S> approx_cmp::UlpsEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Capsule3Tol<<S as approx_cmp::UlpsEq>::Tolerance>;
+    type UlpsTolerance = Capsule3Tol<<S as approx_cmp::UlpsEq>::UlpsTolerance>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.start, &other.start, &max_abs_diff.start, &max_ulps.start)
+            && approx_cmp::UlpsEq::ulps_eq(&self.end, &other.end, &max_abs_diff.end, &max_ulps.end)
+            && approx_cmp::UlpsEq::ulps_eq(&self.radius, &other.radius, &max_abs_diff.radius, &max_ulps.radius)
+    }
+}
+
+impl<S> approx_cmp::UlpsAllEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.start, &other.start, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.end, &other.end, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.radius, &other.radius, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Capsule3Diff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff>;
+    type DebugUlpsDiff = Capsule3Diff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff>;
+    type DebugTolerance = Capsule3Tol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance>;
+    type DebugUlpsTolerance = Capsule3Tol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.end, &other.end);
+        let radius = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.radius, &other.radius);
+
+        Capsule3Diff::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let start = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.end, &other.end);
+        let radius = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.radius, &other.radius);
+
+        Capsule3Diff::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+        let radius = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.radius, &other.radius, &max_abs_diff.radius);
+
+        Capsule3Tol::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let start = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.start, &other.start, &max_ulps.start);
+        let end = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.end, &other.end, &max_ulps.end);
+        let radius = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.radius, &other.radius, &max_ulps.radius);
+
+        Capsule3Tol::from_parts(start, end, radius)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsAllEq for Capsule3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Capsule3Tol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance>;
+    type AllDebugUlpsTolerance = Capsule3Tol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+        let radius = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.radius, &other.radius, max_abs_diff);
+
+        Capsule3Tol::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let start = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.start, &other.start, max_ulps);
+        let end = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.end, &other.end, max_ulps);
+        let radius = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.radius, &other.radius, max_ulps);
+
+        Capsule3Tol::from_parts(start, end, radius)
+    }
+}
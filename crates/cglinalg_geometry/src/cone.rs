@@ -0,0 +1,664 @@
+use crate::aabb::Aabb3;
+use crate::bounded::Bounded;
+use crate::cylinder::disk_aabb;
+use crate::segment::Segment2;
+use crate::support_map::SupportMap;
+use cglinalg_core::{
+    Point2,
+    Point3,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::Isometry3;
+
+use core::fmt;
+
+
+/// A solid truncated circular cone in three dimensions.
+///
+/// A cone is the solid swept out by the disks perpendicular to the line segment
+/// between the endpoints `start` and `end` of its axis, centered on the segment, whose
+/// radii vary linearly from `start_radius` at `start` to `end_radius` at `end`. The
+/// endpoints of the axis are the centers of the two circular caps of the cone. A cone
+/// with equal radii is a cylinder, and a cone with one zero radius is a cone with its
+/// apex at the corresponding endpoint.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cone3<S> {
+    start: Point3<S>,
+    end: Point3<S>,
+    start_radius: S,
+    end_radius: S,
+}
+
+impl<S> Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new cone from the endpoints of its axis, and the radii of its caps.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Cone3;
+    /// #
+    /// let start = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let end = Point3::new(0_f64, 0_f64, 3_f64);
+    /// let cone = Cone3::new(start, end, 2_f64, 0_f64);
+    ///
+    /// assert_eq!(cone.start(), start);
+    /// assert_eq!(cone.end(), end);
+    /// assert_eq!(cone.start_radius(), 2_f64);
+    /// assert_eq!(cone.end_radius(), 0_f64);
+    /// ```
+    #[inline]
+    pub const fn new(start: Point3<S>, end: Point3<S>, start_radius: S, end_radius: S) -> Self {
+        Self {
+            start,
+            end,
+            start_radius,
+            end_radius,
+        }
+    }
+
+    /// Get the center of the starting cap of the cone.
+    #[inline]
+    pub const fn start(&self) -> Point3<S> {
+        self.start
+    }
+
+    /// Get the center of the ending cap of the cone.
+    #[inline]
+    pub const fn end(&self) -> Point3<S> {
+        self.end
+    }
+
+    /// Get the radius of the starting cap of the cone.
+    #[inline]
+    pub const fn start_radius(&self) -> S {
+        self.start_radius
+    }
+
+    /// Get the radius of the ending cap of the cone.
+    #[inline]
+    pub const fn end_radius(&self) -> S {
+        self.end_radius
+    }
+
+    /// Compute the vector from the center of the starting cap to the center of the
+    /// ending cap of the cone.
+    #[inline]
+    pub fn axis(&self) -> Vector3<S> {
+        self.end - self.start
+    }
+
+    /// Compute the height of a point along the axis of the cone, and the component
+    /// of the point orthogonal to the axis.
+    ///
+    /// The height is zero at the starting cap, and one at the ending cap.
+    fn axial_coordinates(&self, point: &Point3<S>) -> (S, Vector3<S>) {
+        let axis = self.axis();
+        let offset = point - self.start;
+        let length_squared = axis.norm_squared();
+        if length_squared.is_zero() {
+            return (S::zero(), offset);
+        }
+
+        (offset.dot(&axis) / length_squared, offset.reject(&axis))
+    }
+
+    /// Determine whether a cone contains a point.
+    ///
+    /// The cone is closed, so points on the boundary of the cone are contained in
+    /// the cone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Cone3;
+    /// #
+    /// let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 2_f64, 0_f64);
+    ///
+    /// assert!(cone.contains(&Point3::new(0_f64, 0_f64, 1_f64)));
+    /// assert!(cone.contains(&Point3::new(1_f64, 0_f64, 1_f64)));
+    /// assert!(!cone.contains(&Point3::new(1.5_f64, 0_f64, 1_f64)));
+    /// assert!(!cone.contains(&Point3::new(0_f64, 0_f64, 3_f64)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: &Point3<S>) -> bool {
+        let (height, radial) = self.axial_coordinates(point);
+        if height < S::zero() || height > S::one() {
+            return false;
+        }
+
+        let radius = self.start_radius + (self.end_radius - self.start_radius) * height;
+
+        radial.norm_squared() <= radius * radius
+    }
+
+    /// Compute the closest point of a cone to a point.
+    ///
+    /// The function returns the point itself when the cone contains the point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Cone3;
+    /// #
+    /// let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 2_f64, 0_f64);
+    ///
+    /// assert_relative_eq!(
+    ///     cone.closest_point(&Point3::new(2_f64, 0_f64, 2_f64)),
+    ///     Point3::new(1_f64, 0_f64, 1_f64),
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// assert_eq!(cone.closest_point(&Point3::new(3_f64, 0_f64, -1_f64)), Point3::new(2_f64, 0_f64, 0_f64));
+    /// assert_eq!(cone.closest_point(&Point3::new(0_f64, 0_f64, 1_f64)), Point3::new(0_f64, 0_f64, 1_f64));
+    /// ```
+    pub fn closest_point(&self, point: &Point3<S>) -> Point3<S> {
+        if self.contains(point) {
+            return *point;
+        }
+
+        // The cone is symmetric about its axis, so the problem reduces to finding the
+        // closest point of the trapezoid swept out by the axis and the radius in the
+        // half plane of the point, with coordinates `(height, distance from axis)`.
+        let axis = self.axis();
+        let length = axis.norm();
+        let (height, radial) = self.axial_coordinates(point);
+        let radial_norm = radial.norm();
+        let projected = Point2::new(height * length, radial_norm);
+        let start_rim = Point2::new(S::zero(), self.start_radius);
+        let end_rim = Point2::new(length, self.end_radius);
+        let edges = [
+            Segment2::new(Point2::origin(), start_rim),
+            Segment2::new(start_rim, end_rim),
+            Segment2::new(end_rim, Point2::new(length, S::zero())),
+        ];
+        let closest = edges[1..].iter().fold(edges[0].closest_point(&projected), |acc, edge| {
+            let candidate = edge.closest_point(&projected);
+            if (candidate - projected).norm_squared() < (acc - projected).norm_squared() {
+                candidate
+            } else {
+                acc
+            }
+        });
+
+        let unit_axis = if length.is_zero() { Vector3::zero() } else { axis / length };
+        let unit_radial = if radial_norm.is_zero() { Vector3::zero() } else { radial / radial_norm };
+
+        self.start + unit_axis * closest[0] + unit_radial * closest[1]
+    }
+
+    /// Compute the smallest axis-aligned bounding box containing the cone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Cone3,
+    /// # };
+    /// #
+    /// let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 2_f64, 0_f64);
+    /// let expected = Aabb3::new(Point3::new(-2_f64, -2_f64, 0_f64), Point3::new(2_f64, 2_f64, 2_f64));
+    ///
+    /// assert_eq!(cone.to_aabb(), expected);
+    /// ```
+    #[inline]
+    pub fn to_aabb(&self) -> Aabb3<S> {
+        let axis = self.axis();
+        let start_cap = disk_aabb(&self.start, &axis, self.start_radius);
+        let end_cap = disk_aabb(&self.end, &axis, self.end_radius);
+
+        start_cap.union(&end_cap)
+    }
+
+    /// Transform a cone by an isometry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Cone3;
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(2_f64, 0_f64, 0_f64), 1_f64, 0_f64);
+    /// let isometry = Isometry3::from_parts(
+    ///     &Translation3::new(0_f64, 0_f64, 1_f64),
+    ///     &Rotation3::from_angle_z(Degrees(90_f64)),
+    /// );
+    /// let expected = Cone3::new(Point3::new(0_f64, 0_f64, 1_f64), Point3::new(0_f64, 2_f64, 1_f64), 1_f64, 0_f64);
+    /// let result = cone.transform_isometry(&isometry);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn transform_isometry(&self, isometry: &Isometry3<S>) -> Self {
+        Self::new(
+            isometry.apply_point(&self.start),
+            isometry.apply_point(&self.end),
+            self.start_radius,
+            self.end_radius,
+        )
+    }
+}
+
+impl<S> fmt::Display for Cone3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Cone3 [start={}, end={}, start_radius={}, end_radius={}]",
+            self.start, self.end, self.start_radius, self.end_radius
+        )
+    }
+}
+
+impl<S> Bounded<S> for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn aabb(&self) -> Aabb3<S> {
+        self.to_aabb()
+    }
+}
+
+impl<S> SupportMap<S> for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S> {
+        // A cone is the convex hull of its two caps, so its support point is the
+        // furthest of the support points of the rims of the caps.
+        let axis = self.axis();
+        let radial = if axis.norm_squared().is_zero() {
+            *direction
+        } else {
+            direction.reject(&axis)
+        };
+        let radial_norm = radial.norm();
+        let unit_radial = if radial_norm.is_zero() { Vector3::zero() } else { radial / radial_norm };
+        let start_rim = self.start + unit_radial * self.start_radius;
+        let end_rim = self.end + unit_radial * self.end_radius;
+
+        if end_rim.to_vector().dot(direction) >= start_rim.to_vector().dot(direction) {
+            end_rim
+        } else {
+            start_rim
+        }
+    }
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cone3Tol<S> {
+    start: Vector3<S>,
+    end: Vector3<S>,
+    start_radius: S,
+    end_radius: S,
+}
+
+impl<S> Cone3Tol<S> {
+    #[inline]
+    pub const fn from_parts(start: Vector3<S>, end: Vector3<S>, start_radius: S, end_radius: S) -> Self {
+        Self { start, end, start_radius, end_radius }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cone3Diff<S> {
+    start: Vector3<S>,
+    end: Vector3<S>,
+    start_radius: S,
+    end_radius: S,
+}
+
+impl<S> Cone3Diff<S> {
+    #[inline]
+    const fn from_parts(start: Vector3<S>, end: Vector3<S>, start_radius: S, end_radius: S) -> Self {
+        Self { start, end, start_radius, end_radius }
+    }
+}
+
+impl<S> approx_cmp::AbsDiffEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Cone3Tol<<S as approx_cmp::AbsDiffEq>::Tolerance>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.start, &other.start, &max_abs_diff.start)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.end, &other.end, &max_abs_diff.end)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.start_radius, &other.start_radius, &max_abs_diff.start_radius)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.end_radius, &other.end_radius, &max_abs_diff.end_radius)
+    }
+}
+
+impl<S> approx_cmp::AbsDiffAllEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.start, &other.start, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.end, &other.end, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.start_radius, &other.start_radius, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.end_radius, &other.end_radius, max_abs_diff)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Cone3Diff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff>;
+    type DebugTolerance = Cone3Tol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.end, &other.end);
+        let start_radius = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.start_radius, &other.start_radius);
+        let end_radius = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.end_radius, &other.end_radius);
+
+        Cone3Diff::from_parts(start, end, start_radius, end_radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+        let start_radius = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(
+            &self.start_radius,
+            &other.start_radius,
+            &max_abs_diff.start_radius,
+        );
+        let end_radius = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(
+            &self.end_radius,
+            &other.end_radius,
+            &max_abs_diff.end_radius,
+        );
+
+        Cone3Tol::from_parts(start, end, start_radius, end_radius)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffAllEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Cone3Tol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+        let start_radius = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(
+            &self.start_radius,
+            &other.start_radius,
+            max_abs_diff,
+        );
+        let end_radius = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.end_radius, &other.end_radius, max_abs_diff);
+
+        Cone3Tol::from_parts(start, end, start_radius, end_radius)
+    }
+}
+
+impl<S> approx_cmp::RelativeEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Cone3Tol<<S as approx_cmp::RelativeEq>::Tolerance>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.start, &other.start, &max_abs_diff.start, &max_relative.start)
+            && approx_cmp::RelativeEq::relative_eq(&self.end, &other.end, &max_abs_diff.end, &max_relative.end)
+            && approx_cmp::RelativeEq::relative_eq(
+                &self.start_radius,
+                &other.start_radius,
+                &max_abs_diff.start_radius,
+                &max_relative.start_radius,
+            )
+            && approx_cmp::RelativeEq::relative_eq(&self.end_radius, &other.end_radius, &max_abs_diff.end_radius, &max_relative.end_radius)
+    }
+}
+
+impl<S> approx_cmp::RelativeAllEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.start, &other.start, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.end, &other.end, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.start_radius, &other.start_radius, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.end_radius, &other.end_radius, max_abs_diff, max_relative)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Cone3Diff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff>;
+    type DebugTolerance = Cone3Tol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.end, &other.end);
+        let start_radius = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.start_radius, &other.start_radius);
+        let end_radius = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.end_radius, &other.end_radius);
+
+        Cone3Diff::from_parts(start, end, start_radius, end_radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+        let start_radius = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(
+            &self.start_radius,
+            &other.start_radius,
+            &max_abs_diff.start_radius,
+        );
+        let end_radius = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(
+            &self.end_radius,
+            &other.end_radius,
+            &max_abs_diff.end_radius,
+        );
+
+        Cone3Tol::from_parts(start, end, start_radius, end_radius)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.start, &other.start, &max_relative.start);
+        let end = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.end, &other.end, &max_relative.end);
+        let start_radius = approx_cmp::AssertRelativeEq::debug_relative_tolerance(
+            &self.start_radius,
+            &other.start_radius,
+            &max_relative.start_radius,
+        );
+        let end_radius = approx_cmp::AssertRelativeEq::debug_relative_tolerance(
+            &self.end_radius,
+            &other.end_radius,
+            &max_relative.end_radius,
+        );
+
+        Cone3Tol::from_parts(start, end, start_radius, end_radius)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeAllEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Cone3Tol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+        let start_radius = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(
+            &self.start_radius,
+            &other.start_radius,
+            max_abs_diff,
+        );
+        let end_radius = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.end_radius, &other.end_radius, max_abs_diff);
+
+        Cone3Tol::from_parts(start, end, start_radius, end_radius)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.start, &other.start, max_relative);
+        let end = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.end, &other.end, max_relative);
+        let start_radius = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(
+            &self.start_radius,
+            &other.start_radius,
+            max_relative,
+        );
+        let end_radius = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.end_radius, &other.end_radius, max_relative);
+
+        Cone3Tol::from_parts(start, end, start_radius, end_radius)
+    }
+}
+
+impl<S> approx_cmp::UlpsEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Cone3Tol<<S as approx_cmp::UlpsEq>::Tolerance>;
+    type UlpsTolerance = Cone3Tol<<S as approx_cmp::UlpsEq>::UlpsTolerance>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.start, &other.start, &max_abs_diff.start, &max_ulps.start)
+            && approx_cmp::UlpsEq::ulps_eq(&self.end, &other.end, &max_abs_diff.end, &max_ulps.end)
+            && approx_cmp::UlpsEq::ulps_eq(&self.start_radius, &other.start_radius, &max_abs_diff.start_radius, &max_ulps.start_radius)
+            && approx_cmp::UlpsEq::ulps_eq(&self.end_radius, &other.end_radius, &max_abs_diff.end_radius, &max_ulps.end_radius)
+    }
+}
+
+impl<S> approx_cmp::UlpsAllEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.start, &other.start, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.end, &other.end, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.start_radius, &other.start_radius, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.end_radius, &other.end_radius, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Cone3Diff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff>;
+    type DebugUlpsDiff = Cone3Diff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff>;
+    type DebugTolerance = Cone3Tol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance>;
+    type DebugUlpsTolerance = Cone3Tol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.end, &other.end);
+        let start_radius = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.start_radius, &other.start_radius);
+        let end_radius = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.end_radius, &other.end_radius);
+
+        Cone3Diff::from_parts(start, end, start_radius, end_radius)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let start = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.end, &other.end);
+        let start_radius = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.start_radius, &other.start_radius);
+        let end_radius = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.end_radius, &other.end_radius);
+
+        Cone3Diff::from_parts(start, end, start_radius, end_radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+        let start_radius = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(
+            &self.start_radius,
+            &other.start_radius,
+            &max_abs_diff.start_radius,
+        );
+        let end_radius = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.end_radius, &other.end_radius, &max_abs_diff.end_radius);
+
+        Cone3Tol::from_parts(start, end, start_radius, end_radius)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let start = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.start, &other.start, &max_ulps.start);
+        let end = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.end, &other.end, &max_ulps.end);
+        let start_radius = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.start_radius, &other.start_radius, &max_ulps.start_radius);
+        let end_radius = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.end_radius, &other.end_radius, &max_ulps.end_radius);
+
+        Cone3Tol::from_parts(start, end, start_radius, end_radius)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsAllEq for Cone3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Cone3Tol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance>;
+    type AllDebugUlpsTolerance = Cone3Tol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+        let start_radius = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.start_radius, &other.start_radius, max_abs_diff);
+        let end_radius = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.end_radius, &other.end_radius, max_abs_diff);
+
+        Cone3Tol::from_parts(start, end, start_radius, end_radius)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let start = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.start, &other.start, max_ulps);
+        let end = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.end, &other.end, max_ulps);
+        let start_radius = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.start_radius, &other.start_radius, max_ulps);
+        let end_radius = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.end_radius, &other.end_radius, max_ulps);
+
+        Cone3Tol::from_parts(start, end, start_radius, end_radius)
+    }
+}
@@ -0,0 +1,668 @@
+use cglinalg_core::{
+    Normed,
+    Point2,
+    Point3,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+
+/// Compute the convex hull of a set of points in the plane.
+///
+/// The function uses **Andrew's monotone chain** algorithm, which runs in
+/// `O(n log n)` time. It returns the indices into `points` of the vertices of the
+/// convex hull in counterclockwise order, starting from the vertex with the smallest
+/// coordinates in lexicographic order. Points lying in the interior of an edge of the
+/// hull are not vertices of the hull, and duplicate points are reported once.
+///
+/// When every point is collinear, the hull is the segment between the two extreme
+/// points, and the function returns the indices of its endpoints. The function
+/// returns a single index when every point coincides, and no indices when `points`
+/// is empty.
+///
+/// This function requires the `alloc` feature.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::Point2;
+/// # use cglinalg_geometry::convex_hull2;
+/// #
+/// let points = [
+///     Point2::new(1_f64, 1_f64),
+///     Point2::new(0_f64, 0_f64),
+///     Point2::new(2_f64, 2_f64),
+///     Point2::new(2_f64, 0_f64),
+///     Point2::new(1_f64, 0_f64),
+///     Point2::new(0_f64, 2_f64),
+/// ];
+///
+/// assert_eq!(convex_hull2(&points), vec![1, 3, 2, 5]);
+/// ```
+pub fn convex_hull2<S>(points: &[Point2<S>]) -> Vec<usize>
+where
+    S: SimdScalarFloat,
+{
+    let mut indices: Vec<usize> = (0..points.len()).collect();
+    indices.sort_by(|i, j| {
+        let p = &points[*i];
+        let q = &points[*j];
+
+        p[0].partial_cmp(&q[0])
+            .unwrap_or(Ordering::Equal)
+            .then(p[1].partial_cmp(&q[1]).unwrap_or(Ordering::Equal))
+    });
+    indices.dedup_by(|i, j| points[*i] == points[*j]);
+    if indices.len() < 3 {
+        return indices;
+    }
+
+    // The signed area of the parallelogram spanned by the edges `ab` and `ac`.
+    let cross = |a: usize, b: usize, c: usize| {
+        let edge1 = points[b] - points[a];
+        let edge2 = points[c] - points[a];
+
+        edge1[0] * edge2[1] - edge1[1] * edge2[0]
+    };
+
+    // Build the lower hull from left to right, then the upper hull from right to left,
+    // keeping only strict left turns.
+    let mut hull: Vec<usize> = Vec::with_capacity(2 * indices.len());
+    for index in indices.iter() {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], *index) <= S::zero() {
+            hull.pop();
+        }
+        hull.push(*index);
+    }
+    let lower_len = hull.len() + 1;
+    for index in indices.iter().rev().skip(1) {
+        while hull.len() >= lower_len && cross(hull[hull.len() - 2], hull[hull.len() - 1], *index) <= S::zero() {
+            hull.pop();
+        }
+        hull.push(*index);
+    }
+    // The last point of the upper hull is the first point of the lower hull.
+    hull.pop();
+
+    hull
+}
+
+
+/// The convex hull of a set of points in three dimensions.
+///
+/// The hull is a closed triangle mesh whose faces refer to the points the hull was
+/// computed from by their indices. The vertices of each face wind counterclockwise
+/// when viewed from outside the hull, and each face carries its outward unit normal
+/// vector. A flat polygonal face of the hull may be split into several coplanar
+/// triangles.
+///
+/// This type requires the `alloc` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvexHull3<S> {
+    faces: Vec<[usize; 3]>,
+    normals: Vec<Vector3<S>>,
+}
+
+impl<S> ConvexHull3<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Get the faces of the convex hull, as triples of indices into the points the
+    /// hull was computed from.
+    #[inline]
+    pub fn faces(&self) -> &[[usize; 3]] {
+        &self.faces
+    }
+
+    /// Get the outward unit normal vectors of the faces of the convex hull.
+    ///
+    /// The normal vector `normals()[i]` belongs to the face `faces()[i]`.
+    #[inline]
+    pub fn normals(&self) -> &[Vector3<S>] {
+        &self.normals
+    }
+
+    /// Get the indices of the vertices of the convex hull, in increasing order.
+    pub fn vertices(&self) -> Vec<usize> {
+        let mut result: Vec<usize> = self.faces.iter().flatten().copied().collect();
+        result.sort_unstable();
+        result.dedup();
+
+        result
+    }
+}
+
+
+/// A face of the convex hull under construction.
+#[derive(Clone, Debug)]
+struct HullFace<S> {
+    vertices: [usize; 3],
+    normal: Vector3<S>,
+    offset: S,
+    outside: Vec<usize>,
+    is_alive: bool,
+}
+
+impl<S> HullFace<S>
+where
+    S: SimdScalarFloat,
+{
+    fn new(points: &[Point3<S>], vertices: [usize; 3]) -> Self {
+        let [a, b, c] = vertices;
+        // The normal vector of a thin face computed in floating point arithmetic can
+        // point in a very different direction than the true normal vector, so the
+        // normal vector is rounded from its exact value instead.
+        let [x, y, z] = exact_cross(&points[a], &points[b], &points[c]);
+        let normal = Vector3::new(x.estimate(), y.estimate(), z.estimate()).normalize();
+        let offset = normal.dot(&points[a].to_vector());
+
+        Self {
+            vertices,
+            normal,
+            offset,
+            outside: Vec::new(),
+            is_alive: true,
+        }
+    }
+
+    #[inline]
+    fn signed_distance(&self, point: &Point3<S>) -> S {
+        self.normal.dot(&point.to_vector()) - self.offset
+    }
+
+    /// Determine whether a point lies strictly outside the plane of the face.
+    #[inline]
+    fn is_visible_from(&self, points: &[Point3<S>], point: &Point3<S>) -> bool {
+        let [a, b, c] = self.vertices;
+
+        orientation(&points[a], &points[b], &points[c], point) == Ordering::Greater
+    }
+
+    #[inline]
+    fn edges(&self) -> [(usize, usize); 3] {
+        let [a, b, c] = self.vertices;
+
+        [(a, b), (b, c), (c, a)]
+    }
+}
+
+
+/// Compute the convex hull of a set of points in three dimensions.
+///
+/// The function uses the **quickhull** algorithm, which runs in `O(n log n)` expected
+/// time. Whether a point lies outside a face is decided by the sign of an orientation
+/// determinant, which is evaluated exactly whenever its round-off error could change
+/// the sign. The hull is therefore a closed, consistently oriented mesh even when
+/// the points are nearly coplanar. Points lying on the boundary of the hull that are
+/// not corners of the hull, such as points in the interior of a face, do not become
+/// vertices of the hull.
+///
+/// The function returns `None` when the points do not span a volume, i.e. when there
+/// are fewer than four points, or every point is coplanar up to a small tolerance
+/// relative to the extent of the point set.
+///
+/// This function requires the `alloc` feature.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::Point3;
+/// # use cglinalg_geometry::convex_hull3;
+/// #
+/// let points = [
+///     Point3::new(0_f64, 0_f64, 0_f64),
+///     Point3::new(1_f64, 0_f64, 0_f64),
+///     Point3::new(0_f64, 1_f64, 0_f64),
+///     Point3::new(0_f64, 0_f64, 1_f64),
+///     Point3::new(0.1_f64, 0.1_f64, 0.1_f64),
+/// ];
+/// let hull = convex_hull3(&points).unwrap();
+///
+/// assert_eq!(hull.faces().len(), 4);
+/// assert_eq!(hull.vertices(), vec![0, 1, 2, 3]);
+/// for (face, normal) in hull.faces().iter().zip(hull.normals()) {
+///     let vertex = points[face[0]].to_vector();
+///     for point in points.iter() {
+///         assert!(normal.dot(&(point.to_vector() - vertex)) <= 1e-12);
+///     }
+/// }
+/// ```
+pub fn convex_hull3<S>(points: &[Point3<S>]) -> Option<ConvexHull3<S>>
+where
+    S: SimdScalarFloat,
+{
+    if points.len() < 4 {
+        return None;
+    }
+
+    // The tolerance of the quickhull algorithm of Barber, Dobkin, and Huhdanpaa, which
+    // decides whether the points are too close to coplanar to span a volume.
+    let mut max_abs = Vector3::zero();
+    for point in points.iter() {
+        for i in 0..3 {
+            max_abs[i] = S::max(max_abs[i], point[i].abs());
+        }
+    }
+    let three: S = cglinalg_numeric::cast(3_f64);
+    let tolerance = three * S::default_epsilon() * (max_abs[0] + max_abs[1] + max_abs[2]);
+
+    let [v0, v1, v2, v3] = initial_simplex(points, tolerance)?;
+    let mut faces = Vec::new();
+    let mut edges: BTreeMap<(usize, usize), usize> = BTreeMap::new();
+    for vertices in [[v0, v1, v2], [v0, v3, v1], [v1, v3, v2], [v2, v3, v0]] {
+        add_face(points, &mut faces, &mut edges, vertices);
+    }
+
+    let simplex = [v0, v1, v2, v3];
+    let candidates = (0..points.len()).filter(|index| !simplex.contains(index));
+    assign_outside_points(points, &mut faces, 0..4, candidates);
+
+    let mut pending: Vec<usize> = (0..4).collect();
+    while let Some(face_index) = pending.pop() {
+        if !faces[face_index].is_alive || faces[face_index].outside.is_empty() {
+            continue;
+        }
+
+        // The eye point is the point furthest outside the face.
+        let face = &faces[face_index];
+        let eye = face.outside.iter().copied().fold(face.outside[0], |acc, index| {
+            if face.signed_distance(&points[index]) > face.signed_distance(&points[acc]) {
+                index
+            } else {
+                acc
+            }
+        });
+
+        // Find the faces visible from the eye point by a flood fill from the face, and
+        // the horizon edges separating the visible faces from the rest of the hull.
+        let mut visible = Vec::new();
+        let mut horizon = Vec::new();
+        let mut stack = Vec::from([face_index]);
+        faces[face_index].is_alive = false;
+        while let Some(visible_index) = stack.pop() {
+            visible.push(visible_index);
+            for (a, b) in faces[visible_index].edges() {
+                // Every edge of a closed mesh has an opposite edge.
+                let neighbor_index = *edges.get(&(b, a))?;
+                let neighbor = &faces[neighbor_index];
+                if !neighbor.is_alive {
+                    continue;
+                }
+
+                if neighbor.is_visible_from(points, &points[eye]) {
+                    faces[neighbor_index].is_alive = false;
+                    stack.push(neighbor_index);
+                } else {
+                    horizon.push((a, b));
+                }
+            }
+        }
+
+        let mut orphans = Vec::new();
+        for visible_index in visible.iter() {
+            for edge in faces[*visible_index].edges() {
+                edges.remove(&edge);
+            }
+            orphans.append(&mut faces[*visible_index].outside);
+        }
+
+        // Cone the horizon to the eye point. Each horizon edge winds the same way as in
+        // the visible face it came from, so the new faces face outward.
+        let first_new_face = faces.len();
+        for (a, b) in horizon {
+            add_face(points, &mut faces, &mut edges, [a, b, eye]);
+        }
+        let new_faces = first_new_face..faces.len();
+        let candidates = orphans.into_iter().filter(|index| *index != eye);
+        assign_outside_points(points, &mut faces, new_faces.clone(), candidates);
+        pending.extend(new_faces);
+    }
+
+    let (faces, normals) = faces
+        .into_iter()
+        .filter(|face| face.is_alive)
+        .map(|face| (face.vertices, face.normal))
+        .unzip();
+
+    Some(ConvexHull3 { faces, normals })
+}
+
+/// Find four points spanning a tetrahedron whose volume is as large as practical.
+///
+/// The vertices are ordered so that the vertex `v3` lies below the plane through the
+/// vertices `v0`, `v1`, and `v2` in counterclockwise order.
+fn initial_simplex<S>(points: &[Point3<S>], tolerance: S) -> Option<[usize; 4]>
+where
+    S: SimdScalarFloat,
+{
+    // Take the two most distant of the extreme points along the coordinate axes.
+    let mut extremes = [0_usize; 6];
+    for (index, point) in points.iter().enumerate() {
+        for axis in 0..3 {
+            if point[axis] < points[extremes[2 * axis]][axis] {
+                extremes[2 * axis] = index;
+            }
+            if point[axis] > points[extremes[2 * axis + 1]][axis] {
+                extremes[2 * axis + 1] = index;
+            }
+        }
+    }
+    let mut v0 = extremes[0];
+    let mut v1 = extremes[1];
+    for i in 0..6 {
+        for j in (i + 1)..6 {
+            let distance_squared = (points[extremes[j]] - points[extremes[i]]).norm_squared();
+            if distance_squared > (points[v1] - points[v0]).norm_squared() {
+                v0 = extremes[i];
+                v1 = extremes[j];
+            }
+        }
+    }
+    let direction = points[v1] - points[v0];
+    if direction.norm() <= tolerance {
+        return None;
+    }
+
+    // Take the point furthest from the line through the first two points.
+    let unit_direction = direction.normalize();
+    let distance_to_line = |index: usize| (points[index] - points[v0]).cross(&unit_direction).norm();
+    let v2 = (0..points.len()).fold(v0, |acc, index| {
+        if distance_to_line(index) > distance_to_line(acc) {
+            index
+        } else {
+            acc
+        }
+    });
+    if distance_to_line(v2) <= tolerance {
+        return None;
+    }
+
+    // Take the point furthest from the plane through the first three points.
+    let normal = direction.cross(&(points[v2] - points[v0])).normalize();
+    let distance_to_plane = |index: usize| normal.dot(&(points[index] - points[v0]));
+    let v3 = (0..points.len()).fold(v0, |acc, index| {
+        if distance_to_plane(index).abs() > distance_to_plane(acc).abs() {
+            index
+        } else {
+            acc
+        }
+    });
+    if distance_to_plane(v3).abs() <= tolerance {
+        return None;
+    }
+
+    match orientation(&points[v0], &points[v1], &points[v2], &points[v3]) {
+        Ordering::Greater => Some([v0, v2, v1, v3]),
+        Ordering::Less => Some([v0, v1, v2, v3]),
+        Ordering::Equal => None,
+    }
+}
+
+fn add_face<S>(points: &[Point3<S>], faces: &mut Vec<HullFace<S>>, edges: &mut BTreeMap<(usize, usize), usize>, vertices: [usize; 3])
+where
+    S: SimdScalarFloat,
+{
+    let face = HullFace::new(points, vertices);
+    for edge in face.edges() {
+        edges.insert(edge, faces.len());
+    }
+    faces.push(face);
+}
+
+/// Assign each candidate point to the outside set of the first face in a range of
+/// faces that it lies outside of. Points lying inside every face are discarded.
+fn assign_outside_points<S, I>(points: &[Point3<S>], faces: &mut [HullFace<S>], face_range: core::ops::Range<usize>, candidates: I)
+where
+    S: SimdScalarFloat,
+    I: Iterator<Item = usize>,
+{
+    for index in candidates {
+        for face in faces[face_range.clone()].iter_mut() {
+            if face.is_visible_from(points, &points[index]) {
+                face.outside.push(index);
+                break;
+            }
+        }
+    }
+}
+
+
+/// Compute the sign of the orientation determinant `dot(cross(b - a, c - a), p - a)`
+/// of four points.
+///
+/// The result is [`Ordering::Greater`] when `p` lies on the side of the plane through
+/// `a`, `b`, and `c` that the vertices `a`, `b`, and `c` wind counterclockwise around,
+/// [`Ordering::Less`] when `p` lies on the other side, and [`Ordering::Equal`] when
+/// the points are coplanar. The determinant is first evaluated in floating point
+/// arithmetic. When the result is smaller than the bound of Shewchuk on its round-off
+/// error, the determinant is evaluated exactly instead.
+fn orientation<S>(a: &Point3<S>, b: &Point3<S>, c: &Point3<S>, p: &Point3<S>) -> Ordering
+where
+    S: SimdScalarFloat,
+{
+    let u = b - a;
+    let v = c - a;
+    let w = p - a;
+    let determinant = w.dot(&u.cross(&v));
+    let permanent = w[0].abs() * ((u[1] * v[2]).abs() + (u[2] * v[1]).abs())
+        + w[1].abs() * ((u[2] * v[0]).abs() + (u[0] * v[2]).abs())
+        + w[2].abs() * ((u[0] * v[1]).abs() + (u[1] * v[0]).abs());
+    let four: S = cglinalg_numeric::cast(4_f64);
+    let error_bound = four * S::default_epsilon() * permanent;
+    if determinant > error_bound {
+        return Ordering::Greater;
+    }
+    if determinant < -error_bound {
+        return Ordering::Less;
+    }
+
+    let normal = exact_cross(a, b, c);
+    let mut result = Expansion::zero();
+    for i in 0..3 {
+        result = result.add(&normal[i].mul(&Expansion::difference(p[i], a[i])));
+    }
+
+    result.sign()
+}
+
+/// Compute the components of the cross product `cross(b - a, c - a)` exactly.
+fn exact_cross<S>(a: &Point3<S>, b: &Point3<S>, c: &Point3<S>) -> [Expansion<S>; 3]
+where
+    S: SimdScalarFloat,
+{
+    let u: [Expansion<S>; 3] = core::array::from_fn(|i| Expansion::difference(b[i], a[i]));
+    let v: [Expansion<S>; 3] = core::array::from_fn(|i| Expansion::difference(c[i], a[i]));
+
+    core::array::from_fn(|i| {
+        let j = (i + 1) % 3;
+        let k = (i + 2) % 3;
+
+        u[j].mul(&v[k]).add(&u[k].mul(&v[j]).negate())
+    })
+}
+
+/// The largest number of components of an expansion in an exact orientation test.
+///
+/// The differences of coordinates have two components, the components of the cross
+/// product of two differences have sixteen, and the dot product of the cross product
+/// with a third difference has `3 * 16 * 2 * 2 == 192`.
+const EXPANSION_CAPACITY: usize = 192;
+
+/// A floating point expansion, which represents a number exactly as the sum of its
+/// components.
+///
+/// The components are nonoverlapping and increase in magnitude, following Shewchuk,
+/// *Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric
+/// Predicates*. Zero components are eliminated, so the sign of the expansion is the
+/// sign of its last component. Each operation allocates only as many components as
+/// its result can have, which for the exact orientation test never exceeds
+/// [`EXPANSION_CAPACITY`].
+#[derive(Clone, Debug)]
+struct Expansion<S> {
+    components: Vec<S>,
+}
+
+impl<S> Expansion<S>
+where
+    S: SimdScalarFloat,
+{
+    fn zero() -> Self {
+        Self { components: Vec::new() }
+    }
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            components: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Construct the expansion of `a - b`.
+    fn difference(a: S, b: S) -> Self {
+        let (difference, error) = two_sum(a, -b);
+        let mut result = Self::with_capacity(2);
+        result.grow(error);
+        result.grow(difference);
+
+        result
+    }
+
+    #[inline]
+    fn components(&self) -> &[S] {
+        &self.components
+    }
+
+    #[inline]
+    fn push(&mut self, component: S) {
+        if !component.is_zero() {
+            debug_assert!(
+                self.components.len() < EXPANSION_CAPACITY,
+                "expansion exceeds the {} components of an exact orientation test",
+                EXPANSION_CAPACITY
+            );
+            self.components.push(component);
+        }
+    }
+
+    /// Add a number to the expansion in place.
+    ///
+    /// Zero elimination never writes a component ahead of the one being read, so
+    /// the components are overwritten as they are consumed.
+    fn grow(&mut self, value: S) {
+        let mut sum = value;
+        let mut len = 0;
+        for i in 0..self.components.len() {
+            let (new_sum, error) = two_sum(sum, self.components[i]);
+            if !error.is_zero() {
+                self.components[len] = error;
+                len += 1;
+            }
+            sum = new_sum;
+        }
+        self.components.truncate(len);
+        self.push(sum);
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        let mut result = Self::with_capacity(self.components.len() + other.components.len());
+        result.components.extend_from_slice(self.components());
+        for component in other.components().iter() {
+            result.grow(*component);
+        }
+
+        result
+    }
+
+    fn negate(&self) -> Self {
+        let mut result = self.clone();
+        for component in result.components.iter_mut() {
+            *component = -*component;
+        }
+
+        result
+    }
+
+    /// Multiply the expansion by a number.
+    fn scale(&self, factor: S) -> Self {
+        let mut result = Self::with_capacity(2 * self.components.len());
+        let Some((first, rest)) = self.components().split_first() else {
+            return result;
+        };
+
+        let (mut sum, error) = two_product(*first, factor);
+        result.push(error);
+        for component in rest.iter() {
+            let (product, product_error) = two_product(*component, factor);
+            let (partial_sum, error) = two_sum(sum, product_error);
+            result.push(error);
+            let (new_sum, error) = fast_two_sum(product, partial_sum);
+            result.push(error);
+            sum = new_sum;
+        }
+        result.push(sum);
+
+        result
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        other
+            .components()
+            .iter()
+            .fold(Self::zero(), |product, component| product.add(&self.scale(*component)))
+    }
+
+    /// Approximate the value of the expansion by a single number.
+    fn estimate(&self) -> S {
+        self.components().iter().fold(S::zero(), |sum, component| sum + *component)
+    }
+
+    fn sign(&self) -> Ordering {
+        match self.components().last() {
+            Some(component) if *component > S::zero() => Ordering::Greater,
+            Some(_) => Ordering::Less,
+            None => Ordering::Equal,
+        }
+    }
+}
+
+/// Compute the sum of two numbers together with its round-off error.
+#[inline]
+fn two_sum<S>(a: S, b: S) -> (S, S)
+where
+    S: SimdScalarFloat,
+{
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    let error = (a - a_virtual) + (b - b_virtual);
+
+    (sum, error)
+}
+
+/// Compute the sum of two numbers together with its round-off error, provided that
+/// `abs(a) >= abs(b)`.
+#[inline]
+fn fast_two_sum<S>(a: S, b: S) -> (S, S)
+where
+    S: SimdScalarFloat,
+{
+    let sum = a + b;
+    let error = b - (sum - a);
+
+    (sum, error)
+}
+
+/// Compute the product of two numbers together with its round-off error.
+#[inline]
+fn two_product<S>(a: S, b: S) -> (S, S)
+where
+    S: SimdScalarFloat,
+{
+    let product = a * b;
+    let error = a.mul_add(b, -product);
+
+    (product, error)
+}
@@ -0,0 +1,560 @@
+use crate::aabb::Aabb3;
+use crate::bounded::Bounded;
+use crate::support_map::SupportMap;
+use cglinalg_core::{
+    Point3,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::Isometry3;
+
+use core::fmt;
+
+
+/// A solid circular cylinder in three dimensions.
+///
+/// A cylinder is the set of points whose distance from the line segment between
+/// the endpoints `start` and `end` of its axis is at most `radius`, and whose orthogonal
+/// projection onto the line through the axis lies on the segment. The endpoints of
+/// the axis are the centers of the two circular caps of the cylinder.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cylinder3<S> {
+    start: Point3<S>,
+    end: Point3<S>,
+    radius: S,
+}
+
+impl<S> Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new cylinder from the endpoints of its axis, and its radius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Cylinder3;
+    /// #
+    /// let start = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let end = Point3::new(0_f64, 0_f64, 3_f64);
+    /// let cylinder = Cylinder3::new(start, end, 2_f64);
+    ///
+    /// assert_eq!(cylinder.start(), start);
+    /// assert_eq!(cylinder.end(), end);
+    /// assert_eq!(cylinder.radius(), 2_f64);
+    /// ```
+    #[inline]
+    pub const fn new(start: Point3<S>, end: Point3<S>, radius: S) -> Self {
+        Self { start, end, radius }
+    }
+
+    /// Get the center of the starting cap of the cylinder.
+    #[inline]
+    pub const fn start(&self) -> Point3<S> {
+        self.start
+    }
+
+    /// Get the center of the ending cap of the cylinder.
+    #[inline]
+    pub const fn end(&self) -> Point3<S> {
+        self.end
+    }
+
+    /// Get the radius of the cylinder.
+    #[inline]
+    pub const fn radius(&self) -> S {
+        self.radius
+    }
+
+    /// Compute the vector from the center of the starting cap to the center of the
+    /// ending cap of the cylinder.
+    #[inline]
+    pub fn axis(&self) -> Vector3<S> {
+        self.end - self.start
+    }
+
+    /// Compute the height of a point along the axis of the cylinder, and the
+    /// component of the point orthogonal to the axis.
+    ///
+    /// The height is zero at the starting cap, and one at the ending cap.
+    fn axial_coordinates(&self, point: &Point3<S>) -> (S, Vector3<S>) {
+        let axis = self.axis();
+        let offset = point - self.start;
+        let length_squared = axis.norm_squared();
+        if length_squared.is_zero() {
+            return (S::zero(), offset);
+        }
+
+        (offset.dot(&axis) / length_squared, offset.reject(&axis))
+    }
+
+    /// Determine whether a cylinder contains a point.
+    ///
+    /// The cylinder is closed, so points on the boundary of the cylinder are
+    /// contained in the cylinder.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Cylinder3;
+    /// #
+    /// let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 3_f64), 2_f64);
+    ///
+    /// assert!(cylinder.contains(&Point3::new(1_f64, 1_f64, 1_f64)));
+    /// assert!(cylinder.contains(&Point3::new(2_f64, 0_f64, 3_f64)));
+    /// assert!(!cylinder.contains(&Point3::new(0_f64, 0_f64, 4_f64)));
+    /// assert!(!cylinder.contains(&Point3::new(2_f64, 2_f64, 1_f64)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: &Point3<S>) -> bool {
+        let (height, radial) = self.axial_coordinates(point);
+
+        height >= S::zero() && height <= S::one() && radial.norm_squared() <= self.radius * self.radius
+    }
+
+    /// Compute the closest point of a cylinder to a point.
+    ///
+    /// The function returns the point itself when the cylinder contains the point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Cylinder3;
+    /// #
+    /// let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 3_f64), 2_f64);
+    ///
+    /// assert_eq!(cylinder.closest_point(&Point3::new(4_f64, 0_f64, 1_f64)), Point3::new(2_f64, 0_f64, 1_f64));
+    /// assert_eq!(cylinder.closest_point(&Point3::new(1_f64, 0_f64, 5_f64)), Point3::new(1_f64, 0_f64, 3_f64));
+    /// assert_eq!(cylinder.closest_point(&Point3::new(0_f64, 4_f64, -1_f64)), Point3::new(0_f64, 2_f64, 0_f64));
+    /// assert_eq!(cylinder.closest_point(&Point3::new(1_f64, 1_f64, 1_f64)), Point3::new(1_f64, 1_f64, 1_f64));
+    /// ```
+    pub fn closest_point(&self, point: &Point3<S>) -> Point3<S> {
+        // In coordinates along the axis and away from the axis, the cylinder is a
+        // rectangle, so clamping each coordinate separately gives the closest point.
+        let (height, radial) = self.axial_coordinates(point);
+        let clamped_height = S::min(S::max(height, S::zero()), S::one());
+        let radial_norm = radial.norm();
+        let clamped_radial = if radial_norm <= self.radius {
+            radial
+        } else {
+            radial * (self.radius / radial_norm)
+        };
+
+        self.start + self.axis() * clamped_height + clamped_radial
+    }
+
+    /// Compute the smallest axis-aligned bounding box containing the cylinder.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Cylinder3,
+    /// # };
+    /// #
+    /// let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 3_f64), 2_f64);
+    /// let expected = Aabb3::new(Point3::new(-2_f64, -2_f64, 0_f64), Point3::new(2_f64, 2_f64, 3_f64));
+    ///
+    /// assert_eq!(cylinder.to_aabb(), expected);
+    /// ```
+    #[inline]
+    pub fn to_aabb(&self) -> Aabb3<S> {
+        let axis = self.axis();
+        let start_cap = disk_aabb(&self.start, &axis, self.radius);
+        let end_cap = disk_aabb(&self.end, &axis, self.radius);
+
+        start_cap.union(&end_cap)
+    }
+
+    /// Transform a cylinder by an isometry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Cylinder3;
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(2_f64, 0_f64, 0_f64), 1_f64);
+    /// let isometry = Isometry3::from_parts(
+    ///     &Translation3::new(0_f64, 0_f64, 1_f64),
+    ///     &Rotation3::from_angle_z(Degrees(90_f64)),
+    /// );
+    /// let expected = Cylinder3::new(Point3::new(0_f64, 0_f64, 1_f64), Point3::new(0_f64, 2_f64, 1_f64), 1_f64);
+    /// let result = cylinder.transform_isometry(&isometry);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn transform_isometry(&self, isometry: &Isometry3<S>) -> Self {
+        Self::new(isometry.apply_point(&self.start), isometry.apply_point(&self.end), self.radius)
+    }
+}
+
+impl<S> fmt::Display for Cylinder3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Cylinder3 [start={}, end={}, radius={}]", self.start, self.end, self.radius)
+    }
+}
+
+impl<S> Bounded<S> for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn aabb(&self) -> Aabb3<S> {
+        self.to_aabb()
+    }
+}
+
+impl<S> SupportMap<S> for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S> {
+        // The support point of a cylinder is the support point of its axis, pushed out
+        // to the rim of the cap in the component of the direction orthogonal to the axis.
+        let axis = self.axis();
+        let endpoint = if axis.dot(direction) >= S::zero() { self.end } else { self.start };
+        let axis_length_squared = axis.norm_squared();
+        let radial = if axis_length_squared.is_zero() {
+            *direction
+        } else {
+            direction - axis * (axis.dot(direction) / axis_length_squared)
+        };
+        let radial_norm = radial.norm();
+        if radial_norm.is_zero() {
+            return endpoint;
+        }
+
+        endpoint + radial * (self.radius / radial_norm)
+    }
+}
+
+/// Compute the smallest axis-aligned bounding box containing a disk with center
+/// `center` and radius `radius`, lying in the plane perpendicular to `normal`.
+///
+/// A disk with a zero normal vector is bounded by the sphere of the same radius.
+pub(crate) fn disk_aabb<S>(center: &Point3<S>, normal: &Vector3<S>, radius: S) -> Aabb3<S>
+where
+    S: SimdScalarFloat,
+{
+    // The extent of the disk along the coordinate axis `e_i` is
+    // `radius * sqrt(1 - (dot(n, e_i))^2)` for the unit normal vector `n`.
+    let normal_norm_squared = normal.norm_squared();
+    let mut half_extents = Vector3::from_fill(radius);
+    if !normal_norm_squared.is_zero() {
+        for i in 0..3 {
+            let cos_squared = normal[i] * normal[i] / normal_norm_squared;
+            half_extents[i] = radius * S::max(S::one() - cos_squared, S::zero()).sqrt();
+        }
+    }
+
+    Aabb3::from_center_half_extents(center, &half_extents)
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cylinder3Tol<S> {
+    start: Vector3<S>,
+    end: Vector3<S>,
+    radius: S,
+}
+
+impl<S> Cylinder3Tol<S> {
+    #[inline]
+    pub const fn from_parts(start: Vector3<S>, end: Vector3<S>, radius: S) -> Self {
+        Self { start, end, radius }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cylinder3Diff<S> {
+    start: Vector3<S>,
+    end: Vector3<S>,
+    radius: S,
+}
+
+impl<S> Cylinder3Diff<S> {
+    #[inline]
+    const fn from_parts(start: Vector3<S>, end: Vector3<S>, radius: S) -> Self {
+        Self { start, end, radius }
+    }
+}
+
+impl<S> approx_cmp::AbsDiffEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Cylinder3Tol<<S as approx_cmp::AbsDiffEq>::Tolerance>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.start, &other.start, &max_abs_diff.start)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.end, &other.end, &max_abs_diff.end)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.radius, &other.radius, &max_abs_diff.radius)
+    }
+}
+
+impl<S> approx_cmp::AbsDiffAllEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.start, &other.start, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.end, &other.end, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.radius, &other.radius, max_abs_diff)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Cylinder3Diff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff>;
+    type DebugTolerance = Cylinder3Tol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.end, &other.end);
+        let radius = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.radius, &other.radius);
+
+        Cylinder3Diff::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+        let radius = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.radius, &other.radius, &max_abs_diff.radius);
+
+        Cylinder3Tol::from_parts(start, end, radius)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffAllEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Cylinder3Tol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+        let radius = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.radius, &other.radius, max_abs_diff);
+
+        Cylinder3Tol::from_parts(start, end, radius)
+    }
+}
+
+impl<S> approx_cmp::RelativeEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Cylinder3Tol<<S as approx_cmp::RelativeEq>::Tolerance>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.start, &other.start, &max_abs_diff.start, &max_relative.start)
+            && approx_cmp::RelativeEq::relative_eq(&self.end, &other.end, &max_abs_diff.end, &max_relative.end)
+            && approx_cmp::RelativeEq::relative_eq(&self.radius, &other.radius, &max_abs_diff.radius, &max_relative.radius)
+    }
+}
+
+impl<S> approx_cmp::RelativeAllEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.start, &other.start, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.end, &other.end, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.radius, &other.radius, max_abs_diff, max_relative)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Cylinder3Diff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff>;
+    type DebugTolerance = Cylinder3Tol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.end, &other.end);
+        let radius = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.radius, &other.radius);
+
+        Cylinder3Diff::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+        let radius = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.radius, &other.radius, &max_abs_diff.radius);
+
+        Cylinder3Tol::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.start, &other.start, &max_relative.start);
+        let end = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.end, &other.end, &max_relative.end);
+        let radius = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.radius, &other.radius, &max_relative.radius);
+
+        Cylinder3Tol::from_parts(start, end, radius)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeAllEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Cylinder3Tol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+        let radius = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.radius, &other.radius, max_abs_diff);
+
+        Cylinder3Tol::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.start, &other.start, max_relative);
+        let end = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.end, &other.end, max_relative);
+        let radius = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.radius, &other.radius, max_relative);
+
+        Cylinder3Tol::from_parts(start, end, radius)
+    }
+}
+
+impl<S> approx_cmp::UlpsEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Cylinder3Tol<<S as approx_cmp::UlpsEq>::Tolerance>;
+    type UlpsTolerance = Cylinder3Tol<<S as approx_cmp::UlpsEq>::UlpsTolerance>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.start, &other.start, &max_abs_diff.start, &max_ulps.start)
+            && approx_cmp::UlpsEq::ulps_eq(&self.end, &other.end, &max_abs_diff.end, &max_ulps.end)
+            && approx_cmp::UlpsEq::ulps_eq(&self.radius, &other.radius, &max_abs_diff.radius, &max_ulps.radius)
+    }
+}
+
+impl<S> approx_cmp::UlpsAllEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.start, &other.start, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.end, &other.end, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.radius, &other.radius, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Cylinder3Diff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff>;
+    type DebugUlpsDiff = Cylinder3Diff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff>;
+    type DebugTolerance = Cylinder3Tol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance>;
+    type DebugUlpsTolerance = Cylinder3Tol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.end, &other.end);
+        let radius = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.radius, &other.radius);
+
+        Cylinder3Diff::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let start = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.end, &other.end);
+        let radius = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.radius, &other.radius);
+
+        Cylinder3Diff::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+        let radius = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.radius, &other.radius, &max_abs_diff.radius);
+
+        Cylinder3Tol::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let start = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.start, &other.start, &max_ulps.start);
+        let end = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.end, &other.end, &max_ulps.end);
+        let radius = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.radius, &other.radius, &max_ulps.radius);
+
+        Cylinder3Tol::from_parts(start, end, radius)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsAllEq for Cylinder3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Cylinder3Tol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance>;
+    type AllDebugUlpsTolerance = Cylinder3Tol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+        let radius = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.radius, &other.radius, max_abs_diff);
+
+        Cylinder3Tol::from_parts(start, end, radius)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let start = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.start, &other.start, max_ulps);
+        let end = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.end, &other.end, max_ulps);
+        let radius = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.radius, &other.radius, max_ulps);
+
+        Cylinder3Tol::from_parts(start, end, radius)
+    }
+}
@@ -0,0 +1,471 @@
+use crate::gjk::{
+    gjk,
+    GjkStatus,
+    MinkowskiVertex,
+    Simplex,
+};
+use crate::support_map::SupportMap;
+use crate::triangle::Triangle3;
+use cglinalg_core::{
+    Point3,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::Isometry3;
+
+
+/// The maximum number of iterations of the EPA algorithm.
+const EPA_MAX_ITERATIONS: usize = 64;
+
+/// The maximum number of vertices of the expanding polytope.
+const EPA_MAX_VERTICES: usize = EPA_MAX_ITERATIONS + 4;
+
+/// The maximum number of faces of the expanding polytope, live or discarded.
+///
+/// A closed triangle mesh with `V` vertices has `2 * V - 4` faces, and the discarded
+/// faces are removed before each expansion, so there is room for every face the
+/// polytope can have at once.
+const EPA_MAX_FACES: usize = 4 * EPA_MAX_VERTICES;
+
+/// The maximum number of edges on the horizon of the expanding polytope.
+const EPA_MAX_HORIZON_EDGES: usize = 2 * EPA_MAX_VERTICES;
+
+
+/// The result of a successful penetration query between two intersecting shapes.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Penetration<S> {
+    normal: Vector3<S>,
+    depth: S,
+    point1: Point3<S>,
+    point2: Point3<S>,
+    is_converged: bool,
+}
+
+impl<S> Penetration<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new penetration query result from the contact normal, the
+    /// penetration depth, the deepest point on each shape, and whether the query
+    /// converged.
+    #[inline]
+    pub const fn new(normal: Vector3<S>, depth: S, point1: Point3<S>, point2: Point3<S>, is_converged: bool) -> Self {
+        Self {
+            normal,
+            depth,
+            point1,
+            point2,
+            is_converged,
+        }
+    }
+
+    /// Get the unit contact normal, in the world frame.
+    ///
+    /// The contact normal points from the first shape towards the second shape.
+    /// Translating the second shape by `depth * normal` separates the shapes.
+    #[inline]
+    pub const fn normal(&self) -> Vector3<S> {
+        self.normal
+    }
+
+    /// Get the penetration depth.
+    ///
+    /// The penetration depth is the length of the shortest translation that
+    /// separates the shapes. When the query did not converge, the depth is an upper
+    /// bound on the penetration depth, and translating the second shape by
+    /// `depth * normal` still separates the shapes.
+    #[inline]
+    pub const fn depth(&self) -> S {
+        self.depth
+    }
+
+    /// Get the point of the first shape deepest inside the second shape, in the
+    /// world frame.
+    #[inline]
+    pub const fn point1(&self) -> Point3<S> {
+        self.point1
+    }
+
+    /// Get the point of the second shape deepest inside the first shape, in the
+    /// world frame.
+    #[inline]
+    pub const fn point2(&self) -> Point3<S> {
+        self.point2
+    }
+
+    /// Determine whether the penetration query converged to the penetration depth.
+    ///
+    /// A query does not converge when the expanding polytope runs out of room
+    /// before it approximates the Minkowski difference of the shapes closely enough,
+    /// as can happen for pairs of curved shapes with many directions of nearly
+    /// least penetration.
+    #[inline]
+    pub const fn is_converged(&self) -> bool {
+        self.is_converged
+    }
+}
+
+
+/// A triangular face of the expanding polytope.
+#[derive(Copy, Clone, Debug)]
+struct EpaFace<S> {
+    indices: [usize; 3],
+    normal: Vector3<S>,
+    distance: S,
+    is_live: bool,
+}
+
+/// A convex polytope inside the Minkowski difference of two shapes, containing the
+/// origin, and stored in fixed capacity arrays so that the algorithm does not allocate.
+struct Polytope<S> {
+    vertices: [MinkowskiVertex<S>; EPA_MAX_VERTICES],
+    vertex_count: usize,
+    faces: [EpaFace<S>; EPA_MAX_FACES],
+    face_count: usize,
+}
+
+impl<S> Polytope<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct the initial polytope from a tetrahedron containing the origin.
+    fn from_tetrahedron(tetrahedron: &[MinkowskiVertex<S>; 4]) -> Option<Self> {
+        let empty_face = EpaFace {
+            indices: [0; 3],
+            normal: Vector3::zero(),
+            distance: S::zero(),
+            is_live: false,
+        };
+        let mut polytope = Self {
+            vertices: [tetrahedron[0]; EPA_MAX_VERTICES],
+            vertex_count: 4,
+            faces: [empty_face; EPA_MAX_FACES],
+            face_count: 0,
+        };
+        polytope.vertices[..4].copy_from_slice(tetrahedron);
+
+        // Orient the faces of the tetrahedron so that they wind counterclockwise when
+        // viewed from outside.
+        let [a, b, c, d] = tetrahedron.map(|vertex| vertex.point);
+        let orientation = (b - a).cross(&(c - a)).dot(&(d - a));
+        if orientation.is_zero() {
+            return None;
+        }
+        let faces = if orientation < S::zero() {
+            [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+        } else {
+            [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]]
+        };
+        for indices in faces {
+            if !polytope.push_face(indices) {
+                return None;
+            }
+        }
+
+        Some(polytope)
+    }
+
+    /// Add a face to the polytope, returning `false` if the face is degenerate or
+    /// there is no room left for it.
+    fn push_face(&mut self, indices: [usize; 3]) -> bool {
+        if self.face_count == EPA_MAX_FACES {
+            return false;
+        }
+
+        let [a, b, c] = indices.map(|index| self.vertices[index].point);
+        let normal = (b - a).cross(&(c - a));
+        let norm = normal.norm();
+        if norm.is_zero() {
+            return false;
+        }
+
+        let normal = normal / norm;
+        self.faces[self.face_count] = EpaFace {
+            indices,
+            normal,
+            distance: normal.dot(&a),
+            is_live: true,
+        };
+        self.face_count += 1;
+
+        true
+    }
+
+    /// Find the live face of the polytope closest to the origin.
+    fn closest_face(&self) -> Option<EpaFace<S>> {
+        self.faces[..self.face_count]
+            .iter()
+            .filter(|face| face.is_live)
+            .fold(None, |closest: Option<EpaFace<S>>, face| match closest {
+                Some(closest) if closest.distance <= face.distance => Some(closest),
+                _ => Some(*face),
+            })
+    }
+
+    /// Remove the faces discarded by previous expansions of the polytope.
+    fn remove_discarded_faces(&mut self) {
+        let mut count = 0;
+        for i in 0..self.face_count {
+            if self.faces[i].is_live {
+                self.faces[count] = self.faces[i];
+                count += 1;
+            }
+        }
+        self.face_count = count;
+    }
+
+    /// Add a vertex to the polytope, replacing the faces visible from the vertex with
+    /// a fan of faces connecting the vertex to the horizon.
+    ///
+    /// A face is visible from the vertex when the vertex lies more than `tolerance`
+    /// above the plane of the face. Coplanar faces of the Minkowski difference, such
+    /// as the faces of a box, produce vertices lying in the plane of existing faces up
+    /// to rounding errors, and treating such faces as visible would carve concave
+    /// notches into the polytope.
+    ///
+    /// The function returns `false` if the polytope could not be expanded.
+    fn expand(&mut self, vertex: MinkowskiVertex<S>, tolerance: S) -> bool {
+        if self.vertex_count == EPA_MAX_VERTICES {
+            return false;
+        }
+
+        self.remove_discarded_faces();
+
+        let mut horizon = [[0_usize; 2]; EPA_MAX_HORIZON_EDGES];
+        let mut horizon_count = 0;
+        for face in self.faces[..self.face_count].iter_mut() {
+            let origin = self.vertices[face.indices[0]].point;
+            if !face.is_live || face.normal.dot(&(vertex.point - origin)) <= tolerance {
+                continue;
+            }
+
+            // The edges shared by two visible faces appear once in each direction, and
+            // cancel, which leaves exactly the edges on the horizon.
+            face.is_live = false;
+            let [i, j, k] = face.indices;
+            for edge in [[i, j], [j, k], [k, i]] {
+                let reversed = [edge[1], edge[0]];
+                match horizon[..horizon_count].iter().position(|other| *other == reversed) {
+                    Some(position) => {
+                        horizon_count -= 1;
+                        horizon[position] = horizon[horizon_count];
+                    }
+                    None => {
+                        if horizon_count == EPA_MAX_HORIZON_EDGES {
+                            return false;
+                        }
+                        horizon[horizon_count] = edge;
+                        horizon_count += 1;
+                    }
+                }
+            }
+        }
+
+        let new_index = self.vertex_count;
+        self.vertices[new_index] = vertex;
+        self.vertex_count += 1;
+        for [i, j] in horizon[..horizon_count].iter().copied() {
+            if !self.push_face([i, j, new_index]) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Grow a simplex containing the origin into a tetrahedron containing the origin.
+///
+/// When GJK reports an intersection with a simplex of fewer than four vertices, the
+/// origin lies on the boundary of the simplex, and the missing vertices come from
+/// support points in directions transverse to the simplex.
+fn complete_simplex<S, A, B>(
+    simplex: &Simplex<S>,
+    shape1: &A,
+    isometry1: &Isometry3<S>,
+    shape2: &B,
+    isometry2: &Isometry3<S>,
+) -> Option<[MinkowskiVertex<S>; 4]>
+where
+    S: SimdScalarFloat,
+    A: SupportMap<S> + ?Sized,
+    B: SupportMap<S> + ?Sized,
+{
+    let support = |direction: &Vector3<S>| MinkowskiVertex::support(shape1, isometry1, shape2, isometry2, direction);
+    let mut vertices = simplex.vertices;
+    let mut count = simplex.count;
+    if count == 1 {
+        let candidates = [
+            Vector3::unit_x(),
+            -Vector3::unit_x(),
+            Vector3::unit_y(),
+            -Vector3::unit_y(),
+            Vector3::unit_z(),
+            -Vector3::unit_z(),
+        ];
+        let vertex = candidates
+            .iter()
+            .map(support)
+            .find(|vertex| !(vertex.point - vertices[0].point).norm_squared().is_zero())?;
+        vertices[1] = vertex;
+        count = 2;
+    }
+
+    if count == 2 {
+        let edge = vertices[1].point - vertices[0].point;
+        let [x, y, z] = [edge[0].abs(), edge[1].abs(), edge[2].abs()];
+        let axis = if x <= y && x <= z {
+            Vector3::unit_x()
+        } else if y <= z {
+            Vector3::unit_y()
+        } else {
+            Vector3::unit_z()
+        };
+        let perpendicular1 = edge.cross(&axis);
+        let perpendicular2 = edge.cross(&perpendicular1);
+        let candidates = [perpendicular1, -perpendicular1, perpendicular2, -perpendicular2];
+        let vertex = candidates
+            .iter()
+            .map(support)
+            .find(|vertex| !edge.cross(&(vertex.point - vertices[0].point)).norm_squared().is_zero())?;
+        vertices[2] = vertex;
+        count = 3;
+    }
+
+    if count == 3 {
+        let normal = (vertices[1].point - vertices[0].point).cross(&(vertices[2].point - vertices[0].point));
+        let candidates = [normal, -normal];
+        let vertex = candidates
+            .iter()
+            .map(support)
+            .find(|vertex| !normal.dot(&(vertex.point - vertices[0].point)).is_zero())?;
+        vertices[3] = vertex;
+    }
+
+    Some(vertices)
+}
+
+/// Compute the penetration depth and contact normal of two intersecting convex shapes.
+///
+/// Each shape is given in its local frame, and is placed in the world by its
+/// isometry. The function returns `None` if the shapes do not intersect, or if the
+/// intersection is too thin to determine a contact normal, as happens for instance
+/// when two flat shapes overlap in a common plane.
+///
+/// The function runs the Gilbert-Johnson-Keerthi (GJK) algorithm to detect the
+/// intersection, followed by the Expanding Polytope Algorithm (EPA) to find the face
+/// of the Minkowski difference of the shapes closest to the origin. Curved shapes are
+/// approximated by polytopes with a bounded number of vertices. When the polytope runs
+/// out of vertices before its closest face agrees with the boundary of the Minkowski
+/// difference to a small relative tolerance, the result is the direction of least
+/// support distance found, whose depth overestimates the penetration depth but still
+/// separates the shapes, and [`Penetration::is_converged`] returns `false`.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_core::{
+/// #     Point3,
+/// #     Vector3,
+/// # };
+/// # use cglinalg_geometry::{
+/// #     Aabb3,
+/// #     epa_penetration,
+/// # };
+/// # use cglinalg_transform::{
+/// #     Isometry3,
+/// #     Rotation3,
+/// #     Translation3,
+/// # };
+/// #
+/// let aabb = Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(1_f64, 1_f64, 1_f64));
+/// let isometry1 = Isometry3::identity();
+/// let isometry2 = Isometry3::from_parts(&Translation3::new(0.2_f64, 1.5_f64, 0.1_f64), &Rotation3::identity());
+/// let result = epa_penetration(&aabb, &isometry1, &aabb, &isometry2).unwrap();
+///
+/// assert_relative_eq!(result.depth(), 0.5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// assert_relative_eq!(result.normal(), Vector3::unit_y(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// ```
+pub fn epa_penetration<S, A, B>(
+    shape1: &A,
+    isometry1: &Isometry3<S>,
+    shape2: &B,
+    isometry2: &Isometry3<S>,
+) -> Option<Penetration<S>>
+where
+    S: SimdScalarFloat,
+    A: SupportMap<S> + ?Sized,
+    B: SupportMap<S> + ?Sized,
+{
+    let simplex = match gjk(shape1, isometry1, shape2, isometry2) {
+        GjkStatus::Intersecting(simplex) => simplex,
+        GjkStatus::Separated(_) => return None,
+    };
+    let tetrahedron = complete_simplex(&simplex, shape1, isometry1, shape2, isometry2)?;
+    let mut polytope = Polytope::from_tetrahedron(&tetrahedron)?;
+    let support = |direction: &Vector3<S>| MinkowskiVertex::support(shape1, isometry1, shape2, isometry2, direction);
+    let relative_tolerance = S::default_epsilon().sqrt();
+    let mut closest_face = polytope.closest_face()?;
+    let mut closest_support = support(&closest_face.normal);
+    let mut closest_support_distance = closest_face.normal.dot(&closest_support.point);
+    // The support distance of the Minkowski difference in any direction is an upper
+    // bound on the penetration depth, so the direction with the least support distance
+    // found so far still gives a separating translation if the polytope fails to
+    // converge.
+    let mut best_normal = closest_face.normal;
+    let mut best_support = closest_support;
+    let mut best_support_distance = closest_support_distance;
+    // The polytope runs out of room for vertices after at most `EPA_MAX_ITERATIONS`
+    // expansions, which bounds the number of iterations.
+    let mut is_converged = false;
+    loop {
+        // The distance of the closest face is a lower bound on the penetration depth.
+        let tolerance = relative_tolerance * S::one().max(closest_support_distance.abs());
+        if closest_support_distance - closest_face.distance <= tolerance {
+            is_converged = true;
+            break;
+        }
+        if !polytope.expand(closest_support, tolerance) {
+            break;
+        }
+
+        closest_face = match polytope.closest_face() {
+            Some(face) => face,
+            None => break,
+        };
+        closest_support = support(&closest_face.normal);
+        closest_support_distance = closest_face.normal.dot(&closest_support.point);
+        if closest_support_distance < best_support_distance {
+            best_normal = closest_face.normal;
+            best_support = closest_support;
+            best_support_distance = closest_support_distance;
+        }
+    }
+
+    if !is_converged {
+        return Some(Penetration::new(
+            best_normal,
+            best_support_distance,
+            best_support.support1,
+            best_support.support2,
+            false,
+        ));
+    }
+
+    // Express the projection of the origin onto the closest face in barycentric
+    // coordinates, and carry those over to the support points on each shape.
+    let [a, b, c] = closest_face.indices.map(|index| polytope.vertices[index]);
+    let face = Triangle3::new(
+        Point3::from_vector(&a.point),
+        Point3::from_vector(&b.point),
+        Point3::from_vector(&c.point),
+    );
+    let projection = Point3::from_vector(&(closest_face.normal * closest_face.distance));
+    let barycentric = face.barycentric(&projection)?;
+    let point1 = Triangle3::new(a.support1, b.support1, c.support1).from_barycentric(&barycentric);
+    let point2 = Triangle3::new(a.support2, b.support2, c.support2).from_barycentric(&barycentric);
+
+    Some(Penetration::new(closest_face.normal, closest_face.distance, point1, point2, true))
+}
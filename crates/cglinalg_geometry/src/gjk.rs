@@ -0,0 +1,574 @@
+use crate::support_map::SupportMap;
+use cglinalg_core::{
+    Point3,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::Isometry3;
+
+
+/// The maximum number of iterations of the GJK algorithm.
+///
+/// The GJK algorithm terminates in finitely many steps for polytopes, but only
+/// converges in the limit for curved shapes such as spheres, so the number of
+/// iterations must be bounded.
+const GJK_MAX_ITERATIONS: usize = 128;
+
+
+/// The result of a successful closest points query between two separated shapes.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ClosestPoints<S> {
+    point1: Point3<S>,
+    point2: Point3<S>,
+    distance: S,
+}
+
+impl<S> ClosestPoints<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new closest points query result from the closest point on each
+    /// shape, and the distance between the shapes.
+    #[inline]
+    pub const fn new(point1: Point3<S>, point2: Point3<S>, distance: S) -> Self {
+        Self {
+            point1,
+            point2,
+            distance,
+        }
+    }
+
+    /// Get the point on the first shape closest to the second shape, in the
+    /// world frame.
+    #[inline]
+    pub const fn point1(&self) -> Point3<S> {
+        self.point1
+    }
+
+    /// Get the point on the second shape closest to the first shape, in the
+    /// world frame.
+    #[inline]
+    pub const fn point2(&self) -> Point3<S> {
+        self.point2
+    }
+
+    /// Get the distance between the shapes.
+    #[inline]
+    pub const fn distance(&self) -> S {
+        self.distance
+    }
+
+    /// Compute the unit vector pointing from the first shape to the second shape
+    /// along the line between the closest points.
+    ///
+    /// The function returns `None` if the distance between the shapes is zero,
+    /// since touching shapes have no unique separating direction.
+    #[inline]
+    pub fn normal(&self) -> Option<Vector3<S>> {
+        if self.distance.is_zero() {
+            return None;
+        }
+
+        Some((self.point2 - self.point1) / self.distance)
+    }
+}
+
+
+/// A point of the Minkowski difference `shape1 - shape2` of two shapes, together
+/// with the support points on each shape that produced it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct MinkowskiVertex<S> {
+    pub(crate) point: Vector3<S>,
+    pub(crate) support1: Point3<S>,
+    pub(crate) support2: Point3<S>,
+}
+
+impl<S> MinkowskiVertex<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the support point of the Minkowski difference `shape1 - shape2` in the
+    /// direction `direction`.
+    #[inline]
+    pub(crate) fn support<A, B>(
+        shape1: &A,
+        isometry1: &Isometry3<S>,
+        shape2: &B,
+        isometry2: &Isometry3<S>,
+        direction: &Vector3<S>,
+    ) -> Self
+    where
+        A: SupportMap<S> + ?Sized,
+        B: SupportMap<S> + ?Sized,
+    {
+        let support1 = shape1.support_point(isometry1, direction);
+        let support2 = shape2.support_point(isometry2, &(-direction));
+
+        Self {
+            point: support1 - support2,
+            support1,
+            support2,
+        }
+    }
+}
+
+
+/// A simplex of at most four vertices in the Minkowski difference of two shapes.
+///
+/// The weights are the barycentric coordinates of the point of the simplex closest
+/// to the origin.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Simplex<S> {
+    pub(crate) vertices: [MinkowskiVertex<S>; 4],
+    pub(crate) weights: [S; 4],
+    pub(crate) count: usize,
+}
+
+impl<S> Simplex<S>
+where
+    S: SimdScalarFloat,
+{
+    fn new(vertex: MinkowskiVertex<S>) -> Self {
+        Self {
+            vertices: [vertex; 4],
+            weights: [S::one(), S::zero(), S::zero(), S::zero()],
+            count: 1,
+        }
+    }
+
+    fn push(&mut self, vertex: MinkowskiVertex<S>) {
+        self.vertices[self.count] = vertex;
+        self.count += 1;
+    }
+
+    fn contains(&self, point: &Vector3<S>) -> bool {
+        self.vertices[..self.count].iter().any(|vertex| vertex.point == *point)
+    }
+
+    /// Compute the point of the simplex closest to the origin, and discard the
+    /// vertices that do not contribute to it.
+    ///
+    /// When the origin lies inside a tetrahedral simplex, the function returns the
+    /// zero vector, and keeps all four vertices.
+    fn reduce(&mut self) -> Vector3<S> {
+        match self.count {
+            1 => {
+                self.weights[0] = S::one();
+            }
+            2 => {
+                let a = self.vertices[0].point;
+                let b = self.vertices[1].point;
+                let weights = closest_weights_segment(&a, &b);
+                self.retain(&[weights[0], weights[1]]);
+            }
+            3 => {
+                let a = self.vertices[0].point;
+                let b = self.vertices[1].point;
+                let c = self.vertices[2].point;
+                let weights = closest_weights_triangle(&a, &b, &c);
+                self.retain(&weights);
+            }
+            _ => {
+                let weights = self.closest_weights_tetrahedron();
+                self.retain(&weights);
+            }
+        }
+
+        self.closest_point()
+    }
+
+    /// Compute the closest point of a tetrahedral simplex to the origin by
+    /// searching the faces of the tetrahedron whose planes separate the origin from
+    /// the opposite vertex.
+    ///
+    /// See Christer Ericson, Real-Time Collision Detection, Section 5.1.6.
+    fn closest_weights_tetrahedron(&self) -> [S; 4] {
+        const FACES: [[usize; 4]; 4] = [[0, 1, 2, 3], [0, 3, 1, 2], [0, 2, 3, 1], [1, 3, 2, 0]];
+        let points = self.vertices.map(|vertex| vertex.point);
+        let mut result = [S::zero(); 4];
+        let mut result_distance_squared = S::infinity();
+        let mut is_inside = true;
+        for [i, j, k, opposite] in FACES {
+            let (a, b, c) = (points[i], points[j], points[k]);
+            let normal = (b - a).cross(&(c - a));
+            let side_origin = -a.dot(&normal);
+            let side_opposite = (points[opposite] - a).dot(&normal);
+            let is_outside = side_opposite.is_zero() || side_origin * side_opposite < S::zero();
+            if !is_outside {
+                continue;
+            }
+
+            is_inside = false;
+            let weights = closest_weights_triangle(&a, &b, &c);
+            let closest = a * weights[0] + b * weights[1] + c * weights[2];
+            let distance_squared = closest.norm_squared();
+            if distance_squared < result_distance_squared {
+                result = [S::zero(); 4];
+                result[i] = weights[0];
+                result[j] = weights[1];
+                result[k] = weights[2];
+                result_distance_squared = distance_squared;
+            }
+        }
+
+        if is_inside {
+            return [S::one(), S::one(), S::one(), S::one()];
+        }
+
+        result
+    }
+
+    /// Keep the vertices with positive weight, in order.
+    fn retain(&mut self, weights: &[S]) {
+        let mut count = 0;
+        for (i, weight) in weights.iter().enumerate() {
+            if *weight > S::zero() {
+                self.vertices[count] = self.vertices[i];
+                self.weights[count] = *weight;
+                count += 1;
+            }
+        }
+        self.count = count;
+    }
+
+    /// Compute the point of the simplex with the current weights.
+    fn closest_point(&self) -> Vector3<S> {
+        if self.count == 4 {
+            return Vector3::zero();
+        }
+
+        self.vertices[..self.count]
+            .iter()
+            .zip(self.weights.iter())
+            .fold(Vector3::zero(), |acc, (vertex, weight)| acc + vertex.point * *weight)
+    }
+
+    /// Compute the closest point on each shape from the weights of the simplex.
+    fn closest_supports(&self) -> (Point3<S>, Point3<S>) {
+        let mut point1 = Vector3::zero();
+        let mut point2 = Vector3::zero();
+        for (vertex, weight) in self.vertices[..self.count].iter().zip(self.weights.iter()) {
+            point1 += vertex.support1.to_vector() * *weight;
+            point2 += vertex.support2.to_vector() * *weight;
+        }
+
+        (Point3::from_vector(&point1), Point3::from_vector(&point2))
+    }
+}
+
+/// Compute the barycentric coordinates of the point on a segment closest to
+/// the origin.
+fn closest_weights_segment<S>(a: &Vector3<S>, b: &Vector3<S>) -> [S; 2]
+where
+    S: SimdScalarFloat,
+{
+    let ab = b - a;
+    let length_squared = ab.norm_squared();
+    if length_squared.is_zero() {
+        return [S::one(), S::zero()];
+    }
+
+    let t = -a.dot(&ab) / length_squared;
+    if t <= S::zero() {
+        [S::one(), S::zero()]
+    } else if t >= S::one() {
+        [S::zero(), S::one()]
+    } else {
+        [S::one() - t, t]
+    }
+}
+
+/// Compute the barycentric coordinates of the point on a triangle closest to
+/// the origin.
+///
+/// See Christer Ericson, Real-Time Collision Detection, Section 5.1.5.
+fn closest_weights_triangle<S>(a: &Vector3<S>, b: &Vector3<S>, c: &Vector3<S>) -> [S; 3]
+where
+    S: SimdScalarFloat,
+{
+    let ab = b - a;
+    let ac = c - a;
+
+    let d1 = -ab.dot(a);
+    let d2 = -ac.dot(a);
+    if d1 <= S::zero() && d2 <= S::zero() {
+        return [S::one(), S::zero(), S::zero()];
+    }
+
+    let d3 = -ab.dot(b);
+    let d4 = -ac.dot(b);
+    if d3 >= S::zero() && d4 <= d3 {
+        return [S::zero(), S::one(), S::zero()];
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= S::zero() && d1 >= S::zero() && d3 <= S::zero() {
+        let v = d1 / (d1 - d3);
+        return [S::one() - v, v, S::zero()];
+    }
+
+    let d5 = -ab.dot(c);
+    let d6 = -ac.dot(c);
+    if d6 >= S::zero() && d5 <= d6 {
+        return [S::zero(), S::zero(), S::one()];
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= S::zero() && d2 >= S::zero() && d6 <= S::zero() {
+        let w = d2 / (d2 - d6);
+        return [S::one() - w, S::zero(), w];
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= S::zero() && (d4 - d3) >= S::zero() && (d5 - d6) >= S::zero() {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return [S::zero(), S::one() - w, w];
+    }
+
+    let denominator = va + vb + vc;
+    if denominator.is_zero() {
+        // The triangle is degenerate, so the closest point lies on one of its edges.
+        let [u_ab, v_ab] = closest_weights_segment(a, b);
+        let [u_bc, v_bc] = closest_weights_segment(b, c);
+        let [u_ca, v_ca] = closest_weights_segment(c, a);
+        let candidates = [
+            [u_ab, v_ab, S::zero()],
+            [S::zero(), u_bc, v_bc],
+            [v_ca, S::zero(), u_ca],
+        ];
+        let distance_squared = |weights: &[S; 3]| (a * weights[0] + b * weights[1] + c * weights[2]).norm_squared();
+
+        return candidates
+            .into_iter()
+            .fold(candidates[0], |best, candidate| {
+                if distance_squared(&candidate) < distance_squared(&best) { candidate } else { best }
+            });
+    }
+
+    let v = vb / denominator;
+    let w = vc / denominator;
+
+    [S::one() - v - w, v, w]
+}
+
+
+/// The outcome of running the GJK algorithm on a pair of shapes.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum GjkStatus<S> {
+    /// The shapes are separated, and the simplex contains the closest points.
+    Separated(Simplex<S>),
+    /// The shapes intersect, and the simplex contains the origin, up to tolerance.
+    Intersecting(Simplex<S>),
+}
+
+/// Run the Gilbert-Johnson-Keerthi algorithm on two shapes.
+///
+/// See Gino van den Bergen, Collision Detection in Interactive 3D Environments,
+/// Section 4.3.
+pub(crate) fn gjk<S, A, B>(shape1: &A, isometry1: &Isometry3<S>, shape2: &B, isometry2: &Isometry3<S>) -> GjkStatus<S>
+where
+    S: SimdScalarFloat,
+    A: SupportMap<S> + ?Sized,
+    B: SupportMap<S> + ?Sized,
+{
+    // The error in the closest points is on the order of the square root of the error
+    // in the squared distance, so the relative tolerance needs to be small.
+    let relative_tolerance = S::default_epsilon() * cglinalg_numeric::cast(1024_f64);
+    let absolute_tolerance_factor: S = cglinalg_numeric::cast(64_f64);
+    let absolute_tolerance = absolute_tolerance_factor * S::machine_epsilon();
+    let initial_direction = Vector3::unit_x();
+    let initial_vertex = MinkowskiVertex::support(shape1, isometry1, shape2, isometry2, &initial_direction);
+    let mut simplex = Simplex::new(initial_vertex);
+    let mut closest = initial_vertex.point;
+    let mut max_norm_squared = closest.norm_squared();
+    for _ in 0..GJK_MAX_ITERATIONS {
+        let closest_norm_squared = closest.norm_squared();
+        if closest_norm_squared <= absolute_tolerance * max_norm_squared {
+            return GjkStatus::Intersecting(simplex);
+        }
+
+        let vertex = MinkowskiVertex::support(shape1, isometry1, shape2, isometry2, &(-closest));
+        if simplex.contains(&vertex.point) {
+            return GjkStatus::Separated(simplex);
+        }
+
+        // The gap `norm(v)^2 - dot(v, w)` bounds the error in the squared distance.
+        let gap = closest_norm_squared - closest.dot(&vertex.point);
+        if gap <= relative_tolerance * closest_norm_squared {
+            return GjkStatus::Separated(simplex);
+        }
+
+        let previous_simplex = simplex;
+        simplex.push(vertex);
+        max_norm_squared = max_norm_squared.max(vertex.point.norm_squared());
+        let new_closest = simplex.reduce();
+        if simplex.count == 4 {
+            return GjkStatus::Intersecting(simplex);
+        }
+
+        if new_closest.norm_squared() >= closest_norm_squared {
+            // Rounding errors stalled the algorithm, so the previous simplex is the
+            // best approximation we have.
+            return GjkStatus::Separated(previous_simplex);
+        }
+
+        closest = new_closest;
+    }
+
+    GjkStatus::Separated(simplex)
+}
+
+/// Compute the closest points between two convex shapes.
+///
+/// Each shape is given in its local frame, and is placed in the world by its
+/// isometry. The function returns the closest points on each shape in the world
+/// frame, and the distance between them. The function returns `None` if the shapes
+/// intersect.
+///
+/// The function uses the Gilbert-Johnson-Keerthi (GJK) algorithm, which only requires
+/// a [`SupportMap`] for each shape.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_core::{
+/// #     Point3,
+/// #     Vector3,
+/// # };
+/// # use cglinalg_geometry::{
+/// #     Aabb3,
+/// #     Sphere3,
+/// #     gjk_closest_points,
+/// # };
+/// # use cglinalg_transform::{
+/// #     Isometry3,
+/// #     Rotation3,
+/// #     Translation3,
+/// # };
+/// #
+/// let sphere = Sphere3::new(Point3::origin(), 1_f64);
+/// let aabb = Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(1_f64, 1_f64, 1_f64));
+/// let isometry1 = Isometry3::identity();
+/// let isometry2 = Isometry3::from_parts(&Translation3::new(5_f64, 0_f64, 0_f64), &Rotation3::identity());
+/// let result = gjk_closest_points(&sphere, &isometry1, &aabb, &isometry2).unwrap();
+///
+/// assert_relative_eq!(result.distance(), 3_f64, abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+/// assert_relative_eq!(result.point1(), Point3::new(1_f64, 0_f64, 0_f64), abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+/// assert_relative_eq!(result.normal().unwrap(), Vector3::unit_x(), abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+/// ```
+pub fn gjk_closest_points<S, A, B>(
+    shape1: &A,
+    isometry1: &Isometry3<S>,
+    shape2: &B,
+    isometry2: &Isometry3<S>,
+) -> Option<ClosestPoints<S>>
+where
+    S: SimdScalarFloat,
+    A: SupportMap<S> + ?Sized,
+    B: SupportMap<S> + ?Sized,
+{
+    match gjk(shape1, isometry1, shape2, isometry2) {
+        GjkStatus::Separated(simplex) => {
+            let (point1, point2) = simplex.closest_supports();
+            let distance = (point2 - point1).norm();
+
+            Some(ClosestPoints::new(point1, point2, distance))
+        }
+        GjkStatus::Intersecting(_) => None,
+    }
+}
+
+/// Compute the distance between two convex shapes.
+///
+/// Each shape is given in its local frame, and is placed in the world by its
+/// isometry. The distance between intersecting shapes is zero.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_core::Point3;
+/// # use cglinalg_geometry::{
+/// #     Capsule3,
+/// #     gjk_distance,
+/// # };
+/// # use cglinalg_transform::{
+/// #     Isometry3,
+/// #     Rotation3,
+/// #     Translation3,
+/// # };
+/// #
+/// let capsule = Capsule3::new(Point3::new(0_f64, -1_f64, 0_f64), Point3::new(0_f64, 1_f64, 0_f64), 0.5_f64);
+/// let isometry1 = Isometry3::identity();
+/// let isometry2 = Isometry3::from_parts(&Translation3::new(0_f64, 0_f64, 3_f64), &Rotation3::identity());
+/// let isometry3 = Isometry3::from_parts(&Translation3::new(0_f64, 0_f64, 0.5_f64), &Rotation3::identity());
+///
+/// let distance = gjk_distance(&capsule, &isometry1, &capsule, &isometry2);
+///
+/// assert_relative_eq!(distance, 2_f64, abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+/// assert_eq!(gjk_distance(&capsule, &isometry1, &capsule, &isometry3), 0_f64);
+/// ```
+#[inline]
+pub fn gjk_distance<S, A, B>(shape1: &A, isometry1: &Isometry3<S>, shape2: &B, isometry2: &Isometry3<S>) -> S
+where
+    S: SimdScalarFloat,
+    A: SupportMap<S> + ?Sized,
+    B: SupportMap<S> + ?Sized,
+{
+    gjk_closest_points(shape1, isometry1, shape2, isometry2)
+        .map(|closest_points| closest_points.distance())
+        .unwrap_or(S::zero())
+}
+
+/// Determine whether two convex shapes intersect.
+///
+/// Each shape is given in its local frame, and is placed in the world by its
+/// isometry. Shapes that touch intersect, up to the tolerance of the algorithm.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     Point3,
+/// #     Unit,
+/// #     Vector3,
+/// # };
+/// # use cglinalg_geometry::{
+/// #     Obb3,
+/// #     PointCloud3,
+/// #     gjk_intersects,
+/// # };
+/// # use cglinalg_transform::{
+/// #     Isometry3,
+/// #     Rotation3,
+/// #     Translation3,
+/// # };
+/// # use cglinalg_trigonometry::Degrees;
+/// #
+/// let obb = Obb3::new(Point3::origin(), Vector3::new(1_f64, 1_f64, 1_f64), Rotation3::identity());
+/// let points = [
+///     Point3::new(0_f64, 0_f64, 0_f64),
+///     Point3::new(1_f64, 0_f64, 0_f64),
+///     Point3::new(0_f64, 1_f64, 0_f64),
+///     Point3::new(0_f64, 0_f64, 1_f64),
+/// ];
+/// let point_cloud = PointCloud3::new(&points).unwrap();
+/// let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64)), Degrees(30_f64));
+/// let isometry1 = Isometry3::identity();
+/// let isometry2 = Isometry3::from_parts(&Translation3::new(0.5_f64, 0.5_f64, 0.5_f64), &rotation);
+/// let isometry3 = Isometry3::from_parts(&Translation3::new(0_f64, 0_f64, 2.5_f64), &rotation);
+///
+/// assert!(gjk_intersects(&obb, &isometry1, &point_cloud, &isometry2));
+/// assert!(!gjk_intersects(&obb, &isometry1, &point_cloud, &isometry3));
+/// ```
+#[inline]
+pub fn gjk_intersects<S, A, B>(shape1: &A, isometry1: &Isometry3<S>, shape2: &B, isometry2: &Isometry3<S>) -> bool
+where
+    S: SimdScalarFloat,
+    A: SupportMap<S> + ?Sized,
+    B: SupportMap<S> + ?Sized,
+{
+    matches!(gjk(shape1, isometry1, shape2, isometry2), GjkStatus::Intersecting(_))
+}
@@ -0,0 +1,852 @@
+use cglinalg_core::{
+    Point,
+    Point3,
+    Unit,
+    Vector,
+    Vector4,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::{
+    Isometry,
+    Reflection,
+    Transform3,
+};
+
+use core::fmt;
+
+
+/// A line in two dimensions, viewed as a hyperplane.
+pub type Hyperplane2<S> = Hyperplane<S, 2>;
+
+/// A plane in three dimensions.
+pub type Hyperplane3<S> = Hyperplane<S, 3>;
+
+
+/// A hyperplane in `N` dimensions.
+///
+/// A hyperplane is the set of points
+///
+/// ```text
+/// { x in R^N | dot(normal, x) == distance }
+/// ```
+///
+/// where `normal` is a unit vector normal to the hyperplane, and `distance` is the
+/// signed distance from the origin to the hyperplane along `normal`. The normal
+/// orients the hyperplane: points on the side of the hyperplane that the normal points
+/// towards have positive signed distance, and points on the other side have negative
+/// signed distance. In two dimensions a hyperplane is a line, and in three dimensions
+/// a hyperplane is a plane.
+///
+/// This is the most general hyperplane type. The vast majority of applications
+/// should use [`Hyperplane2`] or [`Hyperplane3`] instead of this type directly.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hyperplane<S, const N: usize> {
+    normal: Vector<S, N>,
+    distance: S,
+}
+
+impl<S, const N: usize> Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new hyperplane from its unit normal vector and its signed distance
+    /// from the origin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// // The plane `z == 2`.
+    /// let normal = Unit::from_value(Vector3::unit_z());
+    /// let plane = Hyperplane3::from_normal_distance(&normal, 2_f64);
+    ///
+    /// assert_eq!(plane.normal(), Vector3::unit_z());
+    /// assert_eq!(plane.distance(), 2_f64);
+    /// assert_eq!(plane.signed_distance(&Point3::new(1_f64, 1_f64, 2_f64)), 0_f64);
+    /// ```
+    #[inline]
+    pub fn from_normal_distance(normal: &Unit<Vector<S, N>>, distance: S) -> Self {
+        Self {
+            normal: normal.into_inner(),
+            distance,
+        }
+    }
+
+    /// Construct a new hyperplane from its unit normal vector and a point lying in
+    /// the hyperplane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// let normal = Unit::from_value(Vector3::new(0_f64, 3_f64, 0_f64));
+    /// let point = Point3::new(4_f64, 5_f64, 6_f64);
+    /// let plane = Hyperplane3::from_normal_point(&normal, &point);
+    ///
+    /// assert_eq!(plane.normal(), Vector3::unit_y());
+    /// assert_eq!(plane.distance(), 5_f64);
+    /// ```
+    #[inline]
+    pub fn from_normal_point(normal: &Unit<Vector<S, N>>, point: &Point<S, N>) -> Self {
+        let normal = normal.into_inner();
+        let distance = normal.dot(&point.to_vector());
+
+        Self { normal, distance }
+    }
+
+    /// Get the unit normal vector of the hyperplane.
+    #[inline]
+    pub const fn normal(&self) -> Vector<S, N> {
+        self.normal
+    }
+
+    /// Get the signed distance from the origin to the hyperplane along the normal
+    /// of the hyperplane.
+    #[inline]
+    pub const fn distance(&self) -> S {
+        self.distance
+    }
+
+    /// Get the point in the hyperplane closest to the origin.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// let normal = Unit::from_value(-Vector3::unit_x());
+    /// let plane = Hyperplane3::from_normal_distance(&normal, 3_f64);
+    ///
+    /// assert_eq!(plane.origin(), Point3::new(-3_f64, 0_f64, 0_f64));
+    /// ```
+    #[inline]
+    pub fn origin(&self) -> Point<S, N> {
+        Point::from_vector(&(self.normal * self.distance))
+    }
+
+    /// Construct the hyperplane with the same points and the opposite orientation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// let normal = Unit::from_value(Vector3::unit_z());
+    /// let plane = Hyperplane3::from_normal_distance(&normal, 2_f64);
+    /// let flipped = plane.flip();
+    /// let point = Point3::new(1_f64, 2_f64, 5_f64);
+    ///
+    /// assert_eq!(flipped.normal(), -Vector3::unit_z());
+    /// assert_eq!(flipped.distance(), -2_f64);
+    /// assert_eq!(flipped.signed_distance(&point), -plane.signed_distance(&point));
+    /// ```
+    #[inline]
+    pub fn flip(&self) -> Self {
+        Self {
+            normal: -self.normal,
+            distance: -self.distance,
+        }
+    }
+
+    /// Compute the signed distance from a point to the hyperplane.
+    ///
+    /// The signed distance is positive on the side of the hyperplane that the normal
+    /// points towards, negative on the opposite side, and zero in the hyperplane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// let normal = Unit::from_value(Vector3::unit_z());
+    /// let plane = Hyperplane3::from_normal_distance(&normal, 2_f64);
+    ///
+    /// assert_eq!(plane.signed_distance(&Point3::new(1_f64, 2_f64, 5_f64)), 3_f64);
+    /// assert_eq!(plane.signed_distance(&Point3::new(1_f64, 2_f64, -1_f64)), -3_f64);
+    /// ```
+    #[inline]
+    pub fn signed_distance(&self, point: &Point<S, N>) -> S {
+        self.normal.dot(&point.to_vector()) - self.distance
+    }
+
+    /// Project a point orthogonally onto the hyperplane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// let normal = Unit::from_value(Vector3::unit_z());
+    /// let plane = Hyperplane3::from_normal_distance(&normal, 2_f64);
+    /// let point = Point3::new(1_f64, 2_f64, 5_f64);
+    ///
+    /// assert_eq!(plane.project_point(&point), Point3::new(1_f64, 2_f64, 2_f64));
+    /// ```
+    #[inline]
+    pub fn project_point(&self, point: &Point<S, N>) -> Point<S, N> {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Reflect a point about the hyperplane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// let normal = Unit::from_value(Vector3::unit_z());
+    /// let plane = Hyperplane3::from_normal_distance(&normal, 2_f64);
+    /// let point = Point3::new(1_f64, 2_f64, 5_f64);
+    ///
+    /// assert_eq!(plane.reflect_point(&point), Point3::new(1_f64, 2_f64, -1_f64));
+    /// ```
+    #[inline]
+    pub fn reflect_point(&self, point: &Point<S, N>) -> Point<S, N> {
+        let two = S::one() + S::one();
+
+        point - self.normal * (two * self.signed_distance(point))
+    }
+
+    /// Intersect the hyperplane with the line passing through the point `point`
+    /// in the direction `direction`.
+    ///
+    /// The function returns `None` when the line is parallel to the hyperplane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// let normal = Unit::from_value(Vector3::unit_z());
+    /// let plane = Hyperplane3::from_normal_distance(&normal, 2_f64);
+    /// let point = Point3::new(1_f64, 1_f64, 0_f64);
+    /// let direction = Vector3::new(1_f64, 0_f64, 1_f64);
+    ///
+    /// assert_eq!(plane.intersect_line(&point, &direction), Some(Point3::new(3_f64, 1_f64, 2_f64)));
+    /// assert_eq!(plane.intersect_line(&point, &Vector3::unit_x()), None);
+    /// ```
+    #[inline]
+    pub fn intersect_line(&self, point: &Point<S, N>, direction: &Vector<S, N>) -> Option<Point<S, N>> {
+        let denominator = self.normal.dot(direction);
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let t = -self.signed_distance(point) / denominator;
+
+        Some(point + direction * t)
+    }
+
+    /// Transform a hyperplane by an isometry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Rotation3,
+    /// #     Translation3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let plane = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::unit_z()), 2_f64);
+    /// let isometry = Isometry3::from_parts(
+    ///     &Translation3::from_vector(&Vector3::new(0_f64, 0_f64, 1_f64)),
+    ///     &Rotation3::from_angle_x(Degrees(90_f64)),
+    /// );
+    /// let expected = Hyperplane3::from_normal_distance(&Unit::from_value(-Vector3::unit_y()), 2_f64);
+    /// let result = plane.transform_isometry(&isometry);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn transform_isometry(&self, isometry: &Isometry<S, N>) -> Self {
+        let normal = isometry.apply_vector(&self.normal);
+        let origin = isometry.apply_point(&self.origin());
+        let distance = normal.dot(&origin.to_vector());
+
+        Self { normal, distance }
+    }
+}
+
+impl<S> Hyperplane3<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a plane passing through the three points `a`, `b`, and `c`.
+    ///
+    /// The normal of the plane is given by the right-hand rule with respect to
+    /// the winding order `a`, `b`, `c`. The function returns `None` when the points
+    /// are collinear.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// let a = Point3::new(0_f64, 0_f64, 3_f64);
+    /// let b = Point3::new(1_f64, 0_f64, 3_f64);
+    /// let c = Point3::new(0_f64, 1_f64, 3_f64);
+    /// let plane = Hyperplane3::from_points(&a, &b, &c).unwrap();
+    ///
+    /// assert_eq!(plane.normal(), Vector3::unit_z());
+    /// assert_eq!(plane.distance(), 3_f64);
+    ///
+    /// let collinear = Point3::new(2_f64, 0_f64, 3_f64);
+    ///
+    /// assert!(Hyperplane3::from_points(&a, &b, &collinear).is_none());
+    /// ```
+    #[inline]
+    pub fn from_points(a: &Point3<S>, b: &Point3<S>, c: &Point3<S>) -> Option<Self> {
+        let normal = (b - a).cross(&(c - a));
+        let norm = normal.norm();
+        if norm.is_zero() {
+            return None;
+        }
+
+        Some(Self::from_normal_point(&Unit::from_value_unchecked(normal / norm), a))
+    }
+
+    /// Construct a plane from the coefficients of its equation.
+    ///
+    /// The coefficients `[a, b, c, d]` describe the plane
+    ///
+    /// ```text
+    /// a * x + b * y + c * z + d == 0
+    /// ```
+    ///
+    /// The coefficients are normalized so that the normal of the plane is a unit
+    /// vector. The function returns `None` when `[a, b, c] == [0, 0, 0]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Vector3,
+    /// #     Vector4,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// // The plane `2 * y - 6 == 0`.
+    /// let equation = Vector4::new(0_f64, 2_f64, 0_f64, -6_f64);
+    /// let plane = Hyperplane3::from_equation(&equation).unwrap();
+    ///
+    /// assert_eq!(plane.normal(), Vector3::unit_y());
+    /// assert_eq!(plane.distance(), 3_f64);
+    /// ```
+    #[inline]
+    pub fn from_equation(equation: &Vector4<S>) -> Option<Self> {
+        let normal = equation.contract();
+        let norm = normal.norm();
+        if norm.is_zero() {
+            return None;
+        }
+
+        Some(Self {
+            normal: normal / norm,
+            distance: -equation[3] / norm,
+        })
+    }
+
+    /// Get the normalized coefficients of the equation of the plane.
+    ///
+    /// The coefficients `[a, b, c, d]` describe the plane
+    ///
+    /// ```text
+    /// a * x + b * y + c * z + d == 0
+    /// ```
+    ///
+    /// where `[a, b, c]` is the unit normal of the plane. Equivalently, the signed
+    /// distance from a point `p` to the plane is `dot(equation, [p.x, p.y, p.z, 1])`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Unit,
+    /// #     Vector3,
+    /// #     Vector4,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// let normal = Unit::from_value(Vector3::unit_y());
+    /// let plane = Hyperplane3::from_normal_distance(&normal, 3_f64);
+    ///
+    /// assert_eq!(plane.to_equation(), Vector4::new(0_f64, 1_f64, 0_f64, -3_f64));
+    /// ```
+    #[inline]
+    pub fn to_equation(&self) -> Vector4<S> {
+        self.normal.extend(-self.distance)
+    }
+
+    /// Compute the common point of three planes.
+    ///
+    /// The function returns `None` when the normals of the planes are linearly
+    /// dependent, in which case the planes do not meet in a single point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// #
+    /// let plane_x = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::unit_x()), 1_f64);
+    /// let plane_y = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::unit_y()), 2_f64);
+    /// let plane_z = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::unit_z()), 3_f64);
+    /// let expected = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let result = plane_x.intersect_planes(&plane_y, &plane_z).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(plane_x.intersect_planes(&plane_y, &plane_x.flip()).is_none());
+    /// ```
+    #[inline]
+    pub fn intersect_planes(&self, plane1: &Self, plane2: &Self) -> Option<Point3<S>> {
+        // By Cramer's rule, the solution to the linear system
+        // ```text
+        // dot(n0, x) == d0
+        // dot(n1, x) == d1
+        // dot(n2, x) == d2
+        // ```
+        // is given by
+        // ```text
+        // x == (d0 * (n1 x n2) + d1 * (n2 x n0) + d2 * (n0 x n1)) / dot(n0, n1 x n2)
+        // ```
+        let n1_cross_n2 = plane1.normal.cross(&plane2.normal);
+        let determinant = self.normal.dot(&n1_cross_n2);
+        if determinant.is_zero() {
+            return None;
+        }
+
+        let n2_cross_n0 = plane2.normal.cross(&self.normal);
+        let n0_cross_n1 = self.normal.cross(&plane1.normal);
+        let numerator = n1_cross_n2 * self.distance + n2_cross_n0 * plane1.distance + n0_cross_n1 * plane2.distance;
+
+        Some(Point3::from_vector(&(numerator / determinant)))
+    }
+
+    /// Transform a plane by a generic transformation.
+    ///
+    /// Planes transform by the inverse transpose of the matrix of the transformation,
+    /// i.e. if `M` is the matrix of the transformation, the equation of the transformed
+    /// plane is
+    ///
+    /// ```text
+    /// equation' := transpose(inverse(M)) * equation
+    /// ```
+    ///
+    /// so that a point `p` lies in the plane if and only if `M * p` lies in the
+    /// transformed plane. The function returns `None` when the transformation is not
+    /// invertible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Hyperplane3;
+    /// # use cglinalg_transform::{
+    /// #     Scale3,
+    /// #     Transform3,
+    /// # };
+    /// #
+    /// let plane = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64)), 1_f64);
+    /// let scale = Scale3::from_nonuniform_scale(&Vector3::new(2_f64, 1_f64, 1_f64));
+    /// let transform = scale.to_transform();
+    /// let result = plane.transform(&transform).unwrap();
+    /// let point = Point3::new(f64::sqrt(2_f64), 0_f64, 0_f64);
+    ///
+    /// assert_relative_eq!(plane.signed_distance(&point), 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(
+    ///     result.signed_distance(&transform.apply_point(&point)),
+    ///     0_f64,
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// ```
+    #[inline]
+    pub fn transform(&self, transform: &Transform3<S>) -> Option<Self> {
+        let inverse = transform.try_inverse()?;
+        let equation = inverse.matrix().transpose() * self.to_equation();
+
+        Self::from_equation(&equation)
+    }
+}
+
+impl<S, const N: usize> From<Reflection<S, N>> for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(reflection: Reflection<S, N>) -> Self {
+        Self::from(&reflection)
+    }
+}
+
+impl<S, const N: usize> From<&Reflection<S, N>> for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(reflection: &Reflection<S, N>) -> Self {
+        Self::from_normal_point(&Unit::from_value_unchecked(reflection.normal()), &reflection.bias())
+    }
+}
+
+impl<S, const N: usize> From<Hyperplane<S, N>> for Reflection<S, N>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(hyperplane: Hyperplane<S, N>) -> Self {
+        Self::from(&hyperplane)
+    }
+}
+
+impl<S, const N: usize> From<&Hyperplane<S, N>> for Reflection<S, N>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(hyperplane: &Hyperplane<S, N>) -> Self {
+        Reflection::from_normal_bias(&Unit::from_value_unchecked(hyperplane.normal), &hyperplane.origin())
+    }
+}
+
+impl<S, const N: usize> fmt::Display for Hyperplane<S, N>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Hyperplane{} [normal={}, distance={}]", N, self.normal, self.distance)
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HyperplaneTol<S, const N: usize> {
+    normal: Vector<S, N>,
+    distance: S,
+}
+
+impl<S, const N: usize> HyperplaneTol<S, N> {
+    #[inline]
+    pub const fn from_parts(normal: Vector<S, N>, distance: S) -> Self {
+        Self { normal, distance }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HyperplaneDiff<S, const N: usize> {
+    normal: Vector<S, N>,
+    distance: S,
+}
+
+impl<S, const N: usize> HyperplaneDiff<S, N> {
+    #[inline]
+    const fn from_parts(normal: Vector<S, N>, distance: S) -> Self {
+        Self { normal, distance }
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = HyperplaneTol<<S as approx_cmp::AbsDiffEq>::Tolerance, N>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.normal, &other.normal, &max_abs_diff.normal)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.distance, &other.distance, &max_abs_diff.distance)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffAllEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.normal, &other.normal, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.distance, &other.distance, max_abs_diff)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = HyperplaneDiff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff, N>;
+    type DebugTolerance = HyperplaneTol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let normal = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.normal, &other.normal);
+        let distance = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.distance, &other.distance);
+
+        HyperplaneDiff::from_parts(normal, distance)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let normal = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.normal, &other.normal, &max_abs_diff.normal);
+        let distance = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.distance, &other.distance, &max_abs_diff.distance);
+
+        HyperplaneTol::from_parts(normal, distance)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffAllEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = HyperplaneTol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let normal = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.normal, &other.normal, max_abs_diff);
+        let distance = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.distance, &other.distance, max_abs_diff);
+
+        HyperplaneTol::from_parts(normal, distance)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = HyperplaneTol<<S as approx_cmp::RelativeEq>::Tolerance, N>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.normal, &other.normal, &max_abs_diff.normal, &max_relative.normal)
+            && approx_cmp::RelativeEq::relative_eq(&self.distance, &other.distance, &max_abs_diff.distance, &max_relative.distance)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeAllEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.normal, &other.normal, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.distance, &other.distance, max_abs_diff, max_relative)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = HyperplaneDiff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff, N>;
+    type DebugTolerance = HyperplaneTol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let normal = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.normal, &other.normal);
+        let distance = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.distance, &other.distance);
+
+        HyperplaneDiff::from_parts(normal, distance)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let normal = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.normal, &other.normal, &max_abs_diff.normal);
+        let distance = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.distance, &other.distance, &max_abs_diff.distance);
+
+        HyperplaneTol::from_parts(normal, distance)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let normal = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.normal, &other.normal, &max_relative.normal);
+        let distance = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.distance, &other.distance, &max_relative.distance);
+
+        HyperplaneTol::from_parts(normal, distance)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeAllEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = HyperplaneTol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let normal = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.normal, &other.normal, max_abs_diff);
+        let distance = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.distance, &other.distance, max_abs_diff);
+
+        HyperplaneTol::from_parts(normal, distance)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let normal = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.normal, &other.normal, max_relative);
+        let distance = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.distance, &other.distance, max_relative);
+
+        HyperplaneTol::from_parts(normal, distance)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = HyperplaneTol<<S as approx_cmp::UlpsEq>::Tolerance, N>;
+    type UlpsTolerance = HyperplaneTol<<S as approx_cmp::UlpsEq>::UlpsTolerance, N>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.normal, &other.normal, &max_abs_diff.normal, &max_ulps.normal)
+            && approx_cmp::UlpsEq::ulps_eq(&self.distance, &other.distance, &max_abs_diff.distance, &max_ulps.distance)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsAllEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.normal, &other.normal, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.distance, &other.distance, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = HyperplaneDiff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff, N>;
+    type DebugUlpsDiff = HyperplaneDiff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff, N>;
+    type DebugTolerance = HyperplaneTol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance, N>;
+    type DebugUlpsTolerance = HyperplaneTol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let normal = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.normal, &other.normal);
+        let distance = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.distance, &other.distance);
+
+        HyperplaneDiff::from_parts(normal, distance)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let normal = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.normal, &other.normal);
+        let distance = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.distance, &other.distance);
+
+        HyperplaneDiff::from_parts(normal, distance)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let normal = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.normal, &other.normal, &max_abs_diff.normal);
+        let distance = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.distance, &other.distance, &max_abs_diff.distance);
+
+        HyperplaneTol::from_parts(normal, distance)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let normal = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.normal, &other.normal, &max_ulps.normal);
+        let distance = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.distance, &other.distance, &max_ulps.distance);
+
+        HyperplaneTol::from_parts(normal, distance)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsAllEq for Hyperplane<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = HyperplaneTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance, N>;
+    type AllDebugUlpsTolerance = HyperplaneTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let normal = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.normal, &other.normal, max_abs_diff);
+        let distance = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.distance, &other.distance, max_abs_diff);
+
+        HyperplaneTol::from_parts(normal, distance)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let normal = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.normal, &other.normal, max_ulps);
+        let distance = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.distance, &other.distance, max_ulps);
+
+        HyperplaneTol::from_parts(normal, distance)
+    }
+}
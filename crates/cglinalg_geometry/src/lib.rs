@@ -0,0 +1,44 @@
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+
+mod aabb;
+mod bounded;
+#[cfg(feature = "alloc")]
+mod bvh;
+mod capsule;
+mod cone;
+#[cfg(feature = "alloc")]
+mod convex_hull;
+mod cylinder;
+mod epa;
+mod gjk;
+mod hyperplane;
+mod line;
+mod obb;
+mod ray;
+mod segment;
+mod sphere;
+mod support_map;
+mod triangle;
+
+
+pub use aabb::*;
+pub use bounded::*;
+#[cfg(feature = "alloc")]
+pub use bvh::*;
+pub use capsule::*;
+pub use cone::*;
+#[cfg(feature = "alloc")]
+pub use convex_hull::*;
+pub use cylinder::*;
+pub use epa::*;
+pub use gjk::*;
+pub use hyperplane::*;
+pub use line::*;
+pub use obb::*;
+pub use ray::*;
+pub use segment::*;
+pub use sphere::*;
+pub use support_map::*;
+pub use triangle::*;
@@ -0,0 +1,479 @@
+use cglinalg_core::{
+    Point,
+    Vector,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// A line in two dimensions.
+pub type Line2<S> = Line<S, 2>;
+
+/// A line in three dimensions.
+pub type Line3<S> = Line<S, 3>;
+
+
+/// An infinite line.
+///
+/// A line is the set of points
+///
+/// ```text
+/// { origin + t * direction | t in R }
+/// ```
+///
+/// where `origin` is a point on the line, and `direction` is the nonzero direction
+/// of the line. The direction of a line need not be a unit vector.
+///
+/// This is the most general line type. The vast majority of applications should use
+/// [`Line2`] or [`Line3`] instead of this type directly.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Line<S, const N: usize> {
+    origin: Point<S, N>,
+    direction: Vector<S, N>,
+}
+
+impl<S, const N: usize> Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new line from a point on the line and the direction of the line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Line3;
+    /// #
+    /// let origin = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let direction = Vector3::new(0_f64, 1_f64, 0_f64);
+    /// let line = Line3::new(origin, direction);
+    ///
+    /// assert_eq!(line.origin(), origin);
+    /// assert_eq!(line.direction(), direction);
+    /// ```
+    #[inline]
+    pub const fn new(origin: Point<S, N>, direction: Vector<S, N>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Construct the line passing through two distinct points.
+    ///
+    /// The line passes through `a` at parameter `0` and through `b` at parameter `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Line3;
+    /// #
+    /// let a = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let b = Point3::new(4_f64, 6_f64, 3_f64);
+    /// let line = Line3::from_points(&a, &b);
+    ///
+    /// assert_eq!(line.at(0_f64), a);
+    /// assert_eq!(line.at(1_f64), b);
+    /// ```
+    #[inline]
+    pub fn from_points(a: &Point<S, N>, b: &Point<S, N>) -> Self {
+        Self::new(*a, b - a)
+    }
+
+    /// Get the point on the line at parameter `0`.
+    #[inline]
+    pub const fn origin(&self) -> Point<S, N> {
+        self.origin
+    }
+
+    /// Get the direction of the line.
+    #[inline]
+    pub const fn direction(&self) -> Vector<S, N> {
+        self.direction
+    }
+
+    /// Compute the point on the line at the parameter `t`.
+    #[inline]
+    pub fn at(&self, t: S) -> Point<S, N> {
+        self.origin + self.direction * t
+    }
+
+    /// Compute the parameter of the point on the line closest to a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Line3;
+    /// #
+    /// let line = Line3::new(Point3::origin(), Vector3::new(2_f64, 0_f64, 0_f64));
+    /// let point = Point3::new(3_f64, 4_f64, 5_f64);
+    ///
+    /// assert_eq!(line.closest_parameter(&point), 3_f64 / 2_f64);
+    /// ```
+    #[inline]
+    pub fn closest_parameter(&self, point: &Point<S, N>) -> S {
+        (point - self.origin).dot(&self.direction) / self.direction.norm_squared()
+    }
+
+    /// Compute the point on the line closest to a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Line3;
+    /// #
+    /// let line = Line3::new(Point3::origin(), Vector3::new(2_f64, 0_f64, 0_f64));
+    /// let point = Point3::new(3_f64, 4_f64, 5_f64);
+    ///
+    /// assert_eq!(line.closest_point(&point), Point3::new(3_f64, 0_f64, 0_f64));
+    /// ```
+    #[inline]
+    pub fn closest_point(&self, point: &Point<S, N>) -> Point<S, N> {
+        self.at(self.closest_parameter(point))
+    }
+
+    /// Compute the squared distance from a point to the line.
+    #[inline]
+    pub fn distance_squared(&self, point: &Point<S, N>) -> S {
+        (point - self.closest_point(point)).norm_squared()
+    }
+
+    /// Compute a pair of closest points between two lines.
+    ///
+    /// The function returns the point on `self` and the point on `other` that are
+    /// closest to each other. When the lines are parallel every point on `self` has
+    /// a closest point on `other`, and the function uses the origin of `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Line3;
+    /// #
+    /// let line1 = Line3::new(Point3::new(0_f64, 0_f64, 0_f64), Vector3::unit_x());
+    /// let line2 = Line3::new(Point3::new(2_f64, 3_f64, 1_f64), Vector3::unit_y());
+    /// let (point1, point2) = line1.closest_points_line(&line2);
+    ///
+    /// assert_eq!(point1, Point3::new(2_f64, 0_f64, 0_f64));
+    /// assert_eq!(point2, Point3::new(2_f64, 0_f64, 1_f64));
+    /// ```
+    pub fn closest_points_line(&self, other: &Self) -> (Point<S, N>, Point<S, N>) {
+        // Minimizing `norm(origin1 + s * d1 - origin2 - t * d2)^2` over `s` and `t`
+        // gives the linear system
+        // ```text
+        // a * s - b * t == -c
+        // b * s - e * t == -f
+        // ```
+        // where `a := dot(d1, d1)`, `b := dot(d1, d2)`, `e := dot(d2, d2)`,
+        // `c := dot(d1, r)`, `f := dot(d2, r)`, and `r := origin1 - origin2`.
+        let d1 = self.direction;
+        let d2 = other.direction;
+        let r = self.origin - other.origin;
+        let a = d1.dot(&d1);
+        let b = d1.dot(&d2);
+        let c = d1.dot(&r);
+        let e = d2.dot(&d2);
+        let f = d2.dot(&r);
+        let denominator = a * e - b * b;
+        let s = if denominator.is_zero() {
+            S::zero()
+        } else {
+            (b * f - c * e) / denominator
+        };
+        let t = (b * s + f) / e;
+
+        (self.at(s), other.at(t))
+    }
+}
+
+impl<S, const N: usize> fmt::Display for Line<S, N>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Line{} [origin={}, direction={}]", N, self.origin, self.direction)
+    }
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineTol<S, const N: usize> {
+    origin: Vector<S, N>,
+    direction: Vector<S, N>,
+}
+
+impl<S, const N: usize> LineTol<S, N> {
+    #[inline]
+    pub const fn from_parts(origin: Vector<S, N>, direction: Vector<S, N>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineDiff<S, const N: usize> {
+    origin: Vector<S, N>,
+    direction: Vector<S, N>,
+}
+
+impl<S, const N: usize> LineDiff<S, N> {
+    #[inline]
+    const fn from_parts(origin: Vector<S, N>, direction: Vector<S, N>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = LineTol<<S as approx_cmp::AbsDiffEq>::Tolerance, N>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.origin, &other.origin, &max_abs_diff.origin)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.direction, &other.direction, &max_abs_diff.direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffAllEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.origin, &other.origin, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.direction, &other.direction, max_abs_diff)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = LineDiff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff, N>;
+    type DebugTolerance = LineTol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let origin = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.origin, &other.origin);
+        let direction = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.direction, &other.direction);
+
+        LineDiff::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let origin = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.origin, &other.origin, &max_abs_diff.origin);
+        let direction = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.direction, &other.direction, &max_abs_diff.direction);
+
+        LineTol::from_parts(origin, direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffAllEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = LineTol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let origin = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.origin, &other.origin, max_abs_diff);
+        let direction = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.direction, &other.direction, max_abs_diff);
+
+        LineTol::from_parts(origin, direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = LineTol<<S as approx_cmp::RelativeEq>::Tolerance, N>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.origin, &other.origin, &max_abs_diff.origin, &max_relative.origin)
+            && approx_cmp::RelativeEq::relative_eq(&self.direction, &other.direction, &max_abs_diff.direction, &max_relative.direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeAllEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.origin, &other.origin, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.direction, &other.direction, max_abs_diff, max_relative)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = LineDiff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff, N>;
+    type DebugTolerance = LineTol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let origin = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.origin, &other.origin);
+        let direction = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.direction, &other.direction);
+
+        LineDiff::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let origin = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.origin, &other.origin, &max_abs_diff.origin);
+        let direction = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.direction, &other.direction, &max_abs_diff.direction);
+
+        LineTol::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let origin = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.origin, &other.origin, &max_relative.origin);
+        let direction = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.direction, &other.direction, &max_relative.direction);
+
+        LineTol::from_parts(origin, direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeAllEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = LineTol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let origin = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.origin, &other.origin, max_abs_diff);
+        let direction = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.direction, &other.direction, max_abs_diff);
+
+        LineTol::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let origin = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.origin, &other.origin, max_relative);
+        let direction = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.direction, &other.direction, max_relative);
+
+        LineTol::from_parts(origin, direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = LineTol<<S as approx_cmp::UlpsEq>::Tolerance, N>;
+    type UlpsTolerance = LineTol<<S as approx_cmp::UlpsEq>::UlpsTolerance, N>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.origin, &other.origin, &max_abs_diff.origin, &max_ulps.origin)
+            && approx_cmp::UlpsEq::ulps_eq(&self.direction, &other.direction, &max_abs_diff.direction, &max_ulps.direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsAllEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.origin, &other.origin, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.direction, &other.direction, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = LineDiff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff, N>;
+    type DebugUlpsDiff = LineDiff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff, N>;
+    type DebugTolerance = LineTol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance, N>;
+    type DebugUlpsTolerance = LineTol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let origin = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.origin, &other.origin);
+        let direction = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.direction, &other.direction);
+
+        LineDiff::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let origin = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.origin, &other.origin);
+        let direction = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.direction, &other.direction);
+
+        LineDiff::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let origin = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.origin, &other.origin, &max_abs_diff.origin);
+        let direction = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.direction, &other.direction, &max_abs_diff.direction);
+
+        LineTol::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let origin = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.origin, &other.origin, &max_ulps.origin);
+        let direction = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.direction, &other.direction, &max_ulps.direction);
+
+        LineTol::from_parts(origin, direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsAllEq for Line<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = LineTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance, N>;
+    type AllDebugUlpsTolerance = LineTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let origin = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.origin, &other.origin, max_abs_diff);
+        let direction = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.direction, &other.direction, max_abs_diff);
+
+        LineTol::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let origin = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.origin, &other.origin, max_ulps);
+        let direction = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.direction, &other.direction, max_ulps);
+
+        LineTol::from_parts(origin, direction)
+    }
+}
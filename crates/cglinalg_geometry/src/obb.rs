@@ -0,0 +1,927 @@
+use crate::aabb::Aabb;
+use crate::bounded::Bounded;
+use crate::support_map::SupportMap;
+use cglinalg_core::{
+    Matrix3x3,
+    Normed,
+    Point3,
+    Quaternion,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::{
+    Isometry3,
+    Rotation3,
+};
+
+use core::fmt;
+
+
+/// An oriented bounding box in three dimensions.
+///
+/// An oriented bounding box is the set of points
+///
+/// ```text
+/// { center + x * u0 + y * u1 + z * u2 | |x| <= h0, |y| <= h1, |z| <= h2 }
+/// ```
+///
+/// where `center` is the center of the box, `[h0, h1, h2]` are the half extents
+/// of the box, and the **local axes** `u0`, `u1`, and `u2` of the box are the
+/// columns of the matrix of `rotation`. Equivalently, an oriented bounding box is
+/// the image of the axis-aligned box with half extents `[h0, h1, h2]` centered at
+/// the origin under the rotation followed by the translation to `center`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Obb3<S> {
+    center: Point3<S>,
+    half_extents: Vector3<S>,
+    rotation: Rotation3<S>,
+}
+
+impl<S> Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new oriented bounding box from its center, half extents, and
+    /// orientation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Obb3;
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let center = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let half_extents = Vector3::new(1_f64, 2_f64, 3_f64);
+    /// let rotation = Rotation3::from_angle_z(Degrees(30_f64));
+    /// let obb = Obb3::new(center, half_extents, rotation);
+    ///
+    /// assert_eq!(obb.center(), center);
+    /// assert_eq!(obb.half_extents(), half_extents);
+    /// assert_eq!(obb.rotation(), rotation);
+    /// ```
+    #[inline]
+    pub const fn new(center: Point3<S>, half_extents: Vector3<S>, rotation: Rotation3<S>) -> Self {
+        Self {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+
+    /// Construct an oriented bounding box from an axis-aligned bounding box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Obb3,
+    /// # };
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(-1_f64, 0_f64, 1_f64), Point3::new(3_f64, 2_f64, 7_f64));
+    /// let obb = Obb3::from_aabb(&aabb);
+    ///
+    /// assert_eq!(obb.center(), Point3::new(1_f64, 1_f64, 4_f64));
+    /// assert_eq!(obb.half_extents(), Vector3::new(2_f64, 1_f64, 3_f64));
+    /// assert_eq!(obb.to_aabb(), aabb);
+    /// ```
+    #[inline]
+    pub fn from_aabb(aabb: &Aabb<S, 3>) -> Self {
+        Self::new(aabb.center(), aabb.half_extents(), Rotation3::identity())
+    }
+
+    /// Fit an oriented bounding box to a set of points.
+    ///
+    /// The axes of the box are the eigenvectors of the covariance matrix of the
+    /// points, i.e. the principal axes of the point set, and the extents of the box
+    /// are the smallest extents along these axes that contain every point. The
+    /// resulting box contains all of the points, and fits elongated point sets much
+    /// more tightly than an axis-aligned box, but it is not the minimal volume
+    /// oriented bounding box in general. The covariance matrix of the points is
+    /// sensitive to how the points are distributed, so the fit works best for points
+    /// sampled evenly over the object being bounded, such as the vertices of its
+    /// convex hull.
+    ///
+    /// The function returns `None` when `points` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Obb3;
+    /// #
+    /// // Points along the diagonal line `x == y == z`.
+    /// let points = [
+    ///     Point3::new(0_f64, 0_f64, 0_f64),
+    ///     Point3::new(1_f64, 1_f64, 1_f64),
+    ///     Point3::new(2_f64, 2_f64, 2_f64),
+    ///     Point3::new(3_f64, 3_f64, 3_f64),
+    /// ];
+    /// let obb = Obb3::from_points(&points).unwrap();
+    /// let half_extents = obb.half_extents();
+    /// let mut half_extents = [half_extents[0], half_extents[1], half_extents[2]];
+    /// half_extents.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ///
+    /// assert!((obb.center() - Point3::new(1.5_f64, 1.5_f64, 1.5_f64)).norm() <= 1e-10);
+    /// assert!(half_extents[0].abs() <= 1e-10);
+    /// assert!(half_extents[1].abs() <= 1e-10);
+    /// assert!((half_extents[2] - 1.5_f64 * f64::sqrt(3_f64)).abs() <= 1e-10);
+    /// ```
+    pub fn from_points(points: &[Point3<S>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let count: S = cglinalg_numeric::cast(points.len());
+        let mean = points
+            .iter()
+            .fold(Vector3::zero(), |sum, point| sum + point.to_vector())
+            / count;
+        let mut covariance = [[S::zero(); 3]; 3];
+        for point in points.iter() {
+            let offset = point.to_vector() - mean;
+            for i in 0..3 {
+                for j in 0..3 {
+                    covariance[i][j] += offset[i] * offset[j] / count;
+                }
+            }
+        }
+
+        // Complete the first two eigenvectors to a right-handed frame so that the
+        // axes form a rotation.
+        let eigenvectors = symmetric_eigenvectors(covariance);
+        let frame = [eigenvectors[0], eigenvectors[1], eigenvectors[0].cross(&eigenvectors[1])];
+        let rotation = Rotation3::from_quaternion(&Quaternion::from_matrix(&Matrix3x3::from_columns(&frame)).normalize());
+        let axes = [rotation.matrix()[0], rotation.matrix()[1], rotation.matrix()[2]];
+        let mut min = Vector3::from_fill(S::infinity());
+        let mut max = Vector3::from_fill(S::neg_infinity());
+        for point in points.iter() {
+            let offset = point.to_vector() - mean;
+            for i in 0..3 {
+                let projection = offset.dot(&axes[i]);
+                min[i] = min[i].min(projection);
+                max[i] = max[i].max(projection);
+            }
+        }
+
+        let one_half: S = cglinalg_numeric::cast(0.5_f64);
+        let local_center = (min + max) * one_half;
+        let half_extents = (max - min) * one_half;
+        let center = Point3::from_vector(&(mean + axes[0] * local_center[0] + axes[1] * local_center[1] + axes[2] * local_center[2]));
+
+        Some(Self::new(center, half_extents, rotation))
+    }
+
+    /// Get the center of the box.
+    #[inline]
+    pub const fn center(&self) -> Point3<S> {
+        self.center
+    }
+
+    /// Get the half extents of the box along its local axes.
+    #[inline]
+    pub const fn half_extents(&self) -> Vector3<S> {
+        self.half_extents
+    }
+
+    /// Get the orientation of the box.
+    #[inline]
+    pub const fn rotation(&self) -> Rotation3<S> {
+        self.rotation
+    }
+
+    /// Get the local axes of the box.
+    ///
+    /// The local axes of the box are the columns of the matrix of the orientation
+    /// of the box.
+    #[inline]
+    pub fn axes(&self) -> [Vector3<S>; 3] {
+        let matrix = self.rotation.matrix();
+
+        [matrix[0], matrix[1], matrix[2]]
+    }
+
+    /// Compute the corners of the box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Obb3,
+    /// # };
+    /// #
+    /// let obb = Obb3::from_aabb(&Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 2_f64, 3_f64)));
+    /// let corners = obb.corners();
+    ///
+    /// assert_eq!(corners[0], Point3::new(0_f64, 0_f64, 0_f64));
+    /// assert_eq!(corners[7], Point3::new(1_f64, 2_f64, 3_f64));
+    /// ```
+    pub fn corners(&self) -> [Point3<S>; 8] {
+        let [u0, u1, u2] = self.axes();
+        let h = self.half_extents;
+
+        core::array::from_fn(|i| {
+            let sign = |bit: usize| if i & bit == 0 { -S::one() } else { S::one() };
+
+            self.center + u0 * (sign(1) * h[0]) + u1 * (sign(2) * h[1]) + u2 * (sign(4) * h[2])
+        })
+    }
+
+    /// Compute the volume of the box.
+    #[inline]
+    pub fn volume(&self) -> S {
+        let eight: S = cglinalg_numeric::cast(8_f64);
+
+        eight * self.half_extents[0] * self.half_extents[1] * self.half_extents[2]
+    }
+
+    /// Determine whether the box contains a point.
+    ///
+    /// The box is closed, so points on the boundary of the box are contained in the box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Obb3;
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let obb = Obb3::new(Point3::origin(), Vector3::new(2_f64, 1_f64, 1_f64), Rotation3::from_angle_z(Degrees(90_f64)));
+    ///
+    /// assert!(obb.contains(&Point3::new(0_f64, 1.5_f64, 0_f64)));
+    /// assert!(!obb.contains(&Point3::new(1.5_f64, 0_f64, 0_f64)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: &Point3<S>) -> bool {
+        let local = self.rotation.inverse_apply_vector(&(point - self.center));
+
+        (0..3).all(|i| local[i].abs() <= self.half_extents[i])
+    }
+
+    /// Compute the point in the box closest to a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Obb3;
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let obb = Obb3::new(Point3::origin(), Vector3::new(2_f64, 1_f64, 1_f64), Rotation3::from_angle_z(Degrees(90_f64)));
+    /// let point = Point3::new(5_f64, 5_f64, 0_f64);
+    /// let expected = Point3::new(1_f64, 2_f64, 0_f64);
+    /// let result = obb.closest_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn closest_point(&self, point: &Point3<S>) -> Point3<S> {
+        let mut local = self.rotation.inverse_apply_vector(&(point - self.center));
+        for i in 0..3 {
+            local[i] = local[i].max(-self.half_extents[i]).min(self.half_extents[i]);
+        }
+
+        self.center + self.rotation.apply_vector(&local)
+    }
+
+    /// Compute the squared distance from a point to the box.
+    ///
+    /// The distance is zero for points inside the box.
+    #[inline]
+    pub fn distance_squared(&self, point: &Point3<S>) -> S {
+        (point - self.closest_point(point)).norm_squared()
+    }
+
+    /// Determine whether two oriented bounding boxes overlap using the separating
+    /// axis theorem.
+    ///
+    /// Two convex polyhedra are disjoint if and only if there is an axis such that
+    /// the projections of the polyhedra onto the axis are disjoint. For two boxes it
+    /// suffices to test the fifteen axes given by the three face normals of each box
+    /// and the nine cross products of an edge direction of one box with an edge
+    /// direction of the other.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Obb3;
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let half_extents = Vector3::new(1_f64, 1_f64, 1_f64);
+    /// let obb1 = Obb3::new(Point3::origin(), half_extents, Rotation3::identity());
+    /// let rotation = Rotation3::from_angle_z(Degrees(45_f64));
+    /// let obb2 = Obb3::new(Point3::new(2.3_f64, 0_f64, 0_f64), half_extents, rotation);
+    /// let obb3 = Obb3::new(Point3::new(2.5_f64, 0_f64, 0_f64), half_extents, rotation);
+    ///
+    /// // The corner of the rotated box reaches `2.3 - sqrt(2) < 1` along the x-axis.
+    /// assert!(obb1.intersects(&obb2));
+    /// // The corner of the rotated box reaches `2.5 - sqrt(2) > 1` along the x-axis.
+    /// assert!(!obb1.intersects(&obb3));
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        let a = self.half_extents;
+        let b = other.half_extents;
+        let axes_a = self.axes();
+        let axes_b = other.axes();
+
+        // Express the orientation of the other box and the offset between the
+        // centers in the local frame of this box.
+        let mut r = [[S::zero(); 3]; 3];
+        let mut abs_r = [[S::zero(); 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = axes_a[i].dot(&axes_b[j]);
+                // Pad the absolute values to guard against nearly parallel edges, whose
+                // cross product is nearly zero and gives a meaningless separating axis.
+                abs_r[i][j] = r[i][j].abs() + S::default_epsilon();
+            }
+        }
+        let offset = other.center - self.center;
+        let t = Vector3::new(offset.dot(&axes_a[0]), offset.dot(&axes_a[1]), offset.dot(&axes_a[2]));
+
+        // The face normals of this box.
+        for i in 0..3 {
+            let radius_a = a[i];
+            let radius_b = b[0] * abs_r[i][0] + b[1] * abs_r[i][1] + b[2] * abs_r[i][2];
+            if t[i].abs() > radius_a + radius_b {
+                return false;
+            }
+        }
+
+        // The face normals of the other box.
+        for j in 0..3 {
+            let radius_a = a[0] * abs_r[0][j] + a[1] * abs_r[1][j] + a[2] * abs_r[2][j];
+            let radius_b = b[j];
+            let distance = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if distance.abs() > radius_a + radius_b {
+                return false;
+            }
+        }
+
+        // The cross products of the edge directions of the two boxes.
+        for i in 0..3 {
+            let i1 = (i + 1) % 3;
+            let i2 = (i + 2) % 3;
+            for j in 0..3 {
+                let j1 = (j + 1) % 3;
+                let j2 = (j + 2) % 3;
+                let radius_a = a[i1] * abs_r[i2][j] + a[i2] * abs_r[i1][j];
+                let radius_b = b[j1] * abs_r[i][j2] + b[j2] * abs_r[i][j1];
+                let distance = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if distance.abs() > radius_a + radius_b {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Compute the smallest axis-aligned bounding box containing the box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Obb3,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let obb = Obb3::new(Point3::new(1_f64, 0_f64, 0_f64), Vector3::new(1_f64, 1_f64, 2_f64), Rotation3::from_angle_z(Degrees(45_f64)));
+    /// let sqrt_2 = f64::sqrt(2_f64);
+    /// let expected = Aabb3::new(Point3::new(1_f64 - sqrt_2, -sqrt_2, -2_f64), Point3::new(1_f64 + sqrt_2, sqrt_2, 2_f64));
+    /// let result = obb.to_aabb();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn to_aabb(&self) -> Aabb<S, 3> {
+        let matrix = self.rotation.matrix();
+        let mut half_extents = Vector3::zero();
+        for row in 0..3 {
+            for column in 0..3 {
+                half_extents[row] += matrix[column][row].abs() * self.half_extents[column];
+            }
+        }
+
+        Aabb::from_center_half_extents(&self.center, &half_extents)
+    }
+
+    /// Transform an oriented bounding box by an isometry.
+    #[inline]
+    pub fn transform_isometry(&self, isometry: &Isometry3<S>) -> Self {
+        let center = isometry.apply_point(&self.center);
+        let rotation = isometry.rotation() * self.rotation;
+
+        Self::new(center, self.half_extents, rotation)
+    }
+}
+
+impl<S> fmt::Display for Obb3<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Obb3 [center={}, half_extents={}, rotation={}]",
+            self.center, self.half_extents, self.rotation
+        )
+    }
+}
+
+
+impl<S> SupportMap<S> for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S> {
+        let axes = self.axes();
+        let mut result = self.center;
+        for (i, axis) in axes.iter().enumerate() {
+            if axis.dot(direction) >= S::zero() {
+                result += axis * self.half_extents[i];
+            } else {
+                result -= axis * self.half_extents[i];
+            }
+        }
+
+        result
+    }
+}
+
+impl<S> Bounded<S> for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn aabb(&self) -> Aabb<S, 3> {
+        self.to_aabb()
+    }
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Obb3Tol<S> {
+    center: Vector3<S>,
+    half_extents: Vector3<S>,
+    rotation: Matrix3x3<S>,
+}
+
+impl<S> Obb3Tol<S> {
+    #[inline]
+    pub const fn from_parts(center: Vector3<S>, half_extents: Vector3<S>, rotation: Matrix3x3<S>) -> Self {
+        Self { center, half_extents, rotation }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Obb3Diff<S> {
+    center: Vector3<S>,
+    half_extents: Vector3<S>,
+    rotation: Matrix3x3<S>,
+}
+
+impl<S> Obb3Diff<S> {
+    #[inline]
+    const fn from_parts(center: Vector3<S>, half_extents: Vector3<S>, rotation: Matrix3x3<S>) -> Self {
+        Self { center, half_extents, rotation }
+    }
+}
+
+impl<S> approx_cmp::AbsDiffEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Obb3Tol<<S as approx_cmp::AbsDiffEq>::Tolerance>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.center, &other.center, &max_abs_diff.center)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.half_extents, &other.half_extents, &max_abs_diff.half_extents)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(self.rotation.matrix(), other.rotation.matrix(), &max_abs_diff.rotation)
+    }
+}
+
+impl<S> approx_cmp::AbsDiffAllEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.center, &other.center, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.half_extents, &other.half_extents, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(self.rotation.matrix(), other.rotation.matrix(), max_abs_diff)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Obb3Diff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff>;
+    type DebugTolerance = Obb3Tol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let center = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.center, &other.center);
+        let half_extents = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.half_extents, &other.half_extents);
+        let rotation = approx_cmp::AssertAbsDiffEq::debug_abs_diff(self.rotation.matrix(), other.rotation.matrix());
+
+        Obb3Diff::from_parts(center, half_extents, rotation)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let center = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.center, &other.center, &max_abs_diff.center);
+        let half_extents = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(
+            &self.half_extents,
+            &other.half_extents,
+            &max_abs_diff.half_extents,
+        );
+        let rotation = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(
+            self.rotation.matrix(),
+            other.rotation.matrix(),
+            &max_abs_diff.rotation,
+        );
+
+        Obb3Tol::from_parts(center, half_extents, rotation)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffAllEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Obb3Tol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let center = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.center, &other.center, max_abs_diff);
+        let half_extents = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(
+            &self.half_extents,
+            &other.half_extents,
+            max_abs_diff,
+        );
+        let rotation = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(
+            self.rotation.matrix(),
+            other.rotation.matrix(),
+            max_abs_diff,
+        );
+
+        Obb3Tol::from_parts(center, half_extents, rotation)
+    }
+}
+
+impl<S> approx_cmp::RelativeEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Obb3Tol<<S as approx_cmp::RelativeEq>::Tolerance>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.center, &other.center, &max_abs_diff.center, &max_relative.center)
+            && approx_cmp::RelativeEq::relative_eq(
+                &self.half_extents,
+                &other.half_extents,
+                &max_abs_diff.half_extents,
+                &max_relative.half_extents,
+            )
+            && approx_cmp::RelativeEq::relative_eq(
+                self.rotation.matrix(),
+                other.rotation.matrix(),
+                &max_abs_diff.rotation,
+                &max_relative.rotation,
+            )
+    }
+}
+
+impl<S> approx_cmp::RelativeAllEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.center, &other.center, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.half_extents, &other.half_extents, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(self.rotation.matrix(), other.rotation.matrix(), max_abs_diff, max_relative)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Obb3Diff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff>;
+    type DebugTolerance = Obb3Tol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let center = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.center, &other.center);
+        let half_extents = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.half_extents, &other.half_extents);
+        let rotation = approx_cmp::AssertRelativeEq::debug_abs_diff(self.rotation.matrix(), other.rotation.matrix());
+
+        Obb3Diff::from_parts(center, half_extents, rotation)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let center = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.center, &other.center, &max_abs_diff.center);
+        let half_extents = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(
+            &self.half_extents,
+            &other.half_extents,
+            &max_abs_diff.half_extents,
+        );
+        let rotation = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(
+            self.rotation.matrix(),
+            other.rotation.matrix(),
+            &max_abs_diff.rotation,
+        );
+
+        Obb3Tol::from_parts(center, half_extents, rotation)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let center = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.center, &other.center, &max_relative.center);
+        let half_extents = approx_cmp::AssertRelativeEq::debug_relative_tolerance(
+            &self.half_extents,
+            &other.half_extents,
+            &max_relative.half_extents,
+        );
+        let rotation = approx_cmp::AssertRelativeEq::debug_relative_tolerance(
+            self.rotation.matrix(),
+            other.rotation.matrix(),
+            &max_relative.rotation,
+        );
+
+        Obb3Tol::from_parts(center, half_extents, rotation)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeAllEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Obb3Tol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let center = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.center, &other.center, max_abs_diff);
+        let half_extents = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(
+            &self.half_extents,
+            &other.half_extents,
+            max_abs_diff,
+        );
+        let rotation = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(
+            self.rotation.matrix(),
+            other.rotation.matrix(),
+            max_abs_diff,
+        );
+
+        Obb3Tol::from_parts(center, half_extents, rotation)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let center = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.center, &other.center, max_relative);
+        let half_extents = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(
+            &self.half_extents,
+            &other.half_extents,
+            max_relative,
+        );
+        let rotation = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(
+            self.rotation.matrix(),
+            other.rotation.matrix(),
+            max_relative,
+        );
+
+        Obb3Tol::from_parts(center, half_extents, rotation)
+    }
+}
+
+impl<S> approx_cmp::UlpsEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Obb3Tol<<S as approx_cmp::UlpsEq>::Tolerance>;
+    type UlpsTolerance = Obb3Tol<<S as approx_cmp::UlpsEq>::UlpsTolerance>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.center, &other.center, &max_abs_diff.center, &max_ulps.center)
+            && approx_cmp::UlpsEq::ulps_eq(&self.half_extents, &other.half_extents, &max_abs_diff.half_extents, &max_ulps.half_extents)
+            && approx_cmp::UlpsEq::ulps_eq(self.rotation.matrix(), other.rotation.matrix(), &max_abs_diff.rotation, &max_ulps.rotation)
+    }
+}
+
+impl<S> approx_cmp::UlpsAllEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.center, &other.center, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.half_extents, &other.half_extents, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(self.rotation.matrix(), other.rotation.matrix(), max_abs_diff, max_ulps)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Obb3Diff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff>;
+    type DebugUlpsDiff = Obb3Diff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff>;
+    type DebugTolerance = Obb3Tol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance>;
+    type DebugUlpsTolerance = Obb3Tol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let center = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.center, &other.center);
+        let half_extents = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.half_extents, &other.half_extents);
+        let rotation = approx_cmp::AssertUlpsEq::debug_abs_diff(self.rotation.matrix(), other.rotation.matrix());
+
+        Obb3Diff::from_parts(center, half_extents, rotation)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let center = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.center, &other.center);
+        let half_extents = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.half_extents, &other.half_extents);
+        let rotation = approx_cmp::AssertUlpsEq::debug_ulps_diff(self.rotation.matrix(), other.rotation.matrix());
+
+        Obb3Diff::from_parts(center, half_extents, rotation)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let center = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.center, &other.center, &max_abs_diff.center);
+        let half_extents = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(
+            &self.half_extents,
+            &other.half_extents,
+            &max_abs_diff.half_extents,
+        );
+        let rotation = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(
+            self.rotation.matrix(),
+            other.rotation.matrix(),
+            &max_abs_diff.rotation,
+        );
+
+        Obb3Tol::from_parts(center, half_extents, rotation)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let center = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.center, &other.center, &max_ulps.center);
+        let half_extents = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.half_extents, &other.half_extents, &max_ulps.half_extents);
+        let rotation = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(self.rotation.matrix(), other.rotation.matrix(), &max_ulps.rotation);
+
+        Obb3Tol::from_parts(center, half_extents, rotation)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsAllEq for Obb3<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Obb3Tol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance>;
+    type AllDebugUlpsTolerance = Obb3Tol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let center = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.center, &other.center, max_abs_diff);
+        let half_extents = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.half_extents, &other.half_extents, max_abs_diff);
+        let rotation = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(
+            self.rotation.matrix(),
+            other.rotation.matrix(),
+            max_abs_diff,
+        );
+
+        Obb3Tol::from_parts(center, half_extents, rotation)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let center = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.center, &other.center, max_ulps);
+        let half_extents = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.half_extents, &other.half_extents, max_ulps);
+        let rotation = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(self.rotation.matrix(), other.rotation.matrix(), max_ulps);
+
+        Obb3Tol::from_parts(center, half_extents, rotation)
+    }
+}
+
+
+/// Compute the eigenvectors of a symmetric `3 x 3` matrix using the cyclic
+/// Jacobi eigenvalue algorithm.
+///
+/// Each Jacobi rotation zeros out one off-diagonal element of the matrix, and the
+/// product of the rotations converges to an orthogonal matrix whose columns are the
+/// eigenvectors of the matrix. The matrix is indexed as `matrix[row][column]`.
+fn symmetric_eigenvectors<S>(matrix: [[S; 3]; 3]) -> [Vector3<S>; 3]
+where
+    S: SimdScalarFloat,
+{
+    const MAX_SWEEPS: usize = 32;
+
+    let mut a = matrix;
+    let mut v = [[S::zero(); 3]; 3];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = S::one();
+    }
+
+    let two = S::one() + S::one();
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal = a[0][1].abs() + a[0][2].abs() + a[1][2].abs();
+        let diagonal = a[0][0].abs() + a[1][1].abs() + a[2][2].abs();
+        if off_diagonal <= S::machine_epsilon() * diagonal || off_diagonal.is_zero() {
+            break;
+        }
+
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q].is_zero() {
+                continue;
+            }
+
+            let theta = (a[q][q] - a[p][p]) / (two * a[p][q]);
+            let t = theta.signum() / (theta.abs() + (theta * theta + S::one()).sqrt());
+            let c = S::one() / (t * t + S::one()).sqrt();
+            let s = t * c;
+
+            // Apply the rotation `a := transpose(J) * a * J`, where `J` is the identity
+            // matrix except for `J[p][p] == J[q][q] == c` and `J[p][q] == -J[q][p] == s`.
+            for row in a.iter_mut() {
+                let a_p = row[p];
+                let a_q = row[q];
+                row[p] = c * a_p - s * a_q;
+                row[q] = s * a_p + c * a_q;
+            }
+            let row_p = a[p];
+            let row_q = a[q];
+            a[p] = core::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+            a[q] = core::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+            for row in v.iter_mut() {
+                let v_p = row[p];
+                let v_q = row[q];
+                row[p] = c * v_p - s * v_q;
+                row[q] = s * v_p + c * v_q;
+            }
+        }
+    }
+
+    [
+        Vector3::new(v[0][0], v[1][0], v[2][0]).normalize(),
+        Vector3::new(v[0][1], v[1][1], v[2][1]).normalize(),
+        Vector3::new(v[0][2], v[1][2], v[2][2]).normalize(),
+    ]
+}
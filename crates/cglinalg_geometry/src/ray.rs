@@ -0,0 +1,1347 @@
+use crate::aabb::Aabb;
+use crate::capsule::Capsule3;
+use crate::cone::Cone3;
+use crate::cylinder::Cylinder3;
+use crate::hyperplane::Hyperplane;
+use crate::obb::Obb3;
+use crate::sphere::Sphere;
+use cglinalg_core::{
+    Const,
+    DimAdd,
+    Normed,
+    Point,
+    Point3,
+    ShapeConstraint,
+    Vector,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::{
+    Isometry,
+    Reflection,
+    Rotation,
+    Scale,
+    Shear,
+    Similarity,
+    Transform,
+    Translation,
+};
+
+use core::fmt;
+
+
+/// A ray in two dimensions.
+pub type Ray2<S> = Ray<S, 2>;
+
+/// A ray in three dimensions.
+pub type Ray3<S> = Ray<S, 3>;
+
+
+/// A ray, i.e. a half-line.
+///
+/// A ray is the set of points
+///
+/// ```text
+/// { origin + t * direction | t >= 0 }
+/// ```
+///
+/// where `origin` is the starting point of the ray, and `direction` is the
+/// direction that the ray travels in. The direction of a ray need not be a unit
+/// vector. Every intersection routine reports hits in terms of the **ray parameter**
+/// `t`, so the distance to a hit along the ray is `t * direction.norm()`. In particular,
+/// the ray parameter equals the distance to the hit when the direction is a unit vector.
+///
+/// Because the ray parameter is measured in units of the ray direction, transforming
+/// a ray by an affine transformation preserves the ray parameters of its hits. That is,
+/// the ray parameter of a hit against a transformed object is the ray parameter of the
+/// hit of the untransformed ray against the untransformed object.
+///
+/// This is the most general ray type. The vast majority of applications should use
+/// [`Ray2`] or [`Ray3`] instead of this type directly.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray<S, const N: usize> {
+    origin: Point<S, N>,
+    direction: Vector<S, N>,
+}
+
+impl<S, const N: usize> Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new ray from its origin and direction.
+    ///
+    /// The direction of the ray must be a nonzero vector, but it need not be a
+    /// unit vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Ray3;
+    /// #
+    /// let origin = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let direction = Vector3::new(0_f64, 0_f64, -1_f64);
+    /// let ray = Ray3::new(origin, direction);
+    ///
+    /// assert_eq!(ray.origin(), origin);
+    /// assert_eq!(ray.direction(), direction);
+    /// ```
+    #[inline]
+    pub const fn new(origin: Point<S, N>, direction: Vector<S, N>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Construct a new ray starting at the point `origin` and passing through the
+    /// point `target` with a unit direction vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Ray3;
+    /// #
+    /// let origin = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let target = Point3::new(1_f64, 2_f64, -3_f64);
+    /// let ray = Ray3::from_points(&origin, &target);
+    ///
+    /// assert_eq!(ray.origin(), origin);
+    /// assert_eq!(ray.direction(), Vector3::new(0_f64, 0_f64, -1_f64));
+    /// ```
+    #[inline]
+    pub fn from_points(origin: &Point<S, N>, target: &Point<S, N>) -> Self {
+        Self::new(*origin, (target - origin).normalize())
+    }
+
+    /// Get the origin of the ray.
+    #[inline]
+    pub const fn origin(&self) -> Point<S, N> {
+        self.origin
+    }
+
+    /// Get the direction of the ray.
+    #[inline]
+    pub const fn direction(&self) -> Vector<S, N> {
+        self.direction
+    }
+
+    /// Compute the point on the line through the ray at the ray parameter `t`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Ray3;
+    /// #
+    /// let ray = Ray3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(0_f64, 2_f64, 0_f64));
+    ///
+    /// assert_eq!(ray.at(0_f64), Point3::new(1_f64, 2_f64, 3_f64));
+    /// assert_eq!(ray.at(3_f64), Point3::new(1_f64, 8_f64, 3_f64));
+    /// ```
+    #[inline]
+    pub fn at(&self, t: S) -> Point<S, N> {
+        self.origin + self.direction * t
+    }
+
+    /// Construct a ray with the same origin whose direction is a unit vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Ray3;
+    /// #
+    /// let ray = Ray3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(0_f64, 2_f64, 0_f64));
+    /// let expected = Ray3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(0_f64, 1_f64, 0_f64));
+    /// let result = ray.normalize();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        Self::new(self.origin, self.direction.normalize())
+    }
+
+    /// Transform a ray by a translation.
+    #[inline]
+    pub fn transform_translation(&self, translation: &Translation<S, N>) -> Self {
+        Self::new(translation.apply_point(&self.origin), self.direction)
+    }
+
+    /// Transform a ray by a rotation.
+    #[inline]
+    pub fn transform_rotation(&self, rotation: &Rotation<S, N>) -> Self {
+        Self::new(rotation.apply_point(&self.origin), rotation.apply_vector(&self.direction))
+    }
+
+    /// Transform a ray by a scaling transformation.
+    ///
+    /// The direction of the transformed ray is not renormalized, so the ray
+    /// parameters of hits are preserved.
+    #[inline]
+    pub fn transform_scale(&self, scale: &Scale<S, N>) -> Self {
+        Self::new(scale.apply_point(&self.origin), scale.apply_vector(&self.direction))
+    }
+
+    /// Transform a ray by a shearing transformation.
+    ///
+    /// The direction of the transformed ray is not renormalized, so the ray
+    /// parameters of hits are preserved.
+    #[inline]
+    pub fn transform_shear(&self, shear: &Shear<S, N>) -> Self {
+        Self::new(shear.apply_point(&self.origin), shear.apply_vector(&self.direction))
+    }
+
+    /// Transform a ray by a reflection.
+    #[inline]
+    pub fn transform_reflection(&self, reflection: &Reflection<S, N>) -> Self {
+        Self::new(reflection.apply_point(&self.origin), reflection.apply_vector(&self.direction))
+    }
+
+    /// Transform a ray by an isometry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Ray3;
+    /// # use cglinalg_transform::Isometry3;
+    /// #
+    /// let ray = Ray3::new(Point3::new(0_f64, 0_f64, 0_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+    /// let isometry = Isometry3::look_at_rh(
+    ///     &Point3::new(0_f64, 0_f64, 5_f64),
+    ///     &Point3::origin(),
+    ///     &Vector3::unit_y(),
+    /// ).inverse();
+    /// let expected = Ray3::new(Point3::new(0_f64, 0_f64, 5_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+    /// let result = ray.transform_isometry(&isometry);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn transform_isometry(&self, isometry: &Isometry<S, N>) -> Self {
+        Self::new(isometry.apply_point(&self.origin), isometry.apply_vector(&self.direction))
+    }
+
+    /// Transform a ray by a similarity transformation.
+    ///
+    /// The direction of the transformed ray is not renormalized, so the ray
+    /// parameters of hits are preserved.
+    #[inline]
+    pub fn transform_similarity(&self, similarity: &Similarity<S, N>) -> Self {
+        Self::new(similarity.apply_point(&self.origin), similarity.apply_vector(&self.direction))
+    }
+
+    /// Transform a ray by a generic transformation.
+    ///
+    /// For an affine transformation the direction of the transformed ray is not
+    /// renormalized, so the ray parameters of hits are preserved. A projective
+    /// transformation maps the line of the ray onto a line, but not uniformly, so
+    /// the direction of the transformed ray is the derivative of the transformation
+    /// along the ray at its origin, and the ray parameters of hits are not preserved.
+    ///
+    /// The function returns `None` if the origin of the ray lies on or behind the
+    /// plane that a projective transformation sends to infinity, i.e. the
+    /// transformed origin has a homogeneous `w` coordinate that is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Matrix3x3,
+    /// #     Point2,
+    /// #     Vector2,
+    /// # };
+    /// # use cglinalg_geometry::Ray2;
+    /// # use cglinalg_transform::Transform2;
+    /// #
+    /// let ray = Ray2::new(Point2::new(1_f64, 1_f64), Vector2::new(1_f64, 0_f64));
+    /// #[rustfmt::skip]
+    /// let transform = Transform2::from_matrix_unchecked(Matrix3x3::new(
+    ///     2_f64, 0_f64, 0_f64,
+    ///     0_f64, 2_f64, 0_f64,
+    ///     3_f64, 4_f64, 1_f64,
+    /// ));
+    /// let expected = Ray2::new(Point2::new(5_f64, 6_f64), Vector2::new(2_f64, 0_f64));
+    /// let result = ray.transform(&transform).unwrap();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn transform<const NPLUS1: usize>(&self, transform: &Transform<S, N, NPLUS1>) -> Option<Self>
+    where
+        ShapeConstraint: DimAdd<Const<N>, Const<1>, Output = Const<NPLUS1>>,
+        ShapeConstraint: DimAdd<Const<1>, Const<N>, Output = Const<NPLUS1>>,
+    {
+        // The image of the point `origin + t * direction` in homogeneous coordinates
+        // is `origin_h + t * direction_h`, whose projection has the derivative
+        // ```text
+        // (direction_h[i] * w - origin_h[i] * dw) / w^2
+        // ```
+        // at `t == 0`, where `w := origin_h[N]` and `dw := direction_h[N]`.
+        let matrix = transform.matrix();
+        let mut origin_h = matrix[N];
+        let mut direction_h: Vector<S, NPLUS1> = Vector::zero();
+        for j in 0..N {
+            origin_h += matrix[j] * self.origin[j];
+            direction_h += matrix[j] * self.direction[j];
+        }
+
+        let w = origin_h[N];
+        if w <= S::zero() {
+            return None;
+        }
+
+        let dw = direction_h[N];
+        let mut origin: Point<S, N> = Point::origin();
+        let mut direction: Vector<S, N> = Vector::zero();
+        for i in 0..N {
+            origin[i] = origin_h[i] / w;
+            direction[i] = (direction_h[i] - origin[i] * dw) / w;
+        }
+
+        Some(Self::new(origin, direction))
+    }
+
+    /// Intersect a ray with a plane.
+    ///
+    /// The plane is the set of points `x` satisfying `dot(normal, x - point) == 0`.
+    /// The function returns `None` if the ray is parallel to the plane, or if the ray
+    /// points away from the plane. The normal of the hit is the unit normal of the
+    /// plane in the direction of `normal`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Ray3;
+    /// #
+    /// let ray = Ray3::new(Point3::new(1_f64, 5_f64, 2_f64), Vector3::new(0_f64, -1_f64, 0_f64));
+    /// let point = Point3::origin();
+    /// let normal = Vector3::new(0_f64, 2_f64, 0_f64);
+    /// let hit = ray.intersect_plane(&point, &normal).unwrap();
+    ///
+    /// assert_eq!(hit.distance(), 5_f64);
+    /// assert_eq!(hit.normal(), Vector3::unit_y());
+    /// assert_eq!(ray.at(hit.distance()), Point3::new(1_f64, 0_f64, 2_f64));
+    /// ```
+    #[inline]
+    pub fn intersect_plane(&self, point: &Point<S, N>, normal: &Vector<S, N>) -> Option<RayHit<S, N>> {
+        let denominator = normal.dot(&self.direction);
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let t = normal.dot(&(point - self.origin)) / denominator;
+        if t < S::zero() {
+            return None;
+        }
+
+        Some(RayHit::new(t, normal.normalize()))
+    }
+
+    /// Intersect a ray with a hyperplane.
+    ///
+    /// The function returns `None` if the ray is parallel to the hyperplane, or if
+    /// the ray points away from the hyperplane. The normal of the hit is the normal
+    /// of the hyperplane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Unit,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Hyperplane3,
+    /// #     Ray3,
+    /// # };
+    /// #
+    /// let plane = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::unit_z()), 2_f64);
+    /// let ray = Ray3::new(Point3::new(1_f64, 1_f64, 5_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+    /// let hit = ray.intersect_hyperplane(&plane).unwrap();
+    ///
+    /// assert_eq!(hit.distance(), 3_f64);
+    /// assert_eq!(hit.normal(), Vector3::unit_z());
+    /// ```
+    #[inline]
+    pub fn intersect_hyperplane(&self, hyperplane: &Hyperplane<S, N>) -> Option<RayHit<S, N>> {
+        let denominator = hyperplane.normal().dot(&self.direction);
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let t = -hyperplane.signed_distance(&self.origin) / denominator;
+        if t < S::zero() {
+            return None;
+        }
+
+        Some(RayHit::new(t, hyperplane.normal()))
+    }
+
+    /// Intersect a ray with a sphere.
+    ///
+    /// The function returns the first point where the ray crosses the surface of
+    /// the sphere, or `None` if the ray misses the sphere. If the origin of the
+    /// ray lies inside the sphere, the hit is the point where the ray exits the
+    /// sphere. The normal of the hit is the outward unit normal of the sphere.
+    ///
+    /// A ray with a zero direction never crosses the surface of the sphere. In this
+    /// case the function returns a hit at the origin of the ray with a zero normal
+    /// if the origin lies inside the sphere, and `None` otherwise. A sphere with zero
+    /// radius is a single point without a normal, so a ray passing through it hits
+    /// it with a zero normal.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Ray3,
+    /// #     Sphere3,
+    /// # };
+    /// #
+    /// let ray = Ray3::new(Point3::new(0_f64, 0_f64, 10_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+    /// let sphere = Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 2_f64);
+    /// let hit = ray.intersect_sphere(&sphere).unwrap();
+    ///
+    /// assert_eq!(hit.distance(), 8_f64);
+    /// assert_eq!(hit.normal(), Vector3::unit_z());
+    /// ```
+    #[inline]
+    pub fn intersect_sphere(&self, sphere: &Sphere<S, N>) -> Option<RayHit<S, N>> {
+        // Substituting the ray into the equation of the sphere gives the quadratic
+        // equation
+        // ```text
+        // a * t^2 + 2 * b * t + c == 0
+        // ```
+        // where
+        // ```text
+        // a := dot(direction, direction)
+        // b := dot(origin - center, direction)
+        // c := dot(origin - center, origin - center) - radius^2
+        // ```
+        let center = sphere.center();
+        let radius = sphere.radius();
+        let offset = self.origin - center;
+        let a = self.direction.dot(&self.direction);
+        let b = offset.dot(&self.direction);
+        let c = offset.dot(&offset) - radius * radius;
+        if a.is_zero() {
+            return if c <= S::zero() {
+                Some(RayHit::new(S::zero(), Vector::zero()))
+            } else {
+                None
+            };
+        }
+
+        let discriminant = b * b - a * c;
+        if discriminant < S::zero() {
+            return None;
+        }
+
+        let sqrt_discriminant = discriminant.sqrt();
+        let t_near = (-b - sqrt_discriminant) / a;
+        let t_far = (-b + sqrt_discriminant) / a;
+        let t = if t_near >= S::zero() {
+            t_near
+        } else if t_far >= S::zero() {
+            t_far
+        } else {
+            return None;
+        };
+        let normal = if radius.is_zero() {
+            Vector::zero()
+        } else {
+            (self.at(t) - center) / radius
+        };
+
+        Some(RayHit::new(t, normal))
+    }
+
+    /// Intersect a ray with an axis-aligned bounding box using the slab method.
+    ///
+    /// The function returns the first point where the ray crosses the boundary of
+    /// the box, or `None` if the ray misses the box. If the origin of the ray lies
+    /// inside the box, the hit is the point where the ray exits the box. The normal
+    /// of the hit is the outward unit normal of the face of the box that the ray
+    /// crosses.
+    ///
+    /// A ray with a zero direction never crosses the boundary of the box. In this
+    /// case the function returns a hit at the origin of the ray with a zero normal
+    /// if the origin lies inside the box, and `None` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Ray3,
+    /// # };
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(1_f64, 1_f64, 1_f64));
+    /// let ray = Ray3::new(Point3::new(-5_f64, 1_f64 / 2_f64, 0_f64), Vector3::new(1_f64, 0_f64, 0_f64));
+    /// let hit = ray.intersect_aabb(&aabb).unwrap();
+    ///
+    /// assert_eq!(hit.distance(), 4_f64);
+    /// assert_eq!(hit.normal(), -Vector3::unit_x());
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &Aabb<S, N>) -> Option<RayHit<S, N>> {
+        // The box is the intersection of `N` slabs, one for each coordinate axis.
+        // The ray is inside the box on the intersection of the parameter intervals
+        // where it is inside each slab.
+        if self.direction.is_zero() {
+            return if aabb.contains(&self.origin) {
+                Some(RayHit::new(S::zero(), Vector::zero()))
+            } else {
+                None
+            };
+        }
+
+        let min = aabb.min();
+        let max = aabb.max();
+        let mut t_enter = S::neg_infinity();
+        let mut t_exit = S::infinity();
+        let mut enter_axis = 0;
+        let mut exit_axis = 0;
+        for i in 0..N {
+            if self.direction[i].is_zero() {
+                if self.origin[i] < min[i] || self.origin[i] > max[i] {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let inverse_direction = S::one() / self.direction[i];
+            let t0 = (min[i] - self.origin[i]) * inverse_direction;
+            let t1 = (max[i] - self.origin[i]) * inverse_direction;
+            let (t_slab_enter, t_slab_exit) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+            if t_slab_enter > t_enter {
+                t_enter = t_slab_enter;
+                enter_axis = i;
+            }
+            if t_slab_exit < t_exit {
+                t_exit = t_slab_exit;
+                exit_axis = i;
+            }
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+
+        if t_exit < S::zero() {
+            return None;
+        }
+
+        let mut normal = Vector::zero();
+        if t_enter >= S::zero() {
+            normal[enter_axis] = -self.direction[enter_axis].signum();
+
+            Some(RayHit::new(t_enter, normal))
+        } else {
+            normal[exit_axis] = self.direction[exit_axis].signum();
+
+            Some(RayHit::new(t_exit, normal))
+        }
+    }
+}
+
+impl<S> Ray<S, 3>
+where
+    S: SimdScalarFloat,
+{
+    /// Intersect a ray with a triangle using the Möller-Trumbore algorithm.
+    ///
+    /// The triangle has vertices `a`, `b`, and `c`. Both sides of the triangle
+    /// are hit. The function returns `None` if the ray misses the triangle, or if
+    /// the ray is parallel to the plane of the triangle. The normal of the hit is
+    /// the unit normal of the triangle given by the right-hand rule with respect to
+    /// the winding order `a`, `b`, `c`, regardless of the side of the triangle that
+    /// the ray hits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Ray3;
+    /// #
+    /// let a = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let b = Point3::new(4_f64, 0_f64, 0_f64);
+    /// let c = Point3::new(0_f64, 4_f64, 0_f64);
+    /// let ray = Ray3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+    /// let hit = ray.intersect_triangle(&a, &b, &c).unwrap();
+    ///
+    /// assert_eq!(hit.distance(), 3_f64);
+    /// assert_eq!(hit.normal(), Vector3::unit_z());
+    /// assert_relative_eq!(
+    ///     hit.barycentric(),
+    ///     Vector3::new(1_f64 / 4_f64, 1_f64 / 4_f64, 1_f64 / 2_f64),
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// ```
+    pub fn intersect_triangle(&self, a: &Point3<S>, b: &Point3<S>, c: &Point3<S>) -> Option<RayTriangleHit<S>> {
+        // Write the hit point in barycentric coordinates as
+        // ```text
+        // origin + t * direction == (1 - u - v) * a + u * b + v * c
+        // ```
+        // and solve the resulting linear system for `(t, u, v)` using Cramer's rule.
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let p = self.direction.cross(&edge2);
+        let determinant = edge1.dot(&p);
+        if determinant.is_zero() {
+            return None;
+        }
+
+        let inverse_determinant = S::one() / determinant;
+        let s = self.origin - a;
+        let u = s.dot(&p) * inverse_determinant;
+        if u < S::zero() || u > S::one() {
+            return None;
+        }
+
+        let q = s.cross(&edge1);
+        let v = self.direction.dot(&q) * inverse_determinant;
+        if v < S::zero() || u + v > S::one() {
+            return None;
+        }
+
+        let t = edge2.dot(&q) * inverse_determinant;
+        if t < S::zero() {
+            return None;
+        }
+
+        let normal = edge1.cross(&edge2).normalize();
+        let barycentric = Vector3::new(S::one() - u - v, u, v);
+
+        Some(RayTriangleHit::new(t, normal, barycentric))
+    }
+
+    /// Intersect a ray with an oriented bounding box.
+    ///
+    /// The function returns the first point where the ray crosses the boundary of
+    /// the box, or `None` if the ray misses the box. If the origin of the ray lies
+    /// inside the box, the hit is the point where the ray exits the box. The normal
+    /// of the hit is the outward unit normal of the face of the box that the ray
+    /// crosses.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Obb3,
+    /// #     Ray3,
+    /// # };
+    /// # use cglinalg_transform::Rotation3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let obb = Obb3::new(
+    ///     Point3::new(5_f64, 0_f64, 0_f64),
+    ///     Vector3::new(1_f64, 1_f64, 1_f64),
+    ///     Rotation3::from_angle_z(Degrees(45_f64)),
+    /// );
+    /// let ray = Ray3::new(Point3::origin(), Vector3::unit_x());
+    /// let hit = ray.intersect_obb(&obb).unwrap();
+    /// let expected_normal = Vector3::new(-1_f64, -1_f64, 0_f64) / f64::sqrt(2_f64);
+    ///
+    /// assert_relative_eq!(hit.distance(), 5_f64 - f64::sqrt(2_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(hit.normal(), expected_normal, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn intersect_obb(&self, obb: &Obb3<S>) -> Option<RayHit<S, 3>> {
+        let center = obb.center();
+        let rotation = obb.rotation();
+        let half_extents = obb.half_extents();
+        let local_origin = Point3::from_vector(&rotation.inverse_apply_vector(&(self.origin - center)));
+        let local_direction = rotation.inverse_apply_vector(&self.direction);
+        let local_ray = Self::new(local_origin, local_direction);
+        let local_aabb = Aabb::new(Point3::from_vector(&(-half_extents)), Point3::from_vector(&half_extents));
+
+        local_ray
+            .intersect_aabb(&local_aabb)
+            .map(|hit| RayHit::new(hit.distance(), rotation.apply_vector(&hit.normal())))
+    }
+
+    /// Intersect a ray with a capsule.
+    ///
+    /// The function returns the first point where the ray crosses the surface of
+    /// the capsule, or `None` if the ray misses the capsule. If the origin of the
+    /// ray lies inside the capsule, the hit is the point where the ray exits the
+    /// capsule. The normal of the hit is the outward unit normal of the capsule.
+    ///
+    /// A capsule whose radius is not positive is a segment without a surface
+    /// normal, so the function returns `None` for it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Capsule3,
+    /// #     Ray3,
+    /// # };
+    /// #
+    /// let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 4_f64), 1_f64);
+    /// let side_ray = Ray3::new(Point3::new(5_f64, 0_f64, 2_f64), Vector3::new(-1_f64, 0_f64, 0_f64));
+    /// let side_hit = side_ray.intersect_capsule(&capsule).unwrap();
+    ///
+    /// assert_eq!(side_hit.distance(), 4_f64);
+    /// assert_eq!(side_hit.normal(), Vector3::unit_x());
+    ///
+    /// let cap_ray = Ray3::new(Point3::new(0_f64, 0_f64, 10_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+    /// let cap_hit = cap_ray.intersect_capsule(&capsule).unwrap();
+    ///
+    /// assert_eq!(cap_hit.distance(), 5_f64);
+    /// assert_eq!(cap_hit.normal(), Vector3::unit_z());
+    /// ```
+    pub fn intersect_capsule(&self, capsule: &Capsule3<S>) -> Option<RayHit<S, 3>> {
+        let start = capsule.start();
+        let end = capsule.end();
+        let radius = capsule.radius();
+        if radius <= S::zero() {
+            return None;
+        }
+
+        let axis = end - start;
+        let length_squared = axis.norm_squared();
+        if length_squared.is_zero() {
+            return self.intersect_sphere(&Sphere::new(start, radius));
+        }
+
+        // The surface of a capsule consists of the side of the cylinder around the
+        // segment, and the two hemispheres beyond the ends of the segment. The hit is
+        // the nearest crossing of any of the three surfaces on its own part of the
+        // capsule.
+        let axial_parameter = |t: S| (self.at(t) - start).dot(&axis) / length_squared;
+        let side = {
+            let offset = self.origin - start;
+            let direction_axial = self.direction.dot(&axis);
+            let offset_axial = offset.dot(&axis);
+            let a = self.direction.norm_squared() - direction_axial * direction_axial / length_squared;
+            let b = offset.dot(&self.direction) - offset_axial * direction_axial / length_squared;
+            let c = offset.norm_squared() - offset_axial * offset_axial / length_squared - radius * radius;
+
+            smallest_root(a, b, c, |t| {
+                let parameter = axial_parameter(t);
+                parameter >= S::zero() && parameter <= S::one()
+            })
+        };
+        let start_cap = self.sphere_root(&start, radius, |t| axial_parameter(t) <= S::zero());
+        let end_cap = self.sphere_root(&end, radius, |t| axial_parameter(t) >= S::one());
+        let t = nearest(nearest(side, start_cap), end_cap)?;
+        let hit_point = self.at(t);
+        let parameter = S::min(S::max(axial_parameter(t), S::zero()), S::one());
+        let normal = (hit_point - (start + axis * parameter)) / radius;
+
+        Some(RayHit::new(t, normal))
+    }
+
+    /// Intersect a ray with a cylinder.
+    ///
+    /// The function returns the first point where the ray crosses the surface of
+    /// the cylinder, or `None` if the ray misses the cylinder. If the origin of the
+    /// ray lies inside the cylinder, the hit is the point where the ray exits the
+    /// cylinder. The normal of the hit is the outward unit normal of the side or the
+    /// cap of the cylinder that the ray crosses.
+    ///
+    /// A cylinder whose radius is not positive is a segment without a surface
+    /// normal, so the function returns `None` for it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Cylinder3,
+    /// #     Ray3,
+    /// # };
+    /// #
+    /// let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 4_f64), 1_f64);
+    /// let side_ray = Ray3::new(Point3::new(0_f64, 5_f64, 2_f64), Vector3::new(0_f64, -1_f64, 0_f64));
+    /// let side_hit = side_ray.intersect_cylinder(&cylinder).unwrap();
+    ///
+    /// assert_eq!(side_hit.distance(), 4_f64);
+    /// assert_eq!(side_hit.normal(), Vector3::unit_y());
+    ///
+    /// let cap_ray = Ray3::new(Point3::new(0.5_f64, 0_f64, 10_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+    /// let cap_hit = cap_ray.intersect_cylinder(&cylinder).unwrap();
+    ///
+    /// assert_eq!(cap_hit.distance(), 6_f64);
+    /// assert_eq!(cap_hit.normal(), Vector3::unit_z());
+    /// ```
+    #[inline]
+    pub fn intersect_cylinder(&self, cylinder: &Cylinder3<S>) -> Option<RayHit<S, 3>> {
+        self.intersect_frustum(&cylinder.start(), &cylinder.end(), cylinder.radius(), cylinder.radius())
+    }
+
+    /// Intersect a ray with a cone.
+    ///
+    /// The function returns the first point where the ray crosses the surface of
+    /// the cone, or `None` if the ray misses the cone. If the origin of the ray lies
+    /// inside the cone, the hit is the point where the ray exits the cone. The normal
+    /// of the hit is the outward unit normal of the side or the cap of the cone that
+    /// the ray crosses.
+    ///
+    /// A cone with a negative radius, or with both radii zero, has no well-defined
+    /// surface normal, so the function returns `None` for it. A single zero radius
+    /// is the apex of a proper cone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::{
+    /// #     Cone3,
+    /// #     Ray3,
+    /// # };
+    /// #
+    /// let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 2_f64, 0_f64);
+    /// let ray = Ray3::new(Point3::new(5_f64, 0_f64, 1_f64), Vector3::new(-1_f64, 0_f64, 0_f64));
+    /// let hit = ray.intersect_cone(&cone).unwrap();
+    /// let expected_normal = Vector3::new(1_f64, 0_f64, 1_f64) / f64::sqrt(2_f64);
+    ///
+    /// assert_relative_eq!(hit.distance(), 4_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(hit.normal(), expected_normal, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn intersect_cone(&self, cone: &Cone3<S>) -> Option<RayHit<S, 3>> {
+        self.intersect_frustum(&cone.start(), &cone.end(), cone.start_radius(), cone.end_radius())
+    }
+
+    /// Compute the smallest ray parameter at which the ray crosses the surface of
+    /// a sphere at a point satisfying a predicate.
+    fn sphere_root<F>(&self, center: &Point3<S>, radius: S, is_valid: F) -> Option<S>
+    where
+        F: Fn(S) -> bool,
+    {
+        let offset = self.origin - center;
+        let a = self.direction.norm_squared();
+        let b = offset.dot(&self.direction);
+        let c = offset.norm_squared() - radius * radius;
+
+        smallest_root(a, b, c, is_valid)
+    }
+
+    /// Intersect a ray with a truncated cone whose caps are centered at `start` and `end`
+    /// with radii `start_radius` and `end_radius` respectively.
+    fn intersect_frustum(&self, start: &Point3<S>, end: &Point3<S>, start_radius: S, end_radius: S) -> Option<RayHit<S, 3>> {
+        if start_radius < S::zero() || end_radius < S::zero() || (start_radius.is_zero() && end_radius.is_zero()) {
+            return None;
+        }
+
+        let axis = end - start;
+        let length = axis.norm();
+        if length.is_zero() {
+            return None;
+        }
+
+        // A point at height `h` along the unit axis `u` above `start` lies on the side
+        // of the cone when its distance from the axis is `r(h) := start_radius + k * h`,
+        // where `k` is the slope of the side. Substituting the ray into the squared
+        // equation gives the quadratic equation
+        // ```text
+        // a * t^2 + 2 * b * t + c == 0
+        // ```
+        // whose roots at heights in `[0, length]` are crossings of the side.
+        let unit_axis = axis / length;
+        let slope = (end_radius - start_radius) / length;
+        let height = |t: S| (self.at(t) - start).dot(&unit_axis);
+        let offset = self.origin - start;
+        let offset_axial = offset.dot(&unit_axis);
+        let direction_axial = self.direction.dot(&unit_axis);
+        let offset_radius = start_radius + slope * offset_axial;
+        let a = self.direction.norm_squared() - (S::one() + slope * slope) * direction_axial * direction_axial;
+        let b = offset.dot(&self.direction) - offset_axial * direction_axial - slope * direction_axial * offset_radius;
+        let c = offset.norm_squared() - offset_axial * offset_axial - offset_radius * offset_radius;
+        let side = smallest_root(a, b, c, |t| {
+            let h = height(t);
+            h >= S::zero() && h <= length
+        });
+
+        let cap = |center: &Point3<S>, radius: S| {
+            if direction_axial.is_zero() || radius.is_zero() {
+                return None;
+            }
+
+            let t = (center - self.origin).dot(&unit_axis) / direction_axial;
+            if t >= S::zero() && (self.at(t) - center).norm_squared() <= radius * radius {
+                Some(t)
+            } else {
+                None
+            }
+        };
+        let start_cap = cap(start, start_radius);
+        let end_cap = cap(end, end_radius);
+
+        let t = nearest(nearest(side, start_cap), end_cap)?;
+        let normal = if Some(t) == side {
+            // The outward normal of the side is the gradient of `distance(p, axis) - r(h)`.
+            let radial = (self.at(t) - start).reject(&unit_axis);
+            let radial_norm = radial.norm();
+            let radial_unit = if radial_norm.is_zero() { Vector3::zero() } else { radial / radial_norm };
+
+            (radial_unit - unit_axis * slope).normalize()
+        } else if Some(t) == start_cap {
+            -unit_axis
+        } else {
+            unit_axis
+        };
+
+        Some(RayHit::new(t, normal))
+    }
+}
+
+/// Compute the smallest non-negative root of the quadratic equation
+/// `a * t^2 + 2 * b * t + c == 0` satisfying a predicate.
+///
+/// The roots are computed in the numerically stable form of the quadratic formula,
+/// which remains accurate when `a` is close to zero.
+fn smallest_root<S, F>(a: S, b: S, c: S, is_valid: F) -> Option<S>
+where
+    S: SimdScalarFloat,
+    F: Fn(S) -> bool,
+{
+    let discriminant = b * b - a * c;
+    if discriminant < S::zero() {
+        return None;
+    }
+
+    let sqrt_discriminant = discriminant.sqrt();
+    let q = if b >= S::zero() { -(b + sqrt_discriminant) } else { -b + sqrt_discriminant };
+    let root1 = if a.is_zero() { None } else { Some(q / a) };
+    let root2 = if q.is_zero() { None } else { Some(c / q) };
+    let candidate = |root: Option<S>| root.filter(|t| *t >= S::zero() && is_valid(*t));
+
+    nearest(candidate(root1), candidate(root2))
+}
+
+/// Choose the nearer of two optional ray parameters.
+#[inline]
+fn nearest<S>(t1: Option<S>, t2: Option<S>) -> Option<S>
+where
+    S: SimdScalarFloat,
+{
+    match (t1, t2) {
+        (Some(t1), Some(t2)) => Some(S::min(t1, t2)),
+        (Some(t1), None) => Some(t1),
+        (None, t2) => t2,
+    }
+}
+
+impl<S, const N: usize> fmt::Display for Ray<S, N>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Ray{} [origin={}, direction={}]", N, self.origin, self.direction)
+    }
+}
+
+
+/// The result of a successful ray intersection query.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayHit<S, const N: usize> {
+    distance: S,
+    normal: Vector<S, N>,
+}
+
+impl<S, const N: usize> RayHit<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new ray hit from the ray parameter of the hit and the surface
+    /// normal at the hit.
+    #[inline]
+    pub const fn new(distance: S, normal: Vector<S, N>) -> Self {
+        Self { distance, normal }
+    }
+
+    /// Get the ray parameter of the hit.
+    ///
+    /// The hit point is `ray.at(hit.distance())`. The ray parameter is the distance
+    /// from the origin of the ray to the hit point when the ray direction is a
+    /// unit vector.
+    #[inline]
+    pub const fn distance(&self) -> S {
+        self.distance
+    }
+
+    /// Get the unit surface normal at the hit.
+    #[inline]
+    pub const fn normal(&self) -> Vector<S, N> {
+        self.normal
+    }
+}
+
+
+/// The result of a successful ray-triangle intersection query.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayTriangleHit<S> {
+    distance: S,
+    normal: Vector3<S>,
+    barycentric: Vector3<S>,
+}
+
+impl<S> RayTriangleHit<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new ray-triangle hit from the ray parameter of the hit, the
+    /// normal of the triangle, and the barycentric coordinates of the hit.
+    #[inline]
+    pub const fn new(distance: S, normal: Vector3<S>, barycentric: Vector3<S>) -> Self {
+        Self {
+            distance,
+            normal,
+            barycentric,
+        }
+    }
+
+    /// Get the ray parameter of the hit.
+    #[inline]
+    pub const fn distance(&self) -> S {
+        self.distance
+    }
+
+    /// Get the unit normal of the triangle.
+    #[inline]
+    pub const fn normal(&self) -> Vector3<S> {
+        self.normal
+    }
+
+    /// Get the barycentric coordinates of the hit.
+    ///
+    /// The barycentric coordinates `[w, u, v]` are the weights of the vertices
+    /// `a`, `b`, and `c` of the triangle respectively, such that the hit point is
+    /// `w * a + u * b + v * c`, where `w + u + v == 1`.
+    #[inline]
+    pub const fn barycentric(&self) -> Vector3<S> {
+        self.barycentric
+    }
+
+    /// Convert a ray-triangle hit into a ray hit, discarding the barycentric
+    /// coordinates.
+    #[inline]
+    pub const fn to_ray_hit(&self) -> RayHit<S, 3> {
+        RayHit::new(self.distance, self.normal)
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayTol<S, const N: usize> {
+    origin: Vector<S, N>,
+    direction: Vector<S, N>,
+}
+
+impl<S, const N: usize> RayTol<S, N> {
+    #[inline]
+    pub const fn from_parts(origin: Vector<S, N>, direction: Vector<S, N>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayDiff<S, const N: usize> {
+    origin: Vector<S, N>,
+    direction: Vector<S, N>,
+}
+
+impl<S, const N: usize> RayDiff<S, N> {
+    #[inline]
+    const fn from_parts(origin: Vector<S, N>, direction: Vector<S, N>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = RayTol<<S as approx_cmp::AbsDiffEq>::Tolerance, N>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.origin, &other.origin, &max_abs_diff.origin)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.direction, &other.direction, &max_abs_diff.direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffAllEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.origin, &other.origin, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.direction, &other.direction, max_abs_diff)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = RayDiff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff, N>;
+    type DebugTolerance = RayTol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let origin = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.origin, &other.origin);
+        let direction = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.direction, &other.direction);
+
+        RayDiff::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let origin = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.origin, &other.origin, &max_abs_diff.origin);
+        let direction = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.direction, &other.direction, &max_abs_diff.direction);
+
+        RayTol::from_parts(origin, direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffAllEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = RayTol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let origin = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.origin, &other.origin, max_abs_diff);
+        let direction = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.direction, &other.direction, max_abs_diff);
+
+        RayTol::from_parts(origin, direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = RayTol<<S as approx_cmp::RelativeEq>::Tolerance, N>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.origin, &other.origin, &max_abs_diff.origin, &max_relative.origin)
+            && approx_cmp::RelativeEq::relative_eq(&self.direction, &other.direction, &max_abs_diff.direction, &max_relative.direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeAllEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.origin, &other.origin, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.direction, &other.direction, max_abs_diff, max_relative)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = RayDiff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff, N>;
+    type DebugTolerance = RayTol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let origin = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.origin, &other.origin);
+        let direction = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.direction, &other.direction);
+
+        RayDiff::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let origin = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.origin, &other.origin, &max_abs_diff.origin);
+        let direction = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.direction, &other.direction, &max_abs_diff.direction);
+
+        RayTol::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let origin = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.origin, &other.origin, &max_relative.origin);
+        let direction = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.direction, &other.direction, &max_relative.direction);
+
+        RayTol::from_parts(origin, direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeAllEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = RayTol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let origin = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.origin, &other.origin, max_abs_diff);
+        let direction = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.direction, &other.direction, max_abs_diff);
+
+        RayTol::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let origin = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.origin, &other.origin, max_relative);
+        let direction = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.direction, &other.direction, max_relative);
+
+        RayTol::from_parts(origin, direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = RayTol<<S as approx_cmp::UlpsEq>::Tolerance, N>;
+    type UlpsTolerance = RayTol<<S as approx_cmp::UlpsEq>::UlpsTolerance, N>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.origin, &other.origin, &max_abs_diff.origin, &max_ulps.origin)
+            && approx_cmp::UlpsEq::ulps_eq(&self.direction, &other.direction, &max_abs_diff.direction, &max_ulps.direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsAllEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.origin, &other.origin, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.direction, &other.direction, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = RayDiff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff, N>;
+    type DebugUlpsDiff = RayDiff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff, N>;
+    type DebugTolerance = RayTol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance, N>;
+    type DebugUlpsTolerance = RayTol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let origin = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.origin, &other.origin);
+        let direction = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.direction, &other.direction);
+
+        RayDiff::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let origin = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.origin, &other.origin);
+        let direction = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.direction, &other.direction);
+
+        RayDiff::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let origin = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.origin, &other.origin, &max_abs_diff.origin);
+        let direction = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.direction, &other.direction, &max_abs_diff.direction);
+
+        RayTol::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let origin = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.origin, &other.origin, &max_ulps.origin);
+        let direction = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.direction, &other.direction, &max_ulps.direction);
+
+        RayTol::from_parts(origin, direction)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsAllEq for Ray<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = RayTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance, N>;
+    type AllDebugUlpsTolerance = RayTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let origin = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.origin, &other.origin, max_abs_diff);
+        let direction = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.direction, &other.direction, max_abs_diff);
+
+        RayTol::from_parts(origin, direction)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let origin = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.origin, &other.origin, max_ulps);
+        let direction = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.direction, &other.direction, max_ulps);
+
+        RayTol::from_parts(origin, direction)
+    }
+}
@@ -0,0 +1,552 @@
+use crate::aabb::Aabb;
+use crate::bounded::Bounded;
+use crate::support_map::SupportMap;
+use cglinalg_core::{
+    Point,
+    Point3,
+    Vector,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// A line segment in two dimensions.
+pub type Segment2<S> = Segment<S, 2>;
+
+/// A line segment in three dimensions.
+pub type Segment3<S> = Segment<S, 3>;
+
+
+/// A line segment.
+///
+/// A line segment is the set of points
+///
+/// ```text
+/// { (1 - t) * start + t * end | 0 <= t <= 1 }
+/// ```
+///
+/// where `start` and `end` are the endpoints of the segment. A segment whose
+/// endpoints coincide is degenerate, but remains a valid segment.
+///
+/// This is the most general line segment type. The vast majority of applications
+/// should use [`Segment2`] or [`Segment3`] instead of this type directly.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Segment<S, const N: usize> {
+    start: Point<S, N>,
+    end: Point<S, N>,
+}
+
+impl<S, const N: usize> Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new line segment from its endpoints.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Segment3;
+    /// #
+    /// let start = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let end = Point3::new(4_f64, 6_f64, 3_f64);
+    /// let segment = Segment3::new(start, end);
+    ///
+    /// assert_eq!(segment.start(), start);
+    /// assert_eq!(segment.end(), end);
+    /// assert_eq!(segment.length(), 5_f64);
+    /// ```
+    #[inline]
+    pub const fn new(start: Point<S, N>, end: Point<S, N>) -> Self {
+        Self { start, end }
+    }
+
+    /// Get the starting point of the segment.
+    #[inline]
+    pub const fn start(&self) -> Point<S, N> {
+        self.start
+    }
+
+    /// Get the ending point of the segment.
+    #[inline]
+    pub const fn end(&self) -> Point<S, N> {
+        self.end
+    }
+
+    /// Compute the vector from the start of the segment to the end of the segment.
+    #[inline]
+    pub fn direction(&self) -> Vector<S, N> {
+        self.end - self.start
+    }
+
+    /// Compute the length of the segment.
+    #[inline]
+    pub fn length(&self) -> S {
+        self.direction().norm()
+    }
+
+    /// Compute the squared length of the segment.
+    #[inline]
+    pub fn length_squared(&self) -> S {
+        self.direction().norm_squared()
+    }
+
+    /// Compute the midpoint of the segment.
+    #[inline]
+    pub fn midpoint(&self) -> Point<S, N> {
+        let one_half = cglinalg_numeric::cast(0.5_f64);
+
+        self.at(one_half)
+    }
+
+    /// Compute the point on the line through the segment at the parameter `t`.
+    ///
+    /// The parameter `t == 0` gives the start of the segment, and `t == 1` gives the
+    /// end of the segment.
+    #[inline]
+    pub fn at(&self, t: S) -> Point<S, N> {
+        self.start + self.direction() * t
+    }
+
+    /// Compute the parameter of the point on the segment closest to a point.
+    ///
+    /// The parameter is in the interval `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_geometry::Segment2;
+    /// #
+    /// let segment = Segment2::new(Point2::new(0_f64, 0_f64), Point2::new(4_f64, 0_f64));
+    ///
+    /// assert_eq!(segment.closest_parameter(&Point2::new(1_f64, 3_f64)), 1_f64 / 4_f64);
+    /// assert_eq!(segment.closest_parameter(&Point2::new(-1_f64, 3_f64)), 0_f64);
+    /// assert_eq!(segment.closest_parameter(&Point2::new(7_f64, 3_f64)), 1_f64);
+    /// ```
+    #[inline]
+    pub fn closest_parameter(&self, point: &Point<S, N>) -> S {
+        let direction = self.direction();
+        let length_squared = direction.norm_squared();
+        if length_squared.is_zero() {
+            return S::zero();
+        }
+
+        let t = (point - self.start).dot(&direction) / length_squared;
+
+        t.max(S::zero()).min(S::one())
+    }
+
+    /// Compute the point on the segment closest to a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_geometry::Segment2;
+    /// #
+    /// let segment = Segment2::new(Point2::new(0_f64, 0_f64), Point2::new(4_f64, 0_f64));
+    ///
+    /// assert_eq!(segment.closest_point(&Point2::new(1_f64, 3_f64)), Point2::new(1_f64, 0_f64));
+    /// assert_eq!(segment.closest_point(&Point2::new(7_f64, 3_f64)), Point2::new(4_f64, 0_f64));
+    /// ```
+    #[inline]
+    pub fn closest_point(&self, point: &Point<S, N>) -> Point<S, N> {
+        self.at(self.closest_parameter(point))
+    }
+
+    /// Compute the squared distance from a point to the segment.
+    #[inline]
+    pub fn distance_squared(&self, point: &Point<S, N>) -> S {
+        (point - self.closest_point(point)).norm_squared()
+    }
+
+    /// Compute a pair of closest points between two segments.
+    ///
+    /// The function returns the point on `self` and the point on `other` that are
+    /// closest to each other. When several pairs of points are closest, as happens for
+    /// overlapping parallel segments, the function returns one of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Segment3;
+    /// #
+    /// let segment1 = Segment3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(4_f64, 0_f64, 0_f64));
+    /// let segment2 = Segment3::new(Point3::new(6_f64, -1_f64, 2_f64), Point3::new(6_f64, 1_f64, 2_f64));
+    /// let (point1, point2) = segment1.closest_points_segment(&segment2);
+    ///
+    /// assert_eq!(point1, Point3::new(4_f64, 0_f64, 0_f64));
+    /// assert_eq!(point2, Point3::new(6_f64, 0_f64, 2_f64));
+    /// ```
+    pub fn closest_points_segment(&self, other: &Self) -> (Point<S, N>, Point<S, N>) {
+        // See Christer Ericson, Real-Time Collision Detection, Section 5.1.9. The
+        // closest points of the lines through the segments are clamped to the first
+        // segment, and then the closest point on the second segment is recomputed and
+        // clamped, which in turn may require recomputing the point on the first segment.
+        let clamp = |value: S| value.max(S::zero()).min(S::one());
+        let d1 = self.direction();
+        let d2 = other.direction();
+        let r = self.start - other.start;
+        let a = d1.dot(&d1);
+        let e = d2.dot(&d2);
+        let f = d2.dot(&r);
+        let (s, t) = if a.is_zero() && e.is_zero() {
+            (S::zero(), S::zero())
+        } else if a.is_zero() {
+            (S::zero(), clamp(f / e))
+        } else {
+            let c = d1.dot(&r);
+            if e.is_zero() {
+                (clamp(-c / a), S::zero())
+            } else {
+                let b = d1.dot(&d2);
+                let denominator = a * e - b * b;
+                let s = if denominator.is_zero() {
+                    S::zero()
+                } else {
+                    clamp((b * f - c * e) / denominator)
+                };
+                let t = (b * s + f) / e;
+                if t < S::zero() {
+                    (clamp(-c / a), S::zero())
+                } else if t > S::one() {
+                    (clamp((b - c) / a), S::one())
+                } else {
+                    (s, t)
+                }
+            }
+        };
+
+        (self.at(s), other.at(t))
+    }
+
+    /// Compute the squared distance between two segments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_geometry::Segment2;
+    /// #
+    /// let segment1 = Segment2::new(Point2::new(0_f64, 0_f64), Point2::new(2_f64, 2_f64));
+    /// let segment2 = Segment2::new(Point2::new(0_f64, 2_f64), Point2::new(2_f64, 0_f64));
+    /// let segment3 = Segment2::new(Point2::new(3_f64, 0_f64), Point2::new(5_f64, 0_f64));
+    ///
+    /// assert_eq!(segment1.distance_squared_segment(&segment2), 0_f64);
+    /// assert_eq!(segment1.distance_squared_segment(&segment3), 9_f64 / 2_f64);
+    /// ```
+    #[inline]
+    pub fn distance_squared_segment(&self, other: &Self) -> S {
+        let (point1, point2) = self.closest_points_segment(other);
+
+        (point1 - point2).norm_squared()
+    }
+}
+
+impl<S, const N: usize> fmt::Display for Segment<S, N>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Segment{} [start={}, end={}]", N, self.start, self.end)
+    }
+}
+
+
+impl<S> SupportMap<S> for Segment<S, 3>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S> {
+        if self.direction().dot(direction) >= S::zero() {
+            self.end
+        } else {
+            self.start
+        }
+    }
+}
+
+impl<S> Bounded<S> for Segment<S, 3>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn aabb(&self) -> Aabb<S, 3> {
+        Aabb::from_corners(&self.start, &self.end)
+    }
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SegmentTol<S, const N: usize> {
+    start: Vector<S, N>,
+    end: Vector<S, N>,
+}
+
+impl<S, const N: usize> SegmentTol<S, N> {
+    #[inline]
+    pub const fn from_parts(start: Vector<S, N>, end: Vector<S, N>) -> Self {
+        Self { start, end }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SegmentDiff<S, const N: usize> {
+    start: Vector<S, N>,
+    end: Vector<S, N>,
+}
+
+impl<S, const N: usize> SegmentDiff<S, N> {
+    #[inline]
+    const fn from_parts(start: Vector<S, N>, end: Vector<S, N>) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = SegmentTol<<S as approx_cmp::AbsDiffEq>::Tolerance, N>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.start, &other.start, &max_abs_diff.start)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.end, &other.end, &max_abs_diff.end)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffAllEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.start, &other.start, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.end, &other.end, max_abs_diff)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = SegmentDiff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff, N>;
+    type DebugTolerance = SegmentTol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.end, &other.end);
+
+        SegmentDiff::from_parts(start, end)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+
+        SegmentTol::from_parts(start, end)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffAllEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = SegmentTol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+
+        SegmentTol::from_parts(start, end)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = SegmentTol<<S as approx_cmp::RelativeEq>::Tolerance, N>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.start, &other.start, &max_abs_diff.start, &max_relative.start)
+            && approx_cmp::RelativeEq::relative_eq(&self.end, &other.end, &max_abs_diff.end, &max_relative.end)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeAllEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.start, &other.start, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.end, &other.end, max_abs_diff, max_relative)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = SegmentDiff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff, N>;
+    type DebugTolerance = SegmentTol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.end, &other.end);
+
+        SegmentDiff::from_parts(start, end)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+
+        SegmentTol::from_parts(start, end)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.start, &other.start, &max_relative.start);
+        let end = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.end, &other.end, &max_relative.end);
+
+        SegmentTol::from_parts(start, end)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeAllEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = SegmentTol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+
+        SegmentTol::from_parts(start, end)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.start, &other.start, max_relative);
+        let end = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.end, &other.end, max_relative);
+
+        SegmentTol::from_parts(start, end)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = SegmentTol<<S as approx_cmp::UlpsEq>::Tolerance, N>;
+    type UlpsTolerance = SegmentTol<<S as approx_cmp::UlpsEq>::UlpsTolerance, N>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.start, &other.start, &max_abs_diff.start, &max_ulps.start)
+            && approx_cmp::UlpsEq::ulps_eq(&self.end, &other.end, &max_abs_diff.end, &max_ulps.end)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsAllEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.start, &other.start, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.end, &other.end, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = SegmentDiff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff, N>;
+    type DebugUlpsDiff = SegmentDiff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff, N>;
+    type DebugTolerance = SegmentTol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance, N>;
+    type DebugUlpsTolerance = SegmentTol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let start = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.end, &other.end);
+
+        SegmentDiff::from_parts(start, end)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let start = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.start, &other.start);
+        let end = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.end, &other.end);
+
+        SegmentDiff::from_parts(start, end)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let start = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.start, &other.start, &max_abs_diff.start);
+        let end = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.end, &other.end, &max_abs_diff.end);
+
+        SegmentTol::from_parts(start, end)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let start = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.start, &other.start, &max_ulps.start);
+        let end = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.end, &other.end, &max_ulps.end);
+
+        SegmentTol::from_parts(start, end)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsAllEq for Segment<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = SegmentTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance, N>;
+    type AllDebugUlpsTolerance = SegmentTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let start = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.start, &other.start, max_abs_diff);
+        let end = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.end, &other.end, max_abs_diff);
+
+        SegmentTol::from_parts(start, end)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let start = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.start, &other.start, max_ulps);
+        let end = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.end, &other.end, max_ulps);
+
+        SegmentTol::from_parts(start, end)
+    }
+}
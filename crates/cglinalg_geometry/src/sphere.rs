@@ -0,0 +1,907 @@
+use crate::aabb::Aabb;
+use crate::bounded::Bounded;
+use crate::support_map::SupportMap;
+use cglinalg_core::{
+    Point,
+    Point3,
+    Vector,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::{
+    Isometry,
+    Similarity,
+};
+
+use core::fmt;
+
+
+/// A circle in two dimensions.
+pub type Sphere2<S> = Sphere<S, 2>;
+
+/// A sphere in three dimensions.
+pub type Sphere3<S> = Sphere<S, 3>;
+
+
+/// A sphere, i.e. a closed ball.
+///
+/// A sphere is the set of points
+///
+/// ```text
+/// { p in R^N | norm(p - center) <= radius }
+/// ```
+///
+/// where `center` is the center of the sphere, and `radius` is the radius of the
+/// sphere. In two dimensions a sphere is a disk. Spheres are the cheapest bounding
+/// volumes to test against each other, which makes them useful for coarse culling
+/// and level of detail selection.
+///
+/// This is the most general sphere type. The vast majority of applications should
+/// use [`Sphere2`] or [`Sphere3`] instead of this type directly.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sphere<S, const N: usize> {
+    center: Point<S, N>,
+    radius: S,
+}
+
+impl<S, const N: usize> Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new sphere from its center and radius.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Sphere3;
+    /// #
+    /// let sphere = Sphere3::new(Point3::new(1_f64, 2_f64, 3_f64), 4_f64);
+    ///
+    /// assert_eq!(sphere.center(), Point3::new(1_f64, 2_f64, 3_f64));
+    /// assert_eq!(sphere.radius(), 4_f64);
+    /// ```
+    #[inline]
+    pub const fn new(center: Point<S, N>, radius: S) -> Self {
+        Self { center, radius }
+    }
+
+    /// Construct the smallest sphere containing an axis-aligned bounding box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Sphere3,
+    /// # };
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(-1_f64, -2_f64, -2_f64), Point3::new(1_f64, 2_f64, 2_f64));
+    /// let sphere = Sphere3::from_aabb(&aabb);
+    ///
+    /// assert_eq!(sphere.center(), Point3::origin());
+    /// assert_eq!(sphere.radius(), 3_f64);
+    /// ```
+    #[inline]
+    pub fn from_aabb(aabb: &Aabb<S, N>) -> Self {
+        Self::new(aabb.center(), aabb.half_extents().norm())
+    }
+
+    /// Get the center of the sphere.
+    #[inline]
+    pub const fn center(&self) -> Point<S, N> {
+        self.center
+    }
+
+    /// Get the radius of the sphere.
+    #[inline]
+    pub const fn radius(&self) -> S {
+        self.radius
+    }
+
+    /// Compute the smallest axis-aligned bounding box containing the sphere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Sphere3,
+    /// # };
+    /// #
+    /// let sphere = Sphere3::new(Point3::new(1_f64, 2_f64, 3_f64), 4_f64);
+    /// let expected = Aabb3::new(Point3::new(-3_f64, -2_f64, -1_f64), Point3::new(5_f64, 6_f64, 7_f64));
+    /// let result = sphere.to_aabb();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn to_aabb(&self) -> Aabb<S, N> {
+        let half_extents = Vector::from_fill(self.radius);
+
+        Aabb::from_center_half_extents(&self.center, &half_extents)
+    }
+
+    /// Determine whether a sphere contains a point.
+    ///
+    /// The sphere is closed, so points on the boundary of the sphere are contained
+    /// in the sphere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Sphere3;
+    /// #
+    /// let sphere = Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 2_f64);
+    ///
+    /// assert!(sphere.contains(&Point3::new(1_f64, 1_f64, 1_f64)));
+    /// assert!(sphere.contains(&Point3::new(0_f64, 2_f64, 0_f64)));
+    /// assert!(!sphere.contains(&Point3::new(2_f64, 2_f64, 0_f64)));
+    /// ```
+    #[inline]
+    pub fn contains(&self, point: &Point<S, N>) -> bool {
+        (point - self.center).norm_squared() <= self.radius * self.radius
+    }
+
+    /// Determine whether a sphere contains another sphere.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Sphere3;
+    /// #
+    /// let sphere = Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 5_f64);
+    /// let inner = Sphere3::new(Point3::new(3_f64, 0_f64, 0_f64), 2_f64);
+    /// let overlapping = Sphere3::new(Point3::new(4_f64, 0_f64, 0_f64), 2_f64);
+    ///
+    /// assert!(sphere.contains_sphere(&inner));
+    /// assert!(!sphere.contains_sphere(&overlapping));
+    /// ```
+    #[inline]
+    pub fn contains_sphere(&self, other: &Self) -> bool {
+        let difference = self.radius - other.radius;
+
+        difference >= S::zero() && (other.center - self.center).norm_squared() <= difference * difference
+    }
+
+    /// Determine whether two spheres overlap.
+    ///
+    /// Spheres that touch at a single point overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Sphere3;
+    /// #
+    /// let sphere1 = Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 2_f64);
+    /// let sphere2 = Sphere3::new(Point3::new(3_f64, 0_f64, 0_f64), 1_f64);
+    /// let sphere3 = Sphere3::new(Point3::new(0_f64, 4_f64, 0_f64), 1_f64);
+    ///
+    /// assert!(sphere1.intersects(&sphere2));
+    /// assert!(!sphere1.intersects(&sphere3));
+    /// ```
+    #[inline]
+    pub fn intersects(&self, other: &Self) -> bool {
+        let sum = self.radius + other.radius;
+
+        (other.center - self.center).norm_squared() <= sum * sum
+    }
+
+    /// Determine whether a sphere overlaps an axis-aligned bounding box.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::{
+    /// #     Aabb3,
+    /// #     Sphere3,
+    /// # };
+    /// #
+    /// let aabb = Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(1_f64, 1_f64, 1_f64));
+    /// let sphere1 = Sphere3::new(Point3::new(3_f64, 0_f64, 0_f64), 2_f64);
+    /// let sphere2 = Sphere3::new(Point3::new(2_f64, 2_f64, 2_f64), 1_f64);
+    ///
+    /// assert!(sphere1.intersects_aabb(&aabb));
+    /// assert!(!sphere2.intersects_aabb(&aabb));
+    /// ```
+    #[inline]
+    pub fn intersects_aabb(&self, aabb: &Aabb<S, N>) -> bool {
+        aabb.distance_squared(&self.center) <= self.radius * self.radius
+    }
+
+    /// Compute the smallest sphere containing two spheres.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Sphere3;
+    /// #
+    /// let sphere1 = Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 1_f64);
+    /// let sphere2 = Sphere3::new(Point3::new(4_f64, 0_f64, 0_f64), 1_f64);
+    /// let expected = Sphere3::new(Point3::new(2_f64, 0_f64, 0_f64), 3_f64);
+    /// let result = sphere1.merge(&sphere2);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn merge(&self, other: &Self) -> Self {
+        if self.contains_sphere(other) {
+            return *self;
+        }
+        if other.contains_sphere(self) {
+            return *other;
+        }
+
+        let one_half = cglinalg_numeric::cast(0.5_f64);
+        let offset = other.center - self.center;
+        let distance = offset.norm();
+        let radius = (distance + self.radius + other.radius) * one_half;
+        let center = self.center + offset * ((radius - self.radius) / distance);
+
+        Self::new(center, radius)
+    }
+
+    /// Compute the smallest sphere containing a sphere and a point, such that the
+    /// point lies on the boundary of the new sphere and the side of the sphere
+    /// opposite the point is unchanged.
+    ///
+    /// The function returns the original sphere if the sphere already contains the point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Sphere3;
+    /// #
+    /// let sphere = Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 1_f64);
+    /// let point = Point3::new(0_f64, 5_f64, 0_f64);
+    /// let expected = Sphere3::new(Point3::new(0_f64, 2_f64, 0_f64), 3_f64);
+    /// let result = sphere.union_point(&point);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn union_point(&self, point: &Point<S, N>) -> Self {
+        self.merge(&Self::new(*point, S::zero()))
+    }
+
+    /// Transform a sphere by an isometry.
+    #[inline]
+    pub fn transform_isometry(&self, isometry: &Isometry<S, N>) -> Self {
+        Self::new(isometry.apply_point(&self.center), self.radius)
+    }
+
+    /// Transform a sphere by a similarity transformation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Sphere3;
+    /// # use cglinalg_transform::{
+    /// #     Rotation3,
+    /// #     Similarity3,
+    /// #     Translation3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let sphere = Sphere3::new(Point3::new(1_f64, 0_f64, 0_f64), 2_f64);
+    /// let similarity = Similarity3::from_parts(
+    ///     &Translation3::new(0_f64, 0_f64, 1_f64),
+    ///     &Rotation3::from_angle_z(Degrees(90_f64)),
+    ///     3_f64,
+    /// );
+    /// let expected = Sphere3::new(Point3::new(0_f64, 3_f64, 1_f64), 6_f64);
+    /// let result = sphere.transform_similarity(&similarity);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn transform_similarity(&self, similarity: &Similarity<S, N>) -> Self {
+        Self::new(similarity.apply_point(&self.center), self.radius * similarity.scale().abs())
+    }
+
+    /// Compute a bounding sphere for a set of points using Ritter's algorithm.
+    ///
+    /// Ritter's algorithm computes a bounding sphere in two passes over the points.
+    /// The first pass picks two points far apart from each other and starts from
+    /// the sphere with these points as a diameter, and the second pass grows the sphere
+    /// to contain every point. The resulting sphere contains all the points, and is
+    /// typically within a few percent of the minimal enclosing sphere, but it is not
+    /// minimal in general. Use [`Sphere::from_points_welzl`] to compute the minimal
+    /// enclosing sphere.
+    ///
+    /// The function returns `None` when `points` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Sphere3;
+    /// #
+    /// let points = [
+    ///     Point3::new(1_f64, 0_f64, 0_f64),
+    ///     Point3::new(-1_f64, 0_f64, 0_f64),
+    ///     Point3::new(0_f64, 1_f64, 0_f64),
+    ///     Point3::new(0_f64, 0_f64, -1_f64),
+    /// ];
+    /// let sphere = Sphere3::from_points_ritter(&points).unwrap();
+    ///
+    /// assert_eq!(sphere, Sphere3::new(Point3::origin(), 1_f64));
+    /// ```
+    pub fn from_points_ritter(points: &[Point<S, N>]) -> Option<Self> {
+        let first = points.first()?;
+        let farthest_from = |point: &Point<S, N>| {
+            points
+                .iter()
+                .fold(*point, |farthest, p| {
+                    if (p - point).norm_squared() > (farthest - point).norm_squared() {
+                        *p
+                    } else {
+                        farthest
+                    }
+                })
+        };
+        let a = farthest_from(first);
+        let b = farthest_from(&a);
+        let one_half = cglinalg_numeric::cast(0.5_f64);
+        let center = Point::from_vector(&((a.to_vector() + b.to_vector()) * one_half));
+        let radius = (b - a).norm() * one_half;
+        let sphere = points
+            .iter()
+            .fold(Self::new(center, radius), |sphere, point| sphere.union_point(point));
+
+        Some(sphere)
+    }
+
+    /// Compute the minimal enclosing sphere of a set of points using Welzl's algorithm.
+    ///
+    /// The minimal enclosing sphere is the unique sphere of smallest radius containing
+    /// all of the points. The function does not allocate, so it is available without
+    /// the standard library. Welzl's algorithm runs in expected linear time when the
+    /// points are visited in a random order, so the points are visited in a fixed
+    /// scrambled order rather than the order given. This keeps inputs with a lot of
+    /// structure, such as points sorted along a curve, from taking much longer.
+    ///
+    /// The function returns `None` when `points` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_geometry::Sphere2;
+    /// #
+    /// let points = [
+    ///     Point2::new(0_f64, 0_f64),
+    ///     Point2::new(4_f64, 0_f64),
+    ///     Point2::new(2_f64, 1_f64),
+    ///     Point2::new(0_f64, 4_f64),
+    ///     Point2::new(1_f64, 1_f64),
+    /// ];
+    /// let sphere = Sphere2::from_points_welzl(&points).unwrap();
+    ///
+    /// assert_relative_eq!(sphere.center(), Point2::new(2_f64, 2_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(sphere.radius(), f64::sqrt(8_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn from_points_welzl(points: &[Point<S, N>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+
+        let stride = visiting_stride(points.len());
+
+        Some(Self::welzl(points, stride, points.len(), None, 0))
+    }
+
+    /// Compute the minimal sphere containing the first `count` points in visiting
+    /// order with the points of `support` on its boundary.
+    ///
+    /// The `k`-th point in visiting order is `points[(k * stride) % points.len()]`,
+    /// which visits every point exactly once since `stride` is coprime to the
+    /// number of points.
+    ///
+    /// The support points live in a linked list on the call stack, so no heap
+    /// allocation is necessary. Each recursive call adds one support point, and a
+    /// sphere in `N` dimensions is determined by at most `N + 1` boundary points, so
+    /// the recursion depth is at most `N + 1`.
+    fn welzl(
+        points: &[Point<S, N>],
+        stride: usize,
+        count: usize,
+        support: Option<&SupportPoint<S, N>>,
+        support_count: usize,
+    ) -> Self {
+        let mut sphere = Self::circumsphere(support);
+        if support_count == N + 1 {
+            return sphere;
+        }
+
+        let mut index = 0;
+        for k in 0..count {
+            if !sphere.contains_with_tolerance(&points[index]) {
+                let next_support = SupportPoint {
+                    point: points[index],
+                    next: support,
+                };
+                sphere = Self::welzl(points, stride, k, Some(&next_support), support_count + 1);
+            }
+
+            index = (index + stride) % points.len();
+        }
+
+        sphere
+    }
+
+    /// Compute the smallest sphere with the points of `support` on its boundary.
+    ///
+    /// The smallest sphere passing through the points `p0, p1, ..., pm` has its
+    /// center in the affine hull of the points. Writing the center as
+    ///
+    /// ```text
+    /// center == p0 + lambda[1] * q1 + ... + lambda[m] * qm
+    /// ```
+    ///
+    /// where `qi := pi - p0`, the condition `norm(center - pi) == norm(center - p0)`
+    /// for each `i` gives the linear system
+    ///
+    /// ```text
+    /// sum(j, 2 * dot(qi, qj) * lambda[j]) == dot(qi, qi)
+    /// ```
+    ///
+    /// When the support points are affinely dependent the system is singular, and the
+    /// dependent directions are dropped.
+    fn circumsphere(support: Option<&SupportPoint<S, N>>) -> Self {
+        let Some(first) = support else {
+            // The empty sphere contains no points.
+            return Self::new(Point::origin(), -S::one());
+        };
+
+        let origin = first.point;
+        let mut offsets = [Vector::zero(); N];
+        let mut count = 0;
+        let mut current = first.next;
+        while let Some(support_point) = current {
+            offsets[count] = support_point.point - origin;
+            count += 1;
+            current = support_point.next;
+        }
+
+        let two = S::one() + S::one();
+        let mut matrix = [[S::zero(); N]; N];
+        let mut rhs = [S::zero(); N];
+        for i in 0..count {
+            for j in 0..count {
+                matrix[i][j] = two * offsets[i].dot(&offsets[j]);
+            }
+            rhs[i] = offsets[i].dot(&offsets[i]);
+        }
+
+        let lambda = solve_symmetric_positive_semidefinite(&mut matrix, &mut rhs, count);
+        let mut center_offset = Vector::zero();
+        for i in 0..count {
+            center_offset += offsets[i] * lambda[i];
+        }
+
+        Self::new(origin + center_offset, center_offset.norm())
+    }
+
+    /// Determine whether a sphere contains a point, up to floating point roundoff
+    /// relative to the size of the sphere.
+    #[inline]
+    fn contains_with_tolerance(&self, point: &Point<S, N>) -> bool {
+        if self.radius < S::zero() {
+            return false;
+        }
+
+        let tolerance_factor: S = cglinalg_numeric::cast(64_f64);
+        let tolerance = tolerance_factor * S::machine_epsilon() * (S::one() + self.radius);
+        let radius = self.radius + tolerance;
+
+        (point - self.center).norm_squared() <= radius * radius
+    }
+}
+
+impl<S, const N: usize> fmt::Display for Sphere<S, N>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Sphere{} [center={}, radius={}]", N, self.center, self.radius)
+    }
+}
+
+
+impl<S> SupportMap<S> for Sphere<S, 3>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S> {
+        let norm = direction.norm();
+        if norm.is_zero() {
+            return self.center;
+        }
+
+        self.center + direction * (self.radius / norm)
+    }
+}
+
+impl<S> Bounded<S> for Sphere<S, 3>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn aabb(&self) -> Aabb<S, 3> {
+        self.to_aabb()
+    }
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SphereTol<S, const N: usize> {
+    center: Vector<S, N>,
+    radius: S,
+}
+
+impl<S, const N: usize> SphereTol<S, N> {
+    #[inline]
+    pub const fn from_parts(center: Vector<S, N>, radius: S) -> Self {
+        Self { center, radius }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SphereDiff<S, const N: usize> {
+    center: Vector<S, N>,
+    radius: S,
+}
+
+impl<S, const N: usize> SphereDiff<S, N> {
+    #[inline]
+    const fn from_parts(center: Vector<S, N>, radius: S) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = SphereTol<<S as approx_cmp::AbsDiffEq>::Tolerance, N>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.center, &other.center, &max_abs_diff.center)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.radius, &other.radius, &max_abs_diff.radius)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffAllEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.center, &other.center, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.radius, &other.radius, max_abs_diff)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = SphereDiff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff, N>;
+    type DebugTolerance = SphereTol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let center = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.center, &other.center);
+        let radius = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.radius, &other.radius);
+
+        SphereDiff::from_parts(center, radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let center = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.center, &other.center, &max_abs_diff.center);
+        let radius = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.radius, &other.radius, &max_abs_diff.radius);
+
+        SphereTol::from_parts(center, radius)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffAllEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = SphereTol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let center = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.center, &other.center, max_abs_diff);
+        let radius = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.radius, &other.radius, max_abs_diff);
+
+        SphereTol::from_parts(center, radius)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = SphereTol<<S as approx_cmp::RelativeEq>::Tolerance, N>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.center, &other.center, &max_abs_diff.center, &max_relative.center)
+            && approx_cmp::RelativeEq::relative_eq(&self.radius, &other.radius, &max_abs_diff.radius, &max_relative.radius)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeAllEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.center, &other.center, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.radius, &other.radius, max_abs_diff, max_relative)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = SphereDiff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff, N>;
+    type DebugTolerance = SphereTol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let center = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.center, &other.center);
+        let radius = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.radius, &other.radius);
+
+        SphereDiff::from_parts(center, radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let center = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.center, &other.center, &max_abs_diff.center);
+        let radius = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.radius, &other.radius, &max_abs_diff.radius);
+
+        SphereTol::from_parts(center, radius)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let center = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.center, &other.center, &max_relative.center);
+        let radius = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.radius, &other.radius, &max_relative.radius);
+
+        SphereTol::from_parts(center, radius)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeAllEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = SphereTol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let center = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.center, &other.center, max_abs_diff);
+        let radius = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.radius, &other.radius, max_abs_diff);
+
+        SphereTol::from_parts(center, radius)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let center = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.center, &other.center, max_relative);
+        let radius = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.radius, &other.radius, max_relative);
+
+        SphereTol::from_parts(center, radius)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = SphereTol<<S as approx_cmp::UlpsEq>::Tolerance, N>;
+    type UlpsTolerance = SphereTol<<S as approx_cmp::UlpsEq>::UlpsTolerance, N>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.center, &other.center, &max_abs_diff.center, &max_ulps.center)
+            && approx_cmp::UlpsEq::ulps_eq(&self.radius, &other.radius, &max_abs_diff.radius, &max_ulps.radius)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsAllEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.center, &other.center, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.radius, &other.radius, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = SphereDiff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff, N>;
+    type DebugUlpsDiff = SphereDiff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff, N>;
+    type DebugTolerance = SphereTol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance, N>;
+    type DebugUlpsTolerance = SphereTol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let center = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.center, &other.center);
+        let radius = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.radius, &other.radius);
+
+        SphereDiff::from_parts(center, radius)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let center = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.center, &other.center);
+        let radius = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.radius, &other.radius);
+
+        SphereDiff::from_parts(center, radius)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let center = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.center, &other.center, &max_abs_diff.center);
+        let radius = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.radius, &other.radius, &max_abs_diff.radius);
+
+        SphereTol::from_parts(center, radius)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let center = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.center, &other.center, &max_ulps.center);
+        let radius = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.radius, &other.radius, &max_ulps.radius);
+
+        SphereTol::from_parts(center, radius)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsAllEq for Sphere<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = SphereTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance, N>;
+    type AllDebugUlpsTolerance = SphereTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let center = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.center, &other.center, max_abs_diff);
+        let radius = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.radius, &other.radius, max_abs_diff);
+
+        SphereTol::from_parts(center, radius)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let center = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.center, &other.center, max_ulps);
+        let radius = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.radius, &other.radius, max_ulps);
+
+        SphereTol::from_parts(center, radius)
+    }
+}
+
+
+/// Choose the step between consecutive points visited by Welzl's algorithm.
+///
+/// The step is the first integer from `floor(len / phi)` upward that is coprime to
+/// `len`, where `phi` is the golden ratio. Stepping through the points by this amount modulo `len`
+/// visits every point once, and consecutive points in the visiting order are far
+/// apart in the original order.
+fn visiting_stride(len: usize) -> usize {
+    const INVERSE_GOLDEN_RATIO: u128 = 0x9e37_79b9_7f4a_7c15;
+
+    let gcd = |mut a: usize, mut b: usize| {
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+
+        a
+    };
+    let mut stride = ((len as u128 * INVERSE_GOLDEN_RATIO) >> 64) as usize;
+    stride = stride.max(1);
+    while gcd(stride, len) != 1 {
+        stride += 1;
+    }
+
+    stride
+}
+
+/// A node in the stack-allocated list of boundary points in Welzl's algorithm.
+struct SupportPoint<'a, S, const N: usize> {
+    point: Point<S, N>,
+    next: Option<&'a SupportPoint<'a, S, N>>,
+}
+
+/// Solve the leading `count x count` block of a symmetric positive semidefinite
+/// linear system by Gaussian elimination with partial pivoting.
+///
+/// Unknowns whose pivots vanish are set to zero.
+fn solve_symmetric_positive_semidefinite<S, const N: usize>(matrix: &mut [[S; N]; N], rhs: &mut [S; N], count: usize) -> [S; N]
+where
+    S: SimdScalarFloat,
+{
+    let mut scale = S::zero();
+    for (i, row) in matrix.iter().enumerate().take(count) {
+        scale = scale.max(row[i].abs());
+    }
+    let tolerance_factor: S = cglinalg_numeric::cast(64_f64);
+    let tolerance = tolerance_factor * S::machine_epsilon() * scale;
+
+    let mut pivot_rows = [usize::MAX; N];
+    let mut used = [false; N];
+    for column in 0..count {
+        let mut pivot_row = usize::MAX;
+        let mut pivot_value = tolerance;
+        for row in 0..count {
+            if !used[row] && matrix[row][column].abs() > pivot_value {
+                pivot_row = row;
+                pivot_value = matrix[row][column].abs();
+            }
+        }
+        if pivot_row == usize::MAX {
+            continue;
+        }
+
+        used[pivot_row] = true;
+        pivot_rows[column] = pivot_row;
+        let pivot = matrix[pivot_row];
+        let pivot_rhs = rhs[pivot_row];
+        for row in 0..count {
+            if row != pivot_row {
+                let factor = matrix[row][column] / pivot[column];
+                for (entry, pivot_entry) in matrix[row][column..count].iter_mut().zip(pivot[column..count].iter()) {
+                    *entry -= factor * *pivot_entry;
+                }
+                rhs[row] -= factor * pivot_rhs;
+            }
+        }
+    }
+
+    let mut solution = [S::zero(); N];
+    for column in 0..count {
+        let pivot_row = pivot_rows[column];
+        if pivot_row != usize::MAX {
+            solution[column] = rhs[pivot_row] / matrix[pivot_row][column];
+        }
+    }
+
+    solution
+}
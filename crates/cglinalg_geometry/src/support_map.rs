@@ -0,0 +1,172 @@
+use cglinalg_core::{
+    Point3,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::Isometry3;
+
+
+/// A convex shape described by its support function.
+///
+/// The **support point** of a convex shape `C` in the direction `d` is a point
+/// of `C` that is furthest in the direction `d`, i.e. a point `p` in `C` such that
+///
+/// ```text
+/// dot(p, d) == max { dot(q, d) | q in C }
+/// ```
+///
+/// Every convex shape is completely described by its support points, which makes
+/// support mappings the common language of the convex collision detection algorithms
+/// in this crate, such as [`gjk_closest_points`](crate::gjk_closest_points) and
+/// [`epa_penetration`](crate::epa_penetration).
+///
+/// Implementors describe the shape in its own **local frame**. Shapes placed in the
+/// world by an [`Isometry3`] use [`SupportMap::support_point`] to get support
+/// points in world space.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     Point3,
+/// #     Vector3,
+/// # };
+/// # use cglinalg_geometry::{
+/// #     Sphere3,
+/// #     SupportMap,
+/// # };
+/// # use cglinalg_transform::{
+/// #     Isometry3,
+/// #     Rotation3,
+/// #     Translation3,
+/// # };
+/// #
+/// let sphere = Sphere3::new(Point3::origin(), 2_f64);
+/// let direction = Vector3::new(0_f64, 3_f64, 0_f64);
+///
+/// assert_eq!(sphere.local_support_point(&direction), Point3::new(0_f64, 2_f64, 0_f64));
+///
+/// let isometry = Isometry3::from_parts(&Translation3::new(1_f64, 0_f64, 0_f64), &Rotation3::identity());
+///
+/// assert_eq!(sphere.support_point(&isometry, &direction), Point3::new(1_f64, 2_f64, 0_f64));
+/// ```
+pub trait SupportMap<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute a support point of the shape in the direction `direction`, in the
+    /// local frame of the shape.
+    ///
+    /// The direction need not be a unit vector. When the direction is the zero vector,
+    /// the function may return any point of the shape.
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S>;
+
+    /// Compute a support point of the shape placed in the world by `isometry`, in the
+    /// direction `direction`.
+    ///
+    /// Both the direction and the resulting point are expressed in the world frame.
+    #[inline]
+    fn support_point(&self, isometry: &Isometry3<S>, direction: &Vector3<S>) -> Point3<S> {
+        let local_direction = isometry.inverse_apply_vector(direction);
+        let local_support_point = self.local_support_point(&local_direction);
+
+        isometry.apply_point(&local_support_point)
+    }
+}
+
+impl<S, T> SupportMap<S> for &T
+where
+    S: SimdScalarFloat,
+    T: SupportMap<S> + ?Sized,
+{
+    #[inline]
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S> {
+        (**self).local_support_point(direction)
+    }
+}
+
+/// The convex hull of a nonempty set of points.
+///
+/// A point cloud borrows its points, and describes the convex hull of the points
+/// by its support function, so that the points need not be in convex position.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_core::{
+/// #     Point3,
+/// #     Vector3,
+/// # };
+/// # use cglinalg_geometry::{
+/// #     PointCloud3,
+/// #     SupportMap,
+/// # };
+/// #
+/// let points = [
+///     Point3::new(0_f64, 0_f64, 0_f64),
+///     Point3::new(3_f64, 1_f64, 0_f64),
+///     Point3::new(-1_f64, 4_f64, 2_f64),
+/// ];
+/// let point_cloud = PointCloud3::new(&points).unwrap();
+///
+/// assert_eq!(point_cloud.local_support_point(&Vector3::unit_x()), points[1]);
+/// assert_eq!(point_cloud.local_support_point(&Vector3::unit_y()), points[2]);
+/// assert!(PointCloud3::<f64>::new(&[]).is_none());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PointCloud3<'a, S> {
+    points: &'a [Point3<S>],
+}
+
+impl<'a, S> PointCloud3<'a, S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new point cloud from a set of points.
+    ///
+    /// The function returns `None` if the set of points is empty, since the convex
+    /// hull of an empty set of points has no support points.
+    #[inline]
+    pub fn new(points: &'a [Point3<S>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+
+        Some(Self { points })
+    }
+
+    /// Get the points of the point cloud.
+    #[inline]
+    pub const fn points(&self) -> &'a [Point3<S>] {
+        self.points
+    }
+}
+
+impl<S> SupportMap<S> for PointCloud3<'_, S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S> {
+        farthest_point(self.points, direction)
+    }
+}
+
+/// Compute a point of a nonempty set of points that is furthest in the direction
+/// `direction`.
+pub(crate) fn farthest_point<S>(points: &[Point3<S>], direction: &Vector3<S>) -> Point3<S>
+where
+    S: SimdScalarFloat,
+{
+    let mut result = points[0];
+    let mut result_dot = result.to_vector().dot(direction);
+    for point in points[1..].iter() {
+        let dot = point.to_vector().dot(direction);
+        if dot > result_dot {
+            result = *point;
+            result_dot = dot;
+        }
+    }
+
+    result
+}
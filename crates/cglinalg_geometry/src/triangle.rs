@@ -0,0 +1,845 @@
+use crate::aabb::Aabb;
+use crate::bounded::Bounded;
+use crate::hyperplane::Hyperplane3;
+use crate::ray::Ray3;
+use crate::segment::Segment;
+use crate::support_map::{
+    farthest_point,
+    SupportMap,
+};
+use cglinalg_core::{
+    Point,
+    Point2,
+    Point3,
+    Vector,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// A triangle in two dimensions.
+pub type Triangle2<S> = Triangle<S, 2>;
+
+/// A triangle in three dimensions.
+pub type Triangle3<S> = Triangle<S, 3>;
+
+
+/// A triangle.
+///
+/// A triangle is the set of points
+///
+/// ```text
+/// { u * a + v * b + w * c | u + v + w == 1, u >= 0, v >= 0, w >= 0 }
+/// ```
+///
+/// where `a`, `b`, and `c` are the vertices of the triangle, and `(u, v, w)` are the
+/// **barycentric coordinates** of a point with respect to the triangle. A triangle
+/// whose vertices are collinear is degenerate.
+///
+/// This is the most general triangle type. The vast majority of applications
+/// should use [`Triangle2`] or [`Triangle3`] instead of this type directly.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Triangle<S, const N: usize> {
+    a: Point<S, N>,
+    b: Point<S, N>,
+    c: Point<S, N>,
+}
+
+impl<S, const N: usize> Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new triangle from its vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Triangle3;
+    /// #
+    /// let a = Point3::new(0_f64, 0_f64, 0_f64);
+    /// let b = Point3::new(1_f64, 0_f64, 0_f64);
+    /// let c = Point3::new(0_f64, 1_f64, 0_f64);
+    /// let triangle = Triangle3::new(a, b, c);
+    ///
+    /// assert_eq!(triangle.vertices(), [a, b, c]);
+    /// ```
+    #[inline]
+    pub const fn new(a: Point<S, N>, b: Point<S, N>, c: Point<S, N>) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Get the first vertex of the triangle.
+    #[inline]
+    pub const fn a(&self) -> Point<S, N> {
+        self.a
+    }
+
+    /// Get the second vertex of the triangle.
+    #[inline]
+    pub const fn b(&self) -> Point<S, N> {
+        self.b
+    }
+
+    /// Get the third vertex of the triangle.
+    #[inline]
+    pub const fn c(&self) -> Point<S, N> {
+        self.c
+    }
+
+    /// Get the vertices of the triangle.
+    #[inline]
+    pub const fn vertices(&self) -> [Point<S, N>; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// Get the edges of the triangle.
+    ///
+    /// The edges are the segments `[a, b]`, `[b, c]`, and `[c, a]`, in that order.
+    #[inline]
+    pub const fn edges(&self) -> [Segment<S, N>; 3] {
+        [
+            Segment::new(self.a, self.b),
+            Segment::new(self.b, self.c),
+            Segment::new(self.c, self.a),
+        ]
+    }
+
+    /// Compute the centroid of the triangle.
+    #[inline]
+    pub fn centroid(&self) -> Point<S, N> {
+        let one_third = S::one() / cglinalg_numeric::cast(3_f64);
+
+        self.a + ((self.b - self.a) + (self.c - self.a)) * one_third
+    }
+
+    /// Compute the area of the triangle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Triangle3;
+    /// #
+    /// let triangle = Triangle3::new(
+    ///     Point3::new(1_f64, 0_f64, 0_f64),
+    ///     Point3::new(4_f64, 0_f64, 0_f64),
+    ///     Point3::new(1_f64, 0_f64, 2_f64),
+    /// );
+    ///
+    /// assert_eq!(triangle.area(), 3_f64);
+    /// ```
+    #[inline]
+    pub fn area(&self) -> S {
+        // By Lagrange's identity, the squared area of the parallelogram spanned by two
+        // vectors is `norm(e1)^2 * norm(e2)^2 - dot(e1, e2)^2` in any dimension.
+        let one_half: S = cglinalg_numeric::cast(0.5_f64);
+        let edge1 = self.b - self.a;
+        let edge2 = self.c - self.a;
+        let dot = edge1.dot(&edge2);
+        let area_squared = edge1.norm_squared() * edge2.norm_squared() - dot * dot;
+
+        one_half * area_squared.max(S::zero()).sqrt()
+    }
+
+    /// Compute the barycentric coordinates of a point with respect to the triangle.
+    ///
+    /// The function returns the coordinates `(u, v, w)` such that
+    /// `u * a + v * b + w * c` is the orthogonal projection of `point` onto the plane
+    /// of the triangle, and `u + v + w == 1`. The point lies inside the triangle
+    /// exactly when every coordinate is nonnegative.
+    ///
+    /// The function returns `None` if the triangle is degenerate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Triangle2;
+    /// #
+    /// let triangle = Triangle2::new(
+    ///     Point2::new(0_f64, 0_f64),
+    ///     Point2::new(4_f64, 0_f64),
+    ///     Point2::new(0_f64, 4_f64),
+    /// );
+    /// let point = Point2::new(1_f64, 2_f64);
+    /// let expected = Vector3::new(1_f64 / 4_f64, 1_f64 / 4_f64, 1_f64 / 2_f64);
+    /// let result = triangle.barycentric(&point).unwrap();
+    ///
+    /// assert_eq!(result, expected);
+    /// assert_eq!(triangle.from_barycentric(&result), point);
+    /// ```
+    pub fn barycentric(&self, point: &Point<S, N>) -> Option<Vector3<S>> {
+        // See Christer Ericson, Real-Time Collision Detection, Section 3.4. Writing
+        // `point - a == v * (b - a) + w * (c - a)` and taking dot products with both
+        // edges gives a 2x2 linear system that we solve using Cramer's rule.
+        let edge1 = self.b - self.a;
+        let edge2 = self.c - self.a;
+        let offset = point - self.a;
+        let d11 = edge1.dot(&edge1);
+        let d12 = edge1.dot(&edge2);
+        let d22 = edge2.dot(&edge2);
+        let d01 = offset.dot(&edge1);
+        let d02 = offset.dot(&edge2);
+        let determinant = d11 * d22 - d12 * d12;
+        if determinant.is_zero() {
+            return None;
+        }
+
+        let v = (d22 * d01 - d12 * d02) / determinant;
+        let w = (d11 * d02 - d12 * d01) / determinant;
+        let u = S::one() - v - w;
+
+        Some(Vector3::new(u, v, w))
+    }
+
+    /// Compute the point with the given barycentric coordinates with respect to
+    /// the triangle.
+    ///
+    /// The barycentric coordinates `(u, v, w)` map to the point `u * a + v * b + w * c`.
+    /// The coordinates should satisfy `u + v + w == 1`.
+    #[inline]
+    pub fn from_barycentric(&self, barycentric: &Vector3<S>) -> Point<S, N> {
+        self.a + (self.b - self.a) * barycentric[1] + (self.c - self.a) * barycentric[2]
+    }
+
+    /// Compute the point on the triangle closest to a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::Triangle3;
+    /// #
+    /// let triangle = Triangle3::new(
+    ///     Point3::new(0_f64, 0_f64, 0_f64),
+    ///     Point3::new(4_f64, 0_f64, 0_f64),
+    ///     Point3::new(0_f64, 4_f64, 0_f64),
+    /// );
+    ///
+    /// // Above the interior of the triangle.
+    /// assert_eq!(triangle.closest_point(&Point3::new(1_f64, 1_f64, 5_f64)), Point3::new(1_f64, 1_f64, 0_f64));
+    /// // Beyond a vertex.
+    /// assert_eq!(triangle.closest_point(&Point3::new(-1_f64, -2_f64, 3_f64)), Point3::new(0_f64, 0_f64, 0_f64));
+    /// // Beyond an edge.
+    /// assert_eq!(triangle.closest_point(&Point3::new(2_f64, -3_f64, 1_f64)), Point3::new(2_f64, 0_f64, 0_f64));
+    /// ```
+    pub fn closest_point(&self, point: &Point<S, N>) -> Point<S, N> {
+        // See Christer Ericson, Real-Time Collision Detection, Section 5.1.5. The
+        // function determines which Voronoi region of the triangle contains the point,
+        // testing the vertex regions, then the edge regions, and finally the face region,
+        // and then projects the point onto the corresponding feature.
+        let ab = self.b - self.a;
+        let ac = self.c - self.a;
+
+        let ap = point - self.a;
+        let d1 = ab.dot(&ap);
+        let d2 = ac.dot(&ap);
+        if d1 <= S::zero() && d2 <= S::zero() {
+            return self.a;
+        }
+
+        let bp = point - self.b;
+        let d3 = ab.dot(&bp);
+        let d4 = ac.dot(&bp);
+        if d3 >= S::zero() && d4 <= d3 {
+            return self.b;
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= S::zero() && d1 >= S::zero() && d3 <= S::zero() {
+            let v = d1 / (d1 - d3);
+            return self.a + ab * v;
+        }
+
+        let cp = point - self.c;
+        let d5 = ab.dot(&cp);
+        let d6 = ac.dot(&cp);
+        if d6 >= S::zero() && d5 <= d6 {
+            return self.c;
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= S::zero() && d2 >= S::zero() && d6 <= S::zero() {
+            let w = d2 / (d2 - d6);
+            return self.a + ac * w;
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= S::zero() && (d4 - d3) >= S::zero() && (d5 - d6) >= S::zero() {
+            let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+            return self.b + (self.c - self.b) * w;
+        }
+
+        let inverse_denominator = S::one() / (va + vb + vc);
+        let v = vb * inverse_denominator;
+        let w = vc * inverse_denominator;
+
+        self.a + ab * v + ac * w
+    }
+
+    /// Compute the squared distance from a point to the triangle.
+    #[inline]
+    pub fn distance_squared(&self, point: &Point<S, N>) -> S {
+        (point - self.closest_point(point)).norm_squared()
+    }
+
+    /// Compute the closest points between a segment and the boundary of the triangle,
+    /// or the endpoints of the segment and the triangle.
+    ///
+    /// When the segment does not pass through the interior of the triangle, the
+    /// closest pair of points involves either an endpoint of the segment, or an edge
+    /// of the triangle.
+    fn closest_points_segment_features(&self, segment: &Segment<S, N>) -> (Point<S, N>, Point<S, N>) {
+        let start = segment.start();
+        let end = segment.end();
+        let mut closest_triangle = self.closest_point(&start);
+        let mut closest_segment = start;
+        let mut closest_distance_squared = (closest_triangle - closest_segment).norm_squared();
+        let candidate = self.closest_point(&end);
+        let distance_squared = (candidate - end).norm_squared();
+        if distance_squared < closest_distance_squared {
+            closest_triangle = candidate;
+            closest_segment = end;
+            closest_distance_squared = distance_squared;
+        }
+
+        for edge in self.edges().iter() {
+            let (candidate_triangle, candidate_segment) = edge.closest_points_segment(segment);
+            let distance_squared = (candidate_triangle - candidate_segment).norm_squared();
+            if distance_squared < closest_distance_squared {
+                closest_triangle = candidate_triangle;
+                closest_segment = candidate_segment;
+                closest_distance_squared = distance_squared;
+            }
+        }
+
+        (closest_triangle, closest_segment)
+    }
+}
+
+impl<S> Triangle2<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the signed area of the triangle.
+    ///
+    /// The signed area is positive when the vertices of the triangle wind
+    /// counterclockwise, and negative when they wind clockwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_geometry::Triangle2;
+    /// #
+    /// let a = Point2::new(0_f64, 0_f64);
+    /// let b = Point2::new(2_f64, 0_f64);
+    /// let c = Point2::new(0_f64, 3_f64);
+    ///
+    /// assert_eq!(Triangle2::new(a, b, c).signed_area(), 3_f64);
+    /// assert_eq!(Triangle2::new(a, c, b).signed_area(), -3_f64);
+    /// ```
+    #[inline]
+    pub fn signed_area(&self) -> S {
+        let one_half: S = cglinalg_numeric::cast(0.5_f64);
+        let edge1 = self.b - self.a;
+        let edge2 = self.c - self.a;
+
+        one_half * (edge1[0] * edge2[1] - edge1[1] * edge2[0])
+    }
+
+    /// Determine whether a triangle contains a point.
+    ///
+    /// Points on the boundary of the triangle are contained in the triangle. The
+    /// function works for either winding order of the vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_geometry::Triangle2;
+    /// #
+    /// let triangle = Triangle2::new(
+    ///     Point2::new(0_f64, 0_f64),
+    ///     Point2::new(4_f64, 0_f64),
+    ///     Point2::new(0_f64, 4_f64),
+    /// );
+    ///
+    /// assert!(triangle.contains(&Point2::new(1_f64, 1_f64)));
+    /// assert!(triangle.contains(&Point2::new(2_f64, 2_f64)));
+    /// assert!(!triangle.contains(&Point2::new(3_f64, 3_f64)));
+    /// ```
+    pub fn contains(&self, point: &Point2<S>) -> bool {
+        let orientation = |p: &Point2<S>, q: &Point2<S>| -> S {
+            let edge = q - p;
+            let offset = point - p;
+
+            edge[0] * offset[1] - edge[1] * offset[0]
+        };
+        let side_ab = orientation(&self.a, &self.b);
+        let side_bc = orientation(&self.b, &self.c);
+        let side_ca = orientation(&self.c, &self.a);
+        let has_negative = side_ab < S::zero() || side_bc < S::zero() || side_ca < S::zero();
+        let has_positive = side_ab > S::zero() || side_bc > S::zero() || side_ca > S::zero();
+
+        !(has_negative && has_positive)
+    }
+
+    /// Compute a pair of closest points between the triangle and a segment.
+    ///
+    /// The function returns the point on the triangle and the point on the segment
+    /// that are closest to each other. When the segment intersects the triangle, both
+    /// points are the same point of intersection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_geometry::{
+    /// #     Segment2,
+    /// #     Triangle2,
+    /// # };
+    /// #
+    /// let triangle = Triangle2::new(
+    ///     Point2::new(0_f64, 0_f64),
+    ///     Point2::new(4_f64, 0_f64),
+    ///     Point2::new(0_f64, 4_f64),
+    /// );
+    /// let segment = Segment2::new(Point2::new(6_f64, -1_f64), Point2::new(6_f64, 1_f64));
+    /// let (point_triangle, point_segment) = triangle.closest_points_segment(&segment);
+    ///
+    /// assert_eq!(point_triangle, Point2::new(4_f64, 0_f64));
+    /// assert_eq!(point_segment, Point2::new(6_f64, 0_f64));
+    /// ```
+    #[inline]
+    pub fn closest_points_segment(&self, segment: &Segment<S, 2>) -> (Point2<S>, Point2<S>) {
+        // A segment that intersects a triangle in the plane either has an endpoint
+        // inside the triangle, or crosses one of its edges, so the closest features
+        // always include an endpoint of the segment or an edge of the triangle.
+        self.closest_points_segment_features(segment)
+    }
+}
+
+impl<S> Triangle3<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Compute the unit normal vector of the triangle.
+    ///
+    /// The normal points towards the side of the triangle from which the vertices
+    /// appear to wind counterclockwise. The function returns `None` if the triangle
+    /// is degenerate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_geometry::Triangle3;
+    /// #
+    /// let triangle = Triangle3::new(
+    ///     Point3::new(0_f64, 0_f64, 1_f64),
+    ///     Point3::new(2_f64, 0_f64, 1_f64),
+    ///     Point3::new(0_f64, 2_f64, 1_f64),
+    /// );
+    ///
+    /// assert_eq!(triangle.normal(), Some(Vector3::unit_z()));
+    /// ```
+    #[inline]
+    pub fn normal(&self) -> Option<Vector3<S>> {
+        let normal = (self.b - self.a).cross(&(self.c - self.a));
+        let norm = normal.norm();
+        if norm.is_zero() {
+            return None;
+        }
+
+        Some(normal / norm)
+    }
+
+    /// Construct the plane containing the triangle.
+    ///
+    /// The normal of the plane is the normal of the triangle. The function returns
+    /// `None` if the triangle is degenerate.
+    #[inline]
+    pub fn to_hyperplane(&self) -> Option<Hyperplane3<S>> {
+        Hyperplane3::from_points(&self.a, &self.b, &self.c)
+    }
+
+    /// Compute a pair of closest points between the triangle and a segment.
+    ///
+    /// The function returns the point on the triangle and the point on the segment
+    /// that are closest to each other. When the segment intersects the triangle, both
+    /// points are the same point of intersection.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_geometry::{
+    /// #     Segment3,
+    /// #     Triangle3,
+    /// # };
+    /// #
+    /// let triangle = Triangle3::new(
+    ///     Point3::new(0_f64, 0_f64, 0_f64),
+    ///     Point3::new(4_f64, 0_f64, 0_f64),
+    ///     Point3::new(0_f64, 4_f64, 0_f64),
+    /// );
+    /// let piercing = Segment3::new(Point3::new(1_f64, 1_f64, -1_f64), Point3::new(1_f64, 1_f64, 3_f64));
+    /// let (point_triangle, point_segment) = triangle.closest_points_segment(&piercing);
+    ///
+    /// assert_relative_eq!(point_triangle, Point3::new(1_f64, 1_f64, 0_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_eq!(point_triangle, point_segment);
+    ///
+    /// let above = Segment3::new(Point3::new(1_f64, 1_f64, 2_f64), Point3::new(6_f64, 1_f64, 2_f64));
+    /// let (point_triangle, point_segment) = triangle.closest_points_segment(&above);
+    ///
+    /// assert_eq!(point_triangle, Point3::new(1_f64, 1_f64, 0_f64));
+    /// assert_eq!(point_segment, Point3::new(1_f64, 1_f64, 2_f64));
+    /// ```
+    pub fn closest_points_segment(&self, segment: &Segment<S, 3>) -> (Point3<S>, Point3<S>) {
+        // See Christer Ericson, Real-Time Collision Detection, Section 5.1.10. Unless
+        // the segment passes through the triangle, the closest pair of points involves
+        // either an endpoint of the segment, or an edge of the triangle.
+        let ray = Ray3::new(segment.start(), segment.direction());
+        if let Some(hit) = ray.intersect_triangle(&self.a, &self.b, &self.c) {
+            if hit.distance() <= S::one() {
+                let point = ray.at(hit.distance());
+                return (point, point);
+            }
+        }
+
+        self.closest_points_segment_features(segment)
+    }
+}
+
+impl<S, const N: usize> fmt::Display for Triangle<S, N>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Triangle{} [a={}, b={}, c={}]", N, self.a, self.b, self.c)
+    }
+}
+
+
+impl<S> SupportMap<S> for Triangle<S, 3>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn local_support_point(&self, direction: &Vector3<S>) -> Point3<S> {
+        farthest_point(&self.vertices(), direction)
+    }
+}
+
+impl<S> Bounded<S> for Triangle<S, 3>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn aabb(&self) -> Aabb<S, 3> {
+        Aabb::from_corners(&self.a, &self.b).union_point(&self.c)
+    }
+}
+
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TriangleTol<S, const N: usize> {
+    a: Vector<S, N>,
+    b: Vector<S, N>,
+    c: Vector<S, N>,
+}
+
+impl<S, const N: usize> TriangleTol<S, N> {
+    #[inline]
+    pub const fn from_parts(a: Vector<S, N>, b: Vector<S, N>, c: Vector<S, N>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TriangleDiff<S, const N: usize> {
+    a: Vector<S, N>,
+    b: Vector<S, N>,
+    c: Vector<S, N>,
+}
+
+impl<S, const N: usize> TriangleDiff<S, N> {
+    #[inline]
+    const fn from_parts(a: Vector<S, N>, b: Vector<S, N>, c: Vector<S, N>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = TriangleTol<<S as approx_cmp::AbsDiffEq>::Tolerance, N>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.a, &other.a, &max_abs_diff.a)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.b, &other.b, &max_abs_diff.b)
+            && approx_cmp::AbsDiffEq::abs_diff_eq(&self.c, &other.c, &max_abs_diff.c)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AbsDiffAllEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.a, &other.a, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.b, &other.b, max_abs_diff)
+            && approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.c, &other.c, max_abs_diff)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = TriangleDiff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff, N>;
+    type DebugTolerance = TriangleTol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let a = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.a, &other.a);
+        let b = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.b, &other.b);
+        let c = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.c, &other.c);
+
+        TriangleDiff::from_parts(a, b, c)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let a = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.a, &other.a, &max_abs_diff.a);
+        let b = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.b, &other.b, &max_abs_diff.b);
+        let c = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.c, &other.c, &max_abs_diff.c);
+
+        TriangleTol::from_parts(a, b, c)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertAbsDiffAllEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = TriangleTol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let a = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.a, &other.a, max_abs_diff);
+        let b = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.b, &other.b, max_abs_diff);
+        let c = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.c, &other.c, max_abs_diff);
+
+        TriangleTol::from_parts(a, b, c)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = TriangleTol<<S as approx_cmp::RelativeEq>::Tolerance, N>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.a, &other.a, &max_abs_diff.a, &max_relative.a)
+            && approx_cmp::RelativeEq::relative_eq(&self.b, &other.b, &max_abs_diff.b, &max_relative.b)
+            && approx_cmp::RelativeEq::relative_eq(&self.c, &other.c, &max_abs_diff.c, &max_relative.c)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::RelativeAllEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.a, &other.a, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.b, &other.b, max_abs_diff, max_relative)
+            && approx_cmp::RelativeAllEq::relative_all_eq(&self.c, &other.c, max_abs_diff, max_relative)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = TriangleDiff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff, N>;
+    type DebugTolerance = TriangleTol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let a = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.a, &other.a);
+        let b = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.b, &other.b);
+        let c = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.c, &other.c);
+
+        TriangleDiff::from_parts(a, b, c)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let a = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.a, &other.a, &max_abs_diff.a);
+        let b = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.b, &other.b, &max_abs_diff.b);
+        let c = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.c, &other.c, &max_abs_diff.c);
+
+        TriangleTol::from_parts(a, b, c)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let a = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.a, &other.a, &max_relative.a);
+        let b = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.b, &other.b, &max_relative.b);
+        let c = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.c, &other.c, &max_relative.c);
+
+        TriangleTol::from_parts(a, b, c)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertRelativeAllEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = TriangleTol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let a = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.a, &other.a, max_abs_diff);
+        let b = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.b, &other.b, max_abs_diff);
+        let c = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.c, &other.c, max_abs_diff);
+
+        TriangleTol::from_parts(a, b, c)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let a = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.a, &other.a, max_relative);
+        let b = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.b, &other.b, max_relative);
+        let c = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.c, &other.c, max_relative);
+
+        TriangleTol::from_parts(a, b, c)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = TriangleTol<<S as approx_cmp::UlpsEq>::Tolerance, N>;
+    type UlpsTolerance = TriangleTol<<S as approx_cmp::UlpsEq>::UlpsTolerance, N>;
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.a, &other.a, &max_abs_diff.a, &max_ulps.a)
+            && approx_cmp::UlpsEq::ulps_eq(&self.b, &other.b, &max_abs_diff.b, &max_ulps.b)
+            && approx_cmp::UlpsEq::ulps_eq(&self.c, &other.c, &max_abs_diff.c, &max_ulps.c)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::UlpsAllEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.a, &other.a, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.b, &other.b, max_abs_diff, max_ulps)
+            && approx_cmp::UlpsAllEq::ulps_all_eq(&self.c, &other.c, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = TriangleDiff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff, N>;
+    type DebugUlpsDiff = TriangleDiff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff, N>;
+    type DebugTolerance = TriangleTol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance, N>;
+    type DebugUlpsTolerance = TriangleTol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let a = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.a, &other.a);
+        let b = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.b, &other.b);
+        let c = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.c, &other.c);
+
+        TriangleDiff::from_parts(a, b, c)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let a = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.a, &other.a);
+        let b = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.b, &other.b);
+        let c = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.c, &other.c);
+
+        TriangleDiff::from_parts(a, b, c)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let a = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.a, &other.a, &max_abs_diff.a);
+        let b = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.b, &other.b, &max_abs_diff.b);
+        let c = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.c, &other.c, &max_abs_diff.c);
+
+        TriangleTol::from_parts(a, b, c)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let a = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.a, &other.a, &max_ulps.a);
+        let b = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.b, &other.b, &max_ulps.b);
+        let c = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.c, &other.c, &max_ulps.c);
+
+        TriangleTol::from_parts(a, b, c)
+    }
+}
+
+impl<S, const N: usize> approx_cmp::AssertUlpsAllEq for Triangle<S, N>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = TriangleTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance, N>;
+    type AllDebugUlpsTolerance = TriangleTol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance, N>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let a = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.a, &other.a, max_abs_diff);
+        let b = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.b, &other.b, max_abs_diff);
+        let c = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.c, &other.c, max_abs_diff);
+
+        TriangleTol::from_parts(a, b, c)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let a = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.a, &other.a, max_ulps);
+        let b = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.b, &other.b, max_ulps);
+        let c = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.c, &other.c, max_ulps);
+
+        TriangleTol::from_parts(a, b, c)
+    }
+}
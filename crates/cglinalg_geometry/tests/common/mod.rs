@@ -0,0 +1,223 @@
+// Each test crate uses a different subset of the shared helpers.
+#![allow(dead_code, unused_imports, unused_macros)]
+
+use cglinalg_core::Point3;
+use cglinalg_geometry::Aabb3;
+use cglinalg_transform::{
+    Isometry3,
+    Rotation3,
+    Translation3,
+};
+use rand::Rng;
+use rand_isaac::IsaacRng;
+
+
+pub fn random_point(rng: &mut IsaacRng, extent: f64) -> Point3<f64> {
+    Point3::new(
+        rng.gen_range(-extent..extent),
+        rng.gen_range(-extent..extent),
+        rng.gen_range(-extent..extent),
+    )
+}
+
+pub fn translation(x: f64, y: f64, z: f64) -> Isometry3<f64> {
+    Isometry3::from_parts(&Translation3::new(x, y, z), &Rotation3::identity())
+}
+
+pub fn unit_cube() -> Aabb3<f64> {
+    Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(1_f64, 1_f64, 1_f64))
+}
+
+/// Generate the property tests shared by the solid shapes.
+///
+/// The macro takes the type of the shape, a function in the enclosing module
+/// constructing a shape whose bounding box lies well inside the cube of half-width
+/// three about the origin, and the method of `Ray3` casting rays against the shape.
+/// The shape type must provide `contains`, `closest_point`, and `transform_isometry`,
+/// and implement `Bounded` and `SupportMap`.
+macro_rules! solid_props {
+    ($ShapeType:ident, $shape:ident, $intersect:ident) => {
+        mod solid_props {
+            use super::{
+                $shape as shape,
+                $ShapeType,
+            };
+            use crate::common::random_point;
+            use approx_cmp::assert_relative_eq;
+            use cglinalg_core::{
+                Normed,
+                Point3,
+                Unit,
+                Vector3,
+            };
+            use cglinalg_geometry::{
+                Bounded,
+                Ray3,
+                SupportMap,
+            };
+            use cglinalg_transform::{
+                Isometry3,
+                Rotation3,
+                Translation3,
+            };
+            use cglinalg_trigonometry::Degrees;
+            use rand::{
+                Rng,
+                SeedableRng,
+            };
+            use rand_isaac::IsaacRng;
+
+
+            /// Sample points of the shape by rejection sampling from its bounding box.
+            fn interior_points(shape: &$ShapeType<f64>, rng: &mut IsaacRng, count: usize) -> Vec<Point3<f64>> {
+                let aabb = shape.aabb();
+                let mut result = Vec::with_capacity(count);
+                while result.len() < count {
+                    let point = Point3::new(
+                        rng.gen_range(aabb.min()[0]..aabb.max()[0]),
+                        rng.gen_range(aabb.min()[1]..aabb.max()[1]),
+                        rng.gen_range(aabb.min()[2]..aabb.max()[2]),
+                    );
+                    if shape.contains(&point) {
+                        result.push(point);
+                    }
+                }
+
+                result
+            }
+
+            #[test]
+            fn test_closest_point_is_closest() {
+                let shape = shape();
+                let mut rng = IsaacRng::seed_from_u64(0);
+                let samples = interior_points(&shape, &mut rng, 500);
+                for _ in 0..100 {
+                    let point = random_point(&mut rng, 6_f64);
+                    let closest = shape.closest_point(&point);
+                    let distance = (point - closest).norm();
+                    if shape.contains(&point) {
+                        assert_eq!(closest, point);
+                    }
+                    for sample in samples.iter() {
+                        assert!((point - sample).norm() >= distance - 1e-10);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_closest_point_of_outside_point_is_on_boundary() {
+                let shape = shape();
+                let mut rng = IsaacRng::seed_from_u64(1);
+                for _ in 0..100 {
+                    let point = random_point(&mut rng, 6_f64);
+                    if shape.contains(&point) {
+                        continue;
+                    }
+
+                    let closest = shape.closest_point(&point);
+                    let direction = (point - closest).normalize();
+
+                    assert_relative_eq!(shape.closest_point(&closest), closest, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+                    assert!(!shape.contains(&(closest + direction * 1e-6)));
+                }
+            }
+
+            #[test]
+            fn test_aabb_is_tight() {
+                let shape = shape();
+                let aabb = shape.aabb();
+                let mut rng = IsaacRng::seed_from_u64(2);
+                for point in interior_points(&shape, &mut rng, 500).iter() {
+                    assert!(aabb.contains(point));
+                }
+                for i in 0..3 {
+                    let mut axis = Vector3::zero();
+                    axis[i] = 1_f64;
+                    let max = shape.local_support_point(&axis);
+                    let min = shape.local_support_point(&(-axis));
+
+                    assert_relative_eq!(max[i], aabb.max()[i], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+                    assert_relative_eq!(min[i], aabb.min()[i], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+                }
+            }
+
+            #[test]
+            fn test_transform_isometry() {
+                let shape = shape();
+                let isometry = Isometry3::from_parts(
+                    &Translation3::new(1_f64, -2_f64, 3_f64),
+                    &Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64)), Degrees(37_f64)),
+                );
+                let transformed = shape.transform_isometry(&isometry);
+                let mut rng = IsaacRng::seed_from_u64(3);
+                for _ in 0..200 {
+                    let point = random_point(&mut rng, 6_f64);
+                    let transformed_point = isometry.apply_point(&point);
+
+                    assert_eq!(shape.contains(&point), transformed.contains(&transformed_point));
+                    assert_relative_eq!(
+                        isometry.apply_point(&shape.closest_point(&point)),
+                        transformed.closest_point(&transformed_point),
+                        abs_diff_all <= 1e-10,
+                        relative_all <= f64::EPSILON,
+                    );
+                }
+            }
+
+            #[test]
+            fn test_ray_hits_are_on_boundary() {
+                let shape = shape();
+                let mut rng = IsaacRng::seed_from_u64(4);
+                let mut hit_count = 0;
+                for _ in 0..300 {
+                    let ray = Ray3::from_points(&random_point(&mut rng, 8_f64), &random_point(&mut rng, 3_f64));
+                    let Some(hit) = ray.$intersect(&shape) else {
+                        continue;
+                    };
+                    let point = ray.at(hit.distance());
+                    let normal = hit.normal();
+                    hit_count += 1;
+
+                    assert!(hit.distance() >= 0_f64);
+                    assert_relative_eq!(normal.norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+                    assert!(shape.contains(&(point - normal * 1e-6)));
+                    assert!(!shape.contains(&(point + normal * 1e-6)));
+                }
+
+                assert!(hit_count > 50);
+            }
+
+            #[test]
+            fn test_ray_hits_are_nearest() {
+                let shape = shape();
+                let mut rng = IsaacRng::seed_from_u64(5);
+                for _ in 0..300 {
+                    let ray = Ray3::from_points(&random_point(&mut rng, 8_f64), &random_point(&mut rng, 3_f64));
+                    let origin_inside = shape.contains(&ray.origin());
+                    let hit = ray.$intersect(&shape);
+                    let end = hit.map_or(20_f64, |hit| hit.distance() - 1e-6);
+                    for i in 0..200 {
+                        let t = end * (i as f64) / 200_f64;
+
+                        assert_eq!(shape.contains(&ray.at(t)), origin_inside);
+                    }
+                }
+            }
+
+            #[test]
+            fn test_ray_from_inside_exits() {
+                let shape = shape();
+                let mut rng = IsaacRng::seed_from_u64(6);
+                for origin in interior_points(&shape, &mut rng, 100).iter() {
+                    let direction = random_point(&mut rng, 1_f64).to_vector();
+                    let ray = Ray3::new(*origin, direction);
+                    let hit = ray.$intersect(&shape).unwrap();
+
+                    assert!(!shape.contains(&ray.at(hit.distance() + 1e-6)));
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use solid_props;
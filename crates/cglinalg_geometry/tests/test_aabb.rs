@@ -0,0 +1,331 @@
+#[cfg(test)]
+mod aabb2_tests {
+    use cglinalg_core::{
+        Point2,
+        Vector2,
+    };
+    use cglinalg_geometry::Aabb2;
+
+
+    #[test]
+    fn test_aabb_from_points() {
+        let points = [
+            Point2::new(1_f64, 5_f64),
+            Point2::new(-2_f64, 3_f64),
+            Point2::new(4_f64, -1_f64),
+            Point2::new(0_f64, 0_f64),
+        ];
+        let expected = Aabb2::new(Point2::new(-2_f64, -1_f64), Point2::new(4_f64, 5_f64));
+        let result = Aabb2::from_points(&points).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_aabb_from_points_single_point() {
+        let point = Point2::new(1_f64, 5_f64);
+        let expected = Aabb2::new(point, point);
+        let result = Aabb2::from_points(&[point]).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_aabb_from_points_empty() {
+        let result = Aabb2::<f64>::from_points(&[]);
+
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_aabb_from_points_contains_points() {
+        let points = [
+            Point2::new(1_f64, 5_f64),
+            Point2::new(-2_f64, 3_f64),
+            Point2::new(4_f64, -1_f64),
+        ];
+        let aabb = Aabb2::from_points(&points).unwrap();
+        for point in points.iter() {
+            assert!(aabb.contains(point));
+        }
+    }
+
+    #[test]
+    fn test_aabb_from_center_half_extents() {
+        let center = Point2::new(1_f64, 2_f64);
+        let half_extents = Vector2::new(3_f64, 4_f64);
+        let aabb = Aabb2::from_center_half_extents(&center, &half_extents);
+
+        assert_eq!(aabb.center(), center);
+        assert_eq!(aabb.half_extents(), half_extents);
+        assert_eq!(aabb.extents(), half_extents * 2_f64);
+    }
+
+    #[test]
+    fn test_aabb_surface_area_is_perimeter() {
+        let aabb = Aabb2::new(Point2::new(-1_f64, 2_f64), Point2::new(3_f64, 5_f64));
+
+        assert_eq!(aabb.surface_area(), 14_f64);
+    }
+
+    #[test]
+    fn test_aabb_volume_is_area() {
+        let aabb = Aabb2::new(Point2::new(-1_f64, 2_f64), Point2::new(3_f64, 5_f64));
+
+        assert_eq!(aabb.volume(), 12_f64);
+    }
+}
+
+#[cfg(test)]
+mod aabb3_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix4x4,
+        Point3,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_geometry::Aabb3;
+    use cglinalg_transform::{
+        Isometry3,
+        Perspective3,
+        Rotation3,
+        Similarity3,
+        Transform3,
+        Translation3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn corners(aabb: &Aabb3<f64>) -> [Point3<f64>; 8] {
+        let min = aabb.min();
+        let max = aabb.max();
+
+        [
+            Point3::new(min.x, min.y, min.z),
+            Point3::new(max.x, min.y, min.z),
+            Point3::new(min.x, max.y, min.z),
+            Point3::new(max.x, max.y, min.z),
+            Point3::new(min.x, min.y, max.z),
+            Point3::new(max.x, min.y, max.z),
+            Point3::new(min.x, max.y, max.z),
+            Point3::new(max.x, max.y, max.z),
+        ]
+    }
+
+    #[test]
+    fn test_aabb_from_corners() {
+        let a = Point3::new(1_f64, -2_f64, 3_f64);
+        let b = Point3::new(-4_f64, 5_f64, -6_f64);
+        let expected = Aabb3::new(Point3::new(-4_f64, -2_f64, -6_f64), Point3::new(1_f64, 5_f64, 3_f64));
+        let result = Aabb3::from_corners(&a, &b);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_aabb_center_extents() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, 0_f64, 2_f64), Point3::new(3_f64, 2_f64, 8_f64));
+
+        assert_eq!(aabb.center(), Point3::new(1_f64, 1_f64, 5_f64));
+        assert_eq!(aabb.extents(), Vector3::new(4_f64, 2_f64, 6_f64));
+        assert_eq!(aabb.half_extents(), Vector3::new(2_f64, 1_f64, 3_f64));
+    }
+
+    #[test]
+    fn test_aabb_surface_area() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, 0_f64, 2_f64), Point3::new(3_f64, 2_f64, 8_f64));
+        let expected = 2_f64 * (4_f64 * 2_f64 + 2_f64 * 6_f64 + 4_f64 * 6_f64);
+        let result = aabb.surface_area();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_aabb_volume() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, 0_f64, 2_f64), Point3::new(3_f64, 2_f64, 8_f64));
+
+        assert_eq!(aabb.volume(), 48_f64);
+    }
+
+    #[test]
+    fn test_aabb_degenerate_volume() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, 0_f64, 2_f64), Point3::new(3_f64, 0_f64, 8_f64));
+
+        assert_eq!(aabb.volume(), 0_f64);
+        assert_eq!(aabb.surface_area(), 48_f64);
+    }
+
+    #[test]
+    fn test_aabb_union() {
+        let aabb1 = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 1_f64));
+        let aabb2 = Aabb3::new(Point3::new(-1_f64, 2_f64, 1_f64 / 2_f64), Point3::new(0_f64, 3_f64, 3_f64 / 4_f64));
+        let expected = Aabb3::new(Point3::new(-1_f64, 0_f64, 0_f64), Point3::new(1_f64, 3_f64, 1_f64));
+        let result = aabb1.union(&aabb2);
+
+        assert_eq!(result, expected);
+        assert!(result.contains_aabb(&aabb1));
+        assert!(result.contains_aabb(&aabb2));
+    }
+
+    #[test]
+    fn test_aabb_union_commutative() {
+        let aabb1 = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 1_f64));
+        let aabb2 = Aabb3::new(Point3::new(-1_f64, 2_f64, 1_f64 / 2_f64), Point3::new(0_f64, 3_f64, 3_f64 / 4_f64));
+
+        assert_eq!(aabb1.union(&aabb2), aabb2.union(&aabb1));
+    }
+
+    #[test]
+    fn test_aabb_intersection() {
+        let aabb1 = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(2_f64, 2_f64, 2_f64));
+        let aabb2 = Aabb3::new(Point3::new(1_f64, 1_f64, -1_f64), Point3::new(3_f64, 3_f64, 1_f64));
+        let expected = Some(Aabb3::new(Point3::new(1_f64, 1_f64, 0_f64), Point3::new(2_f64, 2_f64, 1_f64)));
+        let result = aabb1.intersection(&aabb2);
+
+        assert_eq!(result, expected);
+        assert!(aabb1.intersects(&aabb2));
+    }
+
+    #[test]
+    fn test_aabb_intersection_touching() {
+        let aabb1 = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 1_f64));
+        let aabb2 = Aabb3::new(Point3::new(1_f64, 0_f64, 0_f64), Point3::new(2_f64, 1_f64, 1_f64));
+        let expected = Some(Aabb3::new(Point3::new(1_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 1_f64)));
+        let result = aabb1.intersection(&aabb2);
+
+        assert_eq!(result, expected);
+        assert!(aabb1.intersects(&aabb2));
+    }
+
+    #[test]
+    fn test_aabb_intersection_disjoint() {
+        let aabb1 = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 1_f64));
+        let aabb2 = Aabb3::new(Point3::new(0_f64, 0_f64, 2_f64), Point3::new(1_f64, 1_f64, 3_f64));
+
+        assert_eq!(aabb1.intersection(&aabb2), None);
+        assert!(!aabb1.intersects(&aabb2));
+    }
+
+    #[test]
+    fn test_aabb_contains() {
+        let aabb = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 2_f64, 3_f64));
+
+        assert!(aabb.contains(&Point3::new(1_f64 / 2_f64, 1_f64, 2_f64)));
+        assert!(aabb.contains(&aabb.min()));
+        assert!(aabb.contains(&aabb.max()));
+        assert!(!aabb.contains(&Point3::new(1_f64 / 2_f64, 1_f64, 4_f64)));
+        assert!(!aabb.contains(&Point3::new(-1_f64, 1_f64, 2_f64)));
+    }
+
+    #[test]
+    fn test_aabb_closest_point_inside() {
+        let aabb = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 2_f64, 3_f64));
+        let point = Point3::new(1_f64 / 2_f64, 1_f64, 2_f64);
+
+        assert_eq!(aabb.closest_point(&point), point);
+        assert_eq!(aabb.distance_squared(&point), 0_f64);
+    }
+
+    #[test]
+    fn test_aabb_closest_point_outside() {
+        let aabb = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 2_f64, 3_f64));
+        let point = Point3::new(-2_f64, 1_f64, 5_f64);
+        let expected = Point3::new(0_f64, 1_f64, 3_f64);
+        let result = aabb.closest_point(&point);
+
+        assert_eq!(result, expected);
+        assert_eq!(aabb.distance_squared(&point), 8_f64);
+    }
+
+    #[test]
+    fn test_aabb_transform_isometry_matches_corners() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, 0_f64, 2_f64), Point3::new(3_f64, 2_f64, 8_f64));
+        let isometry = Isometry3::from_parts(
+            &Translation3::new(1_f64, -2_f64, 3_f64),
+            &Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64)), Degrees(37_f64)),
+        );
+        let transformed_corners = corners(&aabb).map(|corner| isometry.apply_point(&corner));
+        let expected = Aabb3::from_points(&transformed_corners).unwrap();
+        let result = aabb.transform_isometry(&isometry);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_aabb_transform_similarity_matches_corners() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, 0_f64, 2_f64), Point3::new(3_f64, 2_f64, 8_f64));
+        let similarity = Similarity3::from_parts(
+            &Translation3::new(1_f64, -2_f64, 3_f64),
+            &Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64)), Degrees(37_f64)),
+            5_f64,
+        );
+        let transformed_corners = corners(&aabb).map(|corner| similarity.apply_point(&corner));
+        let expected = Aabb3::from_points(&transformed_corners).unwrap();
+        let result = aabb.transform_similarity(&similarity);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_aabb_transform_matches_corners() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, 0_f64, 2_f64), Point3::new(3_f64, 2_f64, 8_f64));
+        let transform = Transform3::from_matrix_unchecked(Matrix4x4::new(
+             1_f64, 2_f64, -1_f64, 0_f64,
+            -3_f64, 1_f64,  4_f64, 0_f64,
+             2_f64, 0_f64,  1_f64, 0_f64,
+             5_f64, 6_f64,  7_f64, 1_f64,
+        ));
+        let transformed_corners = corners(&aabb).map(|corner| transform.apply_point(&corner));
+        let expected = Aabb3::from_points(&transformed_corners).unwrap();
+        let result = aabb.transform(&transform).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_aabb_transform_projective_matches_corners() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, 0_f64, -2_f64), Point3::new(3_f64, 2_f64, 1_f64));
+        let perspective = Perspective3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 100_f64);
+        let view = Isometry3::look_at_rh(
+            &Point3::new(0_f64, 0_f64, 10_f64),
+            &Point3::origin(),
+            &Vector3::unit_y(),
+        );
+        let view_projection = perspective * view;
+        let projected_corners = corners(&aabb).map(|corner| view_projection.project_point(&corner).unwrap());
+        let expected = Aabb3::from_points(&projected_corners).unwrap();
+        let result = aabb.transform(&view_projection).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_aabb_transform_projective_crossing_eye_plane() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, -1_f64, 5_f64), Point3::new(1_f64, 1_f64, 15_f64));
+        let perspective = Perspective3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 100_f64);
+        let view = Isometry3::look_at_rh(
+            &Point3::new(0_f64, 0_f64, 10_f64),
+            &Point3::origin(),
+            &Vector3::unit_y(),
+        );
+        let view_projection = perspective * view;
+
+        assert!(aabb.transform(&view_projection).is_none());
+    }
+
+    #[test]
+    fn test_aabb_transform_affine_matrix_matches_transform() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, 0_f64, 2_f64), Point3::new(3_f64, 2_f64, 8_f64));
+        let isometry = Isometry3::from_parts(
+            &Translation3::new(1_f64, -2_f64, 3_f64),
+            &Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64)), Degrees(37_f64)),
+        );
+        let matrix = isometry.to_affine_matrix();
+        let expected = aabb.transform_isometry(&isometry);
+        let result = aabb.transform_affine_matrix(&matrix);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
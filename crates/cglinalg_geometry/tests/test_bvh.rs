@@ -0,0 +1,261 @@
+mod common;
+
+
+#[cfg(test)]
+mod bvh_tests {
+    use crate::common::random_point;
+    use cglinalg_core::{
+        Point3,
+        Vector3,
+    };
+    use cglinalg_geometry::{
+        Aabb3,
+        Bounded,
+        Bvh,
+        Ray3,
+        RayHit,
+        Sphere3,
+        Triangle3,
+    };
+    use rand::{
+        Rng,
+        SeedableRng,
+    };
+    use rand_isaac::IsaacRng;
+
+
+    fn random_spheres(count: usize, seed: u64) -> Vec<Sphere3<f64>> {
+        let mut rng = IsaacRng::seed_from_u64(seed);
+
+        (0..count)
+            .map(|_| Sphere3::new(random_point(&mut rng, 50_f64), rng.gen_range(0.5_f64..3_f64)))
+            .collect()
+    }
+
+    fn random_triangles(count: usize, seed: u64) -> Vec<Triangle3<f64>> {
+        let mut rng = IsaacRng::seed_from_u64(seed);
+
+        (0..count)
+            .map(|_| {
+                let center = random_point(&mut rng, 50_f64);
+                let a = center + random_point(&mut rng, 3_f64).to_vector();
+                let b = center + random_point(&mut rng, 3_f64).to_vector();
+                let c = center + random_point(&mut rng, 3_f64).to_vector();
+
+                Triangle3::new(a, b, c)
+            })
+            .collect()
+    }
+
+    fn random_rays(count: usize, seed: u64) -> Vec<Ray3<f64>> {
+        let mut rng = IsaacRng::seed_from_u64(seed);
+
+        (0..count)
+            .map(|_| {
+                let origin = random_point(&mut rng, 80_f64);
+                let target = random_point(&mut rng, 40_f64);
+
+                Ray3::from_points(&origin, &target)
+            })
+            .collect()
+    }
+
+    fn intersect_triangle(ray: &Ray3<f64>, triangle: &Triangle3<f64>) -> Option<RayHit<f64, 3>> {
+        ray.intersect_triangle(&triangle.a(), &triangle.b(), &triangle.c())
+            .map(|hit| hit.to_ray_hit())
+    }
+
+    fn brute_force_all<T, F>(objects: &[T], intersect: F) -> Vec<(usize, RayHit<f64, 3>)>
+    where
+        F: Fn(&T) -> Option<RayHit<f64, 3>>,
+    {
+        let mut result: Vec<(usize, RayHit<f64, 3>)> = objects
+            .iter()
+            .enumerate()
+            .filter_map(|(i, object)| intersect(object).map(|hit| (i, hit)))
+            .collect();
+        result.sort_by(|(_, hit1), (_, hit2)| hit1.distance().partial_cmp(&hit2.distance()).unwrap());
+
+        result
+    }
+
+    fn brute_force_query<T>(objects: &[T], aabb: &Aabb3<f64>) -> Vec<usize>
+    where
+        T: Bounded<f64>,
+    {
+        objects
+            .iter()
+            .enumerate()
+            .filter(|(_, object)| object.aabb().intersects(aabb))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    #[test]
+    fn test_bvh_empty() {
+        let spheres: Vec<Sphere3<f64>> = Vec::new();
+        let bvh = Bvh::build(&spheres);
+        let ray = Ray3::new(Point3::origin(), Vector3::unit_x());
+        let aabb = Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(1_f64, 1_f64, 1_f64));
+
+        assert!(bvh.is_empty());
+        assert_eq!(bvh.len(), 0);
+        assert_eq!(bvh.aabb(), None);
+        assert_eq!(bvh.intersect_ray_nearest(&ray, |_| unreachable!()), None);
+        assert!(bvh.intersect_ray_all(&ray, |_| unreachable!()).is_empty());
+        assert!(bvh.query_aabb(&aabb).is_empty());
+    }
+
+    #[test]
+    fn test_bvh_aabb_encloses_objects() {
+        let spheres = random_spheres(200, 1);
+        let bvh = Bvh::build(&spheres);
+        let aabb = bvh.aabb().unwrap();
+
+        assert_eq!(bvh.len(), spheres.len());
+        for sphere in spheres.iter() {
+            assert!(aabb.contains_aabb(&sphere.aabb()));
+        }
+    }
+
+    #[test]
+    fn test_bvh_coincident_objects() {
+        let spheres = vec![Sphere3::new(Point3::new(1_f64, 2_f64, 3_f64), 1_f64); 50];
+        let bvh = Bvh::build(&spheres);
+        let ray = Ray3::new(Point3::new(1_f64, 2_f64, -10_f64), Vector3::unit_z());
+        let hits = bvh.intersect_ray_all(&ray, |i| ray.intersect_sphere(&spheres[i]));
+
+        assert_eq!(hits.len(), spheres.len());
+    }
+
+    #[test]
+    fn test_bvh_intersect_ray_nearest_spheres() {
+        let spheres = random_spheres(300, 2);
+        let bvh = Bvh::build(&spheres);
+        for ray in random_rays(200, 3).iter() {
+            let expected = brute_force_all(&spheres, |sphere| ray.intersect_sphere(sphere))
+                .first()
+                .map(|(_, hit)| hit.distance());
+            let result = bvh
+                .intersect_ray_nearest(ray, |i| ray.intersect_sphere(&spheres[i]))
+                .map(|(_, hit)| hit.distance());
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_bvh_intersect_ray_nearest_triangles() {
+        let triangles = random_triangles(500, 4);
+        let bvh = Bvh::build(&triangles);
+        for ray in random_rays(200, 5).iter() {
+            let expected = brute_force_all(&triangles, |triangle| intersect_triangle(ray, triangle))
+                .first()
+                .map(|(_, hit)| hit.distance());
+            let result = bvh
+                .intersect_ray_nearest(ray, |i| intersect_triangle(ray, &triangles[i]))
+                .map(|(_, hit)| hit.distance());
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_bvh_intersect_ray_nearest_from_inside() {
+        let spheres = [
+            Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 2_f64),
+            Sphere3::new(Point3::new(0_f64, 0_f64, 10_f64), 1_f64),
+        ];
+        let bvh = Bvh::build(&spheres);
+        let ray = Ray3::new(Point3::origin(), Vector3::unit_z());
+        let (index, hit) = bvh.intersect_ray_nearest(&ray, |i| ray.intersect_sphere(&spheres[i])).unwrap();
+
+        assert_eq!(index, 0);
+        assert_eq!(hit.distance(), 2_f64);
+    }
+
+    #[test]
+    fn test_bvh_intersect_ray_all() {
+        let triangles = random_triangles(500, 6);
+        let bvh = Bvh::build(&triangles);
+        for ray in random_rays(200, 7).iter() {
+            let expected: Vec<f64> = brute_force_all(&triangles, |triangle| intersect_triangle(ray, triangle))
+                .iter()
+                .map(|(_, hit)| hit.distance())
+                .collect();
+            let result: Vec<f64> = bvh
+                .intersect_ray_all(ray, |i| intersect_triangle(ray, &triangles[i]))
+                .iter()
+                .map(|(_, hit)| hit.distance())
+                .collect();
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_bvh_query_aabb() {
+        let spheres = random_spheres(300, 8);
+        let bvh = Bvh::build(&spheres);
+        let mut rng = IsaacRng::seed_from_u64(9);
+        for _ in 0..200 {
+            let aabb = Aabb3::from_corners(&random_point(&mut rng, 60_f64), &random_point(&mut rng, 60_f64));
+            let expected = brute_force_query(&spheres, &aabb);
+            let result = bvh.query_aabb(&aabb);
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_bvh_refit() {
+        let mut spheres = random_spheres(300, 10);
+        let mut bvh = Bvh::build(&spheres);
+        let mut rng = IsaacRng::seed_from_u64(11);
+        for sphere in spheres.iter_mut() {
+            let displacement = random_point(&mut rng, 10_f64).to_vector();
+            *sphere = Sphere3::new(sphere.center() + displacement, sphere.radius());
+        }
+        bvh.refit(&spheres);
+
+        for sphere in spheres.iter() {
+            assert!(bvh.aabb().unwrap().contains_aabb(&sphere.aabb()));
+        }
+        for ray in random_rays(100, 12).iter() {
+            let expected = brute_force_all(&spheres, |sphere| ray.intersect_sphere(sphere))
+                .first()
+                .map(|(_, hit)| hit.distance());
+            let result = bvh
+                .intersect_ray_nearest(ray, |i| ray.intersect_sphere(&spheres[i]))
+                .map(|(_, hit)| hit.distance());
+
+            assert_eq!(result, expected);
+        }
+        for _ in 0..100 {
+            let aabb = Aabb3::from_corners(&random_point(&mut rng, 60_f64), &random_point(&mut rng, 60_f64));
+
+            assert_eq!(bvh.query_aabb(&aabb), brute_force_query(&spheres, &aabb));
+        }
+    }
+
+    #[test]
+    fn test_bvh_skewed_centroids() {
+        // Each centroid is farther from the others than all of them are from each
+        // other, so every split separates a single object.
+        let spheres: Vec<Sphere3<f64>> = (0..1000)
+            .map(|i| Sphere3::new(Point3::new(2_f64.powi(i), 0_f64, 0_f64), 0.25_f64))
+            .collect();
+        let bvh = Bvh::build(&spheres);
+        let ray = Ray3::new(Point3::new(0_f64, 0_f64, 0_f64), Vector3::unit_x());
+        let (index, _) = bvh
+            .intersect_ray_nearest(&ray, |i| ray.intersect_sphere(&spheres[i]))
+            .unwrap();
+
+        assert_eq!(bvh.len(), spheres.len());
+        assert_eq!(index, 0);
+        for sphere in spheres.iter() {
+            assert!(bvh.aabb().unwrap().contains_aabb(&sphere.aabb()));
+            assert_eq!(bvh.query_aabb(&sphere.aabb()), brute_force_query(&spheres, &sphere.aabb()));
+        }
+    }
+}
@@ -0,0 +1,56 @@
+mod common;
+
+
+#[cfg(test)]
+mod capsule_tests {
+    use crate::common::{
+        random_point,
+        solid_props,
+    };
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Point3,
+        Vector3,
+    };
+    use cglinalg_geometry::Capsule3;
+    use rand::SeedableRng;
+    use rand_isaac::IsaacRng;
+
+
+    fn capsule() -> Capsule3<f64> {
+        Capsule3::new(Point3::new(-1_f64, 0.5_f64, 0_f64), Point3::new(2_f64, -1_f64, 1_f64), 1.5_f64)
+    }
+
+    #[test]
+    fn test_capsule_contains_matches_segment_distance() {
+        let capsule = capsule();
+        let mut rng = IsaacRng::seed_from_u64(7);
+        for _ in 0..200 {
+            let point = random_point(&mut rng, 5_f64);
+            let distance = capsule.segment().distance_squared(&point).sqrt();
+
+            assert_eq!(capsule.contains(&point), distance <= capsule.radius());
+        }
+    }
+
+    #[test]
+    fn test_capsule_closest_point_distance() {
+        let capsule = capsule();
+        let point = capsule.end() + Vector3::new(2_f64, 2_f64, 1_f64);
+        let closest = capsule.closest_point(&point);
+
+        assert_relative_eq!((point - closest).norm(), 1.5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_capsule_degenerate_is_sphere() {
+        let capsule = Capsule3::new(Point3::new(1_f64, 1_f64, 1_f64), Point3::new(1_f64, 1_f64, 1_f64), 2_f64);
+        let point = Point3::new(1_f64, 5_f64, 1_f64);
+
+        assert_eq!(capsule.closest_point(&point), Point3::new(1_f64, 3_f64, 1_f64));
+        assert!(capsule.contains(&Point3::new(2_f64, 2_f64, 2_f64)));
+        assert!(!capsule.contains(&Point3::new(3_f64, 2_f64, 2_f64)));
+    }
+
+    solid_props!(Capsule3, capsule, intersect_capsule);
+}
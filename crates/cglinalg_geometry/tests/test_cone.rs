@@ -0,0 +1,67 @@
+mod common;
+
+
+#[cfg(test)]
+mod cone_tests {
+    use crate::common::{
+        random_point,
+        solid_props,
+    };
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::Point3;
+    use cglinalg_geometry::{
+        Bounded,
+        Cone3,
+        Cylinder3,
+    };
+    use rand::SeedableRng;
+    use rand_isaac::IsaacRng;
+
+
+    fn cone() -> Cone3<f64> {
+        Cone3::new(Point3::new(-1_f64, 0.5_f64, 0_f64), Point3::new(2_f64, -1_f64, 1_f64), 2_f64, 0.5_f64)
+    }
+
+    #[test]
+    fn test_cone_equal_radii_is_cylinder() {
+        let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 2_f64, 2_f64), 1_f64, 1_f64);
+        let cylinder = Cylinder3::new(cone.start(), cone.end(), 1_f64);
+        let mut rng = IsaacRng::seed_from_u64(7);
+        for _ in 0..200 {
+            let point = random_point(&mut rng, 4_f64);
+
+            assert_eq!(cone.contains(&point), cylinder.contains(&point));
+            assert_relative_eq!(
+                cone.closest_point(&point),
+                cylinder.closest_point(&point),
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON,
+            );
+        }
+        assert_eq!(cone.aabb(), cylinder.aabb());
+    }
+
+    #[test]
+    fn test_cone_closest_point_apex() {
+        let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 1_f64, 0_f64);
+        let point = Point3::new(0.1_f64, 0_f64, 4_f64);
+
+        assert_relative_eq!(
+            cone.closest_point(&point),
+            Point3::new(0_f64, 0_f64, 2_f64),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+    }
+
+    #[test]
+    fn test_cone_closest_point_side() {
+        let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 2_f64, 0_f64);
+        let point = Point3::new(0_f64, 3_f64, 3_f64);
+        let expected = Point3::new(0_f64, 1_f64, 1_f64);
+
+        assert_relative_eq!(cone.closest_point(&point), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    solid_props!(Cone3, cone, intersect_cone);
+}
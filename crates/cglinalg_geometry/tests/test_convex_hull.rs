@@ -0,0 +1,306 @@
+#[cfg(test)]
+mod convex_hull2_tests {
+    use cglinalg_core::Point2;
+    use cglinalg_geometry::convex_hull2;
+    use rand::{
+        Rng,
+        SeedableRng,
+    };
+    use rand_isaac::IsaacRng;
+
+
+    fn cross(a: &Point2<f64>, b: &Point2<f64>, c: &Point2<f64>) -> f64 {
+        let edge1 = b - a;
+        let edge2 = c - a;
+
+        edge1[0] * edge2[1] - edge1[1] * edge2[0]
+    }
+
+    #[test]
+    fn test_convex_hull2_empty() {
+        let points: [Point2<f64>; 0] = [];
+
+        assert!(convex_hull2(&points).is_empty());
+    }
+
+    #[test]
+    fn test_convex_hull2_single_point() {
+        let points = [Point2::new(1_f64, 2_f64), Point2::new(1_f64, 2_f64)];
+
+        assert_eq!(convex_hull2(&points).len(), 1);
+    }
+
+    #[test]
+    fn test_convex_hull2_collinear() {
+        let points = [
+            Point2::new(1_f64, 1_f64),
+            Point2::new(3_f64, 3_f64),
+            Point2::new(0_f64, 0_f64),
+            Point2::new(2_f64, 2_f64),
+        ];
+
+        assert_eq!(convex_hull2(&points), vec![2, 1]);
+    }
+
+    #[test]
+    fn test_convex_hull2_square_drops_interior_and_edge_points() {
+        let points = [
+            Point2::new(0.5_f64, 0.5_f64),
+            Point2::new(1_f64, 1_f64),
+            Point2::new(0_f64, 1_f64),
+            Point2::new(0.5_f64, 0_f64),
+            Point2::new(0_f64, 0_f64),
+            Point2::new(1_f64, 0_f64),
+            Point2::new(1_f64, 0.5_f64),
+            Point2::new(0_f64, 0_f64),
+        ];
+
+        assert_eq!(convex_hull2(&points), vec![4, 5, 1, 2]);
+    }
+
+    #[test]
+    fn test_convex_hull2_random_points() {
+        let mut rng = IsaacRng::seed_from_u64(0);
+        let points: Vec<Point2<f64>> = (0..500)
+            .map(|_| Point2::new(rng.gen_range(-10_f64..10_f64), rng.gen_range(-10_f64..10_f64)))
+            .collect();
+        let hull = convex_hull2(&points);
+
+        assert!(hull.len() >= 3);
+        for i in 0..hull.len() {
+            let a = &points[hull[i]];
+            let b = &points[hull[(i + 1) % hull.len()]];
+            let c = &points[hull[(i + 2) % hull.len()]];
+
+            assert!(cross(a, b, c) > 0_f64);
+            for point in points.iter() {
+                assert!(cross(a, b, point) >= -1e-12);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod convex_hull3_tests {
+    use cglinalg_core::{
+        Normed,
+        Point3,
+        Vector3,
+    };
+    use cglinalg_geometry::{
+        convex_hull3,
+        ConvexHull3,
+    };
+    use rand::{
+        Rng,
+        SeedableRng,
+    };
+    use rand_isaac::IsaacRng;
+
+
+    fn cube_points() -> Vec<Point3<f64>> {
+        let mut points = Vec::new();
+        for i in 0..8 {
+            let x = if i & 1 == 0 { -1_f64 } else { 1_f64 };
+            let y = if i & 2 == 0 { -1_f64 } else { 1_f64 };
+            let z = if i & 4 == 0 { -1_f64 } else { 1_f64 };
+            points.push(Point3::new(x, y, z));
+        }
+
+        points
+    }
+
+    /// Sample points on the surface of the unit cube, and perturb each coordinate by
+    /// at most `noise`, so that the points on each face are nearly coplanar.
+    fn perturbed_cube_surface_points(rng: &mut IsaacRng, count: usize, noise: f64) -> Vec<Point3<f64>> {
+        (0..count)
+            .map(|_| {
+                let face = rng.gen_range(0..6);
+                let mut coordinates = [rng.gen_range(0_f64..1_f64), rng.gen_range(0_f64..1_f64), rng.gen_range(0_f64..1_f64)];
+                coordinates[face / 2] = (face % 2) as f64;
+                for coordinate in coordinates.iter_mut() {
+                    *coordinate += rng.gen_range(-noise..=noise);
+                }
+
+                Point3::new(coordinates[0], coordinates[1], coordinates[2])
+            })
+            .collect()
+    }
+
+    /// Check that the hull is a closed, consistently oriented triangle mesh of genus
+    /// zero containing every point, and that every normal vector is the outward unit
+    /// normal vector of its face.
+    fn assert_valid_hull(points: &[Point3<f64>], hull: &ConvexHull3<f64>) {
+        let faces = hull.faces();
+        let normals = hull.normals();
+        assert_eq!(faces.len(), normals.len());
+
+        let mut edges = Vec::new();
+        for face in faces.iter() {
+            edges.push((face[0], face[1]));
+            edges.push((face[1], face[2]));
+            edges.push((face[2], face[0]));
+        }
+        for (a, b) in edges.iter() {
+            assert_eq!(edges.iter().filter(|edge| **edge == (*a, *b)).count(), 1);
+            assert_eq!(edges.iter().filter(|edge| **edge == (*b, *a)).count(), 1);
+        }
+        let vertex_count = hull.vertices().len() as isize;
+        let edge_count = (edges.len() / 2) as isize;
+        let face_count = faces.len() as isize;
+        assert_eq!(vertex_count - edge_count + face_count, 2);
+
+        for (face, normal) in faces.iter().zip(normals.iter()) {
+            let a = points[face[0]];
+            let b = points[face[1]];
+            let c = points[face[2]];
+            let expected = (b - a).cross(&(c - a)).normalize();
+
+            assert!((normal - expected).norm() <= 1e-10);
+            for point in points.iter() {
+                assert!(normal.dot(&(point - a)) <= 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convex_hull3_too_few_points() {
+        let points = [
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(1_f64, 0_f64, 0_f64),
+            Point3::new(0_f64, 1_f64, 0_f64),
+        ];
+
+        assert_eq!(convex_hull3(&points), None);
+    }
+
+    #[test]
+    fn test_convex_hull3_coincident_points() {
+        let points = vec![Point3::new(1_f64, 2_f64, 3_f64); 10];
+
+        assert_eq!(convex_hull3(&points), None);
+    }
+
+    #[test]
+    fn test_convex_hull3_collinear_points() {
+        let points: Vec<Point3<f64>> = (0..10).map(|i| Point3::new(i as f64, 2_f64 * i as f64, 1_f64)).collect();
+
+        assert_eq!(convex_hull3(&points), None);
+    }
+
+    #[test]
+    fn test_convex_hull3_coplanar_points() {
+        let mut rng = IsaacRng::seed_from_u64(0);
+        let points: Vec<Point3<f64>> = (0..100)
+            .map(|_| Point3::new(rng.gen_range(-1_f64..1_f64), rng.gen_range(-1_f64..1_f64), 2_f64))
+            .collect();
+
+        assert_eq!(convex_hull3(&points), None);
+    }
+
+    #[test]
+    fn test_convex_hull3_tetrahedron() {
+        let points = [
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(1_f64, 0_f64, 0_f64),
+            Point3::new(0_f64, 1_f64, 0_f64),
+            Point3::new(0_f64, 0_f64, 1_f64),
+        ];
+        let hull = convex_hull3(&points).unwrap();
+
+        assert_eq!(hull.faces().len(), 4);
+        assert_eq!(hull.vertices(), vec![0, 1, 2, 3]);
+        assert_valid_hull(&points, &hull);
+    }
+
+    #[test]
+    fn test_convex_hull3_cube_with_interior_points() {
+        let mut rng = IsaacRng::seed_from_u64(1);
+        let mut points = cube_points();
+        for _ in 0..200 {
+            points.push(Point3::new(
+                rng.gen_range(-0.99_f64..0.99_f64),
+                rng.gen_range(-0.99_f64..0.99_f64),
+                rng.gen_range(-0.99_f64..0.99_f64),
+            ));
+        }
+        let hull = convex_hull3(&points).unwrap();
+
+        assert_eq!(hull.vertices(), vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(hull.faces().len(), 12);
+        assert_valid_hull(&points, &hull);
+    }
+
+    #[test]
+    fn test_convex_hull3_cube_with_face_points() {
+        let mut points = cube_points();
+        points.push(Point3::new(0_f64, 0_f64, 1_f64));
+        points.push(Point3::new(1_f64, 0_f64, 0_f64));
+        points.push(Point3::new(0_f64, 1_f64, 1_f64));
+        points.extend(cube_points());
+        let hull = convex_hull3(&points).unwrap();
+
+        assert_eq!(hull.vertices(), vec![0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_valid_hull(&points, &hull);
+    }
+
+    #[test]
+    fn test_convex_hull3_points_on_sphere() {
+        let mut rng = IsaacRng::seed_from_u64(2);
+        let points: Vec<Point3<f64>> = (0..300)
+            .map(|_| {
+                let direction = Vector3::new(
+                    rng.gen_range(-1_f64..1_f64),
+                    rng.gen_range(-1_f64..1_f64),
+                    rng.gen_range(-1_f64..1_f64),
+                );
+
+                Point3::from_vector(&(direction.normalize() * 5_f64))
+            })
+            .collect();
+        let hull = convex_hull3(&points).unwrap();
+
+        assert_eq!(hull.vertices(), (0..points.len()).collect::<Vec<usize>>());
+        assert_valid_hull(&points, &hull);
+    }
+
+    #[test]
+    fn test_convex_hull3_random_points() {
+        let mut rng = IsaacRng::seed_from_u64(3);
+        let points: Vec<Point3<f64>> = (0..1000)
+            .map(|_| {
+                Point3::new(
+                    rng.gen_range(-10_f64..10_f64),
+                    rng.gen_range(-20_f64..20_f64),
+                    rng.gen_range(-5_f64..5_f64),
+                )
+            })
+            .collect();
+        let hull = convex_hull3(&points).unwrap();
+
+        assert_valid_hull(&points, &hull);
+    }
+
+    #[test]
+    fn test_convex_hull3_nearly_coplanar_points_1e_14() {
+        let mut rng = IsaacRng::seed_from_u64(4);
+        for _ in 0..20 {
+            let points = perturbed_cube_surface_points(&mut rng, 500, 1e-14);
+            let hull = convex_hull3(&points).unwrap();
+
+            assert_valid_hull(&points, &hull);
+        }
+    }
+
+    #[test]
+    fn test_convex_hull3_nearly_coplanar_points_1e_12() {
+        let mut rng = IsaacRng::seed_from_u64(5);
+        for _ in 0..20 {
+            let points = perturbed_cube_surface_points(&mut rng, 500, 1e-12);
+            let hull = convex_hull3(&points).unwrap();
+
+            assert_valid_hull(&points, &hull);
+        }
+    }
+}
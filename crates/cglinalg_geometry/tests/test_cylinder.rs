@@ -0,0 +1,49 @@
+mod common;
+
+
+#[cfg(test)]
+mod cylinder_tests {
+    use crate::common::solid_props;
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::Point3;
+    use cglinalg_geometry::{
+        Bounded,
+        Cylinder3,
+    };
+
+
+    fn cylinder() -> Cylinder3<f64> {
+        Cylinder3::new(Point3::new(-1_f64, 0.5_f64, 0_f64), Point3::new(2_f64, -1_f64, 1_f64), 1.5_f64)
+    }
+
+    #[test]
+    fn test_cylinder_contains_caps_and_rim() {
+        let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 1_f64);
+
+        assert!(cylinder.contains(&Point3::new(0_f64, 0_f64, 0_f64)));
+        assert!(cylinder.contains(&Point3::new(0_f64, 0_f64, 2_f64)));
+        assert!(cylinder.contains(&Point3::new(1_f64, 0_f64, 2_f64)));
+        assert!(!cylinder.contains(&Point3::new(0_f64, 0_f64, -0.1_f64)));
+        assert!(!cylinder.contains(&Point3::new(0.8_f64, 0.8_f64, 1_f64)));
+    }
+
+    #[test]
+    fn test_cylinder_closest_point_rim() {
+        let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 1_f64);
+        let point = Point3::new(3_f64, 4_f64, 5_f64);
+        let expected = Point3::new(3_f64 / 5_f64, 4_f64 / 5_f64, 2_f64);
+
+        assert_relative_eq!(cylinder.closest_point(&point), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_cylinder_aabb_axis_aligned() {
+        let cylinder = Cylinder3::new(Point3::new(1_f64, 2_f64, 3_f64), Point3::new(1_f64, 7_f64, 3_f64), 2_f64);
+        let aabb = cylinder.aabb();
+
+        assert_eq!(aabb.min(), Point3::new(-1_f64, 2_f64, 1_f64));
+        assert_eq!(aabb.max(), Point3::new(3_f64, 7_f64, 5_f64));
+    }
+
+    solid_props!(Cylinder3, cylinder, intersect_cylinder);
+}
@@ -0,0 +1,170 @@
+mod common;
+
+
+#[cfg(test)]
+mod epa_tests {
+    use crate::common::{
+        translation,
+        unit_cube,
+    };
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Point3,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_geometry::{
+        epa_penetration,
+        gjk_intersects,
+        Aabb3,
+        Capsule3,
+        Obb3,
+        Sphere3,
+    };
+    use cglinalg_transform::{
+        Isometry3,
+        Rotation3,
+        Translation3,
+    };
+    use cglinalg_trigonometry::Degrees;
+    use rand::{
+        Rng,
+        SeedableRng,
+    };
+    use rand_isaac::IsaacRng;
+
+
+    #[test]
+    fn test_epa_box_box() {
+        let cube = unit_cube();
+        let isometry1 = Isometry3::identity();
+        let isometry2 = translation(-1.7_f64, 0.3_f64, 0.5_f64);
+        let result = epa_penetration(&cube, &isometry1, &cube, &isometry2).unwrap();
+
+        assert_relative_eq!(result.depth(), 0.3_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.normal(), -Vector3::unit_x(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.point1()[0], -1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.point2()[0], -0.7_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_epa_sphere_sphere() {
+        let sphere1 = Sphere3::new(Point3::origin(), 1_f64);
+        let sphere2 = Sphere3::new(Point3::origin(), 2_f64);
+        let isometry1 = Isometry3::identity();
+        let isometry2 = translation(0_f64, 1.2_f64, 1.6_f64);
+        let result = epa_penetration(&sphere1, &isometry1, &sphere2, &isometry2).unwrap();
+
+        assert_relative_eq!(result.depth(), 1_f64, abs_diff_all <= 1e-3, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.normal(), Vector3::new(0_f64, 0.6_f64, 0.8_f64), abs_diff_all <= 1e-2, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_epa_contact_points_differ_by_depth() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 2_f64));
+        let obb = Obb3::new(Point3::origin(), Vector3::new(1_f64, 2_f64, 0.5_f64), Rotation3::identity());
+        let capsule = Capsule3::new(Point3::new(0_f64, -1_f64, 0_f64), Point3::new(0_f64, 1_f64, 0_f64), 0.5_f64);
+        let isometry1 = Isometry3::from_parts(&Translation3::new(0_f64, 0_f64, 0_f64), &Rotation3::from_axis_angle(&axis, Degrees(25_f64)));
+        let isometry2 = Isometry3::from_parts(&Translation3::new(1.2_f64, 0.3_f64, 0.4_f64), &Rotation3::from_angle_x(Degrees(60_f64)));
+        let result = epa_penetration(&obb, &isometry1, &capsule, &isometry2).unwrap();
+        let expected = result.normal() * result.depth();
+
+        assert!(result.depth() > 0_f64);
+        assert_relative_eq!(result.normal().norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.point1() - result.point2(), expected, abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_epa_translation_separates() {
+        let axis = Unit::from_value(Vector3::new(-1_f64, 2_f64, 1_f64));
+        let obb = Obb3::new(Point3::origin(), Vector3::new(1_f64, 0.5_f64, 0.75_f64), Rotation3::identity());
+        let isometry1 = Isometry3::identity();
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(30_f64));
+        let isometry2 = Isometry3::from_parts(&Translation3::new(1.2_f64, 0.6_f64, -0.3_f64), &rotation);
+        let result = epa_penetration(&obb, &isometry1, &obb, &isometry2).unwrap();
+        let push = |amount: f64| {
+            let offset = result.normal() * amount + Vector3::new(1.2_f64, 0.6_f64, -0.3_f64);
+            Isometry3::from_parts(&Translation3::from_vector(&offset), &rotation)
+        };
+
+        assert!(gjk_intersects(&obb, &isometry1, &obb, &push(result.depth() - 1e-3)));
+        assert!(!gjk_intersects(&obb, &isometry1, &obb, &push(result.depth() + 1e-3)));
+    }
+
+    #[test]
+    fn test_epa_separated() {
+        let cube = unit_cube();
+        let isometry1 = Isometry3::identity();
+        let isometry2 = translation(3_f64, 0_f64, 0_f64);
+
+        assert!(epa_penetration(&cube, &isometry1, &cube, &isometry2).is_none());
+    }
+
+    #[test]
+    fn test_epa_concentric_spheres() {
+        let sphere1 = Sphere3::new(Point3::origin(), 1_f64);
+        let sphere2 = Sphere3::new(Point3::origin(), 0.5_f64);
+        let isometry = translation(1_f64, 2_f64, 3_f64);
+        let result = epa_penetration(&sphere1, &isometry, &sphere2, &isometry).unwrap();
+
+        // Every direction is a direction of least penetration, so the expanding polytope
+        // approximates the whole sphere, and the lower bound converges slowly. The
+        // support distance in every direction is exact, though.
+        assert_relative_eq!(result.depth(), 1.5_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.normal().norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_epa_random_boxes() {
+        let mut rng = IsaacRng::seed_from_u64(0);
+        let random_half_extents = |rng: &mut IsaacRng| {
+            Vector3::new(rng.gen_range(0.2_f64..1.5_f64), rng.gen_range(0.2_f64..1.5_f64), rng.gen_range(0.2_f64..1.5_f64))
+        };
+        for _ in 0..1000 {
+            let half_extents1 = random_half_extents(&mut rng);
+            let half_extents2 = random_half_extents(&mut rng);
+            let offset = Vector3::new(rng.gen_range(-1.5_f64..1.5_f64), rng.gen_range(-1.5_f64..1.5_f64), rng.gen_range(-1.5_f64..1.5_f64));
+            let overlaps = Vector3::new(
+                half_extents1[0] + half_extents2[0] - offset[0].abs(),
+                half_extents1[1] + half_extents2[1] - offset[1].abs(),
+                half_extents1[2] + half_extents2[2] - offset[2].abs(),
+            );
+            if overlaps[0] <= 1e-3 || overlaps[1] <= 1e-3 || overlaps[2] <= 1e-3 {
+                continue;
+            }
+            let expected = overlaps[0].min(overlaps[1]).min(overlaps[2]);
+            let box1 = Aabb3::new(Point3::from_vector(&-half_extents1), Point3::from_vector(&half_extents1));
+            let box2 = Aabb3::new(Point3::from_vector(&-half_extents2), Point3::from_vector(&half_extents2));
+            let isometry2 = translation(offset[0], offset[1], offset[2]);
+            let result = epa_penetration(&box1, &Isometry3::identity(), &box2, &isometry2).unwrap();
+
+            assert!(result.is_converged());
+            assert_relative_eq!(result.depth(), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_epa_random_spheres() {
+        let mut rng = IsaacRng::seed_from_u64(1);
+        for _ in 0..1000 {
+            let radius1 = rng.gen_range(0.2_f64..2_f64);
+            let radius2 = rng.gen_range(0.2_f64..2_f64);
+            let direction = Vector3::new(rng.gen_range(-1_f64..1_f64), rng.gen_range(-1_f64..1_f64), rng.gen_range(-1_f64..1_f64));
+            let offset = direction * (radius1 + radius2) * 0.5_f64;
+            let expected = radius1 + radius2 - offset.norm();
+            let sphere1 = Sphere3::new(Point3::origin(), radius1);
+            let sphere2 = Sphere3::new(Point3::origin(), radius2);
+            let isometry2 = translation(offset[0], offset[1], offset[2]);
+            let result = epa_penetration(&sphere1, &Isometry3::identity(), &sphere2, &isometry2).unwrap();
+
+            // A result that did not converge overestimates the depth, but still separates
+            // the spheres.
+            if result.is_converged() {
+                assert_relative_eq!(result.depth(), expected, abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+            } else {
+                assert!(result.depth() >= expected - 1e-10);
+                assert_relative_eq!(result.depth(), expected, abs_diff_all <= 1e-2, relative_all <= f64::EPSILON);
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+mod common;
+
+
+#[cfg(test)]
+mod gjk_tests {
+    use crate::common::{
+        translation,
+        unit_cube,
+    };
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Point3,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_geometry::{
+        gjk_closest_points,
+        gjk_distance,
+        gjk_intersects,
+        Capsule3,
+        ClosestPoints,
+        Cylinder3,
+        Obb3,
+        PointCloud3,
+        Segment3,
+        Sphere3,
+    };
+    use cglinalg_transform::{
+        Isometry3,
+        Rotation3,
+        Translation3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    #[test]
+    fn test_gjk_sphere_sphere() {
+        let sphere1 = Sphere3::new(Point3::origin(), 1_f64);
+        let sphere2 = Sphere3::new(Point3::origin(), 2_f64);
+        let isometry1 = translation(1_f64, 1_f64, 1_f64);
+        let isometry2 = translation(1_f64, 5_f64, 4_f64);
+        let result = gjk_closest_points(&sphere1, &isometry1, &sphere2, &isometry2).unwrap();
+
+        assert_relative_eq!(result.distance(), 2_f64, abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.point1(), Point3::new(1_f64, 1.8_f64, 1.6_f64), abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.point2(), Point3::new(1_f64, 3.4_f64, 2.8_f64), abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.normal().unwrap(), Vector3::new(0_f64, 0.8_f64, 0.6_f64), abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_gjk_box_box_face_to_face() {
+        let cube = unit_cube();
+        let isometry1 = Isometry3::identity();
+        let isometry2 = translation(0.5_f64, -0.5_f64, 3_f64);
+        let result = gjk_closest_points(&cube, &isometry1, &cube, &isometry2).unwrap();
+
+        assert_relative_eq!(result.distance(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.normal().unwrap(), Vector3::unit_z(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.point1()[2], 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.point2()[2], 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_closest_points_touching_has_no_normal() {
+        let point = Point3::new(1_f64, 2_f64, 3_f64);
+        let closest_points = ClosestPoints::new(point, point, 0_f64);
+
+        assert!(closest_points.normal().is_none());
+    }
+
+    #[test]
+    fn test_gjk_box_box_corner_to_corner() {
+        let cube = unit_cube();
+        let isometry1 = Isometry3::identity();
+        let isometry2 = translation(3_f64, 3_f64, 3_f64);
+        let result = gjk_closest_points(&cube, &isometry1, &cube, &isometry2).unwrap();
+
+        assert_relative_eq!(result.distance(), f64::sqrt(3_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.point1(), Point3::new(1_f64, 1_f64, 1_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.point2(), Point3::new(2_f64, 2_f64, 2_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_gjk_obb_sphere_agrees_with_closest_point() {
+        let axis = Unit::from_value(Vector3::new(1_f64, -2_f64, 1_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(50_f64));
+        let obb = Obb3::new(Point3::origin(), Vector3::new(2_f64, 1_f64, 0.5_f64), Rotation3::identity());
+        let world_obb = Obb3::new(Point3::new(1_f64, 2_f64, 3_f64), obb.half_extents(), rotation);
+        let isometry1 = Isometry3::from_parts(&Translation3::new(1_f64, 2_f64, 3_f64), &rotation);
+        let sphere = Sphere3::new(Point3::origin(), 0.75_f64);
+        let centers = [
+            Point3::new(5_f64, 2_f64, 3_f64),
+            Point3::new(-2_f64, 4_f64, 6_f64),
+            Point3::new(1_f64, -3_f64, 0_f64),
+        ];
+        for center in centers.iter() {
+            let isometry2 = translation(center[0], center[1], center[2]);
+            let expected = world_obb.distance_squared(center).sqrt() - sphere.radius();
+            let result = gjk_distance(&obb, &isometry1, &sphere, &isometry2);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_gjk_capsule_capsule_agrees_with_segments() {
+        let capsule1 = Capsule3::new(Point3::new(-1_f64, 0_f64, 0_f64), Point3::new(1_f64, 0_f64, 0_f64), 0.25_f64);
+        let capsule2 = Capsule3::new(Point3::new(0_f64, -2_f64, 0_f64), Point3::new(0_f64, 2_f64, 0_f64), 0.5_f64);
+        let isometry1 = Isometry3::from_parts(&Translation3::new(0.5_f64, 0_f64, 0_f64), &Rotation3::from_angle_z(Degrees(20_f64)));
+        let isometry2 = Isometry3::from_parts(&Translation3::new(2_f64, 1_f64, 3_f64), &Rotation3::from_angle_x(Degrees(40_f64)));
+        let segment1 = Segment3::new(isometry1.apply_point(&capsule1.start()), isometry1.apply_point(&capsule1.end()));
+        let segment2 = Segment3::new(isometry2.apply_point(&capsule2.start()), isometry2.apply_point(&capsule2.end()));
+        let expected = segment1.distance_squared_segment(&segment2).sqrt() - capsule1.radius() - capsule2.radius();
+        let result = gjk_distance(&capsule1, &isometry1, &capsule2, &isometry2);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_gjk_cylinder_sphere() {
+        let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 1_f64);
+        let sphere = Sphere3::new(Point3::origin(), 0.5_f64);
+        let isometry1 = Isometry3::identity();
+
+        let beside = gjk_distance(&cylinder, &isometry1, &sphere, &translation(3_f64, 0_f64, 1_f64));
+        let above = gjk_distance(&cylinder, &isometry1, &sphere, &translation(0.5_f64, 0_f64, 4_f64));
+
+        assert_relative_eq!(beside, 1.5_f64, abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+        assert_relative_eq!(above, 1.5_f64, abs_diff_all <= 1e-6, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_gjk_point_cloud_box() {
+        let points = [
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(1_f64, 0_f64, 0_f64),
+            Point3::new(0_f64, 1_f64, 0_f64),
+            Point3::new(0_f64, 0_f64, 1_f64),
+        ];
+        let point_cloud = PointCloud3::new(&points).unwrap();
+        let cube = unit_cube();
+        let isometry1 = translation(2_f64, 0_f64, 0_f64);
+        let isometry2 = Isometry3::identity();
+        let result = gjk_closest_points(&point_cloud, &isometry1, &cube, &isometry2).unwrap();
+
+        assert_relative_eq!(result.distance(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.point1()[0], 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_gjk_intersects() {
+        let cube = unit_cube();
+        let sphere = Sphere3::new(Point3::origin(), 1_f64);
+        let isometry1 = Isometry3::from_parts(&Translation3::new(0_f64, 0_f64, 0_f64), &Rotation3::from_angle_z(Degrees(45_f64)));
+
+        // The corner of the rotated cube reaches out to a distance of `sqrt(2)` along the x-axis.
+        assert!(gjk_intersects(&cube, &isometry1, &sphere, &translation(2.3_f64, 0_f64, 0_f64)));
+        assert!(!gjk_intersects(&cube, &isometry1, &sphere, &translation(2.5_f64, 0_f64, 0_f64)));
+        assert!(gjk_intersects(&sphere, &translation(2.3_f64, 0_f64, 0_f64), &cube, &isometry1));
+        assert!(gjk_intersects(&cube, &isometry1, &sphere, &Isometry3::identity()));
+    }
+
+    #[test]
+    fn test_gjk_intersecting_closest_points() {
+        let cube = unit_cube();
+        let isometry1 = Isometry3::identity();
+        let isometry2 = translation(0.5_f64, 0.5_f64, 0.5_f64);
+
+        assert!(gjk_closest_points(&cube, &isometry1, &cube, &isometry2).is_none());
+        assert_eq!(gjk_distance(&cube, &isometry1, &cube, &isometry2), 0_f64);
+    }
+}
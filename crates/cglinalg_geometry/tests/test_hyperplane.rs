@@ -0,0 +1,245 @@
+#[cfg(test)]
+mod hyperplane2_tests {
+    use cglinalg_core::{
+        Point2,
+        Unit,
+        Vector2,
+    };
+    use cglinalg_geometry::Hyperplane2;
+
+
+    #[test]
+    fn test_hyperplane_signed_distance() {
+        let line = Hyperplane2::from_normal_point(&Unit::from_value(Vector2::new(3_f64, 4_f64)), &Point2::new(3_f64, 4_f64));
+
+        assert_eq!(line.distance(), 5_f64);
+        assert_eq!(line.signed_distance(&Point2::new(0_f64, 0_f64)), -5_f64);
+        assert_eq!(line.signed_distance(&Point2::new(6_f64, 8_f64)), 5_f64);
+    }
+
+    #[test]
+    fn test_hyperplane_project_point() {
+        let line = Hyperplane2::from_normal_distance(&Unit::from_value(Vector2::unit_y()), 1_f64);
+        let point = Point2::new(3_f64, -4_f64);
+        let expected = Point2::new(3_f64, 1_f64);
+        let result = line.project_point(&point);
+
+        assert_eq!(result, expected);
+        assert_eq!(line.signed_distance(&result), 0_f64);
+    }
+
+    #[test]
+    fn test_hyperplane_intersect_line() {
+        let line = Hyperplane2::from_normal_distance(&Unit::from_value(Vector2::unit_x()), 2_f64);
+        let point = Point2::new(0_f64, 0_f64);
+        let direction = Vector2::new(1_f64, 1_f64);
+
+        assert_eq!(line.intersect_line(&point, &direction), Some(Point2::new(2_f64, 2_f64)));
+        assert_eq!(line.intersect_line(&point, &Vector2::unit_y()), None);
+    }
+}
+
+#[cfg(test)]
+mod hyperplane3_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix4x4,
+        Point3,
+        Unit,
+        Vector3,
+        Vector4,
+    };
+    use cglinalg_geometry::{
+        Hyperplane3,
+        Ray3,
+    };
+    use cglinalg_transform::{
+        Isometry3,
+        Reflection3,
+        Rotation3,
+        Transform3,
+        Translation3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    #[test]
+    fn test_hyperplane_from_points() {
+        let a = Point3::new(1_f64, 0_f64, 0_f64);
+        let b = Point3::new(0_f64, 1_f64, 0_f64);
+        let c = Point3::new(0_f64, 0_f64, 1_f64);
+        let plane = Hyperplane3::from_points(&a, &b, &c).unwrap();
+        let expected_normal = Vector3::new(1_f64, 1_f64, 1_f64) / f64::sqrt(3_f64);
+
+        assert_relative_eq!(plane.normal(), expected_normal, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(plane.distance(), 1_f64 / f64::sqrt(3_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        for point in [a, b, c].iter() {
+            assert_relative_eq!(plane.signed_distance(point), 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_hyperplane_from_points_winding() {
+        let a = Point3::new(0_f64, 0_f64, 0_f64);
+        let b = Point3::new(1_f64, 0_f64, 0_f64);
+        let c = Point3::new(0_f64, 1_f64, 0_f64);
+        let plane = Hyperplane3::from_points(&a, &c, &b).unwrap();
+
+        assert_eq!(plane.normal(), -Vector3::unit_z());
+    }
+
+    #[test]
+    fn test_hyperplane_from_equation_to_equation() {
+        let equation = Vector4::new(2_f64, -1_f64, 2_f64, 6_f64);
+        let plane = Hyperplane3::from_equation(&equation).unwrap();
+        let expected = equation / 3_f64;
+        let result = plane.to_equation();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(plane.distance(), -2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_hyperplane_from_equation_degenerate() {
+        let equation = Vector4::new(0_f64, 0_f64, 0_f64, 1_f64);
+
+        assert!(Hyperplane3::from_equation(&equation).is_none());
+    }
+
+    #[test]
+    fn test_hyperplane_reflect_point_twice() {
+        let plane = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64)), 3_f64);
+        let point = Point3::new(4_f64, -5_f64, 6_f64);
+        let reflected = plane.reflect_point(&point);
+
+        assert_relative_eq!(
+            plane.signed_distance(&reflected),
+            -plane.signed_distance(&point),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+        assert_relative_eq!(plane.reflect_point(&reflected), point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_hyperplane_intersect_planes() {
+        let plane0 = Hyperplane3::from_equation(&Vector4::new(1_f64, 1_f64, 1_f64, -6_f64)).unwrap();
+        let plane1 = Hyperplane3::from_equation(&Vector4::new(1_f64, -1_f64, 0_f64, 1_f64)).unwrap();
+        let plane2 = Hyperplane3::from_equation(&Vector4::new(0_f64, 1_f64, -1_f64, 1_f64)).unwrap();
+        let expected = Point3::new(1_f64, 2_f64, 3_f64);
+        let result = plane0.intersect_planes(&plane1, &plane2).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_hyperplane_intersect_planes_parallel() {
+        let plane0 = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::unit_z()), 1_f64);
+        let plane1 = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::unit_z()), 2_f64);
+        let plane2 = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::unit_x()), 0_f64);
+
+        assert!(plane0.intersect_planes(&plane1, &plane2).is_none());
+    }
+
+    #[test]
+    fn test_hyperplane_transform_isometry() {
+        let plane = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64)), 4_f64);
+        let isometry = Isometry3::from_parts(
+            &Translation3::from_vector(&Vector3::new(-1_f64, 5_f64, 2_f64)),
+            &Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(3_f64, 1_f64, 1_f64)), Degrees(70_f64)),
+        );
+        let point = plane.project_point(&Point3::new(1_f64, 1_f64, 1_f64));
+        let result = plane.transform_isometry(&isometry);
+
+        assert_relative_eq!(
+            result.signed_distance(&isometry.apply_point(&point)),
+            0_f64,
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+    }
+
+    #[test]
+    fn test_hyperplane_transform_agrees_with_isometry() {
+        let plane = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64)), 4_f64);
+        let isometry = Isometry3::from_parts(
+            &Translation3::from_vector(&Vector3::new(-1_f64, 5_f64, 2_f64)),
+            &Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(3_f64, 1_f64, 1_f64)), Degrees(70_f64)),
+        );
+        let expected = plane.transform_isometry(&isometry);
+        let result = plane.transform(&isometry.to_transform()).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_hyperplane_transform_shear_preserves_incidence() {
+        #[rustfmt::skip]
+        let matrix = Matrix4x4::new(
+            1_f64, 0_f64, 0_f64, 0_f64,
+            2_f64, 1_f64, 0_f64, 0_f64,
+            0_f64, 3_f64, 2_f64, 0_f64,
+            1_f64, 1_f64, 1_f64, 1_f64,
+        );
+        let transform = Transform3::from_matrix_unchecked(matrix);
+        let plane = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::new(1_f64, -1_f64, 1_f64)), 2_f64);
+        let points = [
+            plane.project_point(&Point3::new(1_f64, 2_f64, 3_f64)),
+            plane.project_point(&Point3::new(-4_f64, 0_f64, 1_f64)),
+            plane.project_point(&Point3::new(2_f64, -3_f64, 0_f64)),
+        ];
+        let result = plane.transform(&transform).unwrap();
+        for point in points.iter() {
+            assert_relative_eq!(
+                result.signed_distance(&transform.apply_point(point)),
+                0_f64,
+                abs_diff_all <= 1e-10,
+                relative_all <= f64::EPSILON,
+            );
+        }
+    }
+
+    #[test]
+    fn test_hyperplane_transform_singular() {
+        let transform = Transform3::from_matrix_unchecked(Matrix4x4::zero());
+        let plane = Hyperplane3::from_normal_distance(&Unit::from_value(Vector3::unit_z()), 1_f64);
+
+        assert!(plane.transform(&transform).is_none());
+    }
+
+    #[test]
+    fn test_hyperplane_reflection_round_trip() {
+        let normal = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+        let bias = Point3::new(1_f64, 2_f64, 3_f64);
+        let reflection = Reflection3::from_normal_bias(&normal, &bias);
+        let plane = Hyperplane3::from(reflection);
+        let point = Point3::new(-3_f64, 4_f64, 7_f64);
+
+        assert_relative_eq!(
+            plane.reflect_point(&point),
+            reflection.apply_point(&point),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+
+        let round_trip = Reflection3::from(plane);
+
+        assert_relative_eq!(
+            round_trip.apply_point(&point),
+            reflection.apply_point(&point),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+    }
+
+    #[test]
+    fn test_ray_intersect_hyperplane_agrees_with_intersect_plane() {
+        let plane = Hyperplane3::from_normal_point(&Unit::from_value(Vector3::new(1_f64, 2_f64, 2_f64)), &Point3::new(1_f64, 1_f64, 1_f64));
+        let ray = Ray3::new(Point3::new(-5_f64, -5_f64, -5_f64), Vector3::new(1_f64, 1_f64, 2_f64));
+        let expected = ray.intersect_plane(&Point3::new(1_f64, 1_f64, 1_f64), &plane.normal()).unwrap();
+        let result = ray.intersect_hyperplane(&plane).unwrap();
+
+        assert_relative_eq!(result.distance(), expected.distance(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.normal(), expected.normal(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
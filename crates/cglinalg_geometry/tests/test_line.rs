@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod line3_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Point3,
+        Vector3,
+    };
+    use cglinalg_geometry::Line3;
+
+
+    #[test]
+    fn test_line_closest_point_is_orthogonal() {
+        let line = Line3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(1_f64, -2_f64, 2_f64));
+        let point = Point3::new(4_f64, 0_f64, -1_f64);
+        let closest = line.closest_point(&point);
+
+        assert_relative_eq!((point - closest).dot(&line.direction()), 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_line_closest_point_on_line() {
+        let line = Line3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(1_f64, -2_f64, 2_f64));
+        let point = line.at(-3_f64);
+
+        assert_relative_eq!(line.closest_point(&point), point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(line.distance_squared(&point), 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_line_distance_squared() {
+        let line = Line3::new(Point3::new(0_f64, 0_f64, 1_f64), Vector3::new(0_f64, 3_f64, 0_f64));
+        let point = Point3::new(3_f64, 7_f64, 5_f64);
+
+        assert_eq!(line.distance_squared(&point), 25_f64);
+    }
+
+    #[test]
+    fn test_line_closest_points_skew_lines() {
+        let line1 = Line3::new(Point3::new(0_f64, 0_f64, 0_f64), Vector3::new(1_f64, 1_f64, 0_f64));
+        let line2 = Line3::new(Point3::new(0_f64, 2_f64, 3_f64), Vector3::new(1_f64, -1_f64, 0_f64));
+        let (point1, point2) = line1.closest_points_line(&line2);
+
+        assert_relative_eq!(point1, Point3::new(1_f64, 1_f64, 0_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(point2, Point3::new(1_f64, 1_f64, 3_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_line_closest_points_parallel_lines() {
+        let line1 = Line3::new(Point3::new(1_f64, 0_f64, 0_f64), Vector3::unit_x());
+        let line2 = Line3::new(Point3::new(5_f64, 2_f64, 0_f64), Vector3::new(-2_f64, 0_f64, 0_f64));
+        let (point1, point2) = line1.closest_points_line(&line2);
+
+        assert_eq!(point1, line1.origin());
+        assert_eq!(point2, Point3::new(1_f64, 2_f64, 0_f64));
+    }
+}
@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod obb3_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Point3,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_geometry::{
+        Aabb3,
+        Obb3,
+    };
+    use cglinalg_transform::{
+        Isometry3,
+        Rotation3,
+        Translation3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn rotated_box() -> Obb3<f64> {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Degrees(40_f64));
+
+        Obb3::new(Point3::new(1_f64, -2_f64, 3_f64), Vector3::new(4_f64, 2_f64, 1_f64), rotation)
+    }
+
+    /// Sample points over the surface of a box.
+    fn box_surface_points(obb: &Obb3<f64>) -> [Point3<f64>; 98] {
+        let [u0, u1, u2] = obb.axes();
+        let h = obb.half_extents();
+        let mut points = [Point3::origin(); 98];
+        let mut count = 0;
+        for i in 0..5 {
+            for j in 0..5 {
+                for k in 0..5 {
+                    if i % 4 != 0 && j % 4 != 0 && k % 4 != 0 {
+                        continue;
+                    }
+                    let x = (i as f64 / 2_f64 - 1_f64) * h[0];
+                    let y = (j as f64 / 2_f64 - 1_f64) * h[1];
+                    let z = (k as f64 / 2_f64 - 1_f64) * h[2];
+                    points[count] = obb.center() + u0 * x + u1 * y + u2 * z;
+                    count += 1;
+                }
+            }
+        }
+        assert_eq!(count, 98);
+
+        points
+    }
+
+    fn sorted(vector: &Vector3<f64>) -> Vector3<f64> {
+        let mut components = [vector[0], vector[1], vector[2]];
+        components.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        Vector3::new(components[0], components[1], components[2])
+    }
+
+    fn contains_approx(obb: &Obb3<f64>, point: &Point3<f64>) -> bool {
+        obb.distance_squared(point) <= 1e-16
+    }
+
+    #[test]
+    fn test_obb_from_points_recovers_box() {
+        let obb = rotated_box();
+        let points = box_surface_points(&obb);
+        let result = Obb3::from_points(&points).unwrap();
+        let expected_half_extents = sorted(&obb.half_extents());
+        let result_half_extents = sorted(&result.half_extents());
+
+        assert_relative_eq!(result.center(), obb.center(), abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result_half_extents, expected_half_extents, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.volume(), obb.volume(), abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_obb_from_points_contains_points() {
+        let points = [
+            Point3::new(1_f64, 2_f64, 0_f64),
+            Point3::new(-3_f64, 5_f64, 1_f64),
+            Point3::new(4_f64, -1_f64, 2_f64),
+            Point3::new(0_f64, 0_f64, -3_f64),
+            Point3::new(2_f64, 7_f64, 5_f64),
+            Point3::new(-1_f64, -4_f64, 1_f64),
+        ];
+        let obb = Obb3::from_points(&points).unwrap();
+
+        for point in points.iter() {
+            assert!(contains_approx(&obb, point));
+        }
+    }
+
+    #[test]
+    fn test_obb_from_points_right_handed() {
+        let points = box_surface_points(&rotated_box());
+        let obb = Obb3::from_points(&points).unwrap();
+        let [u0, u1, u2] = obb.axes();
+
+        assert_relative_eq!(u0.cross(&u1), u2, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_obb_from_points_single_point() {
+        let point = Point3::new(1_f64, 2_f64, 3_f64);
+        let obb = Obb3::from_points(&[point]).unwrap();
+
+        assert_eq!(obb.center(), point);
+        assert_eq!(obb.half_extents(), Vector3::zero());
+    }
+
+    #[test]
+    fn test_obb_from_points_empty() {
+        assert!(Obb3::<f64>::from_points(&[]).is_none());
+    }
+
+    #[test]
+    fn test_obb_contains_corners() {
+        let obb = rotated_box();
+
+        for corner in obb.corners().iter() {
+            assert!(contains_approx(&obb, corner));
+        }
+        assert!(obb.contains(&obb.center()));
+    }
+
+    #[test]
+    fn test_obb_closest_point_inside() {
+        let obb = rotated_box();
+        let point = obb.center() + obb.axes()[0] * 3_f64;
+
+        assert_relative_eq!(obb.closest_point(&point), point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_eq!(obb.distance_squared(&obb.center()), 0_f64);
+    }
+
+    #[test]
+    fn test_obb_closest_point_outside() {
+        let obb = rotated_box();
+        let [u0, u1, u2] = obb.axes();
+        let point = obb.center() + u0 * 6_f64 + u1 * 1_f64 + u2 * -3_f64;
+        let expected = obb.center() + u0 * 4_f64 + u1 * 1_f64 + u2 * -1_f64;
+        let result = obb.closest_point(&point);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(obb.distance_squared(&point), 8_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_obb_to_aabb_is_tight() {
+        let obb = rotated_box();
+        let expected = Aabb3::from_points(&obb.corners()).unwrap();
+        let result = obb.to_aabb();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_obb_intersects_self() {
+        let obb = rotated_box();
+
+        assert!(obb.intersects(&obb));
+    }
+
+    #[test]
+    fn test_obb_intersects_face_separation() {
+        let half_extents = Vector3::new(1_f64, 1_f64, 1_f64);
+        let obb1 = Obb3::new(Point3::origin(), half_extents, Rotation3::identity());
+        let obb2 = Obb3::new(Point3::new(0_f64, 0_f64, 2.01_f64), half_extents, Rotation3::from_angle_z(Degrees(30_f64)));
+        let obb3 = Obb3::new(Point3::new(0_f64, 0_f64, 1.99_f64), half_extents, Rotation3::from_angle_z(Degrees(30_f64)));
+
+        assert!(!obb1.intersects(&obb2));
+        assert!(obb1.intersects(&obb3));
+    }
+
+    #[test]
+    fn test_obb_intersects_edge_edge_separation() {
+        // Two boxes whose edges cross at right angles with a small gap are only
+        // separated by the cross product of the edge directions.
+        let half_extents = Vector3::new(1_f64, 1_f64, 1_f64);
+        let sqrt_2 = f64::sqrt(2_f64);
+        let rotation1 = Rotation3::from_angle_x(Degrees(45_f64));
+        let rotation2 = Rotation3::from_angle_y(Degrees(45_f64));
+        let obb1 = Obb3::new(Point3::origin(), half_extents, rotation1);
+        let obb2 = Obb3::new(Point3::new(0_f64, 0_f64, 2_f64 * sqrt_2 + 0.01_f64), half_extents, rotation2);
+        let obb3 = Obb3::new(Point3::new(0_f64, 0_f64, 2_f64 * sqrt_2 - 0.01_f64), half_extents, rotation2);
+
+        assert!(!obb1.intersects(&obb2));
+        assert!(!obb2.intersects(&obb1));
+        assert!(obb1.intersects(&obb3));
+        assert!(obb3.intersects(&obb1));
+    }
+
+    #[test]
+    fn test_obb_intersects_agrees_with_aabb() {
+        let aabb1 = Aabb3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(2_f64, 2_f64, 2_f64));
+        let aabb2 = Aabb3::new(Point3::new(1_f64, 1.5_f64, -1_f64), Point3::new(3_f64, 4_f64, 0.5_f64));
+        let aabb3 = Aabb3::new(Point3::new(2.5_f64, 0_f64, 0_f64), Point3::new(3_f64, 2_f64, 2_f64));
+
+        assert_eq!(Obb3::from_aabb(&aabb1).intersects(&Obb3::from_aabb(&aabb2)), aabb1.intersects(&aabb2));
+        assert_eq!(Obb3::from_aabb(&aabb1).intersects(&Obb3::from_aabb(&aabb3)), aabb1.intersects(&aabb3));
+    }
+
+    #[test]
+    fn test_obb_transform_isometry() {
+        let obb = rotated_box();
+        let isometry = Isometry3::from_parts(
+            &Translation3::new(3_f64, 1_f64, -2_f64),
+            &Rotation3::from_angle_y(Degrees(60_f64)),
+        );
+        let result = obb.transform_isometry(&isometry);
+        let expected_corners = obb.corners().map(|corner| isometry.apply_point(&corner));
+        let result_corners = result.corners();
+
+        for i in 0..8 {
+            assert_relative_eq!(result_corners[i], expected_corners[i], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+}
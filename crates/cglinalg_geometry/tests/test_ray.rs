@@ -0,0 +1,678 @@
+#[cfg(test)]
+mod ray2_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Point2,
+        Vector2,
+    };
+    use cglinalg_geometry::{
+        Aabb2,
+        Ray2,
+        Sphere2,
+    };
+
+
+    #[test]
+    fn test_ray_at() {
+        let ray = Ray2::new(Point2::new(1_f64, 2_f64), Vector2::new(3_f64, -1_f64));
+
+        assert_eq!(ray.at(0_f64), ray.origin());
+        assert_eq!(ray.at(2_f64), Point2::new(7_f64, 0_f64));
+        assert_eq!(ray.at(-1_f64), Point2::new(-2_f64, 3_f64));
+    }
+
+    #[test]
+    fn test_ray_intersect_aabb_axis_parallel_miss() {
+        let aabb = Aabb2::new(Point2::new(-1_f64, -1_f64), Point2::new(1_f64, 1_f64));
+        let ray = Ray2::new(Point2::new(-5_f64, 2_f64), Vector2::new(1_f64, 0_f64));
+
+        assert!(ray.intersect_aabb(&aabb).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_aabb_diagonal() {
+        let aabb = Aabb2::new(Point2::new(1_f64, 1_f64), Point2::new(3_f64, 2_f64));
+        let ray = Ray2::new(Point2::new(0_f64, 0_f64), Vector2::new(1_f64, 1_f64));
+        let hit = ray.intersect_aabb(&aabb).unwrap();
+
+        assert_eq!(hit.distance(), 1_f64);
+        assert_eq!(ray.at(hit.distance()), Point2::new(1_f64, 1_f64));
+    }
+
+    #[test]
+    fn test_ray_intersect_aabb_zero_direction_inside() {
+        let aabb = Aabb2::new(Point2::new(-1_f64, -1_f64), Point2::new(1_f64, 1_f64));
+        let ray = Ray2::new(Point2::new(1_f64 / 2_f64, 0_f64), Vector2::zero());
+        let hit = ray.intersect_aabb(&aabb).unwrap();
+
+        assert_eq!(hit.distance(), 0_f64);
+        assert_eq!(hit.normal(), Vector2::zero());
+    }
+
+    #[test]
+    fn test_ray_intersect_aabb_zero_direction_outside() {
+        let aabb = Aabb2::new(Point2::new(-1_f64, -1_f64), Point2::new(1_f64, 1_f64));
+        let ray = Ray2::new(Point2::new(5_f64, 0_f64), Vector2::zero());
+
+        assert!(ray.intersect_aabb(&aabb).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_aabb_behind() {
+        let aabb = Aabb2::new(Point2::new(-1_f64, -1_f64), Point2::new(1_f64, 1_f64));
+        let ray = Ray2::new(Point2::new(5_f64, 0_f64), Vector2::new(1_f64, 0_f64));
+
+        assert!(ray.intersect_aabb(&aabb).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_sphere_two_dimensions() {
+        let ray = Ray2::new(Point2::new(-5_f64, 0_f64), Vector2::new(2_f64, 0_f64));
+        let hit = ray.intersect_sphere(&Sphere2::new(Point2::origin(), 1_f64)).unwrap();
+
+        assert_eq!(hit.distance(), 2_f64);
+        assert_eq!(hit.normal(), Vector2::new(-1_f64, 0_f64));
+    }
+}
+
+#[cfg(test)]
+mod ray3_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix4x4,
+        Point3,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_geometry::{
+        Aabb3,
+        Capsule3,
+        Cone3,
+        Cylinder3,
+        Obb3,
+        Ray3,
+        Sphere3,
+    };
+    use cglinalg_transform::{
+        Isometry3,
+        Reflection3,
+        Rotation3,
+        Scale3,
+        Shear3,
+        Similarity3,
+        Transform3,
+        Translation3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn unit_cube() -> Aabb3<f64> {
+        Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(1_f64, 1_f64, 1_f64))
+    }
+
+    #[test]
+    fn test_ray_from_points() {
+        let origin = Point3::new(1_f64, 1_f64, 1_f64);
+        let target = Point3::new(4_f64, 5_f64, 1_f64);
+        let ray = Ray3::from_points(&origin, &target);
+
+        assert_eq!(ray.origin(), origin);
+        assert_relative_eq!(
+            ray.direction(),
+            Vector3::new(3_f64 / 5_f64, 4_f64 / 5_f64, 0_f64),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+        assert_relative_eq!(ray.at(5_f64), target, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ray_intersect_plane() {
+        let ray = Ray3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(1_f64, 1_f64, 1_f64));
+        let point = Point3::new(0_f64, 0_f64, 6_f64);
+        let normal = Vector3::new(0_f64, 0_f64, -3_f64);
+        let hit = ray.intersect_plane(&point, &normal).unwrap();
+
+        assert_eq!(hit.distance(), 3_f64);
+        assert_eq!(hit.normal(), -Vector3::unit_z());
+        assert_eq!(ray.at(hit.distance()), Point3::new(4_f64, 5_f64, 6_f64));
+    }
+
+    #[test]
+    fn test_ray_intersect_plane_parallel() {
+        let ray = Ray3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(1_f64, 1_f64, 0_f64));
+        let point = Point3::new(0_f64, 0_f64, 6_f64);
+        let normal = Vector3::unit_z();
+
+        assert!(ray.intersect_plane(&point, &normal).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_plane_behind() {
+        let ray = Ray3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+        let point = Point3::new(0_f64, 0_f64, 6_f64);
+        let normal = Vector3::unit_z();
+
+        assert!(ray.intersect_plane(&point, &normal).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_sphere_outside() {
+        let center = Point3::new(1_f64, 2_f64, 3_f64);
+        let ray = Ray3::new(Point3::new(1_f64, 2_f64, -7_f64), Vector3::new(0_f64, 0_f64, 1_f64));
+        let hit = ray.intersect_sphere(&Sphere3::new(center, 4_f64)).unwrap();
+
+        assert_eq!(hit.distance(), 6_f64);
+        assert_eq!(hit.normal(), -Vector3::unit_z());
+    }
+
+    #[test]
+    fn test_ray_intersect_sphere_inside() {
+        let center = Point3::new(1_f64, 2_f64, 3_f64);
+        let ray = Ray3::new(center, Vector3::new(0_f64, 2_f64, 0_f64));
+        let hit = ray.intersect_sphere(&Sphere3::new(center, 4_f64)).unwrap();
+
+        assert_eq!(hit.distance(), 2_f64);
+        assert_eq!(hit.normal(), Vector3::unit_y());
+    }
+
+    #[test]
+    fn test_ray_intersect_sphere_miss() {
+        let ray = Ray3::new(Point3::new(0_f64, 3_f64, -10_f64), Vector3::new(0_f64, 0_f64, 1_f64));
+
+        assert!(ray.intersect_sphere(&Sphere3::new(Point3::origin(), 2_f64)).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_sphere_behind() {
+        let ray = Ray3::new(Point3::new(0_f64, 0_f64, 10_f64), Vector3::new(0_f64, 0_f64, 1_f64));
+
+        assert!(ray.intersect_sphere(&Sphere3::new(Point3::origin(), 2_f64)).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_sphere_zero_direction_inside() {
+        let ray = Ray3::new(Point3::new(1_f64, 0_f64, 0_f64), Vector3::zero());
+        let hit = ray.intersect_sphere(&Sphere3::new(Point3::origin(), 2_f64)).unwrap();
+
+        assert_eq!(hit.distance(), 0_f64);
+        assert_eq!(hit.normal(), Vector3::zero());
+    }
+
+    #[test]
+    fn test_ray_intersect_sphere_zero_direction_outside() {
+        let ray = Ray3::new(Point3::new(5_f64, 0_f64, 0_f64), Vector3::zero());
+
+        assert!(ray.intersect_sphere(&Sphere3::new(Point3::origin(), 2_f64)).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_sphere_zero_radius() {
+        let center = Point3::new(1_f64, 2_f64, 3_f64);
+        let ray = Ray3::new(Point3::new(1_f64, 2_f64, -7_f64), Vector3::new(0_f64, 0_f64, 1_f64));
+        let hit = ray.intersect_sphere(&Sphere3::new(center, 0_f64)).unwrap();
+
+        assert_eq!(hit.distance(), 10_f64);
+        assert_eq!(hit.normal(), Vector3::zero());
+    }
+
+    #[test]
+    fn test_ray_intersect_sphere_zero_radius_miss() {
+        let ray = Ray3::new(Point3::new(0_f64, 1_f64, -7_f64), Vector3::new(0_f64, 0_f64, 1_f64));
+
+        assert!(ray.intersect_sphere(&Sphere3::new(Point3::origin(), 0_f64)).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_aabb_faces() {
+        let aabb = unit_cube();
+        let directions = [
+            Vector3::unit_x(),
+            -Vector3::unit_x(),
+            Vector3::unit_y(),
+            -Vector3::unit_y(),
+            Vector3::unit_z(),
+            -Vector3::unit_z(),
+        ];
+        for direction in directions.iter() {
+            let ray = Ray3::new(Point3::from_vector(&(-direction * 5_f64)), *direction);
+            let hit = ray.intersect_aabb(&aabb).unwrap();
+
+            assert_eq!(hit.distance(), 4_f64);
+            assert_eq!(hit.normal(), -direction);
+        }
+    }
+
+    #[test]
+    fn test_ray_intersect_aabb_inside() {
+        let aabb = unit_cube();
+        let ray = Ray3::new(Point3::new(0_f64, 0_f64, 0_f64), Vector3::new(0_f64, -1_f64, 0_f64));
+        let hit = ray.intersect_aabb(&aabb).unwrap();
+
+        assert_eq!(hit.distance(), 1_f64);
+        assert_eq!(hit.normal(), -Vector3::unit_y());
+    }
+
+    #[test]
+    fn test_ray_intersect_aabb_miss() {
+        let aabb = unit_cube();
+        let ray = Ray3::new(Point3::new(-5_f64, 0_f64, 0_f64), Vector3::new(1_f64, 1_f64, 0_f64));
+
+        assert!(ray.intersect_aabb(&aabb).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_aabb_agrees_with_sphere_bound() {
+        // Any hit of the box lies between the inscribed and circumscribed spheres of the box.
+        let aabb = unit_cube();
+        let ray = Ray3::new(Point3::new(-4_f64, 3_f64, 2_f64), Vector3::new(5_f64, -3_f64, -2_f64));
+        let hit = ray.intersect_aabb(&aabb).unwrap();
+        let inner = ray.intersect_sphere(&Sphere3::new(Point3::origin(), 1_f64)).unwrap();
+        let outer = ray.intersect_sphere(&Sphere3::new(Point3::origin(), f64::sqrt(3_f64))).unwrap();
+
+        assert!(outer.distance() <= hit.distance());
+        assert!(hit.distance() <= inner.distance());
+    }
+
+    #[test]
+    fn test_ray_intersect_triangle() {
+        let a = Point3::new(1_f64, 0_f64, 0_f64);
+        let b = Point3::new(0_f64, 1_f64, 0_f64);
+        let c = Point3::new(0_f64, 0_f64, 1_f64);
+        let ray = Ray3::new(Point3::origin(), Vector3::new(1_f64, 1_f64, 1_f64));
+        let hit = ray.intersect_triangle(&a, &b, &c).unwrap();
+        let expected_normal = Vector3::new(1_f64, 1_f64, 1_f64) / f64::sqrt(3_f64);
+        let expected_barycentric = Vector3::new(1_f64 / 3_f64, 1_f64 / 3_f64, 1_f64 / 3_f64);
+
+        assert_relative_eq!(hit.distance(), 1_f64 / 3_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(hit.normal(), expected_normal, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(hit.barycentric(), expected_barycentric, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ray_intersect_triangle_barycentric_reconstructs_hit_point() {
+        let a = Point3::new(-1_f64, -2_f64, 3_f64);
+        let b = Point3::new(4_f64, 0_f64, 2_f64);
+        let c = Point3::new(0_f64, 5_f64, 1_f64);
+        let ray = Ray3::new(Point3::new(1_f64, 1_f64, 10_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+        let hit = ray.intersect_triangle(&a, &b, &c).unwrap();
+        let weights = hit.barycentric();
+        let expected = ray.at(hit.distance());
+        let result = Point3::from_vector(&(a.to_vector() * weights[0] + b.to_vector() * weights[1] + c.to_vector() * weights[2]));
+
+        assert_relative_eq!(weights.x + weights.y + weights.z, 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ray_intersect_triangle_back_face() {
+        let a = Point3::new(0_f64, 0_f64, 0_f64);
+        let b = Point3::new(4_f64, 0_f64, 0_f64);
+        let c = Point3::new(0_f64, 4_f64, 0_f64);
+        let ray = Ray3::new(Point3::new(1_f64, 1_f64, -2_f64), Vector3::new(0_f64, 0_f64, 1_f64));
+        let hit = ray.intersect_triangle(&a, &b, &c).unwrap();
+
+        assert_eq!(hit.distance(), 2_f64);
+        assert_eq!(hit.normal(), Vector3::unit_z());
+    }
+
+    #[test]
+    fn test_ray_intersect_triangle_miss() {
+        let a = Point3::new(0_f64, 0_f64, 0_f64);
+        let b = Point3::new(4_f64, 0_f64, 0_f64);
+        let c = Point3::new(0_f64, 4_f64, 0_f64);
+        let ray = Ray3::new(Point3::new(3_f64, 3_f64, 2_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+
+        assert!(ray.intersect_triangle(&a, &b, &c).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_triangle_parallel() {
+        let a = Point3::new(0_f64, 0_f64, 0_f64);
+        let b = Point3::new(4_f64, 0_f64, 0_f64);
+        let c = Point3::new(0_f64, 4_f64, 0_f64);
+        let ray = Ray3::new(Point3::new(1_f64, 1_f64, 0_f64), Vector3::new(1_f64, 0_f64, 0_f64));
+
+        assert!(ray.intersect_triangle(&a, &b, &c).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_capsule_side() {
+        let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 4_f64, 0_f64), 1_f64);
+        let ray = Ray3::new(Point3::new(0_f64, 2_f64, -5_f64), Vector3::new(0_f64, 0_f64, 2_f64));
+        let hit = ray.intersect_capsule(&capsule).unwrap();
+
+        assert_eq!(hit.distance(), 2_f64);
+        assert_eq!(hit.normal(), -Vector3::unit_z());
+    }
+
+    #[test]
+    fn test_ray_intersect_capsule_hemisphere() {
+        let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 4_f64, 0_f64), 1_f64);
+        let ray = Ray3::new(Point3::new(0_f64, -5_f64, 0_f64), Vector3::unit_y());
+        let hit = ray.intersect_capsule(&capsule).unwrap();
+
+        assert_eq!(hit.distance(), 4_f64);
+        assert_eq!(hit.normal(), -Vector3::unit_y());
+    }
+
+    #[test]
+    fn test_ray_intersect_capsule_inside_exits_through_hemisphere() {
+        let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 4_f64, 0_f64), 1_f64);
+        let ray = Ray3::new(Point3::new(0_f64, 2_f64, 0_f64), -Vector3::unit_y());
+        let hit = ray.intersect_capsule(&capsule).unwrap();
+
+        assert_eq!(hit.distance(), 3_f64);
+        assert_eq!(hit.normal(), -Vector3::unit_y());
+    }
+
+    #[test]
+    fn test_ray_intersect_capsule_miss() {
+        let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 4_f64, 0_f64), 1_f64);
+        let ray = Ray3::new(Point3::new(2_f64, -5_f64, 0_f64), Vector3::unit_y());
+
+        assert!(ray.intersect_capsule(&capsule).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_capsule_degenerate_is_sphere() {
+        let center = Point3::new(1_f64, 2_f64, 3_f64);
+        let capsule = Capsule3::new(center, center, 2_f64);
+        let ray = Ray3::new(Point3::new(1_f64, 2_f64, -7_f64), Vector3::unit_z());
+
+        assert_eq!(ray.intersect_capsule(&capsule), ray.intersect_sphere(&Sphere3::new(center, 2_f64)));
+    }
+
+    #[test]
+    fn test_ray_intersect_capsule_zero_radius() {
+        let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 4_f64, 0_f64), 0_f64);
+        let ray = Ray3::new(Point3::new(-5_f64, 2_f64, 0_f64), Vector3::unit_x());
+
+        assert!(ray.intersect_capsule(&capsule).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_cylinder_side() {
+        let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(4_f64, 0_f64, 0_f64), 2_f64);
+        let ray = Ray3::new(Point3::new(1_f64, -5_f64, 0_f64), Vector3::unit_y());
+        let hit = ray.intersect_cylinder(&cylinder).unwrap();
+
+        assert_eq!(hit.distance(), 3_f64);
+        assert_eq!(hit.normal(), -Vector3::unit_y());
+    }
+
+    #[test]
+    fn test_ray_intersect_cylinder_cap() {
+        let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(4_f64, 0_f64, 0_f64), 2_f64);
+        let ray = Ray3::new(Point3::new(-3_f64, 1_f64, 1_f64), Vector3::unit_x());
+        let hit = ray.intersect_cylinder(&cylinder).unwrap();
+
+        assert_eq!(hit.distance(), 3_f64);
+        assert_eq!(hit.normal(), -Vector3::unit_x());
+    }
+
+    #[test]
+    fn test_ray_intersect_cylinder_inside() {
+        let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(4_f64, 0_f64, 0_f64), 2_f64);
+        let ray = Ray3::new(Point3::new(1_f64, 0_f64, 0_f64), Vector3::unit_x());
+        let hit = ray.intersect_cylinder(&cylinder).unwrap();
+
+        assert_eq!(hit.distance(), 3_f64);
+        assert_eq!(hit.normal(), Vector3::unit_x());
+    }
+
+    #[test]
+    fn test_ray_intersect_cylinder_miss_beyond_cap() {
+        let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(4_f64, 0_f64, 0_f64), 2_f64);
+        let ray = Ray3::new(Point3::new(5_f64, -5_f64, 0_f64), Vector3::unit_y());
+
+        assert!(ray.intersect_cylinder(&cylinder).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_cylinder_zero_radius() {
+        let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(4_f64, 0_f64, 0_f64), 0_f64);
+        let ray = Ray3::new(Point3::new(1_f64, -5_f64, 0_f64), Vector3::unit_y());
+
+        assert!(ray.intersect_cylinder(&cylinder).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_cone_base() {
+        let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 3_f64), 1_f64, 0_f64);
+        let ray = Ray3::new(Point3::new(0.5_f64, 0_f64, -2_f64), Vector3::unit_z());
+        let hit = ray.intersect_cone(&cone).unwrap();
+
+        assert_eq!(hit.distance(), 2_f64);
+        assert_eq!(hit.normal(), -Vector3::unit_z());
+    }
+
+    #[test]
+    fn test_ray_intersect_cone_near_apex() {
+        let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 3_f64), 1_f64, 0_f64);
+        let ray = Ray3::new(Point3::new(0.1_f64, 0_f64, 5_f64), -Vector3::unit_z());
+        let hit = ray.intersect_cone(&cone).unwrap();
+        let expected_normal = Vector3::new(3_f64, 0_f64, 1_f64) / f64::sqrt(10_f64);
+
+        assert_relative_eq!(hit.distance(), 2.3_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(hit.normal(), expected_normal, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ray_intersect_cone_side_of_frustum() {
+        let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 1_f64, 3_f64);
+        let ray = Ray3::new(Point3::new(-10_f64, 0_f64, 1_f64), Vector3::unit_x());
+        let hit = ray.intersect_cone(&cone).unwrap();
+        let expected_normal = Vector3::new(-1_f64, 0_f64, -1_f64) / f64::sqrt(2_f64);
+
+        assert_relative_eq!(hit.distance(), 8_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(hit.normal(), expected_normal, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ray_intersect_cone_miss() {
+        let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 3_f64), 1_f64, 0_f64);
+        let ray = Ray3::new(Point3::new(-5_f64, 0_f64, 2.9_f64), Vector3::new(1_f64, 0.5_f64, 0_f64));
+
+        assert!(ray.intersect_cone(&cone).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_cone_zero_radii() {
+        let cone = Cone3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 3_f64), 0_f64, 0_f64);
+        let ray = Ray3::new(Point3::new(-5_f64, 0_f64, 1_f64), Vector3::unit_x());
+
+        assert!(ray.intersect_cone(&cone).is_none());
+    }
+
+    #[test]
+    fn test_ray_intersect_obb() {
+        let center = Point3::new(0_f64, 0_f64, -10_f64);
+        let rotation = Rotation3::from_angle_z(Degrees(90_f64));
+        let half_extents = Vector3::new(1_f64, 2_f64, 3_f64);
+        let obb = Obb3::new(center, half_extents, rotation);
+        let ray = Ray3::new(Point3::new(-10_f64, 0_f64, -10_f64), Vector3::unit_x());
+        let hit = ray.intersect_obb(&obb).unwrap();
+
+        assert_relative_eq!(hit.distance(), 8_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(hit.normal(), -Vector3::unit_x(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ray_intersect_obb_matches_rotated_frame() {
+        let center = Point3::new(3_f64, 4_f64, 0_f64);
+        let rotation = Rotation3::from_angle_z(Degrees(30_f64));
+        let half_extents = Vector3::new(2_f64, 1_f64, 1_f64);
+        let obb = Obb3::new(center, half_extents, rotation);
+        let ray = Ray3::new(Point3::new(-10_f64, 4_f64, 0_f64), Vector3::new(1_f64, 0_f64, 0_f64));
+        let hit = ray.intersect_obb(&obb).unwrap();
+        let hit_point = ray.at(hit.distance());
+        let local_hit_point = rotation.inverse_apply_vector(&(hit_point - center));
+        let local_normal = rotation.inverse_apply_vector(&hit.normal());
+
+        // The hit point lies on the boundary of the box, on the face whose normal is reported.
+        assert!(local_hit_point[0].abs() <= half_extents[0] + 1e-10);
+        assert!(local_hit_point[1].abs() <= half_extents[1] + 1e-10);
+        assert!(local_hit_point[2].abs() <= half_extents[2] + 1e-10);
+        assert_relative_eq!(
+            local_hit_point.dot(&local_normal).abs(),
+            half_extents.dot(&local_normal.component_mul(&local_normal)),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+        assert!(hit.normal().dot(&ray.direction()) < 0_f64);
+    }
+
+    #[test]
+    fn test_ray_intersect_obb_miss() {
+        let center = Point3::new(3_f64, 4_f64, 0_f64);
+        let rotation = Rotation3::from_angle_z(Degrees(45_f64));
+        let half_extents = Vector3::new(1_f64, 1_f64, 1_f64);
+        let obb = Obb3::new(center, half_extents, rotation);
+        let ray = Ray3::new(Point3::new(-10_f64, 6_f64, 0_f64), Vector3::new(1_f64, 0_f64, 0_f64));
+
+        assert!(ray.intersect_obb(&obb).is_none());
+    }
+
+    #[test]
+    fn test_ray_transform_translation() {
+        let ray = Ray3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(4_f64, 5_f64, 6_f64));
+        let translation = Translation3::from_vector(&Vector3::new(1_f64, 1_f64, 1_f64));
+        let expected = Ray3::new(Point3::new(2_f64, 3_f64, 4_f64), Vector3::new(4_f64, 5_f64, 6_f64));
+        let result = ray.transform_translation(&translation);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ray_transform_scale_preserves_ray_parameter() {
+        let ray = Ray3::new(Point3::new(0_f64, 0_f64, 10_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+        let scale = Scale3::from_nonuniform_scale(&Vector3::new(2_f64, 3_f64, 4_f64));
+        let center = Point3::new(0_f64, 0_f64, 1_f64);
+        let hit = ray.intersect_sphere(&Sphere3::new(center, 1_f64)).unwrap();
+        let transformed_ray = ray.transform_scale(&scale);
+        let transformed_plane_point = scale.apply_point(&Point3::new(0_f64, 0_f64, 2_f64));
+        let transformed_hit = transformed_ray.intersect_plane(&transformed_plane_point, &Vector3::unit_z()).unwrap();
+
+        assert_eq!(transformed_hit.distance(), hit.distance());
+    }
+
+    #[test]
+    fn test_ray_transform_rotation() {
+        let ray = Ray3::new(Point3::new(1_f64, 0_f64, 0_f64), Vector3::new(1_f64, 0_f64, 0_f64));
+        let rotation = Rotation3::from_angle_z(Degrees(90_f64));
+        let expected = Ray3::new(Point3::new(0_f64, 1_f64, 0_f64), Vector3::new(0_f64, 1_f64, 0_f64));
+        let result = ray.transform_rotation(&rotation);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ray_transform_shear() {
+        let ray = Ray3::new(Point3::new(1_f64, 2_f64, 3_f64), Vector3::new(0_f64, 1_f64, 0_f64));
+        let shear = Shear3::from_shear_xy(2_f64);
+        let expected = Ray3::new(shear.apply_point(&ray.origin()), shear.apply_vector(&ray.direction()));
+        let result = ray.transform_shear(&shear);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ray_transform_reflection() {
+        let normal = Unit::from_value(Vector3::unit_z());
+        let bias = Point3::new(0_f64, 0_f64, 1_f64);
+        let reflection = Reflection3::from_normal_bias(&normal, &bias);
+        let ray = Ray3::new(Point3::new(1_f64, 2_f64, 5_f64), Vector3::new(0_f64, 0_f64, -1_f64));
+        let expected = Ray3::new(Point3::new(1_f64, 2_f64, -3_f64), Vector3::new(0_f64, 0_f64, 1_f64));
+        let result = ray.transform_reflection(&reflection);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ray_transform_isometry_intersect_obb() {
+        // Transforming the ray into the local frame of an oriented box reduces the
+        // intersection to an axis-aligned box query.
+        let rotation = Rotation3::from_axis_angle(&Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64)), Degrees(37_f64));
+        let translation = Translation3::from_vector(&Vector3::new(4_f64, -2_f64, 1_f64));
+        let isometry = Isometry3::from_parts(&translation, &rotation);
+        let half_extents = Vector3::new(1_f64, 2_f64, 3_f64);
+        let ray = Ray3::new(Point3::new(-10_f64, -1_f64, 0_f64), Vector3::new(1_f64, 0_f64, 0_f64));
+        let obb = Obb3::new(Point3::from_vector(translation.vector()), half_extents, rotation);
+        let hit = ray.intersect_obb(&obb).unwrap();
+        let local_ray = ray.transform_isometry(&isometry.inverse());
+        let local_aabb = Aabb3::from_center_half_extents(&Point3::origin(), &half_extents);
+        let local_hit = local_ray.intersect_aabb(&local_aabb).unwrap();
+
+        assert_relative_eq!(hit.distance(), local_hit.distance(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(
+            hit.normal(),
+            rotation.apply_vector(&local_hit.normal()),
+            abs_diff_all <= 1e-10,
+            relative_all <= f64::EPSILON,
+        );
+    }
+
+    #[test]
+    fn test_ray_transform_similarity() {
+        let similarity = Similarity3::from_parts(
+            &Translation3::from_vector(&Vector3::new(1_f64, 2_f64, 3_f64)),
+            &Rotation3::from_angle_y(Degrees(90_f64)),
+            2_f64,
+        );
+        let ray = Ray3::new(Point3::new(1_f64, 0_f64, 0_f64), Vector3::new(0_f64, 0_f64, 1_f64));
+        let expected = Ray3::new(Point3::new(1_f64, 2_f64, 1_f64), Vector3::new(2_f64, 0_f64, 0_f64));
+        let result = ray.transform_similarity(&similarity);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_ray_transform_affine() {
+        #[rustfmt::skip]
+        let matrix = Matrix4x4::new(
+            2_f64, 0_f64, 0_f64, 0_f64,
+            1_f64, 3_f64, 0_f64, 0_f64,
+            0_f64, 0_f64, 4_f64, 0_f64,
+            5_f64, 6_f64, 7_f64, 1_f64,
+        );
+        let transform = Transform3::from_matrix_unchecked(matrix);
+        let ray = Ray3::new(Point3::new(1_f64, 1_f64, 1_f64), Vector3::new(0_f64, 1_f64, 0_f64));
+        let expected = Ray3::new(Point3::new(8_f64, 9_f64, 11_f64), Vector3::new(1_f64, 3_f64, 0_f64));
+        let result = ray.transform(&transform).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ray_transform_projective() {
+        #[rustfmt::skip]
+        let matrix = Matrix4x4::new(
+            1_f64, 0_f64, 0_f64, 0_f64,
+            0_f64, 1_f64, 0_f64, 1_f64,
+            0_f64, 0_f64, 1_f64, 0_f64,
+            0_f64, 0_f64, 0_f64, 1_f64,
+        );
+        let transform = Transform3::from_matrix_unchecked(matrix);
+        let ray = Ray3::new(Point3::new(2_f64, 1_f64, 0_f64), Vector3::new(0_f64, 1_f64, 0_f64));
+        let expected = Ray3::new(Point3::new(1_f64, 0.5_f64, 0_f64), Vector3::new(-0.5_f64, 0.25_f64, 0_f64));
+        let result = ray.transform(&transform).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_ray_transform_projective_origin_behind() {
+        #[rustfmt::skip]
+        let matrix = Matrix4x4::new(
+            1_f64, 0_f64, 0_f64, 0_f64,
+            0_f64, 1_f64, 0_f64, 1_f64,
+            0_f64, 0_f64, 1_f64, 0_f64,
+            0_f64, 0_f64, 0_f64, 1_f64,
+        );
+        let transform = Transform3::from_matrix_unchecked(matrix);
+        let ray = Ray3::new(Point3::new(0_f64, -2_f64, 0_f64), Vector3::new(1_f64, 0_f64, 0_f64));
+
+        assert!(ray.transform(&transform).is_none());
+    }
+}
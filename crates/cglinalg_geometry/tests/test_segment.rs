@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod segment2_tests {
+    use cglinalg_core::Point2;
+    use cglinalg_geometry::Segment2;
+
+
+    #[test]
+    fn test_segment_midpoint() {
+        let segment = Segment2::new(Point2::new(1_f64, 2_f64), Point2::new(5_f64, -2_f64));
+
+        assert_eq!(segment.midpoint(), Point2::new(3_f64, 0_f64));
+    }
+
+    #[test]
+    fn test_segment_closest_point_degenerate() {
+        let point = Point2::new(1_f64, 2_f64);
+        let segment = Segment2::new(point, point);
+
+        assert_eq!(segment.closest_point(&Point2::new(4_f64, 6_f64)), point);
+        assert_eq!(segment.distance_squared(&Point2::new(4_f64, 6_f64)), 25_f64);
+    }
+
+    #[test]
+    fn test_segment_closest_points_crossing() {
+        let segment1 = Segment2::new(Point2::new(0_f64, 0_f64), Point2::new(4_f64, 4_f64));
+        let segment2 = Segment2::new(Point2::new(0_f64, 4_f64), Point2::new(4_f64, 0_f64));
+        let (point1, point2) = segment1.closest_points_segment(&segment2);
+
+        assert_eq!(point1, Point2::new(2_f64, 2_f64));
+        assert_eq!(point2, Point2::new(2_f64, 2_f64));
+    }
+}
+
+#[cfg(test)]
+mod segment3_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::Point3;
+    use cglinalg_geometry::Segment3;
+
+
+    /// Compute the squared distance between two segments by sampling both segments.
+    fn distance_squared_sampled(segment1: &Segment3<f64>, segment2: &Segment3<f64>) -> f64 {
+        let mut result = f64::INFINITY;
+        for i in 0..=200 {
+            for j in 0..=200 {
+                let point1 = segment1.at(i as f64 / 200_f64);
+                let point2 = segment2.at(j as f64 / 200_f64);
+                result = result.min((point1 - point2).norm_squared());
+            }
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_segment_closest_point_interior() {
+        let segment = Segment3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 10_f64));
+        let point = Point3::new(3_f64, 4_f64, 6_f64);
+
+        assert_eq!(segment.closest_point(&point), Point3::new(0_f64, 0_f64, 6_f64));
+        assert_eq!(segment.distance_squared(&point), 25_f64);
+    }
+
+    #[test]
+    fn test_segment_closest_point_endpoints() {
+        let segment = Segment3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 10_f64));
+
+        assert_eq!(segment.closest_point(&Point3::new(1_f64, 1_f64, -5_f64)), segment.start());
+        assert_eq!(segment.closest_point(&Point3::new(1_f64, 1_f64, 15_f64)), segment.end());
+    }
+
+    #[test]
+    fn test_segment_closest_points_skew_interior() {
+        let segment1 = Segment3::new(Point3::new(-1_f64, 0_f64, 0_f64), Point3::new(1_f64, 0_f64, 0_f64));
+        let segment2 = Segment3::new(Point3::new(0_f64, -1_f64, 2_f64), Point3::new(0_f64, 1_f64, 2_f64));
+        let (point1, point2) = segment1.closest_points_segment(&segment2);
+
+        assert_eq!(point1, Point3::new(0_f64, 0_f64, 0_f64));
+        assert_eq!(point2, Point3::new(0_f64, 0_f64, 2_f64));
+    }
+
+    #[test]
+    fn test_segment_closest_points_parallel_overlapping() {
+        let segment1 = Segment3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(4_f64, 0_f64, 0_f64));
+        let segment2 = Segment3::new(Point3::new(2_f64, 1_f64, 0_f64), Point3::new(6_f64, 1_f64, 0_f64));
+
+        assert_eq!(segment1.distance_squared_segment(&segment2), 1_f64);
+    }
+
+    #[test]
+    fn test_segment_closest_points_degenerate() {
+        let segment1 = Segment3::new(Point3::new(1_f64, 1_f64, 1_f64), Point3::new(1_f64, 1_f64, 1_f64));
+        let segment2 = Segment3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(4_f64, 0_f64, 0_f64));
+        let (point1, point2) = segment1.closest_points_segment(&segment2);
+        let (point3, point4) = segment2.closest_points_segment(&segment1);
+
+        assert_eq!(point1, segment1.start());
+        assert_eq!(point2, Point3::new(1_f64, 0_f64, 0_f64));
+        assert_eq!(point3, Point3::new(1_f64, 0_f64, 0_f64));
+        assert_eq!(point4, segment1.start());
+    }
+
+    #[test]
+    fn test_segment_closest_points_agrees_with_sampling() {
+        let segments = [
+            Segment3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(4_f64, 1_f64, -2_f64)),
+            Segment3::new(Point3::new(5_f64, 3_f64, 1_f64), Point3::new(6_f64, -2_f64, 0_f64)),
+            Segment3::new(Point3::new(-2_f64, 4_f64, 3_f64), Point3::new(1_f64, 1_f64, -1_f64)),
+            Segment3::new(Point3::new(3_f64, -3_f64, 2_f64), Point3::new(-1_f64, 2_f64, 2_f64)),
+        ];
+        for segment1 in segments.iter() {
+            for segment2 in segments.iter() {
+                let result = segment1.distance_squared_segment(segment2);
+                let sampled = distance_squared_sampled(segment1, segment2);
+
+                assert!(result <= sampled + 1e-12);
+                assert_relative_eq!(result, sampled, abs_diff_all <= 1e-2, relative_all <= f64::EPSILON);
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+#[cfg(test)]
+mod sphere2_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::Point2;
+    use cglinalg_geometry::Sphere2;
+
+
+    #[test]
+    fn test_sphere_from_points_welzl_single_point() {
+        let point = Point2::new(3_f64, -1_f64);
+        let expected = Sphere2::new(point, 0_f64);
+        let result = Sphere2::from_points_welzl(&[point]).unwrap();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_sphere_from_points_welzl_two_points() {
+        let points = [Point2::new(-1_f64, 2_f64), Point2::new(3_f64, 2_f64)];
+        let expected = Sphere2::new(Point2::new(1_f64, 2_f64), 2_f64);
+        let result = Sphere2::from_points_welzl(&points).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sphere_from_points_welzl_obtuse_triangle() {
+        // The minimal enclosing circle of an obtuse triangle has the longest edge as
+        // a diameter, rather than being the circumcircle of the triangle.
+        let points = [Point2::new(-2_f64, 0_f64), Point2::new(2_f64, 0_f64), Point2::new(0_f64, 1_f64)];
+        let expected = Sphere2::new(Point2::new(0_f64, 0_f64), 2_f64);
+        let result = Sphere2::from_points_welzl(&points).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sphere_from_points_welzl_acute_triangle() {
+        let points = [Point2::new(0_f64, 0_f64), Point2::new(2_f64, 0_f64), Point2::new(1_f64, 2_f64)];
+        let expected = Sphere2::new(Point2::new(1_f64, 3_f64 / 4_f64), 5_f64 / 4_f64);
+        let result = Sphere2::from_points_welzl(&points).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sphere_from_points_welzl_collinear() {
+        let points = [
+            Point2::new(0_f64, 0_f64),
+            Point2::new(1_f64, 1_f64),
+            Point2::new(2_f64, 2_f64),
+            Point2::new(3_f64, 3_f64),
+        ];
+        let expected = Sphere2::new(Point2::new(3_f64 / 2_f64, 3_f64 / 2_f64), 3_f64 * f64::sqrt(2_f64) / 2_f64);
+        let result = Sphere2::from_points_welzl(&points).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sphere_from_points_welzl_circle() {
+        let points: [Point2<f64>; 32] = core::array::from_fn(|i| {
+            let angle = (i as f64) * core::f64::consts::TAU / 32_f64;
+            Point2::new(5_f64 + 2_f64 * angle.cos(), -1_f64 + 2_f64 * angle.sin())
+        });
+        let expected = Sphere2::new(Point2::new(5_f64, -1_f64), 2_f64);
+        let result = Sphere2::from_points_welzl(&points).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sphere_from_points_welzl_spiral() {
+        // Points sorted outward along a spiral each fall outside the enclosing circle
+        // of the points before them, which makes Welzl's algorithm take cubic time
+        // when it visits the points in the order given.
+        let points: [Point2<f64>; 20000] = core::array::from_fn(|i| {
+            let t = (i as f64) / 20000_f64;
+            let angle = t * 200_f64 * core::f64::consts::TAU;
+            Point2::new(t * angle.cos(), t * angle.sin())
+        });
+        let max_norm = points.iter().fold(0_f64, |max_norm, point| max_norm.max(point.to_vector().norm()));
+        let result = Sphere2::from_points_welzl(&points).unwrap();
+
+        assert!(result.radius() <= max_norm + 1e-10);
+        for point in points.iter() {
+            assert!((point - result.center()).norm() <= result.radius() + 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_sphere_from_points_empty() {
+        assert!(Sphere2::<f64>::from_points_welzl(&[]).is_none());
+        assert!(Sphere2::<f64>::from_points_ritter(&[]).is_none());
+    }
+}
+
+#[cfg(test)]
+mod sphere3_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Point3,
+        Vector3,
+    };
+    use cglinalg_geometry::{
+        Aabb3,
+        Sphere3,
+    };
+    use cglinalg_transform::{
+        Isometry3,
+        Rotation3,
+        Translation3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    /// A deterministic pseudorandom point cloud.
+    fn point_cloud() -> [Point3<f64>; 200] {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            (state >> 11) as f64 / (1_u64 << 53) as f64 * 20_f64 - 10_f64
+        };
+
+        core::array::from_fn(|_| Point3::new(next(), next(), 0.5_f64 * next()))
+    }
+
+    fn contains_approx(sphere: &Sphere3<f64>, point: &Point3<f64>) -> bool {
+        (point - sphere.center()).norm() <= sphere.radius() + 1e-10
+    }
+
+    #[test]
+    fn test_sphere_from_points_welzl_tetrahedron() {
+        let points = [
+            Point3::new(1_f64, 1_f64, 1_f64),
+            Point3::new(1_f64, -1_f64, -1_f64),
+            Point3::new(-1_f64, 1_f64, -1_f64),
+            Point3::new(-1_f64, -1_f64, 1_f64),
+        ];
+        let expected = Sphere3::new(Point3::origin(), f64::sqrt(3_f64));
+        let result = Sphere3::from_points_welzl(&points).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sphere_from_points_welzl_cube_corners() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, -2_f64, -3_f64), Point3::new(3_f64, 2_f64, 1_f64));
+        let points = [
+            Point3::new(-1_f64, -2_f64, -3_f64),
+            Point3::new(3_f64, -2_f64, -3_f64),
+            Point3::new(-1_f64, 2_f64, -3_f64),
+            Point3::new(3_f64, 2_f64, -3_f64),
+            Point3::new(-1_f64, -2_f64, 1_f64),
+            Point3::new(3_f64, -2_f64, 1_f64),
+            Point3::new(-1_f64, 2_f64, 1_f64),
+            Point3::new(3_f64, 2_f64, 1_f64),
+        ];
+        let expected = Sphere3::from_aabb(&aabb);
+        let result = Sphere3::from_points_welzl(&points).unwrap();
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_sphere_from_points_welzl_contains_points() {
+        let points = point_cloud();
+        let sphere = Sphere3::from_points_welzl(&points).unwrap();
+
+        for point in points.iter() {
+            assert!(contains_approx(&sphere, point));
+        }
+    }
+
+    #[test]
+    fn test_sphere_from_points_welzl_is_minimal() {
+        // The minimal enclosing sphere has at least two of the points on its boundary,
+        // and shrinking it even slightly about any of the points leaves some point outside.
+        let points = point_cloud();
+        let sphere = Sphere3::from_points_welzl(&points).unwrap();
+        let boundary_count = points
+            .iter()
+            .filter(|point| ((*point - sphere.center()).norm() - sphere.radius()).abs() <= 1e-8)
+            .count();
+
+        assert!(boundary_count >= 2);
+
+        let shrunk = Sphere3::new(sphere.center(), sphere.radius() * (1_f64 - 1e-6));
+
+        assert!(points.iter().any(|point| !shrunk.contains(point)));
+    }
+
+    #[test]
+    fn test_sphere_from_points_ritter_contains_points() {
+        let points = point_cloud();
+        let sphere = Sphere3::from_points_ritter(&points).unwrap();
+
+        for point in points.iter() {
+            assert!(contains_approx(&sphere, point));
+        }
+    }
+
+    #[test]
+    fn test_sphere_from_points_ritter_bounds_welzl() {
+        let points = point_cloud();
+        let ritter = Sphere3::from_points_ritter(&points).unwrap();
+        let welzl = Sphere3::from_points_welzl(&points).unwrap();
+
+        assert!(welzl.radius() <= ritter.radius() + 1e-10);
+        assert!(ritter.radius() <= 1.2_f64 * welzl.radius());
+    }
+
+    #[test]
+    fn test_sphere_merge_contains_both() {
+        let sphere1 = Sphere3::new(Point3::new(1_f64, 2_f64, 3_f64), 2_f64);
+        let sphere2 = Sphere3::new(Point3::new(-4_f64, 0_f64, 5_f64), 3_f64);
+        let merged = sphere1.merge(&sphere2);
+        let expected_radius = ((sphere2.center() - sphere1.center()).norm() + 5_f64) / 2_f64;
+
+        assert_relative_eq!(merged.radius(), expected_radius, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert!(merged.radius() >= sphere1.radius() && merged.radius() >= sphere2.radius());
+        assert!((sphere1.center() - merged.center()).norm() + sphere1.radius() <= merged.radius() + 1e-10);
+        assert!((sphere2.center() - merged.center()).norm() + sphere2.radius() <= merged.radius() + 1e-10);
+    }
+
+    #[test]
+    fn test_sphere_merge_nested() {
+        let outer = Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 5_f64);
+        let inner = Sphere3::new(Point3::new(1_f64, 1_f64, 1_f64), 1_f64);
+
+        assert_eq!(outer.merge(&inner), outer);
+        assert_eq!(inner.merge(&outer), outer);
+    }
+
+    #[test]
+    fn test_sphere_union_point_inside() {
+        let sphere = Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 5_f64);
+        let point = Point3::new(1_f64, 1_f64, 1_f64);
+
+        assert_eq!(sphere.union_point(&point), sphere);
+    }
+
+    #[test]
+    fn test_sphere_intersects_tangent() {
+        let sphere1 = Sphere3::new(Point3::new(0_f64, 0_f64, 0_f64), 1_f64);
+        let sphere2 = Sphere3::new(Point3::new(0_f64, 0_f64, 3_f64), 2_f64);
+
+        assert!(sphere1.intersects(&sphere2));
+        assert!(sphere2.intersects(&sphere1));
+    }
+
+    #[test]
+    fn test_sphere_intersects_aabb_corner() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, -1_f64, -1_f64), Point3::new(1_f64, 1_f64, 1_f64));
+        let corner = Point3::new(2_f64, 2_f64, 2_f64);
+
+        assert!(!Sphere3::new(corner, 1.7_f64).intersects_aabb(&aabb));
+        assert!(Sphere3::new(corner, 1.75_f64).intersects_aabb(&aabb));
+    }
+
+    #[test]
+    fn test_sphere_to_aabb_contains_sphere_bounding_box() {
+        let sphere = Sphere3::new(Point3::new(1_f64, -2_f64, 3_f64), 2_f64);
+        let aabb = sphere.to_aabb();
+
+        assert_eq!(aabb.center(), sphere.center());
+        assert_eq!(aabb.half_extents(), Vector3::new(2_f64, 2_f64, 2_f64));
+    }
+
+    #[test]
+    fn test_sphere_transform_isometry() {
+        let sphere = Sphere3::new(Point3::new(1_f64, 0_f64, 0_f64), 2_f64);
+        let isometry = Isometry3::from_parts(
+            &Translation3::new(0_f64, 0_f64, 1_f64),
+            &Rotation3::from_angle_z(Degrees(90_f64)),
+        );
+        let expected = Sphere3::new(Point3::new(0_f64, 1_f64, 1_f64), 2_f64);
+        let result = sphere.transform_isometry(&isometry);
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
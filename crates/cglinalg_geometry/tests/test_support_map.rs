@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod support_map_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Point3,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_geometry::{
+        Aabb3,
+        Capsule3,
+        Cylinder3,
+        Obb3,
+        PointCloud3,
+        Segment3,
+        Sphere3,
+        SupportMap,
+        Triangle3,
+    };
+    use cglinalg_transform::{
+        Isometry3,
+        Rotation3,
+        Translation3,
+    };
+    use cglinalg_trigonometry::Degrees;
+
+
+    fn directions() -> [Vector3<f64>; 8] {
+        [
+            Vector3::new(1_f64, 0_f64, 0_f64),
+            Vector3::new(0_f64, -1_f64, 0_f64),
+            Vector3::new(0_f64, 0_f64, 2_f64),
+            Vector3::new(1_f64, 1_f64, 1_f64),
+            Vector3::new(-1_f64, 2_f64, -3_f64),
+            Vector3::new(0.5_f64, -0.25_f64, 4_f64),
+            Vector3::new(-3_f64, -1_f64, 0.5_f64),
+            Vector3::new(2_f64, -5_f64, 1_f64),
+        ]
+    }
+
+    fn isometry() -> Isometry3<f64> {
+        let axis = Unit::from_value(Vector3::new(2_f64, -1_f64, 3_f64));
+
+        Isometry3::from_parts(&Translation3::new(1_f64, -2_f64, 4_f64), &Rotation3::from_axis_angle(&axis, Degrees(70_f64)))
+    }
+
+    #[test]
+    fn test_support_map_sphere() {
+        let sphere = Sphere3::new(Point3::new(1_f64, 2_f64, 3_f64), 2_f64);
+        let direction = Vector3::new(0_f64, 3_f64, 4_f64);
+
+        assert_eq!(sphere.local_support_point(&direction), Point3::new(1_f64, 2_f64 + 6_f64 / 5_f64, 3_f64 + 8_f64 / 5_f64));
+        assert_eq!(sphere.local_support_point(&Vector3::zero()), sphere.center());
+    }
+
+    #[test]
+    fn test_support_map_aabb_corner() {
+        let aabb = Aabb3::new(Point3::new(-1_f64, -2_f64, -3_f64), Point3::new(4_f64, 5_f64, 6_f64));
+        let direction = Vector3::new(1_f64, -1_f64, 1_f64);
+
+        assert_eq!(aabb.local_support_point(&direction), Point3::new(4_f64, -2_f64, 6_f64));
+    }
+
+    #[test]
+    fn test_support_map_obb_maximizes_over_corners() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let obb = Obb3::new(
+            Point3::new(1_f64, -1_f64, 2_f64),
+            Vector3::new(3_f64, 2_f64, 1_f64),
+            Rotation3::from_axis_angle(&axis, Degrees(35_f64)),
+        );
+        let corners = obb.corners();
+        let corners = PointCloud3::new(&corners).unwrap();
+        for direction in directions().iter() {
+            let result = obb.local_support_point(direction);
+            let expected = corners.local_support_point(direction);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_support_map_point_cloud() {
+        let points = [
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(3_f64, 1_f64, 0_f64),
+            Point3::new(-1_f64, 4_f64, 2_f64),
+            Point3::new(1_f64, -2_f64, 5_f64),
+        ];
+        let point_cloud = PointCloud3::new(&points).unwrap();
+
+        assert_eq!(point_cloud.local_support_point(&Vector3::unit_x()), points[1]);
+        assert_eq!(point_cloud.local_support_point(&Vector3::unit_y()), points[2]);
+        assert_eq!(point_cloud.local_support_point(&Vector3::unit_z()), points[3]);
+        assert_eq!(point_cloud.local_support_point(&(-Vector3::unit_y())), points[3]);
+    }
+
+    #[test]
+    fn test_point_cloud_empty() {
+        let points: [Point3<f64>; 0] = [];
+
+        assert!(PointCloud3::new(&points).is_none());
+    }
+
+    #[test]
+    fn test_support_map_segment_and_triangle() {
+        let segment = Segment3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(1_f64, 1_f64, 0_f64));
+        let triangle = Triangle3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(2_f64, 0_f64, 0_f64), Point3::new(0_f64, 3_f64, 0_f64));
+
+        assert_eq!(segment.local_support_point(&Vector3::unit_x()), segment.end());
+        assert_eq!(segment.local_support_point(&(-Vector3::unit_x())), segment.start());
+        assert_eq!(triangle.local_support_point(&Vector3::unit_x()), triangle.b());
+        assert_eq!(triangle.local_support_point(&Vector3::unit_y()), triangle.c());
+    }
+
+    #[test]
+    fn test_support_map_capsule() {
+        let capsule = Capsule3::new(Point3::new(0_f64, 0_f64, -1_f64), Point3::new(0_f64, 0_f64, 1_f64), 0.5_f64);
+
+        assert_eq!(capsule.local_support_point(&Vector3::unit_z()), Point3::new(0_f64, 0_f64, 1.5_f64));
+        let direction = Vector3::new(0_f64, -1_f64, -1_f64);
+        let expected = Point3::new(0_f64, -0.5_f64 / f64::sqrt(2_f64), -1_f64 - 0.5_f64 / f64::sqrt(2_f64));
+
+        assert_relative_eq!(capsule.local_support_point(&direction), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_support_map_cylinder() {
+        let cylinder = Cylinder3::new(Point3::new(0_f64, 0_f64, 0_f64), Point3::new(0_f64, 0_f64, 2_f64), 1_f64);
+
+        assert_eq!(cylinder.local_support_point(&Vector3::new(1_f64, 0_f64, 1_f64)), Point3::new(1_f64, 0_f64, 2_f64));
+        assert_eq!(cylinder.local_support_point(&Vector3::new(0_f64, -3_f64, -4_f64)), Point3::new(0_f64, -1_f64, 0_f64));
+        assert_eq!(cylinder.local_support_point(&(-Vector3::unit_z())), Point3::new(0_f64, 0_f64, 0_f64));
+    }
+
+    #[test]
+    fn test_support_point_in_world_frame() {
+        let isometry = isometry();
+        let points = [
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(3_f64, 1_f64, 0_f64),
+            Point3::new(-1_f64, 4_f64, 2_f64),
+            Point3::new(1_f64, -2_f64, 5_f64),
+        ];
+        let world_points = points.map(|point| isometry.apply_point(&point));
+        let point_cloud = PointCloud3::new(&points).unwrap();
+        let world_point_cloud = PointCloud3::new(&world_points).unwrap();
+        for direction in directions().iter() {
+            let result = point_cloud.support_point(&isometry, direction);
+            let expected = world_point_cloud.local_support_point(direction);
+
+            assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+#[cfg(test)]
+mod triangle2_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Point2,
+        Vector3,
+    };
+    use cglinalg_geometry::{
+        Segment2,
+        Triangle2,
+    };
+
+
+    fn triangle() -> Triangle2<f64> {
+        Triangle2::new(Point2::new(1_f64, 1_f64), Point2::new(5_f64, 1_f64), Point2::new(1_f64, 4_f64))
+    }
+
+    #[test]
+    fn test_triangle_area() {
+        assert_eq!(triangle().area(), 6_f64);
+        assert_eq!(triangle().signed_area(), 6_f64);
+    }
+
+    #[test]
+    fn test_triangle_contains_vertices_and_edges() {
+        let triangle = triangle();
+
+        for vertex in triangle.vertices().iter() {
+            assert!(triangle.contains(vertex));
+        }
+        for edge in triangle.edges().iter() {
+            assert!(triangle.contains(&edge.midpoint()));
+        }
+        assert!(triangle.contains(&triangle.centroid()));
+    }
+
+    #[test]
+    fn test_triangle_contains_either_winding() {
+        let triangle = triangle();
+        let reversed = Triangle2::new(triangle.a(), triangle.c(), triangle.b());
+        let inside = Point2::new(2_f64, 2_f64);
+        let outside = Point2::new(4_f64, 3_f64);
+
+        assert!(reversed.signed_area() < 0_f64);
+        assert!(triangle.contains(&inside) && reversed.contains(&inside));
+        assert!(!triangle.contains(&outside) && !reversed.contains(&outside));
+    }
+
+    #[test]
+    fn test_triangle_barycentric_vertices() {
+        let triangle = triangle();
+
+        assert_eq!(triangle.barycentric(&triangle.a()).unwrap(), Vector3::unit_x());
+        assert_eq!(triangle.barycentric(&triangle.b()).unwrap(), Vector3::unit_y());
+        assert_eq!(triangle.barycentric(&triangle.c()).unwrap(), Vector3::unit_z());
+    }
+
+    #[test]
+    fn test_triangle_barycentric_roundtrip() {
+        let triangle = triangle();
+        let point = Point2::new(7_f64, -3_f64);
+        let barycentric = triangle.barycentric(&point).unwrap();
+
+        assert_relative_eq!(barycentric[0] + barycentric[1] + barycentric[2], 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(triangle.from_barycentric(&barycentric), point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_triangle_barycentric_degenerate() {
+        let triangle = Triangle2::new(Point2::new(0_f64, 0_f64), Point2::new(1_f64, 1_f64), Point2::new(2_f64, 2_f64));
+
+        assert!(triangle.barycentric(&Point2::new(1_f64, 0_f64)).is_none());
+        assert_eq!(triangle.area(), 0_f64);
+    }
+
+    #[test]
+    fn test_triangle_closest_points_segment_boundary() {
+        let triangle = triangle();
+        let segment = Segment2::new(Point2::new(3_f64, 0_f64), Point2::new(3_f64, -2_f64));
+        let (point_triangle, point_segment) = triangle.closest_points_segment(&segment);
+
+        assert_eq!(point_triangle, Point2::new(3_f64, 1_f64));
+        assert_eq!(point_segment, Point2::new(3_f64, 0_f64));
+
+        let through = Segment2::new(Point2::new(0_f64, 2_f64), Point2::new(3_f64, 2_f64));
+        let (point_triangle, point_segment) = triangle.closest_points_segment(&through);
+
+        assert_eq!(point_triangle, point_segment);
+
+        let outside = Segment2::new(Point2::new(0_f64, 2_f64), Point2::new(-3_f64, 2_f64));
+        let (point_triangle, point_segment) = triangle.closest_points_segment(&outside);
+
+        assert_eq!(point_triangle, Point2::new(1_f64, 2_f64));
+        assert_eq!(point_segment, Point2::new(0_f64, 2_f64));
+    }
+
+    #[test]
+    fn test_triangle_closest_points_segment_inside() {
+        let triangle = triangle();
+        let segment = Segment2::new(Point2::new(1.5_f64, 1.5_f64), Point2::new(2_f64, 2_f64));
+        let (point_triangle, point_segment) = triangle.closest_points_segment(&segment);
+
+        assert_eq!(point_triangle, point_segment);
+    }
+}
+
+#[cfg(test)]
+mod triangle3_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Point3,
+        Vector3,
+    };
+    use cglinalg_geometry::{
+        Segment3,
+        Triangle3,
+    };
+
+
+    fn triangle() -> Triangle3<f64> {
+        Triangle3::new(Point3::new(1_f64, 0_f64, 0_f64), Point3::new(0_f64, 2_f64, 0_f64), Point3::new(0_f64, 0_f64, 3_f64))
+    }
+
+    /// Compute the point on a triangle closest to a point by sampling the triangle.
+    fn distance_squared_sampled(triangle: &Triangle3<f64>, point: &Point3<f64>) -> f64 {
+        let mut result = f64::INFINITY;
+        for i in 0..=100 {
+            for j in 0..=(100 - i) {
+                let barycentric = Vector3::new(1_f64 - (i + j) as f64 / 100_f64, i as f64 / 100_f64, j as f64 / 100_f64);
+                let sample = triangle.from_barycentric(&barycentric);
+                result = result.min((point - sample).norm_squared());
+            }
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_triangle_normal() {
+        let triangle = triangle();
+        let normal = triangle.normal().unwrap();
+        let expected = Vector3::new(6_f64, 3_f64, 2_f64) / 7_f64;
+
+        assert_relative_eq!(normal, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_triangle_normal_degenerate() {
+        let point = Point3::new(1_f64, 2_f64, 3_f64);
+        let triangle = Triangle3::new(point, point, Point3::new(4_f64, 5_f64, 6_f64));
+
+        assert!(triangle.normal().is_none());
+        assert!(triangle.to_hyperplane().is_none());
+    }
+
+    #[test]
+    fn test_triangle_to_hyperplane_contains_vertices() {
+        let triangle = triangle();
+        let plane = triangle.to_hyperplane().unwrap();
+
+        for vertex in triangle.vertices().iter() {
+            assert_relative_eq!(plane.signed_distance(vertex), 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_triangle_area_matches_cross_product() {
+        let triangle = triangle();
+        let expected = (triangle.b() - triangle.a()).cross(&(triangle.c() - triangle.a())).norm() / 2_f64;
+
+        assert_relative_eq!(triangle.area(), expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(triangle.area(), 7_f64 / 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_triangle_barycentric_projects_onto_plane() {
+        let triangle = triangle();
+        let inside = triangle.from_barycentric(&Vector3::new(0.2_f64, 0.3_f64, 0.5_f64));
+        let point = inside + triangle.normal().unwrap() * 5_f64;
+        let barycentric = triangle.barycentric(&point).unwrap();
+
+        assert_relative_eq!(triangle.from_barycentric(&barycentric), inside, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_triangle_closest_point_voronoi_regions() {
+        let triangle = triangle();
+        let normal = triangle.normal().unwrap();
+        let inside = triangle.centroid();
+        let above = inside + normal * 2_f64;
+
+        assert_relative_eq!(triangle.closest_point(&above), inside, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_eq!(triangle.closest_point(&Point3::new(3_f64, -1_f64, -1_f64)), triangle.a());
+        assert_eq!(triangle.closest_point(&Point3::new(-1_f64, 4_f64, -1_f64)), triangle.b());
+        assert_eq!(triangle.closest_point(&Point3::new(-1_f64, -1_f64, 5_f64)), triangle.c());
+    }
+
+    #[test]
+    fn test_triangle_closest_point_agrees_with_sampling() {
+        let triangle = triangle();
+        let points = [
+            Point3::new(2_f64, 2_f64, 2_f64),
+            Point3::new(-1_f64, -1_f64, -1_f64),
+            Point3::new(0.5_f64, 1.5_f64, -2_f64),
+            Point3::new(3_f64, -2_f64, 1_f64),
+            Point3::new(-2_f64, 1_f64, 4_f64),
+            Point3::new(0_f64, 3_f64, 3_f64),
+        ];
+        for point in points.iter() {
+            let result = triangle.distance_squared(point);
+            let sampled = distance_squared_sampled(&triangle, point);
+
+            assert!(result <= sampled + 1e-12);
+            assert_relative_eq!(result, sampled, abs_diff_all <= 1e-2, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_triangle_closest_points_segment_piercing() {
+        let triangle = triangle();
+        let centroid = triangle.centroid();
+        let normal = triangle.normal().unwrap();
+        let segment = Segment3::new(centroid - normal, centroid + normal * 2_f64);
+        let (point_triangle, point_segment) = triangle.closest_points_segment(&segment);
+
+        assert_relative_eq!(point_triangle, centroid, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_eq!(point_triangle, point_segment);
+    }
+
+    #[test]
+    fn test_triangle_closest_points_segment_not_reaching() {
+        let triangle = triangle();
+        let centroid = triangle.centroid();
+        let normal = triangle.normal().unwrap();
+        let segment = Segment3::new(centroid + normal, centroid + normal * 3_f64);
+        let (point_triangle, point_segment) = triangle.closest_points_segment(&segment);
+
+        assert_relative_eq!(point_triangle, centroid, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(point_segment, centroid + normal, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_triangle_closest_points_segment_edge() {
+        let triangle = Triangle3::new(
+            Point3::new(0_f64, 0_f64, 0_f64),
+            Point3::new(4_f64, 0_f64, 0_f64),
+            Point3::new(0_f64, 4_f64, 0_f64),
+        );
+        let segment = Segment3::new(Point3::new(1_f64, -2_f64, -1_f64), Point3::new(1_f64, -2_f64, 1_f64));
+        let (point_triangle, point_segment) = triangle.closest_points_segment(&segment);
+
+        assert_eq!(point_triangle, Point3::new(1_f64, 0_f64, 0_f64));
+        assert_eq!(point_segment, Point3::new(1_f64, -2_f64, 0_f64));
+    }
+}
@@ -0,0 +1,66 @@
+use cglinalg_core::Vector;
+use cglinalg_numeric::SimdScalarFloat;
+
+
+/// The largest magnitude of a distance or shear factor generated by
+/// [`finite_scalar`] and [`finite_vector`].
+const MAX_MAGNITUDE: i32 = 1024;
+
+/// The largest magnitude of the base two exponent of a scalar generated by
+/// [`positive_scalar`].
+const MAX_EXPONENT: i32 = 10;
+
+/// Generate a scalar in the closed interval `[min_value, max_value]` from
+/// unstructured fuzzer input.
+///
+/// Unlike generating the scalar directly, this never produces a non-finite
+/// value, so the constrained transformations built from it stay valid.
+pub(crate) fn scalar_in_range<S>(u: &mut arbitrary::Unstructured<'_>, min_value: S, max_value: S) -> arbitrary::Result<S>
+where
+    S: SimdScalarFloat,
+{
+    let bits: u32 = u.arbitrary()?;
+    let fraction = cglinalg_numeric::cast::<u32, S>(bits) / cglinalg_numeric::cast::<u32, S>(u32::MAX);
+
+    Ok(min_value + (max_value - min_value) * fraction)
+}
+
+/// Generate a finite scalar in the interval `[-1024, 1024]` from unstructured
+/// fuzzer input.
+pub(crate) fn finite_scalar<S>(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<S>
+where
+    S: SimdScalarFloat,
+{
+    let max_magnitude: S = cglinalg_numeric::cast(MAX_MAGNITUDE);
+
+    scalar_in_range(u, -max_magnitude, max_magnitude)
+}
+
+/// Generate a vector whose components are finite scalars in the interval
+/// `[-1024, 1024]` from unstructured fuzzer input.
+pub(crate) fn finite_vector<S, const N: usize>(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<Vector<S, N>>
+where
+    S: SimdScalarFloat,
+{
+    let mut vector = Vector::zero();
+    for i in 0..N {
+        vector[i] = finite_scalar(u)?;
+    }
+
+    Ok(vector)
+}
+
+/// Generate a positive scalar in the interval `[2^-10, 2^10]` from unstructured
+/// fuzzer input.
+///
+/// The exponent is generated uniformly, so small and large magnitudes are both
+/// well represented.
+pub(crate) fn positive_scalar<S>(u: &mut arbitrary::Unstructured<'_>) -> arbitrary::Result<S>
+where
+    S: SimdScalarFloat,
+{
+    let max_exponent: S = cglinalg_numeric::cast(MAX_EXPONENT);
+    let exponent = scalar_in_range(u, -max_exponent, max_exponent)?;
+
+    Ok(exponent.exp2())
+}
@@ -0,0 +1,519 @@
+use crate::isometry::Isometry2;
+use crate::projection::Orthographic2;
+use crate::rotation::Rotation2;
+use crate::similarity::Similarity2;
+use crate::translation::Translation2;
+use cglinalg_core::{
+    Matrix3x3,
+    Point2,
+    Vector2,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_trigonometry::Radians;
+
+use core::fmt;
+
+
+/// A two-dimensional camera.
+///
+/// A two-dimensional camera views a scene in the plane through an orthographic
+/// projection [`Orthographic2`]. The camera is placed in the scene by its
+/// **position**, which is the point of the world that appears at the origin of
+/// view space, its **angle**, which is the counterclockwise rotation of the camera
+/// relative to the world, and its **zoom**, which is the magnification of the
+/// world in view space. A zoom greater than one magnifies the scene, and a zoom
+/// less than one shrinks it.
+///
+/// # View Transformation
+///
+/// The camera maps a point `p` in world space to the view space point
+///
+/// ```text
+/// view(p) := zoom * R(-angle) * (p - position)
+/// ```
+///
+/// where `R(-angle)` is the rotation by `-angle`. The view transformation is a
+/// similarity transformation, and the projection then maps view space to
+/// normalized device coordinates.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "S: serde::Deserialize<'de> + Copy"))
+)]
+pub struct Camera2<S> {
+    projection: Orthographic2<S>,
+    position: Point2<S>,
+    angle: Radians<S>,
+    zoom: S,
+}
+
+impl<S> Camera2<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new two-dimensional camera.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_transform::{
+    /// #     Camera2,
+    /// #     Orthographic2,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let projection = Orthographic2::new(4_f64, 4_f64, 3_f64, 3_f64);
+    /// let position = Point2::new(10_f64, 20_f64);
+    /// let angle = Degrees(90_f64);
+    /// let zoom = 2_f64;
+    /// let camera = Camera2::new(&projection, &position, angle, zoom);
+    /// // The camera is rotated by a quarter turn, so the point one unit to the left
+    /// // of the camera position appears above the center of the screen.
+    /// let point = Point2::new(9_f64, 20_f64);
+    /// let expected = Point2::new(0_f64, 2_f64 / 3_f64);
+    /// let result = camera.project_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn new<A>(projection: &Orthographic2<S>, position: &Point2<S>, angle: A, zoom: S) -> Self
+    where
+        A: Into<Radians<S>>,
+    {
+        Self {
+            projection: *projection,
+            position: *position,
+            angle: angle.into(),
+            zoom,
+        }
+    }
+
+    /// Construct a new two-dimensional camera from its placement in the scene.
+    ///
+    /// The isometry `pose` maps the view space of the camera to world space, i.e. it
+    /// moves the camera from the origin to its position and orientation in the world.
+    /// This is the inverse of [`Camera2::view_isometry`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Vector2,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Camera2,
+    /// #     Isometry2,
+    /// #     Orthographic2,
+    /// # };
+    /// # use cglinalg_trigonometry::{
+    /// #     Degrees,
+    /// #     Radians,
+    /// # };
+    /// #
+    /// let projection = Orthographic2::new(4_f64, 4_f64, 3_f64, 3_f64);
+    /// let pose = Isometry2::from_angle_translation(Degrees(30_f64), &Vector2::new(10_f64, 20_f64));
+    /// let camera = Camera2::from_pose(&projection, &pose, 2_f64);
+    ///
+    /// assert_relative_eq!(camera.position(), Point2::new(10_f64, 20_f64), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(camera.angle(), Radians::from(Degrees(30_f64)), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(camera.zoom(), 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_pose(projection: &Orthographic2<S>, pose: &Isometry2<S>, zoom: S) -> Self {
+        let position = Point2::from_vector(pose.translation().vector());
+        let angle = pose.rotation().angle();
+
+        Self::new(projection, &position, angle, zoom)
+    }
+
+    /// Get the projection of the camera.
+    #[inline]
+    pub const fn projection(&self) -> &Orthographic2<S> {
+        &self.projection
+    }
+
+    /// Get the position of the camera in world space.
+    #[inline]
+    pub const fn position(&self) -> Point2<S> {
+        self.position
+    }
+
+    /// Get the counterclockwise rotation angle of the camera relative to world space.
+    #[inline]
+    pub const fn angle(&self) -> Radians<S> {
+        self.angle
+    }
+
+    /// Get the zoom factor of the camera.
+    #[inline]
+    pub const fn zoom(&self) -> S {
+        self.zoom
+    }
+
+    /// Set the projection of the camera.
+    #[inline]
+    pub fn set_projection(&mut self, projection: &Orthographic2<S>) {
+        self.projection = *projection;
+    }
+
+    /// Set the position of the camera in world space.
+    #[inline]
+    pub fn set_position(&mut self, position: &Point2<S>) {
+        self.position = *position;
+    }
+
+    /// Set the counterclockwise rotation angle of the camera relative to world space.
+    #[inline]
+    pub fn set_angle<A>(&mut self, angle: A)
+    where
+        A: Into<Radians<S>>,
+    {
+        self.angle = angle.into();
+    }
+
+    /// Set the zoom factor of the camera.
+    #[inline]
+    pub fn set_zoom(&mut self, zoom: S) {
+        self.zoom = zoom;
+    }
+
+    /// Move the camera by a displacement `distance` in world space.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Vector2,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Camera2,
+    /// #     Orthographic2,
+    /// # };
+    /// # use cglinalg_trigonometry::Radians;
+    /// #
+    /// let projection = Orthographic2::new(4_f64, 4_f64, 3_f64, 3_f64);
+    /// let mut camera = Camera2::new(&projection, &Point2::new(1_f64, 2_f64), Radians(0_f64), 1_f64);
+    /// camera.pan(&Vector2::new(3_f64, -1_f64));
+    ///
+    /// assert_eq!(camera.position(), Point2::new(4_f64, 1_f64));
+    /// ```
+    #[inline]
+    pub fn pan(&mut self, distance: &Vector2<S>) {
+        self.position += distance;
+    }
+
+    /// Move the camera by a displacement `distance` measured in normalized device
+    /// coordinates.
+    ///
+    /// This moves the scene on the screen in the opposite direction to `distance`, which
+    /// is convenient for dragging the view with a mouse: converting a mouse displacement
+    /// to normalized device coordinates and panning the camera by its negation keeps the
+    /// point under the cursor fixed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Vector2,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Camera2,
+    /// #     Orthographic2,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let projection = Orthographic2::new(4_f64, 4_f64, 3_f64, 3_f64);
+    /// let mut camera = Camera2::new(&projection, &Point2::new(1_f64, 2_f64), Degrees(90_f64), 2_f64);
+    /// let point = Point2::new(3_f64, 5_f64);
+    /// let ndc_point = camera.project_point(&point);
+    /// let drag = Vector2::new(1_f64 / 4_f64, -1_f64 / 8_f64);
+    /// camera.pan_ndc(&(-drag));
+    /// let expected = ndc_point + drag;
+    /// let result = camera.project_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn pan_ndc(&mut self, distance: &Vector2<S>) {
+        let world_distance = self.unproject_vector(distance);
+        self.position += world_distance;
+    }
+
+    /// Rotate the camera counterclockwise by an angle `angle`.
+    #[inline]
+    pub fn rotate<A>(&mut self, angle: A)
+    where
+        A: Into<Radians<S>>,
+    {
+        self.angle += angle.into();
+    }
+
+    /// Multiply the zoom factor of the camera by `factor`.
+    #[inline]
+    pub fn zoom_by(&mut self, factor: S) {
+        self.zoom *= factor;
+    }
+
+    /// Multiply the zoom factor of the camera by `factor` while keeping the
+    /// world space point `point` fixed on the screen.
+    ///
+    /// This is the usual behavior for zooming towards the mouse cursor.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_transform::{
+    /// #     Camera2,
+    /// #     Orthographic2,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let projection = Orthographic2::new(4_f64, 4_f64, 3_f64, 3_f64);
+    /// let mut camera = Camera2::new(&projection, &Point2::new(1_f64, 2_f64), Degrees(30_f64), 2_f64);
+    /// let point = Point2::new(3_f64, 5_f64);
+    /// let expected = camera.project_point(&point);
+    /// camera.zoom_by_about(3_f64, &point);
+    /// let result = camera.project_point(&point);
+    ///
+    /// assert_relative_eq!(camera.zoom(), 6_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn zoom_by_about(&mut self, factor: S, point: &Point2<S>) {
+        // The view transformation maps `point` to `zoom * R(-angle) * (point - position)`.
+        // Keeping this fixed while multiplying `zoom` by `factor` requires that
+        // `point - position_new == (point - position_old) / factor`.
+        let offset = (point - self.position) / factor;
+        self.position = point - offset;
+        self.zoom *= factor;
+    }
+
+    /// Get the isometry mapping world space to the view space of the camera,
+    /// without the zoom.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_transform::{
+    /// #     Camera2,
+    /// #     Orthographic2,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let projection = Orthographic2::new(4_f64, 4_f64, 3_f64, 3_f64);
+    /// let camera = Camera2::new(&projection, &Point2::new(1_f64, 2_f64), Degrees(90_f64), 2_f64);
+    /// let view_isometry = camera.view_isometry();
+    ///
+    /// assert_relative_eq!(
+    ///     view_isometry.apply_point(&Point2::new(1_f64, 2_f64)),
+    ///     Point2::origin(),
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// assert_relative_eq!(
+    ///     view_isometry.apply_point(&Point2::new(1_f64, 3_f64)),
+    ///     Point2::new(1_f64, 0_f64),
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// ```
+    #[inline]
+    pub fn view_isometry(&self) -> Isometry2<S> {
+        self.pose().inverse()
+    }
+
+    /// Get the isometry mapping the view space of the camera to world space,
+    /// without the zoom.
+    ///
+    /// This is the inverse of [`Camera2::view_isometry`].
+    #[inline]
+    pub fn pose(&self) -> Isometry2<S> {
+        let translation = Translation2::from_vector(&self.position.to_vector());
+        let rotation = Rotation2::from_angle(self.angle);
+
+        Isometry2::from_parts(&translation, &rotation)
+    }
+
+    /// Get the similarity transformation mapping world space to the view
+    /// space of the camera.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_transform::{
+    /// #     Camera2,
+    /// #     Orthographic2,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let projection = Orthographic2::new(4_f64, 4_f64, 3_f64, 3_f64);
+    /// let camera = Camera2::new(&projection, &Point2::new(1_f64, 2_f64), Degrees(90_f64), 2_f64);
+    /// let view = camera.view();
+    ///
+    /// assert_relative_eq!(
+    ///     view.apply_point(&Point2::new(1_f64, 3_f64)),
+    ///     Point2::new(2_f64, 0_f64),
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// ```
+    #[inline]
+    pub fn view(&self) -> Similarity2<S> {
+        Similarity2::from_scale(self.zoom) * Similarity2::from_isometry(&self.view_isometry())
+    }
+
+    /// Get the matrix mapping world space to normalized device coordinates.
+    ///
+    /// This is the product of the projection matrix and the affine matrix of
+    /// the view transformation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Camera2,
+    /// #     Orthographic2,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let projection = Orthographic2::new(4_f64, 4_f64, 3_f64, 3_f64);
+    /// let camera = Camera2::new(&projection, &Point2::new(1_f64, 2_f64), Degrees(30_f64), 2_f64);
+    /// let point = Point2::new(3_f64, 5_f64);
+    /// let expected = camera.project_point(&point).to_homogeneous();
+    /// let result = camera.view_projection_matrix() * point.to_homogeneous();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn view_projection_matrix(&self) -> Matrix3x3<S> {
+        self.projection.matrix() * self.view().to_affine_matrix()
+    }
+
+    /// Map a point in world space to normalized device coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_transform::{
+    /// #     Camera2,
+    /// #     Orthographic2,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let projection = Orthographic2::new(4_f64, 4_f64, 3_f64, 3_f64);
+    /// let camera = Camera2::new(&projection, &Point2::new(1_f64, 2_f64), Degrees(90_f64), 2_f64);
+    /// let point = Point2::new(1_f64, 3_f64);
+    /// let expected = Point2::new(1_f64 / 2_f64, 0_f64);
+    /// let result = camera.project_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn project_point(&self, point: &Point2<S>) -> Point2<S> {
+        let view_point = self.view().apply_point(point);
+
+        self.projection.project_point(&view_point)
+    }
+
+    /// Map a vector in world space to normalized device coordinates.
+    #[inline]
+    pub fn project_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        let view_vector = self.view().apply_vector(vector);
+
+        self.projection.project_vector(&view_vector)
+    }
+
+    /// Map a point in normalized device coordinates back to world space.
+    ///
+    /// This is the inverse operation of [`Camera2::project_point`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_transform::{
+    /// #     Camera2,
+    /// #     Orthographic2,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let projection = Orthographic2::new(4_f64, 4_f64, 3_f64, 3_f64);
+    /// let camera = Camera2::new(&projection, &Point2::new(1_f64, 2_f64), Degrees(30_f64), 2_f64);
+    /// let point = Point2::new(3_f64, 5_f64);
+    /// let expected = point;
+    /// let result = camera.unproject_point(&camera.project_point(&point));
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn unproject_point(&self, point: &Point2<S>) -> Point2<S> {
+        let view_point = self.projection.unproject_point(point);
+
+        self.view().inverse_apply_point(&view_point)
+    }
+
+    /// Map a vector in normalized device coordinates back to world space.
+    ///
+    /// This is the inverse operation of [`Camera2::project_vector`].
+    #[inline]
+    pub fn unproject_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        let view_vector = self.projection.unproject_vector(vector);
+
+        self.view().inverse_apply_vector(&view_vector)
+    }
+}
+
+/// Generate a two-dimensional camera with an arbitrary projection, a position
+/// whose coordinates lie in the interval `[-1024, 1024]`, an angle in the
+/// interval `[-pi, pi]` radians, and a positive zoom in the interval
+/// `[2^-10, 2^10]`.
+#[cfg(feature = "arbitrary")]
+impl<'a, S> arbitrary::Arbitrary<'a> for Camera2<S>
+where
+    S: SimdScalarFloat,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let projection: Orthographic2<S> = u.arbitrary()?;
+        let position = Point2::from_vector(&crate::arbitrary_support::finite_vector(u)?);
+        let angle = crate::arbitrary_support::scalar_in_range(u, -S::pi(), S::pi())?;
+        let zoom = crate::arbitrary_support::positive_scalar(u)?;
+
+        Ok(Camera2::new(&projection, &position, Radians(angle), zoom))
+    }
+}
+
+impl<S> fmt::Display for Camera2<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Camera2 [projection={}, position={}, angle={}, zoom={}]",
+            self.projection, self.position, self.angle, self.zoom
+        )
+    }
+}
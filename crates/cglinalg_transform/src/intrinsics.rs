@@ -0,0 +1,173 @@
+use cglinalg_core::Matrix3x3;
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// The intrinsic parameters of a pinhole camera.
+///
+/// The intrinsic parameters describe how a pinhole camera maps points in its
+/// coordinate frame onto the pixels of an image, following the conventions of
+/// OpenCV and most computer vision datasets. The parameters are
+///
+/// * `fx` and `fy`, the focal lengths of the camera measured in pixels along
+///   the rows and columns of the image respectively.
+/// * `cx` and `cy`, the pixel coordinates of the **principal point**, the point
+///   where the optical axis of the camera crosses the image.
+/// * `width` and `height`, the dimensions of the image in pixels.
+///
+/// # Coordinate Conventions
+///
+/// The camera coordinate frame is a right-handed orthonormal frame whose
+/// **positive z-axis** is the viewing direction, whose **positive x-axis** points
+/// right, and whose **positive y-axis** points down. The pixel coordinates `(u, v)`
+/// of a point `(x, y, z)` with `z > 0` in the camera frame are
+///
+/// ```text
+/// u == fx * (x / z) + cx
+/// v == fy * (y / z) + cy
+/// ```
+///
+/// The origin of the pixel coordinates is the center of the upper left pixel, the
+/// **u-axis** points right along the rows of the image, and the **v-axis** points down
+/// along the columns of the image. In particular, the image covers the pixel
+/// coordinates `[-1 / 2, width - 1 / 2] x [-1 / 2, height - 1 / 2]`.
+///
+/// The camera is assumed to have square pixel axes, i.e. the skew of the
+/// camera is zero.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct CameraIntrinsics<S> {
+    fx: S,
+    fy: S,
+    cx: S,
+    cy: S,
+    width: S,
+    height: S,
+}
+
+impl<S> CameraIntrinsics<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new set of pinhole camera intrinsic parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_transform::CameraIntrinsics;
+    /// #
+    /// let intrinsics = CameraIntrinsics::new(525_f64, 525_f64, 319.5_f64, 239.5_f64, 640_f64, 480_f64);
+    ///
+    /// assert_eq!(intrinsics.fx(), 525_f64);
+    /// assert_eq!(intrinsics.fy(), 525_f64);
+    /// assert_eq!(intrinsics.cx(), 319.5_f64);
+    /// assert_eq!(intrinsics.cy(), 239.5_f64);
+    /// assert_eq!(intrinsics.width(), 640_f64);
+    /// assert_eq!(intrinsics.height(), 480_f64);
+    /// ```
+    #[inline]
+    pub const fn new(fx: S, fy: S, cx: S, cy: S, width: S, height: S) -> Self {
+        Self {
+            fx,
+            fy,
+            cx,
+            cy,
+            width,
+            height,
+        }
+    }
+
+    /// Get the focal length in pixels along the **u-axis** of the image.
+    #[inline]
+    pub const fn fx(&self) -> S {
+        self.fx
+    }
+
+    /// Get the focal length in pixels along the **v-axis** of the image.
+    #[inline]
+    pub const fn fy(&self) -> S {
+        self.fy
+    }
+
+    /// Get the **u-coordinate** of the principal point in pixels.
+    #[inline]
+    pub const fn cx(&self) -> S {
+        self.cx
+    }
+
+    /// Get the **v-coordinate** of the principal point in pixels.
+    #[inline]
+    pub const fn cy(&self) -> S {
+        self.cy
+    }
+
+    /// Get the width of the image in pixels.
+    #[inline]
+    pub const fn width(&self) -> S {
+        self.width
+    }
+
+    /// Get the height of the image in pixels.
+    #[inline]
+    pub const fn height(&self) -> S {
+        self.height
+    }
+
+    /// Get the camera matrix of the intrinsic parameters.
+    ///
+    /// The camera matrix is the matrix
+    ///
+    /// ```text
+    /// [ fx  0   cx ]
+    /// [ 0   fy  cy ]
+    /// [ 0   0   1  ]
+    /// ```
+    ///
+    /// that maps a point `(x, y, z)` in the camera frame to the homogeneous pixel
+    /// coordinates `(z * u, z * v, z)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Matrix3x3;
+    /// # use cglinalg_transform::CameraIntrinsics;
+    /// #
+    /// let intrinsics = CameraIntrinsics::new(525_f64, 520_f64, 319.5_f64, 239.5_f64, 640_f64, 480_f64);
+    /// let expected = Matrix3x3::new(
+    ///     525_f64,   0_f64,     0_f64,
+    ///     0_f64,     520_f64,   0_f64,
+    ///     319.5_f64, 239.5_f64, 1_f64,
+    /// );
+    /// let result = intrinsics.to_matrix();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn to_matrix(&self) -> Matrix3x3<S> {
+        let zero = S::zero();
+        let one = S::one();
+
+        Matrix3x3::new(
+            self.fx,  zero,     zero,
+            zero,     self.fy,  zero,
+            self.cx,  self.cy,  one,
+        )
+    }
+}
+
+impl<S> fmt::Display for CameraIntrinsics<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "CameraIntrinsics [fx={}, fy={}, cx={}, cy={}, width={}, height={}]",
+            self.fx, self.fy, self.cx, self.cy, self.width, self.height
+        )
+    }
+}
@@ -52,6 +52,11 @@ pub type Isometry3<S> = Isometry<S, 3>;
 /// should use [`Isometry2`] or [`Isometry3`] instead of this type directly.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "S: SimdScalarFloat + serde::Deserialize<'de>"))
+)]
 pub struct Isometry<S, const N: usize> {
     /// The rotation component of an isometry.
     pub(crate) rotation: Rotation<S, N>,
@@ -1778,6 +1783,82 @@ where
 
         Self::from_parts(&translation, &rotation)
     }
+
+    /// Construct the view transformations for the six faces of a cube map centered
+    /// at the position `position`.
+    ///
+    /// The views follow the standard cube map face order and orientation used by
+    /// OpenGL, Vulkan, and Direct3D, i.e. the faces are ordered
+    /// `[+x, -x, +y, -y, +z, -z]`, and each face uses the following gaze direction
+    /// and up direction
+    ///
+    /// ```text
+    /// face | direction  | up
+    /// -----|------------|-----------
+    /// +x   | [+1, 0, 0] | [0, -1,  0]
+    /// -x   | [-1, 0, 0] | [0, -1,  0]
+    /// +y   | [0, +1, 0] | [0,  0, +1]
+    /// -y   | [0, -1, 0] | [0,  0, -1]
+    /// +z   | [0, 0, +1] | [0, -1,  0]
+    /// -z   | [0, 0, -1] | [0, -1,  0]
+    /// ```
+    ///
+    /// Each view is a right-handed view transformation constructed with
+    /// [`Isometry3::look_to_rh`]. Rendering the scene once with each view and the
+    /// projection [`Perspective3::cube_map`] produces the faces of a cube map, e.g.
+    /// an environment map or the depth map of a point light shadow, which samples
+    /// correctly with the world space direction from `position`.
+    ///
+    /// [`Perspective3::cube_map`]: crate::Perspective3::cube_map
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Isometry3;
+    /// #
+    /// let position = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let views = Isometry3::cube_map_views(&position);
+    /// let minus_unit_z = -Vector3::unit_z();
+    ///
+    /// // The positive x-axis face looks along the positive x-axis.
+    /// assert_relative_eq!(
+    ///     views[0].apply_vector(&Vector3::unit_x()),
+    ///     minus_unit_z,
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// // The negative y-axis face looks along the negative y-axis.
+    /// assert_relative_eq!(
+    ///     views[3].apply_vector(&(-Vector3::unit_y())),
+    ///     minus_unit_z,
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// // Every face is centered at the position.
+    /// for view in views.iter() {
+    ///     assert_relative_eq!(view.apply_point(&position), Point3::origin(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// }
+    /// ```
+    #[inline]
+    pub fn cube_map_views(position: &Point3<S>) -> [Self; 6] {
+        let zero = S::zero();
+        let one = S::one();
+        let faces = [
+            (Vector3::new(one, zero, zero), Vector3::new(zero, -one, zero)),
+            (Vector3::new(-one, zero, zero), Vector3::new(zero, -one, zero)),
+            (Vector3::new(zero, one, zero), Vector3::new(zero, zero, one)),
+            (Vector3::new(zero, -one, zero), Vector3::new(zero, zero, -one)),
+            (Vector3::new(zero, zero, one), Vector3::new(zero, -one, zero)),
+            (Vector3::new(zero, zero, -one), Vector3::new(zero, -one, zero)),
+        ];
+
+        faces.map(|(direction, up)| Self::look_to_rh(position, &direction, &up))
+    }
 }
 
 
@@ -1809,6 +1890,23 @@ impl<S, const N: usize> IsometryDiff<S, N> {
     }
 }
 
+/// Generate an isometry from an arbitrary rotation and a translation whose
+/// components lie in the interval `[-1024, 1024]`.
+#[cfg(feature = "arbitrary")]
+impl<'a, S, const N: usize> arbitrary::Arbitrary<'a> for Isometry<S, N>
+where
+    S: SimdScalarFloat,
+    Rotation<S, N>: arbitrary::Arbitrary<'a>,
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let rotation: Rotation<S, N> = u.arbitrary()?;
+        let translation = Translation::from_vector(&crate::arbitrary_support::finite_vector(u)?);
+
+        Ok(Isometry::from_parts(&translation, &rotation))
+    }
+}
+
 impl<S, const N: usize> approx_cmp::AbsDiffEq for Isometry<S, N>
 where
     S: SimdScalarFloat,
@@ -1,3 +1,7 @@
+#[cfg(feature = "arbitrary")]
+mod arbitrary_support;
+mod camera;
+mod intrinsics;
 mod isometry;
 mod projection;
 mod reflection;
@@ -5,12 +9,17 @@ mod rotation;
 mod scale;
 mod shear;
 mod similarity;
+#[cfg(feature = "proptest")]
+pub mod strategies;
 mod transform;
 mod translation;
+mod viewport;
 
 mod isometry_ops;
 
 
+pub use camera::*;
+pub use intrinsics::*;
 pub use isometry::*;
 pub use projection::*;
 pub use reflection::*;
@@ -20,5 +29,6 @@ pub use shear::*;
 pub use similarity::*;
 pub use transform::*;
 pub use translation::*;
+pub use viewport::*;
 
 // pub use isometry_ops::*;
@@ -1,17 +1,76 @@
-use crate::transform::Transform3;
+use crate::intrinsics::CameraIntrinsics;
+use crate::isometry::Isometry3;
+use crate::similarity::Similarity3;
+use crate::transform::{
+    Transform2,
+    Transform3,
+};
+use crate::viewport::Viewport;
 use cglinalg_core::{
+    Matrix3x3,
     Matrix4x4,
+    Normed,
+    Point2,
     Point3,
     Unit,
+    Vector2,
     Vector3,
 };
 use cglinalg_numeric::SimdScalarFloat;
-use cglinalg_trigonometry::Radians;
+use cglinalg_trigonometry::{
+    Angle,
+    Radians,
+};
 
 use core::fmt;
 use core::ops;
 
 
+/// Map a point in world space to window coordinates through a projection.
+///
+/// The function `project_point` maps camera view space to normalized device
+/// coordinates. See [`Perspective3::world_to_screen`] for details.
+#[inline]
+fn world_to_screen<S, F>(project_point: F, view: &Isometry3<S>, viewport: &Viewport<S>, point: &Point3<S>) -> Point3<S>
+where
+    S: SimdScalarFloat,
+    F: Fn(&Point3<S>) -> Point3<S>,
+{
+    let view_point = view.apply_point(point);
+    let ndc_point = project_point(&view_point);
+
+    viewport.ndc_to_window(&ndc_point)
+}
+
+/// Construct the ray in world space passing through a point in window coordinates
+/// through a projection.
+///
+/// The function `unproject_point` maps normalized device coordinates back to
+/// camera view space. See [`Perspective3::screen_to_world_ray`] for details.
+#[inline]
+fn screen_to_world_ray<S, F>(
+    unproject_point: F,
+    view: &Isometry3<S>,
+    viewport: &Viewport<S>,
+    pixel: &Point2<S>,
+) -> (Point3<S>, Vector3<S>)
+where
+    S: SimdScalarFloat,
+    F: Fn(&Point3<S>) -> Point3<S>,
+{
+    // The near and far planes map to the depths -1 and 1 in normalized device
+    // coordinates, independently of the depth range of the viewport.
+    let one = S::one();
+    let ndc_point = viewport.window_to_ndc(&Point3::new(pixel.x, pixel.y, viewport.min_depth()));
+    let near_view_point = unproject_point(&Point3::new(ndc_point.x, ndc_point.y, -one));
+    let far_view_point = unproject_point(&Point3::new(ndc_point.x, ndc_point.y, one));
+    let origin = view.inverse_apply_point(&near_view_point);
+    let direction = view.inverse_apply_vector(&(far_view_point - near_view_point)).normalize();
+
+    (origin, direction)
+}
+
+
 /// A perspective projection transformation.
 ///
 /// The data type represents a perspective projection transformation that follows
@@ -140,7 +199,14 @@ use core::ops;
 /// ```
 ///
 /// where the matrix entries are indexed in column-major order.
+#[repr(transparent)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent),
+    serde(bound(deserialize = "S: serde::Deserialize<'de> + Copy"))
+)]
 pub struct Perspective3<S> {
     matrix: Matrix4x4<S>,
 }
@@ -599,6 +665,178 @@ where
         }
     }
 
+    /// Construct a perspective projection matrix based on the `near`
+    /// plane, the `far` plane and the horizontal field of view angle `hfov` and
+    /// the horizontal/vertical aspect ratio `aspect_ratio`.
+    ///
+    /// This is the horizontal counterpart of [`Perspective3::from_vfov`]. The
+    /// perspective view volume is the symmetric frustum contained in
+    /// `[-right, right] x [-top, top] x [-far, -near]` where
+    ///
+    /// ```text
+    /// right == near * tan(hfov / 2)
+    /// top   == right / aspect_ratio
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let hfov = Degrees(90_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_hfov(hfov, aspect_ratio, near, far);
+    ///
+    /// assert_relative_eq!(perspective.hfov(),         hfov.into(),    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.aspect_ratio(), aspect_ratio,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.right(),        1_f64,          abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.top(),          3_f64 / 4_f64,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_hfov<A>(hfov: A, aspect_ratio: S, near: S, far: S) -> Self
+    where
+        A: Into<Radians<S>>,
+    {
+        let two = S::one() + S::one();
+        let spec_hfov = hfov.into();
+        let right = near * (spec_hfov / two).tan();
+        let top = right / aspect_ratio;
+
+        Self::new(right, right, top, top, near, far)
+    }
+
+    /// Construct a perspective projection matrix from the focal length of a
+    /// physical camera lens and the dimensions of the camera sensor.
+    ///
+    /// The parameters `focal_length`, `sensor_width`, and `sensor_height` are
+    /// measured in the same units, e.g. millimeters. The camera sensor is centered
+    /// on the optical axis, so the perspective view volume is the symmetric frustum
+    /// contained in `[-right, right] x [-top, top] x [-far, -near]` where
+    ///
+    /// ```text
+    /// right == near * (sensor_width / 2) / focal_length
+    /// top   == near * (sensor_height / 2) / focal_length
+    /// ```
+    ///
+    /// by similar triangles.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Perspective3;
+    /// #
+    /// // A 50mm lens on a full frame 36mm x 24mm sensor.
+    /// let focal_length = 50_f64;
+    /// let sensor_width = 36_f64;
+    /// let sensor_height = 24_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_focal_length_and_sensor_size(focal_length, sensor_width, sensor_height, near, far);
+    ///
+    /// assert_relative_eq!(perspective.right(),        18_f64 / 50_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.top(),          12_f64 / 50_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.aspect_ratio(), 3_f64 / 2_f64,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_focal_length_and_sensor_size(focal_length: S, sensor_width: S, sensor_height: S, near: S, far: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let right = near * (one_half * sensor_width) / focal_length;
+        let top = near * (one_half * sensor_height) / focal_length;
+
+        Self::new(right, right, top, top, near, far)
+    }
+
+    /// Construct a perspective projection matrix from the intrinsic parameters
+    /// of a pinhole camera.
+    ///
+    /// The resulting perspective projection maps the image of the camera onto the
+    /// canonical view volume, so that rendering with it into a viewport of the same
+    /// dimensions as the image reproduces the camera image pixel for pixel. See
+    /// [`CameraIntrinsics`] for the conventions of the intrinsic parameters. The
+    /// camera frame of the intrinsic parameters is related to view space by a
+    /// rotation by half a turn about the **x-axis**, i.e. the camera frame point
+    /// `(x, y, z)` is the view space point `(x, -y, -z)`. The principal point does
+    /// not need to be the center of the image, in which case the resulting frustum
+    /// is asymmetric.
+    ///
+    /// # Discussion
+    ///
+    /// The image covers the pixel coordinates `[-1 / 2, width - 1 / 2] x [-1 / 2, height - 1 / 2]`,
+    /// so by similar triangles, the frustum parameters on the **near plane** are
+    ///
+    /// ```text
+    /// left   == near * (cx + 1 / 2) / fx
+    /// right  == near * (width - 1 / 2 - cx) / fx
+    /// top    == near * (cy + 1 / 2) / fy
+    /// bottom == near * (height - 1 / 2 - cy) / fy
+    /// ```
+    ///
+    /// where the **top plane** corresponds to the first row of the image since the
+    /// **v-axis** of the image points down.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::{
+    /// #     CameraIntrinsics,
+    /// #     Perspective3,
+    /// # };
+    /// #
+    /// let intrinsics = CameraIntrinsics::new(500_f64, 500_f64, 319.5_f64, 239.5_f64, 640_f64, 480_f64);
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_intrinsics(&intrinsics, near, far);
+    ///
+    /// assert_relative_eq!(perspective.left(),   0.64_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.right(),  0.64_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.bottom(), 0.48_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(perspective.top(),    0.48_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn from_intrinsics(intrinsics: &CameraIntrinsics<S>, near: S, far: S) -> Self {
+        let one_half = S::one() / (S::one() + S::one());
+        let left = near * (intrinsics.cx() + one_half) / intrinsics.fx();
+        let right = near * (intrinsics.width() - one_half - intrinsics.cx()) / intrinsics.fx();
+        let top = near * (intrinsics.cy() + one_half) / intrinsics.fy();
+        let bottom = near * (intrinsics.height() - one_half - intrinsics.cy()) / intrinsics.fy();
+
+        Self::new(left, right, bottom, top, near, far)
+    }
+
+    /// Construct the perspective projection for rendering the faces of a cube map.
+    ///
+    /// The projection has a vertical and horizontal field of view of a quarter turn
+    /// and an aspect ratio of one, so the views of [`Isometry3::cube_map_views`]
+    /// cover the six faces of a cube centered at the eye position exactly. The
+    /// view volume is contained in `[-near, near] x [-near, near] x [-far, -near]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let near = 1_f64 / 10_f64;
+    /// let far = 50_f64;
+    /// let expected = Perspective3::from_vfov(Degrees(90_f64), 1_f64, near, far);
+    /// let result = Perspective3::cube_map(near, far);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn cube_map(near: S, far: S) -> Self {
+        // tan(vfov / 2) == tan(pi / 4) == 1, so every side plane lies at a
+        // distance `near` from the gaze axis on the **near plane**.
+        Self::new(near, near, near, near, near, far)
+    }
+
     /// Get the aspect ratio of the viewport represented by the perspective projection.
     ///
     /// The aspect ratio is the ratio of the width of the viewing plane of the view
@@ -832,6 +1070,102 @@ where
         Radians(vfov)
     }
 
+    /// Get the horizontal field of view angle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let hfov = Degrees(110_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_hfov(hfov, aspect_ratio, near, far);
+    /// let expected = hfov.into();
+    /// let result = perspective.hfov();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn hfov(&self) -> Radians<S> {
+        // Following the derivation of the vertical field of view, the horizontal
+        // field of view angle is the sum of the angles `angle_hfov_r` and `angle_hfov_l`
+        // between the gaze direction and the **right plane** and **left plane**
+        // respectively, where
+        // ```text
+        // tan(angle_hfov_r) == right / near == (m[2, 0] + 1) / m[0, 0]
+        // tan(angle_hfov_l) == left / near  == (1 - m[2, 0]) / m[0, 0]
+        // ```
+        // Summing the arctangents of each side separately remains correct for
+        // fields of view wider than a quarter turn.
+        //
+        let one = S::one();
+        let tan_hfov_r = (self.matrix[2][0] + one) / self.matrix[0][0];
+        let tan_hfov_l = (one - self.matrix[2][0]) / self.matrix[0][0];
+
+        Radians(tan_hfov_r.atan() + tan_hfov_l.atan())
+    }
+
+    /// Get the intrinsic parameters of the pinhole camera whose image of
+    /// dimensions `width` and `height` the perspective projection reproduces.
+    ///
+    /// This is the inverse operation of [`Perspective3::from_intrinsics`].
+    ///
+    /// # Discussion
+    ///
+    /// The matrix elements of the perspective projection satisfy
+    ///
+    /// ```text
+    /// m[0, 0] == (2 * near) / (right + left) == (2 * fx) / width
+    /// m[1, 1] == (2 * near) / (top + bottom) == (2 * fy) / height
+    /// m[2, 0] == (right - left) / (right + left) == (width - 1 - 2 * cx) / width
+    /// m[2, 1] == (top - bottom) / (top + bottom) == (2 * cy + 1 - height) / height
+    /// ```
+    ///
+    /// so that
+    ///
+    /// ```text
+    /// fx == m[0, 0] * (width / 2)
+    /// fy == m[1, 1] * (height / 2)
+    /// cx == (width * (1 - m[2, 0]) - 1) / 2
+    /// cy == (height * (1 + m[2, 1]) - 1) / 2
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::{
+    /// #     CameraIntrinsics,
+    /// #     Perspective3,
+    /// # };
+    /// #
+    /// let intrinsics = CameraIntrinsics::new(517.3_f64, 516.5_f64, 318.6_f64, 255.3_f64, 640_f64, 480_f64);
+    /// let near = 0.1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_intrinsics(&intrinsics, near, far);
+    /// let result = perspective.to_intrinsics(640_f64, 480_f64);
+    ///
+    /// assert_relative_eq!(result.fx(), intrinsics.fx(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.fy(), intrinsics.fy(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.cx(), intrinsics.cx(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.cy(), intrinsics.cy(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn to_intrinsics(&self, width: S, height: S) -> CameraIntrinsics<S> {
+        let one = S::one();
+        let one_half = one / (one + one);
+        let fx = self.matrix[0][0] * width * one_half;
+        let fy = self.matrix[1][1] * height * one_half;
+        let cx = (width * (one - self.matrix[2][0]) - one) * one_half;
+        let cy = (height * (one + self.matrix[2][1]) - one) * one_half;
+
+        CameraIntrinsics::new(fx, fy, cx, cy, width, height)
+    }
+
     /// Get the position of the **near plane** of the viewing
     /// frustum described by the perspective projection of the plane
     /// parallel to the **xy-plane** positioned along the **negative z-axis**.
@@ -1136,56 +1470,25 @@ where
         //             == [(right - left) + (right + left)] / (right + left)
         //             == (2 * right) / (right + left)
         // ```
-        // and
-        // ```text
-        // m[2, 0] - 1 == [(right - left) / (right + left)] - 1
-        //             == [(right - left) - (right + left))] / (right + left)
-        //             == -(2 * left) / (right + left)
-        // ```
-        // so that
-        // ```text
-        // (2 * right) / (right + left) == m[2, 0] + 1
-        // (2 * left) / (right + left) == -m[2, 0] + 1
-        // ```
-        // Define
+        // Using the matrix element `m[0, 0]`
         // ```text
-        // ratio := right / left
+        // 1 / m[0, 0] == (right + left) / (2 * near)
         // ```
         // so that
         // ```text
-        // ratio == right / left
-        //       == (2 * right) / (2 * left)
-        //       == [(2 * right) / (right + left)] / [(2 * left) / (right + left)]
-        //       == (m[2, 0] + 1) / (-m[2, 0] + 1)
-        // ```
-        // and
-        // ```text
-        // left == right / ratio
-        // ```
-        // Using matrix element `m[0, 0]`
-        // ```text
-        // m[0, 0] == (2 * near) / (right + left)
-        // ```
-        // or equivalently
-        // ```text
-        // 2 * near / m[0, 0] == (2 * near) * (1 / m[0, 0])
-        //                    == right + left
-        //                    == right + right / ratio
-        //                    == (1 + (1 / ratio)) * right
-        //                    == ((ratio + 1) / ratio) * right
+        // (m[2, 0] + 1) / m[0, 0] == [(2 * right) / (right + left)] * [(right + left) / (2 * near)]
+        //                         == right / near
         // ```
         // We obtain
         // ```text
-        // right == [(2 * near) * (ratio / (ratio + 1))] * (1 / m[0, 0])
+        // right == near * [(m[2, 0] + 1) / m[0, 0]]
         // ```
         // which is the desired formula.
         //
         let one = S::one();
-        let two = one + one;
-        let ratio = (self.matrix[2][0] + one) / (-self.matrix[2][0] + one);
         let near = self.near();
 
-        ((two * near) * (ratio / (ratio + one))) * (one / self.matrix[0][0])
+        near * ((self.matrix[2][0] + one) / self.matrix[0][0])
     }
 
     /// Get the position of the **left plane** of the viewing
@@ -1250,59 +1553,29 @@ where
         // ```
         // Observe that
         // ```text
-        // m[2, 0] + 1 == [(right - left) / (right + left)] + 1
-        //             == [(right - left) + (right + left)] / (right + left)
-        //             == (2 * right) / (right + left)
-        // ```
-        // and
-        // ```text
-        // m[2, 0] - 1 == [(right - left) / (right + left)] - 1
-        //             == [(right - left) - (right + left))] / (right + left)
-        //             == -(2 * left) / (right + left)
-        // ```
-        // so that
-        // ```text
-        // (2 * right) / (right + left) == m[2, 0] + 1
-        // (2 * left) / (right + left) == -m[2, 0] + 1
+        // 1 - m[2, 0] == 1 - [(right - left) / (right + left)]
+        //             == [(right + left) - (right - left)] / (right + left)
+        //             == (2 * left) / (right + left)
         // ```
-        // Define
+        // Using the matrix element `m[0, 0]`
         // ```text
-        // ratio := right / left
+        // 1 / m[0, 0] == (right + left) / (2 * near)
         // ```
         // so that
         // ```text
-        // ratio == right / left
-        //       == (2 * right) / (2 * left)
-        //       == [(2 * right) / (right + left)] / [(2 * left) / (right + left)]
-        //       == (m[2, 0] + 1) / (-m[2, 0] + 1)
-        // ```
-        // and
-        // ```text
-        // right == ratio * left
-        // ```
-        // Using matrix element `m[0, 0]`
-        // ```text
-        // m[0, 0] == (2 * near) / (right + left)
-        // ```
-        // or equivalently
-        // ```text
-        // 2 * near / m[0, 0] == (2 * near) * (1 / m[0, 0])
-        //                    == right + left
-        //                    == ratio * left + left
-        //                    == (ratio + 1) * left
+        // (1 - m[2, 0]) / m[0, 0] == [(2 * left) / (right + left)] * [(right + left) / (2 * near)]
+        //                         == left / near
         // ```
         // We obtain
         // ```text
-        // left == [(2 * near) * (1 / (ratio + 1))] * (1 / m[0, 0])
+        // left == near * [(1 - m[2, 0]) / m[0, 0]]
         // ```
         // which is the desired formula.
         //
         let one = S::one();
-        let two = one + one;
-        let ratio = (self.matrix[2][0] + one) / (-self.matrix[2][0] + one);
         let near = self.near();
 
-        ((two * near) * (one / (ratio + one))) * (one / self.matrix[0][0])
+        near * ((one - self.matrix[2][0]) / self.matrix[0][0])
     }
 
     /// Get the position of the **top plane** of the viewing
@@ -1371,56 +1644,25 @@ where
         //             == [(top - bottom) + (top + bottom)] / (top + bottom)
         //             == (2 * top) / (top + bottom)
         // ```
-        // and
-        // ```text
-        // m[2, 1] - 1 == [(top - bottom) / (top + bottom)] - 1
-        //             == [(top - bottom) - (top + bottom)] / (top + bottom)
-        //             == -(2 * bottom) / (top + bottom)
-        // ```
-        // so that
-        // ```text
-        // (2 * top) / (top + bottom) == m[2, 1] + 1
-        // (2 * bottom) / (top + bottom) == -m[2, 1] + 1
-        // ```
-        // Define
-        // ```text
-        // ratio := top / bottom
-        // ```
-        // so that
-        // ```text
-        // ratio == top / bottom
-        //       == (2 * top) / (2 * bottom)
-        //       == [(2 * top) / (top + bottom)] / [(2 * bottom) / (top + bottom)]
-        //       == (m[2, 1] + 1) / (-m[2, 1] + 1)
-        // ```
-        // and
-        // ```text
-        // bottom = top / ratio
-        // ```
         // Using the matrix element `m[1, 1]`
         // ```text
-        // m[1, 1] == (2 * near) / (top + bottom)
+        // 1 / m[1, 1] == (top + bottom) / (2 * near)
         // ```
-        // or equivalently
+        // so that
         // ```text
-        // (2 * near) / m[1, 1] == (2 * near) * (1 / m[1, 1])
-        //                      == top + bottom
-        //                      == top + top / ratio
-        //                      == (1 + (1 / ratio)) * top
-        //                      == ((ratio + 1) / ratio) * top
+        // (m[2, 1] + 1) / m[1, 1] == [(2 * top) / (top + bottom)] * [(top + bottom) / (2 * near)]
+        //                         == top / near
         // ```
         // We obtain
         // ```text
-        // top == [(2 * near) * (ratio / (ratio + 1))] * (1 / m[1, 1])
+        // top == near * [(m[2, 1] + 1) / m[1, 1]]
         // ```
         // which is the desired formula.
         //
         let one = S::one();
-        let two = one + one;
-        let ratio = (self.matrix[2][1] + one) / (-self.matrix[2][1] + one);
         let near = self.near();
 
-        ((two * near) * (ratio / (ratio + one))) * (one / self.matrix[1][1])
+        near * ((self.matrix[2][1] + one) / self.matrix[1][1])
     }
 
     /// Get the position of the **bottom plane** of the viewing
@@ -1485,59 +1727,29 @@ where
         // ```
         // Observe that
         // ```text
-        // m[2, 1] + 1 == [(top - bottom) / (top + bottom)] + 1
-        //             == [(top - bottom) + (top + bottom)] / (top + bottom)
-        //             == (2 * top) / (top + bottom)
-        // ```
-        // and
-        // ```text
-        // m[2, 1] - 1 == [(top - bottom) / (top + bottom)] - 1
-        //             == [(top - bottom) - (top + bottom)] / (top + bottom)
-        //             == -(2 * bottom) / (top + bottom)
-        // ```
-        // so that
-        // ```text
-        // (2 * top) / (top + bottom) == m[2, 1] + 1
-        // (2 * bottom) / (top + bottom) == -m[2, 1] + 1
-        // ```
-        // Define
-        // ```text
-        // ratio := top / bottom
-        // ```
-        // so that
-        // ```text
-        // ratio == top / bottom
-        //       == (2 * top) / (2 * bottom)
-        //       == [(2 * top) / (top + bottom)] / [(2 * bottom) / (top + bottom)]
-        //       == (m[2, 1] + 1) / (-m[2, 1] + 1)
-        // ```
-        // and
-        // ```text
-        // top = ratio * bottom
+        // 1 - m[2, 1] == 1 - [(top - bottom) / (top + bottom)]
+        //             == [(top + bottom) - (top - bottom)] / (top + bottom)
+        //             == (2 * bottom) / (top + bottom)
         // ```
         // Using the matrix element `m[1, 1]`
         // ```text
-        // m[1, 1] == (2 * near) / (top + bottom)
+        // 1 / m[1, 1] == (top + bottom) / (2 * near)
         // ```
-        // or equivalently
+        // so that
         // ```text
-        // (2 * near) / m[1, 1] == (2 * near) * (1 / m[1, 1])
-        //                      == top + bottom
-        //                      == ratio * bottom + bottom
-        //                      == (ratio + 1) * bottom
+        // (1 - m[2, 1]) / m[1, 1] == [(2 * bottom) / (top + bottom)] * [(top + bottom) / (2 * near)]
+        //                         == bottom / near
         // ```
         // We obtain
         // ```text
-        // bottom == [(2 * near) * (1 / (ratio + 1))] * (1 / m[1, 1])
+        // bottom == near * [(1 - m[2, 1]) / m[1, 1]]
         // ```
         // which is the desired formula.
         //
         let one = S::one();
-        let two = one + one;
-        let ratio = (self.matrix[2][1] + one) / (-self.matrix[2][1] + one);
         let near = self.near();
 
-        ((two * near) * (one / (ratio + one))) * (one / self.matrix[1][1])
+        near * ((one - self.matrix[2][1]) / self.matrix[1][1])
     }
 
     /// Get the matrix that implements the perspective projection transformation.
@@ -1921,7 +2133,7 @@ where
 
         Point3::new(
             (self.matrix.c0r0 * point.x + self.matrix.c2r0 * point.z) * inverse_w,
-            (self.matrix.c1r1 * point.y + self.matrix.c3r1 * point.z) * inverse_w,
+            (self.matrix.c1r1 * point.y + self.matrix.c2r1 * point.z) * inverse_w,
             (self.matrix.c2r2 * point.z + self.matrix.c3r2) * inverse_w,
         )
     }
@@ -2173,39 +2385,273 @@ where
         )
     }
 
-    /// Convert a perspective projection to a projective matrix.
+    /// Offset a perspective projection by a subpixel jitter.
     ///
-    /// # Example (Frustum Parametrization)
+    /// The jitter `jitter` is an offset in pixels of a viewport with dimensions
+    /// `viewport_width` and `viewport_height`. The resulting projection maps each
+    /// point to the same normalized device coordinates as the original projection,
+    /// displaced by the jitter along the **x-axis** and **y-axis**. The depth
+    /// coordinate is unchanged. Temporal anti-aliasing typically draws a jitter
+    /// sample from `[-1 / 2, 1 / 2] x [-1 / 2, 1 / 2]` every frame, e.g. using
+    /// [`halton_jitter`].
+    ///
+    /// # Discussion
     ///
+    /// A jitter of `jitter_x` pixels corresponds to an offset in normalized device
+    /// coordinates of
+    ///
+    /// ```text
+    /// ndc_offset_x := 2 * jitter_x / viewport_width
+    /// ndc_offset_y := 2 * jitter_y / viewport_height
     /// ```
-    /// # use approx_cmp::assert_relative_eq;
-    /// # use cglinalg_core::Matrix4x4;
-    /// # use cglinalg_transform::Perspective3;
-    /// # use cglinalg_trigonometry::Degrees;
-    /// #
-    /// let left = 3_f64;
-    /// let right = 3_f64;
-    /// let bottom = 2_f64;
-    /// let top = 2_f64;
-    /// let near = 1_f64;
-    /// let far = 100_f64;
-    /// let perspective = Perspective3::new(left, right, bottom, top, near, far);
-    /// let expected = Matrix4x4::new(
-    ///     1_f64 / 3_f64, 0_f64,          0_f64,             0_f64,
-    ///     0_f64,         1_f64 / 2_f64,  0_f64,             0_f64,
-    ///     0_f64,         0_f64,         -101_f64 / 99_f64, -1_f64,
-    ///     0_f64,         0_f64,         -200_f64 / 99_f64,  0_f64,
-    /// );
-    /// let result = perspective.to_projective_matrix();
     ///
-    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// since the canonical view volume has a width and height of `2`. The normalized
+    /// device coordinates of a view space point `(x, y, z)` satisfy
+    ///
+    /// ```text
+    /// x_ndc == (m[0, 0] * x + m[2, 0] * z) / (-z) == -m[0, 0] * (x / z) - m[2, 0]
+    /// y_ndc == (m[1, 1] * y + m[2, 1] * z) / (-z) == -m[1, 1] * (y / z) - m[2, 1]
     /// ```
     ///
-    /// # Example (Field Of View Parametrization)
+    /// so the jitter is realized by subtracting `ndc_offset_x` from `m[2, 0]`, and
+    /// subtracting `ndc_offset_y` from `m[2, 1]`.
+    ///
+    /// # Example
     ///
     /// ```
     /// # use approx_cmp::assert_relative_eq;
-    /// # use cglinalg_core::Matrix4x4;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector2,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// let jitter = Vector2::new(1_f64 / 2_f64, -1_f64 / 4_f64);
+    /// let jittered = perspective.jitter(&jitter, 800_f64, 600_f64);
+    /// let point = Point3::new(1_f64, 2_f64, -10_f64);
+    /// let expected = perspective.project_point(&point) + Vector3::new(1_f64 / 800_f64, -1_f64 / 1200_f64, 0_f64);
+    /// let result = jittered.project_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn jitter(&self, jitter: &Vector2<S>, viewport_width: S, viewport_height: S) -> Self {
+        let two = S::one() + S::one();
+        let ndc_offset_x = (two * jitter.x) / viewport_width;
+        let ndc_offset_y = (two * jitter.y) / viewport_height;
+        let mut matrix = self.matrix;
+        matrix.c2r0 -= ndc_offset_x;
+        matrix.c2r1 -= ndc_offset_y;
+
+        Self { matrix }
+    }
+
+    /// Construct the perspective projection for a rectangular tile of a viewport.
+    ///
+    /// The tile is the rectangle of pixels with lower left corner `(x, y)`, width
+    /// `width`, and height `height` inside a viewport with dimensions `viewport_width`
+    /// and `viewport_height`. Following OpenGL's window coordinate conventions, the
+    /// origin of the viewport is its lower left corner, and the **positive y-axis**
+    /// points up. The resulting projection maps the part of the viewing frustum
+    /// that the tile covers onto the entire canonical view volume, so rendering
+    /// each tile to its own target and stitching the results together reproduces the
+    /// image of the original projection. This is useful for tiled rendering and for
+    /// rendering one image across multiple displays.
+    ///
+    /// The resulting frustum is generally asymmetric, and it can be off-axis, i.e. it
+    /// need not contain the **negative z-axis**.
+    ///
+    /// # Discussion
+    ///
+    /// The **near plane** of the frustum covers the rectangle
+    /// `[-left, right] x [-bottom, top]`. The tile covers the rectangle
+    /// `[x_min, x_max] x [y_min, y_max]` of the **near plane** where
+    ///
+    /// ```text
+    /// x_min := -left   + (right + left) * (x / viewport_width)
+    /// x_max := -left   + (right + left) * ((x + width) / viewport_width)
+    /// y_min := -bottom + (top + bottom) * (y / viewport_height)
+    /// y_max := -bottom + (top + bottom) * ((y + height) / viewport_height)
+    /// ```
+    ///
+    /// so the tile frustum has the parameters `left == -x_min`, `right == x_max`,
+    /// `bottom == -y_min`, and `top == y_max`, with the same **near plane** and
+    /// **far plane** as the original frustum.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect_ratio = 1_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// // The upper right quadrant of an 800 x 800 viewport.
+    /// let expected = Perspective3::new(0_f64, 1_f64, 0_f64, 1_f64, near, far);
+    /// let result = perspective.sub_frustum(400_f64, 400_f64, 400_f64, 400_f64, 800_f64, 800_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.left(),   0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.right(),  1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.bottom(), 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.top(),    1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn sub_frustum(&self, x: S, y: S, width: S, height: S, viewport_width: S, viewport_height: S) -> Self {
+        let left = self.left();
+        let right = self.right();
+        let bottom = self.bottom();
+        let top = self.top();
+        let x_min = -left + (right + left) * (x / viewport_width);
+        let x_max = -left + (right + left) * ((x + width) / viewport_width);
+        let y_min = -bottom + (top + bottom) * (y / viewport_height);
+        let y_max = -bottom + (top + bottom) * ((y + height) / viewport_height);
+
+        Self::new(-x_min, x_max, -y_min, y_max, self.near(), self.far())
+    }
+
+    /// Map a point in world space to window coordinates.
+    ///
+    /// The view transformation `view` maps world space to camera view space, e.g.
+    /// an isometry constructed with [`Isometry3::look_at_rh`]. The point is mapped
+    /// to view space by `view`, projected to normalized device coordinates by the
+    /// perspective projection, and then mapped to window coordinates by `viewport`.
+    /// The depth component of the result is the window depth of the point inside
+    /// the depth range of the viewport.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// #     Viewport,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// let eye = Point3::new(0_f64, 0_f64, 5_f64);
+    /// let target = Point3::origin();
+    /// let up = Vector3::unit_y();
+    /// let view = Isometry3::look_at_rh(&eye, &target, &up);
+    /// let viewport = Viewport::from_size(800_f64, 600_f64);
+    /// let point = Point3::origin();
+    /// let expected = Point3::new(400_f64, 300_f64, 80_f64 / 99_f64);
+    /// let result = perspective.world_to_screen(&view, &viewport, &point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn world_to_screen(&self, view: &Isometry3<S>, viewport: &Viewport<S>, point: &Point3<S>) -> Point3<S> {
+        world_to_screen(|point| self.project_point(point), view, viewport, point)
+    }
+
+    /// Construct the ray in world space passing through a point in window coordinates.
+    ///
+    /// The view transformation `view` maps world space to camera view space, e.g.
+    /// an isometry constructed with [`Isometry3::look_at_rh`]. The window point
+    /// `pixel` is given in the window coordinates of `viewport`, whose origin is the
+    /// lower left corner of the window. To cast a ray through the center of the pixel
+    /// in column `i` and row `j`, use the window point `(i + 1 / 2, j + 1 / 2)`.
+    ///
+    /// This function returns the pair `(origin, direction)`, where `origin` is the
+    /// point on the **near plane** that projects to `pixel`, and `direction` is the
+    /// unit vector from `origin` towards the point on the **far plane** that projects
+    /// to `pixel`. Every point `origin + t * direction` with `t >= 0` up to the
+    /// **far plane** projects onto the window point `pixel`. This is the ray used for
+    /// mouse picking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// #     Viewport,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let vfov = Degrees(90_f64);
+    /// let aspect_ratio = 800_f64 / 600_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    /// let eye = Point3::new(0_f64, 0_f64, 5_f64);
+    /// let target = Point3::origin();
+    /// let up = Vector3::unit_y();
+    /// let view = Isometry3::look_at_rh(&eye, &target, &up);
+    /// let viewport = Viewport::from_size(800_f64, 600_f64);
+    /// let pixel = Point2::new(400_f64, 300_f64);
+    /// let expected_origin = Point3::new(0_f64, 0_f64, 4_f64);
+    /// let expected_direction = -Vector3::unit_z();
+    /// let (result_origin, result_direction) = perspective.screen_to_world_ray(&view, &viewport, &pixel);
+    ///
+    /// assert_relative_eq!(result_origin, expected_origin, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result_direction, expected_direction, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn screen_to_world_ray(&self, view: &Isometry3<S>, viewport: &Viewport<S>, pixel: &Point2<S>) -> (Point3<S>, Vector3<S>) {
+        screen_to_world_ray(|point| self.unproject_point(point), view, viewport, pixel)
+    }
+
+    /// Convert a perspective projection to a projective matrix.
+    ///
+    /// # Example (Frustum Parametrization)
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Matrix4x4;
+    /// # use cglinalg_transform::Perspective3;
+    /// # use cglinalg_trigonometry::Degrees;
+    /// #
+    /// let left = 3_f64;
+    /// let right = 3_f64;
+    /// let bottom = 2_f64;
+    /// let top = 2_f64;
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let perspective = Perspective3::new(left, right, bottom, top, near, far);
+    /// let expected = Matrix4x4::new(
+    ///     1_f64 / 3_f64, 0_f64,          0_f64,             0_f64,
+    ///     0_f64,         1_f64 / 2_f64,  0_f64,             0_f64,
+    ///     0_f64,         0_f64,         -101_f64 / 99_f64, -1_f64,
+    ///     0_f64,         0_f64,         -200_f64 / 99_f64,  0_f64,
+    /// );
+    /// let result = perspective.to_projective_matrix();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    ///
+    /// # Example (Field Of View Parametrization)
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Matrix4x4;
     /// # use cglinalg_transform::Perspective3;
     /// # use cglinalg_trigonometry::{
     /// #     Angle,
@@ -2430,6 +2876,150 @@ where
     }
 }
 
+impl<S> ops::Mul<Isometry3<S>> for Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Isometry3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<&Isometry3<S>> for Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &Isometry3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<Isometry3<S>> for &Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Isometry3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<'a, S> ops::Mul<&'a Isometry3<S>> for &Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &'a Isometry3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<Similarity3<S>> for Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Similarity3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<&Similarity3<S>> for Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &Similarity3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<Similarity3<S>> for &Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Similarity3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<'a, S> ops::Mul<&'a Similarity3<S>> for &Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &'a Similarity3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<Transform3<S>> for Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Transform3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_matrix())
+    }
+}
+
+impl<S> ops::Mul<&Transform3<S>> for Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &Transform3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_matrix())
+    }
+}
+
+impl<S> ops::Mul<Transform3<S>> for &Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Transform3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_matrix())
+    }
+}
+
+impl<'a, S> ops::Mul<&'a Transform3<S>> for &Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &'a Transform3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_matrix())
+    }
+}
+
 
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -2467,6 +3057,26 @@ impl<S> Perspective3Diff<S> {
     }
 }
 
+/// Generate a perspective projection whose view frustum extents and near plane
+/// distance lie in the interval `[2^-10, 2^10]`, and whose far plane lies
+/// strictly beyond the near plane.
+#[cfg(feature = "arbitrary")]
+impl<'a, S> arbitrary::Arbitrary<'a> for Perspective3<S>
+where
+    S: SimdScalarFloat,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let left = crate::arbitrary_support::positive_scalar(u)?;
+        let right = crate::arbitrary_support::positive_scalar(u)?;
+        let bottom = crate::arbitrary_support::positive_scalar(u)?;
+        let top = crate::arbitrary_support::positive_scalar(u)?;
+        let near = crate::arbitrary_support::positive_scalar(u)?;
+        let far = near + crate::arbitrary_support::positive_scalar(u)?;
+
+        Ok(Perspective3::new(left, right, bottom, top, near, far))
+    }
+}
+
 impl<S> approx_cmp::AbsDiffEq for Perspective3<S>
 where
     S: SimdScalarFloat,
@@ -2814,7 +3424,14 @@ where
 /// m[3, 2] == -(far + near) / (far - near)
 /// ```
 /// where the matrix entries are indexed in column-major order.
+#[repr(transparent)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent),
+    serde(bound(deserialize = "S: serde::Deserialize<'de> + Copy"))
+)]
 pub struct Orthographic3<S> {
     matrix: Matrix4x4<S>,
 }
@@ -2981,21 +3598,153 @@ where
         }
     }
 
-    /// Get the position of the **near plane** of the viewing
-    /// volume described by the orthographic projection of the plane
-    /// parallel to the **xy-plane** positioned along the **negative z-axis**.
+    /// Construct a tight fitting orthographic projection and light view for
+    /// one cascade of a directional light shadow map.
+    ///
+    /// The camera is given by its perspective projection `perspective` and its
+    /// view transformation `view` mapping world space to camera view space. The
+    /// cascade covers the slice of the camera frustum between the distances
+    /// `split_near` and `split_far` along the camera gaze direction, where
+    /// `perspective.near() <= split_near < split_far <= perspective.far()`. The
+    /// directional light shines along the world space direction `light_direction`.
+    ///
+    /// This function returns the pair `(light_view, projection)`, where `light_view`
+    /// is a view transformation mapping world space to light view space looking along
+    /// `light_direction`, and `projection` is the smallest orthographic projection
+    /// whose view volume in light view space contains the frustum slice. The
+    /// **near plane** passes through the corner of the frustum slice closest to
+    /// the light. Shadow casters between the light and the frustum slice lie in front
+    /// of the **near plane**, so callers typically clamp their depth to the near plane
+    /// ("shadow pancaking") or move the **near plane** towards the light.
+    ///
+    /// # Discussion
+    ///
+    /// The eight corners of the frustum slice in camera view space are
+    ///
+    /// ```text
+    /// [x, y, -d] for d in { split_near, split_far },
+    ///                x in { -left * (d / near), right * (d / near) },
+    ///                y in { -bottom * (d / near), top * (d / near) }
+    /// ```
+    ///
+    /// which are mapped to world space with the inverse of `view`, and then to
+    /// light view space. The orthographic projection is the axis-aligned bounding
+    /// box of the corners in light view space.
     ///
     /// # Example
     ///
     /// ```
     /// # use approx_cmp::assert_relative_eq;
-    /// # use cglinalg_core::Vector3;
-    /// # use cglinalg_transform::Orthographic3;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Orthographic3,
+    /// #     Perspective3,
+    /// # };
+    /// # use cglinalg_trigonometry::Degrees;
     /// #
-    /// let left = 6_f64;
-    /// let right = 6_f64;
-    /// let bottom = 4_f64;
-    /// let top = 4_f64;
+    /// let perspective = Perspective3::from_vfov(Degrees(90_f64), 1_f64, 1_f64, 100_f64);
+    /// let view = Isometry3::look_to_rh(&Point3::origin(), &(-Vector3::unit_z()), &Vector3::unit_y());
+    /// // The sun shines straight down.
+    /// let light_direction = -Vector3::unit_y();
+    /// let (light_view, projection) = Orthographic3::shadow_cascade(&perspective, &view, 1_f64, 10_f64, &light_direction);
+    ///
+    /// // The frustum slice spans `[-10, 10]` along the world x-axis, `[-10, 10]` along the
+    /// // world y-axis that the light looks down along, and `[-10, -1]` along the world z-axis,
+    /// // which is the light view space x-axis.
+    /// assert_relative_eq!(projection.left() + projection.right(), 9_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(projection.bottom() + projection.top(), 20_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(projection.far() - projection.near(), 20_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn shadow_cascade(
+        perspective: &Perspective3<S>,
+        view: &Isometry3<S>,
+        split_near: S,
+        split_far: S,
+        light_direction: &Vector3<S>,
+    ) -> (Isometry3<S>, Self) {
+        let zero = S::zero();
+        let one = S::one();
+        let one_half = one / (one + one);
+        let one_eighth = one_half * one_half * one_half;
+        let near = perspective.near();
+        let left = perspective.left();
+        let right = perspective.right();
+        let bottom = perspective.bottom();
+        let top = perspective.top();
+        let mut corners = [Point3::origin(); 8];
+        for (i, distance) in [split_near, split_far].into_iter().enumerate() {
+            let ratio = distance / near;
+            let x_min = -left * ratio;
+            let x_max = right * ratio;
+            let y_min = -bottom * ratio;
+            let y_max = top * ratio;
+            corners[4 * i] = view.inverse_apply_point(&Point3::new(x_min, y_min, -distance));
+            corners[4 * i + 1] = view.inverse_apply_point(&Point3::new(x_max, y_min, -distance));
+            corners[4 * i + 2] = view.inverse_apply_point(&Point3::new(x_min, y_max, -distance));
+            corners[4 * i + 3] = view.inverse_apply_point(&Point3::new(x_max, y_max, -distance));
+        }
+
+        let center = {
+            let mut sum = Vector3::zero();
+            for corner in corners.iter() {
+                sum += corner.to_vector();
+            }
+
+            Point3::from_vector(&(sum * one_eighth))
+        };
+        // Choose the world axis least aligned with the light direction as the up
+        // direction so that the light view is well-defined for every light direction.
+        let direction = light_direction.normalize();
+        let up = if direction.y.abs() <= direction.x.abs() && direction.y.abs() <= direction.z.abs() {
+            Vector3::new(zero, one, zero)
+        } else if direction.x.abs() <= direction.z.abs() {
+            Vector3::new(one, zero, zero)
+        } else {
+            Vector3::new(zero, zero, one)
+        };
+        let center_view = Isometry3::look_to_rh(&center, &direction, &up);
+        let mut min = center_view.apply_point(&corners[0]);
+        let mut max = min;
+        for corner in corners[1..].iter() {
+            let light_corner = center_view.apply_point(corner);
+            min.x = min.x.min(light_corner.x);
+            min.y = min.y.min(light_corner.y);
+            min.z = min.z.min(light_corner.z);
+            max.x = max.x.max(light_corner.x);
+            max.y = max.y.max(light_corner.y);
+            max.z = max.z.max(light_corner.z);
+        }
+
+        // Move the eye towards the light until every corner lies in front of it.
+        // The light view space **positive z-axis** points towards the light, so the
+        // corner closest to the light has the largest z-coordinate.
+        let eye = center - direction * max.z;
+        let light_view = Isometry3::look_to_rh(&eye, &direction, &up);
+        let projection = Self::new(-min.x, max.x, -min.y, max.y, zero, max.z - min.z);
+
+        (light_view, projection)
+    }
+
+    /// Get the position of the **near plane** of the viewing
+    /// volume described by the orthographic projection of the plane
+    /// parallel to the **xy-plane** positioned along the **negative z-axis**.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Vector3;
+    /// # use cglinalg_transform::Orthographic3;
+    /// #
+    /// let left = 6_f64;
+    /// let right = 6_f64;
+    /// let bottom = 4_f64;
+    /// let top = 4_f64;
     /// let near = 1_f64;
     /// let far = 101_f64;
     /// let orthographic = Orthographic3::new(left, right, bottom, top, near, far);
@@ -3043,42 +3792,19 @@ where
         // ```text
         // (2 * far) / (far - near) == -m[3, 2] + 1
         // ```
-        // Define
-        // ```text
-        // ratio := far / near
-        // ```
-        // the ratio of `far` to `near` so that
-        // ```text
-        // ratio == far / near
-        //       == (2 * far) / (2 * near)
-        //       == [(2 * far) / (far - near)] / [(2 * near) / (far - near)]
-        //       == (-m[3, 2] + 1) / (-m[3, 2] - 1)
-        // ```
-        // so that
-        // ```text
-        // far == ratio * near
-        // ```
-        // Using the matrix element `m[2, 2]`
+        // Dividing by the matrix element `m[2, 2]`
         // ```text
         // m[2, 2] == -2 / (far - near)
         // ```
-        // or equivalently
-        // ```text
-        // -2 / m[2, 2] == far - near
-        //              == ratio * near - near
-        //              == (ratio - 1) * near
-        // ```
         // we obtain
         // ```text
-        // near == -2 * (1 / (ratio - 1)) * (1 / m[2, 2])
+        // near == (m[3, 2] + 1) / m[2, 2]
         // ```
         // which is the desired formula.
         //
         let one = S::one();
-        let two = one + one;
-        let ratio = (-self.matrix[3][2] + one) / (-self.matrix[3][2] - one);
 
-        (-two * (one / (ratio - one))) * (one / self.matrix[2][2])
+        (self.matrix[3][2] + one) / self.matrix[2][2]
     }
 
     /// Get the position of the **far plane** of the viewing
@@ -3143,43 +3869,19 @@ where
         // ```text
         // (2 * far) / (far - near) == -m[3, 2] + 1
         // ```
-        // Define
-        // ```text
-        // ratio := far / near
-        // ```
-        // the ratio of `far` to `near` so that
-        // ```text
-        // ratio == far / near
-        //       == (2 * far) / (2 * near)
-        //       == [(2 * far) / (far - near)] / [(2 * near) / (far - near)]
-        //       == (-m[3, 2] + 1) / (-m[3, 2] - 1)
-        // ```
-        // so that
-        // ```text
-        // near == far / ratio
-        // ```
-        // Using the matrix element `m[2, 2]`
+        // Dividing by the matrix element `m[2, 2]`
         // ```text
         // m[2, 2] == -2 / (far - near)
         // ```
-        // or equivalently
-        // ```text
-        // -2 / m[2, 2] == far - near
-        //              == far - far / ratio
-        //              == (1 - (1 / ratio)) * far
-        //              == ((ratio - 1) / ratio) * far
-        // ```
         // we obtain
         // ```text
-        // far == -2 * (ratio / (ratio - 1)) * (1 / m[2, 2])
+        // far == (m[3, 2] - 1) / m[2, 2]
         // ```
         // which is the desired formula.
         //
         let one = S::one();
-        let two = one + one;
-        let ratio = (-self.matrix[3][2] + one) / (-self.matrix[3][2] - one);
 
-        (-two * (ratio / (ratio - one))) * (one / self.matrix[2][2])
+        (self.matrix[3][2] - one) / self.matrix[2][2]
     }
 
     /// Get the position of the **right plane** of the viewing
@@ -3226,60 +3928,24 @@ where
         // ```
         // Observe that
         // ```text
-        // m[3, 0] - 1 == -[(right - left) / (right + left)] - 1
-        //             == [-(right - left) - (right + left)] / (right + left)
-        //             == [-right + left - right - left] / (right + left)
-        //             == -(2 * right) / (right + left)
-        // ```
-        // and
-        // ```text
-        // m[3, 0] + 1 == -[(right - left) / (right + left)] + 1
-        //             == [-(right - left) + (right + left)] / (right + left)
-        //             == [-right + left + right + left] / (right + left)
-        //             == (2 * left) / (right + left)
-        // ```
-        // so that
-        // ```text
-        // (2 * right) / (right + left) == -m[3, 0] + 1
-        // (2 * left) / (right + left) == m[3, 0] + 1
-        // ```
-        // Define
-        // ```text
-        // ratio := right / left
-        // ```
-        // so that
-        // ```text
-        // ratio == right / left
-        //       == (2 * right) / (2 * left)
-        //       == [(2 * right) / (right + left)] / [(2 * left) / (right + left)]
-        //       == (-m[3, 0] + 1) / (m[3, 0] + 1)
-        // ```
-        // and
-        // ```text
-        // left == right / ratio
+        // 1 - m[3, 0] == 1 + [(right - left) / (right + left)]
+        //             == [(right + left) + (right - left)] / (right + left)
+        //             == (2 * right) / (right + left)
         // ```
         // Using the matrix element `m[0, 0]`
         // ```text
-        // m[0, 0] == 2 / (right + left)
-        // ```
-        // or equivalently
-        // ```text
-        // 2 * (1 / m[0, 0]) == right + left
-        //                   == right + right / ratio
-        //                   == (1 + (1 / ratio)) * right
-        //                   == ((ratio + 1) / ratio) * right
+        // 1 / m[0, 0] == (right + left) / 2
         // ```
-        // We obtain
+        // so that
         // ```text
-        // right == 2 * (ratio / (ratio + 1)) * (1 / m[0, 0])
+        // (1 - m[3, 0]) / m[0, 0] == [(2 * right) / (right + left)] * [(right + left) / 2]
+        //                         == right
         // ```
         // which is the desired formula.
         //
         let one = S::one();
-        let two = one + one;
-        let ratio = (-self.matrix[3][0] + one) / (self.matrix[3][0] + one);
 
-        (two * (ratio / (ratio + one))) * (one / self.matrix[0][0])
+        (one - self.matrix[3][0]) / self.matrix[0][0]
     }
 
     /// Get the position of the **left plane** of the viewing
@@ -3326,59 +3992,24 @@ where
         // ```
         // Observe that
         // ```text
-        // m[3, 0] - 1 == -[(right - left) / (right + left)] - 1
-        //             == [-(right - left) - (right + left)] / (right + left)
-        //             == [-right + left - right - left] / (right + left)
-        //             == -(2 * right) / (right + left)
-        // ```
-        // and
-        // ```text
-        // m[3, 0] + 1 == -[(right - left) / (right + left)] + 1
-        //             == [-(right - left) + (right + left)] / (right + left)
-        //             == [-right + left + right + left] / (right + left)
+        // 1 + m[3, 0] == 1 - [(right - left) / (right + left)]
+        //             == [(right + left) - (right - left)] / (right + left)
         //             == (2 * left) / (right + left)
         // ```
-        // so that
-        // ```text
-        // (2 * right) / (right + left) == -m[3, 0] + 1
-        // (2 * left) / (right + left) == m[3, 0] + 1
-        // ```
-        // Define
-        // ```text
-        // ratio := right / left
-        // ```
-        // so that
-        // ```text
-        // ratio == right / left
-        //       == (2 * right) / (2 * left)
-        //       == [(2 * right) / (right + left)] / [(2 * left) / (right + left)]
-        //       == (-m[3, 0] + 1) / (m[3, 0] + 1)
-        // ```
-        // and
-        // ```text
-        // right == ratio * left
-        // ```
         // Using the matrix element `m[0, 0]`
         // ```text
-        // m[0, 0] == 2 / (right + left)
+        // 1 / m[0, 0] == (right + left) / 2
         // ```
-        // or equivalently
-        // ```text
-        // 2 * (1 / m[0, 0]) == right + left
-        //                   == ratio * left + left
-        //                   == (ratio + 1) * left
-        // ```
-        // We obtain
+        // so that
         // ```text
-        // left == 2 * (1 / (ratio + 1)) * (1 / m[0, 0])
+        // (1 + m[3, 0]) / m[0, 0] == [(2 * left) / (right + left)] * [(right + left) / 2]
+        //                         == left
         // ```
         // which is the desired formula.
         //
         let one = S::one();
-        let two = one + one;
-        let ratio = (-self.matrix[3][0] + one) / (self.matrix[3][0] + one);
 
-        (two * (one / (ratio + one))) * (one / self.matrix[0][0])
+        (one + self.matrix[3][0]) / self.matrix[0][0]
     }
 
     /// Get the position of the **top plane** of the viewing
@@ -3425,59 +4056,24 @@ where
         // ```
         // Observe that
         // ```text
-        // m[3, 1] - 1 == -[(top - bottom) / (top + bottom)] - 1
-        //             == [-(top - bottom) - (top + bottom)] / (top + bottom)
-        //             == [-top + bottom - top - bottom] / (top + bottom)
-        //             == -(2 * top) / (top + bottom)
-        // ```
-        // and
-        // ```text
-        // m[3, 1] + 1 == -[(top - bottom) / (top + bottom)] + 1
-        //             == [-(top - bottom) + (top + bottom)] / (top + bottom)
-        //             == [-top + bottom + top + bottom] / (top + bottom)
-        //             == (2 * bottom) / (top + bottom)
-        // ```
-        // so that
-        // ```text
-        // (2 * top) / (top + bottom) == -m[3, 1] + 1
-        // (2 * bottom) / (top + bottom) == m[3, 1] + 1
-        // ```
-        // Define
-        // ```text
-        // ratio := top / bottom
-        // ```
-        // so that
-        // ```text
-        // ratio == top / bottom
-        //       == (2 * top) / (2 * bottom)
-        //       == [(2 * top) / (top + bottom)] / [(2 * bottom) / (top + bottom)]
-        //       == (-m[3, 1] + 1) / (m[3, 1] + 1)
-        // ```
-        // and
-        // ```text
-        // bottom == top / ratio
+        // 1 - m[3, 1] == 1 + [(top - bottom) / (top + bottom)]
+        //             == [(top + bottom) + (top - bottom)] / (top + bottom)
+        //             == (2 * top) / (top + bottom)
         // ```
         // Using the matrix element `m[1, 1]`
         // ```text
-        // m[1, 1] == 2 / (top + bottom)
-        // ```
-        // or equivalently
-        // ```text
-        // 2 * (1 / m[1, 1]) == top + bottom
-        //                   == top + top / ratio
-        //                   == ((ratio + 1) / ratio) * top
+        // 1 / m[1, 1] == (top + bottom) / 2
         // ```
-        // We obtain
+        // so that
         // ```text
-        // top == 2 * (ratio / (ratio + 1)) * (1 / m[1, 1])
+        // (1 - m[3, 1]) / m[1, 1] == [(2 * top) / (top + bottom)] * [(top + bottom) / 2]
+        //                         == top
         // ```
         // which is the desired formula.
         //
         let one = S::one();
-        let two = one + one;
-        let ratio = (-self.matrix[3][1] + one) / (self.matrix[3][1] + one);
 
-        (two * (ratio / (ratio + one))) * (one / self.matrix[1][1])
+        (one - self.matrix[3][1]) / self.matrix[1][1]
     }
 
     /// Get the position of the **bottom plane** of the viewing
@@ -3524,59 +4120,24 @@ where
         // ```
         // Observe that
         // ```text
-        // m[3, 1] - 1 == -[(top - bottom) / (top + bottom)] - 1
-        //             == [-(top - bottom) - (top + bottom)] / (top + bottom)
-        //             == [-top + bottom - top - bottom] / (top + bottom)
-        //             == -(2 * top) / (top + bottom)
-        // ```
-        // and
-        // ```text
-        // m[3, 1] + 1 == -[(top - bottom) / (top + bottom)] + 1
-        //             == [-(top - bottom) + (top + bottom)] / (top + bottom)
-        //             == [-top + bottom + top + bottom] / (top + bottom)
+        // 1 + m[3, 1] == 1 - [(top - bottom) / (top + bottom)]
+        //             == [(top + bottom) - (top - bottom)] / (top + bottom)
         //             == (2 * bottom) / (top + bottom)
         // ```
-        // so that
-        // ```text
-        // (2 * top) / (top + bottom) == -m[3, 1] + 1
-        // (2 * bottom) / (top + bottom) == m[3, 1] + 1
-        // ```
-        // Define
-        // ```text
-        // ratio := top / bottom
-        // ```
-        // so that
-        // ```text
-        // ratio == top / bottom
-        //       == (2 * top) / (2 * bottom)
-        //       == [(2 * top) / (top + bottom)] / [(2 * bottom) / (top + bottom)]
-        //       == (-m[3, 1] + 1) / (m[3, 1] + 1)
-        // ```
-        // and
-        // ```text
-        // top == ratio * bottom
-        // ```
         // Using the matrix element `m[1, 1]`
         // ```text
-        // m[1, 1] == 2 / (top + bottom)
+        // 1 / m[1, 1] == (top + bottom) / 2
         // ```
-        // or equivalently
-        // ```text
-        // 2 * (1 / m[1, 1]) == top + bottom
-        //                   == ratio * bottom + bottom
-        //                   == (ratio + 1) * bottom
-        // ```
-        // We obtain
+        // so that
         // ```text
-        // bottom == 2 * (1 / (ratio + 1)) * (1 / m[1, 1])
+        // (1 + m[3, 1]) / m[1, 1] == [(2 * bottom) / (top + bottom)] * [(top + bottom) / 2]
+        //                         == bottom
         // ```
         // which is the desired formula.
         //
         let one = S::one();
-        let two = one + one;
-        let ratio = (-self.matrix[3][1] + one) / (self.matrix[3][1] + one);
 
-        (two * (one / (ratio + one))) * (one / self.matrix[1][1])
+        (one + self.matrix[3][1]) / self.matrix[1][1]
     }
 
     /// Get the underlying matrix implementing the orthographic transformation.
@@ -4054,6 +4615,233 @@ where
         Vector3::new(c0r0 * vector.x, c1r1 * vector.y, c2r2 * vector.z)
     }
 
+    /// Offset an orthographic projection by a subpixel jitter.
+    ///
+    /// The jitter `jitter` is an offset in pixels of a viewport with dimensions
+    /// `viewport_width` and `viewport_height`. The resulting projection maps each
+    /// point to the same normalized device coordinates as the original projection,
+    /// displaced by the jitter along the **x-axis** and **y-axis**. The depth
+    /// coordinate is unchanged. Temporal anti-aliasing typically draws a jitter
+    /// sample from `[-1 / 2, 1 / 2] x [-1 / 2, 1 / 2]` every frame, e.g. using
+    /// [`halton_jitter`].
+    ///
+    /// # Discussion
+    ///
+    /// A jitter of `jitter_x` pixels corresponds to an offset in normalized device
+    /// coordinates of
+    ///
+    /// ```text
+    /// ndc_offset_x := 2 * jitter_x / viewport_width
+    /// ndc_offset_y := 2 * jitter_y / viewport_height
+    /// ```
+    ///
+    /// since the canonical view volume has a width and height of `2`. The normalized
+    /// device coordinates of a view space point `(x, y, z)` satisfy
+    ///
+    /// ```text
+    /// x_ndc == m[0, 0] * x + m[3, 0]
+    /// y_ndc == m[1, 1] * y + m[3, 1]
+    /// ```
+    ///
+    /// so the jitter is realized by adding `ndc_offset_x` to `m[3, 0]`, and adding
+    /// `ndc_offset_y` to `m[3, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector2,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::Orthographic3;
+    /// #
+    /// let left = 6_f64;
+    /// let right = 6_f64;
+    /// let bottom = 4_f64;
+    /// let top = 4_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let orthographic = Orthographic3::new(left, right, bottom, top, near, far);
+    /// let jitter = Vector2::new(1_f64 / 2_f64, -1_f64 / 4_f64);
+    /// let jittered = orthographic.jitter(&jitter, 800_f64, 600_f64);
+    /// let point = Point3::new(1_f64, 2_f64, -10_f64);
+    /// let expected = orthographic.project_point(&point) + Vector3::new(1_f64 / 800_f64, -1_f64 / 1200_f64, 0_f64);
+    /// let result = jittered.project_point(&point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn jitter(&self, jitter: &Vector2<S>, viewport_width: S, viewport_height: S) -> Self {
+        let two = S::one() + S::one();
+        let ndc_offset_x = (two * jitter.x) / viewport_width;
+        let ndc_offset_y = (two * jitter.y) / viewport_height;
+        let mut matrix = self.matrix;
+        matrix.c3r0 += ndc_offset_x;
+        matrix.c3r1 += ndc_offset_y;
+
+        Self { matrix }
+    }
+
+    /// Construct the orthographic projection for a rectangular tile of a viewport.
+    ///
+    /// The tile is the rectangle of pixels with lower left corner `(x, y)`, width
+    /// `width`, and height `height` inside a viewport with dimensions `viewport_width`
+    /// and `viewport_height`. Following OpenGL's window coordinate conventions, the
+    /// origin of the viewport is its lower left corner, and the **positive y-axis**
+    /// points up. The resulting projection maps the part of the viewing volume
+    /// that the tile covers onto the entire canonical view volume, so rendering
+    /// each tile to its own target and stitching the results together reproduces the
+    /// image of the original projection. This is useful for tiled rendering and for
+    /// rendering one image across multiple displays.
+    ///
+    /// # Discussion
+    ///
+    /// The viewing volume covers the rectangle `[-left, right] x [-bottom, top]`
+    /// in the **xy-plane**. The tile covers the rectangle
+    /// `[x_min, x_max] x [y_min, y_max]` where
+    ///
+    /// ```text
+    /// x_min := -left   + (right + left) * (x / viewport_width)
+    /// x_max := -left   + (right + left) * ((x + width) / viewport_width)
+    /// y_min := -bottom + (top + bottom) * (y / viewport_height)
+    /// y_max := -bottom + (top + bottom) * ((y + height) / viewport_height)
+    /// ```
+    ///
+    /// so the tile viewing volume has the parameters `left == -x_min`, `right == x_max`,
+    /// `bottom == -y_min`, and `top == y_max`, with the same **near plane** and
+    /// **far plane** as the original viewing volume.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Orthographic3;
+    /// #
+    /// let left = 6_f64;
+    /// let right = 6_f64;
+    /// let bottom = 4_f64;
+    /// let top = 4_f64;
+    /// let near = 1_f64;
+    /// let far = 101_f64;
+    /// let orthographic = Orthographic3::new(left, right, bottom, top, near, far);
+    /// // The lower left quadrant of an 1200 x 800 viewport.
+    /// let expected = Orthographic3::new(6_f64, 0_f64, 4_f64, 0_f64, near, far);
+    /// let result = orthographic.sub_frustum(0_f64, 0_f64, 600_f64, 400_f64, 1200_f64, 800_f64);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.left(),   6_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.right(),  0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.bottom(), 4_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result.top(),    0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    pub fn sub_frustum(&self, x: S, y: S, width: S, height: S, viewport_width: S, viewport_height: S) -> Self {
+        let left = self.left();
+        let right = self.right();
+        let bottom = self.bottom();
+        let top = self.top();
+        let x_min = -left + (right + left) * (x / viewport_width);
+        let x_max = -left + (right + left) * ((x + width) / viewport_width);
+        let y_min = -bottom + (top + bottom) * (y / viewport_height);
+        let y_max = -bottom + (top + bottom) * ((y + height) / viewport_height);
+
+        Self::new(-x_min, x_max, -y_min, y_max, self.near(), self.far())
+    }
+
+    /// Map a point in world space to window coordinates.
+    ///
+    /// The view transformation `view` maps world space to camera view space, e.g.
+    /// an isometry constructed with [`Isometry3::look_at_rh`]. The point is mapped
+    /// to view space by `view`, projected to normalized device coordinates by the
+    /// orthographic projection, and then mapped to window coordinates by `viewport`.
+    /// The depth component of the result is the window depth of the point inside
+    /// the depth range of the viewport.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Orthographic3,
+    /// #     Viewport,
+    /// # };
+    /// #
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let orthographic = Orthographic3::new(4_f64, 4_f64, 3_f64, 3_f64, near, far);
+    /// let eye = Point3::new(0_f64, 0_f64, 5_f64);
+    /// let target = Point3::origin();
+    /// let up = Vector3::unit_y();
+    /// let view = Isometry3::look_at_rh(&eye, &target, &up);
+    /// let viewport = Viewport::from_size(800_f64, 600_f64);
+    /// let point = Point3::origin();
+    /// let expected = Point3::new(400_f64, 300_f64, 4_f64 / 99_f64);
+    /// let result = orthographic.world_to_screen(&view, &viewport, &point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn world_to_screen(&self, view: &Isometry3<S>, viewport: &Viewport<S>, point: &Point3<S>) -> Point3<S> {
+        world_to_screen(|point| self.project_point(point), view, viewport, point)
+    }
+
+    /// Construct the ray in world space passing through a point in window coordinates.
+    ///
+    /// The view transformation `view` maps world space to camera view space, e.g.
+    /// an isometry constructed with [`Isometry3::look_at_rh`]. The window point
+    /// `pixel` is given in the window coordinates of `viewport`, whose origin is the
+    /// lower left corner of the window. To cast a ray through the center of the pixel
+    /// in column `i` and row `j`, use the window point `(i + 1 / 2, j + 1 / 2)`.
+    ///
+    /// This function returns the pair `(origin, direction)`, where `origin` is the
+    /// point on the **near plane** that projects to `pixel`, and `direction` is the
+    /// unit vector from `origin` towards the point on the **far plane** that projects
+    /// to `pixel`. Every point `origin + t * direction` with `t >= 0` up to the
+    /// **far plane** projects onto the window point `pixel`. This is the ray used for
+    /// mouse picking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Orthographic3,
+    /// #     Viewport,
+    /// # };
+    /// #
+    /// let near = 1_f64;
+    /// let far = 100_f64;
+    /// let orthographic = Orthographic3::new(4_f64, 4_f64, 3_f64, 3_f64, near, far);
+    /// let eye = Point3::new(0_f64, 0_f64, 5_f64);
+    /// let target = Point3::origin();
+    /// let up = Vector3::unit_y();
+    /// let view = Isometry3::look_at_rh(&eye, &target, &up);
+    /// let viewport = Viewport::from_size(800_f64, 600_f64);
+    /// let pixel = Point2::new(400_f64, 300_f64);
+    /// let expected_origin = Point3::new(0_f64, 0_f64, 4_f64);
+    /// let expected_direction = -Vector3::unit_z();
+    /// let (result_origin, result_direction) = orthographic.screen_to_world_ray(&view, &viewport, &pixel);
+    ///
+    /// assert_relative_eq!(result_origin, expected_origin, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(result_direction, expected_direction, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn screen_to_world_ray(&self, view: &Isometry3<S>, viewport: &Viewport<S>, pixel: &Point2<S>) -> (Point3<S>, Vector3<S>) {
+        screen_to_world_ray(|point| self.unproject_point(point), view, viewport, pixel)
+    }
+
     /// Convert an orthographic projection to a projective matrix.
     ///
     /// # Example
@@ -4242,13 +5030,157 @@ where
     }
 }
 
-#[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq)]
-pub struct Orthographic3Tol<S> {
-    matrix: Matrix4x4<S>,
-}
+impl<S> ops::Mul<Isometry3<S>> for Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
 
-impl<S> From<Matrix4x4<S>> for Orthographic3Tol<S> {
+    #[inline]
+    fn mul(self, other: Isometry3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<&Isometry3<S>> for Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &Isometry3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<Isometry3<S>> for &Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Isometry3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<'a, S> ops::Mul<&'a Isometry3<S>> for &Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &'a Isometry3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<Similarity3<S>> for Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Similarity3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<&Similarity3<S>> for Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &Similarity3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<Similarity3<S>> for &Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Similarity3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<'a, S> ops::Mul<&'a Similarity3<S>> for &Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &'a Similarity3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_affine_matrix())
+    }
+}
+
+impl<S> ops::Mul<Transform3<S>> for Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Transform3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_matrix())
+    }
+}
+
+impl<S> ops::Mul<&Transform3<S>> for Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &Transform3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_matrix())
+    }
+}
+
+impl<S> ops::Mul<Transform3<S>> for &Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: Transform3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_matrix())
+    }
+}
+
+impl<'a, S> ops::Mul<&'a Transform3<S>> for &Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Transform3<S>;
+
+    #[inline]
+    fn mul(self, other: &'a Transform3<S>) -> Self::Output {
+        Transform3::from_matrix_unchecked(self.matrix * other.to_matrix())
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Orthographic3Tol<S> {
+    matrix: Matrix4x4<S>,
+}
+
+impl<S> From<Matrix4x4<S>> for Orthographic3Tol<S> {
     #[inline]
     fn from(matrix: Matrix4x4<S>) -> Self {
         Self { matrix }
@@ -4278,6 +5210,26 @@ impl<S> Orthographic3Diff<S> {
     }
 }
 
+/// Generate an orthographic projection whose view box extents and near plane
+/// distance lie in the interval `[2^-10, 2^10]`, and whose far plane lies
+/// strictly beyond the near plane.
+#[cfg(feature = "arbitrary")]
+impl<'a, S> arbitrary::Arbitrary<'a> for Orthographic3<S>
+where
+    S: SimdScalarFloat,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let left = crate::arbitrary_support::positive_scalar(u)?;
+        let right = crate::arbitrary_support::positive_scalar(u)?;
+        let bottom = crate::arbitrary_support::positive_scalar(u)?;
+        let top = crate::arbitrary_support::positive_scalar(u)?;
+        let near = crate::arbitrary_support::positive_scalar(u)?;
+        let far = near + crate::arbitrary_support::positive_scalar(u)?;
+
+        Ok(Orthographic3::new(left, right, bottom, top, near, far))
+    }
+}
+
 impl<S> approx_cmp::AbsDiffEq for Orthographic3<S>
 where
     S: SimdScalarFloat,
@@ -4496,3 +5448,935 @@ where
         Orthographic3Tol::from(matrix)
     }
 }
+
+/// An orthographic projection transformation in two dimensions.
+///
+/// The two-dimensional orthographic projection maps a rectangle of the plane,
+/// e.g. the visible part of a two-dimensional scene or user interface, onto the
+/// canonical view rectangle `[-1, 1] x [-1, 1]` of normalized device coordinates.
+/// It is the two-dimensional analogue of [`Orthographic3`] with the depth axis
+/// removed, and it follows the same conventions.
+///
+/// # Vector Space Details
+///
+/// The **view space** is a vector space with a right-handed orthonormal frame
+/// defined as follows.
+///
+/// * The **origin** of the coordinate system is `[0, 0]^T`.
+/// * The **positive x-axis** is the horizontal direction and points right.
+/// * The **positive y-axis** is the vertical direction and points up.
+///
+/// The **canonical view rectangle** is a vector space with an orthonormal frame
+/// identical to the view space with bounds `[-1, 1] x [-1, 1]`.
+///
+/// # Parameter Specification
+///
+/// The orthographic projection is parametrized by the parameters `left`, `right`,
+/// `bottom`, and `top` such that
+///
+/// ```text
+/// left + right > 0
+/// bottom + top > 0
+/// ```
+///
+/// where the parameters define the placement of the edges of the view rectangle.
+///
+/// * `left` defines the location of the **left edge** by its distance along
+///   the **negative x-axis** from the origin of the coordinate frame.
+/// * `right` defines the location of the **right edge** by its distance along
+///   the **positive x-axis** from the origin of the coordinate frame.
+/// * `bottom` defines the location of the **bottom edge** by its distance along
+///   the **negative y-axis** from the origin of the coordinate frame.
+/// * `top` defines the location of the **top edge** by its distance along
+///   the **positive y-axis** from the origin of the coordinate frame.
+///
+/// The view rectangle is then `[-left, right] x [-bottom, top]`.
+///
+/// # Matrix Representation Of The Orthographic Projection Transformation
+///
+/// The underlying matrix is a homogeneous affine matrix with the following form
+///
+/// ```text
+/// [ m[0, 0]  0        m[2, 0] ]
+/// [ 0        m[1, 1]  m[2, 1] ]
+/// [ 0        0        1       ]
+/// ```
+///
+/// where
+///
+/// ```text
+/// m[0, 0] ==  2 / (right - (-left))                 == 2 / (right + left)
+/// m[2, 0] == -(right + (-left)) / (right - (-left)) == -(right - left) / (right + left)
+/// m[1, 1] ==  2 / (top - (-bottom))                 == 2 / (top + bottom)
+/// m[2, 1] == -(top + (-bottom)) / (top - (-bottom)) == -(top - bottom) / (top + bottom)
+/// ```
+///
+/// where the matrix entries are indexed in column-major order.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent),
+    serde(bound(deserialize = "S: serde::Deserialize<'de> + Copy"))
+)]
+pub struct Orthographic2<S> {
+    matrix: Matrix3x3<S>,
+}
+
+impl<S> Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new two-dimensional orthographic projection.
+    ///
+    /// The parameters `left`, `right`, `bottom`, and `top` are the distances of the
+    /// edges of the view rectangle from the origin along the **negative x-axis**,
+    /// **positive x-axis**, **negative y-axis**, and **positive y-axis** respectively,
+    /// so the view rectangle is `[-left, right] x [-bottom, top]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let left = 2_f64;
+    /// let right = 6_f64;
+    /// let bottom = 1_f64;
+    /// let top = 3_f64;
+    /// let orthographic = Orthographic2::new(left, right, bottom, top);
+    ///
+    /// assert_relative_eq!(orthographic.left(),   left,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(orthographic.right(),  right,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(orthographic.bottom(), bottom, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert_relative_eq!(orthographic.top(),    top,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[rustfmt::skip]
+    #[inline]
+    pub fn new(left: S, right: S, bottom: S, top: S) -> Self {
+        let zero = S::zero();
+        let one = S::one();
+        let two = one + one;
+        let c0r0 = two / (right + left);
+        let c1r1 = two / (top + bottom);
+        let c2r0 = -(right - left) / (right + left);
+        let c2r1 = -(top - bottom) / (top + bottom);
+
+        Self {
+            matrix: Matrix3x3::new(
+                c0r0, zero, zero,
+                zero, c1r1, zero,
+                c2r0, c2r1, one,
+            ),
+        }
+    }
+
+    /// Construct a two-dimensional orthographic projection from the bounds of
+    /// the view rectangle `[x_min, x_max] x [y_min, y_max]`.
+    ///
+    /// This is equivalent to `Orthographic2::new(-x_min, x_max, -y_min, y_max)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// // A user interface layer measured in pixels.
+    /// let orthographic = Orthographic2::from_bounds(0_f64, 800_f64, 0_f64, 600_f64);
+    ///
+    /// assert_relative_eq!(
+    ///     orthographic.project_point(&Point2::new(0_f64, 0_f64)),
+    ///     Point2::new(-1_f64, -1_f64),
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// assert_relative_eq!(
+    ///     orthographic.project_point(&Point2::new(800_f64, 600_f64)),
+    ///     Point2::new(1_f64, 1_f64),
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_bounds(x_min: S, x_max: S, y_min: S, y_max: S) -> Self {
+        Self::new(-x_min, x_max, -y_min, y_max)
+    }
+
+    /// Get the position of the **right edge** of the view rectangle along the
+    /// **positive x-axis**.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let expected = 6_f64;
+    /// let result = orthographic.right();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn right(&self) -> S {
+        // Following the derivation for `Orthographic3::right`,
+        // ```text
+        // (1 - m[2, 0]) / m[0, 0] == [(2 * right) / (right + left)] * [(right + left) / 2]
+        //                         == right
+        // ```
+        let one = S::one();
+
+        (one - self.matrix[2][0]) / self.matrix[0][0]
+    }
+
+    /// Get the position of the **left edge** of the view rectangle along the
+    /// **negative x-axis**.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let expected = 2_f64;
+    /// let result = orthographic.left();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn left(&self) -> S {
+        // Following the derivation for `Orthographic3::left`,
+        // ```text
+        // (1 + m[2, 0]) / m[0, 0] == [(2 * left) / (right + left)] * [(right + left) / 2]
+        //                         == left
+        // ```
+        let one = S::one();
+
+        (one + self.matrix[2][0]) / self.matrix[0][0]
+    }
+
+    /// Get the position of the **top edge** of the view rectangle along the
+    /// **positive y-axis**.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let expected = 3_f64;
+    /// let result = orthographic.top();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn top(&self) -> S {
+        // Following the derivation for `Orthographic3::top`,
+        // ```text
+        // (1 - m[2, 1]) / m[1, 1] == [(2 * top) / (top + bottom)] * [(top + bottom) / 2]
+        //                         == top
+        // ```
+        let one = S::one();
+
+        (one - self.matrix[2][1]) / self.matrix[1][1]
+    }
+
+    /// Get the position of the **bottom edge** of the view rectangle along the
+    /// **negative y-axis**.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let expected = 1_f64;
+    /// let result = orthographic.bottom();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn bottom(&self) -> S {
+        // Following the derivation for `Orthographic3::bottom`,
+        // ```text
+        // (1 + m[2, 1]) / m[1, 1] == [(2 * bottom) / (top + bottom)] * [(top + bottom) / 2]
+        //                         == bottom
+        // ```
+        let one = S::one();
+
+        (one + self.matrix[2][1]) / self.matrix[1][1]
+    }
+
+    /// Get the underlying matrix implementing the orthographic transformation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Matrix3x3;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let expected = Matrix3x3::new(
+    ///      1_f64 / 4_f64,  0_f64,         0_f64,
+    ///      0_f64,          1_f64 / 2_f64, 0_f64,
+    ///     -1_f64 / 2_f64, -1_f64 / 2_f64, 1_f64,
+    /// );
+    /// let result = orthographic.matrix();
+    ///
+    /// assert_eq!(result, &expected);
+    /// ```
+    #[inline]
+    pub const fn matrix(&self) -> &Matrix3x3<S> {
+        &self.matrix
+    }
+
+    /// Apply the orthographic projection transformation to a point.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let point = Point2::new(4_f64, 2_f64);
+    /// let expected = Point2::new(1_f64 / 2_f64, 1_f64 / 2_f64);
+    /// let result = orthographic.project_point(&point);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn project_point(&self, point: &Point2<S>) -> Point2<S> {
+        Point2::new(
+            self.matrix.c0r0 * point.x + self.matrix.c2r0,
+            self.matrix.c1r1 * point.y + self.matrix.c2r1,
+        )
+    }
+
+    /// Apply the orthographic projection transformation to a vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Vector2;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let vector = Vector2::new(4_f64, 2_f64);
+    /// let expected = Vector2::new(1_f64, 1_f64);
+    /// let result = orthographic.project_vector(&vector);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn project_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        Vector2::new(self.matrix.c0r0 * vector.x, self.matrix.c1r1 * vector.y)
+    }
+
+    /// Unproject a point from normalized devices coordinates back to
+    /// view space.
+    ///
+    /// This is the inverse operation of [`project_point`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Point2;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let point = Point2::new(4_f64, 2_f64);
+    /// let projected_point = orthographic.project_point(&point);
+    /// let expected = point;
+    /// let result = orthographic.unproject_point(&projected_point);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn unproject_point(&self, point: &Point2<S>) -> Point2<S> {
+        // The orthographic projection matrix has the form
+        // ```text
+        // [ c0r0 c1r0 c2r0 ]    [ 2/(r + l)   0          -(r - l)/(r + l) ]
+        // [ c0r1 c1r1 c2r1 ] == [ 0           2/(t + b)  -(t - b)/(t + b) ]
+        // [ c0r2 c1r2 c2r2 ]    [ 0           0           1               ]
+        // ```
+        //
+        // The inverse matrix of the orthographic projection matrix has the form
+        // ```text
+        // [ c0r0 c1r0 c2r0 ]    [ (r + l)/2   0           (r - l)/2 ]
+        // [ c0r1 c1r1 c2r1 ] == [ 0           (t + b)/2   (t - b)/2 ]
+        // [ c0r2 c1r2 c2r2 ]    [ 0           0           1         ]
+        // ```
+        //
+        // We can optimize the inverse calculation applying only
+        // c0r0, c1r1, c2r0, and c2r1 to the input point.
+        //
+        let one = S::one();
+        let one_half = one / (one + one);
+        let c0r0 = one_half * (self.right() + self.left());
+        let c1r1 = one_half * (self.top() + self.bottom());
+        let c2r0 = one_half * (self.right() - self.left());
+        let c2r1 = one_half * (self.top() - self.bottom());
+
+        Point2::new(c0r0 * point.x + c2r0, c1r1 * point.y + c2r1)
+    }
+
+    /// Unproject a vector from normalized device coordinates back to
+    /// view space.
+    ///
+    /// This is the inverse operation of [`project_vector`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Vector2;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let vector = Vector2::new(4_f64, 2_f64);
+    /// let projected_vector = orthographic.project_vector(&vector);
+    /// let expected = vector;
+    /// let result = orthographic.unproject_vector(&projected_vector);
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub fn unproject_vector(&self, vector: &Vector2<S>) -> Vector2<S> {
+        let one = S::one();
+        let one_half = one / (one + one);
+        let c0r0 = one_half * (self.right() + self.left());
+        let c1r1 = one_half * (self.top() + self.bottom());
+
+        Vector2::new(c0r0 * vector.x, c1r1 * vector.y)
+    }
+
+    /// Convert an orthographic projection to a projective matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Matrix3x3;
+    /// # use cglinalg_transform::Orthographic2;
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let expected = Matrix3x3::new(
+    ///      1_f64 / 4_f64,  0_f64,         0_f64,
+    ///      0_f64,          1_f64 / 2_f64, 0_f64,
+    ///     -1_f64 / 2_f64, -1_f64 / 2_f64, 1_f64,
+    /// );
+    /// let result = orthographic.to_projective_matrix();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub const fn to_projective_matrix(&self) -> Matrix3x3<S> {
+        self.matrix
+    }
+
+    /// Convert an orthographic projection to a generic transformation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Matrix3x3;
+    /// # use cglinalg_transform::{
+    /// #     Orthographic2,
+    /// #     Transform2,
+    /// # };
+    /// #
+    /// let orthographic = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+    /// let expected = Transform2::from_matrix_unchecked(Matrix3x3::new(
+    ///      1_f64 / 4_f64,  0_f64,         0_f64,
+    ///      0_f64,          1_f64 / 2_f64, 0_f64,
+    ///     -1_f64 / 2_f64, -1_f64 / 2_f64, 1_f64,
+    /// ));
+    /// let result = orthographic.to_transform();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    #[inline]
+    pub const fn to_transform(&self) -> Transform2<S> {
+        Transform2::from_matrix_unchecked(self.matrix)
+    }
+}
+
+impl<S> AsRef<Matrix3x3<S>> for Orthographic2<S> {
+    #[inline]
+    fn as_ref(&self) -> &Matrix3x3<S> {
+        &self.matrix
+    }
+}
+
+impl<S> fmt::Display for Orthographic2<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "Orthographic2 [{}]", self.matrix)
+    }
+}
+
+impl<S> From<Orthographic2<S>> for Matrix3x3<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn from(orthographic: Orthographic2<S>) -> Matrix3x3<S> {
+        orthographic.to_projective_matrix()
+    }
+}
+
+impl<S> ops::Mul<Point2<S>> for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: Point2<S>) -> Self::Output {
+        self.project_point(&other)
+    }
+}
+
+impl<S> ops::Mul<&Point2<S>> for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: &Point2<S>) -> Self::Output {
+        self.project_point(other)
+    }
+}
+
+impl<S> ops::Mul<Point2<S>> for &Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: Point2<S>) -> Self::Output {
+        self.project_point(&other)
+    }
+}
+
+impl<'a, S> ops::Mul<&'a Point2<S>> for &Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Point2<S>;
+
+    #[inline]
+    fn mul(self, other: &'a Point2<S>) -> Self::Output {
+        self.project_point(other)
+    }
+}
+
+impl<S> ops::Mul<Vector2<S>> for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Vector2<S>;
+
+    #[inline]
+    fn mul(self, other: Vector2<S>) -> Self::Output {
+        self.project_vector(&other)
+    }
+}
+
+impl<S> ops::Mul<&Vector2<S>> for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Vector2<S>;
+
+    #[inline]
+    fn mul(self, other: &Vector2<S>) -> Self::Output {
+        self.project_vector(other)
+    }
+}
+
+impl<S> ops::Mul<Vector2<S>> for &Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Vector2<S>;
+
+    #[inline]
+    fn mul(self, other: Vector2<S>) -> Self::Output {
+        self.project_vector(&other)
+    }
+}
+
+impl<'a, S> ops::Mul<&'a Vector2<S>> for &Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Output = Vector2<S>;
+
+    #[inline]
+    fn mul(self, other: &'a Vector2<S>) -> Self::Output {
+        self.project_vector(other)
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Orthographic2Tol<S> {
+    matrix: Matrix3x3<S>,
+}
+
+impl<S> From<Matrix3x3<S>> for Orthographic2Tol<S> {
+    #[inline]
+    fn from(matrix: Matrix3x3<S>) -> Self {
+        Self { matrix }
+    }
+}
+
+impl<S> From<&Matrix3x3<S>> for Orthographic2Tol<S>
+where
+    S: Copy,
+{
+    #[inline]
+    fn from(matrix: &Matrix3x3<S>) -> Self {
+        Self { matrix: *matrix }
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Orthographic2Diff<S> {
+    matrix: Matrix3x3<S>,
+}
+
+impl<S> Orthographic2Diff<S> {
+    #[inline]
+    const fn from(matrix: Matrix3x3<S>) -> Self {
+        Self { matrix }
+    }
+}
+
+/// Generate a two-dimensional orthographic projection whose view rectangle
+/// extents lie in the interval `[2^-10, 2^10]`.
+#[cfg(feature = "arbitrary")]
+impl<'a, S> arbitrary::Arbitrary<'a> for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let left = crate::arbitrary_support::positive_scalar(u)?;
+        let right = crate::arbitrary_support::positive_scalar(u)?;
+        let bottom = crate::arbitrary_support::positive_scalar(u)?;
+        let top = crate::arbitrary_support::positive_scalar(u)?;
+
+        Ok(Orthographic2::new(left, right, bottom, top))
+    }
+}
+
+impl<S> approx_cmp::AbsDiffEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Orthographic2Tol<<S as approx_cmp::AbsDiffEq>::Tolerance>;
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> bool {
+        approx_cmp::AbsDiffEq::abs_diff_eq(&self.matrix, &other.matrix, &max_abs_diff.matrix)
+    }
+}
+
+impl<S> approx_cmp::AbsDiffAllEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::AbsDiffAllEq>::AllTolerance;
+
+    #[inline]
+    fn abs_diff_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> bool {
+        approx_cmp::AbsDiffAllEq::abs_diff_all_eq(&self.matrix, &other.matrix, max_abs_diff)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Orthographic2Diff<<S as approx_cmp::AssertAbsDiffEq>::DebugAbsDiff>;
+    type DebugTolerance = Orthographic2Tol<<S as approx_cmp::AssertAbsDiffEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let matrix = approx_cmp::AssertAbsDiffEq::debug_abs_diff(&self.matrix, &other.matrix);
+
+        Orthographic2Diff::from(matrix)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let matrix = approx_cmp::AssertAbsDiffEq::debug_abs_diff_tolerance(&self.matrix, &other.matrix, &max_abs_diff.matrix);
+
+        Orthographic2Tol::from(matrix)
+    }
+}
+
+impl<S> approx_cmp::AssertAbsDiffAllEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Orthographic2Tol<<S as approx_cmp::AssertAbsDiffAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let matrix = approx_cmp::AssertAbsDiffAllEq::debug_abs_diff_all_tolerance(&self.matrix, &other.matrix, max_abs_diff);
+
+        Orthographic2Tol::from(matrix)
+    }
+}
+
+impl<S> approx_cmp::RelativeEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Orthographic2Tol<<S as approx_cmp::RelativeEq>::Tolerance>;
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_relative: &Self::Tolerance) -> bool {
+        approx_cmp::RelativeEq::relative_eq(&self.matrix, &other.matrix, &max_abs_diff.matrix, &max_relative.matrix)
+    }
+}
+
+impl<S> approx_cmp::RelativeAllEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::RelativeAllEq>::AllTolerance;
+
+    #[inline]
+    fn relative_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_relative: &Self::AllTolerance) -> bool {
+        approx_cmp::RelativeAllEq::relative_all_eq(&self.matrix, &other.matrix, max_abs_diff, max_relative)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Orthographic2Diff<<S as approx_cmp::AssertRelativeEq>::DebugAbsDiff>;
+    type DebugTolerance = Orthographic2Tol<<S as approx_cmp::AssertRelativeEq>::DebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let matrix = approx_cmp::AssertRelativeEq::debug_abs_diff(&self.matrix, &other.matrix);
+
+        Orthographic2Diff::from(matrix)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let matrix = approx_cmp::AssertRelativeEq::debug_abs_diff_tolerance(&self.matrix, &other.matrix, &max_abs_diff.matrix);
+
+        Orthographic2Tol::from(matrix)
+    }
+
+    #[inline]
+    fn debug_relative_tolerance(&self, other: &Self, max_relative: &Self::Tolerance) -> Self::DebugTolerance {
+        let matrix = approx_cmp::AssertRelativeEq::debug_relative_tolerance(&self.matrix, &other.matrix, &max_relative.matrix);
+
+        Orthographic2Tol::from(matrix)
+    }
+}
+
+impl<S> approx_cmp::AssertRelativeAllEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Orthographic2Tol<<S as approx_cmp::AssertRelativeAllEq>::AllDebugTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let matrix = approx_cmp::AssertRelativeAllEq::debug_abs_diff_all_tolerance(&self.matrix, &other.matrix, max_abs_diff);
+
+        Orthographic2Tol::from(matrix)
+    }
+
+    #[inline]
+    fn debug_relative_all_tolerance(&self, other: &Self, max_relative: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let matrix = approx_cmp::AssertRelativeAllEq::debug_relative_all_tolerance(&self.matrix, &other.matrix, max_relative);
+
+        Orthographic2Tol::from(matrix)
+    }
+}
+
+impl<S> approx_cmp::UlpsEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type Tolerance = Orthographic2Tol<<S as approx_cmp::UlpsEq>::Tolerance>;
+    type UlpsTolerance = Orthographic2Tol<<S as approx_cmp::UlpsEq>::UlpsTolerance>;
+
+    fn ulps_eq(&self, other: &Self, max_abs_diff: &Self::Tolerance, max_ulps: &Self::UlpsTolerance) -> bool {
+        approx_cmp::UlpsEq::ulps_eq(&self.matrix, &other.matrix, &max_abs_diff.matrix, &max_ulps.matrix)
+    }
+}
+
+impl<S> approx_cmp::UlpsAllEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllTolerance = <S as approx_cmp::UlpsAllEq>::AllTolerance;
+    type AllUlpsTolerance = <S as approx_cmp::UlpsAllEq>::AllUlpsTolerance;
+
+    #[inline]
+    fn ulps_all_eq(&self, other: &Self, max_abs_diff: &Self::AllTolerance, max_ulps: &Self::AllUlpsTolerance) -> bool {
+        approx_cmp::UlpsAllEq::ulps_all_eq(&self.matrix, &other.matrix, max_abs_diff, max_ulps)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type DebugAbsDiff = Orthographic2Diff<<S as approx_cmp::AssertUlpsEq>::DebugAbsDiff>;
+    type DebugUlpsDiff = Orthographic2Diff<<S as approx_cmp::AssertUlpsEq>::DebugUlpsDiff>;
+    type DebugTolerance = Orthographic2Tol<<S as approx_cmp::AssertUlpsEq>::DebugTolerance>;
+    type DebugUlpsTolerance = Orthographic2Tol<<S as approx_cmp::AssertUlpsEq>::DebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff(&self, other: &Self) -> Self::DebugAbsDiff {
+        let matrix = approx_cmp::AssertUlpsEq::debug_abs_diff(&self.matrix, &other.matrix);
+
+        Orthographic2Diff::from(matrix)
+    }
+
+    #[inline]
+    fn debug_ulps_diff(&self, other: &Self) -> Self::DebugUlpsDiff {
+        let matrix = approx_cmp::AssertUlpsEq::debug_ulps_diff(&self.matrix, &other.matrix);
+
+        Orthographic2Diff::from(matrix)
+    }
+
+    #[inline]
+    fn debug_abs_diff_tolerance(&self, other: &Self, max_abs_diff: &Self::Tolerance) -> Self::DebugTolerance {
+        let matrix = approx_cmp::AssertUlpsEq::debug_abs_diff_tolerance(&self.matrix, &other.matrix, &max_abs_diff.matrix);
+
+        Orthographic2Tol::from(matrix)
+    }
+
+    #[inline]
+    fn debug_ulps_tolerance(&self, other: &Self, max_ulps: &Self::UlpsTolerance) -> Self::DebugUlpsTolerance {
+        let matrix = approx_cmp::AssertUlpsEq::debug_ulps_tolerance(&self.matrix, &other.matrix, &max_ulps.matrix);
+
+        Orthographic2Tol::from(matrix)
+    }
+}
+
+impl<S> approx_cmp::AssertUlpsAllEq for Orthographic2<S>
+where
+    S: SimdScalarFloat,
+{
+    type AllDebugTolerance = Orthographic2Tol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugTolerance>;
+    type AllDebugUlpsTolerance = Orthographic2Tol<<S as approx_cmp::AssertUlpsAllEq>::AllDebugUlpsTolerance>;
+
+    #[inline]
+    fn debug_abs_diff_all_tolerance(&self, other: &Self, max_abs_diff: &Self::AllTolerance) -> Self::AllDebugTolerance {
+        let matrix = approx_cmp::AssertUlpsAllEq::debug_abs_diff_all_tolerance(&self.matrix, &other.matrix, max_abs_diff);
+
+        Orthographic2Tol::from(matrix)
+    }
+
+    #[inline]
+    fn debug_ulps_all_tolerance(&self, other: &Self, max_ulps: &Self::AllUlpsTolerance) -> Self::AllDebugUlpsTolerance {
+        let matrix = approx_cmp::AssertUlpsAllEq::debug_ulps_all_tolerance(&self.matrix, &other.matrix, max_ulps);
+
+        Orthographic2Tol::from(matrix)
+    }
+}
+
+/// Compute the element of the Halton low discrepancy sequence with base `base`
+/// at index `index`.
+///
+/// The element of the sequence is the radical inverse of `index` in base `base`:
+/// the digits of `index` written in base `base` are mirrored about the radix point.
+/// The result lies in the interval `[0, 1)`. The element at index `0` is `0`, so
+/// sampling usually starts from index `1`.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_transform::halton;
+/// #
+/// let expected = [1_f64 / 2_f64, 1_f64 / 4_f64, 3_f64 / 4_f64, 1_f64 / 8_f64];
+/// let result: [f64; 4] = [halton(1, 2), halton(2, 2), halton(3, 2), halton(4, 2)];
+///
+/// assert_eq!(result, expected);
+///
+/// let expected = [1_f64 / 3_f64, 2_f64 / 3_f64, 1_f64 / 9_f64, 4_f64 / 9_f64];
+/// let result: [f64; 4] = [halton(1, 3), halton(2, 3), halton(3, 3), halton(4, 3)];
+///
+/// assert_relative_eq!(result[0], expected[0], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// assert_relative_eq!(result[1], expected[1], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// assert_relative_eq!(result[2], expected[2], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// assert_relative_eq!(result[3], expected[3], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// ```
+pub fn halton<S>(index: u32, base: u32) -> S
+where
+    S: SimdScalarFloat,
+{
+    assert!(base >= 2, "The base of a Halton sequence must be at least two. Got base = {}.", base);
+
+    let base_s: S = cglinalg_numeric::cast(base);
+    let mut fraction = S::one();
+    let mut result = S::zero();
+    let mut i = index;
+    while i > 0 {
+        fraction /= base_s;
+        result += fraction * cglinalg_numeric::cast::<u32, S>(i % base);
+        i /= base;
+    }
+
+    result
+}
+
+/// Compute a subpixel jitter sample from the two-dimensional Halton sequence
+/// with bases `2` and `3` at index `index`.
+///
+/// The sample is an offset in pixels in `[-1 / 2, 1 / 2) x [-1 / 2, 1 / 2)`,
+/// centered on the pixel, and is suitable for [`Perspective3::jitter`] and
+/// [`Orthographic3::jitter`]. Temporal anti-aliasing usually cycles through a
+/// short prefix of the sequence, e.g. the indices `1..=8` or `1..=16`.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_core::Vector2;
+/// # use cglinalg_transform::halton_jitter;
+/// #
+/// let expected = Vector2::new(0_f64, -1_f64 / 6_f64);
+/// let result = halton_jitter(1);
+///
+/// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+///
+/// let expected = Vector2::new(-1_f64 / 4_f64, 1_f64 / 6_f64);
+/// let result = halton_jitter(2);
+///
+/// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+/// ```
+#[inline]
+pub fn halton_jitter<S>(index: u32) -> Vector2<S>
+where
+    S: SimdScalarFloat,
+{
+    let one_half = S::one() / (S::one() + S::one());
+
+    Vector2::new(halton::<S>(index, 2) - one_half, halton::<S>(index, 3) - one_half)
+}
@@ -33,6 +33,7 @@ pub type Reflection3<S> = Reflection<S, 3>;
 /// should use [`Reflection2`] or [`Reflection3`] instead of this type directly.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Reflection<S, const N: usize> {
     normal: Vector<S, N>,
     bias: Point<S, N>,
@@ -614,6 +615,33 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de, S, const N: usize> serde::Deserialize<'de> for Reflection<S, N>
+where
+    S: SimdScalarFloat + serde::Deserialize<'de>,
+{
+    /// Deserialize a reflection.
+    ///
+    /// The deserialized normal vector must have unit norm up to the square root of
+    /// the default epsilon of the scalar type. Reflections whose normal vector is
+    /// not normalized are rejected.
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Reflection", bound(deserialize = "S: SimdScalarFloat + serde::Deserialize<'de>"))]
+        struct ReflectionFields<S, const N: usize> {
+            normal: Unit<Vector<S, N>>,
+            bias: Point<S, N>,
+        }
+
+        let fields = ReflectionFields::<S, N>::deserialize(deserializer)?;
+
+        Ok(Self::from_normal_bias(&fields.normal, &fields.bias))
+    }
+}
+
 impl<S, const N: usize> ops::Mul<Point<S, N>> for Reflection<S, N>
 where
     S: SimdScalarFloat,
@@ -931,6 +959,22 @@ impl<S, const N: usize> ReflectionDiff<S, N> {
     }
 }
 
+/// Generate a reflection across a plane with an arbitrary unit normal, whose bias
+/// coordinates lie in the interval `[-1024, 1024]`.
+#[cfg(feature = "arbitrary")]
+impl<'a, S, const N: usize> arbitrary::Arbitrary<'a> for Reflection<S, N>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let normal: Unit<Vector<S, N>> = u.arbitrary()?;
+        let bias = Point::from_vector(&crate::arbitrary_support::finite_vector(u)?);
+
+        Ok(Reflection::from_normal_bias(&normal, &bias))
+    }
+}
+
 impl<S, const N: usize> approx_cmp::AbsDiffEq for Reflection<S, N>
 where
     S: SimdScalarFloat,
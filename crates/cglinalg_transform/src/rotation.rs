@@ -51,6 +51,7 @@ pub type Rotation3<S> = Rotation<S, 3>;
 /// three dimensions.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct Rotation<S, const N: usize> {
     matrix: Matrix<S, N, N>,
 }
@@ -453,6 +454,57 @@ where
         }
     }
 
+    /// Construct a rotation from a matrix, provided that the matrix is a
+    /// rotation matrix.
+    ///
+    /// The matrix must be orthogonal up to the square root of the default epsilon
+    /// of the scalar type, and must have positive determinant. Reflections,
+    /// non-orthogonal matrices, and matrices with non-finite elements yield `None`.
+    ///
+    /// # Example (Two Dimensions)
+    ///
+    /// ```
+    /// # use cglinalg_core::Matrix2x2;
+    /// # use cglinalg_transform::Rotation2;
+    /// #
+    /// let matrix = Matrix2x2::new(
+    ///      0_f64, 1_f64,
+    ///     -1_f64, 0_f64,
+    /// );
+    /// let reflection = Matrix2x2::new(
+    ///     1_f64,  0_f64,
+    ///     0_f64, -1_f64,
+    /// );
+    ///
+    /// assert_eq!(Rotation2::try_from_matrix(&matrix).map(|rotation| rotation.to_matrix()), Some(matrix));
+    /// assert!(Rotation2::try_from_matrix(&reflection).is_none());
+    /// ```
+    ///
+    /// # Example (Three Dimensions)
+    ///
+    /// ```
+    /// # use cglinalg_core::Matrix3x3;
+    /// # use cglinalg_transform::Rotation3;
+    /// #
+    /// let matrix = Matrix3x3::new(
+    ///      0_f64, 1_f64, 0_f64,
+    ///     -1_f64, 0_f64, 0_f64,
+    ///      0_f64, 0_f64, 1_f64,
+    /// );
+    /// let scaled = matrix * 2_f64;
+    ///
+    /// assert_eq!(Rotation3::try_from_matrix(&matrix).map(|rotation| rotation.to_matrix()), Some(matrix));
+    /// assert!(Rotation3::try_from_matrix(&scaled).is_none());
+    /// ```
+    #[inline]
+    pub fn try_from_matrix(matrix: &Matrix<S, N, N>) -> Option<Self> {
+        if is_rotation_matrix(matrix) {
+            Some(Self { matrix: *matrix })
+        } else {
+            None
+        }
+    }
+
     /// Convert a rotation transformation to a matrix.
     ///
     /// The resulting matrix is not an affine. For an affine matrix,
@@ -650,6 +702,82 @@ impl<S, const N: usize> AsRef<Matrix<S, N, N>> for Rotation<S, N> {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de, S, const N: usize> serde::Deserialize<'de> for Rotation<S, N>
+where
+    S: SimdScalarFloat + serde::Deserialize<'de>,
+{
+    /// Deserialize a rotation matrix.
+    ///
+    /// The deserialized matrix must be orthogonal up to the square root of the
+    /// default epsilon of the scalar type, and must have positive determinant.
+    /// Reflections and non-orthogonal matrices are rejected.
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        let matrix = Matrix::<S, N, N>::deserialize(deserializer)?;
+
+        Self::try_from_matrix(&matrix).ok_or_else(|| serde::de::Error::custom("matrix is not a rotation matrix"))
+    }
+}
+
+/// Determine whether a matrix is a rotation matrix, i.e. whether its columns
+/// form an orthonormal basis with positive orientation.
+fn is_rotation_matrix<S, const N: usize>(matrix: &Matrix<S, N, N>) -> bool
+where
+    S: SimdScalarFloat,
+{
+    if !matrix.is_finite() {
+        return false;
+    }
+
+    let tolerance = S::default_epsilon().sqrt();
+    for i in 0..N {
+        for j in 0..N {
+            let dot = matrix[i].dot(&matrix[j]);
+            let expected = if i == j { S::one() } else { S::zero() };
+            if (dot - expected).abs() > tolerance {
+                return false;
+            }
+        }
+    }
+
+    // The determinant of an orthogonal matrix is either `1` or `-1`, so Gaussian
+    // elimination with partial pivoting only needs to track its sign.
+    let mut data: [[S; N]; N] = *matrix.as_ref();
+    let mut is_positive = true;
+    for column in 0..N {
+        let mut pivot = column;
+        for row in (column + 1)..N {
+            if data[column][row].abs() > data[column][pivot].abs() {
+                pivot = row;
+            }
+        }
+        if data[column][pivot] == S::zero() {
+            return false;
+        }
+        if pivot != column {
+            for entry in data.iter_mut() {
+                entry.swap(column, pivot);
+            }
+            is_positive = !is_positive;
+        }
+        if data[column][column] < S::zero() {
+            is_positive = !is_positive;
+        }
+        for row in (column + 1)..N {
+            let factor = data[column][row] / data[column][column];
+            for entry in data.iter_mut().skip(column) {
+                let value = entry[column];
+                entry[row] -= factor * value;
+            }
+        }
+    }
+
+    is_positive
+}
+
 impl<S, const N: usize, const NPLUS1: usize> From<Rotation<S, N>> for Matrix<S, NPLUS1, NPLUS1>
 where
     S: SimdScalarFloat,
@@ -1964,6 +2092,80 @@ impl<S, const N: usize> RotationDiff<S, N> {
     }
 }
 
+/// Sample a rotation in two dimensions uniformly distributed over the group
+/// of rotations in the plane.
+#[cfg(feature = "rand")]
+impl<S> rand::distributions::Distribution<Rotation2<S>> for rand::distributions::Standard
+where
+    S: SimdScalarFloat,
+    rand::distributions::Standard: rand::distributions::Distribution<S>,
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Rotation2<S> {
+        let angle = rand::distributions::Distribution::<Radians<S>>::sample(self, rng);
+
+        Rotation2::from_angle(angle)
+    }
+}
+
+/// Sample a rotation in three dimensions uniformly distributed over the group
+/// of rotations in three dimensions.
+///
+/// The rotation is constructed from a uniformly distributed unit quaternion
+/// generated with Shoemake's subgroup algorithm.
+#[cfg(feature = "rand")]
+impl<S> rand::distributions::Distribution<Rotation3<S>> for rand::distributions::Standard
+where
+    S: SimdScalarFloat,
+    rand::distributions::Standard: rand::distributions::Distribution<S>,
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Rotation3<S> {
+        let quaternion = rand::distributions::Distribution::<Unit<Quaternion<S>>>::sample(self, rng);
+
+        Rotation3::from_quaternion(&quaternion)
+    }
+}
+
+/// Generate a rotation in two dimensions whose angle lies in the interval
+/// `[-pi, pi]` radians.
+#[cfg(feature = "arbitrary")]
+impl<'a, S> arbitrary::Arbitrary<'a> for Rotation2<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let angle = crate::arbitrary_support::scalar_in_range(u, -S::pi(), S::pi())?;
+
+        Ok(Rotation2::from_angle(Radians(angle)))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u32 as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
+/// Generate a rotation in three dimensions from an arbitrary unit quaternion.
+#[cfg(feature = "arbitrary")]
+impl<'a, S> arbitrary::Arbitrary<'a> for Rotation3<S>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let quaternion: Unit<Quaternion<S>> = u.arbitrary()?;
+
+        Ok(Rotation3::from_quaternion(&quaternion))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <Unit<Quaternion<S>> as arbitrary::Arbitrary<'a>>::size_hint(depth)
+    }
+}
+
 impl<S, const N: usize> approx_cmp::AbsDiffEq for Rotation<S, N>
 where
     S: SimdScalarFloat,
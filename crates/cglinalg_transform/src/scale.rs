@@ -44,6 +44,7 @@ pub type Scale3<S> = Scale<S, 3>;
 /// ```
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct Scale<S, const N: usize> {
     vector: Vector<S, N>,
 }
@@ -869,6 +870,26 @@ impl<S, const N: usize> ScaleDiff<S, N> {
     }
 }
 
+/// Generate an invertible nonuniform scale transformation whose scaling factors
+/// have either sign and absolute values in the interval `[2^-10, 2^10]`.
+#[cfg(feature = "arbitrary")]
+impl<'a, S, const N: usize> arbitrary::Arbitrary<'a> for Scale<S, N>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut vector = Vector::zero();
+        for i in 0..N {
+            let magnitude: S = crate::arbitrary_support::positive_scalar(u)?;
+            let is_negative: bool = u.arbitrary()?;
+            vector[i] = if is_negative { -magnitude } else { magnitude };
+        }
+
+        Ok(Scale::from_nonuniform_scale(&vector))
+    }
+}
+
 impl<S, const N: usize> approx_cmp::AbsDiffEq for Scale<S, N>
 where
     S: SimdScalarFloat,
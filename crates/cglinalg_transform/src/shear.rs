@@ -35,6 +35,7 @@ pub type Shear3<S> = Shear<S, 3>;
 /// should use [`Shear2`] or [`Shear3`] instead of this type directly.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Shear<S, const N: usize> {
     shear_factor: S,
     origin: Point<S, N>,
@@ -1125,6 +1126,40 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de, S, const N: usize> serde::Deserialize<'de> for Shear<S, N>
+where
+    S: SimdScalarFloat + serde::Deserialize<'de>,
+{
+    /// Deserialize a shearing transformation.
+    ///
+    /// The deserialized shearing direction and normal vector must have unit norm,
+    /// and must be orthogonal to each other, up to the square root of the default
+    /// epsilon of the scalar type. Inconsistent shearing transformations are rejected.
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Shear", bound(deserialize = "S: SimdScalarFloat + serde::Deserialize<'de>"))]
+        struct ShearFields<S, const N: usize> {
+            shear_factor: S,
+            origin: Point<S, N>,
+            direction: Unit<Vector<S, N>>,
+            normal: Unit<Vector<S, N>>,
+        }
+
+        let fields = ShearFields::<S, N>::deserialize(deserializer)?;
+        let tolerance = S::default_epsilon().sqrt();
+
+        if fields.direction.dot(&fields.normal).abs() <= tolerance {
+            Ok(Self::from_affine_shear(fields.shear_factor, &fields.origin, &fields.direction, &fields.normal))
+        } else {
+            Err(serde::de::Error::custom("shearing direction is not orthogonal to the normal vector"))
+        }
+    }
+}
+
 impl<S, const N: usize> ops::Mul<Point<S, N>> for Shear<S, N>
 where
     S: SimdScalarSigned,
@@ -2053,6 +2088,52 @@ impl<S, const N: usize> ShearDiff<S, N> {
 }
 
 
+/// Generate a shearing transformation with an arbitrary unit normal, a unit
+/// shearing direction orthogonal to it, and a shear factor and origin
+/// coordinates in the interval `[-1024, 1024]`.
+///
+/// The shearing direction is obtained by removing the normal component from an
+/// arbitrary unit vector. When what remains is too short to normalize reliably,
+/// the direction is taken from the coordinate axis least aligned with the normal
+/// instead. Shearing needs at least two dimensions, so generating a shearing
+/// transformation in fewer dimensions always fails.
+#[cfg(feature = "arbitrary")]
+impl<'a, S, const N: usize> arbitrary::Arbitrary<'a> for Shear<S, N>
+where
+    S: SimdScalarFloat,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if N < 2 {
+            return Err(arbitrary::Error::IncorrectFormat);
+        }
+
+        let shear_factor = crate::arbitrary_support::finite_scalar(u)?;
+        let origin = Point::from_vector(&crate::arbitrary_support::finite_vector(u)?);
+        let normal: Unit<Vector<S, N>> = u.arbitrary()?;
+        let candidate: Unit<Vector<S, N>> = u.arbitrary()?;
+        let threshold = S::default_epsilon().sqrt();
+        let project_out_normal = |vector: &Vector<S, N>| vector - normal.as_ref() * vector.dot(normal.as_ref());
+        let first_pass = Unit::try_from_value(project_out_normal(candidate.as_ref()), threshold).unwrap_or_else(|| {
+            let mut axis_index = 0;
+            for i in 1..N {
+                if normal[i].abs() < normal[axis_index].abs() {
+                    axis_index = i;
+                }
+            }
+
+            let mut axis = Vector::zero();
+            axis[axis_index] = S::one();
+
+            Unit::from_value(project_out_normal(&axis))
+        });
+        // Projecting out the normal a second time removes the rounding error the
+        // first normalization amplifies when the remainder is short.
+        let direction = Unit::from_value(project_out_normal(first_pass.as_ref()));
+
+        Ok(Shear::from_affine_shear(shear_factor, &origin, &direction, &normal))
+    }
+}
+
 impl<S, const N: usize> approx_cmp::AbsDiffEq for Shear<S, N>
 where
     S: SimdScalarFloat,
@@ -39,6 +39,11 @@ pub type Similarity3<S> = Similarity<S, 3>;
 /// scaling, followed by the rotation, and finally the translation.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "S: SimdScalarFloat + serde::Deserialize<'de>"))
+)]
 pub struct Similarity<S, const N: usize> {
     isometry: Isometry<S, N>,
     scale: S,
@@ -1854,6 +1859,23 @@ impl<S, const N: usize> SimilarityDiff<S, N> {
     }
 }
 
+/// Generate a similarity from an arbitrary isometry and a positive uniform
+/// scaling factor in the interval `[2^-10, 2^10]`.
+#[cfg(feature = "arbitrary")]
+impl<'a, S, const N: usize> arbitrary::Arbitrary<'a> for Similarity<S, N>
+where
+    S: SimdScalarFloat,
+    Isometry<S, N>: arbitrary::Arbitrary<'a>,
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let isometry: Isometry<S, N> = u.arbitrary()?;
+        let scale = crate::arbitrary_support::positive_scalar(u)?;
+
+        Ok(Similarity::from_parts(isometry.translation(), isometry.rotation(), scale))
+    }
+}
+
 impl<S, const N: usize> approx_cmp::AbsDiffEq for Similarity<S, N>
 where
     S: SimdScalarFloat,
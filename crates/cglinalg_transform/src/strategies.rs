@@ -0,0 +1,601 @@
+use crate::camera::Camera2;
+use crate::isometry::{
+    Isometry2,
+    Isometry3,
+};
+use crate::projection::{
+    Orthographic2,
+    Orthographic3,
+    Perspective3,
+};
+use crate::reflection::Reflection;
+use crate::rotation::{
+    Rotation2,
+    Rotation3,
+};
+use crate::scale::Scale;
+use crate::shear::Shear;
+use crate::similarity::{
+    Similarity2,
+    Similarity3,
+};
+use crate::transform::{
+    Transform2,
+    Transform3,
+};
+use crate::translation::Translation;
+use crate::viewport::Viewport;
+use cglinalg_core::strategies::{
+    point_from_range,
+    scalar_from_range,
+    unit_quaternion,
+    unit_vector,
+    vector_from_range,
+    vector_signed_from_abs_range,
+};
+use cglinalg_core::{
+    Point,
+    Point2,
+    Unit,
+    Vector,
+    Vector2,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_trigonometry::strategies::radians_from_range;
+
+use proptest::prelude::*;
+
+
+/// Generate translations whose displacement components lie in the range
+/// `[min_distance, max_distance]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(translation in strategies::translation_from_range::<f64, 3>(-10_f64, 10_f64))| {
+///     prop_assert!(translation.vector().x.abs() <= 10_f64);
+/// });
+/// ```
+pub fn translation_from_range<S, const N: usize>(min_distance: S, max_distance: S) -> impl Strategy<Value = Translation<S, N>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    vector_from_range(min_distance, max_distance).prop_map(|vector| Translation::from_vector(&vector))
+}
+
+/// Generate nonuniform scale transformations whose scaling factors have either
+/// sign and absolute values in the range `[min_scale, max_scale]`.
+///
+/// The bounds must satisfy `0 <= min_scale < max_scale`. A positive lower bound
+/// guarantees that every generated scale transformation is invertible.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(scale in strategies::scale_signed_from_abs_range::<f64, 3>(0.5_f64, 2_f64))| {
+///     prop_assert!(scale.to_vector().as_slice().iter().all(|factor| factor.abs() >= 0.5_f64));
+/// });
+/// ```
+pub fn scale_signed_from_abs_range<S, const N: usize>(min_scale: S, max_scale: S) -> impl Strategy<Value = Scale<S, N>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    vector_signed_from_abs_range(min_scale, max_scale).prop_map(|vector| Scale::from_nonuniform_scale(&vector))
+}
+
+/// Generate reflections across planes with arbitrary unit normals, whose bias
+/// coordinates lie in the range `[min_distance, max_distance]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(reflection in strategies::reflection_from_range::<f64, 3>(-10_f64, 10_f64))| {
+///     prop_assert!((reflection.normal().norm() - 1_f64).abs() <= 1e-12);
+/// });
+/// ```
+pub fn reflection_from_range<S, const N: usize>(min_distance: S, max_distance: S) -> impl Strategy<Value = Reflection<S, N>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (unit_vector(), point_from_range(min_distance, max_distance)).prop_map(|(normal, bias)| Reflection::from_normal_bias(&normal, &bias))
+}
+
+/// Generate two-dimensional rotations whose angles lie in the range
+/// `[min_angle, max_angle]` radians.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use core::f64;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(rotation in strategies::rotation2_from_range(0_f64, f64::consts::FRAC_PI_2))| {
+///     prop_assert!(rotation.matrix()[0][0] >= -1e-12);
+/// });
+/// ```
+pub fn rotation2_from_range<S>(min_angle: S, max_angle: S) -> impl Strategy<Value = Rotation2<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    radians_from_range(min_angle, max_angle).prop_map(Rotation2::from_angle)
+}
+
+/// Generate arbitrary two-dimensional rotations.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::relative_eq;
+/// # use cglinalg_core::Matrix2x2;
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(rotation in strategies::rotation2::<f64>())| {
+///     let product = rotation * rotation.inverse();
+///
+///     prop_assert!(relative_eq!(product.matrix(), &Matrix2x2::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON));
+/// });
+/// ```
+pub fn rotation2<S>() -> impl Strategy<Value = Rotation2<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    rotation2_from_range(S::zero(), S::two_pi())
+}
+
+/// Generate three-dimensional rotations about arbitrary axes whose angles lie
+/// in the range `[min_angle, max_angle]` radians.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(rotation in strategies::rotation3_from_range(0_f64, 0.1_f64))| {
+///     prop_assert!(rotation.matrix().trace() >= 2_f64);
+/// });
+/// ```
+pub fn rotation3_from_range<S>(min_angle: S, max_angle: S) -> impl Strategy<Value = Rotation3<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (unit_vector(), radians_from_range(min_angle, max_angle)).prop_map(|(axis, angle)| Rotation3::from_axis_angle(&axis, angle))
+}
+
+/// Generate arbitrary three-dimensional rotations.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::relative_eq;
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(rotation in strategies::rotation3::<f64>())| {
+///     prop_assert!(relative_eq!(rotation.matrix().determinant(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON));
+/// });
+/// ```
+pub fn rotation3<S>() -> impl Strategy<Value = Rotation3<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    unit_quaternion().prop_map(|quaternion| Rotation3::from_quaternion(&quaternion))
+}
+
+/// Generate two-dimensional isometries whose rotation angles lie in the range
+/// `[min_angle, max_angle]` radians, and whose translation components lie in the
+/// range `[min_distance, max_distance]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use core::f64;
+/// # use proptest::prelude::*;
+/// #
+/// let strategy = strategies::isometry2_from_range(0_f64, f64::consts::PI, -10_f64, 10_f64);
+/// proptest!(|(isometry in strategy)| {
+///     prop_assert!(isometry.translation().vector().y.abs() <= 10_f64);
+/// });
+/// ```
+pub fn isometry2_from_range<S>(min_angle: S, max_angle: S, min_distance: S, max_distance: S) -> impl Strategy<Value = Isometry2<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        rotation2_from_range(min_angle, max_angle),
+        translation_from_range(min_distance, max_distance),
+    )
+        .prop_map(|(rotation, translation)| Isometry2::from_parts(&translation, &rotation))
+}
+
+/// Generate three-dimensional isometries whose rotations are about arbitrary
+/// axes with angles in the range `[min_angle, max_angle]` radians, and whose
+/// translation components lie in the range `[min_distance, max_distance]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use core::f64;
+/// # use proptest::prelude::*;
+/// #
+/// let strategy = strategies::isometry3_from_range(0_f64, f64::consts::PI, -10_f64, 10_f64);
+/// proptest!(|(isometry in strategy)| {
+///     prop_assert!(isometry.translation().vector().z.abs() <= 10_f64);
+/// });
+/// ```
+pub fn isometry3_from_range<S>(min_angle: S, max_angle: S, min_distance: S, max_distance: S) -> impl Strategy<Value = Isometry3<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        rotation3_from_range(min_angle, max_angle),
+        translation_from_range(min_distance, max_distance),
+    )
+        .prop_map(|(rotation, translation)| Isometry3::from_parts(&translation, &rotation))
+}
+
+/// Generate two-dimensional similarities whose uniform scaling factors lie in the
+/// range `[min_scale, max_scale]`, whose rotation angles lie in the range
+/// `[min_angle, max_angle]` radians, and whose translation components lie in the
+/// range `[min_distance, max_distance]`.
+///
+/// A positive lower bound on the scale guarantees that every generated similarity
+/// is invertible.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use core::f64;
+/// # use proptest::prelude::*;
+/// #
+/// let strategy = strategies::similarity2_from_range(0.5_f64, 2_f64, 0_f64, f64::consts::PI, -10_f64, 10_f64);
+/// proptest!(|(similarity in strategy)| {
+///     prop_assert!(similarity.scale() >= 0.5_f64 && similarity.scale() <= 2_f64);
+/// });
+/// ```
+pub fn similarity2_from_range<S>(
+    min_scale: S,
+    max_scale: S,
+    min_angle: S,
+    max_angle: S,
+    min_distance: S,
+    max_distance: S,
+) -> impl Strategy<Value = Similarity2<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        scalar_from_range(min_scale, max_scale),
+        rotation2_from_range(min_angle, max_angle),
+        translation_from_range(min_distance, max_distance),
+    )
+        .prop_map(|(scale, rotation, translation)| Similarity2::from_parts(&translation, &rotation, scale))
+}
+
+/// Generate three-dimensional similarities whose uniform scaling factors lie in
+/// the range `[min_scale, max_scale]`, whose rotations are about arbitrary axes
+/// with angles in the range `[min_angle, max_angle]` radians, and whose
+/// translation components lie in the range `[min_distance, max_distance]`.
+///
+/// A positive lower bound on the scale guarantees that every generated similarity
+/// is invertible.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use core::f64;
+/// # use proptest::prelude::*;
+/// #
+/// let strategy = strategies::similarity3_from_range(0.5_f64, 2_f64, 0_f64, f64::consts::PI, -10_f64, 10_f64);
+/// proptest!(|(similarity in strategy)| {
+///     prop_assert!(similarity.scale() >= 0.5_f64 && similarity.scale() <= 2_f64);
+/// });
+/// ```
+pub fn similarity3_from_range<S>(
+    min_scale: S,
+    max_scale: S,
+    min_angle: S,
+    max_angle: S,
+    min_distance: S,
+    max_distance: S,
+) -> impl Strategy<Value = Similarity3<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        scalar_from_range(min_scale, max_scale),
+        rotation3_from_range(min_angle, max_angle),
+        translation_from_range(min_distance, max_distance),
+    )
+        .prop_map(|(scale, rotation, translation)| Similarity3::from_parts(&translation, &rotation, scale))
+}
+
+/// Generate shearing transformations with arbitrary unit normals and unit
+/// shearing directions orthogonal to them, whose shear factors lie in the range
+/// `[min_shear, max_shear]`, and whose origin coordinates lie in the range
+/// `[min_distance, max_distance]`.
+///
+/// Shearing needs at least two dimensions. Candidate shearing directions that are
+/// too close to parallel to the normal to orthogonalize reliably are rejected.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(shear in strategies::shear_from_range::<f64, 3>(-2_f64, 2_f64, -10_f64, 10_f64))| {
+///     prop_assert!(shear.direction().dot(&shear.normal()).abs() <= 1e-12);
+/// });
+/// ```
+pub fn shear_from_range<S, const N: usize>(
+    min_shear: S,
+    max_shear: S,
+    min_distance: S,
+    max_distance: S,
+) -> impl Strategy<Value = Shear<S, N>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        scalar_from_range(min_shear, max_shear),
+        point_from_range(min_distance, max_distance),
+        unit_vector(),
+        unit_vector(),
+    )
+        .prop_filter_map(
+            "shearing direction is parallel to the normal",
+            |(shear_factor, origin, normal, candidate): (S, Point<S, N>, Unit<Vector<S, N>>, Unit<Vector<S, N>>)| {
+                let threshold = S::default_epsilon().sqrt();
+                let project_out_normal = |vector: &Vector<S, N>| vector - normal.as_ref() * vector.dot(normal.as_ref());
+                let first_pass = Unit::try_from_value(project_out_normal(candidate.as_ref()), threshold)?;
+                // Projecting out the normal a second time removes the rounding error the
+                // first normalization amplifies when the remainder is short.
+                let direction = Unit::from_value(project_out_normal(first_pass.as_ref()));
+
+                Some(Shear::from_affine_shear(shear_factor, &origin, &direction, &normal))
+            },
+        )
+}
+
+/// Generate invertible two-dimensional affine transformations whose singular
+/// values lie in the range `[min_scale, max_scale]`, and whose translation
+/// components lie in the range `[min_distance, max_distance]`.
+///
+/// The linear part of each transformation is a rotation, followed by a
+/// nonuniform scale with factors of either sign, followed by another rotation.
+/// The bounds must satisfy `0 < min_scale < max_scale`, which guarantees that
+/// every generated transformation is invertible.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(transform in strategies::transform2_from_range(0.5_f64, 2_f64, -10_f64, 10_f64))| {
+///     prop_assert!(transform.try_inverse().is_some());
+/// });
+/// ```
+pub fn transform2_from_range<S>(min_scale: S, max_scale: S, min_distance: S, max_distance: S) -> impl Strategy<Value = Transform2<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        rotation2(),
+        scale_signed_from_abs_range(min_scale, max_scale),
+        rotation2(),
+        translation_from_range(min_distance, max_distance),
+    )
+        .prop_map(|(outer_rotation, scale, inner_rotation, translation)| -> Transform2<S> {
+            translation.to_transform() * outer_rotation.to_transform() * scale.to_transform() * inner_rotation.to_transform()
+        })
+}
+
+/// Generate invertible three-dimensional affine transformations whose singular
+/// values lie in the range `[min_scale, max_scale]`, and whose translation
+/// components lie in the range `[min_distance, max_distance]`.
+///
+/// The linear part of each transformation is a rotation, followed by a
+/// nonuniform scale with factors of either sign, followed by another rotation.
+/// The bounds must satisfy `0 < min_scale < max_scale`, which guarantees that
+/// every generated transformation is invertible.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(transform in strategies::transform3_from_range(0.5_f64, 2_f64, -10_f64, 10_f64))| {
+///     prop_assert!(transform.try_inverse().is_some());
+/// });
+/// ```
+pub fn transform3_from_range<S>(min_scale: S, max_scale: S, min_distance: S, max_distance: S) -> impl Strategy<Value = Transform3<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        rotation3(),
+        scale_signed_from_abs_range(min_scale, max_scale),
+        rotation3(),
+        translation_from_range(min_distance, max_distance),
+    )
+        .prop_map(|(outer_rotation, scale, inner_rotation, translation)| -> Transform3<S> {
+            translation.to_transform() * outer_rotation.to_transform() * scale.to_transform() * inner_rotation.to_transform()
+        })
+}
+
+/// Generate perspective projections whose view frustum extents, near plane
+/// distance, and distance from the near plane to the far plane lie in the range
+/// `[min_value, max_value]`.
+///
+/// The bounds must satisfy `0 < min_value < max_value`, which guarantees that
+/// every generated view frustum is non-degenerate.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(perspective in strategies::perspective3_from_range(0.1_f64, 100_f64))| {
+///     prop_assert!(perspective.near() > 0_f64 && perspective.far() > perspective.near());
+/// });
+/// ```
+pub fn perspective3_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Perspective3<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+    )
+        .prop_map(|(left, right, bottom, top, near, depth)| Perspective3::new(left, right, bottom, top, near, near + depth))
+}
+
+/// Generate orthographic projections whose view box extents, near plane
+/// distance, and distance from the near plane to the far plane lie in the range
+/// `[min_value, max_value]`.
+///
+/// The bounds must satisfy `0 < min_value < max_value`, which guarantees that
+/// every generated view box is non-degenerate.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(orthographic in strategies::orthographic3_from_range(0.1_f64, 100_f64))| {
+///     prop_assert!(orthographic.near() > 0_f64 && orthographic.far() > orthographic.near());
+/// });
+/// ```
+pub fn orthographic3_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Orthographic3<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+    )
+        .prop_map(|(left, right, bottom, top, near, depth)| Orthographic3::new(left, right, bottom, top, near, near + depth))
+}
+
+/// Generate two-dimensional orthographic projections whose view rectangle
+/// extents lie in the range `[min_value, max_value]`.
+///
+/// The bounds must satisfy `0 < min_value < max_value`, which guarantees that
+/// every generated view rectangle is non-degenerate.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(orthographic in strategies::orthographic2_from_range(0.1_f64, 100_f64))| {
+///     prop_assert!(orthographic.right() >= 0.1_f64 && orthographic.top() >= 0.1_f64);
+/// });
+/// ```
+pub fn orthographic2_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Orthographic2<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+        scalar_from_range(min_value, max_value),
+    )
+        .prop_map(|(left, right, bottom, top)| Orthographic2::new(left, right, bottom, top))
+}
+
+/// Generate two-dimensional cameras whose view rectangle extents lie in the
+/// range `[min_extent, max_extent]`, whose position coordinates lie in the range
+/// `[min_distance, max_distance]`, whose angles are arbitrary, and whose zoom
+/// factors lie in the range `[min_zoom, max_zoom]`.
+///
+/// The bounds must satisfy `0 < min_extent < max_extent` and
+/// `0 < min_zoom < max_zoom`, which guarantees that every generated camera is
+/// invertible.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// let strategy = strategies::camera2_from_range(0.1_f64, 100_f64, -10_f64, 10_f64, 0.5_f64, 2_f64);
+/// proptest!(|(camera in strategy)| {
+///     prop_assert!(camera.view().scale() >= 0.5_f64 && camera.view().scale() <= 2_f64);
+/// });
+/// ```
+pub fn camera2_from_range<S>(
+    min_extent: S,
+    max_extent: S,
+    min_distance: S,
+    max_distance: S,
+    min_zoom: S,
+    max_zoom: S,
+) -> impl Strategy<Value = Camera2<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (
+        orthographic2_from_range(min_extent, max_extent),
+        point_from_range(min_distance, max_distance),
+        radians_from_range(-S::pi(), S::pi()),
+        scalar_from_range(min_zoom, max_zoom),
+    )
+        .prop_map(|(projection, position, angle, zoom)| Camera2::new(&projection, &position, angle, zoom))
+}
+
+/// Generate viewports whose lower left corner coordinates lie in the range
+/// `[min_distance, max_distance]`, whose widths and heights lie in the range
+/// `[min_size, max_size]`, and whose depth range is the default depth range
+/// `[0, 1]`.
+///
+/// The bounds must satisfy `0 < min_size < max_size`, which guarantees that
+/// every generated viewport has a positive area.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_transform::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(viewport in strategies::viewport_from_range(0_f64, 100_f64, 1_f64, 1000_f64))| {
+///     prop_assert!(viewport.width() >= 1_f64 && viewport.height() >= 1_f64);
+/// });
+/// ```
+pub fn viewport_from_range<S>(min_distance: S, max_distance: S, min_size: S, max_size: S) -> impl Strategy<Value = Viewport<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    (point_from_range(min_distance, max_distance), vector_from_range(min_size, max_size))
+        .prop_map(|(origin, size): (Point2<S>, Vector2<S>)| Viewport::new(origin.x, origin.y, size.x, size.y, S::zero(), S::one()))
+}
@@ -31,8 +31,21 @@ pub type Transform3<S> = Transform<S, 3, 4>;
 
 
 /// A generic transformation in homogeneous coordinates.
+///
+/// The underlying matrix of a generic transformation need not be an affine
+/// matrix. In particular, a generic transformation can represent a projective
+/// transformation, such as the composition of a perspective or orthographic
+/// projection with a view transformation. Points are mapped through the
+/// transformation in homogeneous coordinates followed by a perspective
+/// division.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent),
+    serde(bound(deserialize = "S: serde::Deserialize<'de> + Copy"))
+)]
 pub struct Transform<S, const N: usize, const NPLUS1: usize>
 where
     ShapeConstraint: DimAdd<Const<N>, Const<1>, Output = Const<NPLUS1>>,
@@ -251,6 +264,30 @@ where
     pub const fn to_matrix(&self) -> Matrix<S, NPLUS1, NPLUS1> {
         self.matrix
     }
+
+    /// Determine whether the transformation is affine, i.e. whether the bottom
+    /// row of its matrix is `[0, ..., 0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::Matrix4x4;
+    /// # use cglinalg_transform::{
+    /// #     Perspective3,
+    /// #     Transform3,
+    /// # };
+    /// #
+    /// let perspective = Perspective3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 100_f64);
+    /// let affine = Transform3::from_matrix_unchecked(Matrix4x4::from_affine_scale(2_f64));
+    /// let projective = perspective.to_transform();
+    ///
+    /// assert!(affine.is_affine());
+    /// assert!(!projective.is_affine());
+    /// ```
+    #[inline]
+    pub fn is_affine(&self) -> bool {
+        (0..N).all(|column| self.matrix[column][N].is_zero()) && self.matrix[N][N].is_one()
+    }
 }
 
 impl<S, const N: usize, const NPLUS1: usize> Transform<S, N, NPLUS1>
@@ -262,6 +299,13 @@ where
 {
     /// Apply the transformation to a vector.
     ///
+    /// The transformation must be affine. The function ignores the bottom row of
+    /// the matrix, so for a projective transformation, such as the product of a
+    /// projection and a view transformation, the result is not the difference of
+    /// the projected endpoints of the vector. Use [`Transform::project_point`]
+    /// on both endpoints instead. In debug builds, the function panics if the
+    /// transformation is not affine.
+    ///
     /// # Example (Two Dimensions)
     ///
     /// ```
@@ -308,10 +352,19 @@ where
     /// ```
     #[inline]
     pub fn apply_vector(&self, vector: &Vector<S, N>) -> Vector<S, N> {
+        debug_assert!(self.is_affine(), "apply_vector requires an affine transformation");
+
         (self.matrix * vector.extend(S::zero())).contract()
     }
 
-    /// Apply the inverse of the transformation to a point.
+    /// Apply the transformation to a point.
+    ///
+    /// The transformation must be affine. A projective transformation, such as the
+    /// product of a projection and a view transformation, sends the points on one
+    /// plane to infinity, so use [`Transform::project_point`] and
+    /// [`Transform::unproject_point`] for those instead. They return `None` for
+    /// points at infinity. In debug builds, the function panics if the
+    /// transformation is not affine.
     ///
     /// # Example (Two Dimensions)
     ///
@@ -359,6 +412,8 @@ where
     /// ```
     #[inline]
     pub fn apply_point(&self, point: &Point<S, N>) -> Point<S, N> {
+        debug_assert!(self.is_affine(), "apply_point requires an affine transformation");
+
         Point::from_homogeneous(&(self.matrix * point.to_homogeneous())).unwrap()
     }
 }
@@ -856,6 +911,100 @@ where
     pub fn inverse_apply_point(&self, point: &Point3<S>) -> Option<Point3<S>> {
         self.try_inverse().map(|matrix_inverse| matrix_inverse.apply_point(point))
     }
+
+    /// Project a point through a projective transformation.
+    ///
+    /// The transformation matrix need not be affine. This function maps the point
+    /// to homogeneous coordinates, applies the transformation matrix, and then
+    /// performs the perspective division by the resulting `w` component. In
+    /// particular, for a view-projection transformation obtained by multiplying a
+    /// [`Perspective3`] or an [`Orthographic3`] with a view transformation, this
+    /// maps a point in world space to normalized device coordinates.
+    ///
+    /// For an affine transformation, this agrees with [`Transform3::apply_point`].
+    ///
+    /// The function returns `None` if the projected point lies at infinity, i.e. the
+    /// `w` component of the transformed homogeneous point is zero. For a perspective
+    /// view-projection transformation, this happens exactly for the points on the
+    /// plane through the eye parallel to the near plane.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// # };
+    /// #
+    /// let perspective = Perspective3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 100_f64);
+    /// let view = Isometry3::look_at_rh(
+    ///     &Point3::new(0_f64, 0_f64, 10_f64),
+    ///     &Point3::origin(),
+    ///     &Vector3::unit_y(),
+    /// );
+    /// let view_projection = perspective * view;
+    /// let point = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let expected = perspective.project_point(&view.apply_point(&point));
+    /// let result = view_projection.project_point(&point).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// assert!(view_projection.project_point(&Point3::new(1_f64, 2_f64, 10_f64)).is_none());
+    /// ```
+    ///
+    /// [`Perspective3`]: crate::Perspective3
+    /// [`Orthographic3`]: crate::Orthographic3
+    #[inline]
+    pub fn project_point(&self, point: &Point3<S>) -> Option<Point3<S>> {
+        Point3::from_homogeneous(&(self.matrix * point.to_homogeneous()))
+    }
+
+    /// Unproject a point through a projective transformation.
+    ///
+    /// This is the inverse operation of [`Transform3::project_point`]. For a
+    /// view-projection transformation, this maps a point in normalized device
+    /// coordinates back to world space. When unprojecting many points, it is
+    /// cheaper to compute the inverse transformation once with [`Transform3::try_inverse`]
+    /// and project the points through the inverse transformation instead.
+    ///
+    /// The function returns `None` if the transformation is not invertible, or if
+    /// the unprojected point lies at infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::{
+    /// #     Point3,
+    /// #     Vector3,
+    /// # };
+    /// # use cglinalg_transform::{
+    /// #     Isometry3,
+    /// #     Perspective3,
+    /// # };
+    /// #
+    /// let perspective = Perspective3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 100_f64);
+    /// let view = Isometry3::look_at_rh(
+    ///     &Point3::new(0_f64, 0_f64, 10_f64),
+    ///     &Point3::origin(),
+    ///     &Vector3::unit_y(),
+    /// );
+    /// let view_projection = perspective * view;
+    /// let expected = Point3::new(1_f64, 2_f64, 3_f64);
+    /// let projected_point = view_projection.project_point(&expected).unwrap();
+    /// let result = view_projection.unproject_point(&projected_point).unwrap();
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn unproject_point(&self, point: &Point3<S>) -> Option<Point3<S>> {
+        self.try_inverse()
+            .and_then(|matrix_inverse| Point3::from_homogeneous(&(matrix_inverse.matrix * point.to_homogeneous())))
+    }
 }
 
 
@@ -911,6 +1060,38 @@ where
     }
 }
 
+/// Generate an invertible affine transformation from an arbitrary similarity
+/// composed with an arbitrary nonuniform scale.
+///
+/// The transformation first scales by the nonuniform scale and then applies the
+/// similarity. Composing affine transformations keeps the last row of the
+/// matrix equal to `[0, ..., 0, 1]`, so every generated transformation maps
+/// points to points.
+#[cfg(feature = "arbitrary")]
+impl<'a, S, const N: usize, const NPLUS1: usize> arbitrary::Arbitrary<'a> for Transform<S, N, NPLUS1>
+where
+    S: SimdScalarFloat,
+    crate::similarity::Similarity<S, N>: arbitrary::Arbitrary<'a>,
+    ShapeConstraint: DimAdd<Const<N>, Const<1>, Output = Const<NPLUS1>>,
+    ShapeConstraint: DimAdd<Const<1>, Const<N>, Output = Const<NPLUS1>>,
+    ShapeConstraint: cglinalg_core::DimLt<Const<N>, Const<NPLUS1>>,
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let similarity: crate::similarity::Similarity<S, N> = u.arbitrary()?;
+        let scale: crate::scale::Scale<S, N> = u.arbitrary()?;
+        let mut matrix = similarity.to_affine_matrix();
+        // Right multiplying by a diagonal matrix scales the columns of the linear part.
+        let factors = scale.to_vector();
+        for i in 0..N {
+            for j in 0..N {
+                matrix[i][j] = matrix[i][j] * factors[i];
+            }
+        }
+
+        Ok(Transform::from_matrix_unchecked(matrix))
+    }
+}
+
 impl<S, const N: usize, const NPLUS1: usize> approx_cmp::AbsDiffEq for Transform<S, N, NPLUS1>
 where
     S: SimdScalarFloat,
@@ -59,6 +59,7 @@ pub type Translation3<S> = Translation<S, 3>;
 /// as desired.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct Translation<S, const N: usize> {
     pub(crate) vector: Vector<S, N>,
 }
@@ -896,6 +897,20 @@ impl<S, const N: usize> TranslationDiff<S, N> {
     }
 }
 
+/// Generate a translation whose components lie in the interval `[-1024, 1024]`.
+#[cfg(feature = "arbitrary")]
+impl<'a, S, const N: usize> arbitrary::Arbitrary<'a> for Translation<S, N>
+where
+    S: SimdScalarFloat,
+{
+    #[inline]
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let vector = crate::arbitrary_support::finite_vector(u)?;
+
+        Ok(Translation::from_vector(&vector))
+    }
+}
+
 impl<S, const N: usize> approx_cmp::AbsDiffEq for Translation<S, N>
 where
     S: SimdScalarFloat,
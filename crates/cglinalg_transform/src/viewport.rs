@@ -0,0 +1,257 @@
+use cglinalg_core::{
+    Point2,
+    Point3,
+    Vector2,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+use core::fmt;
+
+
+/// A viewport transformation.
+///
+/// A viewport maps normalized device coordinates to window coordinates. It
+/// consists of a rectangle of a window, given by its lower left corner `(x, y)`
+/// called the **origin**, and its `width` and `height`, and a depth range
+/// `[min_depth, max_depth]`. Following OpenGL's window coordinate conventions,
+/// the origin of the window is its lower left corner, and the **positive y-axis**
+/// points up. Window systems that place the origin at the upper left corner
+/// (e.g. for mouse coordinates) need to flip the **y-axis** with
+/// `y_window == window_height - y_mouse`.
+///
+/// # Viewport Transformation
+///
+/// A point `(x_ndc, y_ndc, z_ndc)` inside the canonical view volume
+/// `[-1, 1] x [-1, 1] x [-1, 1]` maps to the window coordinates
+///
+/// ```text
+/// x_window := x + (x_ndc + 1) * (width / 2)
+/// y_window := y + (y_ndc + 1) * (height / 2)
+/// z_window := min_depth + (z_ndc + 1) * ((max_depth - min_depth) / 2)
+/// ```
+///
+/// so that the canonical view volume maps onto the box
+/// `[x, x + width] x [y, y + height] x [min_depth, max_depth]`.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Viewport<S> {
+    origin: Point2<S>,
+    size: Vector2<S>,
+    min_depth: S,
+    max_depth: S,
+}
+
+impl<S> Viewport<S>
+where
+    S: SimdScalarFloat,
+{
+    /// Construct a new viewport.
+    ///
+    /// The parameters `x` and `y` are the window coordinates of the lower left
+    /// corner of the viewport, `width` and `height` are the dimensions of the
+    /// viewport, and `[min_depth, max_depth]` is the range of window depths
+    /// that the canonical view volume maps to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Vector2,
+    /// # };
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    ///
+    /// assert_eq!(viewport.origin(), Point2::new(10_f64, 20_f64));
+    /// assert_eq!(viewport.size(), Vector2::new(800_f64, 600_f64));
+    /// assert_eq!(viewport.min_depth(), 0_f64);
+    /// assert_eq!(viewport.max_depth(), 1_f64);
+    /// ```
+    #[inline]
+    pub const fn new(x: S, y: S, width: S, height: S, min_depth: S, max_depth: S) -> Self {
+        Self {
+            origin: Point2::new(x, y),
+            size: Vector2::new(width, height),
+            min_depth,
+            max_depth,
+        }
+    }
+
+    /// Construct a new viewport covering a window of dimensions `width` and `height`.
+    ///
+    /// The origin of the viewport is the lower left corner of the window, and the
+    /// depth range is the default depth range `[0, 1]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_core::{
+    /// #     Point2,
+    /// #     Vector2,
+    /// # };
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::from_size(800_f64, 600_f64);
+    ///
+    /// assert_eq!(viewport.origin(), Point2::new(0_f64, 0_f64));
+    /// assert_eq!(viewport.size(), Vector2::new(800_f64, 600_f64));
+    /// assert_eq!(viewport.min_depth(), 0_f64);
+    /// assert_eq!(viewport.max_depth(), 1_f64);
+    /// ```
+    #[inline]
+    pub fn from_size(width: S, height: S) -> Self {
+        Self::new(S::zero(), S::zero(), width, height, S::zero(), S::one())
+    }
+
+    /// Get the window coordinates of the lower left corner of the viewport.
+    #[inline]
+    pub fn origin(&self) -> Point2<S> {
+        self.origin
+    }
+
+    /// Get the dimensions of the viewport.
+    #[inline]
+    pub fn size(&self) -> Vector2<S> {
+        self.size
+    }
+
+    /// Get the width of the viewport.
+    #[inline]
+    pub fn width(&self) -> S {
+        self.size.x
+    }
+
+    /// Get the height of the viewport.
+    #[inline]
+    pub fn height(&self) -> S {
+        self.size.y
+    }
+
+    /// Get the window depth that the **near plane** of the canonical view
+    /// volume maps to.
+    #[inline]
+    pub fn min_depth(&self) -> S {
+        self.min_depth
+    }
+
+    /// Get the window depth that the **far plane** of the canonical view
+    /// volume maps to.
+    #[inline]
+    pub fn max_depth(&self) -> S {
+        self.max_depth
+    }
+
+    /// Get the aspect ratio of the viewport, i.e. the ratio of its width
+    /// to its height.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::from_size(800_f64, 600_f64);
+    ///
+    /// assert_eq!(viewport.aspect_ratio(), 4_f64 / 3_f64);
+    /// ```
+    #[inline]
+    pub fn aspect_ratio(&self) -> S {
+        self.size.x / self.size.y
+    }
+
+    /// Map a point in normalized device coordinates to window coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    ///
+    /// assert_relative_eq!(
+    ///     viewport.ndc_to_window(&Point3::new(-1_f64, -1_f64, -1_f64)),
+    ///     Point3::new(10_f64, 20_f64, 0_f64),
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// assert_relative_eq!(
+    ///     viewport.ndc_to_window(&Point3::new(0_f64, 0_f64, 0_f64)),
+    ///     Point3::new(410_f64, 320_f64, 1_f64 / 2_f64),
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// assert_relative_eq!(
+    ///     viewport.ndc_to_window(&Point3::new(1_f64, 1_f64, 1_f64)),
+    ///     Point3::new(810_f64, 620_f64, 1_f64),
+    ///     abs_diff_all <= 1e-10,
+    ///     relative_all <= f64::EPSILON,
+    /// );
+    /// ```
+    #[inline]
+    pub fn ndc_to_window(&self, point: &Point3<S>) -> Point3<S> {
+        let one = S::one();
+        let one_half = one / (one + one);
+
+        Point3::new(
+            self.origin.x + (point.x + one) * (self.size.x * one_half),
+            self.origin.y + (point.y + one) * (self.size.y * one_half),
+            self.min_depth + (point.z + one) * ((self.max_depth - self.min_depth) * one_half),
+        )
+    }
+
+    /// Map a point in window coordinates to normalized device coordinates.
+    ///
+    /// This is the inverse operation of [`Viewport::ndc_to_window`]. When the depth
+    /// range of the viewport is empty, i.e. `min_depth == max_depth`, every window
+    /// depth maps to the depth `0` in normalized device coordinates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use approx_cmp::assert_relative_eq;
+    /// # use cglinalg_core::Point3;
+    /// # use cglinalg_transform::Viewport;
+    /// #
+    /// let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    /// let point = Point3::new(1_f64 / 4_f64, -1_f64 / 2_f64, 1_f64 / 3_f64);
+    /// let window_point = viewport.ndc_to_window(&point);
+    /// let expected = point;
+    /// let result = viewport.window_to_ndc(&window_point);
+    ///
+    /// assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    /// ```
+    #[inline]
+    pub fn window_to_ndc(&self, point: &Point3<S>) -> Point3<S> {
+        let one = S::one();
+        let two = one + one;
+        let depth_range = self.max_depth - self.min_depth;
+        let z = if depth_range.is_zero() {
+            S::zero()
+        } else {
+            (two * (point.z - self.min_depth)) / depth_range - one
+        };
+
+        Point3::new(
+            (two * (point.x - self.origin.x)) / self.size.x - one,
+            (two * (point.y - self.origin.y)) / self.size.y - one,
+            z,
+        )
+    }
+}
+
+impl<S> fmt::Display for Viewport<S>
+where
+    S: fmt::Display,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "Viewport [origin={}, size={}, depth_range=[{}, {}]]",
+            self.origin, self.size, self.min_depth, self.max_depth
+        )
+    }
+}
@@ -0,0 +1,208 @@
+#[cfg(all(test, feature = "arbitrary"))]
+mod arbitrary_tests {
+    use approx_cmp::assert_relative_eq;
+    use arbitrary::{
+        Arbitrary,
+        Unstructured,
+    };
+    use cglinalg_core::{
+        Matrix2x2,
+        Matrix3x3,
+        Point3,
+    };
+    use cglinalg_transform::{
+        Camera2,
+        Isometry3,
+        Orthographic2,
+        Orthographic3,
+        Perspective3,
+        Reflection3,
+        Rotation2,
+        Rotation3,
+        Scale3,
+        Shear2,
+        Shear3,
+        Similarity2,
+        Transform3,
+        Translation3,
+    };
+    use rand::{
+        Rng,
+        SeedableRng,
+    };
+    use rand_isaac::IsaacRng;
+
+
+    fn inputs() -> Vec<Vec<u8>> {
+        let mut rng = IsaacRng::seed_from_u64(0);
+        let mut inputs = vec![vec![], vec![0x00; 256], vec![0xFF; 256]];
+        for _ in 0..1000 {
+            let length = rng.gen_range(0..256);
+            inputs.push((0..length).map(|_| rng.gen()).collect());
+        }
+
+        inputs
+    }
+
+    #[test]
+    fn test_rotation2_is_rotation() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let rotation = Rotation2::<f64>::arbitrary(&mut u).unwrap();
+            let matrix = rotation.matrix();
+
+            assert_relative_eq!(matrix.transpose() * matrix, Matrix2x2::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+            assert_relative_eq!(matrix.determinant(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_rotation3_is_rotation() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let rotation = Rotation3::<f64>::arbitrary(&mut u).unwrap();
+            let matrix = rotation.matrix();
+
+            assert_relative_eq!(matrix.transpose() * matrix, Matrix3x3::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+            assert_relative_eq!(matrix.determinant(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_isometry3_preserves_distances() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let isometry = Isometry3::<f64>::arbitrary(&mut u).unwrap();
+            let matrix = isometry.rotation().matrix();
+
+            assert_relative_eq!(matrix.transpose() * matrix, Matrix3x3::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON);
+            assert!(isometry.translation().vector().as_slice().iter().all(|component| component.is_finite()));
+        }
+    }
+
+    #[test]
+    fn test_similarity2_scale_is_positive() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let similarity = Similarity2::<f64>::arbitrary(&mut u).unwrap();
+
+            assert!(similarity.scale() > 0_f64 && similarity.scale().is_finite());
+        }
+    }
+
+    #[test]
+    fn test_reflection3_normal_is_unit() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let reflection = Reflection3::<f64>::arbitrary(&mut u).unwrap();
+
+            assert_relative_eq!(reflection.normal().norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_shear2_direction_orthogonal_to_normal() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let shear = Shear2::<f64>::arbitrary(&mut u).unwrap();
+
+            assert_relative_eq!(shear.direction().norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            assert_relative_eq!(shear.normal().norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            assert_relative_eq!(shear.direction().dot(&shear.normal()), 0_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_shear3_direction_orthogonal_to_normal() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let shear = Shear3::<f64>::arbitrary(&mut u).unwrap();
+
+            assert_relative_eq!(shear.direction().norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            assert_relative_eq!(shear.normal().norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            assert_relative_eq!(shear.direction().dot(&shear.normal()), 0_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_perspective3_frustum_is_valid() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let perspective = Perspective3::<f64>::arbitrary(&mut u).unwrap();
+
+            assert!(perspective.near() > 0_f64);
+            assert!(perspective.far() > perspective.near());
+            assert!(perspective.matrix().as_slice().iter().all(|entry| entry.is_finite()));
+        }
+    }
+
+    #[test]
+    fn test_orthographic3_view_box_is_valid() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let orthographic = Orthographic3::<f64>::arbitrary(&mut u).unwrap();
+
+            assert!(orthographic.near() > 0_f64);
+            assert!(orthographic.far() > orthographic.near());
+            assert!(orthographic.matrix().as_slice().iter().all(|entry| entry.is_finite()));
+        }
+    }
+
+    #[test]
+    fn test_orthographic2_is_invertible() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let orthographic = Orthographic2::<f64>::arbitrary(&mut u).unwrap();
+
+            assert!(orthographic.matrix().determinant() > 0_f64);
+        }
+    }
+
+    #[test]
+    fn test_camera2_zoom_is_positive() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let camera = Camera2::<f64>::arbitrary(&mut u).unwrap();
+
+            assert!(camera.zoom() > 0_f64 && camera.zoom().is_finite());
+            assert!(camera.angle().0.abs() <= core::f64::consts::PI);
+        }
+    }
+
+    #[test]
+    fn test_translation3_is_finite() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let translation = Translation3::<f64>::arbitrary(&mut u).unwrap();
+
+            assert!(translation.vector().as_slice().iter().all(|component| component.is_finite()));
+        }
+    }
+
+    #[test]
+    fn test_scale3_is_invertible() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let scale = Scale3::<f64>::arbitrary(&mut u).unwrap();
+            let inverse = scale.inverse();
+            let point = Point3::new(1_f64, 2_f64, 3_f64);
+
+            assert!(inverse.to_vector().as_slice().iter().all(|factor| factor.is_finite() && *factor != 0_f64));
+            assert_relative_eq!(inverse.apply_point(&scale.apply_point(&point)), point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_transform3_is_affine() {
+        for input in inputs() {
+            let mut u = Unstructured::new(&input);
+            let transform = Transform3::<f64>::arbitrary(&mut u).unwrap();
+            let matrix = transform.matrix();
+            let point = transform.apply_point(&Point3::new(1_f64, 2_f64, 3_f64));
+
+            assert_eq!([matrix[0][3], matrix[1][3], matrix[2][3], matrix[3][3]], [0_f64, 0_f64, 0_f64, 1_f64]);
+            assert!(point.as_slice().iter().all(|component| component.is_finite()));
+            assert!(transform.try_inverse().is_some());
+        }
+    }
+}
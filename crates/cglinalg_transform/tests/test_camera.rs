@@ -0,0 +1,159 @@
+use approx_cmp::assert_relative_eq;
+use cglinalg_core::{
+    Point2,
+    Vector2,
+};
+use cglinalg_transform::{
+    Camera2,
+    Isometry2,
+    Orthographic2,
+    Similarity2,
+};
+use cglinalg_trigonometry::{
+    Degrees,
+    Radians,
+};
+
+
+fn projection() -> Orthographic2<f64> {
+    Orthographic2::new(8_f64, 8_f64, 6_f64, 6_f64)
+}
+
+#[test]
+fn test_camera2_identity() {
+    let camera = Camera2::new(&projection(), &Point2::origin(), Radians(0_f64), 1_f64);
+    let point = Point2::new(4_f64, -3_f64);
+    let expected = Point2::new(1_f64 / 2_f64, -1_f64 / 2_f64);
+    let result = camera.project_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_position_projects_to_center() {
+    let position = Point2::new(-13_f64, 7_f64);
+    let camera = Camera2::new(&projection(), &position, Degrees(37_f64), 5_f64);
+    let expected = Point2::origin();
+    let result = camera.project_point(&position);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_zoom() {
+    let camera = Camera2::new(&projection(), &Point2::origin(), Radians(0_f64), 2_f64);
+    let point = Point2::new(2_f64, -3_f64 / 2_f64);
+    let expected = Point2::new(1_f64 / 2_f64, -1_f64 / 2_f64);
+    let result = camera.project_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_rotation() {
+    let camera = Camera2::new(&projection(), &Point2::origin(), Degrees(90_f64), 1_f64);
+    // The camera is rotated counterclockwise, so the world moves clockwise on screen.
+    let point = Point2::new(0_f64, 4_f64);
+    let expected = Point2::new(1_f64 / 2_f64, 0_f64);
+    let result = camera.project_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_unproject_point() {
+    let camera = Camera2::new(&projection(), &Point2::new(3_f64, -2_f64), Degrees(-123_f64), 3_f64 / 4_f64);
+    let expected = Point2::new(-5_f64, 11_f64);
+    let projected_point = camera.project_point(&expected);
+    let result = camera.unproject_point(&projected_point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_unproject_vector() {
+    let camera = Camera2::new(&projection(), &Point2::new(3_f64, -2_f64), Degrees(-123_f64), 3_f64 / 4_f64);
+    let expected = Vector2::new(-5_f64, 11_f64);
+    let projected_vector = camera.project_vector(&expected);
+    let result = camera.unproject_vector(&projected_vector);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_view_composes_with_similarity() {
+    let camera = Camera2::new(&projection(), &Point2::new(3_f64, -2_f64), Degrees(60_f64), 2_f64);
+    let model = Similarity2::from_angle(Degrees(45_f64));
+    let model_view = camera.view() * model;
+    let point = Point2::new(1_f64, 2_f64);
+    let expected = camera.view().apply_point(&model.apply_point(&point));
+    let result = model_view.apply_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_view_projection_matrix() {
+    let camera = Camera2::new(&projection(), &Point2::new(3_f64, -2_f64), Degrees(60_f64), 2_f64);
+    let point = Point2::new(1_f64, 2_f64);
+    let expected = camera.project_point(&point).to_homogeneous();
+    let result = camera.view_projection_matrix() * point.to_homogeneous();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_from_pose() {
+    let pose = Isometry2::from_angle_translation(Degrees(60_f64), &Vector2::new(3_f64, -2_f64));
+    let camera = Camera2::from_pose(&projection(), &pose, 2_f64);
+    let expected = Camera2::new(&projection(), &Point2::new(3_f64, -2_f64), Degrees(60_f64), 2_f64);
+
+    assert_relative_eq!(camera.position(), expected.position(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(camera.angle(), expected.angle(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(camera.pose(), pose, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_view_isometry_pose_inverse() {
+    let camera = Camera2::new(&projection(), &Point2::new(3_f64, -2_f64), Degrees(60_f64), 2_f64);
+    let point = Point2::new(1_f64, 2_f64);
+    let expected = point;
+    let result = camera.pose().apply_point(&camera.view_isometry().apply_point(&point));
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_pan_ndc_drags_scene() {
+    let mut camera = Camera2::new(&projection(), &Point2::new(3_f64, -2_f64), Degrees(60_f64), 2_f64);
+    let point = Point2::new(1_f64, 2_f64);
+    let ndc_point = camera.project_point(&point);
+    let drag = Vector2::new(-1_f64 / 8_f64, 1_f64 / 16_f64);
+    camera.pan_ndc(&(-drag));
+    let expected = ndc_point + drag;
+    let result = camera.project_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_zoom_by_about_fixed_point() {
+    let mut camera = Camera2::new(&projection(), &Point2::new(3_f64, -2_f64), Degrees(60_f64), 2_f64);
+    let point = Point2::new(1_f64, 2_f64);
+    let expected = camera.project_point(&point);
+    camera.zoom_by_about(1_f64 / 4_f64, &point);
+    let result = camera.project_point(&point);
+
+    assert_relative_eq!(camera.zoom(), 1_f64 / 2_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_camera2_rotate() {
+    let mut camera = Camera2::new(&projection(), &Point2::origin(), Degrees(30_f64), 1_f64);
+    camera.rotate(Degrees(60_f64));
+    let expected = Radians::from(Degrees(90_f64));
+    let result = camera.angle();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
@@ -0,0 +1,70 @@
+#[cfg(all(test, feature = "rand"))]
+mod distributions_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_core::{
+        Matrix3x3,
+        Vector2,
+        Vector3,
+    };
+    use cglinalg_transform::{
+        Rotation2,
+        Rotation3,
+    };
+    use rand::{
+        Rng,
+        SeedableRng,
+    };
+    use rand_isaac::IsaacRng;
+
+
+    #[test]
+    fn test_rotation2_uniform() {
+        let mut rng = IsaacRng::seed_from_u64(0);
+        let mut mean = Vector2::zero();
+        for _ in 0..100_000 {
+            let rotation: Rotation2<f64> = rng.gen();
+            let rotated = rotation.apply_vector(&Vector2::unit_x());
+
+            assert_relative_eq!(rotated.norm(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+            mean += rotated / 100_000_f64;
+        }
+
+        assert!(mean.norm() <= 0.01);
+    }
+
+    #[test]
+    fn test_rotation3_is_rotation() {
+        let mut rng = IsaacRng::seed_from_u64(1);
+        for _ in 0..1000 {
+            let rotation: Rotation3<f64> = rng.gen();
+            let matrix = rotation.matrix();
+
+            assert_relative_eq!(
+                matrix.transpose() * matrix,
+                Matrix3x3::identity(),
+                abs_diff_all <= 1e-12,
+                relative_all <= f64::EPSILON
+            );
+            assert_relative_eq!(matrix.determinant(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_rotation3_uniform() {
+        let mut rng = IsaacRng::seed_from_u64(2);
+        let mut mean = Vector3::zero();
+        let mut trace_mean = 0_f64;
+        for _ in 0..100_000 {
+            let rotation: Rotation3<f64> = rng.gen();
+            let matrix = rotation.matrix();
+
+            mean += rotation.apply_vector(&Vector3::unit_z()) / 100_000_f64;
+            trace_mean += matrix.trace() / 100_000_f64;
+        }
+
+        // A Haar distributed rotation has `E[R] == 0`, so both the rotated vectors and
+        // the trace average to zero.
+        assert!(mean.norm() <= 0.01);
+        assert!(trace_mean.abs() <= 0.02);
+    }
+}
@@ -1,14 +1,32 @@
 use approx_cmp::assert_relative_eq;
 use cglinalg_core::{
+    Matrix3x3,
     Matrix4x4,
+    Normed,
+    Point2,
     Point3,
+    Vector2,
     Vector3,
 };
 use cglinalg_transform::{
+    halton,
+    halton_jitter,
+    CameraIntrinsics,
+    Isometry3,
+    Orthographic2,
     Orthographic3,
     Perspective3,
+    Rotation3,
+    Similarity3,
+    Transform3,
+    Translation3,
+    Viewport,
+};
+use cglinalg_trigonometry::{
+    Angle,
+    Degrees,
+    Radians,
 };
-use cglinalg_trigonometry::Degrees;
 
 
 #[rustfmt::skip]
@@ -217,6 +235,299 @@ fn test_perspective_projection_unproject_vector() {
     assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
 }
 
+#[test]
+fn test_perspective_projection_asymmetric_project_point() {
+    let left = 4_f64;
+    let right = 2_f64;
+    let bottom = 1_f64;
+    let top = 3_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let projection = Perspective3::new(left, right, bottom, top, near, far);
+    let point = Point3::new(-2_f64, 2_f64, -50_f64);
+    let expected = {
+        let projected = projection.matrix() * point.to_homogeneous();
+        Point3::from_homogeneous(&projected).unwrap()
+    };
+    let result = projection.project_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_asymmetric_unproject_point() {
+    let left = 4_f64;
+    let right = 2_f64;
+    let bottom = 1_f64;
+    let top = 3_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let projection = Perspective3::new(left, right, bottom, top, near, far);
+    let expected = Point3::new(-2_f64, 2_f64, -50_f64);
+    let projected_point = projection.project_point(&expected);
+    let result = projection.unproject_point(&projected_point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_jitter() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let projection = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    let jitter = Vector2::new(-3_f64 / 8_f64, 1_f64 / 8_f64);
+    let jittered = projection.jitter(&jitter, 800_f64, 600_f64);
+    let point = Point3::new(-2_f64, 2_f64, -50_f64);
+    let expected = projection.project_point(&point) + Vector3::new(-3_f64 / 3200_f64, 1_f64 / 2400_f64, 0_f64);
+    let result = jittered.project_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_jitter_unproject_point() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let projection = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    let jittered = projection.jitter(&halton_jitter(3), 800_f64, 600_f64);
+    let expected = Point3::new(-2_f64, 2_f64, -50_f64);
+    let projected_point = jittered.project_point(&expected);
+    let result = jittered.unproject_point(&projected_point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_perspective_projection_sub_frustum_tiles_cover_viewport() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let projection = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    let lower_left = projection.sub_frustum(0_f64, 0_f64, 400_f64, 300_f64, 800_f64, 600_f64);
+    let upper_right = projection.sub_frustum(400_f64, 300_f64, 400_f64, 300_f64, 800_f64, 600_f64);
+
+    assert_relative_eq!(lower_left.left(),   projection.left(),   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(lower_left.right(),  0_f64,               abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(lower_left.bottom(), projection.bottom(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(lower_left.top(),    0_f64,               abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(lower_left.near(),   near,                abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(lower_left.far(),    far,                 abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+
+    assert_relative_eq!(upper_right.left(),   0_f64,              abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(upper_right.right(),  projection.right(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(upper_right.bottom(), 0_f64,              abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(upper_right.top(),    projection.top(),   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_sub_frustum_project_point() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let projection = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    let tile = projection.sub_frustum(400_f64, 300_f64, 400_f64, 300_f64, 800_f64, 600_f64);
+    let point = Point3::new(2_f64, 3_f64, -50_f64);
+    let projected_point = projection.project_point(&point);
+    // The upper right quadrant `[0, 1] x [0, 1]` maps onto `[-1, 1] x [-1, 1]`.
+    let expected = Point3::new(
+        2_f64 * projected_point.x - 1_f64,
+        2_f64 * projected_point.y - 1_f64,
+        projected_point.z,
+    );
+    let result = tile.project_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_sub_frustum_unproject_point() {
+    let vfov = Degrees(72_f64);
+    let aspect_ratio = 800_f64 / 600_f64;
+    let near = 0.1_f64;
+    let far = 100_f64;
+    let projection = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    let tile = projection.sub_frustum(400_f64, 0_f64, 400_f64, 300_f64, 800_f64, 600_f64);
+    let expected = Point3::new(2_f64, -3_f64, -50_f64);
+    let projected_point = tile.project_point(&expected);
+    let result = tile.unproject_point(&projected_point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_sub_frustum_entire_viewport() {
+    let left = 4_f64;
+    let right = 2_f64;
+    let bottom = 1_f64;
+    let top = 3_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let projection = Perspective3::new(left, right, bottom, top, near, far);
+    let expected = projection;
+    let result = projection.sub_frustum(0_f64, 0_f64, 640_f64, 480_f64, 640_f64, 480_f64);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_from_hfov_from_vfov() {
+    let hfov = Degrees(100_f64);
+    let aspect_ratio = 16_f64 / 9_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let vfov = Radians(2_f64 * ((Radians::from(hfov) / 2_f64).tan() / aspect_ratio).atan());
+    let expected = Perspective3::from_vfov(vfov, aspect_ratio, near, far);
+    let result = Perspective3::from_hfov(hfov, aspect_ratio, near, far);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_hfov_wide_angle() {
+    let hfov = Degrees(150_f64);
+    let perspective = Perspective3::from_hfov(hfov, 2_f64, 1_f64, 100_f64);
+    let expected = Radians::from(hfov);
+    let result = perspective.hfov();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_hfov_asymmetric() {
+    let perspective = Perspective3::new(1_f64, 3_f64, 2_f64, 2_f64, 1_f64, 100_f64);
+    let expected = Radians(f64::atan(1_f64) + f64::atan(3_f64));
+    let result = perspective.hfov();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_from_focal_length_and_sensor_size() {
+    let focal_length = 35_f64;
+    let sensor_width = 36_f64;
+    let sensor_height = 24_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let hfov = Radians(2_f64 * f64::atan(18_f64 / 35_f64));
+    let expected = Perspective3::from_hfov(hfov, sensor_width / sensor_height, near, far);
+    let result = Perspective3::from_focal_length_and_sensor_size(focal_length, sensor_width, sensor_height, near, far);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_from_intrinsics_to_intrinsics() {
+    let intrinsics = CameraIntrinsics::new(600_f64, 610_f64, 300_f64, 260_f64, 640_f64, 480_f64);
+    let perspective = Perspective3::from_intrinsics(&intrinsics, 1_f64 / 10_f64, 100_f64);
+    let result = perspective.to_intrinsics(640_f64, 480_f64);
+
+    assert_relative_eq!(result.fx(), intrinsics.fx(), abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    assert_relative_eq!(result.fy(), intrinsics.fy(), abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    assert_relative_eq!(result.cx(), intrinsics.cx(), abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    assert_relative_eq!(result.cy(), intrinsics.cy(), abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    assert_eq!(result.width(), intrinsics.width());
+    assert_eq!(result.height(), intrinsics.height());
+}
+
+#[test]
+fn test_perspective_projection_from_intrinsics_matches_pinhole_model() {
+    let (fx, fy, cx, cy) = (600_f64, 610_f64, 300_f64, 260_f64);
+    let (width, height) = (640_f64, 480_f64);
+    let intrinsics = CameraIntrinsics::new(fx, fy, cx, cy, width, height);
+    let perspective = Perspective3::from_intrinsics(&intrinsics, 1_f64 / 10_f64, 100_f64);
+    let viewport = Viewport::from_size(width, height);
+    let points = [
+        Point3::new(0_f64, 0_f64, -5_f64),
+        Point3::new(1_f64, 2_f64, -7_f64),
+        Point3::new(-3_f64, 1_f64, -4_f64),
+        Point3::new(2_f64, -2_f64, -11_f64),
+    ];
+    for point in points.iter() {
+        // The camera frame point is `(x, -y, -z)`.
+        let expected_u = fx * (point.x / -point.z) + cx;
+        let expected_v = fy * (-point.y / -point.z) + cy;
+        let window_point = viewport.ndc_to_window(&perspective.project_point(point));
+        let result_u = window_point.x - 1_f64 / 2_f64;
+        let result_v = height - window_point.y - 1_f64 / 2_f64;
+
+        assert_relative_eq!(result_u, expected_u, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result_v, expected_v, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_perspective_projection_cube_map_face_centers() {
+    let position = Point3::new(1_f64, 2_f64, 3_f64);
+    let views = Isometry3::cube_map_views(&position);
+    let projection = Perspective3::cube_map(1_f64 / 10_f64, 100_f64);
+    let directions = [
+        Vector3::unit_x(),
+        -Vector3::unit_x(),
+        Vector3::unit_y(),
+        -Vector3::unit_y(),
+        Vector3::unit_z(),
+        -Vector3::unit_z(),
+    ];
+    for (view, direction) in views.iter().zip(directions.iter()) {
+        let point = position + direction * 10_f64;
+        let result = projection.project_point(&view.apply_point(&point));
+
+        assert_relative_eq!(result.x, 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.y, 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert!(result.z > -1_f64 && result.z < 1_f64);
+    }
+}
+
+/// The cube map faces follow the orientation conventions of the OpenGL and Vulkan
+/// cube map face selection tables, mapping a direction `r` with major axis `ma`
+/// to the face coordinates `(sc / |ma|, tc / |ma|)`.
+#[test]
+fn test_perspective_projection_cube_map_face_orientation() {
+    let position = Point3::origin();
+    let views = Isometry3::cube_map_views(&position);
+    let projection = Perspective3::cube_map(1_f64 / 10_f64, 100_f64);
+    let r = Vector3::new(1_f64 / 2_f64, 1_f64 / 4_f64, 1_f64 / 8_f64);
+    let directions = [
+        Vector3::new(1_f64, r.y, r.z),
+        Vector3::new(-1_f64, r.y, r.z),
+        Vector3::new(r.x, 1_f64, r.z),
+        Vector3::new(r.x, -1_f64, r.z),
+        Vector3::new(r.x, r.y, 1_f64),
+        Vector3::new(r.x, r.y, -1_f64),
+    ];
+    let expected = [
+        Point2::new(-r.z, -r.y),
+        Point2::new(r.z, -r.y),
+        Point2::new(r.x, r.z),
+        Point2::new(r.x, -r.z),
+        Point2::new(r.x, -r.y),
+        Point2::new(-r.x, -r.y),
+    ];
+    for i in 0..6 {
+        let point = Point3::from_vector(&directions[i]);
+        let projected = projection.project_point(&views[i].apply_point(&point));
+        let result = Point2::new(projected.x, projected.y);
+
+        assert_relative_eq!(result, expected[i], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_perspective_projection_cube_map_field_of_view() {
+    let projection = Perspective3::cube_map(1_f64 / 10_f64, 100_f64);
+
+    assert_relative_eq!(projection.vfov(), Radians::full_turn_div_4(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(projection.hfov(), Radians::full_turn_div_4(), abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(projection.aspect_ratio(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
 #[rustfmt::skip]
 #[test]
 fn test_orthographic_projection_matrix() {
@@ -308,3 +619,461 @@ fn test_orthographic_projection_unproject_vector() {
 
     assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
 }
+
+#[test]
+fn test_orthographic_projection_jitter() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 2_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let projection = Orthographic3::new(left, right, bottom, top, near, far);
+    let jitter = Vector2::new(-3_f64 / 8_f64, 1_f64 / 8_f64);
+    let jittered = projection.jitter(&jitter, 800_f64, 600_f64);
+    let point = Point3::new(1_f64, 1_f64, 50_f64);
+    let expected = projection.project_point(&point) + Vector3::new(-3_f64 / 3200_f64, 1_f64 / 2400_f64, 0_f64);
+    let result = jittered.project_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_orthographic_projection_jitter_unproject_point() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 2_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let projection = Orthographic3::new(left, right, bottom, top, near, far);
+    let jittered = projection.jitter(&halton_jitter(5), 800_f64, 600_f64);
+    let expected = Point3::new(1_f64, 1_f64, 50_f64);
+    let projected_point = jittered.project_point(&expected);
+    let result = jittered.unproject_point(&projected_point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_orthographic_projection_sub_frustum_project_point() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 2_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let projection = Orthographic3::new(left, right, bottom, top, near, far);
+    let tile = projection.sub_frustum(0_f64, 300_f64, 400_f64, 300_f64, 800_f64, 600_f64);
+    let point = Point3::new(-1_f64, 1_f64, 50_f64);
+    let projected_point = projection.project_point(&point);
+    // The upper left quadrant `[-1, 0] x [0, 1]` maps onto `[-1, 1] x [-1, 1]`.
+    let expected = Point3::new(
+        2_f64 * projected_point.x + 1_f64,
+        2_f64 * projected_point.y - 1_f64,
+        projected_point.z,
+    );
+    let result = tile.project_point(&point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_orthographic_projection_sub_frustum_unproject_point() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 2_f64;
+    let near = 1_f64;
+    let far = 100_f64;
+    let projection = Orthographic3::new(left, right, bottom, top, near, far);
+    let tile = projection.sub_frustum(0_f64, 300_f64, 400_f64, 300_f64, 800_f64, 600_f64);
+    let expected = Point3::new(-1_f64, 1_f64, 50_f64);
+    let projected_point = tile.project_point(&expected);
+    let result = tile.unproject_point(&projected_point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+fn shadow_cascade_frustum_slice_corners(
+    perspective: &Perspective3<f64>,
+    view: &Isometry3<f64>,
+    split_near: f64,
+    split_far: f64,
+) -> [Point3<f64>; 8] {
+    let mut corners = [Point3::origin(); 8];
+    for (i, distance) in [split_near, split_far].into_iter().enumerate() {
+        let ratio = distance / perspective.near();
+        let x_min = -perspective.left() * ratio;
+        let x_max = perspective.right() * ratio;
+        let y_min = -perspective.bottom() * ratio;
+        let y_max = perspective.top() * ratio;
+        corners[4 * i] = view.inverse_apply_point(&Point3::new(x_min, y_min, -distance));
+        corners[4 * i + 1] = view.inverse_apply_point(&Point3::new(x_max, y_min, -distance));
+        corners[4 * i + 2] = view.inverse_apply_point(&Point3::new(x_min, y_max, -distance));
+        corners[4 * i + 3] = view.inverse_apply_point(&Point3::new(x_max, y_max, -distance));
+    }
+
+    corners
+}
+
+#[test]
+fn test_orthographic_projection_shadow_cascade_contains_frustum_slice() {
+    let perspective = Perspective3::from_vfov(Degrees(60_f64), 16_f64 / 9_f64, 1_f64 / 10_f64, 100_f64);
+    let view = Isometry3::look_at_rh(
+        &Point3::new(3_f64, 2_f64, 5_f64),
+        &Point3::new(0_f64, 0_f64, 0_f64),
+        &Vector3::unit_y(),
+    );
+    let light_direction = Vector3::new(1_f64, -3_f64, -2_f64);
+    let (split_near, split_far) = (2_f64, 15_f64);
+    let (light_view, projection) = Orthographic3::shadow_cascade(&perspective, &view, split_near, split_far, &light_direction);
+    let corners = shadow_cascade_frustum_slice_corners(&perspective, &view, split_near, split_far);
+    let tolerance = 1e-10;
+    for corner in corners.iter() {
+        let result = projection.project_point(&light_view.apply_point(corner));
+
+        assert!(result.x >= -1_f64 - tolerance && result.x <= 1_f64 + tolerance);
+        assert!(result.y >= -1_f64 - tolerance && result.y <= 1_f64 + tolerance);
+        assert!(result.z >= -1_f64 - tolerance && result.z <= 1_f64 + tolerance);
+    }
+}
+
+#[test]
+fn test_orthographic_projection_shadow_cascade_is_tight() {
+    let perspective = Perspective3::from_vfov(Degrees(60_f64), 16_f64 / 9_f64, 1_f64 / 10_f64, 100_f64);
+    let view = Isometry3::look_at_rh(
+        &Point3::new(3_f64, 2_f64, 5_f64),
+        &Point3::new(0_f64, 0_f64, 0_f64),
+        &Vector3::unit_y(),
+    );
+    let light_direction = Vector3::new(1_f64, -3_f64, -2_f64);
+    let (split_near, split_far) = (2_f64, 15_f64);
+    let (light_view, projection) = Orthographic3::shadow_cascade(&perspective, &view, split_near, split_far, &light_direction);
+    let corners = shadow_cascade_frustum_slice_corners(&perspective, &view, split_near, split_far);
+    let projected_corners = corners.map(|corner| projection.project_point(&light_view.apply_point(&corner)));
+    let min_x = projected_corners.iter().map(|p| p.x).fold(f64::INFINITY, f64::min);
+    let max_x = projected_corners.iter().map(|p| p.x).fold(f64::NEG_INFINITY, f64::max);
+    let min_y = projected_corners.iter().map(|p| p.y).fold(f64::INFINITY, f64::min);
+    let max_y = projected_corners.iter().map(|p| p.y).fold(f64::NEG_INFINITY, f64::max);
+    let min_z = projected_corners.iter().map(|p| p.z).fold(f64::INFINITY, f64::min);
+    let max_z = projected_corners.iter().map(|p| p.z).fold(f64::NEG_INFINITY, f64::max);
+
+    assert_relative_eq!(min_x, -1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(max_x, 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(min_y, -1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(max_y, 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(min_z, -1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(max_z, 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_orthographic_projection_shadow_cascade_looks_along_light_direction() {
+    let perspective = Perspective3::from_vfov(Degrees(60_f64), 16_f64 / 9_f64, 1_f64 / 10_f64, 100_f64);
+    let view = Isometry3::identity();
+    let light_directions = [
+        Vector3::new(1_f64, -3_f64, -2_f64),
+        -Vector3::unit_x(),
+        -Vector3::unit_y(),
+        Vector3::unit_z(),
+    ];
+    for light_direction in light_directions.iter() {
+        let (light_view, _) = Orthographic3::shadow_cascade(&perspective, &view, 1_f64, 10_f64, light_direction);
+        let expected = -Vector3::unit_z();
+        let result = light_view.apply_vector(&light_direction.normalize());
+
+        assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_orthographic_projection_near_plane_at_origin() {
+    let orthographic = Orthographic3::new(4_f64, 4_f64, 2_f64, 2_f64, 0_f64, 100_f64);
+
+    assert_relative_eq!(orthographic.near(), 0_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(orthographic.far(), 100_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_mul_isometry_project_point() {
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 4_f64 / 3_f64, 1_f64 / 10_f64, 100_f64);
+    let view = Isometry3::look_at_rh(
+        &Point3::new(3_f64, 2_f64, 5_f64),
+        &Point3::new(0_f64, 0_f64, 0_f64),
+        &Vector3::unit_y(),
+    );
+    let view_projection = perspective * view;
+    let point = Point3::new(1_f64, -1_f64, 1_f64 / 2_f64);
+    let expected = perspective.project_point(&view.apply_point(&point));
+    let result = view_projection.project_point(&point).unwrap();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_mul_isometry_unproject_point() {
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 4_f64 / 3_f64, 1_f64 / 10_f64, 100_f64);
+    let view = Isometry3::look_at_rh(
+        &Point3::new(3_f64, 2_f64, 5_f64),
+        &Point3::new(0_f64, 0_f64, 0_f64),
+        &Vector3::unit_y(),
+    );
+    let view_projection = perspective * view;
+    let expected = Point3::new(1_f64, -1_f64, 1_f64 / 2_f64);
+    let projected_point = view_projection.project_point(&expected).unwrap();
+    let result = view_projection.unproject_point(&projected_point).unwrap();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_mul_isometry_inverse() {
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 4_f64 / 3_f64, 1_f64 / 10_f64, 100_f64);
+    let view = Isometry3::look_at_rh(
+        &Point3::new(3_f64, 2_f64, 5_f64),
+        &Point3::new(0_f64, 0_f64, 0_f64),
+        &Vector3::unit_y(),
+    );
+    let view_projection = perspective * view;
+    let view_projection_inv = view_projection.inverse();
+    let expected = Transform3::identity();
+    let result = view_projection * view_projection_inv;
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_mul_similarity() {
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 4_f64 / 3_f64, 1_f64 / 10_f64, 100_f64);
+    let similarity = Similarity3::from_parts(
+        &Translation3::new(1_f64, 2_f64, -30_f64),
+        &Rotation3::from_angle_y(Degrees(30_f64)),
+        2_f64,
+    );
+    let point = Point3::new(1_f64, -1_f64, 1_f64 / 2_f64);
+    let expected = perspective.project_point(&similarity.apply_point(&point));
+    let result = (perspective * similarity).project_point(&point).unwrap();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_projection_mul_transform() {
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 4_f64 / 3_f64, 1_f64 / 10_f64, 100_f64);
+    let transform = Translation3::new(1_f64, 2_f64, -30_f64).to_transform();
+    let expected = perspective.to_transform() * transform;
+    let result = perspective * transform;
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_orthographic_projection_mul_isometry_project_point() {
+    let orthographic = Orthographic3::new(4_f64, 4_f64, 3_f64, 3_f64, 1_f64 / 10_f64, 100_f64);
+    let view = Isometry3::look_at_rh(
+        &Point3::new(3_f64, 2_f64, 5_f64),
+        &Point3::new(0_f64, 0_f64, 0_f64),
+        &Vector3::unit_y(),
+    );
+    let view_projection = orthographic * view;
+    let point = Point3::new(1_f64, -1_f64, 1_f64 / 2_f64);
+    let expected = orthographic.project_point(&view.apply_point(&point));
+    let result = view_projection.project_point(&point).unwrap();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_orthographic_projection_mul_similarity_unproject_point() {
+    let orthographic = Orthographic3::new(4_f64, 4_f64, 3_f64, 3_f64, 1_f64 / 10_f64, 100_f64);
+    let similarity = Similarity3::from_parts(
+        &Translation3::new(1_f64, 2_f64, -30_f64),
+        &Rotation3::from_angle_y(Degrees(30_f64)),
+        2_f64,
+    );
+    let view_projection = orthographic * similarity;
+    let expected = Point3::new(1_f64, -1_f64, 1_f64 / 2_f64);
+    let projected_point = view_projection.project_point(&expected).unwrap();
+    let result = view_projection.unproject_point(&projected_point).unwrap();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_transform_unproject_point_singular() {
+    let matrix = Matrix4x4::new(
+        1_f64, 0_f64, 0_f64, 0_f64,
+        0_f64, 1_f64, 0_f64, 0_f64,
+        0_f64, 0_f64, 0_f64, 0_f64,
+        0_f64, 0_f64, 0_f64, 1_f64,
+    );
+    let transform = Transform3::from_matrix_unchecked(matrix);
+    let point = Point3::new(1_f64, 2_f64, 3_f64);
+
+    assert!(transform.unproject_point(&point).is_none());
+}
+
+#[test]
+fn test_transform_project_point_on_eye_plane() {
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 4_f64 / 3_f64, 1_f64 / 10_f64, 100_f64);
+    let view = Isometry3::look_at_rh(
+        &Point3::new(0_f64, 0_f64, 5_f64),
+        &Point3::new(0_f64, 0_f64, 0_f64),
+        &Vector3::unit_y(),
+    );
+    let view_projection = perspective * view;
+    let point = Point3::new(1_f64, -2_f64, 5_f64);
+
+    assert!(view_projection.project_point(&point).is_none());
+}
+
+#[test]
+fn test_transform_view_projection_is_not_affine() {
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 4_f64 / 3_f64, 1_f64 / 10_f64, 100_f64);
+    let orthographic = Orthographic3::new(1_f64, 1_f64, 1_f64, 1_f64, 1_f64, 100_f64);
+    let view = Isometry3::look_at_rh(
+        &Point3::new(0_f64, 0_f64, 5_f64),
+        &Point3::new(0_f64, 0_f64, 0_f64),
+        &Vector3::unit_y(),
+    );
+
+    assert!(view.to_transform().is_affine());
+    assert!(!(perspective * view).is_affine());
+    assert!((orthographic * view).is_affine());
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn test_transform_apply_point_projective_panics() {
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 4_f64 / 3_f64, 1_f64 / 10_f64, 100_f64);
+    let view_projection = perspective * Isometry3::identity();
+
+    view_projection.apply_point(&Point3::new(1_f64, 2_f64, -3_f64));
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_orthographic2_projection_matrix() {
+    let left = 4_f64;
+    let right = 4_f64;
+    let bottom = 2_f64;
+    let top = 2_f64;
+    let expected = Matrix3x3::new(
+        1_f64 / 4_f64, 0_f64,         0_f64,
+        0_f64,         1_f64 / 2_f64, 0_f64,
+        0_f64,         0_f64,         1_f64,
+    );
+    let orthographic = Orthographic2::new(left, right, bottom, top);
+    let result = orthographic.matrix();
+
+    assert_eq!(result, &expected);
+}
+
+#[rustfmt::skip]
+#[test]
+fn test_orthographic2_projection_rectangular_parameters() {
+    let left = 1_f64;
+    let right = 7_f64;
+    let bottom = 2_f64;
+    let top = 0_f64;
+    let orthographic = Orthographic2::new(left, right, bottom, top);
+
+    assert_relative_eq!(orthographic.left(),   left,   abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(orthographic.right(),  right,  abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(orthographic.bottom(), bottom, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(orthographic.top(),    top,    abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_orthographic2_projection_corners() {
+    let orthographic = Orthographic2::from_bounds(-1_f64, 7_f64, -2_f64, 0_f64);
+
+    assert_relative_eq!(
+        orthographic.project_point(&Point2::new(-1_f64, -2_f64)),
+        Point2::new(-1_f64, -1_f64),
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+    assert_relative_eq!(
+        orthographic.project_point(&Point2::new(7_f64, 0_f64)),
+        Point2::new(1_f64, 1_f64),
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+    assert_relative_eq!(
+        orthographic.project_point(&Point2::new(3_f64, -1_f64)),
+        Point2::new(0_f64, 0_f64),
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+}
+
+#[test]
+fn test_orthographic2_projection_matches_matrix() {
+    let orthographic = Orthographic2::new(1_f64, 7_f64, 2_f64, 5_f64);
+    let point = Point2::new(3_f64, -4_f64);
+    let expected = orthographic.matrix() * point.to_homogeneous();
+    let result = orthographic.project_point(&point).to_homogeneous();
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_orthographic2_projection_unproject_point() {
+    let orthographic = Orthographic2::new(1_f64, 7_f64, 2_f64, 5_f64);
+    let expected = Point2::new(3_f64, -4_f64);
+    let projected_point = orthographic.project_point(&expected);
+    let result = orthographic.unproject_point(&projected_point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_orthographic2_projection_unproject_vector() {
+    let orthographic = Orthographic2::new(1_f64, 7_f64, 2_f64, 5_f64);
+    let expected = Vector2::new(3_f64, -4_f64);
+    let projected_vector = orthographic.project_vector(&expected);
+    let result = orthographic.unproject_vector(&projected_vector);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_halton_base_two() {
+    let expected = [0_f64, 1_f64 / 2_f64, 1_f64 / 4_f64, 3_f64 / 4_f64, 1_f64 / 8_f64, 5_f64 / 8_f64, 3_f64 / 8_f64, 7_f64 / 8_f64];
+    let result: [f64; 8] = core::array::from_fn(|i| halton(i as u32, 2));
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_halton_base_three() {
+    let expected = [
+        0_f64,
+        1_f64 / 3_f64,
+        2_f64 / 3_f64,
+        1_f64 / 9_f64,
+        4_f64 / 9_f64,
+        7_f64 / 9_f64,
+        2_f64 / 9_f64,
+        5_f64 / 9_f64,
+    ];
+    let result: [f64; 8] = core::array::from_fn(|i| halton(i as u32, 3));
+
+    for i in 0..8 {
+        assert_relative_eq!(result[i], expected[i], abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_halton_jitter_within_pixel() {
+    let one_half = 1_f64 / 2_f64;
+    for i in 0..64 {
+        let jitter: Vector2<f64> = halton_jitter(i);
+
+        assert!(jitter.x >= -one_half && jitter.x < one_half);
+        assert!(jitter.y >= -one_half && jitter.y < one_half);
+    }
+}
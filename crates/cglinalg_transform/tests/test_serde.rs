@@ -0,0 +1,201 @@
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use cglinalg_core::{
+        Matrix3x3,
+        Matrix4x4,
+        Point2,
+        Point3,
+        Unit,
+        Vector3,
+    };
+    use cglinalg_transform::{
+        Camera2,
+        CameraIntrinsics,
+        Isometry3,
+        Orthographic2,
+        Orthographic3,
+        Perspective3,
+        Reflection3,
+        Rotation2,
+        Rotation3,
+        Scale3,
+        Shear3,
+        Similarity3,
+        Transform3,
+        Translation3,
+        Viewport,
+    };
+    use cglinalg_trigonometry::Radians;
+    use serde::{
+        de::DeserializeOwned,
+        Serialize,
+    };
+
+
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let json = serde_json::to_string(value).unwrap();
+
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_translation_serialize_array() {
+        let translation = Translation3::new(1_f64, 2_f64, 3_f64);
+        let json = serde_json::to_string(&translation).unwrap();
+
+        assert_eq!(json, "[1.0,2.0,3.0]");
+        assert_eq!(round_trip(&translation), translation);
+    }
+
+    #[test]
+    fn test_scale_round_trip() {
+        let scale = Scale3::new(1_f64, 2_f64, 3_f64);
+
+        assert_eq!(round_trip(&scale), scale);
+    }
+
+    #[test]
+    fn test_reflection_round_trip() {
+        let normal = Unit::from_value(Vector3::new(1_f64, 1_f64, 0_f64));
+        let bias = Point3::new(1_f64, 2_f64, 3_f64);
+        let reflection = Reflection3::from_normal_bias(&normal, &bias);
+
+        assert_eq!(round_trip(&reflection), reflection);
+    }
+
+    #[test]
+    fn test_shear_round_trip() {
+        let shear = Shear3::from_shear_xz(5_f64);
+
+        assert_eq!(round_trip(&shear), shear);
+    }
+
+    #[test]
+    fn test_reflection_rejects_non_unit_normal() {
+        let json = r#"{"normal":[2.0,0.0,0.0],"bias":[1.0,2.0,3.0]}"#;
+
+        assert!(serde_json::from_str::<Reflection3<f64>>(json).is_err());
+    }
+
+    #[test]
+    fn test_shear_rejects_non_unit_direction() {
+        let json = r#"{"shear_factor":5.0,"origin":[0.0,0.0,0.0],"direction":[2.0,0.0,0.0],"normal":[0.0,0.0,1.0]}"#;
+
+        assert!(serde_json::from_str::<Shear3<f64>>(json).is_err());
+    }
+
+    #[test]
+    fn test_shear_rejects_non_orthogonal_direction_and_normal() {
+        let json = r#"{"shear_factor":5.0,"origin":[0.0,0.0,0.0],"direction":[1.0,0.0,0.0],"normal":[0.6,0.0,0.8]}"#;
+
+        assert!(serde_json::from_str::<Shear3<f64>>(json).is_err());
+    }
+
+    #[test]
+    fn test_rotation_round_trip() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Radians(0.7_f64));
+
+        assert_eq!(round_trip(&rotation), rotation);
+        assert_eq!(round_trip(&Rotation2::from_angle(Radians(2_f64))), Rotation2::from_angle(Radians(2_f64)));
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_rotation_rejects_non_orthogonal_matrix() {
+        let matrix = Matrix3x3::new(
+            1_f64, 0_f64, 0_f64,
+            0.5_f64, 1_f64, 0_f64,
+            0_f64, 0_f64, 1_f64,
+        );
+        let json = serde_json::to_string(&matrix).unwrap();
+
+        assert!(serde_json::from_str::<Rotation3<f64>>(&json).is_err());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_rotation_rejects_reflection_matrix() {
+        let matrix = Matrix3x3::new(
+            0_f64, 1_f64, 0_f64,
+            1_f64, 0_f64, 0_f64,
+            0_f64, 0_f64, 1_f64,
+        );
+        let json = serde_json::to_string(&matrix).unwrap();
+
+        assert!(serde_json::from_str::<Rotation3<f64>>(&json).is_err());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_rotation_accepts_permutation_matrix() {
+        let matrix = Matrix3x3::new(
+            0_f64, 1_f64, 0_f64,
+            0_f64, 0_f64, 1_f64,
+            1_f64, 0_f64, 0_f64,
+        );
+        let json = serde_json::to_string(&matrix).unwrap();
+        let result: Rotation3<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(result.matrix(), &matrix);
+    }
+
+    #[test]
+    fn test_isometry_round_trip() {
+        let axis = Unit::from_value(Vector3::new(1_f64, -1_f64, 2_f64));
+        let isometry = Isometry3::from_axis_angle_translation(&axis, Radians(1_f64), &Vector3::new(4_f64, 5_f64, 6_f64));
+
+        assert_eq!(round_trip(&isometry), isometry);
+    }
+
+    #[test]
+    fn test_isometry_rejects_invalid_rotation() {
+        let json = r#"{"rotation":[[2.0,0.0,0.0],[0.0,1.0,0.0],[0.0,0.0,1.0]],"translation":[0.0,0.0,0.0]}"#;
+
+        assert!(serde_json::from_str::<Isometry3<f64>>(json).is_err());
+    }
+
+    #[test]
+    fn test_similarity_round_trip() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 1_f64, 1_f64));
+        let similarity = Similarity3::from_axis_angle(&axis, Radians(0.3_f64)) * Similarity3::from_scale(2_f64);
+
+        assert_eq!(round_trip(&similarity), similarity);
+    }
+
+    #[test]
+    fn test_transform_serialize_matrix() {
+        let matrix = Matrix4x4::from_affine_translation(&Vector3::new(1_f64, 2_f64, 3_f64));
+        let transform = Transform3::from_matrix_unchecked(matrix);
+        let json = serde_json::to_string(&transform).unwrap();
+
+        assert_eq!(json, serde_json::to_string(&matrix).unwrap());
+        assert_eq!(round_trip(&transform), transform);
+    }
+
+    #[test]
+    fn test_projection_round_trip() {
+        let perspective = Perspective3::new(3_f64, 3_f64, 2_f64, 2_f64, 1_f64, 100_f64);
+        let orthographic3 = Orthographic3::new(6_f64, 6_f64, 4_f64, 4_f64, 1_f64, 101_f64);
+        let orthographic2 = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+
+        assert_eq!(round_trip(&perspective), perspective);
+        assert_eq!(round_trip(&orthographic3), orthographic3);
+        assert_eq!(round_trip(&orthographic2), orthographic2);
+    }
+
+    #[test]
+    fn test_camera_round_trip() {
+        let projection = Orthographic2::new(2_f64, 6_f64, 1_f64, 3_f64);
+        let camera = Camera2::new(&projection, &Point2::new(1_f64, 2_f64), Radians(0.5_f64), 2_f64);
+        let intrinsics = CameraIntrinsics::new(500_f64, 500_f64, 320_f64, 240_f64, 640_f64, 480_f64);
+        let viewport = Viewport::new(0_f64, 0_f64, 640_f64, 480_f64, 0_f64, 1_f64);
+
+        assert_eq!(round_trip(&camera), camera);
+        assert_eq!(round_trip(&intrinsics), intrinsics);
+        assert_eq!(round_trip(&viewport), viewport);
+    }
+}
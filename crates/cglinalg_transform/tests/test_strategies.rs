@@ -0,0 +1,175 @@
+#[cfg(all(test, feature = "proptest"))]
+mod strategies_tests {
+    use approx_cmp::relative_eq;
+    use cglinalg_core::Matrix3x3;
+    use cglinalg_transform::strategies;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_translation_from_range(translation in strategies::translation_from_range::<f64, 2>(5_f64, 6_f64)) {
+            prop_assert!(translation.vector().x >= 5_f64 && translation.vector().x <= 6_f64);
+            prop_assert!(translation.vector().y >= 5_f64 && translation.vector().y <= 6_f64);
+        }
+
+        #[test]
+        fn test_scale_signed_from_abs_range_invertible(
+            scale in strategies::scale_signed_from_abs_range::<f64, 3>(0.1_f64, 10_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 3>(-10_f64, 10_f64)
+        ) {
+            let result = scale.inverse().apply_point(&scale.apply_point(&point));
+
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_reflection_from_range_involution(
+            reflection in strategies::reflection_from_range::<f64, 3>(-10_f64, 10_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 3>(-10_f64, 10_f64)
+        ) {
+            let result = reflection.apply_point(&reflection.apply_point(&point));
+
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_rotation3_orthonormal(rotation in strategies::rotation3::<f64>()) {
+            let matrix = rotation.matrix();
+
+            prop_assert!(relative_eq!(matrix.transpose() * matrix, Matrix3x3::identity(), abs_diff_all <= 1e-12, relative_all <= f64::EPSILON));
+            prop_assert!(relative_eq!(matrix.determinant(), 1_f64, abs_diff <= 1e-12, relative <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_rotation3_from_range_angle(rotation in strategies::rotation3_from_range(0.5_f64, 1_f64)) {
+            // The trace of a rotation by `angle` is `1 + 2 * cos(angle)`.
+            let angle = f64::acos((rotation.matrix().trace() - 1_f64) / 2_f64);
+
+            prop_assert!((0.5_f64 - 1e-8..=1_f64 + 1e-8).contains(&angle));
+        }
+
+        #[test]
+        fn test_isometry3_from_range_preserves_distance(
+            isometry in strategies::isometry3_from_range(0_f64, 10_f64, -100_f64, 100_f64),
+            point1 in cglinalg_core::strategies::point_from_range::<f64, 3>(-10_f64, 10_f64),
+            point2 in cglinalg_core::strategies::point_from_range::<f64, 3>(-10_f64, 10_f64)
+        ) {
+            let expected = (point2 - point1).norm();
+            let result = (isometry.apply_point(&point2) - isometry.apply_point(&point1)).norm();
+
+            prop_assert!(relative_eq!(result, expected, abs_diff <= 1e-10, relative <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_similarity3_from_range_invertible(
+            similarity in strategies::similarity3_from_range(0.5_f64, 2_f64, 0_f64, 10_f64, -100_f64, 100_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 3>(-10_f64, 10_f64)
+        ) {
+            let result = similarity.inverse().apply_point(&similarity.apply_point(&point));
+
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_similarity2_from_range_scales_distance(
+            similarity in strategies::similarity2_from_range(0.5_f64, 2_f64, 0_f64, 10_f64, -100_f64, 100_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 2>(-10_f64, 10_f64)
+        ) {
+            let origin = cglinalg_core::Point2::origin();
+            let expected = similarity.scale() * (point - origin).norm();
+            let result = (similarity.apply_point(&point) - similarity.apply_point(&origin)).norm();
+
+            prop_assert!(relative_eq!(result, expected, abs_diff <= 1e-10, relative <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_isometry2_from_range_translation(isometry in strategies::isometry2_from_range(0_f64, 1_f64, 2_f64, 3_f64)) {
+            let origin = isometry.apply_point(&cglinalg_core::Point2::origin());
+
+            prop_assert!(origin.x >= 2_f64 && origin.x <= 3_f64);
+            prop_assert!(origin.y >= 2_f64 && origin.y <= 3_f64);
+        }
+
+        #[test]
+        fn test_shear_from_range_invertible(
+            shear in strategies::shear_from_range::<f64, 3>(-2_f64, 2_f64, -10_f64, 10_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 3>(-10_f64, 10_f64)
+        ) {
+            let result = shear.inverse_apply_point(&shear.apply_point(&point));
+
+            prop_assert!(relative_eq!(shear.direction().dot(&shear.normal()), 0_f64, abs_diff <= 1e-12, relative <= f64::EPSILON));
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_transform2_from_range_invertible(
+            transform in strategies::transform2_from_range(0.5_f64, 2_f64, -100_f64, 100_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 2>(-10_f64, 10_f64)
+        ) {
+            let result = transform.inverse_apply_point(&transform.apply_point(&point)).unwrap();
+
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_transform3_from_range_invertible(
+            transform in strategies::transform3_from_range(0.5_f64, 2_f64, -100_f64, 100_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 3>(-10_f64, 10_f64)
+        ) {
+            let result = transform.inverse_apply_point(&transform.apply_point(&point)).unwrap();
+
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_perspective3_from_range_unproject_project(
+            perspective in strategies::perspective3_from_range(0.5_f64, 10_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 3>(-10_f64, -1_f64)
+        ) {
+            let result = perspective.unproject_point(&perspective.project_point(&point));
+
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_orthographic3_from_range_unproject_project(
+            orthographic in strategies::orthographic3_from_range(0.5_f64, 10_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 3>(-10_f64, 10_f64)
+        ) {
+            let result = orthographic.unproject_point(&orthographic.project_point(&point));
+
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_orthographic2_from_range_unproject_project(
+            orthographic in strategies::orthographic2_from_range(0.5_f64, 10_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 2>(-10_f64, 10_f64)
+        ) {
+            let result = orthographic.unproject_point(&orthographic.project_point(&point));
+
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_camera2_from_range_unproject_project(
+            camera in strategies::camera2_from_range(0.5_f64, 10_f64, -10_f64, 10_f64, 0.5_f64, 2_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 2>(-10_f64, 10_f64)
+        ) {
+            let result = camera.unproject_point(&camera.project_point(&point));
+
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON));
+        }
+
+        #[test]
+        fn test_viewport_from_range_window_to_ndc(
+            viewport in strategies::viewport_from_range(-100_f64, 100_f64, 1_f64, 1000_f64),
+            point in cglinalg_core::strategies::point_from_range::<f64, 3>(-1_f64, 1_f64)
+        ) {
+            let result = viewport.window_to_ndc(&viewport.ndc_to_window(&point));
+
+            prop_assert!(viewport.width() > 0_f64 && viewport.height() > 0_f64);
+            prop_assert!(relative_eq!(result, point, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON));
+        }
+    }
+}
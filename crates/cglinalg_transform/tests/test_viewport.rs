@@ -0,0 +1,209 @@
+use approx_cmp::assert_relative_eq;
+use cglinalg_core::{
+    Normed,
+    Point2,
+    Point3,
+    Vector3,
+};
+use cglinalg_transform::{
+    Isometry3,
+    Orthographic3,
+    Perspective3,
+    Viewport,
+};
+use cglinalg_trigonometry::Degrees;
+
+
+#[test]
+fn test_viewport_ndc_to_window_corners() {
+    let viewport = Viewport::new(100_f64, 50_f64, 640_f64, 480_f64, 0_f64, 1_f64);
+
+    assert_relative_eq!(
+        viewport.ndc_to_window(&Point3::new(-1_f64, -1_f64, -1_f64)),
+        Point3::new(100_f64, 50_f64, 0_f64),
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+    assert_relative_eq!(
+        viewport.ndc_to_window(&Point3::new(1_f64, -1_f64, -1_f64)),
+        Point3::new(740_f64, 50_f64, 0_f64),
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+    assert_relative_eq!(
+        viewport.ndc_to_window(&Point3::new(-1_f64, 1_f64, 1_f64)),
+        Point3::new(100_f64, 530_f64, 1_f64),
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+    assert_relative_eq!(
+        viewport.ndc_to_window(&Point3::new(1_f64, 1_f64, 1_f64)),
+        Point3::new(740_f64, 530_f64, 1_f64),
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+}
+
+#[test]
+fn test_viewport_ndc_to_window_depth_range() {
+    let viewport = Viewport::new(0_f64, 0_f64, 800_f64, 600_f64, 1_f64 / 4_f64, 3_f64 / 4_f64);
+
+    assert_relative_eq!(
+        viewport.ndc_to_window(&Point3::new(0_f64, 0_f64, -1_f64)).z,
+        1_f64 / 4_f64,
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+    assert_relative_eq!(
+        viewport.ndc_to_window(&Point3::new(0_f64, 0_f64, 0_f64)).z,
+        1_f64 / 2_f64,
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+    assert_relative_eq!(
+        viewport.ndc_to_window(&Point3::new(0_f64, 0_f64, 1_f64)).z,
+        3_f64 / 4_f64,
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+}
+
+#[test]
+fn test_viewport_window_to_ndc_ndc_to_window() {
+    let viewport = Viewport::new(100_f64, 50_f64, 640_f64, 480_f64, 1_f64 / 4_f64, 3_f64 / 4_f64);
+    let point = Point3::new(317_f64, 211_f64, 1_f64 / 3_f64);
+    let expected = point;
+    let result = viewport.ndc_to_window(&viewport.window_to_ndc(&point));
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_viewport_window_to_ndc_empty_depth_range() {
+    let viewport = Viewport::new(100_f64, 50_f64, 640_f64, 480_f64, 1_f64 / 2_f64, 1_f64 / 2_f64);
+    let expected = Point3::new(-1_f64, -1_f64, 0_f64);
+    let result = viewport.window_to_ndc(&Point3::new(100_f64, 50_f64, 1_f64 / 2_f64));
+
+    assert_eq!(result, expected);
+    assert_eq!(viewport.window_to_ndc(&Point3::new(100_f64, 50_f64, 1_f64)), expected);
+}
+
+#[test]
+fn test_viewport_from_size() {
+    let expected = Viewport::new(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    let result = Viewport::from_size(800_f64, 600_f64);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_perspective_world_to_screen_center() {
+    let perspective = Perspective3::from_vfov(Degrees(90_f64), 800_f64 / 600_f64, 1_f64, 100_f64);
+    let eye = Point3::new(1_f64, 2_f64, 3_f64);
+    let target = Point3::new(-4_f64, 5_f64, -6_f64);
+    let view = Isometry3::look_at_rh(&eye, &target, &Vector3::unit_y());
+    let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    let result = perspective.world_to_screen(&view, &viewport, &target);
+
+    assert_relative_eq!(result.x, 410_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(result.y, 320_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_perspective_screen_to_world_ray_world_to_screen() {
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 800_f64 / 600_f64, 1_f64, 100_f64);
+    let eye = Point3::new(1_f64, 2_f64, 3_f64);
+    let target = Point3::new(-4_f64, 5_f64, -6_f64);
+    let view = Isometry3::look_at_rh(&eye, &target, &Vector3::unit_y());
+    let viewport = Viewport::new(10_f64, 20_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    let pixel = Point2::new(123_f64 + 1_f64 / 2_f64, 456_f64 + 1_f64 / 2_f64);
+    let (origin, direction) = perspective.screen_to_world_ray(&view, &viewport, &pixel);
+
+    assert_relative_eq!(direction.norm(), 1_f64, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+
+    for t in [0_f64, 1_f64, 10_f64, 50_f64] {
+        let point = origin + direction * t;
+        let result = perspective.world_to_screen(&view, &viewport, &point);
+
+        assert_relative_eq!(result.x, pixel.x, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.y, pixel.y, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
+
+#[test]
+fn test_perspective_screen_to_world_ray_origin_near_plane() {
+    let near = 1_f64;
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 800_f64 / 600_f64, near, 100_f64);
+    let eye = Point3::new(1_f64, 2_f64, 3_f64);
+    let target = Point3::new(-4_f64, 5_f64, -6_f64);
+    let view = Isometry3::look_at_rh(&eye, &target, &Vector3::unit_y());
+    let viewport = Viewport::from_size(800_f64, 600_f64);
+    let pixel = Point2::new(700_f64, 100_f64);
+    let (origin, direction) = perspective.screen_to_world_ray(&view, &viewport, &pixel);
+    let origin_view = view.apply_point(&origin);
+    let direction_view = view.apply_vector(&direction);
+
+    assert_relative_eq!(origin_view.z, -near, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    // The ray passes through the eye.
+    let eye_to_origin = origin_view - Point3::origin();
+    assert_relative_eq!(
+        eye_to_origin.normalize(),
+        direction_view,
+        abs_diff_all <= 1e-10,
+        relative_all <= f64::EPSILON,
+    );
+}
+
+#[test]
+fn test_perspective_screen_to_world_ray_empty_depth_range() {
+    let perspective = Perspective3::from_vfov(Degrees(72_f64), 800_f64 / 600_f64, 1_f64, 100_f64);
+    let eye = Point3::new(1_f64, 2_f64, 3_f64);
+    let target = Point3::new(-4_f64, 5_f64, -6_f64);
+    let view = Isometry3::look_at_rh(&eye, &target, &Vector3::unit_y());
+    let viewport = Viewport::new(0_f64, 0_f64, 800_f64, 600_f64, 0_f64, 1_f64);
+    let flat_viewport = Viewport::new(0_f64, 0_f64, 800_f64, 600_f64, 1_f64, 1_f64);
+    let pixel = Point2::new(700_f64, 100_f64);
+    let (expected_origin, expected_direction) = perspective.screen_to_world_ray(&view, &viewport, &pixel);
+    let (result_origin, result_direction) = perspective.screen_to_world_ray(&view, &flat_viewport, &pixel);
+
+    assert_relative_eq!(result_origin, expected_origin, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+    assert_relative_eq!(result_direction, expected_direction, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_orthographic_world_to_screen() {
+    let orthographic = Orthographic3::new(4_f64, 4_f64, 3_f64, 3_f64, 1_f64, 100_f64);
+    let eye = Point3::new(0_f64, 0_f64, 5_f64);
+    let target = Point3::origin();
+    let view = Isometry3::look_at_rh(&eye, &target, &Vector3::unit_y());
+    let viewport = Viewport::from_size(800_f64, 600_f64);
+    let point = Point3::new(2_f64, -3_f64, 0_f64);
+    let expected = Point3::new(600_f64, 0_f64, 4_f64 / 99_f64);
+    let result = orthographic.world_to_screen(&view, &viewport, &point);
+
+    assert_relative_eq!(result, expected, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+}
+
+#[test]
+fn test_orthographic_screen_to_world_ray_parallel() {
+    let orthographic = Orthographic3::new(4_f64, 4_f64, 3_f64, 3_f64, 1_f64, 100_f64);
+    let eye = Point3::new(1_f64, 2_f64, 3_f64);
+    let target = Point3::new(-4_f64, 5_f64, -6_f64);
+    let view = Isometry3::look_at_rh(&eye, &target, &Vector3::unit_y());
+    let viewport = Viewport::from_size(800_f64, 600_f64);
+    let expected_direction = (target - eye).normalize();
+    let pixels = [
+        Point2::new(0_f64, 0_f64),
+        Point2::new(400_f64, 300_f64),
+        Point2::new(123_f64, 456_f64),
+        Point2::new(800_f64, 600_f64),
+    ];
+    for pixel in pixels.iter() {
+        let (origin, direction) = orthographic.screen_to_world_ray(&view, &viewport, pixel);
+        let result = orthographic.world_to_screen(&view, &viewport, &(origin + direction * 10_f64));
+
+        assert_relative_eq!(direction, expected_direction, abs_diff_all <= 1e-10, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.x, pixel.x, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+        assert_relative_eq!(result.y, pixel.y, abs_diff_all <= 1e-8, relative_all <= f64::EPSILON);
+    }
+}
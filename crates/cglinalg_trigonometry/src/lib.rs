@@ -7,6 +7,12 @@ use cglinalg_numeric::{
 use core::f64;
 use core::fmt;
 use core::ops;
+use core::str;
+
+
+/// Property testing strategies for generating angles with proptest.
+#[cfg(feature = "proptest")]
+pub mod strategies;
 
 
 /// Implement trigonometry for typed angles.
@@ -575,6 +581,8 @@ where
 /// The angle (arc length) along the unit circle in units of radians.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Radians<S>(pub S);
 
 impl<S> Radians<S>
@@ -655,6 +663,8 @@ where
 /// The angle (arc length) along the unit circle in units of degrees.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Degrees<S>(pub S);
 
 impl<S> Degrees<S>
@@ -787,6 +797,176 @@ where
     }
 }
 
+/// An error returned when parsing an angle from a string fails.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_trigonometry::{
+/// #     ParseAngleError,
+/// #     Radians,
+/// # };
+/// #
+/// assert_eq!("".parse::<Radians<f64>>(), Err(ParseAngleError::Empty));
+/// assert_eq!("45 turns".parse::<Radians<f64>>(), Err(ParseAngleError::InvalidSyntax));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseAngleError {
+    /// The string is empty or contains only whitespace.
+    Empty,
+    /// The string is not a scalar followed by an optional unit suffix.
+    InvalidSyntax,
+}
+
+impl fmt::Display for ParseAngleError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseAngleError::Empty => write!(formatter, "cannot parse an angle from an empty string"),
+            ParseAngleError::InvalidSyntax => write!(formatter, "invalid syntax"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseAngleError {}
+
+/// The unit of an angle written in a string.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum AngleUnit {
+    Radians,
+    Degrees,
+}
+
+/// Parse the value of an angle and its optional unit suffix from a string.
+fn parse_angle<S>(string: &str) -> Result<(S, Option<AngleUnit>), ParseAngleError>
+where
+    S: str::FromStr,
+{
+    const SUFFIXES: [(&str, AngleUnit); 7] = [
+        ("radians", AngleUnit::Radians),
+        ("radian", AngleUnit::Radians),
+        ("rad", AngleUnit::Radians),
+        ("degrees", AngleUnit::Degrees),
+        ("degree", AngleUnit::Degrees),
+        ("deg", AngleUnit::Degrees),
+        ("\u{b0}", AngleUnit::Degrees),
+    ];
+
+    let string = string.trim();
+    if string.is_empty() {
+        return Err(ParseAngleError::Empty);
+    }
+
+    let (value, unit) = SUFFIXES
+        .iter()
+        .find_map(|&(suffix, unit)| string.strip_suffix(suffix).map(|value| (value.trim_end(), Some(unit))))
+        .unwrap_or((string, None));
+    let value = value.parse().map_err(|_| ParseAngleError::InvalidSyntax)?;
+
+    Ok((value, unit))
+}
+
+/// Parse an angle in degrees from a string.
+///
+/// This accepts the output of the [`Display`](fmt::Display) implementation, such
+/// as `45 degrees`, and the unit suffixes `degree`, `deg`, and `°` with or without
+/// a space before them. An angle without a unit is in degrees. An angle with one
+/// of the unit suffixes `radians`, `radian`, or `rad` is converted to degrees.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_trigonometry::Degrees;
+/// # use core::f64;
+/// #
+/// assert_eq!("45 degrees".parse(), Ok(Degrees(45_f64)));
+/// assert_eq!("45deg".parse(), Ok(Degrees(45_f64)));
+/// assert_eq!("45".parse(), Ok(Degrees(45_f64)));
+///
+/// let angle: Degrees<f64> = "0.5rad".parse().unwrap();
+///
+/// assert_relative_eq!(angle.0, 90_f64 / f64::consts::PI, abs_diff <= 1e-12, relative <= f64::EPSILON);
+/// ```
+impl<S> str::FromStr for Degrees<S>
+where
+    S: SimdScalarFloat + str::FromStr,
+{
+    type Err = ParseAngleError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match parse_angle(string)? {
+            (value, Some(AngleUnit::Radians)) => Ok(Degrees::from(Radians(value))),
+            (value, _) => Ok(Degrees(value)),
+        }
+    }
+}
+
+/// Parse an angle in radians from a string.
+///
+/// This accepts the output of the [`Display`](fmt::Display) implementation, such
+/// as `0.5 radians`, and the unit suffixes `radian` and `rad` with or without a
+/// space before them. An angle without a unit is in radians. An angle with one
+/// of the unit suffixes `degrees`, `degree`, `deg`, or `°` is converted to
+/// radians.
+///
+/// # Example
+///
+/// ```
+/// # use approx_cmp::assert_relative_eq;
+/// # use cglinalg_trigonometry::Radians;
+/// # use core::f64;
+/// #
+/// assert_eq!("0.5 radians".parse(), Ok(Radians(0.5_f64)));
+/// assert_eq!("0.5rad".parse(), Ok(Radians(0.5_f64)));
+/// assert_eq!("0.5".parse(), Ok(Radians(0.5_f64)));
+///
+/// let angle: Radians<f64> = "45deg".parse().unwrap();
+///
+/// assert_relative_eq!(angle.0, f64::consts::FRAC_PI_4, abs_diff <= 1e-12, relative <= f64::EPSILON);
+/// ```
+impl<S> str::FromStr for Radians<S>
+where
+    S: SimdScalarFloat + str::FromStr,
+{
+    type Err = ParseAngleError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match parse_angle(string)? {
+            (value, Some(AngleUnit::Degrees)) => Ok(Radians::from(Degrees(value))),
+            (value, _) => Ok(Radians(value)),
+        }
+    }
+}
+
+/// Sample an angle uniformly distributed over a full turn, i.e. in the interval
+/// `[0, 2 * pi)`.
+#[cfg(feature = "rand")]
+impl<S> rand::distributions::Distribution<Radians<S>> for rand::distributions::Standard
+where
+    S: SimdScalarFloat,
+    rand::distributions::Standard: rand::distributions::Distribution<S>,
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Radians<S> {
+        Radians(S::two_pi() * self.sample(rng))
+    }
+}
+
+/// Sample an angle uniformly distributed over a full turn, i.e. in the interval
+/// `[0, 360)`.
+#[cfg(feature = "rand")]
+impl<S> rand::distributions::Distribution<Degrees<S>> for rand::distributions::Standard
+where
+    S: SimdScalarFloat,
+    rand::distributions::Standard: rand::distributions::Distribution<S>,
+{
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Degrees<S> {
+        Degrees(cglinalg_numeric::cast::<f64, S>(360_f64) * self.sample(rng))
+    }
+}
+
 
 macro_rules! impl_approx_cmp_angle {
     ($T:ident) => {
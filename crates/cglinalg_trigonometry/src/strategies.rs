@@ -0,0 +1,89 @@
+use crate::{
+    Degrees,
+    Radians,
+};
+use cglinalg_numeric::SimdScalarFloat;
+
+use proptest::prelude::*;
+
+
+fn rescale<S>(value: S, min_value: S, max_value: S) -> S
+where
+    S: SimdScalarFloat,
+{
+    let width = max_value - min_value;
+    let remainder = value % width;
+    if remainder < S::zero() {
+        min_value + (remainder + width)
+    } else {
+        min_value + remainder
+    }
+}
+
+/// Generate angles in radians in the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_trigonometry::strategies;
+/// # use core::f64;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(angle in strategies::radians_from_range(0_f64, f64::consts::PI))| {
+///     prop_assert!(angle.0 >= 0_f64 && angle.0 <= f64::consts::PI);
+/// });
+/// ```
+pub fn radians_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Radians<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    any::<S>().prop_map(move |value| Radians(rescale(value, min_value, max_value)))
+}
+
+/// Generate angles in degrees in the range `[min_value, max_value]`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_trigonometry::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(angle in strategies::degrees_from_range(-90_f64, 90_f64))| {
+///     prop_assert!(angle.0 >= -90_f64 && angle.0 <= 90_f64);
+/// });
+/// ```
+pub fn degrees_from_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Degrees<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    any::<S>().prop_map(move |value| Degrees(rescale(value, min_value, max_value)))
+}
+
+/// Generate angles in radians of either sign whose absolute values lie in the
+/// range `[min_value, max_value]`.
+///
+/// The bounds must satisfy `0 <= min_value < max_value`.
+///
+/// # Example
+///
+/// ```
+/// # use cglinalg_trigonometry::strategies;
+/// # use proptest::prelude::*;
+/// #
+/// proptest!(|(angle in strategies::radians_signed_from_abs_range(1_f64, 2_f64))| {
+///     prop_assert!(angle.0.abs() >= 1_f64 && angle.0.abs() <= 2_f64);
+/// });
+/// ```
+pub fn radians_signed_from_abs_range<S>(min_value: S, max_value: S) -> impl Strategy<Value = Radians<S>>
+where
+    S: SimdScalarFloat + Arbitrary,
+{
+    any::<S>().prop_map(move |value| {
+        let abs_value = rescale(value, min_value, max_value);
+        if value < S::zero() {
+            Radians(-abs_value)
+        } else {
+            Radians(abs_value)
+        }
+    })
+}
@@ -698,3 +698,70 @@ mod degree_angle_tests {
         assert_eq!(result, expected);
     }
 }
+
+#[cfg(test)]
+mod parse_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg_trigonometry::{
+        Degrees,
+        ParseAngleError,
+        Radians,
+    };
+    use core::f64;
+
+
+    #[test]
+    fn test_radians_display_round_trip() {
+        let angle = Radians(-1.25_f64);
+        let result = angle.to_string().parse();
+
+        assert_eq!(result, Ok(angle));
+    }
+
+    #[test]
+    fn test_degrees_display_round_trip() {
+        let angle = Degrees(-33.5_f64);
+        let result = angle.to_string().parse();
+
+        assert_eq!(result, Ok(angle));
+    }
+
+    #[test]
+    fn test_radians_unit_suffixes() {
+        assert_eq!("0.5rad".parse(), Ok(Radians(0.5_f64)));
+        assert_eq!("0.5 rad".parse(), Ok(Radians(0.5_f64)));
+        assert_eq!("1 radian".parse(), Ok(Radians(1_f64)));
+        assert_eq!("  0.5  ".parse(), Ok(Radians(0.5_f64)));
+    }
+
+    #[test]
+    fn test_degrees_unit_suffixes() {
+        assert_eq!("45deg".parse(), Ok(Degrees(45_f64)));
+        assert_eq!("45 deg".parse(), Ok(Degrees(45_f64)));
+        assert_eq!("1 degree".parse(), Ok(Degrees(1_f64)));
+        assert_eq!("45°".parse(), Ok(Degrees(45_f64)));
+    }
+
+    #[test]
+    fn test_radians_from_degrees() {
+        let result: Radians<f64> = "180 degrees".parse().unwrap();
+
+        assert_relative_eq!(result.0, f64::consts::PI, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_degrees_from_radians() {
+        let result: Degrees<f64> = "-0.25rad".parse().unwrap();
+
+        assert_relative_eq!(result.0, -45_f64 / f64::consts::PI, abs_diff <= 1e-12, relative <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_parse_angle_errors() {
+        assert_eq!("".parse::<Radians<f64>>(), Err(ParseAngleError::Empty));
+        assert_eq!("   ".parse::<Degrees<f64>>(), Err(ParseAngleError::Empty));
+        assert_eq!("deg".parse::<Degrees<f64>>(), Err(ParseAngleError::InvalidSyntax));
+        assert_eq!("45 grad".parse::<Degrees<f64>>(), Err(ParseAngleError::InvalidSyntax));
+        assert_eq!("forty five".parse::<Radians<f32>>(), Err(ParseAngleError::InvalidSyntax));
+    }
+}
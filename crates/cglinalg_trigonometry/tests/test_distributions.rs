@@ -0,0 +1,41 @@
+#[cfg(all(test, feature = "rand"))]
+mod distributions_tests {
+    use cglinalg_trigonometry::{
+        Degrees,
+        Radians,
+    };
+    use rand::{
+        Rng,
+        SeedableRng,
+    };
+    use rand_isaac::IsaacRng;
+
+
+    #[test]
+    fn test_radians_full_turn() {
+        let mut rng = IsaacRng::seed_from_u64(0);
+        let mut mean = 0_f64;
+        for _ in 0..10000 {
+            let angle: Radians<f64> = rng.gen();
+
+            assert!((0_f64..=2_f64 * core::f64::consts::PI).contains(&angle.0));
+            mean += angle.0 / 10000_f64;
+        }
+
+        assert!((mean - core::f64::consts::PI).abs() <= 0.05);
+    }
+
+    #[test]
+    fn test_degrees_full_turn() {
+        let mut rng = IsaacRng::seed_from_u64(1);
+        let mut mean = 0_f64;
+        for _ in 0..10000 {
+            let angle: Degrees<f64> = rng.gen();
+
+            assert!((0_f64..=360_f64).contains(&angle.0));
+            mean += angle.0 / 10000_f64;
+        }
+
+        assert!((mean - 180_f64).abs() <= 3_f64);
+    }
+}
@@ -0,0 +1,28 @@
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use cglinalg_trigonometry::{
+        Degrees,
+        Radians,
+    };
+
+
+    #[test]
+    fn test_radians_serialize_scalar() {
+        let angle = Radians(1.5_f64);
+        let json = serde_json::to_string(&angle).unwrap();
+        let result: Radians<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, "1.5");
+        assert_eq!(result, angle);
+    }
+
+    #[test]
+    fn test_degrees_serialize_scalar() {
+        let angle = Degrees(90_f64);
+        let json = serde_json::to_string(&angle).unwrap();
+        let result: Degrees<f64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, "90.0");
+        assert_eq!(result, angle);
+    }
+}
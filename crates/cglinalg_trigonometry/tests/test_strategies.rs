@@ -0,0 +1,22 @@
+#[cfg(all(test, feature = "proptest"))]
+mod strategies_tests {
+    use cglinalg_trigonometry::strategies;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_radians_from_range(angle in strategies::radians_from_range(-1_f64, 3_f64)) {
+            prop_assert!(angle.0 >= -1_f64 && angle.0 <= 3_f64);
+        }
+
+        #[test]
+        fn test_degrees_from_range(angle in strategies::degrees_from_range(0_f32, 360_f32)) {
+            prop_assert!(angle.0 >= 0_f32 && angle.0 <= 360_f32);
+        }
+
+        #[test]
+        fn test_radians_signed_from_abs_range(angle in strategies::radians_signed_from_abs_range(0.5_f64, 1_f64)) {
+            prop_assert!(angle.0.abs() >= 0.5_f64 && angle.0.abs() <= 1_f64);
+        }
+    }
+}
@@ -0,0 +1,1498 @@
+/* Generated by cbindgen from the `ffi` module of the `cglinalg` crate. Do not edit. */
+
+#ifndef CGLINALG_H
+#define CGLINALG_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A 2-dimensional vector of `f32` components.
+ */
+typedef struct CglVector2f {
+  float data[2];
+} CglVector2f;
+
+/**
+ * A 3-dimensional vector of `f32` components.
+ */
+typedef struct CglVector3f {
+  float data[3];
+} CglVector3f;
+
+/**
+ * A 4-dimensional vector of `f32` components.
+ */
+typedef struct CglVector4f {
+  float data[4];
+} CglVector4f;
+
+/**
+ * A 2x2 matrix of `f32` elements stored in column-major order.
+ */
+typedef struct CglMatrix2x2f {
+  float data[2][2];
+} CglMatrix2x2f;
+
+/**
+ * A 3x3 matrix of `f32` elements stored in column-major order.
+ */
+typedef struct CglMatrix3x3f {
+  float data[3][3];
+} CglMatrix3x3f;
+
+/**
+ * A 4x4 matrix of `f32` elements stored in column-major order.
+ */
+typedef struct CglMatrix4x4f {
+  float data[4][4];
+} CglMatrix4x4f;
+
+/**
+ * A point in three dimensions with `f32` coordinates.
+ */
+typedef struct CglPoint3f {
+  float data[3];
+} CglPoint3f;
+
+/**
+ * A quaternion of `f32` components, with scalar part `s` and vector part `(x, y, z)`.
+ */
+typedef struct CglQuaternionf {
+  float s;
+  float x;
+  float y;
+  float z;
+} CglQuaternionf;
+
+/**
+ * A translation in three dimensions with `f32` components.
+ */
+typedef struct CglTranslation3f {
+  struct CglVector3f vector;
+} CglTranslation3f;
+
+/**
+ * A nonuniform scale in three dimensions with `f32` scaling factors.
+ */
+typedef struct CglScale3f {
+  struct CglVector3f vector;
+} CglScale3f;
+
+/**
+ * A rotation in three dimensions stored as a `f32` rotation matrix.
+ *
+ * The matrix must be orthonormal with determinant `1`. Functions that take a rotation do not check this, except for the `_try_inverse` functions. Construct rotations with the `cgl_rotation3` constructors, which validate their input, instead of writing the matrix directly.
+ */
+typedef struct CglRotation3f {
+  struct CglMatrix3x3f matrix;
+} CglRotation3f;
+
+/**
+ * An isometry in three dimensions with `f32` components. The rotation is applied first, followed by the translation.
+ *
+ * The rotation must satisfy the orthonormality invariant of rotations.
+ */
+typedef struct CglIsometry3f {
+  struct CglRotation3f rotation;
+  struct CglTranslation3f translation;
+} CglIsometry3f;
+
+/**
+ * A similarity in three dimensions with `f32` components. The uniform scale is applied first, followed by the isometry.
+ *
+ * The rotation must satisfy the orthonormality invariant of rotations.
+ */
+typedef struct CglSimilarity3f {
+  struct CglIsometry3f isometry;
+  float scale;
+} CglSimilarity3f;
+
+/**
+ * A perspective projection stored as a `f32` homogeneous matrix.
+ */
+typedef struct CglPerspective3f {
+  struct CglMatrix4x4f matrix;
+} CglPerspective3f;
+
+/**
+ * An orthographic projection stored as a `f32` homogeneous matrix.
+ */
+typedef struct CglOrthographic3f {
+  struct CglMatrix4x4f matrix;
+} CglOrthographic3f;
+
+/**
+ * A 2-dimensional vector of `f64` components.
+ */
+typedef struct CglVector2d {
+  double data[2];
+} CglVector2d;
+
+/**
+ * A 3-dimensional vector of `f64` components.
+ */
+typedef struct CglVector3d {
+  double data[3];
+} CglVector3d;
+
+/**
+ * A 4-dimensional vector of `f64` components.
+ */
+typedef struct CglVector4d {
+  double data[4];
+} CglVector4d;
+
+/**
+ * A 2x2 matrix of `f64` elements stored in column-major order.
+ */
+typedef struct CglMatrix2x2d {
+  double data[2][2];
+} CglMatrix2x2d;
+
+/**
+ * A 3x3 matrix of `f64` elements stored in column-major order.
+ */
+typedef struct CglMatrix3x3d {
+  double data[3][3];
+} CglMatrix3x3d;
+
+/**
+ * A 4x4 matrix of `f64` elements stored in column-major order.
+ */
+typedef struct CglMatrix4x4d {
+  double data[4][4];
+} CglMatrix4x4d;
+
+/**
+ * A point in three dimensions with `f64` coordinates.
+ */
+typedef struct CglPoint3d {
+  double data[3];
+} CglPoint3d;
+
+/**
+ * A quaternion of `f64` components, with scalar part `s` and vector part `(x, y, z)`.
+ */
+typedef struct CglQuaterniond {
+  double s;
+  double x;
+  double y;
+  double z;
+} CglQuaterniond;
+
+/**
+ * A translation in three dimensions with `f64` components.
+ */
+typedef struct CglTranslation3d {
+  struct CglVector3d vector;
+} CglTranslation3d;
+
+/**
+ * A nonuniform scale in three dimensions with `f64` scaling factors.
+ */
+typedef struct CglScale3d {
+  struct CglVector3d vector;
+} CglScale3d;
+
+/**
+ * A rotation in three dimensions stored as a `f64` rotation matrix.
+ *
+ * The matrix must be orthonormal with determinant `1`. Functions that take a rotation do not check this, except for the `_try_inverse` functions. Construct rotations with the `cgl_rotation3` constructors, which validate their input, instead of writing the matrix directly.
+ */
+typedef struct CglRotation3d {
+  struct CglMatrix3x3d matrix;
+} CglRotation3d;
+
+/**
+ * An isometry in three dimensions with `f64` components. The rotation is applied first, followed by the translation.
+ *
+ * The rotation must satisfy the orthonormality invariant of rotations.
+ */
+typedef struct CglIsometry3d {
+  struct CglRotation3d rotation;
+  struct CglTranslation3d translation;
+} CglIsometry3d;
+
+/**
+ * A similarity in three dimensions with `f64` components. The uniform scale is applied first, followed by the isometry.
+ *
+ * The rotation must satisfy the orthonormality invariant of rotations.
+ */
+typedef struct CglSimilarity3d {
+  struct CglIsometry3d isometry;
+  double scale;
+} CglSimilarity3d;
+
+/**
+ * A perspective projection stored as a `f64` homogeneous matrix.
+ */
+typedef struct CglPerspective3d {
+  struct CglMatrix4x4d matrix;
+} CglPerspective3d;
+
+/**
+ * An orthographic projection stored as a `f64` homogeneous matrix.
+ */
+typedef struct CglOrthographic3d {
+  struct CglMatrix4x4d matrix;
+} CglOrthographic3d;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Add two vectors componentwise.
+ */
+struct CglVector2f cgl_vector2f_add(struct CglVector2f a, struct CglVector2f b);
+
+/**
+ * Subtract two vectors componentwise.
+ */
+struct CglVector2f cgl_vector2f_sub(struct CglVector2f a, struct CglVector2f b);
+
+/**
+ * Multiply a vector by a scalar.
+ */
+struct CglVector2f cgl_vector2f_mul_scalar(struct CglVector2f vector, float scalar);
+
+/**
+ * Compute the dot product of two vectors.
+ */
+float cgl_vector2f_dot(struct CglVector2f a, struct CglVector2f b);
+
+/**
+ * Compute the Euclidean norm of a vector.
+ */
+float cgl_vector2f_norm(struct CglVector2f vector);
+
+/**
+ * Normalize a vector to unit length.
+ */
+struct CglVector2f cgl_vector2f_normalize(struct CglVector2f vector);
+
+/**
+ * Add two vectors componentwise.
+ */
+struct CglVector3f cgl_vector3f_add(struct CglVector3f a, struct CglVector3f b);
+
+/**
+ * Subtract two vectors componentwise.
+ */
+struct CglVector3f cgl_vector3f_sub(struct CglVector3f a, struct CglVector3f b);
+
+/**
+ * Multiply a vector by a scalar.
+ */
+struct CglVector3f cgl_vector3f_mul_scalar(struct CglVector3f vector, float scalar);
+
+/**
+ * Compute the dot product of two vectors.
+ */
+float cgl_vector3f_dot(struct CglVector3f a, struct CglVector3f b);
+
+/**
+ * Compute the Euclidean norm of a vector.
+ */
+float cgl_vector3f_norm(struct CglVector3f vector);
+
+/**
+ * Normalize a vector to unit length.
+ */
+struct CglVector3f cgl_vector3f_normalize(struct CglVector3f vector);
+
+/**
+ * Add two vectors componentwise.
+ */
+struct CglVector4f cgl_vector4f_add(struct CglVector4f a, struct CglVector4f b);
+
+/**
+ * Subtract two vectors componentwise.
+ */
+struct CglVector4f cgl_vector4f_sub(struct CglVector4f a, struct CglVector4f b);
+
+/**
+ * Multiply a vector by a scalar.
+ */
+struct CglVector4f cgl_vector4f_mul_scalar(struct CglVector4f vector, float scalar);
+
+/**
+ * Compute the dot product of two vectors.
+ */
+float cgl_vector4f_dot(struct CglVector4f a, struct CglVector4f b);
+
+/**
+ * Compute the Euclidean norm of a vector.
+ */
+float cgl_vector4f_norm(struct CglVector4f vector);
+
+/**
+ * Normalize a vector to unit length.
+ */
+struct CglVector4f cgl_vector4f_normalize(struct CglVector4f vector);
+
+/**
+ * Construct the identity matrix.
+ */
+struct CglMatrix2x2f cgl_matrix2x2f_identity(void);
+
+/**
+ * Add two matrices elementwise.
+ */
+struct CglMatrix2x2f cgl_matrix2x2f_add(struct CglMatrix2x2f a, struct CglMatrix2x2f b);
+
+/**
+ * Subtract two matrices elementwise.
+ */
+struct CglMatrix2x2f cgl_matrix2x2f_sub(struct CglMatrix2x2f a, struct CglMatrix2x2f b);
+
+/**
+ * Multiply two matrices.
+ */
+struct CglMatrix2x2f cgl_matrix2x2f_mul(struct CglMatrix2x2f a, struct CglMatrix2x2f b);
+
+/**
+ * Multiply a matrix by a column vector.
+ */
+struct CglVector2f cgl_matrix2x2f_mul_vector(struct CglMatrix2x2f matrix,
+                                             struct CglVector2f vector);
+
+/**
+ * Multiply a matrix by a scalar.
+ */
+struct CglMatrix2x2f cgl_matrix2x2f_mul_scalar(struct CglMatrix2x2f matrix, float scalar);
+
+/**
+ * Compute the transpose of a matrix.
+ */
+struct CglMatrix2x2f cgl_matrix2x2f_transpose(struct CglMatrix2x2f matrix);
+
+/**
+ * Compute the determinant of a matrix.
+ */
+float cgl_matrix2x2f_determinant(struct CglMatrix2x2f matrix);
+
+/**
+ * Compute the inverse of a matrix, if it exists.
+ *
+ * Returns `true` and writes the inverse to `result` when the matrix is
+ * invertible, and returns `false` without writing anything otherwise. When
+ * `result` is null, only the invertibility of the matrix is reported.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one matrix.
+ */
+bool cgl_matrix2x2f_try_inverse(struct CglMatrix2x2f matrix, struct CglMatrix2x2f *result);
+
+/**
+ * Construct the identity matrix.
+ */
+struct CglMatrix3x3f cgl_matrix3x3f_identity(void);
+
+/**
+ * Add two matrices elementwise.
+ */
+struct CglMatrix3x3f cgl_matrix3x3f_add(struct CglMatrix3x3f a, struct CglMatrix3x3f b);
+
+/**
+ * Subtract two matrices elementwise.
+ */
+struct CglMatrix3x3f cgl_matrix3x3f_sub(struct CglMatrix3x3f a, struct CglMatrix3x3f b);
+
+/**
+ * Multiply two matrices.
+ */
+struct CglMatrix3x3f cgl_matrix3x3f_mul(struct CglMatrix3x3f a, struct CglMatrix3x3f b);
+
+/**
+ * Multiply a matrix by a column vector.
+ */
+struct CglVector3f cgl_matrix3x3f_mul_vector(struct CglMatrix3x3f matrix,
+                                             struct CglVector3f vector);
+
+/**
+ * Multiply a matrix by a scalar.
+ */
+struct CglMatrix3x3f cgl_matrix3x3f_mul_scalar(struct CglMatrix3x3f matrix, float scalar);
+
+/**
+ * Compute the transpose of a matrix.
+ */
+struct CglMatrix3x3f cgl_matrix3x3f_transpose(struct CglMatrix3x3f matrix);
+
+/**
+ * Compute the determinant of a matrix.
+ */
+float cgl_matrix3x3f_determinant(struct CglMatrix3x3f matrix);
+
+/**
+ * Compute the inverse of a matrix, if it exists.
+ *
+ * Returns `true` and writes the inverse to `result` when the matrix is
+ * invertible, and returns `false` without writing anything otherwise. When
+ * `result` is null, only the invertibility of the matrix is reported.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one matrix.
+ */
+bool cgl_matrix3x3f_try_inverse(struct CglMatrix3x3f matrix, struct CglMatrix3x3f *result);
+
+/**
+ * Construct the identity matrix.
+ */
+struct CglMatrix4x4f cgl_matrix4x4f_identity(void);
+
+/**
+ * Add two matrices elementwise.
+ */
+struct CglMatrix4x4f cgl_matrix4x4f_add(struct CglMatrix4x4f a, struct CglMatrix4x4f b);
+
+/**
+ * Subtract two matrices elementwise.
+ */
+struct CglMatrix4x4f cgl_matrix4x4f_sub(struct CglMatrix4x4f a, struct CglMatrix4x4f b);
+
+/**
+ * Multiply two matrices.
+ */
+struct CglMatrix4x4f cgl_matrix4x4f_mul(struct CglMatrix4x4f a, struct CglMatrix4x4f b);
+
+/**
+ * Multiply a matrix by a column vector.
+ */
+struct CglVector4f cgl_matrix4x4f_mul_vector(struct CglMatrix4x4f matrix,
+                                             struct CglVector4f vector);
+
+/**
+ * Multiply a matrix by a scalar.
+ */
+struct CglMatrix4x4f cgl_matrix4x4f_mul_scalar(struct CglMatrix4x4f matrix, float scalar);
+
+/**
+ * Compute the transpose of a matrix.
+ */
+struct CglMatrix4x4f cgl_matrix4x4f_transpose(struct CglMatrix4x4f matrix);
+
+/**
+ * Compute the determinant of a matrix.
+ */
+float cgl_matrix4x4f_determinant(struct CglMatrix4x4f matrix);
+
+/**
+ * Compute the inverse of a matrix, if it exists.
+ *
+ * Returns `true` and writes the inverse to `result` when the matrix is
+ * invertible, and returns `false` without writing anything otherwise. When
+ * `result` is null, only the invertibility of the matrix is reported.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one matrix.
+ */
+bool cgl_matrix4x4f_try_inverse(struct CglMatrix4x4f matrix, struct CglMatrix4x4f *result);
+
+/**
+ * Compute the cross product of two three-dimensional vectors.
+ */
+struct CglVector3f cgl_vector3f_cross(struct CglVector3f a, struct CglVector3f b);
+
+/**
+ * Construct a right-handed view matrix looking from `eye` towards `target`.
+ */
+struct CglMatrix4x4f cgl_matrix4x4f_look_at_rh(struct CglPoint3f eye,
+                                               struct CglPoint3f target,
+                                               struct CglVector3f up);
+
+/**
+ * Construct a perspective projection matrix from the frustum bounds.
+ *
+ * `left`, `right`, `bottom`, and `top` are the distances from the view
+ * axis to the sides of the near plane, and `near` and `far` are the
+ * distances from the eye to the near and far planes.
+ */
+struct CglMatrix4x4f cgl_matrix4x4f_perspective(float left,
+                                                float right,
+                                                float bottom,
+                                                float top,
+                                                float near,
+                                                float far);
+
+/**
+ * Construct a perspective projection matrix from a vertical field of view in
+ * radians, an aspect ratio, and the near and far plane distances.
+ */
+struct CglMatrix4x4f cgl_matrix4x4f_perspective_vfov(float vfov,
+                                                     float aspect_ratio,
+                                                     float near,
+                                                     float far);
+
+/**
+ * Construct an orthographic projection matrix from the view volume bounds.
+ *
+ * As with the perspective matrix, `left`, `right`, `bottom`, and `top` are
+ * distances from the view axis, and `near` and `far` are distances from the
+ * eye.
+ */
+struct CglMatrix4x4f cgl_matrix4x4f_orthographic(float left,
+                                                 float right,
+                                                 float bottom,
+                                                 float top,
+                                                 float near,
+                                                 float far);
+
+/**
+ * Construct the identity quaternion.
+ */
+struct CglQuaternionf cgl_quaternionf_identity(void);
+
+/**
+ * Construct a unit quaternion rotating by `angle` radians about `axis`. The
+ * axis does not need to be normalized.
+ *
+ * Returns `true` and writes the quaternion to `result` when the axis is
+ * nonzero and finite, and returns `false` without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one quaternion.
+ */
+bool cgl_quaternionf_from_axis_angle(struct CglVector3f axis,
+                                     float angle,
+                                     struct CglQuaternionf *result);
+
+/**
+ * Multiply two quaternions.
+ */
+struct CglQuaternionf cgl_quaternionf_mul(struct CglQuaternionf a, struct CglQuaternionf b);
+
+/**
+ * Compute the conjugate of a quaternion.
+ */
+struct CglQuaternionf cgl_quaternionf_conjugate(struct CglQuaternionf quaternion);
+
+/**
+ * Compute the norm of a quaternion.
+ */
+float cgl_quaternionf_norm(struct CglQuaternionf quaternion);
+
+/**
+ * Normalize a quaternion to unit norm.
+ */
+struct CglQuaternionf cgl_quaternionf_normalize(struct CglQuaternionf quaternion);
+
+/**
+ * Spherically interpolate between two unit quaternions, where `amount` runs
+ * from `0` at `a` to `1` at `b`.
+ */
+struct CglQuaternionf cgl_quaternionf_slerp(struct CglQuaternionf a,
+                                            struct CglQuaternionf b,
+                                            float amount);
+
+/**
+ * Convert a unit quaternion into a three-by-three rotation matrix.
+ */
+struct CglMatrix3x3f cgl_quaternionf_to_matrix3x3(struct CglQuaternionf quaternion);
+
+/**
+ * Construct a translation from a displacement vector.
+ */
+struct CglTranslation3f cgl_translation3f_from_vector(struct CglVector3f vector);
+
+/**
+ * Compute the inverse of a translation.
+ */
+struct CglTranslation3f cgl_translation3f_inverse(struct CglTranslation3f translation);
+
+/**
+ * Apply a translation to a point.
+ */
+struct CglPoint3f cgl_translation3f_apply_point(struct CglTranslation3f translation,
+                                                struct CglPoint3f point);
+
+/**
+ * Convert a translation into a homogeneous affine matrix.
+ */
+struct CglMatrix4x4f cgl_translation3f_to_affine_matrix(struct CglTranslation3f translation);
+
+/**
+ * Construct a nonuniform scale from a vector of scaling factors.
+ */
+struct CglScale3f cgl_scale3f_from_nonuniform_scale(struct CglVector3f vector);
+
+/**
+ * Compute the inverse of a scale.
+ */
+struct CglScale3f cgl_scale3f_inverse(struct CglScale3f scale);
+
+/**
+ * Apply a scale to a vector.
+ */
+struct CglVector3f cgl_scale3f_apply_vector(struct CglScale3f scale, struct CglVector3f vector);
+
+/**
+ * Apply a scale to a point.
+ */
+struct CglPoint3f cgl_scale3f_apply_point(struct CglScale3f scale, struct CglPoint3f point);
+
+/**
+ * Convert a scale into a homogeneous affine matrix.
+ */
+struct CglMatrix4x4f cgl_scale3f_to_affine_matrix(struct CglScale3f scale);
+
+/**
+ * Construct a rotation by `angle` radians about `axis`. The axis does not need
+ * to be normalized.
+ *
+ * Returns `true` and writes the rotation to `result` when the axis is nonzero
+ * and finite, and returns `false` without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one rotation.
+ */
+bool cgl_rotation3f_from_axis_angle(struct CglVector3f axis,
+                                    float angle,
+                                    struct CglRotation3f *result);
+
+/**
+ * Construct a rotation from a quaternion. The quaternion does not need to be
+ * normalized.
+ *
+ * Returns `true` and writes the rotation to `result` when the quaternion is
+ * nonzero and finite, and returns `false` without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one rotation.
+ */
+bool cgl_rotation3f_from_quaternion(struct CglQuaternionf quaternion, struct CglRotation3f *result);
+
+/**
+ * Construct a rotation from a rotation matrix.
+ *
+ * Returns `true` and writes the rotation to `result` when the matrix is
+ * orthonormal with determinant `1`, and returns `false` without writing
+ * anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one rotation.
+ */
+bool cgl_rotation3f_from_matrix(struct CglMatrix3x3f matrix, struct CglRotation3f *result);
+
+/**
+ * Construct the rotation of a right-handed view looking from `eye` towards
+ * `target`.
+ */
+struct CglRotation3f cgl_rotation3f_look_at_rh(struct CglPoint3f eye,
+                                               struct CglPoint3f target,
+                                               struct CglVector3f up);
+
+/**
+ * Compute the inverse of a rotation.
+ *
+ * The inverse of a rotation is the transpose of its matrix, which is only
+ * correct for an orthonormal matrix. Returns `true` and writes the inverse to
+ * `result` when the rotation matrix is orthonormal with determinant `1`, and
+ * returns `false` without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one rotation.
+ */
+bool cgl_rotation3f_try_inverse(struct CglRotation3f rotation, struct CglRotation3f *result);
+
+/**
+ * Compose two rotations, applying `b` first and then `a`.
+ */
+struct CglRotation3f cgl_rotation3f_mul(struct CglRotation3f a, struct CglRotation3f b);
+
+/**
+ * Apply a rotation to a vector.
+ */
+struct CglVector3f cgl_rotation3f_apply_vector(struct CglRotation3f rotation,
+                                               struct CglVector3f vector);
+
+/**
+ * Apply a rotation to a point.
+ */
+struct CglPoint3f cgl_rotation3f_apply_point(struct CglRotation3f rotation,
+                                             struct CglPoint3f point);
+
+/**
+ * Convert a rotation into a homogeneous affine matrix.
+ */
+struct CglMatrix4x4f cgl_rotation3f_to_affine_matrix(struct CglRotation3f rotation);
+
+/**
+ * Construct an isometry from a translation and a rotation.
+ */
+struct CglIsometry3f cgl_isometry3f_from_parts(struct CglTranslation3f translation,
+                                               struct CglRotation3f rotation);
+
+/**
+ * Construct the world-to-view isometry of a right-handed view looking from
+ * `eye` towards `target`.
+ */
+struct CglIsometry3f cgl_isometry3f_look_at_rh(struct CglPoint3f eye,
+                                               struct CglPoint3f target,
+                                               struct CglVector3f up);
+
+/**
+ * Compute the inverse of an isometry.
+ *
+ * Returns `true` and writes the inverse to `result` when the rotation matrix
+ * of the isometry is orthonormal with determinant `1`, and returns `false`
+ * without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one isometry.
+ */
+bool cgl_isometry3f_try_inverse(struct CglIsometry3f isometry, struct CglIsometry3f *result);
+
+/**
+ * Compose two isometries, applying `b` first and then `a`.
+ */
+struct CglIsometry3f cgl_isometry3f_mul(struct CglIsometry3f a, struct CglIsometry3f b);
+
+/**
+ * Apply an isometry to a vector.
+ */
+struct CglVector3f cgl_isometry3f_apply_vector(struct CglIsometry3f isometry,
+                                               struct CglVector3f vector);
+
+/**
+ * Apply an isometry to a point.
+ */
+struct CglPoint3f cgl_isometry3f_apply_point(struct CglIsometry3f isometry,
+                                             struct CglPoint3f point);
+
+/**
+ * Convert an isometry into a homogeneous affine matrix.
+ */
+struct CglMatrix4x4f cgl_isometry3f_to_affine_matrix(struct CglIsometry3f isometry);
+
+/**
+ * Construct a similarity from a translation, a rotation, and a uniform scale.
+ */
+struct CglSimilarity3f cgl_similarity3f_from_parts(struct CglTranslation3f translation,
+                                                   struct CglRotation3f rotation,
+                                                   float scale);
+
+/**
+ * Compute the inverse of a similarity.
+ *
+ * Returns `true` and writes the inverse to `result` when the rotation matrix
+ * of the similarity is orthonormal with determinant `1` and the scale is
+ * nonzero and finite, and returns `false` without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one similarity.
+ */
+bool cgl_similarity3f_try_inverse(struct CglSimilarity3f similarity,
+                                  struct CglSimilarity3f *result);
+
+/**
+ * Compose two similarities, applying `b` first and then `a`.
+ */
+struct CglSimilarity3f cgl_similarity3f_mul(struct CglSimilarity3f a, struct CglSimilarity3f b);
+
+/**
+ * Apply a similarity to a vector.
+ */
+struct CglVector3f cgl_similarity3f_apply_vector(struct CglSimilarity3f similarity,
+                                                 struct CglVector3f vector);
+
+/**
+ * Apply a similarity to a point.
+ */
+struct CglPoint3f cgl_similarity3f_apply_point(struct CglSimilarity3f similarity,
+                                               struct CglPoint3f point);
+
+/**
+ * Convert a similarity into a homogeneous affine matrix.
+ */
+struct CglMatrix4x4f cgl_similarity3f_to_affine_matrix(struct CglSimilarity3f similarity);
+
+/**
+ * Construct a perspective projection from the frustum bounds, with the same
+ * conventions as the perspective matrix constructor.
+ */
+struct CglPerspective3f cgl_perspective3f_new(float left,
+                                              float right,
+                                              float bottom,
+                                              float top,
+                                              float near,
+                                              float far);
+
+/**
+ * Project a point from eye space into normalized device coordinates.
+ */
+struct CglPoint3f cgl_perspective3f_project_point(struct CglPerspective3f perspective,
+                                                  struct CglPoint3f point);
+
+/**
+ * Unproject a point from normalized device coordinates into eye space.
+ */
+struct CglPoint3f cgl_perspective3f_unproject_point(struct CglPerspective3f perspective,
+                                                    struct CglPoint3f point);
+
+/**
+ * Construct an orthographic projection from the view volume bounds, with the
+ * same conventions as the orthographic matrix constructor.
+ */
+struct CglOrthographic3f cgl_orthographic3f_new(float left,
+                                                float right,
+                                                float bottom,
+                                                float top,
+                                                float near,
+                                                float far);
+
+/**
+ * Project a point from eye space into normalized device coordinates.
+ */
+struct CglPoint3f cgl_orthographic3f_project_point(struct CglOrthographic3f orthographic,
+                                                   struct CglPoint3f point);
+
+/**
+ * Unproject a point from normalized device coordinates into eye space.
+ */
+struct CglPoint3f cgl_orthographic3f_unproject_point(struct CglOrthographic3f orthographic,
+                                                     struct CglPoint3f point);
+
+/**
+ * Add two vectors componentwise.
+ */
+struct CglVector2d cgl_vector2d_add(struct CglVector2d a, struct CglVector2d b);
+
+/**
+ * Subtract two vectors componentwise.
+ */
+struct CglVector2d cgl_vector2d_sub(struct CglVector2d a, struct CglVector2d b);
+
+/**
+ * Multiply a vector by a scalar.
+ */
+struct CglVector2d cgl_vector2d_mul_scalar(struct CglVector2d vector, double scalar);
+
+/**
+ * Compute the dot product of two vectors.
+ */
+double cgl_vector2d_dot(struct CglVector2d a, struct CglVector2d b);
+
+/**
+ * Compute the Euclidean norm of a vector.
+ */
+double cgl_vector2d_norm(struct CglVector2d vector);
+
+/**
+ * Normalize a vector to unit length.
+ */
+struct CglVector2d cgl_vector2d_normalize(struct CglVector2d vector);
+
+/**
+ * Add two vectors componentwise.
+ */
+struct CglVector3d cgl_vector3d_add(struct CglVector3d a, struct CglVector3d b);
+
+/**
+ * Subtract two vectors componentwise.
+ */
+struct CglVector3d cgl_vector3d_sub(struct CglVector3d a, struct CglVector3d b);
+
+/**
+ * Multiply a vector by a scalar.
+ */
+struct CglVector3d cgl_vector3d_mul_scalar(struct CglVector3d vector, double scalar);
+
+/**
+ * Compute the dot product of two vectors.
+ */
+double cgl_vector3d_dot(struct CglVector3d a, struct CglVector3d b);
+
+/**
+ * Compute the Euclidean norm of a vector.
+ */
+double cgl_vector3d_norm(struct CglVector3d vector);
+
+/**
+ * Normalize a vector to unit length.
+ */
+struct CglVector3d cgl_vector3d_normalize(struct CglVector3d vector);
+
+/**
+ * Add two vectors componentwise.
+ */
+struct CglVector4d cgl_vector4d_add(struct CglVector4d a, struct CglVector4d b);
+
+/**
+ * Subtract two vectors componentwise.
+ */
+struct CglVector4d cgl_vector4d_sub(struct CglVector4d a, struct CglVector4d b);
+
+/**
+ * Multiply a vector by a scalar.
+ */
+struct CglVector4d cgl_vector4d_mul_scalar(struct CglVector4d vector, double scalar);
+
+/**
+ * Compute the dot product of two vectors.
+ */
+double cgl_vector4d_dot(struct CglVector4d a, struct CglVector4d b);
+
+/**
+ * Compute the Euclidean norm of a vector.
+ */
+double cgl_vector4d_norm(struct CglVector4d vector);
+
+/**
+ * Normalize a vector to unit length.
+ */
+struct CglVector4d cgl_vector4d_normalize(struct CglVector4d vector);
+
+/**
+ * Construct the identity matrix.
+ */
+struct CglMatrix2x2d cgl_matrix2x2d_identity(void);
+
+/**
+ * Add two matrices elementwise.
+ */
+struct CglMatrix2x2d cgl_matrix2x2d_add(struct CglMatrix2x2d a, struct CglMatrix2x2d b);
+
+/**
+ * Subtract two matrices elementwise.
+ */
+struct CglMatrix2x2d cgl_matrix2x2d_sub(struct CglMatrix2x2d a, struct CglMatrix2x2d b);
+
+/**
+ * Multiply two matrices.
+ */
+struct CglMatrix2x2d cgl_matrix2x2d_mul(struct CglMatrix2x2d a, struct CglMatrix2x2d b);
+
+/**
+ * Multiply a matrix by a column vector.
+ */
+struct CglVector2d cgl_matrix2x2d_mul_vector(struct CglMatrix2x2d matrix,
+                                             struct CglVector2d vector);
+
+/**
+ * Multiply a matrix by a scalar.
+ */
+struct CglMatrix2x2d cgl_matrix2x2d_mul_scalar(struct CglMatrix2x2d matrix, double scalar);
+
+/**
+ * Compute the transpose of a matrix.
+ */
+struct CglMatrix2x2d cgl_matrix2x2d_transpose(struct CglMatrix2x2d matrix);
+
+/**
+ * Compute the determinant of a matrix.
+ */
+double cgl_matrix2x2d_determinant(struct CglMatrix2x2d matrix);
+
+/**
+ * Compute the inverse of a matrix, if it exists.
+ *
+ * Returns `true` and writes the inverse to `result` when the matrix is
+ * invertible, and returns `false` without writing anything otherwise. When
+ * `result` is null, only the invertibility of the matrix is reported.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one matrix.
+ */
+bool cgl_matrix2x2d_try_inverse(struct CglMatrix2x2d matrix, struct CglMatrix2x2d *result);
+
+/**
+ * Construct the identity matrix.
+ */
+struct CglMatrix3x3d cgl_matrix3x3d_identity(void);
+
+/**
+ * Add two matrices elementwise.
+ */
+struct CglMatrix3x3d cgl_matrix3x3d_add(struct CglMatrix3x3d a, struct CglMatrix3x3d b);
+
+/**
+ * Subtract two matrices elementwise.
+ */
+struct CglMatrix3x3d cgl_matrix3x3d_sub(struct CglMatrix3x3d a, struct CglMatrix3x3d b);
+
+/**
+ * Multiply two matrices.
+ */
+struct CglMatrix3x3d cgl_matrix3x3d_mul(struct CglMatrix3x3d a, struct CglMatrix3x3d b);
+
+/**
+ * Multiply a matrix by a column vector.
+ */
+struct CglVector3d cgl_matrix3x3d_mul_vector(struct CglMatrix3x3d matrix,
+                                             struct CglVector3d vector);
+
+/**
+ * Multiply a matrix by a scalar.
+ */
+struct CglMatrix3x3d cgl_matrix3x3d_mul_scalar(struct CglMatrix3x3d matrix, double scalar);
+
+/**
+ * Compute the transpose of a matrix.
+ */
+struct CglMatrix3x3d cgl_matrix3x3d_transpose(struct CglMatrix3x3d matrix);
+
+/**
+ * Compute the determinant of a matrix.
+ */
+double cgl_matrix3x3d_determinant(struct CglMatrix3x3d matrix);
+
+/**
+ * Compute the inverse of a matrix, if it exists.
+ *
+ * Returns `true` and writes the inverse to `result` when the matrix is
+ * invertible, and returns `false` without writing anything otherwise. When
+ * `result` is null, only the invertibility of the matrix is reported.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one matrix.
+ */
+bool cgl_matrix3x3d_try_inverse(struct CglMatrix3x3d matrix, struct CglMatrix3x3d *result);
+
+/**
+ * Construct the identity matrix.
+ */
+struct CglMatrix4x4d cgl_matrix4x4d_identity(void);
+
+/**
+ * Add two matrices elementwise.
+ */
+struct CglMatrix4x4d cgl_matrix4x4d_add(struct CglMatrix4x4d a, struct CglMatrix4x4d b);
+
+/**
+ * Subtract two matrices elementwise.
+ */
+struct CglMatrix4x4d cgl_matrix4x4d_sub(struct CglMatrix4x4d a, struct CglMatrix4x4d b);
+
+/**
+ * Multiply two matrices.
+ */
+struct CglMatrix4x4d cgl_matrix4x4d_mul(struct CglMatrix4x4d a, struct CglMatrix4x4d b);
+
+/**
+ * Multiply a matrix by a column vector.
+ */
+struct CglVector4d cgl_matrix4x4d_mul_vector(struct CglMatrix4x4d matrix,
+                                             struct CglVector4d vector);
+
+/**
+ * Multiply a matrix by a scalar.
+ */
+struct CglMatrix4x4d cgl_matrix4x4d_mul_scalar(struct CglMatrix4x4d matrix, double scalar);
+
+/**
+ * Compute the transpose of a matrix.
+ */
+struct CglMatrix4x4d cgl_matrix4x4d_transpose(struct CglMatrix4x4d matrix);
+
+/**
+ * Compute the determinant of a matrix.
+ */
+double cgl_matrix4x4d_determinant(struct CglMatrix4x4d matrix);
+
+/**
+ * Compute the inverse of a matrix, if it exists.
+ *
+ * Returns `true` and writes the inverse to `result` when the matrix is
+ * invertible, and returns `false` without writing anything otherwise. When
+ * `result` is null, only the invertibility of the matrix is reported.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one matrix.
+ */
+bool cgl_matrix4x4d_try_inverse(struct CglMatrix4x4d matrix, struct CglMatrix4x4d *result);
+
+/**
+ * Compute the cross product of two three-dimensional vectors.
+ */
+struct CglVector3d cgl_vector3d_cross(struct CglVector3d a, struct CglVector3d b);
+
+/**
+ * Construct a right-handed view matrix looking from `eye` towards `target`.
+ */
+struct CglMatrix4x4d cgl_matrix4x4d_look_at_rh(struct CglPoint3d eye,
+                                               struct CglPoint3d target,
+                                               struct CglVector3d up);
+
+/**
+ * Construct a perspective projection matrix from the frustum bounds.
+ *
+ * `left`, `right`, `bottom`, and `top` are the distances from the view
+ * axis to the sides of the near plane, and `near` and `far` are the
+ * distances from the eye to the near and far planes.
+ */
+struct CglMatrix4x4d cgl_matrix4x4d_perspective(double left,
+                                                double right,
+                                                double bottom,
+                                                double top,
+                                                double near,
+                                                double far);
+
+/**
+ * Construct a perspective projection matrix from a vertical field of view in
+ * radians, an aspect ratio, and the near and far plane distances.
+ */
+struct CglMatrix4x4d cgl_matrix4x4d_perspective_vfov(double vfov,
+                                                     double aspect_ratio,
+                                                     double near,
+                                                     double far);
+
+/**
+ * Construct an orthographic projection matrix from the view volume bounds.
+ *
+ * As with the perspective matrix, `left`, `right`, `bottom`, and `top` are
+ * distances from the view axis, and `near` and `far` are distances from the
+ * eye.
+ */
+struct CglMatrix4x4d cgl_matrix4x4d_orthographic(double left,
+                                                 double right,
+                                                 double bottom,
+                                                 double top,
+                                                 double near,
+                                                 double far);
+
+/**
+ * Construct the identity quaternion.
+ */
+struct CglQuaterniond cgl_quaterniond_identity(void);
+
+/**
+ * Construct a unit quaternion rotating by `angle` radians about `axis`. The
+ * axis does not need to be normalized.
+ *
+ * Returns `true` and writes the quaternion to `result` when the axis is
+ * nonzero and finite, and returns `false` without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one quaternion.
+ */
+bool cgl_quaterniond_from_axis_angle(struct CglVector3d axis,
+                                     double angle,
+                                     struct CglQuaterniond *result);
+
+/**
+ * Multiply two quaternions.
+ */
+struct CglQuaterniond cgl_quaterniond_mul(struct CglQuaterniond a, struct CglQuaterniond b);
+
+/**
+ * Compute the conjugate of a quaternion.
+ */
+struct CglQuaterniond cgl_quaterniond_conjugate(struct CglQuaterniond quaternion);
+
+/**
+ * Compute the norm of a quaternion.
+ */
+double cgl_quaterniond_norm(struct CglQuaterniond quaternion);
+
+/**
+ * Normalize a quaternion to unit norm.
+ */
+struct CglQuaterniond cgl_quaterniond_normalize(struct CglQuaterniond quaternion);
+
+/**
+ * Spherically interpolate between two unit quaternions, where `amount` runs
+ * from `0` at `a` to `1` at `b`.
+ */
+struct CglQuaterniond cgl_quaterniond_slerp(struct CglQuaterniond a,
+                                            struct CglQuaterniond b,
+                                            double amount);
+
+/**
+ * Convert a unit quaternion into a three-by-three rotation matrix.
+ */
+struct CglMatrix3x3d cgl_quaterniond_to_matrix3x3(struct CglQuaterniond quaternion);
+
+/**
+ * Construct a translation from a displacement vector.
+ */
+struct CglTranslation3d cgl_translation3d_from_vector(struct CglVector3d vector);
+
+/**
+ * Compute the inverse of a translation.
+ */
+struct CglTranslation3d cgl_translation3d_inverse(struct CglTranslation3d translation);
+
+/**
+ * Apply a translation to a point.
+ */
+struct CglPoint3d cgl_translation3d_apply_point(struct CglTranslation3d translation,
+                                                struct CglPoint3d point);
+
+/**
+ * Convert a translation into a homogeneous affine matrix.
+ */
+struct CglMatrix4x4d cgl_translation3d_to_affine_matrix(struct CglTranslation3d translation);
+
+/**
+ * Construct a nonuniform scale from a vector of scaling factors.
+ */
+struct CglScale3d cgl_scale3d_from_nonuniform_scale(struct CglVector3d vector);
+
+/**
+ * Compute the inverse of a scale.
+ */
+struct CglScale3d cgl_scale3d_inverse(struct CglScale3d scale);
+
+/**
+ * Apply a scale to a vector.
+ */
+struct CglVector3d cgl_scale3d_apply_vector(struct CglScale3d scale, struct CglVector3d vector);
+
+/**
+ * Apply a scale to a point.
+ */
+struct CglPoint3d cgl_scale3d_apply_point(struct CglScale3d scale, struct CglPoint3d point);
+
+/**
+ * Convert a scale into a homogeneous affine matrix.
+ */
+struct CglMatrix4x4d cgl_scale3d_to_affine_matrix(struct CglScale3d scale);
+
+/**
+ * Construct a rotation by `angle` radians about `axis`. The axis does not need
+ * to be normalized.
+ *
+ * Returns `true` and writes the rotation to `result` when the axis is nonzero
+ * and finite, and returns `false` without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one rotation.
+ */
+bool cgl_rotation3d_from_axis_angle(struct CglVector3d axis,
+                                    double angle,
+                                    struct CglRotation3d *result);
+
+/**
+ * Construct a rotation from a quaternion. The quaternion does not need to be
+ * normalized.
+ *
+ * Returns `true` and writes the rotation to `result` when the quaternion is
+ * nonzero and finite, and returns `false` without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one rotation.
+ */
+bool cgl_rotation3d_from_quaternion(struct CglQuaterniond quaternion, struct CglRotation3d *result);
+
+/**
+ * Construct a rotation from a rotation matrix.
+ *
+ * Returns `true` and writes the rotation to `result` when the matrix is
+ * orthonormal with determinant `1`, and returns `false` without writing
+ * anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one rotation.
+ */
+bool cgl_rotation3d_from_matrix(struct CglMatrix3x3d matrix, struct CglRotation3d *result);
+
+/**
+ * Construct the rotation of a right-handed view looking from `eye` towards
+ * `target`.
+ */
+struct CglRotation3d cgl_rotation3d_look_at_rh(struct CglPoint3d eye,
+                                               struct CglPoint3d target,
+                                               struct CglVector3d up);
+
+/**
+ * Compute the inverse of a rotation.
+ *
+ * The inverse of a rotation is the transpose of its matrix, which is only
+ * correct for an orthonormal matrix. Returns `true` and writes the inverse to
+ * `result` when the rotation matrix is orthonormal with determinant `1`, and
+ * returns `false` without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one rotation.
+ */
+bool cgl_rotation3d_try_inverse(struct CglRotation3d rotation, struct CglRotation3d *result);
+
+/**
+ * Compose two rotations, applying `b` first and then `a`.
+ */
+struct CglRotation3d cgl_rotation3d_mul(struct CglRotation3d a, struct CglRotation3d b);
+
+/**
+ * Apply a rotation to a vector.
+ */
+struct CglVector3d cgl_rotation3d_apply_vector(struct CglRotation3d rotation,
+                                               struct CglVector3d vector);
+
+/**
+ * Apply a rotation to a point.
+ */
+struct CglPoint3d cgl_rotation3d_apply_point(struct CglRotation3d rotation,
+                                             struct CglPoint3d point);
+
+/**
+ * Convert a rotation into a homogeneous affine matrix.
+ */
+struct CglMatrix4x4d cgl_rotation3d_to_affine_matrix(struct CglRotation3d rotation);
+
+/**
+ * Construct an isometry from a translation and a rotation.
+ */
+struct CglIsometry3d cgl_isometry3d_from_parts(struct CglTranslation3d translation,
+                                               struct CglRotation3d rotation);
+
+/**
+ * Construct the world-to-view isometry of a right-handed view looking from
+ * `eye` towards `target`.
+ */
+struct CglIsometry3d cgl_isometry3d_look_at_rh(struct CglPoint3d eye,
+                                               struct CglPoint3d target,
+                                               struct CglVector3d up);
+
+/**
+ * Compute the inverse of an isometry.
+ *
+ * Returns `true` and writes the inverse to `result` when the rotation matrix
+ * of the isometry is orthonormal with determinant `1`, and returns `false`
+ * without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one isometry.
+ */
+bool cgl_isometry3d_try_inverse(struct CglIsometry3d isometry, struct CglIsometry3d *result);
+
+/**
+ * Compose two isometries, applying `b` first and then `a`.
+ */
+struct CglIsometry3d cgl_isometry3d_mul(struct CglIsometry3d a, struct CglIsometry3d b);
+
+/**
+ * Apply an isometry to a vector.
+ */
+struct CglVector3d cgl_isometry3d_apply_vector(struct CglIsometry3d isometry,
+                                               struct CglVector3d vector);
+
+/**
+ * Apply an isometry to a point.
+ */
+struct CglPoint3d cgl_isometry3d_apply_point(struct CglIsometry3d isometry,
+                                             struct CglPoint3d point);
+
+/**
+ * Convert an isometry into a homogeneous affine matrix.
+ */
+struct CglMatrix4x4d cgl_isometry3d_to_affine_matrix(struct CglIsometry3d isometry);
+
+/**
+ * Construct a similarity from a translation, a rotation, and a uniform scale.
+ */
+struct CglSimilarity3d cgl_similarity3d_from_parts(struct CglTranslation3d translation,
+                                                   struct CglRotation3d rotation,
+                                                   double scale);
+
+/**
+ * Compute the inverse of a similarity.
+ *
+ * Returns `true` and writes the inverse to `result` when the rotation matrix
+ * of the similarity is orthonormal with determinant `1` and the scale is
+ * nonzero and finite, and returns `false` without writing anything otherwise.
+ *
+ * # Safety
+ *
+ * `result` must be either null or valid for writing one similarity.
+ */
+bool cgl_similarity3d_try_inverse(struct CglSimilarity3d similarity,
+                                  struct CglSimilarity3d *result);
+
+/**
+ * Compose two similarities, applying `b` first and then `a`.
+ */
+struct CglSimilarity3d cgl_similarity3d_mul(struct CglSimilarity3d a, struct CglSimilarity3d b);
+
+/**
+ * Apply a similarity to a vector.
+ */
+struct CglVector3d cgl_similarity3d_apply_vector(struct CglSimilarity3d similarity,
+                                                 struct CglVector3d vector);
+
+/**
+ * Apply a similarity to a point.
+ */
+struct CglPoint3d cgl_similarity3d_apply_point(struct CglSimilarity3d similarity,
+                                               struct CglPoint3d point);
+
+/**
+ * Convert a similarity into a homogeneous affine matrix.
+ */
+struct CglMatrix4x4d cgl_similarity3d_to_affine_matrix(struct CglSimilarity3d similarity);
+
+/**
+ * Construct a perspective projection from the frustum bounds, with the same
+ * conventions as the perspective matrix constructor.
+ */
+struct CglPerspective3d cgl_perspective3d_new(double left,
+                                              double right,
+                                              double bottom,
+                                              double top,
+                                              double near,
+                                              double far);
+
+/**
+ * Project a point from eye space into normalized device coordinates.
+ */
+struct CglPoint3d cgl_perspective3d_project_point(struct CglPerspective3d perspective,
+                                                  struct CglPoint3d point);
+
+/**
+ * Unproject a point from normalized device coordinates into eye space.
+ */
+struct CglPoint3d cgl_perspective3d_unproject_point(struct CglPerspective3d perspective,
+                                                    struct CglPoint3d point);
+
+/**
+ * Construct an orthographic projection from the view volume bounds, with the
+ * same conventions as the orthographic matrix constructor.
+ */
+struct CglOrthographic3d cgl_orthographic3d_new(double left,
+                                                double right,
+                                                double bottom,
+                                                double top,
+                                                double near,
+                                                double far);
+
+/**
+ * Project a point from eye space into normalized device coordinates.
+ */
+struct CglPoint3d cgl_orthographic3d_project_point(struct CglOrthographic3d orthographic,
+                                                   struct CglPoint3d point);
+
+/**
+ * Unproject a point from normalized device coordinates into eye space.
+ */
+struct CglPoint3d cgl_orthographic3d_unproject_point(struct CglOrthographic3d orthographic,
+                                                     struct CglPoint3d point);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* CGLINALG_H */
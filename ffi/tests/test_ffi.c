@@ -0,0 +1,233 @@
+#include <math.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "cglinalg.h"
+
+
+static int failures = 0;
+
+#define CHECK(condition)                                                       \
+    do {                                                                       \
+        if (!(condition)) {                                                    \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__,   \
+                    #condition);                                               \
+            failures += 1;                                                     \
+        }                                                                      \
+    } while (0)
+
+#define CHECK_CLOSE(a, b, tolerance) CHECK(fabs((double)(a) - (double)(b)) <= (tolerance))
+
+
+static void test_vector3d_arithmetic(void) {
+    CglVector3d a = {{1.0, 2.0, 3.0}};
+    CglVector3d b = {{4.0, 5.0, 6.0}};
+
+    CglVector3d sum = cgl_vector3d_add(a, b);
+    CHECK(sum.data[0] == 5.0 && sum.data[1] == 7.0 && sum.data[2] == 9.0);
+
+    CglVector3d difference = cgl_vector3d_sub(b, a);
+    CHECK(difference.data[0] == 3.0 && difference.data[1] == 3.0 && difference.data[2] == 3.0);
+
+    CglVector3d scaled = cgl_vector3d_mul_scalar(a, 2.0);
+    CHECK(scaled.data[0] == 2.0 && scaled.data[1] == 4.0 && scaled.data[2] == 6.0);
+
+    CHECK(cgl_vector3d_dot(a, b) == 32.0);
+
+    CglVector3d cross = cgl_vector3d_cross(a, b);
+    CHECK(cross.data[0] == -3.0 && cross.data[1] == 6.0 && cross.data[2] == -3.0);
+
+    CglVector3d unit = cgl_vector3d_normalize(a);
+    CHECK_CLOSE(cgl_vector3d_norm(unit), 1.0, 1e-15);
+}
+
+static void test_vector4f_arithmetic(void) {
+    CglVector4f a = {{1.0f, 2.0f, 3.0f, 4.0f}};
+    CglVector4f b = {{4.0f, 3.0f, 2.0f, 1.0f}};
+
+    CHECK(cgl_vector4f_dot(a, b) == 20.0f);
+    CHECK(cgl_vector4f_norm((CglVector4f){{0.0f, 3.0f, 0.0f, 4.0f}}) == 5.0f);
+}
+
+static void test_matrix_column_major_layout(void) {
+    /* The first column of the matrix is (1, 2). */
+    CglMatrix2x2d matrix = {{{1.0, 2.0}, {3.0, 4.0}}};
+    CglVector2d vector = {{1.0, 0.0}};
+
+    CglVector2d result = cgl_matrix2x2d_mul_vector(matrix, vector);
+    CHECK(result.data[0] == 1.0 && result.data[1] == 2.0);
+
+    CglMatrix2x2d transpose = cgl_matrix2x2d_transpose(matrix);
+    CHECK(transpose.data[0][1] == 3.0 && transpose.data[1][0] == 2.0);
+
+    CHECK(cgl_matrix2x2d_determinant(matrix) == -2.0);
+}
+
+static void test_matrix4x4_inverse(void) {
+    CglMatrix4x4d matrix = {{
+        {2.0, 0.0, 0.0, 0.0},
+        {0.0, 4.0, 0.0, 0.0},
+        {0.0, 0.0, 8.0, 0.0},
+        {1.0, 2.0, 3.0, 1.0},
+    }};
+    CglMatrix4x4d inverse;
+    CHECK(cgl_matrix4x4d_try_inverse(matrix, &inverse));
+
+    CglMatrix4x4d product = cgl_matrix4x4d_mul(matrix, inverse);
+    CglMatrix4x4d identity = cgl_matrix4x4d_identity();
+    for (int c = 0; c < 4; c++) {
+        for (int r = 0; r < 4; r++) {
+            CHECK_CLOSE(product.data[c][r], identity.data[c][r], 1e-15);
+        }
+    }
+
+    CglMatrix4x4d singular = {{{0.0}}};
+    CHECK(!cgl_matrix4x4d_try_inverse(singular, &inverse));
+    CHECK(!cgl_matrix4x4d_try_inverse(singular, NULL));
+}
+
+static void test_quaternion_slerp(void) {
+    CglVector3d axis = {{0.0, 0.0, 1.0}};
+    CglQuaterniond q0 = cgl_quaterniond_identity();
+    CglQuaterniond q1;
+    CHECK(cgl_quaterniond_from_axis_angle(axis, M_PI / 2.0, &q1));
+
+    CglQuaterniond halfway = cgl_quaterniond_slerp(q0, q1, 0.5);
+    CglQuaterniond expected;
+    CHECK(cgl_quaterniond_from_axis_angle(axis, M_PI / 4.0, &expected));
+    CHECK_CLOSE(halfway.s, expected.s, 1e-15);
+    CHECK_CLOSE(halfway.x, expected.x, 1e-15);
+    CHECK_CLOSE(halfway.y, expected.y, 1e-15);
+    CHECK_CLOSE(halfway.z, expected.z, 1e-15);
+    CHECK_CLOSE(cgl_quaterniond_norm(halfway), 1.0, 1e-15);
+
+    CglMatrix3x3d rotation = cgl_quaterniond_to_matrix3x3(q1);
+    CglVector3d x_axis = {{1.0, 0.0, 0.0}};
+    CglVector3d y_axis = cgl_matrix3x3d_mul_vector(rotation, x_axis);
+    CHECK_CLOSE(y_axis.data[0], 0.0, 1e-15);
+    CHECK_CLOSE(y_axis.data[1], 1.0, 1e-15);
+}
+
+static void test_look_at_rh(void) {
+    CglPoint3d eye = {{0.0, 0.0, 5.0}};
+    CglPoint3d target = {{0.0, 0.0, 0.0}};
+    CglVector3d up = {{0.0, 1.0, 0.0}};
+
+    CglMatrix4x4d view = cgl_matrix4x4d_look_at_rh(eye, target, up);
+    CglVector4d eye_homogeneous = {{0.0, 0.0, 5.0, 1.0}};
+    CglVector4d origin = cgl_matrix4x4d_mul_vector(view, eye_homogeneous);
+    CHECK_CLOSE(origin.data[0], 0.0, 1e-15);
+    CHECK_CLOSE(origin.data[1], 0.0, 1e-15);
+    CHECK_CLOSE(origin.data[2], 0.0, 1e-15);
+
+    CglIsometry3d isometry = cgl_isometry3d_look_at_rh(eye, target, up);
+    CglMatrix4x4d isometry_matrix = cgl_isometry3d_to_affine_matrix(isometry);
+    for (int c = 0; c < 4; c++) {
+        for (int r = 0; r < 4; r++) {
+            CHECK_CLOSE(isometry_matrix.data[c][r], view.data[c][r], 1e-15);
+        }
+    }
+}
+
+static void test_projections(void) {
+    CglPerspective3d perspective = cgl_perspective3d_new(3.0, 3.0, 2.0, 2.0, 1.0, 100.0);
+    CglMatrix4x4d perspective_matrix = cgl_matrix4x4d_perspective(3.0, 3.0, 2.0, 2.0, 1.0, 100.0);
+    CHECK(memcmp(&perspective.matrix, &perspective_matrix, sizeof(CglMatrix4x4d)) == 0);
+
+    CglPoint3d point = {{1.0, 1.0, -10.0}};
+    CglPoint3d projected = cgl_perspective3d_project_point(perspective, point);
+    CglPoint3d unprojected = cgl_perspective3d_unproject_point(perspective, projected);
+    for (int i = 0; i < 3; i++) {
+        CHECK_CLOSE(unprojected.data[i], point.data[i], 1e-12);
+    }
+
+    CglOrthographic3f orthographic = cgl_orthographic3f_new(4.0f, 4.0f, 2.0f, 2.0f, 1.0f, 100.0f);
+    CglMatrix4x4f orthographic_matrix = cgl_matrix4x4f_orthographic(4.0f, 4.0f, 2.0f, 2.0f, 1.0f, 100.0f);
+    CHECK(memcmp(&orthographic.matrix, &orthographic_matrix, sizeof(CglMatrix4x4f)) == 0);
+
+    CglPoint3f corner = {{4.0f, 2.0f, -1.0f}};
+    CglPoint3f ndc = cgl_orthographic3f_project_point(orthographic, corner);
+    CHECK_CLOSE(ndc.data[0], 1.0, 1e-6);
+    CHECK_CLOSE(ndc.data[1], 1.0, 1e-6);
+    CHECK_CLOSE(ndc.data[2], -1.0, 1e-6);
+}
+
+static void test_invalid_rotations(void) {
+    CglVector3d zero = {{0.0, 0.0, 0.0}};
+    CglQuaterniond quaternion;
+    CglRotation3d rotation;
+    CHECK(!cgl_quaterniond_from_axis_angle(zero, 1.0, &quaternion));
+    CHECK(!cgl_rotation3d_from_axis_angle(zero, 1.0, &rotation));
+    CHECK(!cgl_rotation3d_from_quaternion((CglQuaterniond){0.0, 0.0, 0.0, 0.0}, &rotation));
+
+    CHECK(cgl_rotation3d_from_quaternion((CglQuaterniond){2.0, 0.0, 0.0, 0.0}, &rotation));
+    for (int c = 0; c < 3; c++) {
+        for (int r = 0; r < 3; r++) {
+            CHECK(rotation.matrix.data[c][r] == (c == r ? 1.0 : 0.0));
+        }
+    }
+
+    CglRotation3d scaled = {{{{2.0, 0.0, 0.0}, {0.0, 2.0, 0.0}, {0.0, 0.0, 2.0}}}};
+    CHECK(!cgl_rotation3d_from_matrix(scaled.matrix, &rotation));
+    CHECK(!cgl_rotation3d_try_inverse(scaled, &rotation));
+    CHECK(cgl_rotation3d_from_matrix(cgl_quaterniond_to_matrix3x3(cgl_quaterniond_identity()), &rotation));
+    CHECK(cgl_rotation3d_try_inverse(rotation, NULL));
+
+    CglIsometry3d isometry = {scaled, cgl_translation3d_from_vector(zero)};
+    CglIsometry3d inverse;
+    CHECK(!cgl_isometry3d_try_inverse(isometry, &inverse));
+}
+
+static void test_transforms(void) {
+    CglVector3d axis = {{0.0, 0.0, 1.0}};
+    CglRotation3d rotation;
+    CHECK(cgl_rotation3d_from_axis_angle(axis, M_PI / 2.0, &rotation));
+    CglTranslation3d translation = cgl_translation3d_from_vector((CglVector3d){{1.0, 2.0, 3.0}});
+    CglPoint3d point = {{1.0, 0.0, 0.0}};
+
+    CglIsometry3d isometry = cgl_isometry3d_from_parts(translation, rotation);
+    CglPoint3d moved = cgl_isometry3d_apply_point(isometry, point);
+    CHECK_CLOSE(moved.data[0], 1.0, 1e-15);
+    CHECK_CLOSE(moved.data[1], 3.0, 1e-15);
+    CHECK_CLOSE(moved.data[2], 3.0, 1e-15);
+
+    CglIsometry3d inverse;
+    CHECK(cgl_isometry3d_try_inverse(isometry, &inverse));
+    CglPoint3d restored = cgl_isometry3d_apply_point(inverse, moved);
+    for (int i = 0; i < 3; i++) {
+        CHECK_CLOSE(restored.data[i], point.data[i], 1e-15);
+    }
+
+    CglSimilarity3d similarity = cgl_similarity3d_from_parts(translation, rotation, 2.0);
+    CglVector3d stretched = cgl_similarity3d_apply_vector(similarity, (CglVector3d){{1.0, 0.0, 0.0}});
+    CHECK_CLOSE(stretched.data[0], 0.0, 1e-15);
+    CHECK_CLOSE(stretched.data[1], 2.0, 1e-15);
+    CglSimilarity3d similarity_inverse;
+    CHECK(cgl_similarity3d_try_inverse(similarity, &similarity_inverse));
+    CHECK(cgl_similarity3d_mul(similarity, similarity_inverse).scale == 1.0);
+
+    CglScale3f scale = cgl_scale3f_from_nonuniform_scale((CglVector3f){{2.0f, 4.0f, 8.0f}});
+    CglVector3f unscaled = cgl_scale3f_apply_vector(cgl_scale3f_inverse(scale), (CglVector3f){{2.0f, 4.0f, 8.0f}});
+    CHECK(unscaled.data[0] == 1.0f && unscaled.data[1] == 1.0f && unscaled.data[2] == 1.0f);
+}
+
+int main(void) {
+    test_vector3d_arithmetic();
+    test_vector4f_arithmetic();
+    test_matrix_column_major_layout();
+    test_matrix4x4_inverse();
+    test_quaternion_slerp();
+    test_look_at_rh();
+    test_projections();
+    test_invalid_rotations();
+    test_transforms();
+
+    if (failures != 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+
+    printf("all checks passed\n");
+
+    return 0;
+}
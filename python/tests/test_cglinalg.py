@@ -0,0 +1,186 @@
+import math
+import unittest
+
+import cglinalg
+
+try:
+    import numpy
+except ImportError:
+    numpy = None
+
+
+class TestVector3(unittest.TestCase):
+    def test_operators(self):
+        a = cglinalg.Vector3(1.0, 2.0, 3.0)
+        b = cglinalg.Vector3(4.0, 5.0, 6.0)
+
+        self.assertEqual(a + b, cglinalg.Vector3(5.0, 7.0, 9.0))
+        self.assertEqual(b - a, cglinalg.Vector3(3.0, 3.0, 3.0))
+        self.assertEqual(-a, cglinalg.Vector3(-1.0, -2.0, -3.0))
+        self.assertEqual(2.0 * a, a * 2.0)
+        self.assertEqual(a / 2.0, cglinalg.Vector3(0.5, 1.0, 1.5))
+        self.assertEqual(a.dot(b), 32.0)
+        self.assertEqual(a.cross(b), cglinalg.Vector3(-3.0, 6.0, -3.0))
+
+    def test_sequence_protocol(self):
+        vector = cglinalg.Vector3(1.0, 2.0, 3.0)
+
+        self.assertEqual(len(vector), 3)
+        self.assertEqual(list(vector), [1.0, 2.0, 3.0])
+        self.assertEqual(vector[-1], 3.0)
+        with self.assertRaises(IndexError):
+            vector[3]
+
+    def test_unsupported_operand(self):
+        with self.assertRaises(TypeError):
+            cglinalg.Vector3.zero() + 1.0
+
+    def test_repr_round_trip(self):
+        vector = cglinalg.Vector3(0.1, -2.5, 1e-300)
+
+        self.assertEqual(eval(repr(vector), {"Vector3": cglinalg.Vector3}), vector)
+
+
+class TestMatrix4x4(unittest.TestCase):
+    def test_indexing_is_row_column(self):
+        matrix = cglinalg.Matrix4x4.from_affine_translation(cglinalg.Vector3(1.0, 2.0, 3.0))
+
+        self.assertEqual(matrix[0, 3], 1.0)
+        self.assertEqual(matrix[1, 3], 2.0)
+        self.assertEqual(matrix[2, 3], 3.0)
+        self.assertEqual(matrix[3, 0], 0.0)
+
+    def test_inverse(self):
+        eye = cglinalg.Vector3(1.0, 2.0, 3.0)
+        target = cglinalg.Vector3(-4.0, 5.0, 0.5)
+        view = cglinalg.Matrix4x4.look_at_rh(eye, target, cglinalg.Vector3.unit_y())
+        product = view @ view.try_inverse()
+        identity = cglinalg.Matrix4x4.identity()
+
+        for row in range(4):
+            for column in range(4):
+                self.assertAlmostEqual(product[row, column], identity[row, column], places=12)
+
+        self.assertIsNone(cglinalg.Matrix4x4.zero().try_inverse())
+
+    def test_products(self):
+        matrix = cglinalg.Matrix4x4.from_perspective(3.0, 3.0, 2.0, 2.0, 1.0, 100.0)
+
+        self.assertEqual(matrix * matrix, matrix @ matrix)
+        self.assertEqual(2.0 * matrix, matrix * 2.0)
+
+
+class TestQuaternion(unittest.TestCase):
+    def test_slerp(self):
+        axis = cglinalg.Vector3.unit_z()
+        q0 = cglinalg.Quaternion.identity()
+        q1 = cglinalg.Quaternion.from_axis_angle(axis, math.pi / 2.0)
+        result = q0.slerp(q1, 0.5)
+        expected = cglinalg.Quaternion.from_axis_angle(axis, math.pi / 4.0)
+
+        for component in ("s", "x", "y", "z"):
+            self.assertAlmostEqual(getattr(result, component), getattr(expected, component), places=15)
+
+    def test_rotate_vector(self):
+        rotation = cglinalg.Quaternion.from_axis_angle(cglinalg.Vector3.unit_z(), math.pi / 2.0)
+        result = rotation.rotate_vector(cglinalg.Vector3.unit_x())
+
+        self.assertAlmostEqual(result.x, 0.0, places=15)
+        self.assertAlmostEqual(result.y, 1.0, places=15)
+
+    def test_rotate_vector_normalizes_quaternion(self):
+        rotation = 3.0 * cglinalg.Quaternion.from_axis_angle(cglinalg.Vector3.unit_z(), math.pi / 2.0)
+        result = rotation.rotate_vector(cglinalg.Vector3.unit_x())
+
+        self.assertAlmostEqual(result.x, 0.0, places=15)
+        self.assertAlmostEqual(result.y, 1.0, places=15)
+
+    def test_zero_quaternion_raises(self):
+        with self.assertRaises(ValueError):
+            cglinalg.Quaternion(0.0, 0.0, 0.0, 0.0).rotate_vector(cglinalg.Vector3.unit_x())
+
+    def test_zero_axis_raises(self):
+        with self.assertRaises(ValueError):
+            cglinalg.Quaternion.from_axis_angle(cglinalg.Vector3.zero(), 1.0)
+
+
+class TestIsometry3(unittest.TestCase):
+    def test_inverse(self):
+        rotation = cglinalg.Quaternion.from_axis_angle(cglinalg.Vector3(0.2, -0.7, 0.4), 1.1)
+        isometry = cglinalg.Isometry3(cglinalg.Vector3(1.0, -2.0, 0.5), rotation)
+        point = cglinalg.Vector3(3.0, 1.0, -4.0)
+        result = isometry.inverse().apply_point(isometry.apply_point(point))
+
+        for i in range(3):
+            self.assertAlmostEqual(result[i], point[i], places=12)
+
+    def test_normalizes_rotation_quaternion(self):
+        rotation = 0.25 * cglinalg.Quaternion.from_axis_angle(cglinalg.Vector3(0.2, -0.7, 0.4), 1.1)
+        isometry = cglinalg.Isometry3(cglinalg.Vector3(1.0, -2.0, 0.5), rotation)
+        point = cglinalg.Vector3(3.0, 1.0, -4.0)
+        result = isometry.inverse().apply_point(isometry.apply_point(point))
+
+        for i in range(3):
+            self.assertAlmostEqual(result[i], point[i], places=12)
+
+    def test_zero_rotation_quaternion_raises(self):
+        with self.assertRaises(ValueError):
+            cglinalg.Isometry3(cglinalg.Vector3.zero(), cglinalg.Quaternion(0.0, 0.0, 0.0, 0.0))
+
+    def test_look_at_rh(self):
+        eye = cglinalg.Vector3(0.0, 0.0, 5.0)
+        view = cglinalg.Isometry3.look_at_rh(eye, cglinalg.Vector3.zero(), cglinalg.Vector3.unit_y())
+
+        self.assertEqual(view.apply_point(eye), cglinalg.Vector3.zero())
+
+
+class TestPerspective3(unittest.TestCase):
+    def test_project_unproject(self):
+        perspective = cglinalg.Perspective3(3.0, 3.0, 2.0, 2.0, 1.0, 100.0)
+        point = cglinalg.Vector3(1.0, 1.0, -10.0)
+        result = perspective.unproject_point(perspective.project_point(point))
+
+        for i in range(3):
+            self.assertAlmostEqual(result[i], point[i], places=12)
+
+        self.assertEqual(perspective.matrix, cglinalg.Matrix4x4.from_perspective(3.0, 3.0, 2.0, 2.0, 1.0, 100.0))
+
+
+@unittest.skipIf(numpy is None, "numpy is not installed")
+class TestNumpy(unittest.TestCase):
+    def test_vector3(self):
+        vector = cglinalg.Vector3(1.0, 2.0, 3.0)
+
+        numpy.testing.assert_array_equal(vector.to_numpy(), [1.0, 2.0, 3.0])
+        numpy.testing.assert_array_equal(numpy.asarray(vector), [1.0, 2.0, 3.0])
+        self.assertEqual(cglinalg.Vector3.from_numpy(numpy.array([1.0, 2.0, 3.0])), vector)
+        with self.assertRaises(ValueError):
+            cglinalg.Vector3.from_numpy(numpy.zeros(4))
+
+    def test_matrix4x4_row_column_layout(self):
+        matrix = cglinalg.Matrix4x4.from_affine_translation(cglinalg.Vector3(1.0, 2.0, 3.0))
+        array = matrix.to_numpy()
+
+        self.assertEqual(array.shape, (4, 4))
+        numpy.testing.assert_array_equal(array[:3, 3], [1.0, 2.0, 3.0])
+        numpy.testing.assert_array_equal(array @ [0.0, 0.0, 0.0, 1.0], [1.0, 2.0, 3.0, 1.0])
+        self.assertEqual(cglinalg.Matrix4x4.from_numpy(array), matrix)
+        self.assertEqual(cglinalg.Matrix4x4.from_numpy(numpy.asfortranarray(array)), matrix)
+
+    def test_quaternion(self):
+        quaternion = cglinalg.Quaternion(1.0, 2.0, 3.0, 4.0)
+
+        numpy.testing.assert_array_equal(quaternion.to_numpy(), [1.0, 2.0, 3.0, 4.0])
+        self.assertEqual(cglinalg.Quaternion.from_numpy(quaternion.to_numpy()), quaternion)
+
+    def test_transforms(self):
+        isometry = cglinalg.Isometry3(cglinalg.Vector3(1.0, 2.0, 3.0), cglinalg.Quaternion.identity())
+        perspective = cglinalg.Perspective3.from_vfov(1.2, 1.5, 0.1, 50.0)
+
+        numpy.testing.assert_array_equal(numpy.asarray(isometry), isometry.to_affine_matrix().to_numpy())
+        numpy.testing.assert_array_equal(numpy.asarray(perspective), perspective.matrix.to_numpy())
+        self.assertEqual(numpy.asarray(perspective, dtype=numpy.float32).dtype, numpy.float32)
+
+
+if __name__ == "__main__":
+    unittest.main()
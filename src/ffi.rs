@@ -0,0 +1,946 @@
+use cglinalg_core::{
+    Matrix,
+    Matrix4x4,
+    Normed,
+    Point3,
+    Quaternion,
+    Unit,
+    Vector,
+    Vector3,
+};
+use cglinalg_numeric::SimdScalarFloat;
+use cglinalg_transform::{
+    Isometry3,
+    Orthographic3,
+    Perspective3,
+    Rotation3,
+    Scale3,
+    Similarity3,
+    Translation3,
+};
+use cglinalg_trigonometry::Radians;
+
+use core::mem;
+
+
+/// A library type whose memory layout is fixed by a `#[repr(C)]` or
+/// `#[repr(transparent)]` attribute.
+///
+/// The C types are converted to and from library types by transmuting them, which
+/// is only sound when the layout of the library type is specified. Requiring this
+/// trait in [`impl_ffi_layout`] restricts those conversions to the types listed
+/// below.
+///
+/// # Safety
+///
+/// The implementing type must be `#[repr(C)]` or `#[repr(transparent)]`, and every
+/// field of it must be a scalar, an array of scalars, or a type that implements
+/// this trait.
+unsafe trait FixedLayout {}
+
+// SAFETY: `Vector` is a `#[repr(C)]` wrapper around an array of scalars.
+unsafe impl<S, const N: usize> FixedLayout for Vector<S, N> {}
+
+// SAFETY: `Matrix` is a `#[repr(C)]` wrapper around an array of arrays of scalars.
+unsafe impl<S, const R: usize, const C: usize> FixedLayout for Matrix<S, R, C> {}
+
+// SAFETY: `Point` is a `#[repr(C)]` wrapper around a `Vector`.
+unsafe impl<S> FixedLayout for Point3<S> {}
+
+// SAFETY: `Quaternion` is a `#[repr(C)]` wrapper around a `Vector`.
+unsafe impl<S> FixedLayout for Quaternion<S> {}
+
+// SAFETY: `Translation` is a `#[repr(C)]` wrapper around a `Vector`.
+unsafe impl<S> FixedLayout for Translation3<S> {}
+
+// SAFETY: `Scale` is a `#[repr(C)]` wrapper around a `Vector`.
+unsafe impl<S> FixedLayout for Scale3<S> {}
+
+// SAFETY: `Rotation` is a `#[repr(C)]` wrapper around a `Matrix`.
+unsafe impl<S> FixedLayout for Rotation3<S> {}
+
+// SAFETY: `Isometry` is `#[repr(C)]` with a `Rotation` field followed by a
+// `Translation` field.
+unsafe impl<S> FixedLayout for Isometry3<S> {}
+
+// SAFETY: `Similarity` is `#[repr(C)]` with an `Isometry` field followed by a
+// scalar field.
+unsafe impl<S> FixedLayout for Similarity3<S> {}
+
+// SAFETY: `Perspective3` is a `#[repr(transparent)]` wrapper around a `Matrix`.
+unsafe impl<S> FixedLayout for Perspective3<S> {}
+
+// SAFETY: `Orthographic3` is a `#[repr(transparent)]` wrapper around a `Matrix`.
+unsafe impl<S> FixedLayout for Orthographic3<S> {}
+
+/// Assert at compile time that a library type has a fixed memory layout.
+const fn assert_fixed_layout<T: FixedLayout>() {}
+
+/// Write a value to a C out-pointer if the value exists, and report whether it
+/// exists. Nothing is written when the value is `None` or `result` is null.
+///
+/// # Safety
+///
+/// `result` must be either null or valid for writing one value.
+unsafe fn write_result<T, U>(value: Option<T>, result: *mut U) -> bool
+where
+    U: From<T>,
+{
+    match value {
+        Some(value) => {
+            if !result.is_null() {
+                // SAFETY: The caller guarantees that a non-null `result` is valid
+                // for writes.
+                unsafe { result.write(value.into()) };
+            }
+
+            true
+        }
+        None => false,
+    }
+}
+
+/// Normalize a rotation axis, provided that it is nonzero and finite.
+fn unit_axis<S>(axis: Vector3<S>) -> Option<Unit<Vector3<S>>>
+where
+    S: SimdScalarFloat,
+{
+    if axis.is_finite() {
+        Unit::try_from_value(axis, S::zero())
+    } else {
+        None
+    }
+}
+
+/// Normalize a rotation quaternion, provided that it is nonzero and finite.
+fn unit_quaternion<S>(quaternion: Quaternion<S>) -> Option<Quaternion<S>>
+where
+    S: SimdScalarFloat,
+{
+    if quaternion.is_finite() {
+        Unit::try_from_value(quaternion, S::zero()).map(Unit::into_inner)
+    } else {
+        None
+    }
+}
+
+/// Check the orthonormality invariant of a rotation passed in from C, whose
+/// matrix could have been written field by field.
+fn is_valid_rotation<S>(rotation: &Rotation3<S>) -> bool
+where
+    S: SimdScalarFloat,
+{
+    Rotation3::try_from_matrix(&rotation.to_matrix()).is_some()
+}
+
+macro_rules! impl_ffi_layout {
+    ($($FfiType:ty => $RustType:ty),* $(,)*) => {$(
+        const _: () = assert_fixed_layout::<$RustType>();
+        const _: () = assert!(mem::size_of::<$FfiType>() == mem::size_of::<$RustType>());
+        const _: () = assert!(mem::align_of::<$FfiType>() == mem::align_of::<$RustType>());
+
+        impl From<$RustType> for $FfiType {
+            #[inline]
+            fn from(value: $RustType) -> Self {
+                // SAFETY: The C type is `#[repr(C)]`, and the library type implements
+                // `FixedLayout`, so its layout is fixed by `#[repr(C)]` or
+                // `#[repr(transparent)]`. Both have the same fields of the same scalar
+                // type in the same order, so their layouts are identical.
+                unsafe { mem::transmute::<$RustType, $FfiType>(value) }
+            }
+        }
+
+        impl From<$FfiType> for $RustType {
+            #[inline]
+            fn from(value: $FfiType) -> Self {
+                // SAFETY: The C type is `#[repr(C)]`, and the library type implements
+                // `FixedLayout`, so its layout is fixed by `#[repr(C)]` or
+                // `#[repr(transparent)]`. Both have the same fields of the same scalar
+                // type in the same order, so their layouts are identical.
+                unsafe { mem::transmute::<$FfiType, $RustType>(value) }
+            }
+        }
+    )*}
+}
+
+macro_rules! impl_ffi_vector {
+    ($ScalarType:ty, $suffix:ident, $n:literal) => {
+        paste::paste! {
+            #[doc = concat!(" A ", stringify!($n), "-dimensional vector of `", stringify!($ScalarType), "` components.")]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglVector $n $suffix>] {
+                pub data: [$ScalarType; $n],
+            }
+
+            impl_ffi_layout!([<CglVector $n $suffix>] => Vector<$ScalarType, $n>);
+
+            /// Add two vectors componentwise.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_vector $n $suffix _add>](a: [<CglVector $n $suffix>], b: [<CglVector $n $suffix>]) -> [<CglVector $n $suffix>] {
+                (Vector::<$ScalarType, $n>::from(a) + Vector::<$ScalarType, $n>::from(b)).into()
+            }
+
+            /// Subtract two vectors componentwise.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_vector $n $suffix _sub>](a: [<CglVector $n $suffix>], b: [<CglVector $n $suffix>]) -> [<CglVector $n $suffix>] {
+                (Vector::<$ScalarType, $n>::from(a) - Vector::<$ScalarType, $n>::from(b)).into()
+            }
+
+            /// Multiply a vector by a scalar.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_vector $n $suffix _mul_scalar>](vector: [<CglVector $n $suffix>], scalar: $ScalarType) -> [<CglVector $n $suffix>] {
+                (Vector::<$ScalarType, $n>::from(vector) * scalar).into()
+            }
+
+            /// Compute the dot product of two vectors.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_vector $n $suffix _dot>](a: [<CglVector $n $suffix>], b: [<CglVector $n $suffix>]) -> $ScalarType {
+                Vector::<$ScalarType, $n>::from(a).dot(&Vector::<$ScalarType, $n>::from(b))
+            }
+
+            /// Compute the Euclidean norm of a vector.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_vector $n $suffix _norm>](vector: [<CglVector $n $suffix>]) -> $ScalarType {
+                Vector::<$ScalarType, $n>::from(vector).norm()
+            }
+
+            /// Normalize a vector to unit length.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_vector $n $suffix _normalize>](vector: [<CglVector $n $suffix>]) -> [<CglVector $n $suffix>] {
+                Vector::<$ScalarType, $n>::from(vector).normalize().into()
+            }
+        }
+    };
+}
+
+macro_rules! impl_ffi_matrix {
+    ($ScalarType:ty, $suffix:ident, $n:literal) => {
+        paste::paste! {
+            #[doc = concat!(
+                " A ", stringify!($n), "x", stringify!($n), " matrix of `", stringify!($ScalarType),
+                "` elements stored in column-major order."
+            )]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglMatrix $n x $n $suffix>] {
+                pub data: [[$ScalarType; $n]; $n],
+            }
+
+            impl_ffi_layout!([<CglMatrix $n x $n $suffix>] => Matrix<$ScalarType, $n, $n>);
+
+            /// Construct the identity matrix.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix $n x $n $suffix _identity>]() -> [<CglMatrix $n x $n $suffix>] {
+                Matrix::<$ScalarType, $n, $n>::identity().into()
+            }
+
+            /// Add two matrices elementwise.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix $n x $n $suffix _add>](
+                a: [<CglMatrix $n x $n $suffix>],
+                b: [<CglMatrix $n x $n $suffix>],
+            ) -> [<CglMatrix $n x $n $suffix>] {
+                (Matrix::<$ScalarType, $n, $n>::from(a) + Matrix::<$ScalarType, $n, $n>::from(b)).into()
+            }
+
+            /// Subtract two matrices elementwise.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix $n x $n $suffix _sub>](
+                a: [<CglMatrix $n x $n $suffix>],
+                b: [<CglMatrix $n x $n $suffix>],
+            ) -> [<CglMatrix $n x $n $suffix>] {
+                (Matrix::<$ScalarType, $n, $n>::from(a) - Matrix::<$ScalarType, $n, $n>::from(b)).into()
+            }
+
+            /// Multiply two matrices.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix $n x $n $suffix _mul>](
+                a: [<CglMatrix $n x $n $suffix>],
+                b: [<CglMatrix $n x $n $suffix>],
+            ) -> [<CglMatrix $n x $n $suffix>] {
+                (Matrix::<$ScalarType, $n, $n>::from(a) * Matrix::<$ScalarType, $n, $n>::from(b)).into()
+            }
+
+            /// Multiply a matrix by a column vector.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix $n x $n $suffix _mul_vector>](
+                matrix: [<CglMatrix $n x $n $suffix>],
+                vector: [<CglVector $n $suffix>],
+            ) -> [<CglVector $n $suffix>] {
+                (Matrix::<$ScalarType, $n, $n>::from(matrix) * Vector::<$ScalarType, $n>::from(vector)).into()
+            }
+
+            /// Multiply a matrix by a scalar.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix $n x $n $suffix _mul_scalar>](
+                matrix: [<CglMatrix $n x $n $suffix>],
+                scalar: $ScalarType,
+            ) -> [<CglMatrix $n x $n $suffix>] {
+                (Matrix::<$ScalarType, $n, $n>::from(matrix) * scalar).into()
+            }
+
+            /// Compute the transpose of a matrix.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix $n x $n $suffix _transpose>](matrix: [<CglMatrix $n x $n $suffix>]) -> [<CglMatrix $n x $n $suffix>] {
+                Matrix::<$ScalarType, $n, $n>::from(matrix).transpose().into()
+            }
+
+            /// Compute the determinant of a matrix.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix $n x $n $suffix _determinant>](matrix: [<CglMatrix $n x $n $suffix>]) -> $ScalarType {
+                Matrix::<$ScalarType, $n, $n>::from(matrix).determinant()
+            }
+
+            /// Compute the inverse of a matrix, if it exists.
+            ///
+            /// Returns `true` and writes the inverse to `result` when the matrix is
+            /// invertible, and returns `false` without writing anything otherwise. When
+            /// `result` is null, only the invertibility of the matrix is reported.
+            ///
+            /// # Safety
+            ///
+            /// `result` must be either null or valid for writing one matrix.
+            #[no_mangle]
+            pub unsafe extern "C" fn [<cgl_matrix $n x $n $suffix _try_inverse>](
+                matrix: [<CglMatrix $n x $n $suffix>],
+                result: *mut [<CglMatrix $n x $n $suffix>],
+            ) -> bool {
+                // SAFETY: The caller guarantees that `result` is either null or valid
+                // for writes.
+                unsafe { write_result(Matrix::<$ScalarType, $n, $n>::from(matrix).try_inverse(), result) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_ffi {
+    ($ScalarType:ty, $suffix:ident) => {
+        impl_ffi_vector!($ScalarType, $suffix, 2);
+        impl_ffi_vector!($ScalarType, $suffix, 3);
+        impl_ffi_vector!($ScalarType, $suffix, 4);
+
+        impl_ffi_matrix!($ScalarType, $suffix, 2);
+        impl_ffi_matrix!($ScalarType, $suffix, 3);
+        impl_ffi_matrix!($ScalarType, $suffix, 4);
+
+        paste::paste! {
+            #[doc = concat!(" A point in three dimensions with `", stringify!($ScalarType), "` coordinates.")]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglPoint3 $suffix>] {
+                pub data: [$ScalarType; 3],
+            }
+
+            #[doc = concat!(
+                " A quaternion of `", stringify!($ScalarType), "` components, with scalar part `s` ",
+                "and vector part `(x, y, z)`."
+            )]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglQuaternion $suffix>] {
+                pub s: $ScalarType,
+                pub x: $ScalarType,
+                pub y: $ScalarType,
+                pub z: $ScalarType,
+            }
+
+            #[doc = concat!(" A translation in three dimensions with `", stringify!($ScalarType), "` components.")]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglTranslation3 $suffix>] {
+                pub vector: [<CglVector3 $suffix>],
+            }
+
+            #[doc = concat!(" A nonuniform scale in three dimensions with `", stringify!($ScalarType), "` scaling factors.")]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglScale3 $suffix>] {
+                pub vector: [<CglVector3 $suffix>],
+            }
+
+            #[doc = concat!(
+                " A rotation in three dimensions stored as a `", stringify!($ScalarType), "` rotation matrix.\n\n",
+                "The matrix must be orthonormal with determinant `1`. Functions that take a rotation ",
+                "do not check this, except for the `_try_inverse` functions. Construct rotations with ",
+                "the `cgl_rotation3` constructors, which validate their input, instead of writing the ",
+                "matrix directly."
+            )]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglRotation3 $suffix>] {
+                pub matrix: [<CglMatrix3x3 $suffix>],
+            }
+
+            #[doc = concat!(
+                " An isometry in three dimensions with `", stringify!($ScalarType), "` components. ",
+                "The rotation is applied first, followed by the translation.\n\n",
+                "The rotation must satisfy the orthonormality invariant of rotations."
+            )]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglIsometry3 $suffix>] {
+                pub rotation: [<CglRotation3 $suffix>],
+                pub translation: [<CglTranslation3 $suffix>],
+            }
+
+            #[doc = concat!(
+                " A similarity in three dimensions with `", stringify!($ScalarType), "` components. ",
+                "The uniform scale is applied first, followed by the isometry.\n\n",
+                "The rotation must satisfy the orthonormality invariant of rotations."
+            )]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglSimilarity3 $suffix>] {
+                pub isometry: [<CglIsometry3 $suffix>],
+                pub scale: $ScalarType,
+            }
+
+            #[doc = concat!(" A perspective projection stored as a `", stringify!($ScalarType), "` homogeneous matrix.")]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglPerspective3 $suffix>] {
+                pub matrix: [<CglMatrix4x4 $suffix>],
+            }
+
+            #[doc = concat!(" An orthographic projection stored as a `", stringify!($ScalarType), "` homogeneous matrix.")]
+            #[repr(C)]
+            #[derive(Copy, Clone, Debug, PartialEq)]
+            pub struct [<CglOrthographic3 $suffix>] {
+                pub matrix: [<CglMatrix4x4 $suffix>],
+            }
+
+            // The conversions from rotations, isometries, and similarities do not check
+            // that the rotation matrix is orthonormal, since a C caller can write it field
+            // by field. Functions that rely on the invariant beyond applying the matrix
+            // check it with `is_valid_rotation`.
+            impl_ffi_layout!(
+                [<CglPoint3 $suffix>] => Point3<$ScalarType>,
+                [<CglQuaternion $suffix>] => Quaternion<$ScalarType>,
+                [<CglTranslation3 $suffix>] => Translation3<$ScalarType>,
+                [<CglScale3 $suffix>] => Scale3<$ScalarType>,
+                [<CglRotation3 $suffix>] => Rotation3<$ScalarType>,
+                [<CglIsometry3 $suffix>] => Isometry3<$ScalarType>,
+                [<CglSimilarity3 $suffix>] => Similarity3<$ScalarType>,
+                [<CglPerspective3 $suffix>] => Perspective3<$ScalarType>,
+                [<CglOrthographic3 $suffix>] => Orthographic3<$ScalarType>,
+            );
+
+            /// Compute the cross product of two three-dimensional vectors.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_vector3 $suffix _cross>](a: [<CglVector3 $suffix>], b: [<CglVector3 $suffix>]) -> [<CglVector3 $suffix>] {
+                Vector3::<$ScalarType>::from(a).cross(&Vector3::from(b)).into()
+            }
+
+            /// Construct a right-handed view matrix looking from `eye` towards `target`.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix4x4 $suffix _look_at_rh>](
+                eye: [<CglPoint3 $suffix>],
+                target: [<CglPoint3 $suffix>],
+                up: [<CglVector3 $suffix>],
+            ) -> [<CglMatrix4x4 $suffix>] {
+                Matrix4x4::look_at_rh(&Point3::from(eye), &Point3::from(target), &Vector3::from(up)).into()
+            }
+
+            /// Construct a perspective projection matrix from the frustum bounds.
+            ///
+            /// `left`, `right`, `bottom`, and `top` are the distances from the view
+            /// axis to the sides of the near plane, and `near` and `far` are the
+            /// distances from the eye to the near and far planes.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix4x4 $suffix _perspective>](
+                left: $ScalarType,
+                right: $ScalarType,
+                bottom: $ScalarType,
+                top: $ScalarType,
+                near: $ScalarType,
+                far: $ScalarType,
+            ) -> [<CglMatrix4x4 $suffix>] {
+                Matrix4x4::from_perspective(left, right, bottom, top, near, far).into()
+            }
+
+            /// Construct a perspective projection matrix from a vertical field of view in
+            /// radians, an aspect ratio, and the near and far plane distances.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix4x4 $suffix _perspective_vfov>](
+                vfov: $ScalarType,
+                aspect_ratio: $ScalarType,
+                near: $ScalarType,
+                far: $ScalarType,
+            ) -> [<CglMatrix4x4 $suffix>] {
+                Matrix4x4::from_perspective_vfov(Radians(vfov), aspect_ratio, near, far).into()
+            }
+
+            /// Construct an orthographic projection matrix from the view volume bounds.
+            ///
+            /// As with the perspective matrix, `left`, `right`, `bottom`, and `top` are
+            /// distances from the view axis, and `near` and `far` are distances from the
+            /// eye.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_matrix4x4 $suffix _orthographic>](
+                left: $ScalarType,
+                right: $ScalarType,
+                bottom: $ScalarType,
+                top: $ScalarType,
+                near: $ScalarType,
+                far: $ScalarType,
+            ) -> [<CglMatrix4x4 $suffix>] {
+                Matrix4x4::from_orthographic(left, right, bottom, top, near, far).into()
+            }
+
+            /// Construct the identity quaternion.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_quaternion $suffix _identity>]() -> [<CglQuaternion $suffix>] {
+                Quaternion::<$ScalarType>::identity().into()
+            }
+
+            /// Construct a unit quaternion rotating by `angle` radians about `axis`. The
+            /// axis does not need to be normalized.
+            ///
+            /// Returns `true` and writes the quaternion to `result` when the axis is
+            /// nonzero and finite, and returns `false` without writing anything otherwise.
+            ///
+            /// # Safety
+            ///
+            /// `result` must be either null or valid for writing one quaternion.
+            #[no_mangle]
+            pub unsafe extern "C" fn [<cgl_quaternion $suffix _from_axis_angle>](
+                axis: [<CglVector3 $suffix>],
+                angle: $ScalarType,
+                result: *mut [<CglQuaternion $suffix>],
+            ) -> bool {
+                let quaternion = unit_axis(Vector3::<$ScalarType>::from(axis)).map(|axis| Quaternion::from_axis_angle(&axis, Radians(angle)));
+
+                // SAFETY: The caller guarantees that `result` is either null or valid
+                // for writes.
+                unsafe { write_result(quaternion, result) }
+            }
+
+            /// Multiply two quaternions.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_quaternion $suffix _mul>](
+                a: [<CglQuaternion $suffix>],
+                b: [<CglQuaternion $suffix>],
+            ) -> [<CglQuaternion $suffix>] {
+                (Quaternion::<$ScalarType>::from(a) * Quaternion::<$ScalarType>::from(b)).into()
+            }
+
+            /// Compute the conjugate of a quaternion.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_quaternion $suffix _conjugate>](quaternion: [<CglQuaternion $suffix>]) -> [<CglQuaternion $suffix>] {
+                Quaternion::<$ScalarType>::from(quaternion).conjugate().into()
+            }
+
+            /// Compute the norm of a quaternion.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_quaternion $suffix _norm>](quaternion: [<CglQuaternion $suffix>]) -> $ScalarType {
+                Quaternion::<$ScalarType>::from(quaternion).norm()
+            }
+
+            /// Normalize a quaternion to unit norm.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_quaternion $suffix _normalize>](quaternion: [<CglQuaternion $suffix>]) -> [<CglQuaternion $suffix>] {
+                Quaternion::<$ScalarType>::from(quaternion).normalize().into()
+            }
+
+            /// Spherically interpolate between two unit quaternions, where `amount` runs
+            /// from `0` at `a` to `1` at `b`.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_quaternion $suffix _slerp>](
+                a: [<CglQuaternion $suffix>],
+                b: [<CglQuaternion $suffix>],
+                amount: $ScalarType,
+            ) -> [<CglQuaternion $suffix>] {
+                Quaternion::<$ScalarType>::from(a).slerp(&Quaternion::from(b), amount).into()
+            }
+
+            /// Convert a unit quaternion into a three-by-three rotation matrix.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_quaternion $suffix _to_matrix3x3>](quaternion: [<CglQuaternion $suffix>]) -> [<CglMatrix3x3 $suffix>] {
+                Matrix::<$ScalarType, 3, 3>::from(Quaternion::<$ScalarType>::from(quaternion)).into()
+            }
+
+            /// Construct a translation from a displacement vector.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_translation3 $suffix _from_vector>](vector: [<CglVector3 $suffix>]) -> [<CglTranslation3 $suffix>] {
+                Translation3::from_vector(&Vector3::<$ScalarType>::from(vector)).into()
+            }
+
+            /// Compute the inverse of a translation.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_translation3 $suffix _inverse>](translation: [<CglTranslation3 $suffix>]) -> [<CglTranslation3 $suffix>] {
+                Translation3::<$ScalarType>::from(translation).inverse().into()
+            }
+
+            /// Apply a translation to a point.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_translation3 $suffix _apply_point>](
+                translation: [<CglTranslation3 $suffix>],
+                point: [<CglPoint3 $suffix>],
+            ) -> [<CglPoint3 $suffix>] {
+                Translation3::<$ScalarType>::from(translation).apply_point(&Point3::from(point)).into()
+            }
+
+            /// Convert a translation into a homogeneous affine matrix.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_translation3 $suffix _to_affine_matrix>](translation: [<CglTranslation3 $suffix>]) -> [<CglMatrix4x4 $suffix>] {
+                Translation3::<$ScalarType>::from(translation).to_affine_matrix().into()
+            }
+
+            /// Construct a nonuniform scale from a vector of scaling factors.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_scale3 $suffix _from_nonuniform_scale>](vector: [<CglVector3 $suffix>]) -> [<CglScale3 $suffix>] {
+                Scale3::from_nonuniform_scale(&Vector3::<$ScalarType>::from(vector)).into()
+            }
+
+            /// Compute the inverse of a scale.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_scale3 $suffix _inverse>](scale: [<CglScale3 $suffix>]) -> [<CglScale3 $suffix>] {
+                Scale3::<$ScalarType>::from(scale).inverse().into()
+            }
+
+            /// Apply a scale to a vector.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_scale3 $suffix _apply_vector>](scale: [<CglScale3 $suffix>], vector: [<CglVector3 $suffix>]) -> [<CglVector3 $suffix>] {
+                Scale3::<$ScalarType>::from(scale).apply_vector(&Vector3::from(vector)).into()
+            }
+
+            /// Apply a scale to a point.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_scale3 $suffix _apply_point>](scale: [<CglScale3 $suffix>], point: [<CglPoint3 $suffix>]) -> [<CglPoint3 $suffix>] {
+                Scale3::<$ScalarType>::from(scale).apply_point(&Point3::from(point)).into()
+            }
+
+            /// Convert a scale into a homogeneous affine matrix.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_scale3 $suffix _to_affine_matrix>](scale: [<CglScale3 $suffix>]) -> [<CglMatrix4x4 $suffix>] {
+                Scale3::<$ScalarType>::from(scale).to_affine_matrix().into()
+            }
+
+            /// Construct a rotation by `angle` radians about `axis`. The axis does not need
+            /// to be normalized.
+            ///
+            /// Returns `true` and writes the rotation to `result` when the axis is nonzero
+            /// and finite, and returns `false` without writing anything otherwise.
+            ///
+            /// # Safety
+            ///
+            /// `result` must be either null or valid for writing one rotation.
+            #[no_mangle]
+            pub unsafe extern "C" fn [<cgl_rotation3 $suffix _from_axis_angle>](
+                axis: [<CglVector3 $suffix>],
+                angle: $ScalarType,
+                result: *mut [<CglRotation3 $suffix>],
+            ) -> bool {
+                let rotation = unit_axis(Vector3::<$ScalarType>::from(axis)).map(|axis| Rotation3::from_axis_angle(&axis, Radians(angle)));
+
+                // SAFETY: The caller guarantees that `result` is either null or valid
+                // for writes.
+                unsafe { write_result(rotation, result) }
+            }
+
+            /// Construct a rotation from a quaternion. The quaternion does not need to be
+            /// normalized.
+            ///
+            /// Returns `true` and writes the rotation to `result` when the quaternion is
+            /// nonzero and finite, and returns `false` without writing anything otherwise.
+            ///
+            /// # Safety
+            ///
+            /// `result` must be either null or valid for writing one rotation.
+            #[no_mangle]
+            pub unsafe extern "C" fn [<cgl_rotation3 $suffix _from_quaternion>](
+                quaternion: [<CglQuaternion $suffix>],
+                result: *mut [<CglRotation3 $suffix>],
+            ) -> bool {
+                let rotation = unit_quaternion(Quaternion::<$ScalarType>::from(quaternion)).map(|unit| Rotation3::from_quaternion(&unit));
+
+                // SAFETY: The caller guarantees that `result` is either null or valid
+                // for writes.
+                unsafe { write_result(rotation, result) }
+            }
+
+            /// Construct a rotation from a rotation matrix.
+            ///
+            /// Returns `true` and writes the rotation to `result` when the matrix is
+            /// orthonormal with determinant `1`, and returns `false` without writing
+            /// anything otherwise.
+            ///
+            /// # Safety
+            ///
+            /// `result` must be either null or valid for writing one rotation.
+            #[no_mangle]
+            pub unsafe extern "C" fn [<cgl_rotation3 $suffix _from_matrix>](
+                matrix: [<CglMatrix3x3 $suffix>],
+                result: *mut [<CglRotation3 $suffix>],
+            ) -> bool {
+                let rotation = Rotation3::try_from_matrix(&Matrix::<$ScalarType, 3, 3>::from(matrix));
+
+                // SAFETY: The caller guarantees that `result` is either null or valid
+                // for writes.
+                unsafe { write_result(rotation, result) }
+            }
+
+            /// Construct the rotation of a right-handed view looking from `eye` towards
+            /// `target`.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_rotation3 $suffix _look_at_rh>](
+                eye: [<CglPoint3 $suffix>],
+                target: [<CglPoint3 $suffix>],
+                up: [<CglVector3 $suffix>],
+            ) -> [<CglRotation3 $suffix>] {
+                Rotation3::look_at_rh(&Point3::from(eye), &Point3::from(target), &Vector3::from(up)).into()
+            }
+
+            /// Compute the inverse of a rotation.
+            ///
+            /// The inverse of a rotation is the transpose of its matrix, which is only
+            /// correct for an orthonormal matrix. Returns `true` and writes the inverse to
+            /// `result` when the rotation matrix is orthonormal with determinant `1`, and
+            /// returns `false` without writing anything otherwise.
+            ///
+            /// # Safety
+            ///
+            /// `result` must be either null or valid for writing one rotation.
+            #[no_mangle]
+            pub unsafe extern "C" fn [<cgl_rotation3 $suffix _try_inverse>](
+                rotation: [<CglRotation3 $suffix>],
+                result: *mut [<CglRotation3 $suffix>],
+            ) -> bool {
+                let rotation = Rotation3::<$ScalarType>::from(rotation);
+                let inverse = is_valid_rotation(&rotation).then(|| rotation.inverse());
+
+                // SAFETY: The caller guarantees that `result` is either null or valid
+                // for writes.
+                unsafe { write_result(inverse, result) }
+            }
+
+            /// Compose two rotations, applying `b` first and then `a`.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_rotation3 $suffix _mul>](a: [<CglRotation3 $suffix>], b: [<CglRotation3 $suffix>]) -> [<CglRotation3 $suffix>] {
+                (Rotation3::<$ScalarType>::from(a) * Rotation3::<$ScalarType>::from(b)).into()
+            }
+
+            /// Apply a rotation to a vector.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_rotation3 $suffix _apply_vector>](
+                rotation: [<CglRotation3 $suffix>],
+                vector: [<CglVector3 $suffix>],
+            ) -> [<CglVector3 $suffix>] {
+                Rotation3::<$ScalarType>::from(rotation).apply_vector(&Vector3::from(vector)).into()
+            }
+
+            /// Apply a rotation to a point.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_rotation3 $suffix _apply_point>](rotation: [<CglRotation3 $suffix>], point: [<CglPoint3 $suffix>]) -> [<CglPoint3 $suffix>] {
+                Rotation3::<$ScalarType>::from(rotation).apply_point(&Point3::from(point)).into()
+            }
+
+            /// Convert a rotation into a homogeneous affine matrix.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_rotation3 $suffix _to_affine_matrix>](rotation: [<CglRotation3 $suffix>]) -> [<CglMatrix4x4 $suffix>] {
+                Rotation3::<$ScalarType>::from(rotation).to_affine_matrix().into()
+            }
+
+            /// Construct an isometry from a translation and a rotation.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_isometry3 $suffix _from_parts>](
+                translation: [<CglTranslation3 $suffix>],
+                rotation: [<CglRotation3 $suffix>],
+            ) -> [<CglIsometry3 $suffix>] {
+                Isometry3::<$ScalarType>::from_parts(&Translation3::from(translation), &Rotation3::from(rotation)).into()
+            }
+
+            /// Construct the world-to-view isometry of a right-handed view looking from
+            /// `eye` towards `target`.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_isometry3 $suffix _look_at_rh>](
+                eye: [<CglPoint3 $suffix>],
+                target: [<CglPoint3 $suffix>],
+                up: [<CglVector3 $suffix>],
+            ) -> [<CglIsometry3 $suffix>] {
+                Isometry3::look_at_rh(&Point3::from(eye), &Point3::from(target), &Vector3::from(up)).into()
+            }
+
+            /// Compute the inverse of an isometry.
+            ///
+            /// Returns `true` and writes the inverse to `result` when the rotation matrix
+            /// of the isometry is orthonormal with determinant `1`, and returns `false`
+            /// without writing anything otherwise.
+            ///
+            /// # Safety
+            ///
+            /// `result` must be either null or valid for writing one isometry.
+            #[no_mangle]
+            pub unsafe extern "C" fn [<cgl_isometry3 $suffix _try_inverse>](
+                isometry: [<CglIsometry3 $suffix>],
+                result: *mut [<CglIsometry3 $suffix>],
+            ) -> bool {
+                let isometry = Isometry3::<$ScalarType>::from(isometry);
+                let inverse = is_valid_rotation(isometry.rotation()).then(|| isometry.inverse());
+
+                // SAFETY: The caller guarantees that `result` is either null or valid
+                // for writes.
+                unsafe { write_result(inverse, result) }
+            }
+
+            /// Compose two isometries, applying `b` first and then `a`.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_isometry3 $suffix _mul>](a: [<CglIsometry3 $suffix>], b: [<CglIsometry3 $suffix>]) -> [<CglIsometry3 $suffix>] {
+                (Isometry3::<$ScalarType>::from(a) * Isometry3::<$ScalarType>::from(b)).into()
+            }
+
+            /// Apply an isometry to a vector.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_isometry3 $suffix _apply_vector>](
+                isometry: [<CglIsometry3 $suffix>],
+                vector: [<CglVector3 $suffix>],
+            ) -> [<CglVector3 $suffix>] {
+                Isometry3::<$ScalarType>::from(isometry).apply_vector(&Vector3::from(vector)).into()
+            }
+
+            /// Apply an isometry to a point.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_isometry3 $suffix _apply_point>](isometry: [<CglIsometry3 $suffix>], point: [<CglPoint3 $suffix>]) -> [<CglPoint3 $suffix>] {
+                Isometry3::<$ScalarType>::from(isometry).apply_point(&Point3::from(point)).into()
+            }
+
+            /// Convert an isometry into a homogeneous affine matrix.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_isometry3 $suffix _to_affine_matrix>](isometry: [<CglIsometry3 $suffix>]) -> [<CglMatrix4x4 $suffix>] {
+                Isometry3::<$ScalarType>::from(isometry).to_affine_matrix().into()
+            }
+
+            /// Construct a similarity from a translation, a rotation, and a uniform scale.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_similarity3 $suffix _from_parts>](
+                translation: [<CglTranslation3 $suffix>],
+                rotation: [<CglRotation3 $suffix>],
+                scale: $ScalarType,
+            ) -> [<CglSimilarity3 $suffix>] {
+                Similarity3::<$ScalarType>::from_parts(&Translation3::from(translation), &Rotation3::from(rotation), scale).into()
+            }
+
+            /// Compute the inverse of a similarity.
+            ///
+            /// Returns `true` and writes the inverse to `result` when the rotation matrix
+            /// of the similarity is orthonormal with determinant `1` and the scale is
+            /// nonzero and finite, and returns `false` without writing anything otherwise.
+            ///
+            /// # Safety
+            ///
+            /// `result` must be either null or valid for writing one similarity.
+            #[no_mangle]
+            pub unsafe extern "C" fn [<cgl_similarity3 $suffix _try_inverse>](
+                similarity: [<CglSimilarity3 $suffix>],
+                result: *mut [<CglSimilarity3 $suffix>],
+            ) -> bool {
+                let similarity = Similarity3::<$ScalarType>::from(similarity);
+                let scale = similarity.scale();
+                let is_invertible = is_valid_rotation(similarity.rotation()) && scale.is_finite() && scale != 0.0;
+                let inverse = is_invertible.then(|| similarity.inverse());
+
+                // SAFETY: The caller guarantees that `result` is either null or valid
+                // for writes.
+                unsafe { write_result(inverse, result) }
+            }
+
+            /// Compose two similarities, applying `b` first and then `a`.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_similarity3 $suffix _mul>](a: [<CglSimilarity3 $suffix>], b: [<CglSimilarity3 $suffix>]) -> [<CglSimilarity3 $suffix>] {
+                (Similarity3::<$ScalarType>::from(a) * Similarity3::<$ScalarType>::from(b)).into()
+            }
+
+            /// Apply a similarity to a vector.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_similarity3 $suffix _apply_vector>](
+                similarity: [<CglSimilarity3 $suffix>],
+                vector: [<CglVector3 $suffix>],
+            ) -> [<CglVector3 $suffix>] {
+                Similarity3::<$ScalarType>::from(similarity).apply_vector(&Vector3::from(vector)).into()
+            }
+
+            /// Apply a similarity to a point.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_similarity3 $suffix _apply_point>](
+                similarity: [<CglSimilarity3 $suffix>],
+                point: [<CglPoint3 $suffix>],
+            ) -> [<CglPoint3 $suffix>] {
+                Similarity3::<$ScalarType>::from(similarity).apply_point(&Point3::from(point)).into()
+            }
+
+            /// Convert a similarity into a homogeneous affine matrix.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_similarity3 $suffix _to_affine_matrix>](similarity: [<CglSimilarity3 $suffix>]) -> [<CglMatrix4x4 $suffix>] {
+                Similarity3::<$ScalarType>::from(similarity).to_affine_matrix().into()
+            }
+
+            /// Construct a perspective projection from the frustum bounds, with the same
+            /// conventions as the perspective matrix constructor.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_perspective3 $suffix _new>](
+                left: $ScalarType,
+                right: $ScalarType,
+                bottom: $ScalarType,
+                top: $ScalarType,
+                near: $ScalarType,
+                far: $ScalarType,
+            ) -> [<CglPerspective3 $suffix>] {
+                Perspective3::new(left, right, bottom, top, near, far).into()
+            }
+
+            /// Project a point from eye space into normalized device coordinates.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_perspective3 $suffix _project_point>](
+                perspective: [<CglPerspective3 $suffix>],
+                point: [<CglPoint3 $suffix>],
+            ) -> [<CglPoint3 $suffix>] {
+                Perspective3::<$ScalarType>::from(perspective).project_point(&Point3::from(point)).into()
+            }
+
+            /// Unproject a point from normalized device coordinates into eye space.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_perspective3 $suffix _unproject_point>](
+                perspective: [<CglPerspective3 $suffix>],
+                point: [<CglPoint3 $suffix>],
+            ) -> [<CglPoint3 $suffix>] {
+                Perspective3::<$ScalarType>::from(perspective).unproject_point(&Point3::from(point)).into()
+            }
+
+            /// Construct an orthographic projection from the view volume bounds, with the
+            /// same conventions as the orthographic matrix constructor.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_orthographic3 $suffix _new>](
+                left: $ScalarType,
+                right: $ScalarType,
+                bottom: $ScalarType,
+                top: $ScalarType,
+                near: $ScalarType,
+                far: $ScalarType,
+            ) -> [<CglOrthographic3 $suffix>] {
+                Orthographic3::new(left, right, bottom, top, near, far).into()
+            }
+
+            /// Project a point from eye space into normalized device coordinates.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_orthographic3 $suffix _project_point>](
+                orthographic: [<CglOrthographic3 $suffix>],
+                point: [<CglPoint3 $suffix>],
+            ) -> [<CglPoint3 $suffix>] {
+                Orthographic3::<$ScalarType>::from(orthographic).project_point(&Point3::from(point)).into()
+            }
+
+            /// Unproject a point from normalized device coordinates into eye space.
+            #[no_mangle]
+            pub extern "C" fn [<cgl_orthographic3 $suffix _unproject_point>](
+                orthographic: [<CglOrthographic3 $suffix>],
+                point: [<CglPoint3 $suffix>],
+            ) -> [<CglPoint3 $suffix>] {
+                Orthographic3::<$ScalarType>::from(orthographic).unproject_point(&Point3::from(point)).into()
+            }
+        }
+    };
+}
+
+impl_ffi!(f32, f);
+impl_ffi!(f64, d);
@@ -14,11 +14,43 @@ extern crate approx_cmp;
 extern crate num_traits;
 
 extern crate cglinalg_core;
+extern crate cglinalg_geometry;
 extern crate cglinalg_numeric;
 extern crate cglinalg_transform;
 extern crate cglinalg_trigonometry;
 
 pub use cglinalg_core::*;
+pub use cglinalg_geometry::*;
 pub use cglinalg_numeric::*;
 pub use cglinalg_transform::*;
 pub use cglinalg_trigonometry::*;
+
+/// A C ABI for the `f32` and `f64` vector, matrix, quaternion, and transformation
+/// types, exported from the `cdylib` build.
+///
+/// Every type is a `#[repr(C)]` struct with the same layout as the corresponding
+/// library type, and every function forwards to the library implementation, so
+/// results computed through the C ABI are bit-identical to results computed in
+/// Rust. The C header is `ffi/include/cglinalg.h`.
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+/// Python bindings for the `f64` `Vector3`, `Matrix4x4`, `Quaternion`, `Isometry3`,
+/// and `Perspective3` types, with numpy array conversions.
+///
+/// The `cdylib` build is a Python extension module named `cglinalg`. Every method
+/// forwards to the library implementation, so results computed in Python are
+/// bit-identical to results computed in Rust. The extension is built with
+/// [maturin](https://www.maturin.rs) from the `python` directory.
+#[cfg(feature = "python")]
+pub mod python;
+
+
+/// Property testing strategies for generating values of every type in the library
+/// with proptest.
+#[cfg(feature = "proptest")]
+pub mod strategies {
+    pub use cglinalg_core::strategies::*;
+    pub use cglinalg_transform::strategies::*;
+    pub use cglinalg_trigonometry::strategies::*;
+}
@@ -0,0 +1,856 @@
+use cglinalg_core::{
+    Matrix4x4,
+    Normed,
+    Point3,
+    Quaternion,
+    Unit,
+    Vector3,
+};
+use cglinalg_transform::{
+    Isometry3,
+    Perspective3,
+    Rotation3,
+    Translation3,
+};
+use cglinalg_trigonometry::Radians;
+
+use numpy::{
+    PyArray1,
+    PyArrayMethods,
+    PyReadonlyArray1,
+    PyReadonlyArray2,
+};
+use pyo3::exceptions::{
+    PyIndexError,
+    PyValueError,
+};
+use pyo3::prelude::*;
+
+use std::format;
+use std::string::String;
+
+
+/// Apply the `dtype` and `copy` arguments of the numpy `__array__` protocol to
+/// a freshly allocated array.
+fn array_protocol<'py>(array: Bound<'py, PyAny>, dtype: Option<Bound<'py, PyAny>>, copy: Option<bool>) -> PyResult<Bound<'py, PyAny>> {
+    if copy == Some(false) {
+        return Err(PyValueError::new_err(
+            "cglinalg values cannot be viewed as numpy arrays without a copy",
+        ));
+    }
+
+    match dtype {
+        Some(dtype) => array.call_method1("astype", (dtype,)),
+        None => Ok(array),
+    }
+}
+
+/// Convert a numpy array with the given shape into a fixed-size array.
+fn array1_from_numpy<const N: usize>(array: PyReadonlyArray1<'_, f64>) -> PyResult<[f64; N]> {
+    let view = array.as_array();
+    if view.len() != N {
+        return Err(PyValueError::new_err(format!(
+            "expected an array of shape ({},), got {:?}",
+            N,
+            view.shape()
+        )));
+    }
+
+    Ok(core::array::from_fn(|i| view[i]))
+}
+
+/// Normalize a rotation axis, raising `ValueError` if the axis has zero length
+/// or non-finite components.
+fn unit_axis(axis: &Vector3<f64>) -> PyResult<Unit<Vector3<f64>>> {
+    if !axis.is_finite() {
+        return Err(PyValueError::new_err("rotation axis must have finite components"));
+    }
+
+    Unit::try_from_value(*axis, 0_f64).ok_or_else(|| PyValueError::new_err("rotation axis must be nonzero"))
+}
+
+/// Normalize a quaternion used as a rotation, raising `ValueError` if the quaternion
+/// is zero or has non-finite components.
+///
+/// Rotations assume a unit quaternion and do not normalize their input, so a scaled
+/// quaternion would otherwise produce a scaled, non-orthonormal rotation.
+fn unit_quaternion(quaternion: &Quaternion<f64>) -> PyResult<Quaternion<f64>> {
+    if !quaternion.is_finite() {
+        return Err(PyValueError::new_err("rotation quaternion must have finite components"));
+    }
+
+    Unit::try_from_value(*quaternion, 0_f64)
+        .map(Unit::into_inner)
+        .ok_or_else(|| PyValueError::new_err("rotation quaternion must be nonzero"))
+}
+
+/// A three-dimensional vector of `float` components.
+///
+/// Points are represented by their position vectors in the Python API.
+#[pyclass(name = "Vector3", module = "cglinalg", frozen, eq)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PyVector3 {
+    inner: Vector3<f64>,
+}
+
+impl From<Vector3<f64>> for PyVector3 {
+    #[inline]
+    fn from(inner: Vector3<f64>) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<PyVector3> for Vector3<f64> {
+    #[inline]
+    fn from(vector: PyVector3) -> Self {
+        vector.inner
+    }
+}
+
+impl PyVector3 {
+    #[inline]
+    fn to_point(&self) -> Point3<f64> {
+        Point3::from_vector(&self.inner)
+    }
+
+    #[inline]
+    fn from_point(point: Point3<f64>) -> Self {
+        Self::from(point.to_vector())
+    }
+}
+
+#[pymethods]
+impl PyVector3 {
+    #[new]
+    fn new(x: f64, y: f64, z: f64) -> Self {
+        Self::from(Vector3::new(x, y, z))
+    }
+
+    /// The zero vector.
+    #[staticmethod]
+    fn zero() -> Self {
+        Self::from(Vector3::zero())
+    }
+
+    /// The unit vector along the **x-axis**.
+    #[staticmethod]
+    fn unit_x() -> Self {
+        Self::from(Vector3::unit_x())
+    }
+
+    /// The unit vector along the **y-axis**.
+    #[staticmethod]
+    fn unit_y() -> Self {
+        Self::from(Vector3::unit_y())
+    }
+
+    /// The unit vector along the **z-axis**.
+    #[staticmethod]
+    fn unit_z() -> Self {
+        Self::from(Vector3::unit_z())
+    }
+
+    /// Construct a vector from a numpy array of shape `(3,)`.
+    #[staticmethod]
+    fn from_numpy(array: PyReadonlyArray1<'_, f64>) -> PyResult<Self> {
+        array1_from_numpy::<3>(array).map(|data| Self::from(Vector3::from(data)))
+    }
+
+    /// Convert the vector into a numpy array of shape `(3,)`.
+    fn to_numpy<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice(py, self.inner.as_slice())
+    }
+
+    #[pyo3(signature = (dtype=None, copy=None))]
+    fn __array__<'py>(&self, py: Python<'py>, dtype: Option<Bound<'py, PyAny>>, copy: Option<bool>) -> PyResult<Bound<'py, PyAny>> {
+        array_protocol(self.to_numpy(py).into_any(), dtype, copy)
+    }
+
+    #[getter]
+    fn x(&self) -> f64 {
+        self.inner.x
+    }
+
+    #[getter]
+    fn y(&self) -> f64 {
+        self.inner.y
+    }
+
+    #[getter]
+    fn z(&self) -> f64 {
+        self.inner.z
+    }
+
+    /// Compute the dot product of two vectors.
+    fn dot(&self, other: &Self) -> f64 {
+        self.inner.dot(&other.inner)
+    }
+
+    /// Compute the cross product of two vectors.
+    fn cross(&self, other: &Self) -> Self {
+        Self::from(self.inner.cross(&other.inner))
+    }
+
+    /// Compute the Euclidean norm of the vector.
+    fn norm(&self) -> f64 {
+        self.inner.norm()
+    }
+
+    /// Normalize the vector to unit length.
+    fn normalize(&self) -> Self {
+        Self::from(self.inner.normalize())
+    }
+
+    fn __len__(&self) -> usize {
+        3
+    }
+
+    fn __getitem__(&self, index: isize) -> PyResult<f64> {
+        let index = if index < 0 { index + 3 } else { index };
+        if !(0..3).contains(&index) {
+            return Err(PyIndexError::new_err("Vector3 index out of range"));
+        }
+
+        Ok(self.inner[index as usize])
+    }
+
+    fn __add__(&self, other: &Self) -> Self {
+        Self::from(self.inner + other.inner)
+    }
+
+    fn __sub__(&self, other: &Self) -> Self {
+        Self::from(self.inner - other.inner)
+    }
+
+    fn __neg__(&self) -> Self {
+        Self::from(-self.inner)
+    }
+
+    fn __mul__(&self, other: f64) -> Self {
+        Self::from(self.inner * other)
+    }
+
+    fn __rmul__(&self, other: f64) -> Self {
+        Self::from(self.inner * other)
+    }
+
+    fn __truediv__(&self, other: f64) -> Self {
+        Self::from(self.inner / other)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Vector3({:?}, {:?}, {:?})", self.inner.x, self.inner.y, self.inner.z)
+    }
+}
+
+/// A four-by-four matrix of `float` elements.
+///
+/// The constructor takes its elements in column-major order, like the Rust
+/// constructor. Indexing and numpy conversions use `(row, column)` indices.
+#[pyclass(name = "Matrix4x4", module = "cglinalg", frozen, eq)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PyMatrix4x4 {
+    inner: Matrix4x4<f64>,
+}
+
+impl From<Matrix4x4<f64>> for PyMatrix4x4 {
+    #[inline]
+    fn from(inner: Matrix4x4<f64>) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<PyMatrix4x4> for Matrix4x4<f64> {
+    #[inline]
+    fn from(matrix: PyMatrix4x4) -> Self {
+        matrix.inner
+    }
+}
+
+impl PyMatrix4x4 {
+    fn to_numpy_matrix<'py>(py: Python<'py>, matrix: &Matrix4x4<f64>) -> PyResult<Bound<'py, PyAny>> {
+        let data: [f64; 16] = core::array::from_fn(|i| matrix[i % 4][i / 4]);
+
+        PyArray1::from_slice(py, &data).reshape([4, 4]).map(Bound::into_any)
+    }
+}
+
+/// The right-hand side of a matrix product.
+#[derive(FromPyObject)]
+enum MatrixProductOperand {
+    Matrix(PyMatrix4x4),
+    Scalar(f64),
+}
+
+#[pymethods]
+impl PyMatrix4x4 {
+    #[new]
+    #[rustfmt::skip]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        c0r0: f64, c0r1: f64, c0r2: f64, c0r3: f64,
+        c1r0: f64, c1r1: f64, c1r2: f64, c1r3: f64,
+        c2r0: f64, c2r1: f64, c2r2: f64, c2r3: f64,
+        c3r0: f64, c3r1: f64, c3r2: f64, c3r3: f64,
+    ) -> Self {
+        Self::from(Matrix4x4::new(
+            c0r0, c0r1, c0r2, c0r3,
+            c1r0, c1r1, c1r2, c1r3,
+            c2r0, c2r1, c2r2, c2r3,
+            c3r0, c3r1, c3r2, c3r3,
+        ))
+    }
+
+    /// The identity matrix.
+    #[staticmethod]
+    fn identity() -> Self {
+        Self::from(Matrix4x4::identity())
+    }
+
+    /// The zero matrix.
+    #[staticmethod]
+    fn zero() -> Self {
+        Self::from(Matrix4x4::zero())
+    }
+
+    /// Construct an affine translation matrix.
+    #[staticmethod]
+    fn from_affine_translation(distance: &PyVector3) -> Self {
+        Self::from(Matrix4x4::from_affine_translation(&distance.inner))
+    }
+
+    /// Construct a right-handed view matrix looking from `eye` towards `target`.
+    #[staticmethod]
+    fn look_at_rh(eye: &PyVector3, target: &PyVector3, up: &PyVector3) -> Self {
+        Self::from(Matrix4x4::look_at_rh(&eye.to_point(), &target.to_point(), &up.inner))
+    }
+
+    /// Construct a perspective projection matrix from the frustum bounds.
+    ///
+    /// `left`, `right`, `bottom`, and `top` are the distances from the view axis to
+    /// the sides of the near plane, and `near` and `far` are the distances from the
+    /// eye to the near and far planes.
+    #[staticmethod]
+    fn from_perspective(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Self {
+        Self::from(Matrix4x4::from_perspective(left, right, bottom, top, near, far))
+    }
+
+    /// Construct a perspective projection matrix from a vertical field of view in
+    /// radians, an aspect ratio, and the near and far plane distances.
+    #[staticmethod]
+    fn from_perspective_vfov(vfov: f64, aspect_ratio: f64, near: f64, far: f64) -> Self {
+        Self::from(Matrix4x4::from_perspective_vfov(Radians(vfov), aspect_ratio, near, far))
+    }
+
+    /// Construct an orthographic projection matrix from the view volume bounds,
+    /// with the same conventions as `from_perspective`.
+    #[staticmethod]
+    fn from_orthographic(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Self {
+        Self::from(Matrix4x4::from_orthographic(left, right, bottom, top, near, far))
+    }
+
+    /// Construct a matrix from a numpy array of shape `(4, 4)` indexed by
+    /// `(row, column)`.
+    #[staticmethod]
+    fn from_numpy(array: PyReadonlyArray2<'_, f64>) -> PyResult<Self> {
+        let view = array.as_array();
+        if view.shape() != [4, 4] {
+            return Err(PyValueError::new_err(format!(
+                "expected an array of shape (4, 4), got {:?}",
+                view.shape()
+            )));
+        }
+
+        let data: [[f64; 4]; 4] = core::array::from_fn(|c| core::array::from_fn(|r| view[[r, c]]));
+
+        Ok(Self::from(Matrix4x4::from(data)))
+    }
+
+    /// Convert the matrix into a numpy array of shape `(4, 4)` indexed by
+    /// `(row, column)`.
+    fn to_numpy<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        Self::to_numpy_matrix(py, &self.inner)
+    }
+
+    #[pyo3(signature = (dtype=None, copy=None))]
+    fn __array__<'py>(&self, py: Python<'py>, dtype: Option<Bound<'py, PyAny>>, copy: Option<bool>) -> PyResult<Bound<'py, PyAny>> {
+        array_protocol(self.to_numpy(py)?, dtype, copy)
+    }
+
+    /// Compute the transpose of the matrix.
+    fn transpose(&self) -> Self {
+        Self::from(self.inner.transpose())
+    }
+
+    /// Compute the determinant of the matrix.
+    fn determinant(&self) -> f64 {
+        self.inner.determinant()
+    }
+
+    /// Determine whether the matrix is invertible.
+    fn is_invertible(&self) -> bool {
+        self.inner.is_invertible()
+    }
+
+    /// Compute the inverse of the matrix, returning `None` if the matrix is not
+    /// invertible.
+    fn try_inverse(&self) -> Option<Self> {
+        self.inner.try_inverse().map(Self::from)
+    }
+
+    fn __getitem__(&self, index: (usize, usize)) -> PyResult<f64> {
+        let (row, column) = index;
+        if row >= 4 || column >= 4 {
+            return Err(PyIndexError::new_err("Matrix4x4 index out of range"));
+        }
+
+        Ok(self.inner[column][row])
+    }
+
+    fn __add__(&self, other: &Self) -> Self {
+        Self::from(self.inner + other.inner)
+    }
+
+    fn __sub__(&self, other: &Self) -> Self {
+        Self::from(self.inner - other.inner)
+    }
+
+    fn __neg__(&self) -> Self {
+        Self::from(-self.inner)
+    }
+
+    fn __mul__(&self, other: MatrixProductOperand) -> Self {
+        match other {
+            MatrixProductOperand::Matrix(other) => Self::from(self.inner * other.inner),
+            MatrixProductOperand::Scalar(other) => Self::from(self.inner * other),
+        }
+    }
+
+    fn __rmul__(&self, other: f64) -> Self {
+        Self::from(self.inner * other)
+    }
+
+    fn __matmul__(&self, other: &Self) -> Self {
+        Self::from(self.inner * other.inner)
+    }
+
+    fn __repr__(&self) -> String {
+        let elements: [String; 16] = core::array::from_fn(|i| format!("{:?}", self.inner[i / 4][i % 4]));
+
+        format!("Matrix4x4({})", elements.join(", "))
+    }
+}
+
+/// A quaternion of `float` components, with scalar part `s` and vector part
+/// `(x, y, z)`.
+#[pyclass(name = "Quaternion", module = "cglinalg", frozen, eq)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PyQuaternion {
+    inner: Quaternion<f64>,
+}
+
+impl From<Quaternion<f64>> for PyQuaternion {
+    #[inline]
+    fn from(inner: Quaternion<f64>) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<PyQuaternion> for Quaternion<f64> {
+    #[inline]
+    fn from(quaternion: PyQuaternion) -> Self {
+        quaternion.inner
+    }
+}
+
+/// The right-hand side of a quaternion product.
+#[derive(FromPyObject)]
+enum QuaternionProductOperand {
+    Quaternion(PyQuaternion),
+    Scalar(f64),
+}
+
+#[pymethods]
+impl PyQuaternion {
+    #[new]
+    fn new(s: f64, x: f64, y: f64, z: f64) -> Self {
+        Self::from(Quaternion::new(s, x, y, z))
+    }
+
+    /// The identity quaternion.
+    #[staticmethod]
+    fn identity() -> Self {
+        Self::from(Quaternion::identity())
+    }
+
+    /// Construct a unit quaternion rotating by `angle` radians about `axis`. The
+    /// axis does not need to be normalized, but raises `ValueError` if it is zero.
+    #[staticmethod]
+    fn from_axis_angle(axis: &PyVector3, angle: f64) -> PyResult<Self> {
+        let axis = unit_axis(&axis.inner)?;
+
+        Ok(Self::from(Quaternion::from_axis_angle(&axis, Radians(angle))))
+    }
+
+    /// Construct a quaternion from a numpy array of shape `(4,)` in `(s, x, y, z)`
+    /// order.
+    #[staticmethod]
+    fn from_numpy(array: PyReadonlyArray1<'_, f64>) -> PyResult<Self> {
+        array1_from_numpy::<4>(array).map(|[s, x, y, z]| Self::new(s, x, y, z))
+    }
+
+    /// Convert the quaternion into a numpy array of shape `(4,)` in `(s, x, y, z)`
+    /// order.
+    fn to_numpy<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_slice(py, self.inner.as_slice())
+    }
+
+    #[pyo3(signature = (dtype=None, copy=None))]
+    fn __array__<'py>(&self, py: Python<'py>, dtype: Option<Bound<'py, PyAny>>, copy: Option<bool>) -> PyResult<Bound<'py, PyAny>> {
+        array_protocol(self.to_numpy(py).into_any(), dtype, copy)
+    }
+
+    #[getter]
+    fn s(&self) -> f64 {
+        self.inner.scalar()
+    }
+
+    #[getter]
+    fn x(&self) -> f64 {
+        self.inner.vector().x
+    }
+
+    #[getter]
+    fn y(&self) -> f64 {
+        self.inner.vector().y
+    }
+
+    #[getter]
+    fn z(&self) -> f64 {
+        self.inner.vector().z
+    }
+
+    /// Compute the dot product of two quaternions.
+    fn dot(&self, other: &Self) -> f64 {
+        self.inner.dot(&other.inner)
+    }
+
+    /// Compute the conjugate of the quaternion.
+    fn conjugate(&self) -> Self {
+        Self::from(self.inner.conjugate())
+    }
+
+    /// Compute the norm of the quaternion.
+    fn norm(&self) -> f64 {
+        self.inner.norm()
+    }
+
+    /// Normalize the quaternion to unit norm.
+    fn normalize(&self) -> Self {
+        Self::from(self.inner.normalize())
+    }
+
+    /// Compute the inverse of the quaternion, returning `None` if the quaternion
+    /// is not invertible.
+    fn try_inverse(&self) -> Option<Self> {
+        self.inner.try_inverse().map(Self::from)
+    }
+
+    /// Spherically interpolate between two unit quaternions, where `amount` runs
+    /// from `0` at `self` to `1` at `other`.
+    fn slerp(&self, other: &Self, amount: f64) -> Self {
+        Self::from(self.inner.slerp(&other.inner, amount))
+    }
+
+    /// Linearly interpolate between two unit quaternions and normalize the result,
+    /// where `amount` runs from `0` at `self` to `1` at `other`.
+    fn nlerp(&self, other: &Self, amount: f64) -> Self {
+        Self::from(self.inner.nlerp(&other.inner, amount))
+    }
+
+    /// Rotate a vector by the quaternion. The quaternion is normalized first, and
+    /// raises `ValueError` if it is zero.
+    fn rotate_vector(&self, vector: &PyVector3) -> PyResult<PyVector3> {
+        let rotation = Rotation3::from_quaternion(&unit_quaternion(&self.inner)?);
+
+        Ok(PyVector3::from(rotation.apply_vector(&vector.inner)))
+    }
+
+    /// Convert the unit quaternion into an affine rotation matrix.
+    fn to_affine_matrix(&self) -> PyMatrix4x4 {
+        PyMatrix4x4::from(self.inner.to_affine_matrix())
+    }
+
+    fn __add__(&self, other: &Self) -> Self {
+        Self::from(self.inner + other.inner)
+    }
+
+    fn __sub__(&self, other: &Self) -> Self {
+        Self::from(self.inner - other.inner)
+    }
+
+    fn __neg__(&self) -> Self {
+        Self::from(-self.inner)
+    }
+
+    fn __mul__(&self, other: QuaternionProductOperand) -> Self {
+        match other {
+            QuaternionProductOperand::Quaternion(other) => Self::from(self.inner * other.inner),
+            QuaternionProductOperand::Scalar(other) => Self::from(self.inner * other),
+        }
+    }
+
+    fn __rmul__(&self, other: f64) -> Self {
+        Self::from(self.inner * other)
+    }
+
+    fn __repr__(&self) -> String {
+        let vector = self.inner.vector();
+
+        format!(
+            "Quaternion({:?}, {:?}, {:?}, {:?})",
+            self.inner.scalar(),
+            vector.x,
+            vector.y,
+            vector.z
+        )
+    }
+}
+
+/// A three-dimensional isometry of `float` components. The rotation is applied
+/// first, followed by the translation.
+#[pyclass(name = "Isometry3", module = "cglinalg", frozen, eq)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PyIsometry3 {
+    inner: Isometry3<f64>,
+}
+
+impl From<Isometry3<f64>> for PyIsometry3 {
+    #[inline]
+    fn from(inner: Isometry3<f64>) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<PyIsometry3> for Isometry3<f64> {
+    #[inline]
+    fn from(isometry: PyIsometry3) -> Self {
+        isometry.inner
+    }
+}
+
+#[pymethods]
+impl PyIsometry3 {
+    /// Construct an isometry from a translation vector and a rotation quaternion.
+    /// The quaternion is normalized first, and raises `ValueError` if it is zero.
+    #[new]
+    fn new(translation: &PyVector3, rotation: &PyQuaternion) -> PyResult<Self> {
+        let translation = Translation3::from_vector(&translation.inner);
+        let rotation = Rotation3::from_quaternion(&unit_quaternion(&rotation.inner)?);
+
+        Ok(Self::from(Isometry3::from_parts(&translation, &rotation)))
+    }
+
+    /// The identity isometry.
+    #[staticmethod]
+    fn identity() -> Self {
+        Self::from(Isometry3::identity())
+    }
+
+    /// Construct the world-to-view isometry of a right-handed view looking from
+    /// `eye` towards `target`.
+    #[staticmethod]
+    fn look_at_rh(eye: &PyVector3, target: &PyVector3, up: &PyVector3) -> Self {
+        Self::from(Isometry3::look_at_rh(&eye.to_point(), &target.to_point(), &up.inner))
+    }
+
+    /// Convert the isometry into a numpy array of shape `(4, 4)` holding its
+    /// affine matrix, indexed by `(row, column)`.
+    fn to_numpy<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        PyMatrix4x4::to_numpy_matrix(py, &self.inner.to_affine_matrix())
+    }
+
+    #[pyo3(signature = (dtype=None, copy=None))]
+    fn __array__<'py>(&self, py: Python<'py>, dtype: Option<Bound<'py, PyAny>>, copy: Option<bool>) -> PyResult<Bound<'py, PyAny>> {
+        array_protocol(self.to_numpy(py)?, dtype, copy)
+    }
+
+    /// The translation part of the isometry.
+    #[getter]
+    fn translation(&self) -> PyVector3 {
+        PyVector3::from(*self.inner.translation().vector())
+    }
+
+    /// The rotation part of the isometry as a unit quaternion.
+    #[getter]
+    fn rotation(&self) -> PyQuaternion {
+        PyQuaternion::from(Quaternion::from(self.inner.rotation()))
+    }
+
+    /// Compute the inverse of the isometry.
+    fn inverse(&self) -> Self {
+        Self::from(self.inner.inverse())
+    }
+
+    /// Apply the isometry to a point.
+    fn apply_point(&self, point: &PyVector3) -> PyVector3 {
+        PyVector3::from_point(self.inner.apply_point(&point.to_point()))
+    }
+
+    /// Apply the isometry to a vector. Vectors are not affected by the translation.
+    fn apply_vector(&self, vector: &PyVector3) -> PyVector3 {
+        PyVector3::from(self.inner.apply_vector(&vector.inner))
+    }
+
+    /// Convert the isometry into an affine matrix.
+    fn to_affine_matrix(&self) -> PyMatrix4x4 {
+        PyMatrix4x4::from(self.inner.to_affine_matrix())
+    }
+
+    fn __mul__(&self, other: &Self) -> Self {
+        Self::from(self.inner * other.inner)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Isometry3({}, {})", self.translation().__repr__(), self.rotation().__repr__())
+    }
+}
+
+/// A perspective projection of `float` components.
+#[pyclass(name = "Perspective3", module = "cglinalg", frozen, eq)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PyPerspective3 {
+    inner: Perspective3<f64>,
+}
+
+impl From<Perspective3<f64>> for PyPerspective3 {
+    #[inline]
+    fn from(inner: Perspective3<f64>) -> Self {
+        Self { inner }
+    }
+}
+
+impl From<PyPerspective3> for Perspective3<f64> {
+    #[inline]
+    fn from(perspective: PyPerspective3) -> Self {
+        perspective.inner
+    }
+}
+
+#[pymethods]
+impl PyPerspective3 {
+    /// Construct a perspective projection from the frustum bounds, with the same
+    /// conventions as `Matrix4x4.from_perspective`.
+    #[new]
+    fn new(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> Self {
+        Self::from(Perspective3::new(left, right, bottom, top, near, far))
+    }
+
+    /// Construct a perspective projection from a vertical field of view in
+    /// radians, an aspect ratio, and the near and far plane distances.
+    #[staticmethod]
+    fn from_vfov(vfov: f64, aspect_ratio: f64, near: f64, far: f64) -> Self {
+        Self::from(Perspective3::from_vfov(Radians(vfov), aspect_ratio, near, far))
+    }
+
+    /// Construct a perspective projection from a horizontal field of view in
+    /// radians, an aspect ratio, and the near and far plane distances.
+    #[staticmethod]
+    fn from_hfov(hfov: f64, aspect_ratio: f64, near: f64, far: f64) -> Self {
+        Self::from(Perspective3::from_hfov(Radians(hfov), aspect_ratio, near, far))
+    }
+
+    /// Convert the projection into a numpy array of shape `(4, 4)` holding its
+    /// matrix, indexed by `(row, column)`.
+    fn to_numpy<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        PyMatrix4x4::to_numpy_matrix(py, self.inner.matrix())
+    }
+
+    #[pyo3(signature = (dtype=None, copy=None))]
+    fn __array__<'py>(&self, py: Python<'py>, dtype: Option<Bound<'py, PyAny>>, copy: Option<bool>) -> PyResult<Bound<'py, PyAny>> {
+        array_protocol(self.to_numpy(py)?, dtype, copy)
+    }
+
+    #[getter]
+    fn left(&self) -> f64 {
+        self.inner.left()
+    }
+
+    #[getter]
+    fn right(&self) -> f64 {
+        self.inner.right()
+    }
+
+    #[getter]
+    fn bottom(&self) -> f64 {
+        self.inner.bottom()
+    }
+
+    #[getter]
+    fn top(&self) -> f64 {
+        self.inner.top()
+    }
+
+    #[getter]
+    fn near(&self) -> f64 {
+        self.inner.near()
+    }
+
+    #[getter]
+    fn far(&self) -> f64 {
+        self.inner.far()
+    }
+
+    /// The aspect ratio of the near plane.
+    #[getter]
+    fn aspect_ratio(&self) -> f64 {
+        self.inner.aspect_ratio()
+    }
+
+    /// The vertical field of view in radians.
+    #[getter]
+    fn vfov(&self) -> f64 {
+        self.inner.vfov().0
+    }
+
+    /// The projection matrix.
+    #[getter]
+    fn matrix(&self) -> PyMatrix4x4 {
+        PyMatrix4x4::from(*self.inner.matrix())
+    }
+
+    /// Project a point from eye space into normalized device coordinates.
+    fn project_point(&self, point: &PyVector3) -> PyVector3 {
+        PyVector3::from_point(self.inner.project_point(&point.to_point()))
+    }
+
+    /// Unproject a point from normalized device coordinates into eye space.
+    fn unproject_point(&self, point: &PyVector3) -> PyVector3 {
+        PyVector3::from_point(self.inner.unproject_point(&point.to_point()))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Perspective3({:?}, {:?}, {:?}, {:?}, {:?}, {:?})",
+            self.left(),
+            self.right(),
+            self.bottom(),
+            self.top(),
+            self.near(),
+            self.far()
+        )
+    }
+}
+
+/// The `cglinalg` Python extension module.
+#[pymodule]
+#[pyo3(name = "cglinalg")]
+pub fn cglinalg_module(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyVector3>()?;
+    module.add_class::<PyMatrix4x4>()?;
+    module.add_class::<PyQuaternion>()?;
+    module.add_class::<PyIsometry3>()?;
+    module.add_class::<PyPerspective3>()?;
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+#[cfg(all(test, feature = "ffi"))]
+mod ffi_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg::ffi::*;
+    use cglinalg::{
+        Isometry3,
+        Matrix3x3,
+        Matrix4x4,
+        Orthographic3,
+        Perspective3,
+        Point3,
+        Quaternion,
+        Radians,
+        Rotation3,
+        Similarity3,
+        Translation3,
+        Unit,
+        Vector3,
+        Vector4,
+    };
+
+
+    #[test]
+    fn test_vector_round_trip() {
+        let vector = Vector3::new(1_f64, -2_f64, 3_f64);
+        let ffi_vector = CglVector3d::from(vector);
+
+        assert_eq!(ffi_vector.data, [1_f64, -2_f64, 3_f64]);
+        assert_eq!(Vector3::from(ffi_vector), vector);
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_matrix_round_trip_column_major() {
+        let matrix = Matrix3x3::new(
+            1_f32, 2_f32, 3_f32,
+            4_f32, 5_f32, 6_f32,
+            7_f32, 8_f32, 9_f32,
+        );
+        let ffi_matrix = CglMatrix3x3f::from(matrix);
+
+        assert_eq!(ffi_matrix.data, [[1_f32, 2_f32, 3_f32], [4_f32, 5_f32, 6_f32], [7_f32, 8_f32, 9_f32]]);
+        assert_eq!(Matrix3x3::from(ffi_matrix), matrix);
+    }
+
+    #[test]
+    fn test_quaternion_component_order() {
+        let quaternion = Quaternion::new(1_f64, 2_f64, 3_f64, 4_f64);
+        let ffi_quaternion = CglQuaterniond::from(quaternion);
+
+        assert_eq!(
+            ffi_quaternion,
+            CglQuaterniond {
+                s: 1_f64,
+                x: 2_f64,
+                y: 3_f64,
+                z: 4_f64
+            }
+        );
+    }
+
+    #[test]
+    fn test_vector_arithmetic_matches() {
+        let a = Vector4::new(1.5_f32, -2.25_f32, 3.125_f32, 0.1_f32);
+        let b = Vector4::new(-0.3_f32, 7_f32, 2.2_f32, -9.9_f32);
+
+        assert_eq!(Vector4::from(cgl_vector4f_add(a.into(), b.into())), a + b);
+        assert_eq!(Vector4::from(cgl_vector4f_sub(a.into(), b.into())), a - b);
+        assert_eq!(Vector4::from(cgl_vector4f_mul_scalar(a.into(), 0.7_f32)), a * 0.7_f32);
+        assert_eq!(cgl_vector4f_dot(a.into(), b.into()), a.dot(&b));
+        assert_eq!(cgl_vector4f_norm(a.into()), a.norm());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_matrix_inverse_matches() {
+        let matrix = Matrix4x4::new(
+            2_f64, 0.5_f64, 0_f64, 0_f64,
+            0.1_f64, 4_f64, 0_f64, 0_f64,
+            0_f64, 0.3_f64, 8_f64, 0_f64,
+            1_f64, 2_f64, 3_f64, 1_f64,
+        );
+        let mut result = cgl_matrix4x4d_identity();
+        let is_invertible = unsafe { cgl_matrix4x4d_try_inverse(matrix.into(), &mut result) };
+
+        assert!(is_invertible);
+        assert_eq!(Matrix4x4::from(result), matrix.try_inverse().unwrap());
+    }
+
+    #[test]
+    fn test_matrix_inverse_singular() {
+        let matrix = Matrix4x4::<f64>::zero();
+        let mut result = cgl_matrix4x4d_identity();
+        let is_invertible = unsafe { cgl_matrix4x4d_try_inverse(matrix.into(), &mut result) };
+
+        assert!(!is_invertible);
+        assert_eq!(result, cgl_matrix4x4d_identity());
+    }
+
+    #[test]
+    fn test_quaternion_slerp_matches() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let q0 = Quaternion::from_axis_angle(&axis, Radians(0.3_f64));
+        let q1 = Quaternion::from_axis_angle(&axis, Radians(2.1_f64));
+        let result = cgl_quaterniond_slerp(q0.into(), q1.into(), 0.37_f64);
+
+        assert_eq!(Quaternion::from(result), q0.slerp(&q1, 0.37_f64));
+    }
+
+    #[test]
+    fn test_look_at_rh_matches() {
+        let eye = Point3::new(1_f32, 2_f32, 3_f32);
+        let target = Point3::new(-4_f32, 5_f32, 0.5_f32);
+        let up = Vector3::unit_y();
+
+        assert_eq!(
+            Matrix4x4::from(cgl_matrix4x4f_look_at_rh(eye.into(), target.into(), up.into())),
+            Matrix4x4::look_at_rh(&eye, &target, &up)
+        );
+        assert_eq!(
+            Isometry3::from(cgl_isometry3f_look_at_rh(eye.into(), target.into(), up.into())),
+            Isometry3::look_at_rh(&eye, &target, &up)
+        );
+    }
+
+    #[test]
+    fn test_projections_match() {
+        let perspective = Perspective3::new(3_f64, 3_f64, 2_f64, 2_f64, 1_f64, 100_f64);
+        let orthographic = Orthographic3::new(4_f64, 4_f64, 2_f64, 2_f64, 1_f64, 100_f64);
+        let point = Point3::new(0.5_f64, -0.25_f64, -7_f64);
+
+        assert_eq!(
+            Matrix4x4::from(cgl_matrix4x4d_perspective(3_f64, 3_f64, 2_f64, 2_f64, 1_f64, 100_f64)),
+            Matrix4x4::from_perspective(3_f64, 3_f64, 2_f64, 2_f64, 1_f64, 100_f64)
+        );
+        assert_eq!(
+            Matrix4x4::from(cgl_matrix4x4d_perspective_vfov(1.2_f64, 1.5_f64, 0.1_f64, 50_f64)),
+            Matrix4x4::from_perspective_vfov(Radians(1.2_f64), 1.5_f64, 0.1_f64, 50_f64)
+        );
+        assert_eq!(
+            Perspective3::from(cgl_perspective3d_new(3_f64, 3_f64, 2_f64, 2_f64, 1_f64, 100_f64)),
+            perspective
+        );
+        assert_eq!(
+            Orthographic3::from(cgl_orthographic3d_new(4_f64, 4_f64, 2_f64, 2_f64, 1_f64, 100_f64)),
+            orthographic
+        );
+        assert_eq!(
+            Point3::from(cgl_perspective3d_project_point(perspective.into(), point.into())),
+            perspective.project_point(&point)
+        );
+        assert_eq!(
+            Point3::from(cgl_orthographic3d_unproject_point(orthographic.into(), point.into())),
+            orthographic.unproject_point(&point)
+        );
+    }
+
+    #[test]
+    fn test_transforms_match() {
+        let axis = Unit::from_value(Vector3::new(0.2_f64, -0.7_f64, 0.4_f64));
+        let rotation = Rotation3::from_axis_angle(&axis, Radians(1.1_f64));
+        let translation = Translation3::new(1_f64, -2_f64, 0.5_f64);
+        let similarity = Similarity3::from_parts(&translation, &rotation, 2.5_f64);
+        let point = Point3::new(3_f64, 1_f64, -4_f64);
+        let ffi_similarity = cgl_similarity3d_from_parts(translation.into(), rotation.into(), 2.5_f64);
+        let mut ffi_rotation = CglRotation3d::from(Rotation3::<f64>::identity());
+        let is_valid = unsafe { cgl_rotation3d_from_axis_angle(axis.into_inner().into(), 1.1_f64, &mut ffi_rotation) };
+        let mut ffi_inverse = ffi_similarity;
+        let is_invertible = unsafe { cgl_similarity3d_try_inverse(ffi_similarity, &mut ffi_inverse) };
+
+        assert!(is_valid);
+        assert_eq!(Rotation3::from(ffi_rotation), rotation);
+        assert_eq!(Similarity3::from(ffi_similarity), similarity);
+        assert_eq!(
+            Point3::from(cgl_similarity3d_apply_point(ffi_similarity, point.into())),
+            similarity.apply_point(&point)
+        );
+        assert!(is_invertible);
+        assert_eq!(Similarity3::from(ffi_inverse), similarity.inverse());
+        assert_eq!(
+            Matrix4x4::from(cgl_similarity3d_to_affine_matrix(ffi_similarity)),
+            similarity.to_affine_matrix()
+        );
+    }
+
+    #[test]
+    fn test_rotation_from_quaternion_normalizes() {
+        let axis = Unit::from_value(Vector3::new(0.2_f64, -0.7_f64, 0.4_f64));
+        let quaternion = Quaternion::from_axis_angle(&axis, Radians(1.1_f64));
+        let mut result = CglRotation3d::from(Rotation3::<f64>::identity());
+        let is_valid = unsafe { cgl_rotation3d_from_quaternion((quaternion * 3_f64).into(), &mut result) };
+
+        assert!(is_valid);
+        assert_relative_eq!(
+            Rotation3::from(result).to_matrix(),
+            Rotation3::from_quaternion(&quaternion).to_matrix(),
+            abs_diff_all <= 1e-15_f64,
+            relative_all <= f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_degenerate_rotations_are_rejected() {
+        let zero = Vector3::<f64>::zero();
+        let mut quaternion = CglQuaterniond::from(Quaternion::<f64>::identity());
+        let mut rotation = CglRotation3d::from(Rotation3::<f64>::identity());
+
+        assert!(!unsafe { cgl_quaterniond_from_axis_angle(zero.into(), 1_f64, &mut quaternion) });
+        assert!(!unsafe { cgl_rotation3d_from_axis_angle(zero.into(), 1_f64, &mut rotation) });
+        assert!(!unsafe { cgl_rotation3d_from_quaternion(Quaternion::<f64>::zero().into(), &mut rotation) });
+        assert_eq!(quaternion, CglQuaterniond::from(Quaternion::<f64>::identity()));
+        assert_eq!(rotation, CglRotation3d::from(Rotation3::<f64>::identity()));
+    }
+
+    #[test]
+    fn test_non_orthonormal_rotation_has_no_inverse() {
+        let scaled = CglRotation3d {
+            matrix: (Matrix3x3::identity() * 2_f64).into(),
+        };
+        let isometry = CglIsometry3d {
+            rotation: scaled,
+            translation: Translation3::new(1_f64, 2_f64, 3_f64).into(),
+        };
+        let mut rotation = CglRotation3d::from(Rotation3::<f64>::identity());
+        let mut inverse = CglIsometry3d::from(Isometry3::<f64>::identity());
+
+        assert!(!unsafe { cgl_rotation3d_from_matrix(scaled.matrix, &mut rotation) });
+        assert!(!unsafe { cgl_rotation3d_try_inverse(scaled, &mut rotation) });
+        assert!(!unsafe { cgl_isometry3d_try_inverse(isometry, &mut inverse) });
+        assert!(unsafe { cgl_rotation3d_try_inverse(CglRotation3d::from(Rotation3::<f64>::identity()), &mut rotation) });
+    }
+}
@@ -0,0 +1,231 @@
+#[cfg(all(test, feature = "python"))]
+mod python_tests {
+    use approx_cmp::assert_relative_eq;
+    use cglinalg::python::{
+        cglinalg_module,
+        PyIsometry3,
+        PyMatrix4x4,
+        PyPerspective3,
+        PyQuaternion,
+        PyVector3,
+    };
+    use cglinalg::{
+        Isometry3,
+        Matrix4x4,
+        Perspective3,
+        Point3,
+        Quaternion,
+        Radians,
+        Rotation3,
+        Translation3,
+        Unit,
+        Vector3,
+    };
+    use core::ffi::CStr;
+    use pyo3::exceptions::PyValueError;
+    use pyo3::prelude::*;
+    use pyo3::types::PyDict;
+
+
+    fn module_globals(py: Python<'_>) -> Bound<'_, PyDict> {
+        let module = PyModule::new(py, "cglinalg").unwrap();
+        cglinalg_module(&module).unwrap();
+        let globals = PyDict::new(py);
+        globals.set_item("cglinalg", module).unwrap();
+
+        globals
+    }
+
+    fn eval<T>(code: &CStr) -> T
+    where
+        T: for<'py> FromPyObjectOwned<'py>,
+    {
+        Python::initialize();
+        Python::attach(|py| {
+            let globals = module_globals(py);
+
+            py.eval(code, Some(&globals), None)
+                .unwrap()
+                .extract::<T>()
+                .map_err(Into::into)
+                .unwrap()
+        })
+    }
+
+    fn eval_raises_value_error(code: &CStr) -> bool {
+        Python::initialize();
+        Python::attach(|py| {
+            let globals = module_globals(py);
+
+            py.eval(code, Some(&globals), None)
+                .is_err_and(|error| error.is_instance_of::<PyValueError>(py))
+        })
+    }
+
+    #[test]
+    fn test_vector3_operators() {
+        let a = Vector3::new(1.5_f64, -2_f64, 0.25_f64);
+        let b = Vector3::new(0.1_f64, 0.2_f64, 0.3_f64);
+
+        assert_eq!(
+            Vector3::from(eval::<PyVector3>(
+                c"cglinalg.Vector3(1.5, -2.0, 0.25) + cglinalg.Vector3(0.1, 0.2, 0.3)"
+            )),
+            a + b
+        );
+        assert_eq!(
+            Vector3::from(eval::<PyVector3>(
+                c"0.7 * (cglinalg.Vector3(1.5, -2.0, 0.25) - cglinalg.Vector3(0.1, 0.2, 0.3))"
+            )),
+            (a - b) * 0.7_f64
+        );
+        assert_eq!(
+            Vector3::from(eval::<PyVector3>(
+                c"cglinalg.Vector3(1.5, -2.0, 0.25).cross(cglinalg.Vector3(0.1, 0.2, 0.3))"
+            )),
+            a.cross(&b)
+        );
+        assert_eq!(eval::<f64>(c"cglinalg.Vector3(1.5, -2.0, 0.25).norm()"), a.norm());
+    }
+
+    #[test]
+    fn test_vector3_sequence_protocol() {
+        assert_eq!(
+            eval::<Vec<f64>>(c"list(cglinalg.Vector3(1.0, 2.0, 3.0))"),
+            vec![1_f64, 2_f64, 3_f64]
+        );
+        assert_eq!(eval::<f64>(c"cglinalg.Vector3(1.0, 2.0, 3.0)[-1]"), 3_f64);
+    }
+
+    #[test]
+    fn test_matrix4x4_indexing_is_row_column() {
+        let matrix = Matrix4x4::from_affine_translation(&Vector3::new(1_f64, 2_f64, 3_f64));
+        let element = eval::<f64>(c"cglinalg.Matrix4x4.from_affine_translation(cglinalg.Vector3(1.0, 2.0, 3.0))[1, 3]");
+
+        assert_eq!(element, matrix[3][1]);
+        assert_eq!(element, 2_f64);
+    }
+
+    #[test]
+    fn test_matrix4x4_look_at_rh_inverse() {
+        let eye = Point3::new(1_f64, 2_f64, 3_f64);
+        let target = Point3::new(-4_f64, 5_f64, 0.5_f64);
+        let up = Vector3::unit_y();
+        let expected = Matrix4x4::look_at_rh(&eye, &target, &up).try_inverse().unwrap();
+        let result = eval::<PyMatrix4x4>(
+            c"cglinalg.Matrix4x4.look_at_rh(cglinalg.Vector3(1.0, 2.0, 3.0), cglinalg.Vector3(-4.0, 5.0, 0.5), cglinalg.Vector3.unit_y()).try_inverse()",
+        );
+
+        assert_eq!(Matrix4x4::from(result), expected);
+    }
+
+    #[test]
+    fn test_matrix4x4_singular_inverse_is_none() {
+        assert!(eval::<bool>(c"cglinalg.Matrix4x4.zero().try_inverse() is None"));
+    }
+
+    #[test]
+    fn test_matrix4x4_products() {
+        let projection = Matrix4x4::from_perspective_vfov(Radians(1.2_f64), 1.5_f64, 0.1_f64, 50_f64);
+        let view = Matrix4x4::from_affine_translation(&Vector3::new(0_f64, 0_f64, -5_f64));
+        let result = eval::<PyMatrix4x4>(
+            c"cglinalg.Matrix4x4.from_perspective_vfov(1.2, 1.5, 0.1, 50.0) @ cglinalg.Matrix4x4.from_affine_translation(cglinalg.Vector3(0.0, 0.0, -5.0))",
+        );
+
+        assert_eq!(Matrix4x4::from(result), projection * view);
+        assert!(eval::<bool>(
+            c"cglinalg.Matrix4x4.identity() * 2.0 == 2.0 * cglinalg.Matrix4x4.identity()"
+        ));
+    }
+
+    #[test]
+    fn test_quaternion_slerp() {
+        let axis = Unit::from_value(Vector3::new(1_f64, 2_f64, 3_f64));
+        let q0 = Quaternion::from_axis_angle(&axis, Radians(0.3_f64));
+        let q1 = Quaternion::from_axis_angle(&axis, Radians(2.1_f64));
+        let result = eval::<PyQuaternion>(
+            c"cglinalg.Quaternion.from_axis_angle(cglinalg.Vector3(1.0, 2.0, 3.0), 0.3).slerp(cglinalg.Quaternion.from_axis_angle(cglinalg.Vector3(1.0, 2.0, 3.0), 2.1), 0.37)",
+        );
+
+        assert_eq!(Quaternion::from(result), q0.slerp(&q1, 0.37_f64));
+    }
+
+    #[test]
+    fn test_isometry3_apply_point() {
+        let axis = Unit::from_value(Vector3::new(0.2_f64, -0.7_f64, 0.4_f64));
+        let rotation = Rotation3::from_quaternion(&Quaternion::from_axis_angle(&axis, Radians(1.1_f64)));
+        let translation = Translation3::new(1_f64, -2_f64, 0.5_f64);
+        let isometry = Isometry3::from_parts(&translation, &rotation);
+        let point = Point3::new(3_f64, 1_f64, -4_f64);
+        let result = eval::<PyVector3>(
+            c"cglinalg.Isometry3(cglinalg.Vector3(1.0, -2.0, 0.5), cglinalg.Quaternion.from_axis_angle(cglinalg.Vector3(0.2, -0.7, 0.4), 1.1)).inverse().apply_point(cglinalg.Vector3(3.0, 1.0, -4.0))",
+        );
+
+        assert_eq!(Vector3::from(result), isometry.inverse().apply_point(&point).to_vector());
+    }
+
+    #[test]
+    fn test_isometry3_normalizes_rotation_quaternion() {
+        let axis = Unit::from_value(Vector3::new(0.2_f64, -0.7_f64, 0.4_f64));
+        let rotation = Rotation3::from_quaternion(&Quaternion::from_axis_angle(&axis, Radians(1.1_f64)));
+        let translation = Translation3::new(1_f64, -2_f64, 0.5_f64);
+        let isometry = Isometry3::from_parts(&translation, &rotation);
+        let point = Point3::new(3_f64, 1_f64, -4_f64);
+        let result = eval::<PyVector3>(
+            c"cglinalg.Isometry3(cglinalg.Vector3(1.0, -2.0, 0.5), 3.0 * cglinalg.Quaternion.from_axis_angle(cglinalg.Vector3(0.2, -0.7, 0.4), 1.1)).inverse().apply_point(cglinalg.Vector3(3.0, 1.0, -4.0))",
+        );
+        let expected = isometry.inverse().apply_point(&point).to_vector();
+
+        assert_relative_eq!(Vector3::from(result), expected, abs_diff_all <= 1e-12_f64, relative_all <= f64::EPSILON);
+    }
+
+    #[test]
+    fn test_zero_rotation_quaternion_raises_value_error() {
+        assert!(eval_raises_value_error(
+            c"cglinalg.Isometry3(cglinalg.Vector3.zero(), cglinalg.Quaternion(0.0, 0.0, 0.0, 0.0))"
+        ));
+        assert!(eval_raises_value_error(
+            c"cglinalg.Quaternion(0.0, 0.0, 0.0, 0.0).rotate_vector(cglinalg.Vector3.unit_x())"
+        ));
+    }
+
+    #[test]
+    fn test_zero_axis_raises_value_error() {
+        assert!(eval_raises_value_error(
+            c"cglinalg.Quaternion.from_axis_angle(cglinalg.Vector3.zero(), 1.0)"
+        ));
+    }
+
+    #[test]
+    fn test_isometry3_look_at_rh() {
+        let eye = Point3::new(1_f64, 2_f64, 3_f64);
+        let target = Point3::new(-4_f64, 5_f64, 0.5_f64);
+        let up = Vector3::unit_y();
+        let result = eval::<PyIsometry3>(
+            c"cglinalg.Isometry3.look_at_rh(cglinalg.Vector3(1.0, 2.0, 3.0), cglinalg.Vector3(-4.0, 5.0, 0.5), cglinalg.Vector3.unit_y())",
+        );
+
+        assert_eq!(Isometry3::from(result), Isometry3::look_at_rh(&eye, &target, &up));
+    }
+
+    #[test]
+    fn test_perspective3_project_point() {
+        let perspective = Perspective3::new(3_f64, 3_f64, 2_f64, 2_f64, 1_f64, 100_f64);
+        let point = Point3::new(0.5_f64, -0.25_f64, -7_f64);
+        let result =
+            eval::<PyVector3>(c"cglinalg.Perspective3(3.0, 3.0, 2.0, 2.0, 1.0, 100.0).project_point(cglinalg.Vector3(0.5, -0.25, -7.0))");
+
+        assert_eq!(
+            Perspective3::from(eval::<PyPerspective3>(c"cglinalg.Perspective3(3.0, 3.0, 2.0, 2.0, 1.0, 100.0)")),
+            perspective
+        );
+        assert_eq!(Vector3::from(result), perspective.project_point(&point).to_vector());
+    }
+
+    #[test]
+    fn test_operand_type_mismatch_raises_type_error() {
+        assert!(eval::<bool>(
+            c"cglinalg.Vector3.__add__(cglinalg.Vector3.zero(), 1.0) is NotImplemented"
+        ));
+    }
+}